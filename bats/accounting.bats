@@ -6,6 +6,7 @@ PERSISTED_LOG_FILE="accounting.e2e-logs"
 RUN_LOG_FILE="accounting.run.e2e-logs"
 
 setup_file() {
+  export LANA_DOMAIN_CONFIG_MANUAL_TRANSACTION_APPROVAL_THRESHOLD_CENTS=100000
   start_server
   login_superadmin
 }
@@ -304,7 +305,7 @@ teardown_file() {
   exec_admin_graphql 'manual-transaction-execute' "$variables"
   graphql_output
   errors=$(graphql_output '.errors')
-  [[ "$errors" =~ "VelocityError" ]] || exit 1
+  [[ "$errors" =~ "PeriodClosed" ]] || exit 1
 }
 
 @test "accounting: can close month in fiscal year" {
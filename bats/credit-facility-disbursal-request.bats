@@ -0,0 +1,193 @@
+#!/usr/bin/env bats
+
+load "helpers"
+
+PERSISTED_LOG_FILE="credit-facility-disbursal-request.e2e-logs"
+RUN_LOG_FILE="credit-facility-disbursal-request.run.e2e-logs"
+
+setup_file() {
+  start_server
+  login_superadmin
+  reset_log_files "$PERSISTED_LOG_FILE" "$RUN_LOG_FILE"
+
+  manual_custodian_id=$(get_or_create_manual_custodian)
+  cache_value 'dr_manual_custodian_id' "$manual_custodian_id"
+}
+
+teardown_file() {
+  stop_server
+  cp "$LOG_FILE" "$PERSISTED_LOG_FILE"
+}
+
+wait_for_dr_approval() {
+  variables=$(jq -n --arg creditFacilityProposalId "$1" '{ id: $creditFacilityProposalId }')
+  exec_admin_graphql 'find-credit-facility-proposal' "$variables"
+  status=$(graphql_output '.data.creditFacilityProposal.status')
+  [[ "$status" == "APPROVED" ]] || return 1
+}
+
+wait_for_dr_active() {
+  variables=$(jq -n --arg creditFacilityId "$1" '{ id: $creditFacilityId }')
+  exec_admin_graphql 'find-credit-facility' "$variables"
+  status=$(graphql_output '.data.creditFacility.status')
+  [[ "$status" == "ACTIVE" ]] || return 1
+}
+
+wait_for_dr_disbursal() {
+  local credit_facility_id=$1
+  local disbursal_id=$2
+
+  variables=$(jq -n --arg creditFacilityId "$credit_facility_id" '{ id: $creditFacilityId }')
+  exec_admin_graphql 'find-credit-facility' "$variables"
+  echo "disbursal-request disbursal | $(graphql_output)" >> $RUN_LOG_FILE
+  num_disbursals=$(
+    graphql_output \
+      --arg disbursal_id "$disbursal_id" \
+      '[.data.creditFacility.disbursals[] | select(.creditFacilityDisbursalId == $disbursal_id)] | length'
+  )
+  [[ "$num_disbursals" -eq "1" ]]
+}
+
+login_customer_by_id() {
+  local customer_id=$1
+
+  variables=$(jq -n --arg id "$customer_id" '{ id: $id }')
+  exec_admin_graphql 'customer' "$variables"
+  email=$(graphql_output .data.customer.email)
+  [[ "$email" != "null" ]] || exit 1
+
+  login_customer "$email" > /dev/null
+  echo "$email"
+}
+
+request_disbursal() {
+  local email=$1
+  local credit_facility_id=$2
+  local deposit_account_id=$3
+  local amount=$4
+
+  variables=$(jq -n \
+    --arg creditFacilityId "$credit_facility_id" \
+    --arg depositAccountId "$deposit_account_id" \
+    --argjson amount "$amount" \
+    '{ input: { creditFacilityId: $creditFacilityId, depositAccountId: $depositAccountId, amount: $amount } }')
+  exec_customer_graphql "$email" 'credit-facility-disbursal-request' "$variables"
+  echo "disbursal-request request | $(graphql_output)" >> $RUN_LOG_FILE
+}
+
+# ===== Tests =====
+
+@test "disbursal-request: setup active facility" {
+  customer_id=$(create_customer)
+  cache_value 'dr_customer_id' "$customer_id"
+
+  deposit_account_id=$(create_deposit_account_for_customer "$customer_id")
+  cache_value 'dr_deposit_account_id' "$deposit_account_id"
+
+  custodian_id=$(read_value 'dr_manual_custodian_id')
+  variables=$(jq -n \
+    --arg customerId "$customer_id" \
+    --arg custodianId "$custodian_id" \
+    '{
+      input: {
+        customerId: $customerId,
+        facility: 100000,
+        custodianId: $custodianId,
+        terms: {
+          annualRate: "12",
+          accrualCycleInterval: "END_OF_MONTH",
+          accrualInterval: "END_OF_DAY",
+          disbursalPolicy: "MULTIPLE_DISBURSAL",
+          oneTimeFeeRate: "0",
+          duration: { period: "MONTHS", units: 3 },
+          interestDueDurationFromAccrual: { period: "DAYS", units: 0 },
+          obligationOverdueDurationFromDue: { period: "DAYS", units: 50 },
+          obligationLiquidationDurationFromDue: { period: "DAYS", units: 360 },
+          liquidationCvl: "105",
+          marginCallCvl: "125",
+          initialCvl: "140"
+        }
+      }
+    }')
+  exec_admin_graphql 'credit-facility-proposal-create' "$variables"
+  proposal_id=$(graphql_output '.data.creditFacilityProposalCreate.creditFacilityProposal.creditFacilityProposalId')
+  [[ "$proposal_id" != "null" ]] || exit 1
+
+  variables=$(jq -n \
+    --arg creditFacilityProposalId "$proposal_id" \
+    '{ input: { creditFacilityProposalId: $creditFacilityProposalId, approved: true } }')
+  exec_admin_graphql 'credit-facility-proposal-customer-approval-conclude' "$variables"
+  retry 30 2 wait_for_dr_approval "$proposal_id"
+
+  variables=$(jq -n --arg id "$proposal_id" '{ id: $id }')
+  exec_admin_graphql 'find-pending-credit-facility' "$variables"
+  collateral_id=$(graphql_output '.data.pendingCreditFacility.collateralId')
+  [[ "$collateral_id" != "null" ]] || exit 1
+
+  exec_admin_graphql 'time'
+  current_date=$(graphql_output '.data.time.currentDate')
+
+  variables=$(jq -n \
+    --arg collateralId "$collateral_id" \
+    --arg effective "$current_date" \
+    '{ input: { collateralId: $collateralId, collateral: 50000000, effective: $effective } }')
+  exec_admin_graphql 'collateral-update' "$variables"
+
+  credit_facility_id="$proposal_id"
+  retry 30 2 wait_for_dr_active "$credit_facility_id"
+  cache_value 'dr_credit_facility_id' "$credit_facility_id"
+}
+
+@test "disbursal-request: customer can request a disbursal to their deposit account" {
+  customer_id=$(read_value 'dr_customer_id')
+  deposit_account_id=$(read_value 'dr_deposit_account_id')
+  credit_facility_id=$(read_value 'dr_credit_facility_id')
+
+  email=$(login_customer_by_id "$customer_id")
+  request_disbursal "$email" "$credit_facility_id" "$deposit_account_id" 40000
+
+  disbursal_id=$(graphql_output '.data.creditFacilityDisbursalRequest.disbursal.creditFacilityDisbursalId')
+  [[ "$disbursal_id" != "null" ]] || exit 1
+  amount=$(graphql_output '.data.creditFacilityDisbursalRequest.disbursal.amount')
+  [[ "$amount" -eq 40000 ]] || exit 1
+
+  retry 30 2 wait_for_dr_disbursal "$credit_facility_id" "$disbursal_id"
+}
+
+@test "disbursal-request: request above the undrawn limit is rejected" {
+  customer_id=$(read_value 'dr_customer_id')
+  deposit_account_id=$(read_value 'dr_deposit_account_id')
+  credit_facility_id=$(read_value 'dr_credit_facility_id')
+
+  email=$(login_customer_by_id "$customer_id")
+  request_disbursal "$email" "$credit_facility_id" "$deposit_account_id" 100001
+
+  [[ "$(graphql_output '.data')" == "null" ]] || exit 1
+  graphql_output '.errors[0].message' | grep -q 'DisbursalExceedsUndrawnLimit' || exit 1
+}
+
+@test "disbursal-request: customer cannot draw on another customer's facility" {
+  credit_facility_id=$(read_value 'dr_credit_facility_id')
+
+  other_customer_id=$(create_customer)
+  other_deposit_account_id=$(create_deposit_account_for_customer "$other_customer_id")
+
+  email=$(login_customer_by_id "$other_customer_id")
+  request_disbursal "$email" "$credit_facility_id" "$other_deposit_account_id" 1000
+
+  [[ "$(graphql_output '.data')" == "null" ]] || exit 1
+  graphql_output '.errors[0].message' | grep -q 'CustomerMismatchForCreditFacility' || exit 1
+}
+
+@test "disbursal-request: customer cannot pay out to another customer's deposit account" {
+  customer_id=$(read_value 'dr_customer_id')
+  credit_facility_id=$(read_value 'dr_credit_facility_id')
+
+  other_customer_id=$(create_customer)
+  other_deposit_account_id=$(create_deposit_account_for_customer "$other_customer_id")
+
+  email=$(login_customer_by_id "$customer_id")
+  request_disbursal "$email" "$credit_facility_id" "$other_deposit_account_id" 1000
+
+  [[ "$(graphql_output '.data')" == "null" ]] || exit 1
+}
@@ -0,0 +1,34 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum BtcRevaluationError {
+    #[error("BtcRevaluationError - DomainConfigError: {0}")]
+    DomainConfigError(#[from] domain_config::DomainConfigError),
+    #[error("BtcRevaluationError - AccountCodeParseError: {0}")]
+    AccountCodeParseError(#[from] crate::primitives::AccountCodeParseError),
+    #[error("BtcRevaluationError - ChartOfAccountsError: {0}")]
+    ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("BtcRevaluationError - CalaBalance: {0}")]
+    CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
+    #[error("BtcRevaluationError - ManualTransactionLedgerError: {0}")]
+    ManualTransactionLedgerError(
+        #[from] crate::manual_transaction::ledger::error::ManualTransactionLedgerError,
+    ),
+    #[error("BtcRevaluationError - MissingAccountCode: {0}")]
+    MissingAccountCode(&'static str),
+}
+
+impl ErrorSeverity for BtcRevaluationError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::DomainConfigError(e) => e.severity(),
+            Self::AccountCodeParseError(e) => e.severity(),
+            Self::ChartOfAccountsError(e) => e.severity(),
+            Self::CalaBalance(_) => Level::ERROR,
+            Self::ManualTransactionLedgerError(e) => e.severity(),
+            Self::MissingAccountCode(_) => Level::WARN,
+        }
+    }
+}
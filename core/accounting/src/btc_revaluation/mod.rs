@@ -0,0 +1,308 @@
+pub mod error;
+
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::collections::HashMap;
+use tracing::instrument;
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use cala_ledger::{BalanceId, CalaLedger, Currency, DebitOrCredit, JournalId, Layer};
+use core_price::{Price, PriceOfOneBTC};
+use domain_config::ExposedDomainConfigsReadOnly;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    chart_of_accounts::{ChartOfAccounts, error::ChartOfAccountsError},
+    config::{
+        BtcRevaluationAccountCode, BtcRevaluationAdjustmentAccountCode,
+        BtcRevaluationUnrealizedGainAccountCode, BtcRevaluationUnrealizedLossAccountCode,
+    },
+    manual_transaction::ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams},
+    primitives::{
+        AccountCode, BTC_REVALUATION, CalaAccountSetId, CalaBalanceRange, CalaTxId, ChartId,
+        CoreAccountingAction, CoreAccountingObject,
+    },
+};
+use error::*;
+
+struct BtcRevaluationAccountCodes {
+    revalued: AccountCode,
+    adjustment: AccountCode,
+    unrealized_gain: AccountCode,
+    unrealized_loss: AccountCode,
+}
+
+/// Outcome of revaluing a closed month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BtcRevaluationOutcome {
+    Revalued,
+    NotConfigured,
+    PriceMissing,
+}
+
+/// Marks BTC balances to market at period end, at the price recorded when the
+/// period ended. The revaluation is posted effective on the last day of the closed
+/// period and reversed effective on the first day of the next one, so every period
+/// is revalued from historical amounts.
+pub struct BtcRevaluations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    cala: CalaLedger,
+    journal_id: JournalId,
+    ledger: ManualTransactionLedger,
+    chart_of_accounts: ChartOfAccounts<Perms>,
+    domain_configs: ExposedDomainConfigsReadOnly,
+    price: Price,
+}
+
+impl<Perms> Clone for BtcRevaluations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cala: self.cala.clone(),
+            journal_id: self.journal_id,
+            ledger: self.ledger.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            domain_configs: self.domain_configs.clone(),
+            price: self.price.clone(),
+        }
+    }
+}
+
+impl<Perms> BtcRevaluations<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        chart_of_accounts: &ChartOfAccounts<Perms>,
+        domain_configs: &ExposedDomainConfigsReadOnly,
+        price: &Price,
+    ) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+            ledger: ManualTransactionLedger::new(cala),
+            chart_of_accounts: chart_of_accounts.clone(),
+            domain_configs: domain_configs.clone(),
+            price: price.clone(),
+        }
+    }
+
+    /// Latest price received, to be recorded as the price at the end of a period.
+    pub(crate) fn current_price(&self) -> Option<PriceOfOneBTC> {
+        self.price.maybe_usd_cents_per_btc()
+    }
+
+    /// Posts the revaluation as of `closed_as_of` at `price` together with its reversal.
+    /// Must run before the period is closed in the ledger. Does nothing when no chart node
+    /// is configured for revaluation or when no price was recorded for the period.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.btc_revaluation.revalue_in_op",
+        skip(self, op),
+        fields(unrealized = tracing::field::Empty)
+    )]
+    pub(crate) async fn revalue_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        chart_id: ChartId,
+        closed_as_of: NaiveDate,
+        price: Option<PriceOfOneBTC>,
+    ) -> Result<BtcRevaluationOutcome, BtcRevaluationError> {
+        let Some(codes) = self.account_codes().await? else {
+            return Ok(BtcRevaluationOutcome::NotConfigured);
+        };
+        let Some(price) = price else {
+            tracing::warn!(%closed_as_of, "no BTC price recorded at month end, skipping revaluation");
+            return Ok(BtcRevaluationOutcome::PriceMissing);
+        };
+
+        let mut chart = self
+            .chart_of_accounts
+            .find_by_id_in_op(op, chart_id)
+            .await?;
+        let account_set_id = |code: &AccountCode| {
+            chart
+                .maybe_account_set_id_from_code(code)
+                .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(code.clone()))
+        };
+        let revalued_id = account_set_id(&codes.revalued)?;
+        let adjustment_id = account_set_id(&codes.adjustment)?;
+
+        let unrealized = self
+            .unrealized_gain_or_loss(revalued_id, adjustment_id, closed_as_of, price)
+            .await?;
+        tracing::Span::current().record("unrealized", tracing::field::display(unrealized));
+        if unrealized.is_zero() {
+            return Ok(BtcRevaluationOutcome::Revalued);
+        }
+
+        let adjustment_account_id = self
+            .chart_of_accounts
+            .manual_transaction_account_id_for_code_in_op(op, &mut chart, &codes.adjustment)
+            .await?;
+        let (adjustment_direction, offset_code) = if unrealized.is_sign_positive() {
+            (DebitOrCredit::Debit, &codes.unrealized_gain)
+        } else {
+            (DebitOrCredit::Credit, &codes.unrealized_loss)
+        };
+        let offset_account_id = self
+            .chart_of_accounts
+            .manual_transaction_account_id_for_code_in_op(op, &mut chart, offset_code)
+            .await?;
+
+        let description = format!("BTC revaluation as of {closed_as_of} at {price} USD/BTC");
+        let entries = |adjustment_direction: DebitOrCredit| {
+            vec![
+                EntryParams {
+                    account_id: adjustment_account_id.into(),
+                    currency: Currency::USD,
+                    amount: unrealized.abs(),
+                    description: description.clone(),
+                    direction: adjustment_direction,
+                    layer: Layer::Settled,
+                },
+                EntryParams {
+                    account_id: offset_account_id.into(),
+                    currency: Currency::USD,
+                    amount: unrealized.abs(),
+                    description: description.clone(),
+                    direction: opposite(adjustment_direction),
+                    layer: Layer::Settled,
+                },
+            ]
+        };
+        let initiated_by =
+            <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(BTC_REVALUATION);
+
+        self.ledger
+            .execute_in_op(
+                op,
+                CalaTxId::new(),
+                ManualTransactionParams {
+                    journal_id: self.journal_id,
+                    description: description.clone(),
+                    entry_params: entries(adjustment_direction),
+                    effective: closed_as_of,
+                    initiated_by: &initiated_by,
+                },
+            )
+            .await?;
+        self.ledger
+            .execute_in_op(
+                op,
+                CalaTxId::new(),
+                ManualTransactionParams {
+                    journal_id: self.journal_id,
+                    description: format!("Reversal of {description}"),
+                    entry_params: entries(opposite(adjustment_direction)),
+                    effective: closed_as_of
+                        .succ_opt()
+                        .expect("closed_as_of was last possible NaiveDate"),
+                    initiated_by: &initiated_by,
+                },
+            )
+            .await?;
+
+        Ok(BtcRevaluationOutcome::Revalued)
+    }
+
+    async fn account_codes(
+        &self,
+    ) -> Result<Option<BtcRevaluationAccountCodes>, BtcRevaluationError> {
+        let Some(revalued) = self
+            .domain_configs
+            .get_without_audit::<BtcRevaluationAccountCode>()
+            .await?
+            .maybe_value()
+        else {
+            return Ok(None);
+        };
+        let adjustment = self
+            .domain_configs
+            .get_without_audit::<BtcRevaluationAdjustmentAccountCode>()
+            .await?
+            .maybe_value()
+            .ok_or(BtcRevaluationError::MissingAccountCode(
+                "btc-revaluation-adjustment-account-code",
+            ))?;
+        let unrealized_gain = self
+            .domain_configs
+            .get_without_audit::<BtcRevaluationUnrealizedGainAccountCode>()
+            .await?
+            .maybe_value()
+            .ok_or(BtcRevaluationError::MissingAccountCode(
+                "btc-revaluation-unrealized-gain-account-code",
+            ))?;
+        let unrealized_loss = self
+            .domain_configs
+            .get_without_audit::<BtcRevaluationUnrealizedLossAccountCode>()
+            .await?
+            .maybe_value()
+            .ok_or(BtcRevaluationError::MissingAccountCode(
+                "btc-revaluation-unrealized-loss-account-code",
+            ))?;
+
+        Ok(Some(BtcRevaluationAccountCodes {
+            revalued: revalued.parse()?,
+            adjustment: adjustment.parse()?,
+            unrealized_gain: unrealized_gain.parse()?,
+            unrealized_loss: unrealized_loss.parse()?,
+        }))
+    }
+
+    /// USD fair value of the revalued BTC balance less its USD carrying amount, as a
+    /// debit-positive amount. The carrying amount includes any USD recorded against the
+    /// revalued node itself and whatever remains on the adjustment node.
+    async fn unrealized_gain_or_loss(
+        &self,
+        revalued_id: CalaAccountSetId,
+        adjustment_id: CalaAccountSetId,
+        as_of: NaiveDate,
+        price: PriceOfOneBTC,
+    ) -> Result<Decimal, BtcRevaluationError> {
+        let balance_ids = [
+            (self.journal_id, revalued_id.into(), Currency::BTC),
+            (self.journal_id, revalued_id.into(), Currency::USD),
+            (self.journal_id, adjustment_id.into(), Currency::USD),
+        ];
+        let balances = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, as_of, Some(as_of))
+            .await?;
+        let [revalued_btc, revalued_usd, adjustment_usd] =
+            balance_ids.map(|id| debit_balance(&balances, &id));
+
+        let fair_value = (revalued_btc * price.into_inner().to_usd())
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+
+        Ok(fair_value - revalued_usd - adjustment_usd)
+    }
+}
+
+fn debit_balance(balances: &HashMap<BalanceId, CalaBalanceRange>, id: &BalanceId) -> Decimal {
+    balances
+        .get(id)
+        .map(|range| match range.close.balance_type {
+            DebitOrCredit::Debit => range.close.settled(),
+            DebitOrCredit::Credit => -range.close.settled(),
+        })
+        .unwrap_or(Decimal::ZERO)
+}
+
+fn opposite(direction: DebitOrCredit) -> DebitOrCredit {
+    match direction {
+        DebitOrCredit::Debit => DebitOrCredit::Credit,
+        DebitOrCredit::Credit => DebitOrCredit::Debit,
+    }
+}
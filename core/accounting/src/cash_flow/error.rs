@@ -0,0 +1,40 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::AccountCode;
+
+#[derive(Error, Debug)]
+pub enum CashFlowStatementError {
+    #[error("CashFlowStatementError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("CashFlowStatementError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("CashFlowStatementError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CashFlowStatementError - CashFlowStatementLedgerError: {0}")]
+    CashFlowStatementLedgerError(#[from] super::ledger::error::CashFlowStatementLedgerError),
+    #[error("CashFlowStatementError - AccountingBaseConfigNotFound")]
+    AccountingBaseConfigNotFound,
+    #[error("CashFlowStatementError - NotABalanceSheetAccount: {0}")]
+    NotABalanceSheetAccount(AccountCode),
+    #[error("CashFlowStatementError - NotAnAssetAccount: {0}")]
+    NotAnAssetAccount(AccountCode),
+    #[error("CashFlowStatementError - ChartNodeAlreadyMapped: {0}")]
+    ChartNodeAlreadyMapped(AccountCode),
+}
+
+impl ErrorSeverity for CashFlowStatementError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::AuditError(e) => e.severity(),
+            Self::AuthorizationError(e) => e.severity(),
+            Self::CashFlowStatementLedgerError(e) => e.severity(),
+            Self::AccountingBaseConfigNotFound => Level::ERROR,
+            Self::NotABalanceSheetAccount(_) => Level::WARN,
+            Self::NotAnAssetAccount(_) => Level::WARN,
+            Self::ChartNodeAlreadyMapped(_) => Level::WARN,
+        }
+    }
+}
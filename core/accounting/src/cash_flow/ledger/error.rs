@@ -0,0 +1,54 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::CalaAccountSetId;
+
+#[derive(Error, Debug)]
+pub enum CashFlowStatementLedgerError {
+    #[error("CashFlowStatementLedgerError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("CashFlowStatementLedgerError - CalaLedger: {0}")]
+    CalaLedger(#[from] cala_ledger::error::LedgerError),
+    #[error("CashFlowStatementLedgerError - CalaAccountSet: {0}")]
+    CalaAccountSet(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("CashFlowStatementLedgerError - CalaBalance: {0}")]
+    CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
+    #[error("CashFlowStatementLedgerError - NonAccountSetMemberTypeFound")]
+    NonAccountSetMemberTypeFound,
+    #[error("CashFlowStatementLedgerError - NotFound: {0}")]
+    NotFound(String),
+    #[error("CashFlowStatementLedgerError - ChartNodeNotMapped: {0}")]
+    ChartNodeNotMapped(CalaAccountSetId),
+}
+
+impl CashFlowStatementLedgerError {
+    pub fn account_set_exists(&self) -> bool {
+        matches!(
+            self,
+            Self::CalaAccountSet(
+                cala_ledger::account_set::error::AccountSetError::ExternalIdAlreadyExists(_),
+            )
+        )
+    }
+}
+
+impl ErrorSeverity for CashFlowStatementLedgerError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::CalaLedger(_) => Level::ERROR,
+            Self::CalaAccountSet(_) => {
+                if self.account_set_exists() {
+                    Level::INFO
+                } else {
+                    Level::ERROR
+                }
+            }
+            Self::CalaBalance(_) => Level::ERROR,
+            Self::NonAccountSetMemberTypeFound => Level::ERROR,
+            Self::NotFound(_) => Level::WARN,
+            Self::ChartNodeNotMapped(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,435 @@
+pub mod error;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::instrument;
+
+use cala_ledger::{
+    AccountSetId, BalanceId, CalaLedger, Currency, DebitOrCredit, JournalId,
+    account_set::{AccountSetMemberId, NewAccountSet},
+};
+
+use tracing_macros::record_error_severity;
+
+use crate::primitives::{BalanceRange, CalaBalanceRange, ResolvedAccountingBaseConfig};
+
+use super::{
+    CASH_NAME, CashFlowSection, CashFlowStatement, CashFlowStatementIds, CashFlowStatementLine,
+    FINANCING_ACTIVITIES_NAME, INVESTING_ACTIVITIES_NAME, NET_INCOME_NAME,
+    OPERATING_ACTIVITIES_NAME,
+};
+
+use error::*;
+
+#[derive(Clone)]
+pub struct CashFlowStatementLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl CashFlowStatementLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.create_account_set_in_op", skip(self, op, parents), fields(reference = %reference, external_id = ?external_id, parents_count = parents.len()))]
+    async fn create_account_set_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        reference: &str,
+        external_id: Option<&str>,
+        normal_balance_type: DebitOrCredit,
+        parents: Vec<AccountSetId>,
+    ) -> Result<AccountSetId, CashFlowStatementLedgerError> {
+        let id = AccountSetId::new();
+        let mut builder = NewAccountSet::builder();
+        builder
+            .id(id)
+            .journal_id(self.journal_id)
+            .name(reference)
+            .description(reference)
+            .normal_balance_type(normal_balance_type);
+        if let Some(external_id) = external_id {
+            builder.external_id(external_id);
+        }
+        let new_account_set = builder.build().expect("Could not build new account set");
+        self.cala
+            .account_sets()
+            .create_in_op(op, new_account_set)
+            .await?;
+
+        for parent_id in parents {
+            self.cala
+                .account_sets()
+                .add_member_in_op(op, parent_id, id)
+                .await?;
+        }
+
+        Ok(id)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.get_member_account_set_ids", skip_all)]
+    async fn get_member_account_set_ids(
+        &self,
+        id: impl Into<AccountSetId> + Copy,
+    ) -> Result<Vec<AccountSetId>, CashFlowStatementLedgerError> {
+        self.cala
+            .account_sets()
+            .list_members_by_created_at(id.into(), Default::default())
+            .await?
+            .entities
+            .into_iter()
+            .map(|m| match m.id {
+                AccountSetMemberId::AccountSet(id) => Ok(id),
+                _ => Err(CashFlowStatementLedgerError::NonAccountSetMemberTypeFound),
+            })
+            .collect()
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "cash_flow_ledger.get_member_account_set_ids_and_names",
+        skip_all
+    )]
+    async fn get_member_account_set_ids_and_names(
+        &self,
+        id: impl Into<AccountSetId> + Copy,
+    ) -> Result<HashMap<String, AccountSetId>, CashFlowStatementLedgerError> {
+        let member_ids = self.get_member_account_set_ids(id).await?;
+
+        let mut accounts: HashMap<String, AccountSetId> = HashMap::new();
+        for id in member_ids {
+            let account_set = self.cala.account_sets().find(id).await?.into_values();
+            accounts.insert(account_set.name, id);
+        }
+
+        Ok(accounts)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.get_line", skip(self, balances_by_id), fields(account_set_id = %account_set_id))]
+    async fn get_line(
+        &self,
+        account_set_id: AccountSetId,
+        balances_by_id: &mut HashMap<BalanceId, CalaBalanceRange>,
+    ) -> Result<CashFlowStatementLine, CashFlowStatementLedgerError> {
+        let values = self
+            .cala
+            .account_sets()
+            .find(account_set_id)
+            .await?
+            .into_values();
+
+        let usd_balance_range = balances_by_id
+            .remove(&(self.journal_id, account_set_id.into(), Currency::USD))
+            .map(balance_range);
+        let btc_balance_range = balances_by_id
+            .remove(&(self.journal_id, account_set_id.into(), Currency::BTC))
+            .map(balance_range);
+
+        Ok(CashFlowStatementLine {
+            id: values.id.into(),
+            name: values.name,
+            usd_balance_range,
+            btc_balance_range,
+        })
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.get_balances_by_id", skip(self, all_account_set_ids), fields(count = all_account_set_ids.len(), from = %from, until = %until))]
+    async fn get_balances_by_id(
+        &self,
+        all_account_set_ids: Vec<AccountSetId>,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<HashMap<BalanceId, CalaBalanceRange>, CashFlowStatementLedgerError> {
+        let balance_ids = all_account_set_ids
+            .iter()
+            .flat_map(|id| {
+                [
+                    (self.journal_id, (*id).into(), Currency::USD),
+                    (self.journal_id, (*id).into(), Currency::BTC),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let res = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, from, Some(until))
+            .await?;
+
+        Ok(res)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.create_in_op", skip(self, op), fields(reference = %reference))]
+    pub async fn create_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        reference: &str,
+    ) -> Result<CashFlowStatementIds, CashFlowStatementLedgerError> {
+        // The statement and its sections are credit-normal so that their settled
+        // balance reads as the cash generated: increases in assets reduce cash,
+        // increases in liabilities, equity and net income add to it.
+        let statement_id = self
+            .create_account_set_in_op(
+                op,
+                reference,
+                Some(reference),
+                DebitOrCredit::Credit,
+                vec![],
+            )
+            .await?;
+
+        let operating_activities_id = self
+            .create_account_set_in_op(
+                op,
+                OPERATING_ACTIVITIES_NAME,
+                None,
+                DebitOrCredit::Credit,
+                vec![statement_id],
+            )
+            .await?;
+        let net_income_id = self
+            .create_account_set_in_op(
+                op,
+                NET_INCOME_NAME,
+                None,
+                DebitOrCredit::Credit,
+                vec![operating_activities_id],
+            )
+            .await?;
+        let investing_activities_id = self
+            .create_account_set_in_op(
+                op,
+                INVESTING_ACTIVITIES_NAME,
+                None,
+                DebitOrCredit::Credit,
+                vec![statement_id],
+            )
+            .await?;
+        let financing_activities_id = self
+            .create_account_set_in_op(
+                op,
+                FINANCING_ACTIVITIES_NAME,
+                None,
+                DebitOrCredit::Credit,
+                vec![statement_id],
+            )
+            .await?;
+        // Cash is kept outside the statement so that its opening and closing
+        // balances can be reconciled against the net change in cash.
+        let cash_id = self
+            .create_account_set_in_op(
+                op,
+                CASH_NAME,
+                Some(&cash_external_id(reference)),
+                DebitOrCredit::Debit,
+                vec![],
+            )
+            .await?;
+
+        Ok(CashFlowStatementIds {
+            id: statement_id,
+            net_income: net_income_id,
+            operating_activities: operating_activities_id,
+            investing_activities: investing_activities_id,
+            financing_activities: financing_activities_id,
+            cash: cash_id,
+        })
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.get_ids_from_reference", skip(self), fields(reference = %reference))]
+    pub async fn get_ids_from_reference(
+        &self,
+        reference: String,
+    ) -> Result<CashFlowStatementIds, CashFlowStatementLedgerError> {
+        let cash_id = self
+            .cala
+            .account_sets()
+            .find_by_external_id(cash_external_id(&reference))
+            .await?
+            .id;
+        let statement_id = self
+            .cala
+            .account_sets()
+            .find_by_external_id(reference)
+            .await?
+            .id;
+
+        let statement_members = self
+            .get_member_account_set_ids_and_names(statement_id)
+            .await?;
+        let member = |members: &HashMap<String, AccountSetId>, name: &str| {
+            members
+                .get(name)
+                .copied()
+                .ok_or_else(|| CashFlowStatementLedgerError::NotFound(name.to_string()))
+        };
+        let operating_activities_id = member(&statement_members, OPERATING_ACTIVITIES_NAME)?;
+        let operating_members = self
+            .get_member_account_set_ids_and_names(operating_activities_id)
+            .await?;
+
+        Ok(CashFlowStatementIds {
+            id: statement_id,
+            net_income: member(&operating_members, NET_INCOME_NAME)?,
+            operating_activities: operating_activities_id,
+            investing_activities: member(&statement_members, INVESTING_ACTIVITIES_NAME)?,
+            financing_activities: member(&statement_members, FINANCING_ACTIVITIES_NAME)?,
+            cash: cash_id,
+        })
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "cash_flow_ledger.attach_net_income_account_sets_in_op",
+        skip(self, op, ids, resolved)
+    )]
+    pub(crate) async fn attach_net_income_account_sets_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        ids: &CashFlowStatementIds,
+        resolved: &ResolvedAccountingBaseConfig,
+    ) -> Result<(), CashFlowStatementLedgerError> {
+        let members = self.get_member_account_set_ids(ids.net_income).await?;
+
+        for child in [
+            resolved.revenue,
+            resolved.cost_of_revenue,
+            resolved.expenses,
+        ] {
+            if !members.contains(&child) {
+                self.cala
+                    .account_sets()
+                    .add_member_in_op(op, ids.net_income, child)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Chart nodes mapped to any section, including cash.
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.mapped_account_set_ids", skip_all)]
+    pub(crate) async fn mapped_account_set_ids(
+        &self,
+        ids: &CashFlowStatementIds,
+    ) -> Result<Vec<AccountSetId>, CashFlowStatementLedgerError> {
+        let mut mapped = Vec::new();
+        for section in CashFlowSection::ALL {
+            mapped.extend(
+                self.get_member_account_set_ids(section.account_set_id(ids))
+                    .await?
+                    .into_iter()
+                    .filter(|id| *id != ids.net_income),
+            );
+        }
+        Ok(mapped)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.add_to_section_in_op", skip(self, op, ids), fields(section = %section, account_set_id = %account_set_id))]
+    pub(crate) async fn add_to_section_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        ids: &CashFlowStatementIds,
+        section: CashFlowSection,
+        account_set_id: AccountSetId,
+    ) -> Result<(), CashFlowStatementLedgerError> {
+        self.cala
+            .account_sets()
+            .add_member_in_op(op, section.account_set_id(ids), account_set_id)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.remove_from_section_in_op", skip(self, op, ids), fields(section = %section, account_set_id = %account_set_id))]
+    pub(crate) async fn remove_from_section_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        ids: &CashFlowStatementIds,
+        section: CashFlowSection,
+        account_set_id: AccountSetId,
+    ) -> Result<(), CashFlowStatementLedgerError> {
+        let section_id = section.account_set_id(ids);
+        let members = self.get_member_account_set_ids(section_id).await?;
+        if !members.contains(&account_set_id) {
+            return Err(CashFlowStatementLedgerError::ChartNodeNotMapped(
+                account_set_id,
+            ));
+        }
+
+        self.cala
+            .account_sets()
+            .remove_member_in_op(op, section_id, account_set_id)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.get_cash_flow_statement", skip(self), fields(reference = %reference, from = %from, until = %until))]
+    pub async fn get_cash_flow_statement(
+        &self,
+        reference: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<CashFlowStatement, CashFlowStatementLedgerError> {
+        let ids = self.get_ids_from_reference(reference).await?;
+        let all_account_set_ids = vec![
+            ids.id,
+            ids.net_income,
+            ids.operating_activities,
+            ids.investing_activities,
+            ids.financing_activities,
+            ids.cash,
+        ];
+
+        let mut balances_by_id = self
+            .get_balances_by_id(all_account_set_ids, from, until)
+            .await?;
+
+        let statement = self.get_line(ids.id, &mut balances_by_id).await?;
+
+        Ok(CashFlowStatement {
+            id: statement.id,
+            name: statement.name,
+            usd_balance_range: statement.usd_balance_range,
+            btc_balance_range: statement.btc_balance_range,
+            net_income: self.get_line(ids.net_income, &mut balances_by_id).await?,
+            operating_activities: self
+                .get_line(ids.operating_activities, &mut balances_by_id)
+                .await?,
+            investing_activities: self
+                .get_line(ids.investing_activities, &mut balances_by_id)
+                .await?,
+            financing_activities: self
+                .get_line(ids.financing_activities, &mut balances_by_id)
+                .await?,
+            cash: self.get_line(ids.cash, &mut balances_by_id).await?,
+        })
+    }
+}
+
+fn cash_external_id(reference: &str) -> String {
+    format!("{reference}:cash")
+}
+
+fn balance_range(range: CalaBalanceRange) -> BalanceRange {
+    BalanceRange {
+        open: Some(range.open),
+        close: Some(range.close),
+        period_activity: Some(range.period),
+    }
+}
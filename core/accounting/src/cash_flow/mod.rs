@@ -0,0 +1,308 @@
+pub mod error;
+pub mod ledger;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::CalaLedger;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    LedgerAccountId,
+    chart_of_accounts::Chart,
+    primitives::{
+        AccountCategory, AccountCode, BalanceRange, CalaAccountSetId, CoreAccountingAction,
+        CoreAccountingObject,
+    },
+};
+use error::*;
+use ledger::*;
+
+pub(crate) const NET_INCOME_NAME: &str = "Net Income";
+pub(crate) const OPERATING_ACTIVITIES_NAME: &str = "Operating Activities";
+pub(crate) const INVESTING_ACTIVITIES_NAME: &str = "Investing Activities";
+pub(crate) const FINANCING_ACTIVITIES_NAME: &str = "Financing Activities";
+pub(crate) const CASH_NAME: &str = "Cash and Cash Equivalents";
+
+#[derive(Clone, Copy)]
+pub struct CashFlowStatementIds {
+    pub id: CalaAccountSetId,
+    pub net_income: CalaAccountSetId,
+    pub operating_activities: CalaAccountSetId,
+    pub investing_activities: CalaAccountSetId,
+    pub financing_activities: CalaAccountSetId,
+    pub cash: CalaAccountSetId,
+}
+
+/// Section of the statement a balance sheet chart node is reported under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum CashFlowSection {
+    Operating,
+    Investing,
+    Financing,
+    /// Cash and cash equivalents, whose change over the period the other sections explain.
+    Cash,
+}
+
+impl CashFlowSection {
+    pub const ALL: [CashFlowSection; 4] = [
+        CashFlowSection::Operating,
+        CashFlowSection::Investing,
+        CashFlowSection::Financing,
+        CashFlowSection::Cash,
+    ];
+
+    fn account_set_id(&self, ids: &CashFlowStatementIds) -> CalaAccountSetId {
+        match self {
+            CashFlowSection::Operating => ids.operating_activities,
+            CashFlowSection::Investing => ids.investing_activities,
+            CashFlowSection::Financing => ids.financing_activities,
+            CashFlowSection::Cash => ids.cash,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CashFlowStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pool: sqlx::PgPool,
+    authz: Perms,
+    cash_flow_ledger: CashFlowStatementLedger,
+}
+
+impl<Perms> CashFlowStatements<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        cala: &CalaLedger,
+        journal_id: cala_ledger::JournalId,
+    ) -> Self {
+        let cash_flow_ledger = CashFlowStatementLedger::new(cala, journal_id);
+
+        Self {
+            pool: pool.clone(),
+            cash_flow_ledger,
+            authz: authz.clone(),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.cash_flow.create_cash_flow_statement", skip(self, name), fields(cash_flow_statement_name = %name))]
+    pub async fn create_cash_flow_statement(
+        &self,
+        name: String,
+    ) -> Result<(), CashFlowStatementError> {
+        let mut op = es_entity::DbOp::init(&self.pool).await?;
+
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut op,
+                crate::primitives::ACCOUNTING_TRIAL_BALANCE,
+                CoreAccountingObject::all_cash_flow_statements(),
+                CoreAccountingAction::CASH_FLOW_STATEMENT_CREATE,
+            )
+            .await?;
+
+        match self.cash_flow_ledger.create_in_op(&mut op, &name).await {
+            Ok(_) => {
+                op.commit().await?;
+                Ok(())
+            }
+            Err(e) if e.account_set_exists() => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Reports the activity of a balance sheet chart node under `section`.
+    /// A node can only be mapped once: neither it nor any of its ancestors or
+    /// descendants may already be mapped to a section, cash included. Cash nodes
+    /// must be assets. The first mapping also links the chart's revenue, cost of
+    /// revenue and expenses nodes as the statement's net income.
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.cash_flow.add_chart_node_to_section", skip(self, chart), fields(chart_id = %chart.id))]
+    pub async fn add_chart_node_to_section(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        chart: &Chart,
+        code: AccountCode,
+        section: CashFlowSection,
+    ) -> Result<(), CashFlowStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow_statements(),
+                CoreAccountingAction::CASH_FLOW_STATEMENT_UPDATE,
+            )
+            .await?;
+
+        let resolved = chart
+            .resolve_accounting_base_config()
+            .ok_or(CashFlowStatementError::AccountingBaseConfigNotFound)?;
+        let account_set_id = if section == CashFlowSection::Cash {
+            chart
+                .find_account_set_id_in_category(&code, AccountCategory::Asset)
+                .ok_or_else(|| CashFlowStatementError::NotAnAssetAccount(code.clone()))?
+        } else {
+            balance_sheet_account_set_id(chart, &code)?
+        };
+        let ids = self
+            .cash_flow_ledger
+            .get_ids_from_reference(reference)
+            .await?;
+
+        let mapped = self.cash_flow_ledger.mapped_account_set_ids(&ids).await?;
+        let descendants = chart
+            .chart()
+            .find_node_by_code(&code)
+            .map(|node| node.descendants_with_set_info())
+            .unwrap_or_default();
+        if std::iter::once(account_set_id)
+            .chain(chart.ancestors::<CalaAccountSetId>(&code))
+            .chain(descendants.into_iter().map(|info| info.account_set_id))
+            .any(|id| mapped.contains(&id))
+        {
+            return Err(CashFlowStatementError::ChartNodeAlreadyMapped(code));
+        }
+
+        let mut op = es_entity::DbOp::init(&self.pool).await?;
+        self.cash_flow_ledger
+            .attach_net_income_account_sets_in_op(&mut op, &ids, &resolved)
+            .await?;
+        self.cash_flow_ledger
+            .add_to_section_in_op(&mut op, &ids, section, account_set_id)
+            .await?;
+        op.commit().await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.cash_flow.remove_chart_node_from_section", skip(self, chart), fields(chart_id = %chart.id))]
+    pub async fn remove_chart_node_from_section(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        chart: &Chart,
+        code: AccountCode,
+        section: CashFlowSection,
+    ) -> Result<(), CashFlowStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow_statements(),
+                CoreAccountingAction::CASH_FLOW_STATEMENT_UPDATE,
+            )
+            .await?;
+
+        let account_set_id = balance_sheet_account_set_id(chart, &code)?;
+        let ids = self
+            .cash_flow_ledger
+            .get_ids_from_reference(reference)
+            .await?;
+
+        let mut op = es_entity::DbOp::init(&self.pool).await?;
+        self.cash_flow_ledger
+            .remove_from_section_in_op(&mut op, &ids, section, account_set_id)
+            .await?;
+        op.commit().await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.cash_flow.cash_flow_statement", skip(self))]
+    pub async fn cash_flow_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<CashFlowStatement, CashFlowStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow_statements(),
+                CoreAccountingAction::CASH_FLOW_STATEMENT_READ,
+            )
+            .await?;
+
+        self.cash_flow_statement_without_audit(reference, from, until)
+            .await
+    }
+
+    pub(crate) async fn cash_flow_statement_without_audit(
+        &self,
+        reference: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<CashFlowStatement, CashFlowStatementError> {
+        Ok(self
+            .cash_flow_ledger
+            .get_cash_flow_statement(reference, from, until)
+            .await?)
+    }
+}
+
+fn balance_sheet_account_set_id(
+    chart: &Chart,
+    code: &AccountCode,
+) -> Result<CalaAccountSetId, CashFlowStatementError> {
+    [
+        AccountCategory::Asset,
+        AccountCategory::Liability,
+        AccountCategory::Equity,
+    ]
+    .into_iter()
+    .find_map(|category| chart.find_account_set_id_in_category(code, category))
+    .ok_or_else(|| CashFlowStatementError::NotABalanceSheetAccount(code.clone()))
+}
+
+/// Statement of cash flows prepared with the indirect method. Each activity
+/// line is a credit-normal account set, so the settled period activity of a
+/// line is the cash it generated (negative when it consumed cash) and the
+/// statement's own period activity is the net change in cash.
+#[derive(Clone)]
+pub struct CashFlowStatement {
+    pub id: LedgerAccountId,
+    pub name: String,
+    pub usd_balance_range: Option<BalanceRange>,
+    pub btc_balance_range: Option<BalanceRange>,
+    /// First line of the operating activities, which it is included in.
+    pub net_income: CashFlowStatementLine,
+    pub operating_activities: CashFlowStatementLine,
+    pub investing_activities: CashFlowStatementLine,
+    pub financing_activities: CashFlowStatementLine,
+    /// Debit-normal; its open and close balances are the opening and closing cash.
+    pub cash: CashFlowStatementLine,
+}
+
+impl CashFlowStatement {
+    pub fn activities(&self) -> [(CashFlowSection, &CashFlowStatementLine); 3] {
+        [
+            (CashFlowSection::Operating, &self.operating_activities),
+            (CashFlowSection::Investing, &self.investing_activities),
+            (CashFlowSection::Financing, &self.financing_activities),
+        ]
+    }
+}
+
+#[derive(Clone)]
+pub struct CashFlowStatementLine {
+    pub id: LedgerAccountId,
+    pub name: String,
+    pub usd_balance_range: Option<BalanceRange>,
+    pub btc_balance_range: Option<BalanceRange>,
+}
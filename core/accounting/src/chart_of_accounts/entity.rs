@@ -472,6 +472,14 @@ impl Chart {
         Ok(())
     }
 
+    /// The last day of the most recently closed period, if any period was closed.
+    pub fn closed_as_of(&self) -> Option<NaiveDate> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            ChartEvent::ClosedAsOf { closed_as_of } => Some(*closed_as_of),
+            _ => None,
+        })
+    }
+
     pub(super) fn close_as_of(&mut self, closed_as_of: NaiveDate) -> Idempotent<NaiveDate> {
         idempotency_guard!(
             self.events.iter_all().rev(),
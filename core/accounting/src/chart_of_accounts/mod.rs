@@ -469,27 +469,65 @@ where
             return Ok(id);
         }
 
+        let id = self
+            .create_manual_transaction_account_in_op(&mut op, &mut chart, &account_id_or_code)
+            .await?;
+        op.commit().await?;
+
+        Ok(id)
+    }
+
+    pub(crate) async fn find_by_id_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        chart_id: ChartId,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        Ok(self.repo.find_by_id_in_op(op, chart_id).await?)
+    }
+
+    /// Returns the manual transaction account of the leaf node `code`, creating it if needed.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.manual_transaction_account_id_for_code_in_op",
+        skip(self, op, chart),
+        fields(chart_id = %chart.id)
+    )]
+    pub(crate) async fn manual_transaction_account_id_for_code_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        chart: &mut Chart,
+        code: &AccountCode,
+    ) -> Result<LedgerAccountId, ChartOfAccountsError> {
+        let account_id_or_code = AccountIdOrCode::Code(code.clone());
+        if let Some(id) = chart.find_manual_transaction_account(&account_id_or_code) {
+            return Ok(id);
+        }
+
+        self.create_manual_transaction_account_in_op(op, chart, &account_id_or_code)
+            .await
+    }
+
+    async fn create_manual_transaction_account_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        chart: &mut Chart,
+        account_id_or_code: &AccountIdOrCode,
+    ) -> Result<LedgerAccountId, ChartOfAccountsError> {
         let (account_set_id, new_account) = chart
-            .create_manual_transaction_account(&account_id_or_code)?
+            .create_manual_transaction_account(account_id_or_code)?
             .expect("create should execute when find returned None");
-        self.repo.update_in_op(&mut op, &mut chart).await?;
+        self.repo.update_in_op(&mut *op, chart).await?;
 
         let Account {
             id: manual_transaction_account_id,
             ..
-        } = self
-            .cala
-            .accounts()
-            .create_in_op(&mut op, new_account)
-            .await?;
+        } = self.cala.accounts().create_in_op(op, new_account).await?;
 
         self.cala
             .account_sets()
-            .add_member_in_op(&mut op, account_set_id, manual_transaction_account_id)
+            .add_member_in_op(op, account_set_id, manual_transaction_account_id)
             .await?;
 
-        op.commit().await?;
-
         Ok(manual_transaction_account_id.into())
     }
 
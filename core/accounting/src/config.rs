@@ -0,0 +1,57 @@
+use domain_config::{DomainConfigError, define_exposed_config};
+
+use crate::primitives::AccountCode;
+
+define_exposed_config! {
+    /// Total debit amount in USD cents up to which a manual transaction is posted without
+    /// governance approval. Every other manual transaction, including any with entries in other
+    /// currencies, is posted only once approved. When unset, every manual transaction requires
+    /// approval.
+    pub struct ManualTransactionApprovalThresholdCents(u64);
+    spec {
+        key: "manual-transaction-approval-threshold-cents";
+    }
+}
+
+define_exposed_config! {
+    /// Chart node whose BTC balance is marked to market in USD when a month is closed. Every
+    /// BTC-denominated ledger account beneath it is included. When unset, no revaluation is posted.
+    pub struct BtcRevaluationAccountCode(String);
+    spec {
+        key: "btc-revaluation-account-code";
+        validate: validate_account_code;
+    }
+}
+
+define_exposed_config! {
+    /// Leaf chart node that carries the USD revaluation adjustment of the revalued BTC balance.
+    pub struct BtcRevaluationAdjustmentAccountCode(String);
+    spec {
+        key: "btc-revaluation-adjustment-account-code";
+        validate: validate_account_code;
+    }
+}
+
+define_exposed_config! {
+    /// Leaf chart node credited with unrealised gains from the BTC revaluation.
+    pub struct BtcRevaluationUnrealizedGainAccountCode(String);
+    spec {
+        key: "btc-revaluation-unrealized-gain-account-code";
+        validate: validate_account_code;
+    }
+}
+
+define_exposed_config! {
+    /// Leaf chart node debited with unrealised losses from the BTC revaluation.
+    pub struct BtcRevaluationUnrealizedLossAccountCode(String);
+    spec {
+        key: "btc-revaluation-unrealized-loss-account-code";
+        validate: validate_account_code;
+    }
+}
+
+fn validate_account_code(code: &str) -> Result<(), DomainConfigError> {
+    code.parse::<AccountCode>()
+        .map(|_| ())
+        .map_err(|e| DomainConfigError::InvalidState(e.to_string()))
+}
@@ -14,8 +14,12 @@ pub enum AccountingCsvExportError {
     JobError(#[from] job::error::JobError),
     #[error("AccountingCsvExportError - DocumentStorageError: {0}")]
     DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("AccountingCsvExportError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] crate::cash_flow::error::CashFlowStatementError),
     #[error("AccountingCsvExportError - CsvError: {0}")]
     CsvError(String),
+    #[error("AccountingCsvExportError - UnsupportedCsvType: {0}")]
+    UnsupportedCsvType(super::AccountingCsvType),
 }
 
 impl ErrorSeverity for AccountingCsvExportError {
@@ -26,7 +30,9 @@ impl ErrorSeverity for AccountingCsvExportError {
             Self::LedgerAccountError(e) => e.severity(),
             Self::JobError(_) => Level::ERROR,
             Self::DocumentStorageError(e) => e.severity(),
+            Self::CashFlowStatementError(e) => e.severity(),
             Self::CsvError(_) => Level::WARN,
+            Self::UnsupportedCsvType(_) => Level::ERROR,
         }
     }
 }
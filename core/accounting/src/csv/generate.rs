@@ -6,8 +6,10 @@ use authz::PermissionCheck;
 use cala_ledger::DebitOrCredit;
 
 use crate::{
-    CoreAccountingAction, CoreAccountingObject, ledger_account::LedgerAccounts,
-    primitives::LedgerAccountId,
+    CoreAccountingAction, CoreAccountingObject,
+    cash_flow::{CashFlowSection, CashFlowStatementLine, CashFlowStatements},
+    ledger_account::LedgerAccounts,
+    primitives::{BalanceRange, CalaAccountBalance, LedgerAccountId},
 };
 
 use super::error::AccountingCsvExportError;
@@ -19,6 +21,7 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
     ledger_accounts: LedgerAccounts<Perms>,
+    cash_flow_statements: CashFlowStatements<Perms>,
 }
 
 impl<Perms> GenerateCsvExport<Perms>
@@ -27,9 +30,13 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
-    pub fn new(ledger_accounts: &LedgerAccounts<Perms>) -> Self {
+    pub fn new(
+        ledger_accounts: &LedgerAccounts<Perms>,
+        cash_flow_statements: &CashFlowStatements<Perms>,
+    ) -> Self {
         Self {
             ledger_accounts: ledger_accounts.clone(),
+            cash_flow_statements: cash_flow_statements.clone(),
         }
     }
 
@@ -79,4 +86,101 @@ where
 
         Ok(csv_data)
     }
+
+    pub async fn generate_cash_flow_csv(
+        &self,
+        reference: String,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<Vec<u8>, AccountingCsvExportError> {
+        let statement = self
+            .cash_flow_statements
+            .cash_flow_statement_without_audit(reference, from, until)
+            .await?;
+
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record(["Section", "Line", "USD", "BTC"])
+            .map_err(|e| AccountingCsvExportError::CsvError(e.to_string()))?;
+
+        let mut write_line = |section: &str, line: &str, usd: Decimal, btc: Decimal| {
+            wtr.write_record([section, line, &usd.to_string(), &btc.to_string()])
+                .map_err(|e| AccountingCsvExportError::CsvError(e.to_string()))
+        };
+
+        for (section, line) in statement.activities() {
+            let (usd, btc) = period_cash_flow(line);
+            if section == CashFlowSection::Operating {
+                let (net_income_usd, net_income_btc) = period_cash_flow(&statement.net_income);
+                write_line(
+                    &line.name,
+                    &statement.net_income.name,
+                    net_income_usd,
+                    net_income_btc,
+                )?;
+                write_line(
+                    &line.name,
+                    "Changes in operating assets and liabilities",
+                    usd - net_income_usd,
+                    btc - net_income_btc,
+                )?;
+            }
+            write_line(
+                &line.name,
+                &format!("Net cash from {}", line.name.to_lowercase()),
+                usd,
+                btc,
+            )?;
+        }
+
+        let cash = &statement.cash;
+        write_line(
+            &cash.name,
+            "Opening cash",
+            settled(cash.usd_balance_range.as_ref(), |r| r.open.as_ref()),
+            settled(cash.btc_balance_range.as_ref(), |r| r.open.as_ref()),
+        )?;
+        write_line(
+            &cash.name,
+            "Net change in cash",
+            settled(statement.usd_balance_range.as_ref(), |r| {
+                r.period_activity.as_ref()
+            }),
+            settled(statement.btc_balance_range.as_ref(), |r| {
+                r.period_activity.as_ref()
+            }),
+        )?;
+        write_line(
+            &cash.name,
+            "Closing cash",
+            settled(cash.usd_balance_range.as_ref(), |r| r.close.as_ref()),
+            settled(cash.btc_balance_range.as_ref(), |r| r.close.as_ref()),
+        )?;
+
+        let csv_data = wtr
+            .into_inner()
+            .map_err(|e| AccountingCsvExportError::CsvError(e.to_string()))?;
+
+        Ok(csv_data)
+    }
+}
+
+fn period_cash_flow(line: &CashFlowStatementLine) -> (Decimal, Decimal) {
+    (
+        settled(line.usd_balance_range.as_ref(), |r| {
+            r.period_activity.as_ref()
+        }),
+        settled(line.btc_balance_range.as_ref(), |r| {
+            r.period_activity.as_ref()
+        }),
+    )
+}
+
+fn settled<'a>(
+    range: Option<&'a BalanceRange>,
+    balance: impl Fn(&'a BalanceRange) -> Option<&'a CalaAccountBalance>,
+) -> Decimal {
+    range
+        .and_then(balance)
+        .map(|balance| balance.settled())
+        .unwrap_or(Decimal::ZERO)
 }
@@ -8,25 +8,47 @@ use job::*;
 use obix::out::{Outbox, OutboxEventMarker};
 use serde::{Deserialize, Serialize};
 
+use crate::cash_flow::CashFlowStatements;
 use crate::event::CoreAccountingEvent;
 use crate::primitives::AccountingCsvId;
 use crate::{ledger_account::LedgerAccounts, primitives::LedgerAccountId};
 
 use super::publisher::AccountingCsvPublisher;
-use super::{CoreAccountingAction, CoreAccountingObject, generate::GenerateCsvExport};
+use super::{
+    AccountingCsvType, CoreAccountingAction, CoreAccountingObject, error::AccountingCsvExportError,
+    generate::GenerateCsvExport,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StatementCsvPeriod {
+    pub reference: String,
+    pub from: chrono::NaiveDate,
+    pub until: chrono::NaiveDate,
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct GenerateAccountingCsvConfig<Perms, E> {
     pub document_id: DocumentId,
+    /// The ledger account, or for statements the statement's root account set.
     pub ledger_account_id: LedgerAccountId,
+    #[serde(default = "default_csv_type")]
+    pub csv_type: AccountingCsvType,
+    #[serde(default)]
+    pub statement_period: Option<StatementCsvPeriod>,
     pub _phantom: std::marker::PhantomData<(Perms, E)>,
 }
 
+fn default_csv_type() -> AccountingCsvType {
+    AccountingCsvType::LedgerAccount
+}
+
 impl<Perms, E> Clone for GenerateAccountingCsvConfig<Perms, E> {
     fn clone(&self) -> Self {
         Self {
             document_id: self.document_id,
             ledger_account_id: self.ledger_account_id,
+            csv_type: self.csv_type,
+            statement_period: self.statement_period.clone(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -39,6 +61,7 @@ where
 {
     document_storage: DocumentStorage,
     ledger_accounts: LedgerAccounts<Perms>,
+    cash_flow_statements: CashFlowStatements<Perms>,
     publisher: AccountingCsvPublisher<E>,
 }
 
@@ -50,11 +73,13 @@ where
     pub fn new(
         document_storage: &DocumentStorage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        cash_flow_statements: &CashFlowStatements<Perms>,
         outbox: &Outbox<E>,
     ) -> Self {
         Self {
             document_storage: document_storage.clone(),
             ledger_accounts: ledger_accounts.clone(),
+            cash_flow_statements: cash_flow_statements.clone(),
             publisher: AccountingCsvPublisher::new(outbox),
         }
     }
@@ -82,7 +107,7 @@ where
         Ok(Box::new(GenerateAccountingCsvExportJobRunner {
             config: job.config()?,
             document_storage: self.document_storage.clone(),
-            generator: GenerateCsvExport::new(&self.ledger_accounts),
+            generator: GenerateCsvExport::new(&self.ledger_accounts, &self.cash_flow_statements),
             publisher: self.publisher.clone(),
         }))
     }
@@ -113,10 +138,21 @@ where
         &self,
         current_job: CurrentJob,
     ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
-        let csv_result = self
-            .generator
-            .generate_ledger_account_csv(self.config.ledger_account_id)
-            .await?;
+        let csv_result = match (self.config.csv_type, &self.config.statement_period) {
+            (AccountingCsvType::LedgerAccount, _) => {
+                self.generator
+                    .generate_ledger_account_csv(self.config.ledger_account_id)
+                    .await?
+            }
+            (AccountingCsvType::CashFlow, Some(period)) => {
+                self.generator
+                    .generate_cash_flow_csv(period.reference.clone(), period.from, period.until)
+                    .await?
+            }
+            (csv_type, _) => {
+                return Err(AccountingCsvExportError::UnsupportedCsvType(csv_type).into());
+            }
+        };
 
         let document_id = self.config.document_id;
 
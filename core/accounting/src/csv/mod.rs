@@ -19,18 +19,20 @@ use tracing_macros::record_error_severity;
 use crate::{Jobs, event::CoreAccountingEvent};
 
 use super::{
-    CoreAccountingAction, CoreAccountingObject, ledger_account::LedgerAccounts,
-    primitives::LedgerAccountId,
+    CoreAccountingAction, CoreAccountingObject, cash_flow::CashFlowStatements,
+    ledger_account::LedgerAccounts, primitives::LedgerAccountId,
 };
 
 use self::job::{
     GenerateAccountingCsvConfig, GenerateAccountingCsvInit, GenerateAccountingCsvJobSpawner,
+    StatementCsvPeriod,
 };
 use error::*;
 use es_entity::PaginatedQueryArgs;
 pub use primitives::*;
 
 pub const LEDGER_ACCOUNT_CSV: DocumentType = DocumentType::new("ledger_account_csv");
+pub const CASH_FLOW_CSV: DocumentType = DocumentType::new("cash_flow_csv");
 
 pub struct AccountingCsvExports<Perms, E>
 where
@@ -40,6 +42,7 @@ where
     authz: Perms,
     generate_accounting_csv_job_spawner: GenerateAccountingCsvJobSpawner<Perms, E>,
     document_storage: DocumentStorage,
+    cash_flow_statements: CashFlowStatements<Perms>,
 }
 
 impl<Perms, E> Clone for AccountingCsvExports<Perms, E>
@@ -52,6 +55,7 @@ where
             authz: self.authz.clone(),
             generate_accounting_csv_job_spawner: self.generate_accounting_csv_job_spawner.clone(),
             document_storage: self.document_storage.clone(),
+            cash_flow_statements: self.cash_flow_statements.clone(),
         }
     }
 }
@@ -68,16 +72,22 @@ where
         jobs: &mut Jobs,
         document_storage: DocumentStorage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        cash_flow_statements: &CashFlowStatements<Perms>,
         outbox: &Outbox<E>,
     ) -> Self {
-        let generate_accounting_csv_job_spawner = jobs.add_initializer(
-            GenerateAccountingCsvInit::new(&document_storage, ledger_accounts, outbox),
-        );
+        let generate_accounting_csv_job_spawner =
+            jobs.add_initializer(GenerateAccountingCsvInit::new(
+                &document_storage,
+                ledger_accounts,
+                cash_flow_statements,
+                outbox,
+            ));
 
         Self {
             authz: authz.clone(),
             generate_accounting_csv_job_spawner,
             document_storage,
+            cash_flow_statements: cash_flow_statements.clone(),
         }
     }
 
@@ -117,6 +127,63 @@ where
                 GenerateAccountingCsvConfig {
                     document_id: document.id,
                     ledger_account_id,
+                    csv_type: AccountingCsvType::LedgerAccount,
+                    statement_period: None,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        db.commit().await?;
+        Ok(document)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.csv.create_cash_flow", skip(self))]
+    pub async fn create_cash_flow_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<Document, AccountingCsvExportError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_csvs(),
+                CoreAccountingAction::ACCOUNTING_CSV_CREATE,
+            )
+            .await?;
+
+        let statement = self
+            .cash_flow_statements
+            .cash_flow_statement(sub, reference.clone(), from, until)
+            .await?;
+
+        let mut db = self.document_storage.begin_op().await?;
+        let document = self
+            .document_storage
+            .create_in_op(
+                &mut db,
+                format!("cash-flow-{from}-{until}.csv"),
+                "text/csv",
+                ReferenceId::from(uuid::Uuid::from(statement.id)),
+                CASH_FLOW_CSV,
+            )
+            .await?;
+
+        self.generate_accounting_csv_job_spawner
+            .spawn_in_op(
+                &mut db,
+                JobId::from(uuid::Uuid::from(document.id)),
+                GenerateAccountingCsvConfig {
+                    document_id: document.id,
+                    ledger_account_id: statement.id,
+                    csv_type: AccountingCsvType::CashFlow,
+                    statement_period: Some(StatementCsvPeriod {
+                        reference,
+                        from,
+                        until,
+                    }),
                     _phantom: std::marker::PhantomData,
                 },
             )
@@ -14,6 +14,7 @@ pub enum AccountingCsvType {
     LedgerAccount,
     ProfitAndLoss,
     BalanceSheet,
+    CashFlow,
 }
 
 #[derive(Debug, Clone)]
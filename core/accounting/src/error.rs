@@ -10,6 +10,10 @@ pub enum CoreAccountingError {
     LedgerAccountError(#[from] super::ledger_account::error::LedgerAccountError),
     #[error("CoreAccountingError - ManualTransaction: {0}")]
     ManualTransactionError(#[from] super::manual_transaction::error::ManualTransactionError),
+    #[error("CoreAccountingError - ManualTransactionSchedule: {0}")]
+    ManualTransactionScheduleError(
+        #[from] super::manual_transaction_schedule::error::ManualTransactionScheduleError,
+    ),
     #[error("CoreAccountingError - LedgerTransaction: {0}")]
     LedgerTransactionError(#[from] super::ledger_transaction::error::LedgerTransactionError),
     #[error("CoreAccountingError - TransactionTemplate: {0}")]
@@ -24,10 +28,16 @@ pub enum CoreAccountingError {
     FiscalYearError(#[from] super::fiscal_year::error::FiscalYearError),
     #[error("CoreAccountingError - AuthorizationError: {0}")]
     AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CoreAccountingError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("CoreAccountingError - RegisterEventHandler: {0}")]
+    RegisterEventHandler(#[from] Box<dyn std::error::Error + Send + Sync>),
     #[error("CoreAccountingError - Sqlx: {0}")]
     Sqlx(#[from] sqlx::Error),
     #[error("CoreAccountingError - BalanceSheetLedgerError: {0}")]
     BalanceSheetLedgerError(#[from] super::balance_sheet::ledger::error::BalanceSheetLedgerError),
+    #[error("CoreAccountingError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] super::cash_flow::error::CashFlowStatementError),
     #[error("CoreAccountingError - ProfitAndLossLedgerError: {0}")]
     ProfitAndLossLedgerError(
         #[from] super::profit_and_loss::ledger::error::ProfitAndLossStatementLedgerError,
@@ -41,6 +51,7 @@ impl ErrorSeverity for CoreAccountingError {
             Self::ChartOfAccountsError(e) => e.severity(),
             Self::LedgerAccountError(e) => e.severity(),
             Self::ManualTransactionError(e) => e.severity(),
+            Self::ManualTransactionScheduleError(e) => e.severity(),
             Self::LedgerTransactionError(e) => e.severity(),
             Self::TransactionTemplateError(e) => e.severity(),
             Self::AccountingCsvExportError(e) => e.severity(),
@@ -48,8 +59,11 @@ impl ErrorSeverity for CoreAccountingError {
             Self::TrialBalance(e) => e.severity(),
             Self::FiscalYearError(e) => e.severity(),
             Self::AuthorizationError(e) => e.severity(),
+            Self::JobError(_) => Level::ERROR,
+            Self::RegisterEventHandler(_) => Level::ERROR,
             Self::Sqlx(_) => Level::ERROR,
             Self::BalanceSheetLedgerError(e) => e.severity(),
+            Self::CashFlowStatementError(e) => e.severity(),
             Self::ProfitAndLossLedgerError(e) => e.severity(),
         }
     }
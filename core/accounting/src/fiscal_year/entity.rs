@@ -9,6 +9,8 @@ use tracing::instrument;
 use super::error::*;
 use tracing_macros::record_error_severity;
 
+use core_price::PriceOfOneBTC;
+
 use crate::primitives::{CalaTxId, ChartId, ClosingTxDetails, FiscalYearId};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +29,14 @@ pub enum FiscalYearEvent {
         month_closed_as_of: NaiveDate,
         month_closed_at: DateTime<Utc>,
     },
+    MonthEndBtcPriceRecorded {
+        month_end: NaiveDate,
+        price: PriceOfOneBTC,
+        closing_time: DateTime<Utc>,
+    },
+    BtcRevaluationSkipped {
+        month_closed_as_of: NaiveDate,
+    },
     YearClosed {
         ledger_tx_id: CalaTxId,
         closed_as_of: NaiveDate,
@@ -50,6 +60,8 @@ pub struct FiscalYear {
 pub struct FiscalMonthClosure {
     pub closed_as_of: NaiveDate,
     pub closed_at: DateTime<Utc>,
+    /// Set when BTC balances were not revalued because no price was recorded at month end.
+    pub btc_revaluation_skipped: bool,
 }
 
 impl FiscalYear {
@@ -127,12 +139,60 @@ impl FiscalYear {
                 } => Some(FiscalMonthClosure {
                     closed_as_of: *month_closed_as_of,
                     closed_at: *month_closed_at,
+                    btc_revaluation_skipped: self.events.iter_all().any(|event| {
+                        matches!(
+                            event,
+                            FiscalYearEvent::BtcRevaluationSkipped { month_closed_as_of: skipped }
+                                if skipped == month_closed_as_of
+                        )
+                    }),
                 }),
                 _ => None,
             })
             .collect()
     }
 
+    /// Records the BTC price at the close of `month_end`, which the month is revalued at
+    /// when it is closed. Does nothing for months outside this year or already closed.
+    pub(super) fn record_month_end_btc_price(
+        &mut self,
+        month_end: NaiveDate,
+        price: PriceOfOneBTC,
+        closing_time: DateTime<Utc>,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            already_applied: FiscalYearEvent::MonthEndBtcPriceRecorded { month_end: existing, .. } if existing == &month_end
+        );
+        match self.next_month_to_close() {
+            Some(next) if next <= month_end && month_end <= self.closes_as_of() => {}
+            _ => return Idempotent::AlreadyApplied,
+        }
+
+        self.events.push(FiscalYearEvent::MonthEndBtcPriceRecorded {
+            month_end,
+            price,
+            closing_time,
+        });
+        Idempotent::Executed(())
+    }
+
+    pub fn month_end_btc_price(&self, month_end: NaiveDate) -> Option<PriceOfOneBTC> {
+        self.events.iter_all().find_map(|event| match event {
+            FiscalYearEvent::MonthEndBtcPriceRecorded {
+                month_end: recorded,
+                price,
+                ..
+            } if *recorded == month_end => Some(*price),
+            _ => None,
+        })
+    }
+
+    pub(super) fn record_btc_revaluation_skipped(&mut self, month_closed_as_of: NaiveDate) {
+        self.events
+            .push(FiscalYearEvent::BtcRevaluationSkipped { month_closed_as_of });
+    }
+
     pub fn is_last_month_of_year_closed(&self) -> bool {
         let last_month_closes_as_of = self.closes_as_of();
         self.events
@@ -224,6 +284,8 @@ impl TryFromEvents<FiscalYearEvent> for FiscalYear {
                         .opened_as_of(*opened_as_of)
                 }
                 FiscalYearEvent::MonthClosed { .. } => {}
+                FiscalYearEvent::MonthEndBtcPriceRecorded { .. } => {}
+                FiscalYearEvent::BtcRevaluationSkipped { .. } => {}
                 FiscalYearEvent::YearClosed { closed_as_of, .. } => {
                     builder = builder.closed_as_of(Some(*closed_as_of));
                 }
@@ -429,4 +491,73 @@ mod test {
         let result = fiscal_year.next(Utc::now());
         assert!(result.is_some());
     }
+
+    fn test_price() -> PriceOfOneBTC {
+        PriceOfOneBTC::new(money::UsdCents::from(10_000_000))
+    }
+
+    #[test]
+    fn month_end_btc_price_is_recorded_once_for_open_months() {
+        let period_start = "2024-01-01".parse::<NaiveDate>().unwrap();
+        let january_end = "2024-01-31".parse::<NaiveDate>().unwrap();
+        let mut fiscal_year = fiscal_year_from(initial_events_with_opened_date(period_start));
+
+        assert!(
+            fiscal_year
+                .record_month_end_btc_price(january_end, test_price(), Utc::now())
+                .did_execute()
+        );
+        assert!(
+            fiscal_year
+                .record_month_end_btc_price(january_end, test_price(), Utc::now())
+                .was_already_applied()
+        );
+        assert_eq!(
+            fiscal_year.month_end_btc_price(january_end),
+            Some(test_price())
+        );
+
+        let next_year_end = "2025-01-31".parse::<NaiveDate>().unwrap();
+        assert!(
+            fiscal_year
+                .record_month_end_btc_price(next_year_end, test_price(), Utc::now())
+                .was_already_applied()
+        );
+        assert_eq!(fiscal_year.month_end_btc_price(next_year_end), None);
+    }
+
+    #[test]
+    fn month_end_btc_price_is_not_recorded_for_closed_months() {
+        let period_start = "2024-01-01".parse::<NaiveDate>().unwrap();
+        let january_end = "2024-01-31".parse::<NaiveDate>().unwrap();
+        let mut fiscal_year = fiscal_year_from(initial_events_with_opened_date(period_start));
+
+        let _ = fiscal_year.close_next_sequential_month(Utc::now()).unwrap();
+        assert!(
+            fiscal_year
+                .record_month_end_btc_price(january_end, test_price(), Utc::now())
+                .was_already_applied()
+        );
+        assert_eq!(fiscal_year.month_end_btc_price(january_end), None);
+    }
+
+    #[test]
+    fn month_closure_reports_skipped_btc_revaluation() {
+        let period_start = "2024-01-01".parse::<NaiveDate>().unwrap();
+        let mut fiscal_year = fiscal_year_from(initial_events_with_opened_date(period_start));
+
+        let january_end = fiscal_year
+            .close_next_sequential_month(Utc::now())
+            .unwrap()
+            .unwrap();
+        fiscal_year.record_btc_revaluation_skipped(january_end);
+        let _ = fiscal_year.close_next_sequential_month(Utc::now()).unwrap();
+
+        let skipped = fiscal_year
+            .month_closures()
+            .into_iter()
+            .map(|closure| closure.btc_revaluation_skipped)
+            .collect::<Vec<_>>();
+        assert_eq!(skipped, vec![true, false]);
+    }
 }
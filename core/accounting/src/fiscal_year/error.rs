@@ -26,6 +26,8 @@ pub enum FiscalYearError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("FiscalYearError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("FiscalYearError - BtcRevaluationError: {0}")]
+    BtcRevaluationError(Box<crate::btc_revaluation::error::BtcRevaluationError>),
     #[error("FiscalYearError - LastMonthNotClosed")]
     LastMonthNotClosed,
     #[error("FiscalYearError - MonthHasNotEnded")]
@@ -53,6 +55,7 @@ impl ErrorSeverity for FiscalYearError {
             Self::Query(_) => Level::ERROR,
             Self::AuthorizationError(e) => e.severity(),
             Self::ChartOfAccountsError(e) => e.severity(),
+            Self::BtcRevaluationError(e) => e.severity(),
             Self::LastMonthNotClosed => Level::WARN,
             Self::AllMonthsAlreadyClosed => Level::ERROR,
             Self::AlreadyOpened => Level::ERROR,
@@ -83,3 +86,9 @@ impl From<FiscalYearCreateError> for FiscalYearError {
         Self::Create(error)
     }
 }
+
+impl From<crate::btc_revaluation::error::BtcRevaluationError> for FiscalYearError {
+    fn from(error: crate::btc_revaluation::error::BtcRevaluationError) -> Self {
+        Self::BtcRevaluationError(Box::new(error))
+    }
+}
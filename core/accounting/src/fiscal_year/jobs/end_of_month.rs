@@ -0,0 +1,68 @@
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_time_events::CoreTimeEvent;
+use job::JobType;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use crate::{
+    fiscal_year::FiscalYears,
+    primitives::{CoreAccountingAction, CoreAccountingObject},
+};
+
+pub const FISCAL_YEAR_END_OF_MONTH: JobType = JobType::new("outbox.fiscal-year-end-of-month");
+
+pub struct FiscalYearEndOfMonthHandler<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fiscal_years: FiscalYears<Perms>,
+}
+
+impl<Perms> FiscalYearEndOfMonthHandler<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(fiscal_years: &FiscalYears<Perms>) -> Self {
+        Self {
+            fiscal_years: fiscal_years.clone(),
+        }
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for FiscalYearEndOfMonthHandler<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    E: OutboxEventMarker<CoreTimeEvent>,
+{
+    #[instrument(name = "core_accounting.fiscal_year.end_of_month.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(
+            e @ CoreTimeEvent::EndOfMonth {
+                month_end,
+                closing_time,
+                ..
+            },
+        ) = event.as_event()
+        else {
+            return Ok(());
+        };
+
+        event.inject_trace_parent();
+        Span::current().record("handled", true);
+        Span::current().record("event_type", e.as_ref());
+
+        self.fiscal_years
+            .record_month_end_btc_price_in_op(op, *month_end, *closing_time)
+            .await?;
+
+        Ok(())
+    }
+}
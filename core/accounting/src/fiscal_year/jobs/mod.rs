@@ -0,0 +1 @@
+pub mod end_of_month;
@@ -1,8 +1,9 @@
 mod entity;
 pub mod error;
+pub mod jobs;
 mod repo;
 
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use tracing::instrument;
 
 use audit::AuditSvc;
@@ -12,6 +13,7 @@ use tracing_macros::record_error_severity;
 
 use crate::{
     ClockHandle, FiscalYearId,
+    btc_revaluation::{BtcRevaluationOutcome, BtcRevaluations},
     chart_of_accounts::ChartOfAccounts,
     primitives::{ChartId, CoreAccountingAction, CoreAccountingObject},
 };
@@ -22,6 +24,11 @@ pub(super) use entity::*;
 pub use entity::{FiscalMonthClosure, FiscalYear};
 use error::*;
 pub use repo::{fiscal_year_cursor::*, *};
+
+/// How long after a month's closing time the current BTC price is still taken as the
+/// price at month end.
+const MONTH_END_PRICE_TOLERANCE: chrono::Duration = chrono::Duration::hours(1);
+
 pub struct FiscalYears<Perms>
 where
     Perms: PermissionCheck,
@@ -30,6 +37,7 @@ where
     repo: FiscalYearRepo,
     authz: Perms,
     chart_of_accounts: ChartOfAccounts<Perms>,
+    btc_revaluations: Option<BtcRevaluations<Perms>>,
 }
 
 impl<Perms> Clone for FiscalYears<Perms>
@@ -42,6 +50,7 @@ where
             repo: self.repo.clone(),
             authz: self.authz.clone(),
             chart_of_accounts: self.chart_of_accounts.clone(),
+            btc_revaluations: self.btc_revaluations.clone(),
         }
     }
 }
@@ -63,9 +72,16 @@ where
             repo: FiscalYearRepo::new(pool, clock),
             authz: authz.clone(),
             chart_of_accounts: chart_of_accounts.clone(),
+            btc_revaluations: None,
         }
     }
 
+    /// Revalues BTC balances as part of closing each month.
+    pub fn with_btc_revaluations(mut self, btc_revaluations: &BtcRevaluations<Perms>) -> Self {
+        self.btc_revaluations = Some(btc_revaluations.clone());
+        self
+    }
+
     #[record_error_severity]
     #[instrument(
         name = "core_accounting.fiscal_year.init_for_chart"
@@ -201,6 +217,19 @@ where
         let mut op = self.repo.begin_op().await?;
         let mut fiscal_year = self.repo.find_by_id_in_op(&mut op, id).await?;
         if let Idempotent::Executed(date) = fiscal_year.close_next_sequential_month(now)? {
+            if let Some(btc_revaluations) = &self.btc_revaluations {
+                let outcome = btc_revaluations
+                    .revalue_in_op(
+                        &mut op,
+                        fiscal_year.chart_id,
+                        date,
+                        fiscal_year.month_end_btc_price(date),
+                    )
+                    .await?;
+                if outcome == BtcRevaluationOutcome::PriceMissing {
+                    fiscal_year.record_btc_revaluation_skipped(date);
+                }
+            }
             self.repo.update_in_op(&mut op, &mut fiscal_year).await?;
             self.chart_of_accounts
                 .close_as_of_in_op(&mut op, sub, fiscal_year.chart_id, date)
@@ -210,6 +239,79 @@ where
         Ok(fiscal_year)
     }
 
+    /// Records the current BTC price as the price at `closing_time`, the end of
+    /// `month_end`, on every open fiscal year the month belongs to. Closing the month
+    /// revalues BTC balances at this price. Nothing is recorded when BTC revaluation is
+    /// not wired, no price is available or `closing_time` is too far in the past for the
+    /// current price to stand for it.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.fiscal_year.record_month_end_btc_price",
+        skip(self)
+    )]
+    pub async fn record_month_end_btc_price(
+        &self,
+        month_end: NaiveDate,
+        closing_time: DateTime<Utc>,
+    ) -> Result<(), FiscalYearError> {
+        let mut op = self.repo.begin_op().await?;
+        self.record_month_end_btc_price_in_op(&mut op, month_end, closing_time)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.fiscal_year.record_month_end_btc_price_in_op",
+        skip(self, op)
+    )]
+    pub(crate) async fn record_month_end_btc_price_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        month_end: NaiveDate,
+        closing_time: DateTime<Utc>,
+    ) -> Result<(), FiscalYearError> {
+        let Some(btc_revaluations) = &self.btc_revaluations else {
+            return Ok(());
+        };
+        if self.clock.now() - closing_time > MONTH_END_PRICE_TOLERANCE {
+            tracing::warn!(%month_end, %closing_time, "month end is too far in the past to record the BTC price");
+            return Ok(());
+        }
+        let Some(price) = btc_revaluations.current_price() else {
+            tracing::warn!(%month_end, "no BTC price available at month end");
+            return Ok(());
+        };
+
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_by_opened_as_of_in_op(&mut *op, query, es_entity::ListDirection::Descending)
+                .await?;
+            for fiscal_year in res.entities.iter_mut() {
+                if fiscal_year.closed_as_of.is_some() {
+                    continue;
+                }
+                if fiscal_year
+                    .record_month_end_btc_price(month_end, price, closing_time)
+                    .did_execute()
+                {
+                    self.repo.update_in_op(&mut *op, fiscal_year).await?;
+                }
+            }
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     #[record_error_severity]
     #[instrument(name = "core_accounting.fiscal_years.list_for_chart_id", skip(self))]
     pub async fn list_for_chart_id(
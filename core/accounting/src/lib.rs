@@ -1,8 +1,12 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
+#![recursion_limit = "256"]
 
 pub mod balance_sheet;
+pub mod btc_revaluation;
+pub mod cash_flow;
 pub mod chart_of_accounts;
+mod config;
 pub mod csv;
 pub mod error;
 pub mod event;
@@ -11,25 +15,53 @@ pub mod journal;
 pub mod ledger_account;
 pub mod ledger_transaction;
 pub mod manual_transaction;
+pub mod manual_transaction_schedule;
 mod primitives;
 pub mod profit_and_loss;
 pub mod transaction_templates;
 pub mod trial_balance;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
+use core_price::Price;
+use core_time_events::CoreTimeEvent;
 use document_storage::DocumentStorage;
+use domain_config::ExposedDomainConfigsReadOnly;
+use fiscal_year::jobs::end_of_month::{FISCAL_YEAR_END_OF_MONTH, FiscalYearEndOfMonthHandler};
+use governance::{Governance, GovernanceEvent};
 use job::Jobs;
-use manual_transaction::ManualTransactions;
-use obix::out::{Outbox, OutboxEventMarker};
+use manual_transaction::{
+    ManualTransactions,
+    jobs::approve::{
+        ApproveManualTransactionJobInitializer, MANUAL_TRANSACTION_APPROVE_JOB,
+        ManualTransactionApprovalHandler,
+    },
+};
+use manual_transaction_schedule::{
+    ManualTransactionSchedules,
+    jobs::{
+        end_of_period::{
+            MANUAL_TRANSACTION_SCHEDULE_END_OF_PERIOD, ManualTransactionScheduleEndOfPeriodHandler,
+        },
+        process_schedules::ProcessManualTransactionSchedulesJobInit,
+    },
+};
+use obix::out::{Outbox, OutboxEventJobConfig, OutboxEventMarker};
 use tracing::instrument;
 use tracing_macros::record_error_severity;
 
 pub use balance_sheet::{BalanceSheet, BalanceSheets};
+pub use btc_revaluation::BtcRevaluations;
+pub use cash_flow::{CashFlowSection, CashFlowStatement, CashFlowStatements};
 pub use chart_of_accounts::{Chart, ChartOfAccounts, error as chart_of_accounts_error, tree};
+pub use config::{
+    BtcRevaluationAccountCode, BtcRevaluationAdjustmentAccountCode,
+    BtcRevaluationUnrealizedGainAccountCode, BtcRevaluationUnrealizedLossAccountCode,
+    ManualTransactionApprovalThresholdCents,
+};
 pub use csv::AccountingCsvExports;
 use error::CoreAccountingError;
 pub use event::{CSV_EXPORT_EVENT_TYPE, CoreAccountingEvent};
@@ -40,7 +72,16 @@ pub use fiscal_year::{
 pub use journal::{Journal, error as journal_error};
 pub use ledger_account::{LedgerAccount, LedgerAccountChildrenCursor, LedgerAccounts};
 pub use ledger_transaction::{LedgerTransaction, LedgerTransactions};
-pub use manual_transaction::ManualEntryInput;
+pub use manual_transaction::{
+    APPROVE_MANUAL_TRANSACTION_PROCESS, ManualEntryInput, ManualTransaction,
+    ManualTransactionDraft, ManualTransactionEntry, ManualTransactionReversalLinks,
+    ManualTransactionStatus, ManualTransactionsByCreatedAtCursor,
+};
+pub use manual_transaction_schedule::{
+    ManualTransactionSchedule, ManualTransactionScheduleExecution,
+    ManualTransactionScheduleFrequency, ManualTransactionScheduleStatus,
+    ManualTransactionSchedulesByCreatedAtCursor,
+};
 pub use primitives::AccountInfo;
 pub use primitives::*;
 pub use profit_and_loss::{ProfitAndLossStatement, ProfitAndLossStatements};
@@ -53,12 +94,13 @@ pub mod event_schema {
     pub use crate::chart_of_accounts::chart_node::ChartNodeEvent;
     pub use crate::fiscal_year::FiscalYearEvent;
     pub use crate::manual_transaction::ManualTransactionEvent;
+    pub use crate::manual_transaction_schedule::ManualTransactionScheduleEvent;
 }
 
 pub struct CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreAccountingEvent>,
+    E: OutboxEventMarker<CoreAccountingEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     clock: ClockHandle,
     authz: Perms,
@@ -66,8 +108,10 @@ where
     journal: Journal<Perms>,
     ledger_accounts: LedgerAccounts<Perms>,
     ledger_transactions: LedgerTransactions<Perms>,
-    manual_transactions: ManualTransactions<Perms>,
+    manual_transactions: ManualTransactions<Perms, E>,
+    manual_transaction_schedules: ManualTransactionSchedules<Perms, E>,
     profit_and_loss: ProfitAndLossStatements<Perms>,
+    cash_flow_statements: CashFlowStatements<Perms>,
     transaction_templates: TransactionTemplates<Perms>,
     balance_sheets: BalanceSheets<Perms>,
     csvs: AccountingCsvExports<Perms, E>,
@@ -78,7 +122,7 @@ where
 impl<Perms, E> Clone for CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreAccountingEvent>,
+    E: OutboxEventMarker<CoreAccountingEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -88,8 +132,10 @@ where
             journal: self.journal.clone(),
             ledger_accounts: self.ledger_accounts.clone(),
             manual_transactions: self.manual_transactions.clone(),
+            manual_transaction_schedules: self.manual_transaction_schedules.clone(),
             ledger_transactions: self.ledger_transactions.clone(),
             profit_and_loss: self.profit_and_loss.clone(),
+            cash_flow_statements: self.cash_flow_statements.clone(),
             transaction_templates: self.transaction_templates.clone(),
             balance_sheets: self.balance_sheets.clone(),
             csvs: self.csvs.clone(),
@@ -102,11 +148,14 @@ where
 impl<Perms, E> CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
-    E: OutboxEventMarker<CoreAccountingEvent>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccountingEvent> + OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn init(
         pool: &sqlx::PgPool,
         authz: &Perms,
         cala: &CalaLedger,
@@ -114,28 +163,81 @@ where
         document_storage: DocumentStorage,
         jobs: &mut Jobs,
         outbox: &Outbox<E>,
-    ) -> Self {
+        governance: &Governance<Perms, E>,
+        domain_configs: &ExposedDomainConfigsReadOnly,
+        price: &Price,
+    ) -> Result<Self, CoreAccountingError>
+    where
+        E: OutboxEventMarker<CoreTimeEvent>,
+    {
         let clock = jobs.clock().clone();
         let chart_of_accounts = ChartOfAccounts::new(pool, clock.clone(), authz, cala, journal_id);
-        let fiscal_year = FiscalYears::new(pool, clock.clone(), authz, &chart_of_accounts);
+        let btc_revaluations =
+            BtcRevaluations::new(cala, journal_id, &chart_of_accounts, domain_configs, price);
+        let fiscal_year = FiscalYears::new(pool, clock.clone(), authz, &chart_of_accounts)
+            .with_btc_revaluations(&btc_revaluations);
         let journal = Journal::new(authz, cala, journal_id);
         let ledger_accounts = LedgerAccounts::new(authz, cala, journal_id);
-        let manual_transactions = ManualTransactions::new(
+        let manual_transactions = ManualTransactions::init(
             pool,
             authz,
             &chart_of_accounts,
             cala,
             journal_id,
+            governance,
+            domain_configs,
             clock.clone(),
-        );
+        )
+        .await?;
+        let manual_transaction_schedules =
+            ManualTransactionSchedules::new(pool, authz, &manual_transactions, clock.clone());
         let ledger_transactions = LedgerTransactions::new(authz, cala);
         let profit_and_loss = ProfitAndLossStatements::new(pool, authz, cala, journal_id);
+        let cash_flow_statements = CashFlowStatements::new(pool, authz, cala, journal_id);
         let transaction_templates = TransactionTemplates::new(authz, cala);
         let balance_sheets = BalanceSheets::new(pool, authz, cala, journal_id);
-        let csvs =
-            AccountingCsvExports::new(authz, jobs, document_storage, &ledger_accounts, outbox);
+        let csvs = AccountingCsvExports::new(
+            authz,
+            jobs,
+            document_storage,
+            &ledger_accounts,
+            &cash_flow_statements,
+            outbox,
+        );
         let trial_balances = TrialBalances::new(pool, authz, cala, journal_id);
-        Self {
+
+        let approve_manual_transaction_spawner = jobs.add_initializer(
+            ApproveManualTransactionJobInitializer::new(Arc::new(manual_transactions.clone())),
+        );
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(MANUAL_TRANSACTION_APPROVE_JOB),
+                ManualTransactionApprovalHandler::new(approve_manual_transaction_spawner),
+            )
+            .await?;
+
+        let process_schedules_spawner =
+            jobs.add_initializer(ProcessManualTransactionSchedulesJobInit::new(Arc::new(
+                manual_transaction_schedules.clone(),
+            )));
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(MANUAL_TRANSACTION_SCHEDULE_END_OF_PERIOD),
+                ManualTransactionScheduleEndOfPeriodHandler::new(process_schedules_spawner),
+            )
+            .await?;
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(FISCAL_YEAR_END_OF_MONTH),
+                FiscalYearEndOfMonthHandler::new(&fiscal_year),
+            )
+            .await?;
+
+        Ok(Self {
             clock,
             authz: authz.clone(),
             chart_of_accounts,
@@ -143,13 +245,15 @@ where
             ledger_accounts,
             ledger_transactions,
             manual_transactions,
+            manual_transaction_schedules,
             profit_and_loss,
+            cash_flow_statements,
             transaction_templates,
             balance_sheets,
             csvs,
             trial_balances,
             fiscal_year,
-        }
+        })
     }
 
     pub fn chart_of_accounts(&self) -> &ChartOfAccounts<Perms> {
@@ -168,14 +272,22 @@ where
         &self.ledger_transactions
     }
 
-    pub fn manual_transactions(&self) -> &ManualTransactions<Perms> {
+    pub fn manual_transactions(&self) -> &ManualTransactions<Perms, E> {
         &self.manual_transactions
     }
 
+    pub fn manual_transaction_schedules(&self) -> &ManualTransactionSchedules<Perms, E> {
+        &self.manual_transaction_schedules
+    }
+
     pub fn profit_and_loss(&self) -> &ProfitAndLossStatements<Perms> {
         &self.profit_and_loss
     }
 
+    pub fn cash_flow_statements(&self) -> &CashFlowStatements<Perms> {
+        &self.cash_flow_statements
+    }
+
     pub fn csvs(&self) -> &AccountingCsvExports<Perms, E> {
         &self.csvs
     }
@@ -282,6 +394,48 @@ where
         Ok(self.ledger_transactions.find_all(ids).await?)
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.find_all_manual_transactions_authorized",
+        skip(self)
+    )]
+    pub async fn find_all_manual_transactions_authorized<T: From<ManualTransaction>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[ManualTransactionId],
+    ) -> Result<HashMap<ManualTransactionId, T>, CoreAccountingError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transactions(),
+                CoreAccountingAction::MANUAL_TRANSACTION_LIST,
+            )
+            .await?;
+        Ok(self.manual_transactions.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.find_all_manual_transaction_schedules_authorized",
+        skip(self)
+    )]
+    pub async fn find_all_manual_transaction_schedules_authorized<
+        T: From<ManualTransactionSchedule>,
+    >(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[ManualTransactionScheduleId],
+    ) -> Result<HashMap<ManualTransactionScheduleId, T>, CoreAccountingError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transaction_schedules(),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_LIST,
+            )
+            .await?;
+        Ok(self.manual_transaction_schedules.find_all(ids).await?)
+    }
+
     #[record_error_severity]
     #[instrument(
         name = "core_accounting.find_all_transaction_templates_authorized",
@@ -439,6 +593,76 @@ where
             .expect("Could not find LedgerTransaction"))
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.submit_manual_transaction",
+        skip(self, entries)
+    )]
+    pub async fn submit_manual_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .submit(sub, chart_ref, reference, description, effective, entries)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.update_manual_transaction_draft",
+        skip(self, entries)
+    )]
+    pub async fn update_manual_transaction_draft(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .update_draft(sub, chart_ref, id, description, effective, entries)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.reverse_manual_transaction", skip(self))]
+    pub async fn reverse_manual_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_transaction_id: impl Into<LedgerTransactionId> + std::fmt::Debug,
+        effective: Option<chrono::NaiveDate>,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .reverse(sub, ledger_transaction_id.into(), effective)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.find_manual_transaction_reversal_links",
+        skip(self)
+    )]
+    pub async fn find_manual_transaction_reversal_links(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_transaction_id: impl Into<LedgerTransactionId> + std::fmt::Debug,
+    ) -> Result<ManualTransactionReversalLinks, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .find_reversal_links(sub, ledger_transaction_id.into())
+            .await?)
+    }
+
     #[record_error_severity]
     #[instrument(name = "core_accounting.import_csv_with_base_config", skip(self, data))]
     pub async fn import_csv_with_base_config(
@@ -565,6 +789,48 @@ where
             .await?)
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.add_chart_node_to_cash_flow_section",
+        skip(self)
+    )]
+    pub async fn add_chart_node_to_cash_flow_section(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        cash_flow_ref: &str,
+        code: AccountCode,
+        section: CashFlowSection,
+    ) -> Result<Chart, CoreAccountingError> {
+        let chart = self.chart_of_accounts.find_by_reference(chart_ref).await?;
+        self.cash_flow_statements
+            .add_chart_node_to_section(sub, cash_flow_ref.to_string(), &chart, code, section)
+            .await?;
+
+        Ok(chart)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.remove_chart_node_from_cash_flow_section",
+        skip(self)
+    )]
+    pub async fn remove_chart_node_from_cash_flow_section(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        cash_flow_ref: &str,
+        code: AccountCode,
+        section: CashFlowSection,
+    ) -> Result<Chart, CoreAccountingError> {
+        let chart = self.chart_of_accounts.find_by_reference(chart_ref).await?;
+        self.cash_flow_statements
+            .remove_chart_node_from_section(sub, cash_flow_ref.to_string(), &chart, code, section)
+            .await?;
+
+        Ok(chart)
+    }
+
     #[record_error_severity]
     #[instrument(name = "core_accounting.add_child_node", skip(self))]
     pub async fn add_child_node(
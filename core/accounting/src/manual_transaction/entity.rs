@@ -1,11 +1,85 @@
 use derive_builder::Builder;
+use rust_decimal::Decimal;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
+
 use es_entity::*;
 
-use crate::primitives::{CalaTxId, ManualTransactionId};
+use crate::primitives::{
+    ApprovalProcessId, CalaCurrency, CalaTxId, DebitOrCredit, LedgerAccountId, ManualTransactionId,
+};
+
+use super::error::ManualTransactionError;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum ManualTransactionStatus {
+    #[default]
+    Posted,
+    PendingApproval,
+    Denied,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ManualTransactionEntry {
+    pub account_id: LedgerAccountId,
+    pub amount: Decimal,
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub currency: CalaCurrency,
+    pub direction: DebitOrCredit,
+    pub description: String,
+}
+
+/// Entries held back from the ledger while the manual transaction awaits approval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManualTransactionDraft {
+    pub effective: chrono::NaiveDate,
+    pub entries: Vec<ManualTransactionEntry>,
+}
+
+impl ManualTransactionDraft {
+    /// Checks that debits and credits balance in every currency and that the entries are not
+    /// dated within a closed period.
+    pub(super) fn validate(
+        &self,
+        closed_as_of: Option<chrono::NaiveDate>,
+    ) -> Result<(), ManualTransactionError> {
+        if closed_as_of.is_some_and(|closed_as_of| self.effective <= closed_as_of) {
+            return Err(ManualTransactionError::PeriodClosed(self.effective));
+        }
+
+        let mut balances: HashMap<CalaCurrency, Decimal> = HashMap::new();
+        for entry in self.entries.iter() {
+            let balance = balances.entry(entry.currency).or_default();
+            match entry.direction {
+                DebitOrCredit::Debit => *balance += entry.amount,
+                DebitOrCredit::Credit => *balance -= entry.amount,
+            }
+        }
+        if let Some((currency, _)) = balances.into_iter().find(|(_, b)| !b.is_zero()) {
+            return Err(ManualTransactionError::UnbalancedEntries(currency));
+        }
+
+        Ok(())
+    }
+}
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -17,6 +91,29 @@ pub enum ManualTransactionEvent {
         ledger_transaction_id: CalaTxId,
         description: String,
         reference: String,
+        #[serde(default)]
+        reversal_of: Option<ManualTransactionId>,
+        #[serde(default)]
+        approval_process_id: Option<ApprovalProcessId>,
+    },
+    DraftUpdated {
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualTransactionEntry>,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    },
+    Reversed {
+        reversal_id: ManualTransactionId,
+        reversal_ledger_transaction_id: CalaTxId,
+    },
+    ReversalVoided {
+        reversal_id: ManualTransactionId,
+    },
+    PostingFailed {
+        reason: String,
     },
 }
 
@@ -27,6 +124,12 @@ pub struct ManualTransaction {
     pub reference: String,
     pub description: String,
     pub ledger_transaction_id: CalaTxId,
+    #[builder(default)]
+    pub reversal_of: Option<ManualTransactionId>,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    #[builder(default)]
+    pub draft: Option<ManualTransactionDraft>,
     events: EntityEvents<ManualTransactionEvent>,
 }
 
@@ -36,6 +139,146 @@ impl ManualTransaction {
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    pub fn status(&self) -> ManualTransactionStatus {
+        if self.approval_process_id.is_none() {
+            return ManualTransactionStatus::Posted;
+        }
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                ManualTransactionEvent::PostingFailed { .. } => {
+                    Some(ManualTransactionStatus::Failed)
+                }
+                ManualTransactionEvent::ApprovalProcessConcluded { approved: true, .. } => {
+                    Some(ManualTransactionStatus::Posted)
+                }
+                ManualTransactionEvent::ApprovalProcessConcluded {
+                    approved: false, ..
+                } => Some(ManualTransactionStatus::Denied),
+                _ => None,
+            })
+            .unwrap_or(ManualTransactionStatus::PendingApproval)
+    }
+
+    pub fn is_posted(&self) -> bool {
+        self.status() == ManualTransactionStatus::Posted
+    }
+
+    pub(super) fn update_draft(
+        &mut self,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualTransactionEntry>,
+    ) -> Result<Idempotent<()>, ManualTransactionError> {
+        if self.status() != ManualTransactionStatus::PendingApproval {
+            return Err(ManualTransactionError::NotPendingApproval(self.id));
+        }
+
+        let draft = ManualTransactionDraft { effective, entries };
+        if self.description == description && self.draft.as_ref() == Some(&draft) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        self.events.push(ManualTransactionEvent::DraftUpdated {
+            description: description.clone(),
+            effective: draft.effective,
+            entries: draft.entries.clone(),
+        });
+        self.description = description;
+        self.draft = Some(draft);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(super) fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+    ) -> Idempotent<Option<ManualTransactionDraft>> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionEvent::ApprovalProcessConcluded { .. }
+        );
+        let Some(approval_process_id) = self.approval_process_id else {
+            return Idempotent::AlreadyApplied;
+        };
+
+        self.events
+            .push(ManualTransactionEvent::ApprovalProcessConcluded {
+                approval_process_id,
+                approved,
+            });
+
+        Idempotent::Executed(approved.then(|| self.draft.clone()).flatten())
+    }
+
+    /// Records that the approved entries were rejected by the ledger, e.g. because their
+    /// period was closed while the approval was pending.
+    pub(super) fn posting_failed(&mut self, reason: String) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionEvent::PostingFailed { .. }
+        );
+        let _ = self.approval_process_concluded(true);
+
+        self.events
+            .push(ManualTransactionEvent::PostingFailed { reason });
+
+        Idempotent::Executed(())
+    }
+
+    /// The reversal of this transaction, including one that is still awaiting approval.
+    pub fn reversed_by(&self) -> Option<(ManualTransactionId, CalaTxId)> {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                ManualTransactionEvent::Reversed {
+                    reversal_id,
+                    reversal_ledger_transaction_id,
+                } => Some(Some((*reversal_id, *reversal_ledger_transaction_id))),
+                ManualTransactionEvent::ReversalVoided { .. } => Some(None),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed_by().is_some()
+    }
+
+    pub(super) fn reverse(
+        &mut self,
+        reversal_id: ManualTransactionId,
+        reversal_ledger_transaction_id: CalaTxId,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionEvent::Reversed { .. },
+            resets_on: ManualTransactionEvent::ReversalVoided { .. }
+        );
+
+        self.events.push(ManualTransactionEvent::Reversed {
+            reversal_id,
+            reversal_ledger_transaction_id,
+        });
+
+        Idempotent::Executed(())
+    }
+
+    /// Drops the link to a reversal that was never posted, so that the transaction can be
+    /// reversed again.
+    pub(super) fn void_reversal(&mut self, reversal_id: ManualTransactionId) -> Idempotent<()> {
+        if self.reversed_by().map(|(id, _)| id) != Some(reversal_id) {
+            return Idempotent::AlreadyApplied;
+        }
+
+        self.events
+            .push(ManualTransactionEvent::ReversalVoided { reversal_id });
+
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
@@ -50,14 +293,33 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                     reference,
                     description,
                     ledger_transaction_id,
-                    ..
+                    reversal_of,
+                    approval_process_id,
                 } => {
                     builder = builder
                         .id(*id)
                         .reference(reference.clone())
                         .description(description.clone())
                         .ledger_transaction_id(*ledger_transaction_id)
+                        .reversal_of(*reversal_of)
+                        .approval_process_id(*approval_process_id)
                 }
+                ManualTransactionEvent::DraftUpdated {
+                    description,
+                    effective,
+                    entries,
+                } => {
+                    builder = builder.description(description.clone()).draft(Some(
+                        ManualTransactionDraft {
+                            effective: *effective,
+                            entries: entries.clone(),
+                        },
+                    ))
+                }
+                ManualTransactionEvent::ApprovalProcessConcluded { .. } => {}
+                ManualTransactionEvent::Reversed { .. } => {}
+                ManualTransactionEvent::ReversalVoided { .. } => {}
+                ManualTransactionEvent::PostingFailed { .. } => {}
             }
         }
         builder.events(events).build()
@@ -71,6 +333,12 @@ pub struct NewManualTransaction {
     reference: Option<String>,
     pub(super) ledger_transaction_id: CalaTxId,
     description: String,
+    #[builder(default)]
+    reversal_of: Option<ManualTransactionId>,
+    #[builder(default)]
+    approval_process_id: Option<ApprovalProcessId>,
+    #[builder(default)]
+    draft: Option<ManualTransactionDraft>,
 }
 
 impl NewManualTransaction {
@@ -89,14 +357,214 @@ impl NewManualTransaction {
 
 impl IntoEvents<ManualTransactionEvent> for NewManualTransaction {
     fn into_events(self) -> EntityEvents<ManualTransactionEvent> {
-        EntityEvents::init(
-            self.id,
-            [ManualTransactionEvent::Initialized {
-                reference: self.reference(),
-                id: self.id,
-                ledger_transaction_id: self.ledger_transaction_id,
+        let mut events = vec![ManualTransactionEvent::Initialized {
+            reference: self.reference(),
+            id: self.id,
+            ledger_transaction_id: self.ledger_transaction_id,
+            description: self.description.clone(),
+            reversal_of: self.reversal_of,
+            approval_process_id: self.approval_process_id,
+        }];
+        if let Some(draft) = self.draft {
+            events.push(ManualTransactionEvent::DraftUpdated {
                 description: self.description,
-            }],
-        )
+                effective: draft.effective,
+                entries: draft.entries,
+            });
+        }
+        EntityEvents::init(self.id, events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manual_transaction() -> ManualTransaction {
+        let new_tx = NewManualTransaction::builder()
+            .id(ManualTransactionId::new())
+            .ledger_transaction_id(CalaTxId::new())
+            .description("Accrual correction".to_string())
+            .reference(None)
+            .build()
+            .unwrap();
+        ManualTransaction::try_from_events(new_tx.into_events()).unwrap()
+    }
+
+    fn draft(amount: Decimal) -> ManualTransactionDraft {
+        let account_id = LedgerAccountId::new();
+        let entry = |direction| ManualTransactionEntry {
+            account_id,
+            amount,
+            currency: CalaCurrency::USD,
+            direction,
+            description: "Accrual".to_string(),
+        };
+        ManualTransactionDraft {
+            effective: chrono::NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            entries: vec![entry(DebitOrCredit::Debit), entry(DebitOrCredit::Credit)],
+        }
+    }
+
+    fn pending_manual_transaction() -> ManualTransaction {
+        let id = ManualTransactionId::new();
+        let new_tx = NewManualTransaction::builder()
+            .id(id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description("Accrual correction".to_string())
+            .reference(None)
+            .approval_process_id(Some(id.into()))
+            .draft(Some(draft(Decimal::ONE)))
+            .build()
+            .unwrap();
+        ManualTransaction::try_from_events(new_tx.into_events()).unwrap()
+    }
+
+    #[test]
+    fn posted_without_approval_process() {
+        let tx = manual_transaction();
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+        assert!(tx.draft.is_none());
+    }
+
+    #[test]
+    fn draft_can_be_edited_while_pending_approval() {
+        let mut tx = pending_manual_transaction();
+        assert_eq!(tx.status(), ManualTransactionStatus::PendingApproval);
+
+        let updated = draft(Decimal::TEN);
+        let res = tx
+            .update_draft(
+                "Corrected accrual".to_string(),
+                updated.effective,
+                updated.entries.clone(),
+            )
+            .unwrap();
+        assert!(res.did_execute());
+        assert_eq!(tx.description, "Corrected accrual");
+        assert_eq!(tx.draft.as_ref(), Some(&updated));
+
+        let res = tx
+            .update_draft(
+                "Corrected accrual".to_string(),
+                updated.effective,
+                updated.entries,
+            )
+            .unwrap();
+        assert!(res.was_already_applied());
+    }
+
+    #[test]
+    fn approval_releases_draft_for_posting() {
+        let mut tx = pending_manual_transaction();
+        let pending = tx.draft.clone();
+
+        let Idempotent::Executed(to_post) = tx.approval_process_concluded(true) else {
+            panic!("approval should execute");
+        };
+        assert!(pending.is_some());
+        assert_eq!(to_post, pending);
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+
+        assert!(tx.approval_process_concluded(true).was_already_applied());
+    }
+
+    #[test]
+    fn denied_draft_is_not_posted_or_editable() {
+        let mut tx = pending_manual_transaction();
+
+        let Idempotent::Executed(to_post) = tx.approval_process_concluded(false) else {
+            panic!("denial should execute");
+        };
+        assert_eq!(to_post, None);
+        assert_eq!(tx.status(), ManualTransactionStatus::Denied);
+
+        let updated = draft(Decimal::TEN);
+        assert!(matches!(
+            tx.update_draft("Too late".to_string(), updated.effective, updated.entries),
+            Err(ManualTransactionError::NotPendingApproval(_))
+        ));
+    }
+
+    #[test]
+    fn failed_posting_is_not_posted() {
+        let mut tx = pending_manual_transaction();
+
+        assert!(tx.posting_failed("period closed".to_string()).did_execute());
+        assert_eq!(tx.status(), ManualTransactionStatus::Failed);
+        assert!(
+            tx.posting_failed("period closed".to_string())
+                .was_already_applied()
+        );
+    }
+
+    #[test]
+    fn draft_must_balance_per_currency() {
+        assert!(draft(Decimal::ONE).validate(None).is_ok());
+
+        let mut unbalanced = draft(Decimal::ONE);
+        unbalanced.entries[1].currency = CalaCurrency::BTC;
+        assert!(matches!(
+            unbalanced.validate(None),
+            Err(ManualTransactionError::UnbalancedEntries(_))
+        ));
+    }
+
+    #[test]
+    fn draft_in_closed_period_is_rejected() {
+        let draft = draft(Decimal::ONE);
+
+        assert!(matches!(
+            draft.validate(Some(draft.effective)),
+            Err(ManualTransactionError::PeriodClosed(_))
+        ));
+        assert!(draft.validate(draft.effective.pred_opt()).is_ok());
+    }
+
+    #[test]
+    fn reverse_records_reversal() {
+        let mut tx = manual_transaction();
+        assert!(!tx.is_reversed());
+
+        let reversal_id = ManualTransactionId::new();
+        let reversal_ledger_transaction_id = CalaTxId::new();
+        assert!(
+            tx.reverse(reversal_id, reversal_ledger_transaction_id)
+                .did_execute()
+        );
+        assert_eq!(
+            tx.reversed_by(),
+            Some((reversal_id, reversal_ledger_transaction_id))
+        );
+    }
+
+    #[test]
+    fn reverse_is_only_applied_once() {
+        let mut tx = manual_transaction();
+        let _ = tx.reverse(ManualTransactionId::new(), CalaTxId::new());
+
+        let res = tx.reverse(ManualTransactionId::new(), CalaTxId::new());
+        assert!(res.was_already_applied());
+    }
+
+    #[test]
+    fn voided_reversal_allows_reversing_again() {
+        let mut tx = manual_transaction();
+        let reversal_id = ManualTransactionId::new();
+        let _ = tx.reverse(reversal_id, CalaTxId::new());
+
+        assert!(
+            tx.void_reversal(ManualTransactionId::new())
+                .was_already_applied()
+        );
+        assert!(tx.void_reversal(reversal_id).did_execute());
+        assert!(!tx.is_reversed());
+        assert!(tx.void_reversal(reversal_id).was_already_applied());
+
+        assert!(
+            tx.reverse(ManualTransactionId::new(), CalaTxId::new())
+                .did_execute()
+        );
+        assert!(tx.is_reversed());
     }
 }
@@ -27,6 +27,26 @@ pub enum ManualTransactionError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("ManualTransactionError - ChartOfAccounts: {0}")]
     ChartOfAccountsError(#[from] chart_of_accounts::error::ChartOfAccountsError),
+    #[error("ManualTransactionError - AlreadyReversed: {0}")]
+    AlreadyReversed(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - NotAManualTransaction: {0}")]
+    NotAManualTransaction(crate::primitives::CalaTxId),
+    #[error("ManualTransactionError - NotPendingApproval: {0}")]
+    NotPendingApproval(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - NotPosted: {0}")]
+    NotPosted(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - ApprovalRequired")]
+    ApprovalRequired,
+    #[error("ManualTransactionError - DraftHasVotes: {0}")]
+    DraftHasVotes(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - PeriodClosed: {0}")]
+    PeriodClosed(chrono::NaiveDate),
+    #[error("ManualTransactionError - UnbalancedEntries: {0}")]
+    UnbalancedEntries(crate::primitives::CalaCurrency),
+    #[error("ManualTransactionError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("ManualTransactionError - DomainConfigError: {0}")]
+    DomainConfigError(#[from] domain_config::DomainConfigError),
 }
 
 impl ErrorSeverity for ManualTransactionError {
@@ -40,6 +60,16 @@ impl ErrorSeverity for ManualTransactionError {
             Self::ManualTransactionLedgerError(e) => e.severity(),
             Self::AuthorizationError(e) => e.severity(),
             Self::ChartOfAccountsError(e) => e.severity(),
+            Self::AlreadyReversed(_) => Level::WARN,
+            Self::NotAManualTransaction(_) => Level::WARN,
+            Self::NotPendingApproval(_) => Level::WARN,
+            Self::NotPosted(_) => Level::WARN,
+            Self::ApprovalRequired => Level::WARN,
+            Self::DraftHasVotes(_) => Level::WARN,
+            Self::PeriodClosed(_) => Level::WARN,
+            Self::UnbalancedEntries(_) => Level::WARN,
+            Self::GovernanceError(e) => e.severity(),
+            Self::DomainConfigError(e) => e.severity(),
         }
     }
 }
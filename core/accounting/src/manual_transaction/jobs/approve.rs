@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcessId, ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
+use job::*;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+use tracing_macros::record_error_severity;
+
+use crate::{
+    manual_transaction::{
+        ManualTransactions, error::ManualTransactionError,
+        ledger::error::ManualTransactionLedgerError,
+    },
+    primitives::{CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+};
+
+pub const MANUAL_TRANSACTION_APPROVE_JOB: JobType =
+    JobType::new("outbox.manual-transaction-approval");
+
+pub const APPROVE_MANUAL_TRANSACTION_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("manual-transaction");
+
+pub const APPROVE_MANUAL_TRANSACTION_COMMAND: JobType =
+    JobType::new("command.accounting.approve-manual-transaction");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApproveManualTransactionConfig {
+    pub approval_process_id: ApprovalProcessId,
+    pub approved: bool,
+}
+
+pub struct ApproveManualTransactionJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    manual_transactions: Arc<ManualTransactions<Perms, E>>,
+}
+
+impl<Perms, E> ApproveManualTransactionJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(manual_transactions: Arc<ManualTransactions<Perms, E>>) -> Self {
+        Self {
+            manual_transactions,
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for ApproveManualTransactionJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    type Config = ApproveManualTransactionConfig;
+
+    fn job_type(&self) -> JobType {
+        APPROVE_MANUAL_TRANSACTION_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ApproveManualTransactionJobRunner {
+            config: job.config()?,
+            manual_transactions: self.manual_transactions.clone(),
+        }))
+    }
+}
+
+struct ApproveManualTransactionJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    config: ApproveManualTransactionConfig,
+    manual_transactions: Arc<ManualTransactions<Perms, E>>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ApproveManualTransactionJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "core_accounting.approve_manual_transaction.process_command",
+        skip_all
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut op = current_job.begin_op().await?;
+        let id: ManualTransactionId = self.config.approval_process_id.into();
+
+        match self
+            .manual_transactions
+            .conclude_approval_process_in_op(&mut op, id, self.config.approved)
+            .await
+        {
+            Ok(_) => Ok(JobCompletion::CompleteWithOp(op)),
+            Err(ManualTransactionError::ManualTransactionLedgerError(e))
+                if !matches!(e, ManualTransactionLedgerError::Sqlx(_)) =>
+            {
+                // Retrying cannot post entries the ledger rejected, e.g. for a closed period
+                drop(op);
+                let mut op = current_job.begin_op().await?;
+                self.manual_transactions
+                    .record_posting_failure_in_op(&mut op, id, e.to_string())
+                    .await?;
+                Ok(JobCompletion::CompleteWithOp(op))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+pub struct ManualTransactionApprovalHandler {
+    approve_manual_transaction: JobSpawner<ApproveManualTransactionConfig>,
+}
+
+impl ManualTransactionApprovalHandler {
+    pub fn new(approve_manual_transaction: JobSpawner<ApproveManualTransactionConfig>) -> Self {
+        Self {
+            approve_manual_transaction,
+        }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for ManualTransactionApprovalHandler
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(name = "core_accounting.manual_transaction_approval_job.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, process_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ GovernanceEvent::ApprovalProcessConcluded { entity }) = event.as_event()
+            && entity.process_type == APPROVE_MANUAL_TRANSACTION_PROCESS
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+            Span::current().record("process_type", entity.process_type.to_string());
+            self.approve_manual_transaction
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    ApproveManualTransactionConfig {
+                        approval_process_id: entity.id,
+                        approved: entity.status.is_approved(),
+                    },
+                    entity.id.to_string(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
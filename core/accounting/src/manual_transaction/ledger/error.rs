@@ -8,6 +8,10 @@ pub enum ManualTransactionLedgerError {
     Sqlx(#[from] sqlx::Error),
     #[error("ManualTransactionLedgerError - CalaLedger: {0}")]
     CalaLedger(#[from] cala_ledger::error::LedgerError),
+    #[error("ManualTransactionLedgerError - CalaTransaction: {0}")]
+    CalaTransaction(#[from] cala_ledger::transaction::error::TransactionError),
+    #[error("ManualTransactionLedgerError - CalaEntry: {0}")]
+    CalaEntry(#[from] cala_ledger::entry::error::EntryError),
     #[error("ManualTransactionLedgerError - CalaTxTemplate: {0}")]
     CalaTxTemplate(#[from] cala_ledger::tx_template::error::TxTemplateError),
 }
@@ -20,6 +24,8 @@ impl ErrorSeverity for ManualTransactionLedgerError {
                 cala_ledger::velocity::error::VelocityError::Enforcement(_),
             )) => Level::WARN,
             Self::CalaLedger(_) => Level::ERROR,
+            Self::CalaTransaction(_) => Level::ERROR,
+            Self::CalaEntry(_) => Level::ERROR,
             Self::CalaTxTemplate(_) => Level::ERROR,
         }
     }
@@ -1,10 +1,12 @@
 pub mod error;
 mod template;
 
-use cala_ledger::CalaLedger;
+use cala_ledger::{CalaLedger, DebitOrCredit};
 
 use crate::primitives::CalaTxId;
 
+use super::ManualTransactionEntry;
+
 use error::*;
 use template::*;
 pub use template::{EntryParams, ManualTransactionParams};
@@ -34,4 +36,35 @@ impl ManualTransactionLedger {
 
         Ok(())
     }
+
+    /// Contra entries for a posted manual transaction. Manual transactions only post on the
+    /// settled layer, which is where the reversal is posted as well.
+    pub async fn reversal_entries_for(
+        &self,
+        tx_id: CalaTxId,
+    ) -> Result<(chrono::NaiveDate, Vec<ManualTransactionEntry>), ManualTransactionLedgerError>
+    {
+        let transaction = self.cala.transactions().find_by_id(tx_id).await?;
+        let mut entries = self.cala.entries().list_for_transaction_id(tx_id).await?;
+        entries.sort_by_key(|entry| entry.values().sequence);
+
+        let reversal_entries = entries
+            .iter()
+            .map(|entry| {
+                let values = entry.values();
+                ManualTransactionEntry {
+                    account_id: values.account_id.into(),
+                    currency: values.currency,
+                    amount: values.units,
+                    description: values.description.clone().unwrap_or_default(),
+                    direction: match values.direction {
+                        DebitOrCredit::Debit => DebitOrCredit::Credit,
+                        DebitOrCredit::Credit => DebitOrCredit::Debit,
+                    },
+                }
+            })
+            .collect();
+
+        Ok((transaction.effective(), reversal_entries))
+    }
 }
@@ -3,7 +3,7 @@ use tracing_macros::record_error_severity;
 
 use cala_ledger::{
     AccountId as CalaAccountId,
-    primitives::DebitOrCredit,
+    primitives::{DebitOrCredit, Layer},
     tx_template::{Params, error::TxTemplateError, *},
     *,
 };
@@ -17,6 +17,7 @@ pub struct EntryParams {
     pub amount: Decimal,
     pub description: String,
     pub direction: DebitOrCredit,
+    pub layer: Layer,
 }
 
 impl EntryParams {
@@ -26,6 +27,7 @@ impl EntryParams {
         params.insert(Self::amount_param_name(n), self.amount);
         params.insert(Self::description_param_name(n), self.description.clone());
         params.insert(Self::direction_param_name(n), self.direction);
+        params.insert(Self::layer_param_name(n), self.layer);
     }
 
     fn defs_for_entry(n: usize) -> Vec<NewParamDefinition> {
@@ -1,68 +1,113 @@
 mod entity;
 pub mod error;
+pub(crate) mod jobs;
 pub mod ledger;
 mod primitives;
 mod repo;
 
+use rust_decimal::Decimal;
 use tracing::instrument;
 
 use std::collections::HashMap;
 
-use audit::AuditSvc;
+use audit::{AuditSvc, SystemSubject};
 use authz::PermissionCheck;
-use cala_ledger::{CalaLedger, JournalId};
-use es_entity::clock::ClockHandle;
+use cala_ledger::{CalaLedger, JournalId, Layer};
+use domain_config::ExposedDomainConfigsReadOnly;
+use es_entity::{Idempotent, clock::ClockHandle};
+use governance::{
+    ApprovalProcess, Governance, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
 use ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams};
+use obix::out::OutboxEventMarker;
 use tracing_macros::record_error_severity;
 
 use crate::{
     chart_of_accounts::ChartOfAccounts,
-    primitives::{CalaTxId, CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+    config::ManualTransactionApprovalThresholdCents,
+    primitives::{
+        CalaCurrency, CalaTxId, CoreAccountingAction, CoreAccountingObject, DebitOrCredit,
+        MANUAL_TRANSACTION_APPROVAL, MANUAL_TRANSACTION_SCHEDULE, ManualTransactionId,
+    },
 };
 use error::*;
 
-pub use entity::ManualTransaction;
 #[cfg(feature = "json-schema")]
 pub use entity::ManualTransactionEvent;
 pub(super) use entity::*;
+pub use entity::{
+    ManualTransaction, ManualTransactionDraft, ManualTransactionEntry, ManualTransactionStatus,
+};
+pub use jobs::approve::APPROVE_MANUAL_TRANSACTION_PROCESS;
 pub use primitives::*;
 pub use repo::manual_transaction_cursor::ManualTransactionsByCreatedAtCursor;
 use repo::*;
 
-#[derive(Clone)]
-pub struct ManualTransactions<Perms>
+pub struct ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     ledger: ManualTransactionLedger,
     authz: Perms,
     chart_of_accounts: ChartOfAccounts<Perms>,
+    governance: Governance<Perms, E>,
+    domain_configs: ExposedDomainConfigsReadOnly,
     journal_id: JournalId,
     repo: ManualTransactionRepo,
 }
 
-impl<Perms> ManualTransactions<Perms>
+impl<Perms, E> Clone for ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
+    fn clone(&self) -> Self {
+        Self {
+            ledger: self.ledger.clone(),
+            authz: self.authz.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            governance: self.governance.clone(),
+            domain_configs: self.domain_configs.clone(),
+            journal_id: self.journal_id,
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ManualTransactions<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub async fn init(
         pool: &sqlx::PgPool,
         authz: &Perms,
         chart_of_accounts: &ChartOfAccounts<Perms>,
         cala: &CalaLedger,
         journal_id: JournalId,
+        governance: &Governance<Perms, E>,
+        domain_configs: &ExposedDomainConfigsReadOnly,
         clock: ClockHandle,
-    ) -> Self {
-        let repo = ManualTransactionRepo::new(pool, clock);
-        Self {
+    ) -> Result<Self, ManualTransactionError> {
+        governance
+            .init_policy(APPROVE_MANUAL_TRANSACTION_PROCESS)
+            .await?;
+
+        Ok(Self {
             ledger: ManualTransactionLedger::new(cala),
             chart_of_accounts: chart_of_accounts.clone(),
             authz: authz.clone(),
+            governance: governance.clone(),
+            domain_configs: domain_configs.clone(),
             journal_id,
-            repo,
-        }
+            repo: ManualTransactionRepo::new(pool, clock),
+        })
     }
 
     #[record_error_severity]
@@ -117,6 +162,9 @@ where
         Ok(self.repo.find_all(ids).await?)
     }
 
+    /// Posts the manual transaction right away. Fails with
+    /// [`ManualTransactionError::ApprovalRequired`] unless it is within the approval threshold;
+    /// use [`Self::submit`] to start the approval process instead.
     #[record_error_severity]
     #[instrument(name = "manual_transaction.execute", skip(self, entries), fields(subject = %sub, chart_ref = %chart_ref, effective = %effective, entries_count = entries.len()))]
     pub async fn execute(
@@ -127,6 +175,55 @@ where
         description: String,
         effective: chrono::NaiveDate,
         entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.create(
+            sub,
+            chart_ref,
+            reference,
+            description,
+            effective,
+            entries,
+            false,
+        )
+        .await
+    }
+
+    /// Starts the approval process for the manual transaction, or posts it right away when it
+    /// is within the configured approval threshold.
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.submit", skip(self, entries), fields(subject = %sub, chart_ref = %chart_ref, effective = %effective, entries_count = entries.len()))]
+    pub async fn submit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.create(
+            sub,
+            chart_ref,
+            reference,
+            description,
+            effective,
+            entries,
+            true,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(name = "manual_transaction.create", skip(self, entries), fields(requires_approval = tracing::field::Empty))]
+    async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+        allow_approval: bool,
     ) -> Result<ManualTransaction, ManualTransactionError> {
         self.authz
             .enforce_permission(
@@ -136,21 +233,256 @@ where
             )
             .await?;
 
-        let ledger_tx_id = CalaTxId::new();
-        let manual_tx_id = ManualTransactionId::new();
+        let draft = self
+            .validated_draft(sub, chart_ref, effective, entries)
+            .await?;
+        let requires_approval = self.requires_approval(&draft.entries).await?;
+        tracing::Span::current().record("requires_approval", requires_approval);
+        if requires_approval && !allow_approval {
+            return Err(ManualTransactionError::ApprovalRequired);
+        }
+
+        let mut new_tx = NewManualTransaction::builder();
+        new_tx.reference(reference);
 
-        let new_tx = NewManualTransaction::builder()
-            .id(manual_tx_id)
+        let mut db = self.repo.begin_op().await?;
+        let manual_transaction = self
+            .create_in_op(
+                &mut db,
+                new_tx,
+                ManualTransactionId::new(),
+                CalaTxId::new(),
+                description,
+                draft,
+                requires_approval,
+                sub,
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(manual_transaction)
+    }
+
+    /// Records the manual transaction and posts it right away, or holds its entries back as a
+    /// draft and starts the approval process when it requires approval.
+    #[allow(clippy::too_many_arguments)]
+    async fn create_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        mut new_tx: NewManualTransactionBuilder,
+        id: ManualTransactionId,
+        ledger_tx_id: CalaTxId,
+        description: String,
+        draft: ManualTransactionDraft,
+        requires_approval: bool,
+        initiated_by: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let new_tx = new_tx
+            .id(id)
             .ledger_transaction_id(ledger_tx_id)
             .description(description.clone())
-            .reference(reference)
+            .approval_process_id(requires_approval.then(|| id.into()))
+            .draft(requires_approval.then(|| draft.clone()))
             .build()
             .expect("Couldn't build new manual transaction");
 
-        let mut db = self.repo.begin_op().await?;
-        let manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+        if requires_approval {
+            self.governance
+                .start_process_in_op(
+                    &mut *op,
+                    id,
+                    id.to_string(),
+                    APPROVE_MANUAL_TRANSACTION_PROCESS,
+                )
+                .await?;
+        }
+        let manual_transaction = self.repo.create_in_op(&mut *op, new_tx).await?;
+
+        if !requires_approval {
+            self.ledger
+                .execute_in_op(
+                    op,
+                    ledger_tx_id,
+                    ManualTransactionParams {
+                        journal_id: self.journal_id,
+                        description,
+                        entry_params: entry_params(&draft.entries),
+                        effective: draft.effective,
+                        initiated_by,
+                    },
+                )
+                .await?;
+        }
+
+        Ok(manual_transaction)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.update_draft", skip(self, entries), fields(subject = %sub, chart_ref = %chart_ref, effective = %effective, entries_count = entries.len()))]
+    pub async fn update_draft(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_UPDATE,
+            )
+            .await?;
+
+        let draft = self
+            .validated_draft(sub, chart_ref, effective, entries)
+            .await?;
+
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        if let Some(approval_process_id) = manual_transaction.approval_process_id {
+            let has_votes = self
+                .governance
+                .find_all_approval_processes::<ApprovalProcess>(&[approval_process_id])
+                .await?
+                .remove(&approval_process_id)
+                .is_some_and(|process| {
+                    !process.approvers().is_empty() || !process.deniers().is_empty()
+                });
+            if has_votes {
+                return Err(ManualTransactionError::DraftHasVotes(id));
+            }
+        }
+
+        if manual_transaction
+            .update_draft(description, draft.effective, draft.entries)?
+            .did_execute()
+        {
+            self.repo.update(&mut manual_transaction).await?;
+        }
+
+        Ok(manual_transaction)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.conclude_approval_process", skip(self, op))]
+    pub(crate) async fn conclude_approval_process_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: ManualTransactionId,
+        approved: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                op,
+                MANUAL_TRANSACTION_APPROVAL,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        let mut manual_transaction = self.repo.find_by_id_in_op(&mut *op, id).await?;
+
+        let Idempotent::Executed(to_post) = manual_transaction.approval_process_concluded(approved)
+        else {
+            return Ok(manual_transaction);
+        };
+        self.repo
+            .update_in_op(&mut *op, &mut manual_transaction)
+            .await?;
+
+        if !approved {
+            self.void_reversal_link_in_op(op, &manual_transaction)
+                .await?;
+        }
 
-        let mut entry_params = vec![];
+        if let Some(draft) = to_post {
+            self.ledger
+                .execute_in_op(
+                    op,
+                    manual_transaction.ledger_transaction_id,
+                    ManualTransactionParams {
+                        journal_id: self.journal_id,
+                        description: manual_transaction.description.clone(),
+                        entry_params: entry_params(&draft.entries),
+                        effective: draft.effective,
+                        initiated_by:
+                            &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                                MANUAL_TRANSACTION_APPROVAL,
+                            ),
+                    },
+                )
+                .await?;
+        }
+
+        Ok(manual_transaction)
+    }
+
+    /// Marks an approved manual transaction whose entries the ledger rejected as failed, so
+    /// that it is not retried.
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.record_posting_failure", skip(self, op))]
+    pub(crate) async fn record_posting_failure_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: ManualTransactionId,
+        reason: String,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let mut manual_transaction = self.repo.find_by_id_in_op(&mut *op, id).await?;
+        if manual_transaction.posting_failed(reason).did_execute() {
+            self.repo
+                .update_in_op(&mut *op, &mut manual_transaction)
+                .await?;
+            self.void_reversal_link_in_op(op, &manual_transaction)
+                .await?;
+        }
+
+        Ok(manual_transaction)
+    }
+
+    /// Lets the original of a reversal that was not posted be reversed again.
+    async fn void_reversal_link_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        manual_transaction: &ManualTransaction,
+    ) -> Result<(), ManualTransactionError> {
+        let Some(original_id) = manual_transaction.reversal_of else {
+            return Ok(());
+        };
+        let mut original = self.repo.find_by_id_in_op(&mut *op, original_id).await?;
+        if original.void_reversal(manual_transaction.id).did_execute() {
+            self.repo.update_in_op(&mut *op, &mut original).await?;
+        }
+        Ok(())
+    }
+
+    async fn validated_draft(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransactionDraft, ManualTransactionError> {
+        let chart = self.chart_of_accounts.find_by_reference(chart_ref).await?;
+        let draft = ManualTransactionDraft {
+            effective,
+            entries: self.resolve_entries(sub, chart_ref, entries).await?,
+        };
+        draft.validate(chart.closed_as_of())?;
+        Ok(draft)
+    }
+
+    pub(crate) async fn resolve_entries(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<Vec<ManualTransactionEntry>, ManualTransactionError> {
+        let mut res = Vec::with_capacity(entries.len());
         for e in entries {
             let account_id = self
                 .chart_of_accounts
@@ -160,31 +492,261 @@ where
                     e.account_id_or_code,
                 )
                 .await?;
-            entry_params.push(EntryParams {
-                account_id: account_id.into(),
+            res.push(ManualTransactionEntry {
+                account_id,
                 amount: e.amount,
                 currency: e.currency,
                 direction: e.direction,
                 description: e.description,
             });
         }
+        Ok(res)
+    }
 
-        self.ledger
-            .execute_in_op(
-                &mut db,
-                ledger_tx_id,
-                ManualTransactionParams {
-                    journal_id: self.journal_id,
-                    description,
-                    entry_params,
-                    effective,
-                    initiated_by: sub,
-                },
+    /// Every manual transaction goes through governance approval, except USD entries whose
+    /// total debits are within the configured approval threshold.
+    async fn requires_approval(
+        &self,
+        entries: &[ManualTransactionEntry],
+    ) -> Result<bool, ManualTransactionError> {
+        let Some(threshold_cents) = self
+            .domain_configs
+            .get_without_audit::<ManualTransactionApprovalThresholdCents>()
+            .await?
+            .maybe_value()
+        else {
+            return Ok(true);
+        };
+
+        if entries.iter().any(|e| e.currency != CalaCurrency::USD) {
+            return Ok(true);
+        }
+        let total_debits: Decimal = entries
+            .iter()
+            .filter(|e| e.direction == DebitOrCredit::Debit)
+            .map(|e| e.amount)
+            .sum();
+        Ok(total_debits * Decimal::ONE_HUNDRED > Decimal::from(threshold_cents))
+    }
+
+    /// Reverses the posted manual transaction behind `ledger_transaction_id`. Like
+    /// [`Self::submit`], the reversal is posted right away only when it is within the
+    /// configured approval threshold.
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.reverse", skip(self), fields(subject = %sub))]
+    pub async fn reverse(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_transaction_id: CalaTxId,
+        effective: Option<chrono::NaiveDate>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transactions(),
+                CoreAccountingAction::MANUAL_TRANSACTION_REVERSE,
             )
             .await?;
 
+        let original = self
+            .repo
+            .maybe_find_by_ledger_transaction_id(ledger_transaction_id)
+            .await?
+            .ok_or(ManualTransactionError::NotAManualTransaction(
+                ledger_transaction_id,
+            ))?;
+
+        let mut db = self.repo.begin_op().await?;
+        let reversal = self
+            .reverse_in_op(&mut db, original.id, effective, sub)
+            .await?;
         db.commit().await?;
 
-        Ok(manual_transaction)
+        Ok(reversal)
+    }
+
+    /// Creates the manual transaction for one occurrence of a schedule. Like [`Self::submit`],
+    /// it is posted right away only when it is within the configured approval threshold.
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.execute_scheduled", skip(self, op, entries), fields(effective = %effective, entries_count = entries.len(), requires_approval = tracing::field::Empty))]
+    pub(crate) async fn execute_scheduled_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: ManualTransactionId,
+        reference: String,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: &[ManualTransactionEntry],
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let requires_approval = self.requires_approval(entries).await?;
+        tracing::Span::current().record("requires_approval", requires_approval);
+
+        let mut new_tx = NewManualTransaction::builder();
+        new_tx.reference(Some(reference));
+
+        self.create_in_op(
+            op,
+            new_tx,
+            id,
+            CalaTxId::new(),
+            description,
+            ManualTransactionDraft {
+                effective,
+                entries: entries.to_vec(),
+            },
+            requires_approval,
+            &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                MANUAL_TRANSACTION_SCHEDULE,
+            ),
+        )
+        .await
+    }
+
+    /// Reverses a scheduled accrual once it is posted. An accrual that was already reversed
+    /// by hand is left untouched.
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.reverse_scheduled", skip(self, op))]
+    pub(crate) async fn reverse_scheduled_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: ManualTransactionId,
+        effective: chrono::NaiveDate,
+    ) -> Result<ScheduledReversal, ManualTransactionError> {
+        let original = self.repo.find_by_id_in_op(&mut *op, id).await?;
+        if let Some((reversal_id, _)) = original.reversed_by() {
+            return Ok(ScheduledReversal::Reversed(reversal_id));
+        }
+        match original.status() {
+            ManualTransactionStatus::PendingApproval => {
+                return Ok(ScheduledReversal::PendingApproval);
+            }
+            ManualTransactionStatus::Denied | ManualTransactionStatus::Failed => {
+                return Ok(ScheduledReversal::Denied);
+            }
+            ManualTransactionStatus::Posted => {}
+        }
+
+        let reversal = self
+            .reverse_in_op(
+                op,
+                id,
+                Some(effective),
+                &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                    MANUAL_TRANSACTION_SCHEDULE,
+                ),
+            )
+            .await?;
+
+        Ok(ScheduledReversal::Reversed(reversal.id))
+    }
+
+    async fn reverse_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: ManualTransactionId,
+        effective: Option<chrono::NaiveDate>,
+        initiated_by: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let mut original = self.repo.find_by_id_in_op(&mut *op, id).await?;
+        if !original.is_posted() {
+            return Err(ManualTransactionError::NotPosted(id));
+        }
+
+        let reversal_ledger_tx_id = CalaTxId::new();
+        let reversal_id = ManualTransactionId::new();
+        if original
+            .reverse(reversal_id, reversal_ledger_tx_id)
+            .was_already_applied()
+        {
+            return Err(ManualTransactionError::AlreadyReversed(id));
+        }
+
+        let (original_effective, entries) = self
+            .ledger
+            .reversal_entries_for(original.ledger_transaction_id)
+            .await?;
+        let requires_approval = self.requires_approval(&entries).await?;
+        self.repo.update_in_op(&mut *op, &mut original).await?;
+
+        let mut new_tx = NewManualTransaction::builder();
+        new_tx
+            .reference(Some(format!("{}:reversal", original.reference)))
+            .reversal_of(Some(id));
+        self.create_in_op(
+            op,
+            new_tx,
+            reversal_id,
+            reversal_ledger_tx_id,
+            format!("Reversal of {}", original.reference),
+            ManualTransactionDraft {
+                effective: effective.unwrap_or(original_effective),
+                entries,
+            },
+            requires_approval,
+            initiated_by,
+        )
+        .await
     }
+
+    /// Ledger transactions of the transaction this one reverses and of its posted reversal.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction.find_reversal_links",
+        skip(self)
+    )]
+    pub async fn find_reversal_links(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_transaction_id: CalaTxId,
+    ) -> Result<ManualTransactionReversalLinks, ManualTransactionError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transactions(),
+                CoreAccountingAction::MANUAL_TRANSACTION_LIST,
+            )
+            .await?;
+
+        let Some(manual_transaction) = self
+            .repo
+            .maybe_find_by_ledger_transaction_id(ledger_transaction_id)
+            .await?
+        else {
+            return Ok(ManualTransactionReversalLinks::default());
+        };
+
+        let linked_ids: Vec<_> = manual_transaction
+            .reversal_of
+            .into_iter()
+            .chain(manual_transaction.reversed_by().map(|(id, _)| id))
+            .collect();
+        let mut linked: HashMap<ManualTransactionId, ManualTransaction> =
+            self.repo.find_all(&linked_ids).await?;
+        let mut posted_ledger_transaction_id = |id: Option<ManualTransactionId>| {
+            id.and_then(|id| linked.remove(&id))
+                .filter(|tx| tx.is_posted())
+                .map(|tx| tx.ledger_transaction_id)
+        };
+
+        Ok(ManualTransactionReversalLinks {
+            reversal_of: posted_ledger_transaction_id(manual_transaction.reversal_of),
+            reversed_by: posted_ledger_transaction_id(
+                manual_transaction.reversed_by().map(|(id, _)| id),
+            ),
+        })
+    }
+}
+
+fn entry_params(entries: &[ManualTransactionEntry]) -> Vec<EntryParams> {
+    entries
+        .iter()
+        .map(|e| EntryParams {
+            account_id: e.account_id.into(),
+            currency: e.currency,
+            amount: e.amount,
+            description: e.description.clone(),
+            direction: e.direction,
+            layer: Layer::Settled,
+        })
+        .collect()
 }
@@ -3,7 +3,7 @@ use derive_builder::Builder;
 use cala_ledger::{Currency, DebitOrCredit};
 use rust_decimal::Decimal;
 
-use crate::primitives::AccountIdOrCode;
+use crate::primitives::{AccountIdOrCode, CalaTxId};
 
 pub use cala_ledger::TransactionId as CalaTransactionId;
 
@@ -22,3 +22,20 @@ impl ManualEntryInput {
         ManualEntryInputBuilder::default()
     }
 }
+
+/// Outcome of auto-reversing a scheduled accrual.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ScheduledReversal {
+    Reversed(crate::primitives::ManualTransactionId),
+    /// The accrual is still awaiting approval; the reversal is retried later.
+    PendingApproval,
+    /// The accrual was denied or rejected by the ledger and never posted, so there is nothing
+    /// to reverse.
+    Denied,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ManualTransactionReversalLinks {
+    pub reversal_of: Option<CalaTxId>,
+    pub reversed_by: Option<CalaTxId>,
+}
@@ -0,0 +1,510 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::{
+    manual_transaction::ManualTransactionEntry,
+    primitives::{ManualTransactionId, ManualTransactionScheduleId},
+};
+
+use super::error::ManualTransactionScheduleError;
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum ManualTransactionScheduleFrequency {
+    /// Posted on every end-of-day time event.
+    Daily,
+    /// Posted on every end-of-month time event.
+    Monthly,
+}
+
+impl ManualTransactionScheduleFrequency {
+    /// First day of the period following the one ending on `period_end`.
+    pub fn next_period_start(&self, period_end: NaiveDate) -> NaiveDate {
+        period_end
+            .succ_opt()
+            .expect("period end is never the last representable date")
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum ManualTransactionScheduleStatus {
+    #[default]
+    Active,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ManualTransactionScheduleExecution {
+    pub period_end: NaiveDate,
+    pub manual_transaction_id: ManualTransactionId,
+    pub reverse_on: Option<NaiveDate>,
+    pub reversal_id: Option<ManualTransactionId>,
+    /// The occurrence was denied in approval and never posted, so there is nothing to reverse.
+    pub denied: bool,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ManualTransactionScheduleId")]
+pub enum ManualTransactionScheduleEvent {
+    Initialized {
+        id: ManualTransactionScheduleId,
+        reference: String,
+        description: String,
+        frequency: ManualTransactionScheduleFrequency,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        auto_reverse: bool,
+        entries: Vec<ManualTransactionEntry>,
+    },
+    Executed {
+        period_end: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+        reverse_on: Option<NaiveDate>,
+    },
+    ExecutionReversed {
+        manual_transaction_id: ManualTransactionId,
+        reversal_id: ManualTransactionId,
+    },
+    ExecutionDenied {
+        manual_transaction_id: ManualTransactionId,
+    },
+    Cancelled {},
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct ManualTransactionSchedule {
+    pub id: ManualTransactionScheduleId,
+    pub reference: String,
+    pub description: String,
+    pub frequency: ManualTransactionScheduleFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub auto_reverse: bool,
+    pub entries: Vec<ManualTransactionEntry>,
+    events: EntityEvents<ManualTransactionScheduleEvent>,
+}
+
+impl ManualTransactionSchedule {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for manual transaction schedule")
+    }
+
+    pub fn status(&self) -> ManualTransactionScheduleStatus {
+        if self
+            .events
+            .iter_all()
+            .any(|e| matches!(e, ManualTransactionScheduleEvent::Cancelled { .. }))
+        {
+            ManualTransactionScheduleStatus::Cancelled
+        } else {
+            ManualTransactionScheduleStatus::Active
+        }
+    }
+
+    pub fn executions(&self) -> Vec<ManualTransactionScheduleExecution> {
+        let mut executions: Vec<ManualTransactionScheduleExecution> = Vec::new();
+        for event in self.events.iter_all() {
+            match event {
+                ManualTransactionScheduleEvent::Executed {
+                    period_end,
+                    manual_transaction_id,
+                    reverse_on,
+                } => executions.push(ManualTransactionScheduleExecution {
+                    period_end: *period_end,
+                    manual_transaction_id: *manual_transaction_id,
+                    reverse_on: *reverse_on,
+                    reversal_id: None,
+                    denied: false,
+                }),
+                ManualTransactionScheduleEvent::ExecutionReversed {
+                    manual_transaction_id,
+                    reversal_id,
+                } => {
+                    if let Some(execution) = executions
+                        .iter_mut()
+                        .find(|e| e.manual_transaction_id == *manual_transaction_id)
+                    {
+                        execution.reversal_id = Some(*reversal_id);
+                    }
+                }
+                ManualTransactionScheduleEvent::ExecutionDenied {
+                    manual_transaction_id,
+                } => {
+                    if let Some(execution) = executions
+                        .iter_mut()
+                        .find(|e| e.manual_transaction_id == *manual_transaction_id)
+                    {
+                        execution.denied = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        executions
+    }
+
+    pub fn is_due(
+        &self,
+        frequency: ManualTransactionScheduleFrequency,
+        period_end: NaiveDate,
+    ) -> bool {
+        self.frequency == frequency
+            && self.status() == ManualTransactionScheduleStatus::Active
+            && self.start_date <= period_end
+            && self.end_date.is_none_or(|end_date| period_end <= end_date)
+    }
+
+    /// Executions whose auto-reversal falls on or before `day` and has not been posted yet.
+    pub fn reversals_due(&self, day: NaiveDate) -> Vec<(ManualTransactionId, NaiveDate)> {
+        self.executions()
+            .into_iter()
+            .filter(|e| e.reversal_id.is_none() && !e.denied)
+            .filter_map(|e| {
+                e.reverse_on
+                    .filter(|reverse_on| *reverse_on <= day)
+                    .map(|reverse_on| (e.manual_transaction_id, reverse_on))
+            })
+            .collect()
+    }
+
+    /// Reference of the manual transaction posted for the period ending on `period_end`.
+    pub(super) fn execution_reference(&self, period_end: NaiveDate) -> String {
+        format!("{}:{}", self.reference, period_end)
+    }
+
+    pub(super) fn record_execution(
+        &mut self,
+        period_end: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionScheduleEvent::Executed { period_end: existing, .. } if existing == &period_end
+        );
+
+        self.events.push(ManualTransactionScheduleEvent::Executed {
+            period_end,
+            manual_transaction_id,
+            reverse_on: self
+                .auto_reverse
+                .then(|| self.frequency.next_period_start(period_end)),
+        });
+
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn record_reversal(
+        &mut self,
+        manual_transaction_id: ManualTransactionId,
+        reversal_id: ManualTransactionId,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionScheduleEvent::ExecutionReversed { manual_transaction_id: existing, .. } if existing == &manual_transaction_id
+        );
+
+        self.events
+            .push(ManualTransactionScheduleEvent::ExecutionReversed {
+                manual_transaction_id,
+                reversal_id,
+            });
+
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn record_denial(
+        &mut self,
+        manual_transaction_id: ManualTransactionId,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionScheduleEvent::ExecutionDenied { manual_transaction_id: existing } if existing == &manual_transaction_id
+        );
+
+        self.events
+            .push(ManualTransactionScheduleEvent::ExecutionDenied {
+                manual_transaction_id,
+            });
+
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn cancel(&mut self) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: ManualTransactionScheduleEvent::Cancelled { .. }
+        );
+
+        self.events
+            .push(ManualTransactionScheduleEvent::Cancelled {});
+
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<ManualTransactionScheduleEvent> for ManualTransactionSchedule {
+    fn try_from_events(
+        events: EntityEvents<ManualTransactionScheduleEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = ManualTransactionScheduleBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                ManualTransactionScheduleEvent::Initialized {
+                    id,
+                    reference,
+                    description,
+                    frequency,
+                    start_date,
+                    end_date,
+                    auto_reverse,
+                    entries,
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .reference(reference.clone())
+                        .description(description.clone())
+                        .frequency(*frequency)
+                        .start_date(*start_date)
+                        .end_date(*end_date)
+                        .auto_reverse(*auto_reverse)
+                        .entries(entries.clone())
+                }
+                ManualTransactionScheduleEvent::Executed { .. } => {}
+                ManualTransactionScheduleEvent::ExecutionReversed { .. } => {}
+                ManualTransactionScheduleEvent::ExecutionDenied { .. } => {}
+                ManualTransactionScheduleEvent::Cancelled { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate", error = "ManualTransactionScheduleError"))]
+pub struct NewManualTransactionSchedule {
+    #[builder(setter(into))]
+    pub(super) id: ManualTransactionScheduleId,
+    reference: Option<String>,
+    description: String,
+    frequency: ManualTransactionScheduleFrequency,
+    start_date: NaiveDate,
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    #[builder(default)]
+    auto_reverse: bool,
+    entries: Vec<ManualTransactionEntry>,
+}
+
+impl NewManualTransactionScheduleBuilder {
+    fn validate(&self) -> Result<(), ManualTransactionScheduleError> {
+        if let (Some(start_date), Some(Some(end_date))) = (self.start_date, self.end_date)
+            && end_date < start_date
+        {
+            return Err(ManualTransactionScheduleError::InvalidDateRange(
+                start_date, end_date,
+            ));
+        }
+        if let Some(ref entries) = self.entries
+            && entries.is_empty()
+        {
+            return Err(ManualTransactionScheduleError::NoEntries);
+        }
+        Ok(())
+    }
+}
+
+impl NewManualTransactionSchedule {
+    pub fn builder() -> NewManualTransactionScheduleBuilder {
+        NewManualTransactionScheduleBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl IntoEvents<ManualTransactionScheduleEvent> for NewManualTransactionSchedule {
+    fn into_events(self) -> EntityEvents<ManualTransactionScheduleEvent> {
+        EntityEvents::init(
+            self.id,
+            [ManualTransactionScheduleEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                description: self.description,
+                frequency: self.frequency,
+                start_date: self.start_date,
+                end_date: self.end_date,
+                auto_reverse: self.auto_reverse,
+                entries: self.entries,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::primitives::{CalaCurrency, DebitOrCredit, LedgerAccountId};
+
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn entries() -> Vec<ManualTransactionEntry> {
+        let entry = |direction| ManualTransactionEntry {
+            account_id: LedgerAccountId::new(),
+            amount: dec!(1000),
+            currency: CalaCurrency::USD,
+            direction,
+            description: "Rent accrual".to_string(),
+        };
+        vec![entry(DebitOrCredit::Debit), entry(DebitOrCredit::Credit)]
+    }
+
+    fn schedule(auto_reverse: bool) -> ManualTransactionSchedule {
+        let new_schedule = NewManualTransactionSchedule::builder()
+            .id(ManualTransactionScheduleId::new())
+            .reference(Some("rent".to_string()))
+            .description("Monthly rent accrual".to_string())
+            .frequency(ManualTransactionScheduleFrequency::Monthly)
+            .start_date(date(2025, 1, 1))
+            .end_date(Some(date(2025, 6, 30)))
+            .auto_reverse(auto_reverse)
+            .entries(entries())
+            .build()
+            .unwrap();
+        ManualTransactionSchedule::try_from_events(new_schedule.into_events()).unwrap()
+    }
+
+    #[test]
+    fn rejects_end_date_before_start_date() {
+        let res = NewManualTransactionSchedule::builder()
+            .id(ManualTransactionScheduleId::new())
+            .reference(None)
+            .description("Depreciation".to_string())
+            .frequency(ManualTransactionScheduleFrequency::Monthly)
+            .start_date(date(2025, 6, 1))
+            .end_date(Some(date(2025, 5, 31)))
+            .entries(entries())
+            .build();
+        assert!(matches!(
+            res,
+            Err(ManualTransactionScheduleError::InvalidDateRange(..))
+        ));
+    }
+
+    #[test]
+    fn is_due_within_date_range_and_frequency() {
+        let schedule = schedule(false);
+        let monthly = ManualTransactionScheduleFrequency::Monthly;
+        assert!(!schedule.is_due(monthly, date(2024, 12, 31)));
+        assert!(schedule.is_due(monthly, date(2025, 1, 31)));
+        assert!(schedule.is_due(monthly, date(2025, 6, 30)));
+        assert!(!schedule.is_due(monthly, date(2025, 7, 31)));
+        assert!(!schedule.is_due(ManualTransactionScheduleFrequency::Daily, date(2025, 1, 31)));
+    }
+
+    #[test]
+    fn record_execution_is_idempotent_per_period() {
+        let mut schedule = schedule(false);
+        let period_end = date(2025, 1, 31);
+        assert!(
+            schedule
+                .record_execution(period_end, ManualTransactionId::new())
+                .did_execute()
+        );
+        assert!(
+            schedule
+                .record_execution(period_end, ManualTransactionId::new())
+                .was_already_applied()
+        );
+        assert!(
+            schedule
+                .record_execution(date(2025, 2, 28), ManualTransactionId::new())
+                .did_execute()
+        );
+        assert_eq!(schedule.executions().len(), 2);
+    }
+
+    #[test]
+    fn auto_reversal_falls_due_on_first_day_of_next_period() {
+        let mut schedule = schedule(true);
+        let manual_transaction_id = ManualTransactionId::new();
+        let _ = schedule.record_execution(date(2025, 1, 31), manual_transaction_id);
+
+        assert!(schedule.reversals_due(date(2025, 1, 31)).is_empty());
+        assert_eq!(
+            schedule.reversals_due(date(2025, 2, 1)),
+            vec![(manual_transaction_id, date(2025, 2, 1))]
+        );
+
+        let _ = schedule.record_reversal(manual_transaction_id, ManualTransactionId::new());
+        assert!(schedule.reversals_due(date(2025, 2, 1)).is_empty());
+    }
+
+    #[test]
+    fn denied_executions_are_never_reversed() {
+        let mut schedule = schedule(true);
+        let manual_transaction_id = ManualTransactionId::new();
+        let _ = schedule.record_execution(date(2025, 1, 31), manual_transaction_id);
+
+        assert!(schedule.record_denial(manual_transaction_id).did_execute());
+        assert!(
+            schedule
+                .record_denial(manual_transaction_id)
+                .was_already_applied()
+        );
+        assert!(schedule.reversals_due(date(2025, 2, 1)).is_empty());
+    }
+
+    #[test]
+    fn executions_without_auto_reverse_are_never_reversed() {
+        let mut schedule = schedule(false);
+        let _ = schedule.record_execution(date(2025, 1, 31), ManualTransactionId::new());
+        assert!(schedule.reversals_due(date(2025, 12, 31)).is_empty());
+    }
+
+    #[test]
+    fn cancelled_schedule_is_not_due() {
+        let mut schedule = schedule(false);
+        assert!(schedule.cancel().did_execute());
+        assert!(schedule.cancel().was_already_applied());
+        assert!(!schedule.is_due(
+            ManualTransactionScheduleFrequency::Monthly,
+            date(2025, 1, 31)
+        ));
+    }
+}
@@ -0,0 +1,53 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::manual_transaction::error::ManualTransactionError;
+
+use super::repo::{
+    ManualTransactionScheduleCreateError, ManualTransactionScheduleFindError,
+    ManualTransactionScheduleModifyError, ManualTransactionScheduleQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum ManualTransactionScheduleError {
+    #[error("ManualTransactionScheduleError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ManualTransactionScheduleError - Create: {0}")]
+    Create(#[from] ManualTransactionScheduleCreateError),
+    #[error("ManualTransactionScheduleError - Modify: {0}")]
+    Modify(#[from] ManualTransactionScheduleModifyError),
+    #[error("ManualTransactionScheduleError - Find: {0}")]
+    Find(#[from] ManualTransactionScheduleFindError),
+    #[error("ManualTransactionScheduleError - Query: {0}")]
+    Query(#[from] ManualTransactionScheduleQueryError),
+    #[error("ManualTransactionScheduleError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ManualTransactionScheduleError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] ManualTransactionError),
+    #[error("ManualTransactionScheduleError - UninitializedField: {0}")]
+    UninitializedField(#[from] derive_builder::UninitializedFieldError),
+    #[error(
+        "ManualTransactionScheduleError - InvalidDateRange: end date {1} is before start date {0}"
+    )]
+    InvalidDateRange(chrono::NaiveDate, chrono::NaiveDate),
+    #[error("ManualTransactionScheduleError - NoEntries")]
+    NoEntries,
+}
+
+impl ErrorSeverity for ManualTransactionScheduleError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::AuthorizationError(e) => e.severity(),
+            Self::ManualTransactionError(e) => e.severity(),
+            Self::UninitializedField(_) => Level::ERROR,
+            Self::InvalidDateRange(..) => Level::WARN,
+            Self::NoEntries => Level::WARN,
+        }
+    }
+}
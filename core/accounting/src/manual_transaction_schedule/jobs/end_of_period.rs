@@ -0,0 +1,61 @@
+use tracing::{Span, instrument};
+
+use core_time_events::CoreTimeEvent;
+use job::{JobId, JobType};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use super::process_schedules::{
+    ProcessManualTransactionSchedulesJobConfig, ProcessManualTransactionSchedulesJobSpawner,
+};
+use crate::manual_transaction_schedule::ManualTransactionScheduleFrequency;
+
+pub const MANUAL_TRANSACTION_SCHEDULE_END_OF_PERIOD: JobType =
+    JobType::new("outbox.manual-transaction-schedule-end-of-period");
+
+pub struct ManualTransactionScheduleEndOfPeriodHandler {
+    process_schedules: ProcessManualTransactionSchedulesJobSpawner,
+}
+
+impl ManualTransactionScheduleEndOfPeriodHandler {
+    pub fn new(process_schedules: ProcessManualTransactionSchedulesJobSpawner) -> Self {
+        Self { process_schedules }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for ManualTransactionScheduleEndOfPeriodHandler
+where
+    E: OutboxEventMarker<CoreTimeEvent>,
+{
+    #[instrument(name = "core_accounting.manual_transaction_schedule.end_of_period.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(e) = event.as_event() else {
+            return Ok(());
+        };
+        let config = match e {
+            CoreTimeEvent::EndOfDay { day, .. } => ProcessManualTransactionSchedulesJobConfig {
+                frequency: ManualTransactionScheduleFrequency::Daily,
+                period_end: *day,
+            },
+            CoreTimeEvent::EndOfMonth { month_end, .. } => {
+                ProcessManualTransactionSchedulesJobConfig {
+                    frequency: ManualTransactionScheduleFrequency::Monthly,
+                    period_end: *month_end,
+                }
+            }
+        };
+
+        event.inject_trace_parent();
+        Span::current().record("handled", true);
+        Span::current().record("event_type", e.as_ref());
+
+        self.process_schedules
+            .spawn_in_op(op, JobId::new(), config)
+            .await?;
+
+        Ok(())
+    }
+}
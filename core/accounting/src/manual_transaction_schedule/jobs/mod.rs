@@ -0,0 +1,2 @@
+pub mod end_of_period;
+pub mod process_schedules;
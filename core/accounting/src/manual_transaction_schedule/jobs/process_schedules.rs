@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use std::sync::Arc;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use obix::out::OutboxEventMarker;
+
+use crate::{
+    manual_transaction_schedule::{
+        ManualTransactionScheduleFrequency, ManualTransactionSchedules,
+        ManualTransactionSchedulesByCreatedAtCursor,
+    },
+    primitives::{CoreAccountingAction, CoreAccountingObject},
+};
+
+const PROCESS_MANUAL_TRANSACTION_SCHEDULES_JOB: JobType =
+    JobType::new("task.process-manual-transaction-schedules");
+const PAGE_SIZE: usize = 100;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProcessManualTransactionSchedulesJobConfig {
+    pub frequency: ManualTransactionScheduleFrequency,
+    pub period_end: chrono::NaiveDate,
+}
+
+pub struct ProcessManualTransactionSchedulesJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    schedules: Arc<ManualTransactionSchedules<Perms, E>>,
+}
+
+impl<Perms, E> ProcessManualTransactionSchedulesJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(schedules: Arc<ManualTransactionSchedules<Perms, E>>) -> Self {
+        Self { schedules }
+    }
+}
+
+impl<Perms, E> JobInitializer for ProcessManualTransactionSchedulesJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    type Config = ProcessManualTransactionSchedulesJobConfig;
+
+    fn job_type(&self) -> JobType {
+        PROCESS_MANUAL_TRANSACTION_SCHEDULES_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ProcessManualTransactionSchedulesJobRunner {
+            config: job.config()?,
+            schedules: self.schedules.clone(),
+        }))
+    }
+}
+
+pub struct ProcessManualTransactionSchedulesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    config: ProcessManualTransactionSchedulesJobConfig,
+    schedules: Arc<ManualTransactionSchedules<Perms, E>>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct ProcessManualTransactionSchedulesState {
+    after: Option<ManualTransactionSchedulesByCreatedAtCursor>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ProcessManualTransactionSchedulesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(
+        name = "core_accounting.manual_transaction_schedule.process_schedules_job",
+        skip(self, current_job),
+        fields(frequency = %self.config.frequency, period_end = %self.config.period_end)
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<ProcessManualTransactionSchedulesState>()?
+            .unwrap_or_default();
+
+        loop {
+            let es_entity::PaginatedQueryRet {
+                entities,
+                has_next_page,
+                end_cursor,
+            } = self
+                .schedules
+                .list_by_created_at_without_audit(es_entity::PaginatedQueryArgs {
+                    first: PAGE_SIZE,
+                    after: state.after.take(),
+                })
+                .await?;
+
+            for schedule in entities {
+                // Accruals from the previous period are reversed before the
+                // current period's entries are posted.
+                if self.config.frequency == ManualTransactionScheduleFrequency::Daily
+                    && !schedule.reversals_due(self.config.period_end).is_empty()
+                {
+                    self.schedules
+                        .reverse_due_executions(schedule.id, self.config.period_end)
+                        .await?;
+                }
+                if schedule.is_due(self.config.frequency, self.config.period_end) {
+                    self.schedules
+                        .execute_for_period(
+                            schedule.id,
+                            self.config.frequency,
+                            self.config.period_end,
+                        )
+                        .await?;
+                }
+            }
+
+            if !has_next_page {
+                break;
+            }
+            state.after = end_cursor;
+            current_job.update_execution_state(&state).await?;
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
+
+pub type ProcessManualTransactionSchedulesJobSpawner =
+    JobSpawner<ProcessManualTransactionSchedulesJobConfig>;
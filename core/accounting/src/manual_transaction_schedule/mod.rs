@@ -0,0 +1,331 @@
+mod entity;
+pub mod error;
+pub(crate) mod jobs;
+mod repo;
+
+use chrono::NaiveDate;
+use tracing::instrument;
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::clock::ClockHandle;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use obix::out::OutboxEventMarker;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    manual_transaction::{ManualEntryInput, ManualTransactions, ScheduledReversal},
+    primitives::{
+        CoreAccountingAction, CoreAccountingObject, MANUAL_TRANSACTION_SCHEDULE,
+        ManualTransactionId, ManualTransactionScheduleId,
+    },
+};
+use error::*;
+
+#[cfg(feature = "json-schema")]
+pub use entity::ManualTransactionScheduleEvent;
+pub(super) use entity::*;
+pub use entity::{
+    ManualTransactionSchedule, ManualTransactionScheduleExecution,
+    ManualTransactionScheduleFrequency, ManualTransactionScheduleStatus,
+};
+pub use repo::manual_transaction_schedule_cursor::ManualTransactionSchedulesByCreatedAtCursor;
+use repo::*;
+
+pub struct ManualTransactionSchedules<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    manual_transactions: ManualTransactions<Perms, E>,
+    repo: ManualTransactionScheduleRepo,
+}
+
+impl<Perms, E> Clone for ManualTransactionSchedules<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ManualTransactionSchedules<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        manual_transactions: &ManualTransactions<Perms, E>,
+        clock: ClockHandle,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            manual_transactions: manual_transactions.clone(),
+            repo: ManualTransactionScheduleRepo::new(pool, clock),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_schedule.find_by_id",
+        skip(self)
+    )]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ManualTransactionScheduleId> + std::fmt::Debug,
+    ) -> Result<Option<ManualTransactionSchedule>, ManualTransactionScheduleError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction_schedule(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_READ,
+            )
+            .await?;
+
+        Ok(self.repo.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.manual_transaction_schedule.list", skip(self))]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<ManualTransactionSchedulesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<
+            ManualTransactionSchedule,
+            ManualTransactionSchedulesByCreatedAtCursor,
+        >,
+        ManualTransactionScheduleError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transaction_schedules(),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_schedule.find_all",
+        skip(self)
+    )]
+    pub async fn find_all<T: From<ManualTransactionSchedule>>(
+        &self,
+        ids: &[ManualTransactionScheduleId],
+    ) -> Result<HashMap<ManualTransactionScheduleId, T>, ManualTransactionScheduleError> {
+        Ok(self.repo.find_all(ids).await?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.manual_transaction_schedule.create", skip(self, entries), fields(subject = %sub, chart_ref = %chart_ref, entries_count = entries.len()))]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        reference: Option<String>,
+        description: String,
+        frequency: ManualTransactionScheduleFrequency,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        auto_reverse: bool,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransactionSchedule, ManualTransactionScheduleError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transaction_schedules(),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_CREATE,
+            )
+            .await?;
+
+        let entries = self
+            .manual_transactions
+            .resolve_entries(sub, chart_ref, entries)
+            .await?;
+
+        let new_schedule = NewManualTransactionSchedule::builder()
+            .id(ManualTransactionScheduleId::new())
+            .reference(reference)
+            .description(description)
+            .frequency(frequency)
+            .start_date(start_date)
+            .end_date(end_date)
+            .auto_reverse(auto_reverse)
+            .entries(entries)
+            .build()?;
+
+        Ok(self.repo.create(new_schedule).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_schedule.cancel",
+        skip(self)
+    )]
+    pub async fn cancel(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ManualTransactionScheduleId> + std::fmt::Debug,
+    ) -> Result<ManualTransactionSchedule, ManualTransactionScheduleError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction_schedule(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_CANCEL,
+            )
+            .await?;
+
+        let mut schedule = self.repo.find_by_id(id).await?;
+        if schedule.cancel().did_execute() {
+            self.repo.update(&mut schedule).await?;
+        }
+
+        Ok(schedule)
+    }
+
+    pub(crate) async fn list_by_created_at_without_audit(
+        &self,
+        query: es_entity::PaginatedQueryArgs<ManualTransactionSchedulesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<
+            ManualTransactionSchedule,
+            ManualTransactionSchedulesByCreatedAtCursor,
+        >,
+        ManualTransactionScheduleError,
+    > {
+        Ok(self
+            .repo
+            .list_by_created_at(query, es_entity::ListDirection::Ascending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_schedule.execute_for_period",
+        skip(self)
+    )]
+    pub(crate) async fn execute_for_period(
+        &self,
+        id: ManualTransactionScheduleId,
+        frequency: ManualTransactionScheduleFrequency,
+        period_end: NaiveDate,
+    ) -> Result<(), ManualTransactionScheduleError> {
+        let mut db = self.repo.begin_op().await?;
+        let mut schedule = self.repo.find_by_id_in_op(&mut db, id).await?;
+        if !schedule.is_due(frequency, period_end) {
+            return Ok(());
+        }
+
+        let manual_transaction_id = ManualTransactionId::new();
+        if schedule
+            .record_execution(period_end, manual_transaction_id)
+            .was_already_applied()
+        {
+            return Ok(());
+        }
+
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut db,
+                MANUAL_TRANSACTION_SCHEDULE,
+                CoreAccountingObject::manual_transaction_schedule(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_EXECUTE,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        self.manual_transactions
+            .execute_scheduled_in_op(
+                &mut db,
+                manual_transaction_id,
+                schedule.execution_reference(period_end),
+                schedule.description.clone(),
+                period_end,
+                &schedule.entries,
+            )
+            .await?;
+        self.repo.update_in_op(&mut db, &mut schedule).await?;
+
+        db.commit().await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_schedule.reverse_due_executions",
+        skip(self)
+    )]
+    pub(crate) async fn reverse_due_executions(
+        &self,
+        id: ManualTransactionScheduleId,
+        day: NaiveDate,
+    ) -> Result<(), ManualTransactionScheduleError> {
+        let mut db = self.repo.begin_op().await?;
+        let mut schedule = self.repo.find_by_id_in_op(&mut db, id).await?;
+        let reversals_due = schedule.reversals_due(day);
+        if reversals_due.is_empty() {
+            return Ok(());
+        }
+
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut db,
+                MANUAL_TRANSACTION_SCHEDULE,
+                CoreAccountingObject::manual_transaction_schedule(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_SCHEDULE_EXECUTE,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        for (manual_transaction_id, reverse_on) in reversals_due {
+            match self
+                .manual_transactions
+                .reverse_scheduled_in_op(&mut db, manual_transaction_id, reverse_on)
+                .await?
+            {
+                ScheduledReversal::Reversed(reversal_id) => {
+                    let _ = schedule.record_reversal(manual_transaction_id, reversal_id);
+                }
+                ScheduledReversal::Denied => {
+                    let _ = schedule.record_denial(manual_transaction_id);
+                }
+                ScheduledReversal::PendingApproval => {}
+            }
+        }
+        self.repo.update_in_op(&mut db, &mut schedule).await?;
+
+        db.commit().await?;
+
+        Ok(())
+    }
+}
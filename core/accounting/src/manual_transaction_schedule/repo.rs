@@ -0,0 +1,37 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::ManualTransactionScheduleId;
+
+use super::entity::*;
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "ManualTransactionSchedule",
+    columns(reference(ty = "String", create(accessor = "reference()"))),
+    tbl_prefix = "core"
+)]
+pub struct ManualTransactionScheduleRepo {
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl Clone for ManualTransactionScheduleRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl ManualTransactionScheduleRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
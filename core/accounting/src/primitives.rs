@@ -6,6 +6,11 @@ use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 
 pub const ACCOUNTING_TRIAL_BALANCE: audit::SystemActor =
     audit::SystemActor::new("accounting-trial-balance");
+pub const MANUAL_TRANSACTION_APPROVAL: audit::SystemActor =
+    audit::SystemActor::new("manual-transaction-approval");
+pub const MANUAL_TRANSACTION_SCHEDULE: audit::SystemActor =
+    audit::SystemActor::new("manual-transaction-schedule");
+pub const BTC_REVALUATION: audit::SystemActor = audit::SystemActor::new("btc-revaluation");
 
 // Re-export everything from the primitives crate
 pub use chart_primitives::*;
@@ -17,11 +22,13 @@ pub use cala_ledger::{
     balance::{AccountBalance as CalaAccountBalance, BalanceRange as CalaBalanceRange},
 };
 
+pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
 pub use money::{Satoshis, UsdCents};
 
 es_entity::entity_id! {
     ChartNodeId,
     ManualTransactionId,
+    ManualTransactionScheduleId,
     LedgerAccountId,
     AccountingCsvId,
     FiscalYearId;
@@ -29,6 +36,7 @@ es_entity::entity_id! {
     LedgerAccountId => CalaAccountId,
     LedgerAccountId => CalaAccountSetId,
     AccountingCsvId => job::JobId,
+    ManualTransactionId => ApprovalProcessId,
 }
 
 impl From<cala_ledger::account_set::AccountSetMemberId> for LedgerAccountId {
@@ -163,8 +171,10 @@ pub type LedgerAccountAllOrOne = AllOrOne<LedgerAccountId>;
 pub type LedgerTransactionAllOrOne = AllOrOne<CalaTxId>;
 pub type TransactionTemplateAllOrOne = AllOrOne<TransactionTemplateId>;
 pub type ManualTransactionAllOrOne = AllOrOne<ManualTransactionId>;
+pub type ManualTransactionScheduleAllOrOne = AllOrOne<ManualTransactionScheduleId>;
 pub type ProfitAndLossAllOrOne = AllOrOne<LedgerAccountId>;
 pub type ProfitAndLossConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
+pub type CashFlowStatementAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type AccountingCsvAllOrOne = AllOrOne<AccountingCsvId>;
@@ -187,8 +197,10 @@ pub enum CoreAccountingAction {
     LedgerTransaction(LedgerTransactionAction),
     TransactionTemplate(TransactionTemplateAction),
     ManualTransaction(ManualTransactionAction),
+    ManualTransactionSchedule(ManualTransactionScheduleAction),
     ProfitAndLoss(ProfitAndLossAction),
     ProfitAndLossConfiguration(ProfitAndLossConfigurationAction),
+    CashFlowStatement(CashFlowStatementAction),
     BalanceSheet(BalanceSheetAction),
     BalanceSheetConfiguration(BalanceSheetConfigurationAction),
     AccountingCsv(AccountingCsvAction),
@@ -218,6 +230,11 @@ impl CoreAccountingAction {
                 ManualTransaction => {
                     map_action!(accounting, ManualTransaction, ManualTransactionAction)
                 }
+                ManualTransactionSchedule => map_action!(
+                    accounting,
+                    ManualTransactionSchedule,
+                    ManualTransactionScheduleAction
+                ),
                 ProfitAndLoss => {
                     map_action!(accounting, ProfitAndLoss, ProfitAndLossAction)
                 }
@@ -226,6 +243,9 @@ impl CoreAccountingAction {
                     ProfitAndLossConfiguration,
                     ProfitAndLossConfigurationAction
                 ),
+                CashFlowStatement => {
+                    map_action!(accounting, CashFlowStatement, CashFlowStatementAction)
+                }
                 BalanceSheet => {
                     map_action!(accounting, BalanceSheet, BalanceSheetAction)
                 }
@@ -258,8 +278,10 @@ pub enum CoreAccountingObject {
     LedgerTransaction(LedgerTransactionAllOrOne),
     TransactionTemplate(TransactionTemplateAllOrOne),
     ManualTransaction(ManualTransactionAllOrOne),
+    ManualTransactionSchedule(ManualTransactionScheduleAllOrOne),
     ProfitAndLoss(ProfitAndLossAllOrOne),
     ProfitAndLossConfiguration(ProfitAndLossConfigurationAllOrOne),
+    CashFlowStatement(CashFlowStatementAllOrOne),
     BalanceSheet(BalanceSheetAllOrOne),
     BalanceSheetConfiguration(BalanceSheetConfigurationAllOrOne),
     AccountingCsv(AccountingCsvAllOrOne),
@@ -312,6 +334,14 @@ impl CoreAccountingObject {
         CoreAccountingObject::ManualTransaction(AllOrOne::ById(id))
     }
 
+    pub fn all_manual_transaction_schedules() -> Self {
+        CoreAccountingObject::ManualTransactionSchedule(AllOrOne::All)
+    }
+
+    pub fn manual_transaction_schedule(id: ManualTransactionScheduleId) -> Self {
+        CoreAccountingObject::ManualTransactionSchedule(AllOrOne::ById(id))
+    }
+
     pub fn all_profit_and_loss() -> Self {
         CoreAccountingObject::ProfitAndLoss(AllOrOne::All)
     }
@@ -324,6 +354,10 @@ impl CoreAccountingObject {
         CoreAccountingObject::ProfitAndLossConfiguration(AllOrOne::All)
     }
 
+    pub fn all_cash_flow_statements() -> Self {
+        CoreAccountingObject::CashFlowStatement(AllOrOne::All)
+    }
+
     pub fn balance_sheet(id: LedgerAccountId) -> Self {
         CoreAccountingObject::BalanceSheet(AllOrOne::ById(id))
     }
@@ -366,8 +400,10 @@ impl Display for CoreAccountingObject {
             LedgerTransaction(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             TransactionTemplate(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ManualTransaction(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            ManualTransactionSchedule(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ProfitAndLoss(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ProfitAndLossConfiguration(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            CashFlowStatement(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             BalanceSheet(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             BalanceSheetConfiguration(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             AccountingCsv(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
@@ -414,6 +450,12 @@ impl FromStr for CoreAccountingObject {
                     .map_err(|_| "could not parse ManualTransaction")?;
                 CoreAccountingObject::ManualTransaction(obj_ref)
             }
+            ManualTransactionSchedule => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse ManualTransactionSchedule")?;
+                CoreAccountingObject::ManualTransactionSchedule(obj_ref)
+            }
             ProfitAndLoss => {
                 let obj_ref = id.parse().map_err(|_| "could not parse ProfitAndLoss")?;
                 CoreAccountingObject::ProfitAndLoss(obj_ref)
@@ -424,6 +466,12 @@ impl FromStr for CoreAccountingObject {
                     .map_err(|_| "could not parse ProfitAndLossConfiguration")?;
                 CoreAccountingObject::ProfitAndLossConfiguration(obj_ref)
             }
+            CashFlowStatement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CashFlowStatement")?;
+                CoreAccountingObject::CashFlowStatement(obj_ref)
+            }
             BalanceSheet => {
                 let obj_ref = id.parse().map_err(|_| "could not parse BalanceSheet")?;
                 CoreAccountingObject::BalanceSheet(obj_ref)
@@ -483,6 +531,24 @@ impl CoreAccountingAction {
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::Create);
     pub const MANUAL_TRANSACTION_LIST: Self =
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::List);
+    pub const MANUAL_TRANSACTION_REVERSE: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::Reverse);
+    pub const MANUAL_TRANSACTION_UPDATE: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::Update);
+    pub const MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::ConcludeApprovalProcess);
+
+    pub const MANUAL_TRANSACTION_SCHEDULE_READ: Self =
+        CoreAccountingAction::ManualTransactionSchedule(ManualTransactionScheduleAction::Read);
+    pub const MANUAL_TRANSACTION_SCHEDULE_LIST: Self =
+        CoreAccountingAction::ManualTransactionSchedule(ManualTransactionScheduleAction::List);
+    pub const MANUAL_TRANSACTION_SCHEDULE_CREATE: Self =
+        CoreAccountingAction::ManualTransactionSchedule(ManualTransactionScheduleAction::Create);
+    pub const MANUAL_TRANSACTION_SCHEDULE_CANCEL: Self =
+        CoreAccountingAction::ManualTransactionSchedule(ManualTransactionScheduleAction::Cancel);
+    pub const MANUAL_TRANSACTION_SCHEDULE_EXECUTE: Self =
+        CoreAccountingAction::ManualTransactionSchedule(ManualTransactionScheduleAction::Execute);
+
     pub const PROFIT_AND_LOSS_READ: Self =
         CoreAccountingAction::ProfitAndLoss(ProfitAndLossAction::Read);
     pub const PROFIT_AND_LOSS_CREATE: Self =
@@ -494,6 +560,13 @@ impl CoreAccountingAction {
     pub const PROFIT_AND_LOSS_CONFIGURATION_UPDATE: Self =
         CoreAccountingAction::ProfitAndLossConfiguration(ProfitAndLossConfigurationAction::Update);
 
+    pub const CASH_FLOW_STATEMENT_READ: Self =
+        CoreAccountingAction::CashFlowStatement(CashFlowStatementAction::Read);
+    pub const CASH_FLOW_STATEMENT_CREATE: Self =
+        CoreAccountingAction::CashFlowStatement(CashFlowStatementAction::Create);
+    pub const CASH_FLOW_STATEMENT_UPDATE: Self =
+        CoreAccountingAction::CashFlowStatement(CashFlowStatementAction::Update);
+
     pub const BALANCE_SHEET_READ: Self =
         CoreAccountingAction::BalanceSheet(BalanceSheetAction::Read);
     pub const BALANCE_SHEET_CREATE: Self =
@@ -539,8 +612,10 @@ impl Display for CoreAccountingAction {
             LedgerTransaction(action) => action.fmt(f),
             TransactionTemplate(action) => action.fmt(f),
             ManualTransaction(action) => action.fmt(f),
+            ManualTransactionSchedule(action) => action.fmt(f),
             ProfitAndLoss(action) => action.fmt(f),
             ProfitAndLossConfiguration(action) => action.fmt(f),
+            CashFlowStatement(action) => action.fmt(f),
             BalanceSheet(action) => action.fmt(f),
             BalanceSheetConfiguration(action) => action.fmt(f),
             AccountingCsv(action) => action.fmt(f),
@@ -574,12 +649,18 @@ impl FromStr for CoreAccountingAction {
             CoreAccountingActionDiscriminants::ManualTransaction => {
                 CoreAccountingAction::from(action.parse::<ManualTransactionAction>()?)
             }
+            CoreAccountingActionDiscriminants::ManualTransactionSchedule => {
+                CoreAccountingAction::from(action.parse::<ManualTransactionScheduleAction>()?)
+            }
             CoreAccountingActionDiscriminants::ProfitAndLoss => {
                 CoreAccountingAction::from(action.parse::<ProfitAndLossAction>()?)
             }
             CoreAccountingActionDiscriminants::ProfitAndLossConfiguration => {
                 CoreAccountingAction::from(action.parse::<ProfitAndLossConfigurationAction>()?)
             }
+            CoreAccountingActionDiscriminants::CashFlowStatement => {
+                CoreAccountingAction::from(action.parse::<CashFlowStatementAction>()?)
+            }
             CoreAccountingActionDiscriminants::BalanceSheet => {
                 CoreAccountingAction::from(action.parse::<BalanceSheetAction>()?)
             }
@@ -720,13 +801,18 @@ pub enum ManualTransactionAction {
     Read,
     Create,
     List,
+    Reverse,
+    Update,
+    ConcludeApprovalProcess,
 }
 
 impl ActionPermission for ManualTransactionAction {
     fn permission_set(&self) -> &'static str {
         match self {
             Self::List | Self::Read => PERMISSION_SET_ACCOUNTING_VIEWER,
-            Self::Create => PERMISSION_SET_ACCOUNTING_WRITER,
+            Self::Create | Self::Reverse | Self::Update | Self::ConcludeApprovalProcess => {
+                PERMISSION_SET_ACCOUNTING_WRITER
+            }
         }
     }
 }
@@ -737,6 +823,31 @@ impl From<ManualTransactionAction> for CoreAccountingAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ManualTransactionScheduleAction {
+    Read,
+    List,
+    Create,
+    Cancel,
+    Execute,
+}
+
+impl ActionPermission for ManualTransactionScheduleAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::List | Self::Read => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create | Self::Cancel | Self::Execute => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<ManualTransactionScheduleAction> for CoreAccountingAction {
+    fn from(action: ManualTransactionScheduleAction) -> Self {
+        CoreAccountingAction::ManualTransactionSchedule(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ProfitAndLossAction {
@@ -782,6 +893,29 @@ impl From<ProfitAndLossConfigurationAction> for CoreAccountingAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CashFlowStatementAction {
+    Read,
+    Create,
+    Update,
+}
+
+impl ActionPermission for CashFlowStatementAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create | Self::Update => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<CashFlowStatementAction> for CoreAccountingAction {
+    fn from(action: CashFlowStatementAction) -> Self {
+        CoreAccountingAction::CashFlowStatement(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum BalanceSheetAction {
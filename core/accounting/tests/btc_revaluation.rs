@@ -0,0 +1,283 @@
+mod helpers;
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cloud_storage::{Storage, config::StorageConfig};
+use document_storage::DocumentStorage;
+use es_entity::clock::ClockHandle;
+use job::{JobSvcConfig, Jobs};
+
+use cala_ledger::{CalaLedger, CalaLedgerConfig, Currency, DebitOrCredit};
+use core_accounting::{
+    AccountIdOrCode, BtcRevaluationAccountCode, BtcRevaluationAdjustmentAccountCode,
+    BtcRevaluationUnrealizedGainAccountCode, BtcRevaluationUnrealizedLossAccountCode,
+    BtcRevaluations, Chart, CoreAccounting, FiscalYear, FiscalYears, ManualEntryInput,
+    ManualTransactionStatus,
+};
+use core_price::{CorePriceEvent, PRICE_UPDATED_EVENT_TYPE, Price, PriceOfOneBTC};
+
+use helpers::{TestPerms, default_accounting_base_config, event::TestEvent};
+
+const BTC_REVALUATION_ACCOUNTS_CSV: &str = r#"
+11,,,BTC Collateral,,
+12,,,BTC Revaluation Adjustment,,
+21,,,BTC Collateral Held,,
+41,,,Unrealized Gain on BTC,,
+61,,,Unrealized Loss on BTC,,
+"#;
+
+#[tokio::test]
+async fn month_close_posts_revaluation_and_reverses_it() -> anyhow::Result<()> {
+    let test = setup_test(2019).await?;
+    let january_end = NaiveDate::from_ymd_opt(2019, 1, 31).unwrap();
+
+    test.post_btc(dec!(2)).await?;
+    test.fiscal_years
+        .record_month_end_btc_price(january_end, test.clock.now())
+        .await?;
+    let fiscal_year = test
+        .fiscal_years
+        .close_month(&DummySubject, test.fiscal_year.id)
+        .await?;
+
+    assert!(!fiscal_year.month_closures()[0].btc_revaluation_skipped);
+    // 2 BTC at 70,000 USD/BTC with no USD carrying amount
+    assert_eq!(
+        test.pl_period_activity(january_end.with_day(1).unwrap(), january_end)
+            .await?,
+        dec!(140000)
+    );
+    assert_eq!(
+        test.pl_period_activity(
+            NaiveDate::from_ymd_opt(2019, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2019, 2, 28).unwrap(),
+        )
+        .await?,
+        dec!(-140000)
+    );
+
+    test.jobs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn month_close_without_recorded_price_skips_revaluation() -> anyhow::Result<()> {
+    // Recorded month end prices apply to every open fiscal year the month belongs to,
+    // so this test uses a year no other test records a price for
+    let test = setup_test(2018).await?;
+    let january_end = NaiveDate::from_ymd_opt(2018, 1, 31).unwrap();
+
+    test.post_btc(dec!(2)).await?;
+    let fiscal_year = test
+        .fiscal_years
+        .close_month(&DummySubject, test.fiscal_year.id)
+        .await?;
+
+    assert!(fiscal_year.month_closures()[0].btc_revaluation_skipped);
+    assert_eq!(
+        test.pl_period_activity(january_end.with_day(1).unwrap(), january_end)
+            .await?,
+        Decimal::ZERO
+    );
+
+    test.jobs.shutdown().await?;
+    Ok(())
+}
+
+struct Test {
+    accounting: CoreAccounting<TestPerms, TestEvent>,
+    fiscal_years: FiscalYears<TestPerms>,
+    chart: Chart,
+    fiscal_year: FiscalYear,
+    clock: ClockHandle,
+    pl_statement_name: String,
+    jobs: Jobs,
+}
+
+impl Test {
+    async fn post_btc(&self, amount: Decimal) -> anyhow::Result<()> {
+        let entry = |code: &str, direction: DebitOrCredit| {
+            ManualEntryInput::builder()
+                .account_id_or_code(code.parse::<AccountIdOrCode>().unwrap())
+                .amount(amount)
+                .currency(Currency::BTC)
+                .direction(direction)
+                .description(format!("BTC {direction:?}"))
+                .build()
+                .unwrap()
+        };
+        // Non-USD manual transactions always go through the (auto-approving) approval process
+        let manual_transaction = self
+            .accounting
+            .submit_manual_transaction(
+                &DummySubject,
+                &self.chart.reference,
+                None,
+                "BTC collateral".to_string(),
+                self.fiscal_year.opened_as_of.with_day(10).unwrap(),
+                vec![
+                    entry("11", DebitOrCredit::Debit),
+                    entry("21", DebitOrCredit::Credit),
+                ],
+            )
+            .await?;
+
+        for _ in 0..100 {
+            let posted = self
+                .accounting
+                .manual_transactions()
+                .find_manual_transaction_by_id(&DummySubject, manual_transaction.id)
+                .await?
+                .is_some_and(|tx| tx.status() == ManualTransactionStatus::Posted);
+            if posted {
+                return Ok(());
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        anyhow::bail!("Timed out waiting for the BTC manual transaction to be approved")
+    }
+
+    async fn pl_period_activity(
+        &self,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> anyhow::Result<Decimal> {
+        Ok(self
+            .accounting
+            .profit_and_loss()
+            .pl_statement(&DummySubject, self.pl_statement_name.clone(), from, until)
+            .await?
+            .usd_balance_range
+            .and_then(|range| range.period_activity)
+            .map(|balance| balance.settled())
+            .unwrap_or(Decimal::ZERO))
+    }
+}
+
+async fn setup_test(year: i32) -> anyhow::Result<Test> {
+    use rand::RngExt;
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::manual();
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .clock(clock.clone())
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let authz = TestPerms::new();
+    let journal_id = helpers::init_journal(&cala).await?;
+    let outbox = helpers::init_outbox(&pool).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let mut jobs = Jobs::init(
+        JobSvcConfig::builder()
+            .pool(pool.clone())
+            .clock(clock.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let (exposed_domain_configs, domain_configs) =
+        helpers::init_domain_configs(&pool, &authz).await?;
+    // All tests configure the same codes, so concurrent modifications can be ignored
+    let _ = exposed_domain_configs
+        .update::<BtcRevaluationAccountCode>(&DummySubject, "11".to_string())
+        .await;
+    let _ = exposed_domain_configs
+        .update::<BtcRevaluationAdjustmentAccountCode>(&DummySubject, "12".to_string())
+        .await;
+    let _ = exposed_domain_configs
+        .update::<BtcRevaluationUnrealizedGainAccountCode>(&DummySubject, "41".to_string())
+        .await;
+    let _ = exposed_domain_configs
+        .update::<BtcRevaluationUnrealizedLossAccountCode>(&DummySubject, "61".to_string())
+        .await;
+
+    let price = Price::new(&outbox);
+    outbox
+        .publish_ephemeral(
+            PRICE_UPDATED_EVENT_TYPE,
+            CorePriceEvent::PriceUpdated {
+                price: PriceOfOneBTC::new(money::UsdCents::from(7_000_000)),
+                timestamp: chrono::Utc::now(),
+            },
+        )
+        .await?;
+    tokio::time::timeout(std::time::Duration::from_secs(5), price.usd_cents_per_btc())
+        .await
+        .map_err(|_| anyhow::anyhow!("Timed out waiting for BTC price to propagate"))?;
+
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone(), None);
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        document_storage,
+        &mut jobs,
+        &outbox,
+        &governance,
+        &domain_configs,
+        &price,
+    )
+    .await?;
+    jobs.start_poll().await?;
+    let btc_revaluations = BtcRevaluations::new(
+        &cala,
+        journal_id,
+        accounting.chart_of_accounts(),
+        &domain_configs,
+        &price,
+    );
+    let fiscal_years =
+        FiscalYears::new(&pool, clock.clone(), &authz, accounting.chart_of_accounts())
+            .with_btc_revaluations(&btc_revaluations);
+
+    let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
+    accounting
+        .chart_of_accounts()
+        .create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone())
+        .await?;
+
+    let (balance_sheet_name, pl_statement_name, trial_balance_name) =
+        helpers::create_test_statements(&accounting).await?;
+    let chart = accounting
+        .import_csv_with_base_config(
+            &DummySubject,
+            &chart_ref,
+            format!(
+                "{}{}",
+                helpers::BASE_ACCOUNTS_CSV,
+                BTC_REVALUATION_ACCOUNTS_CSV
+            ),
+            default_accounting_base_config(),
+            &balance_sheet_name,
+            &pl_statement_name,
+            &trial_balance_name,
+        )
+        .await?;
+
+    let fiscal_year = fiscal_years
+        .init_for_chart(
+            &DummySubject,
+            NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+            chart.id,
+        )
+        .await?;
+
+    Ok(Test {
+        accounting,
+        fiscal_years,
+        chart,
+        fiscal_year,
+        clock,
+        pl_statement_name,
+        jobs,
+    })
+}
@@ -0,0 +1,247 @@
+mod helpers;
+
+use authz::dummy::{DummyPerms, DummySubject};
+use cloud_storage::{Storage, config::StorageConfig};
+use document_storage::DocumentStorage;
+use es_entity::clock::ClockHandle;
+use job::{JobSvcConfig, Jobs};
+use rust_decimal::Decimal;
+
+use cala_ledger::{CalaLedger, CalaLedgerConfig, Currency, DebitOrCredit};
+use core_accounting::{
+    AccountIdOrCode, BalanceRange, CashFlowSection, Chart, CoreAccounting, ManualEntryInput,
+    cash_flow::{CashFlowStatementLine, error::CashFlowStatementError},
+    error::CoreAccountingError,
+};
+use helpers::{action, default_accounting_base_config, object};
+use rust_decimal_macros::dec;
+
+const CASH_FLOW_ACCOUNTS_CSV: &str = r#"
+11,,,Cash,,
+12,,,Loans Receivable,,
+21,,,Customer Deposits,,
+"#;
+
+#[tokio::test]
+async fn chart_node_can_only_be_mapped_once() -> anyhow::Result<()> {
+    let (accounting, chart, cash_flow_ref) = prepare_test().await?;
+    let map = |code: &str, section| {
+        accounting.add_chart_node_to_cash_flow_section(
+            &DummySubject,
+            &chart.reference,
+            &cash_flow_ref,
+            code.parse().unwrap(),
+            section,
+        )
+    };
+
+    map("11", CashFlowSection::Cash).await?;
+
+    let res = map("11", CashFlowSection::Operating).await;
+    assert!(matches!(
+        res,
+        Err(CoreAccountingError::CashFlowStatementError(
+            CashFlowStatementError::ChartNodeAlreadyMapped(_)
+        ))
+    ));
+
+    let res = map("1", CashFlowSection::Investing).await;
+    assert!(matches!(
+        res,
+        Err(CoreAccountingError::CashFlowStatementError(
+            CashFlowStatementError::ChartNodeAlreadyMapped(_)
+        ))
+    ));
+
+    let res = map("21", CashFlowSection::Cash).await;
+    assert!(matches!(
+        res,
+        Err(CoreAccountingError::CashFlowStatementError(
+            CashFlowStatementError::NotAnAssetAccount(_)
+        ))
+    ));
+
+    map("21", CashFlowSection::Financing).await?;
+    let res = map("21", CashFlowSection::Operating).await;
+    assert!(matches!(
+        res,
+        Err(CoreAccountingError::CashFlowStatementError(
+            CashFlowStatementError::ChartNodeAlreadyMapped(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cash_flow_statement_reconciles_to_cash() -> anyhow::Result<()> {
+    let (accounting, chart, cash_flow_ref) = prepare_test().await?;
+    for (code, section) in [
+        ("11", CashFlowSection::Cash),
+        ("12", CashFlowSection::Investing),
+        ("21", CashFlowSection::Financing),
+    ] {
+        accounting
+            .add_chart_node_to_cash_flow_section(
+                &DummySubject,
+                &chart.reference,
+                &cash_flow_ref,
+                code.parse().unwrap(),
+                section,
+            )
+            .await?;
+    }
+
+    accounting
+        .fiscal_year()
+        .init_for_chart(
+            &DummySubject,
+            "2021-01-01".parse::<chrono::NaiveDate>().unwrap(),
+            chart.id,
+        )
+        .await?;
+    let effective = chrono::NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+    for (debit, credit, amount) in [
+        ("11", "21", dec!(1000)),
+        ("12", "11", dec!(400)),
+        ("11", "4", dec!(50)),
+    ] {
+        accounting
+            .execute_manual_transaction(
+                &DummySubject,
+                &chart.reference,
+                None,
+                format!("{debit} / {credit}"),
+                effective,
+                vec![
+                    entry(debit, amount, DebitOrCredit::Debit),
+                    entry(credit, amount, DebitOrCredit::Credit),
+                ],
+            )
+            .await?;
+    }
+
+    let statement = accounting
+        .cash_flow_statements()
+        .cash_flow_statement(
+            &DummySubject,
+            cash_flow_ref,
+            chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+        )
+        .await?;
+
+    assert_eq!(period_usd(&statement.net_income), dec!(50));
+    assert_eq!(period_usd(&statement.operating_activities), dec!(50));
+    assert_eq!(period_usd(&statement.investing_activities), dec!(-400));
+    assert_eq!(period_usd(&statement.financing_activities), dec!(1000));
+
+    let net_change = settled(statement.usd_balance_range.as_ref(), |r| {
+        r.period_activity.as_ref()
+    });
+    let opening = settled(statement.cash.usd_balance_range.as_ref(), |r| {
+        r.open.as_ref()
+    });
+    let closing = settled(statement.cash.usd_balance_range.as_ref(), |r| {
+        r.close.as_ref()
+    });
+    assert_eq!(net_change, dec!(650));
+    assert_eq!(opening + net_change, closing);
+
+    Ok(())
+}
+
+fn entry(code: &str, amount: Decimal, direction: DebitOrCredit) -> ManualEntryInput {
+    ManualEntryInput::builder()
+        .account_id_or_code(code.parse::<AccountIdOrCode>().unwrap())
+        .amount(amount)
+        .currency(Currency::USD)
+        .direction(direction)
+        .description(format!("{code} {direction:?}"))
+        .build()
+        .unwrap()
+}
+
+fn period_usd(line: &CashFlowStatementLine) -> Decimal {
+    settled(line.usd_balance_range.as_ref(), |r| {
+        r.period_activity.as_ref()
+    })
+}
+
+fn settled<'a>(
+    range: Option<&'a BalanceRange>,
+    balance: impl Fn(&'a BalanceRange) -> Option<&'a cala_ledger::balance::AccountBalance>,
+) -> Decimal {
+    range
+        .and_then(balance)
+        .map(|balance| balance.settled())
+        .unwrap_or(Decimal::ZERO)
+}
+
+async fn prepare_test() -> anyhow::Result<(
+    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>, helpers::event::TestEvent>,
+    Chart,
+    String,
+)> {
+    use rand::RngExt;
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::manual();
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .clock(clock.clone())
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let journal_id = helpers::init_journal(&cala).await?;
+    let outbox = helpers::init_outbox(&pool).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let mut jobs = Jobs::init(
+        JobSvcConfig::builder()
+            .pool(pool.clone())
+            .clock(clock.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let accounting = helpers::init_accounting(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        document_storage,
+        &mut jobs,
+        &outbox,
+    )
+    .await?;
+    let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
+    accounting
+        .chart_of_accounts()
+        .create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone())
+        .await?;
+
+    let (balance_sheet_name, pl_name, tb_name) =
+        helpers::create_test_statements(&accounting).await?;
+    let cash_flow_ref = format!("CF-{:010}", rand::rng().random_range(0..10_000_000_000u64));
+    accounting
+        .cash_flow_statements()
+        .create_cash_flow_statement(cash_flow_ref.clone())
+        .await?;
+
+    let chart = accounting
+        .import_csv_with_base_config(
+            &DummySubject,
+            &chart_ref,
+            format!("{}{}", helpers::BASE_ACCOUNTS_CSV, CASH_FLOW_ACCOUNTS_CSV),
+            default_accounting_base_config(),
+            &balance_sheet_name,
+            &pl_name,
+            &tb_name,
+        )
+        .await?;
+
+    Ok((accounting, chart, cash_flow_ref))
+}
@@ -249,7 +249,7 @@ async fn setup_test() -> anyhow::Result<Test> {
     .await?;
 
     let fiscal_year_repo = FiscalYearRepo::new(&pool, clock.clone());
-    let accounting = CoreAccounting::new(
+    let accounting = helpers::init_accounting(
         &pool,
         &authz,
         &cala,
@@ -257,7 +257,8 @@ async fn setup_test() -> anyhow::Result<Test> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let _ = accounting
         .chart_of_accounts()
@@ -1,8 +1,22 @@
+#![allow(dead_code)] // Helper functions may not be used in all tests
+
+use authz::dummy::{DummyPerms, DummySubject};
 use cala_ledger::CalaLedger;
+use document_storage::DocumentStorage;
+use domain_config::{EncryptionConfig, ExposedDomainConfigs, ExposedDomainConfigsReadOnly};
+use job::Jobs;
 use obix::out::Outbox;
 use rand::RngExt;
 
-use core_accounting::{AccountingBaseConfig, CoreAccounting};
+use core_accounting::{
+    AccountingBaseConfig, CoreAccounting, ManualTransactionApprovalThresholdCents,
+};
+use core_price::Price;
+
+pub type TestPerms = DummyPerms<action::DummyAction, object::DummyObject>;
+
+// Manual transactions posted by the tests stay below this threshold
+const MANUAL_TRANSACTION_APPROVAL_THRESHOLD_CENTS: u64 = 100_000_000_000;
 
 pub async fn init_pool() -> anyhow::Result<sqlx::PgPool> {
     let pg_con = std::env::var("PG_CON").unwrap();
@@ -15,6 +29,58 @@ pub async fn init_outbox(pool: &sqlx::PgPool) -> anyhow::Result<Outbox<event::Te
     Ok(outbox)
 }
 
+pub async fn init_domain_configs(
+    pool: &sqlx::PgPool,
+    authz: &TestPerms,
+) -> anyhow::Result<(
+    ExposedDomainConfigs<TestPerms>,
+    ExposedDomainConfigsReadOnly,
+)> {
+    let (_, exposed_domain_configs, domain_configs) = domain_config::init(
+        pool,
+        authz,
+        EncryptionConfig::default(),
+        Vec::<(String, serde_json::Value)>::new(),
+    )
+    .await?;
+    // All tests configure the same threshold, so concurrent modifications can be ignored
+    let _ = exposed_domain_configs
+        .update::<ManualTransactionApprovalThresholdCents>(
+            &DummySubject,
+            MANUAL_TRANSACTION_APPROVAL_THRESHOLD_CENTS,
+        )
+        .await;
+    Ok((exposed_domain_configs, domain_configs))
+}
+
+pub async fn init_accounting(
+    pool: &sqlx::PgPool,
+    authz: &TestPerms,
+    cala: &CalaLedger,
+    journal_id: cala_ledger::JournalId,
+    document_storage: DocumentStorage,
+    jobs: &mut Jobs,
+    outbox: &Outbox<event::TestEvent>,
+) -> anyhow::Result<CoreAccounting<TestPerms, event::TestEvent>> {
+    let (_, domain_configs) = init_domain_configs(pool, authz).await?;
+    let governance = governance::Governance::new(pool, authz, outbox, jobs.clock().clone(), None);
+    let price = Price::new(outbox);
+    let accounting = CoreAccounting::init(
+        pool,
+        authz,
+        cala,
+        journal_id,
+        document_storage,
+        jobs,
+        outbox,
+        &governance,
+        &domain_configs,
+        &price,
+    )
+    .await?;
+    Ok(accounting)
+}
+
 pub async fn init_journal(cala: &CalaLedger) -> anyhow::Result<cala_ledger::JournalId> {
     use cala_ledger::journal::*;
 
@@ -61,10 +127,11 @@ pub async fn create_test_statements<Perms, E>(
 where
     Perms: authz::PermissionCheck,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Action:
-        From<core_accounting::CoreAccountingAction>,
+        From<core_accounting::CoreAccountingAction> + From<governance::GovernanceAction>,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Object:
-        From<core_accounting::CoreAccountingObject>,
-    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>,
+        From<core_accounting::CoreAccountingObject> + From<governance::GovernanceObject>,
+    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>
+        + obix::out::OutboxEventMarker<governance::GovernanceEvent>,
 {
     let bs = format!("BS-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let pl = format!("PL-{:010}", rand::rng().random_range(0..10_000_000_000u64));
@@ -87,7 +154,8 @@ where
 }
 
 pub mod action {
-    use core_accounting::CoreAccountingAction;
+    use core_accounting::{CoreAccountingAction, GovernanceAction};
+    use domain_config::DomainConfigAction;
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DummyAction;
@@ -98,6 +166,18 @@ pub mod action {
         }
     }
 
+    impl From<GovernanceAction> for DummyAction {
+        fn from(_: GovernanceAction) -> Self {
+            Self
+        }
+    }
+
+    impl From<DomainConfigAction> for DummyAction {
+        fn from(_: DomainConfigAction) -> Self {
+            Self
+        }
+    }
+
     impl std::fmt::Display for DummyAction {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "dummy")?;
@@ -115,7 +195,8 @@ pub mod action {
 }
 
 pub mod object {
-    use core_accounting::CoreAccountingObject;
+    use core_accounting::{CoreAccountingObject, GovernanceObject};
+    use domain_config::DomainConfigObject;
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DummyObject;
@@ -126,6 +207,18 @@ pub mod object {
         }
     }
 
+    impl From<GovernanceObject> for DummyObject {
+        fn from(_: GovernanceObject) -> Self {
+            Self
+        }
+    }
+
+    impl From<DomainConfigObject> for DummyObject {
+        fn from(_: DomainConfigObject) -> Self {
+            Self
+        }
+    }
+
     impl std::fmt::Display for DummyObject {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "Dummy")?;
@@ -144,12 +237,18 @@ pub mod object {
 
 pub mod event {
     use core_accounting::CoreAccountingEvent;
+    use core_price::CorePriceEvent;
+    use core_time_events::CoreTimeEvent;
+    use governance::GovernanceEvent;
     use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, Serialize, Deserialize, obix::OutboxEvent)]
+    #[derive(Debug, Serialize, Deserialize, obix::OutboxEvent)]
     #[serde(tag = "module")]
     pub enum TestEvent {
         Accounting(CoreAccountingEvent),
+        Governance(GovernanceEvent),
+        Price(CorePriceEvent),
+        CoreTimeEvent(CoreTimeEvent),
         #[serde(other)]
         Unknown,
     }
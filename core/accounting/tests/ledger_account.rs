@@ -7,7 +7,6 @@ use cala_ledger::{
     account_set::{AccountSetMemberId, NewAccountSet},
 };
 use cloud_storage::{Storage, config::StorageConfig};
-use core_accounting::CoreAccounting;
 use document_storage::DocumentStorage;
 use es_entity::clock::ClockHandle;
 use helpers::{action, default_accounting_base_config, object};
@@ -39,7 +38,7 @@ async fn ledger_account_ancestors() -> anyhow::Result<()> {
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let accounting = helpers::init_accounting(
         &pool,
         &authz,
         &cala,
@@ -47,7 +46,8 @@ async fn ledger_account_ancestors() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -195,7 +195,7 @@ async fn ledger_account_children() -> anyhow::Result<()> {
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let accounting = helpers::init_accounting(
         &pool,
         &authz,
         &cala,
@@ -203,7 +203,8 @@ async fn ledger_account_children() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -318,7 +319,7 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let accounting = helpers::init_accounting(
         &pool,
         &authz,
         &cala,
@@ -326,7 +327,8 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
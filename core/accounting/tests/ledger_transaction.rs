@@ -210,7 +210,7 @@ async fn prepare_test() -> anyhow::Result<(
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let accounting = helpers::init_accounting(
         &pool,
         &authz,
         &cala,
@@ -218,7 +218,8 @@ async fn prepare_test() -> anyhow::Result<(
         document_storage,
         &mut jobs,
         &outbox,
-    );
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -8,7 +8,6 @@ use document_storage::DocumentStorage;
 use es_entity::clock::ClockHandle;
 use job::{JobSvcConfig, Jobs};
 
-use core_accounting::*;
 use helpers::{BASE_ACCOUNTS_CSV, action, default_accounting_base_config, object};
 
 #[tokio::test]
@@ -39,7 +38,7 @@ async fn atomic_import_adds_accounts_to_trial_balance() -> anyhow::Result<()> {
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let accounting = helpers::init_accounting(
         &pool,
         &authz,
         &cala,
@@ -47,7 +46,8 @@ async fn atomic_import_adds_accounts_to_trial_balance() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+    )
+    .await?;
 
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
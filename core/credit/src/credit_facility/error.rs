@@ -99,6 +99,8 @@ pub enum CreditFacilityError {
     NoAccrualCycleInProgress,
     #[error("CreditFacilityError - DisbursalOnInactiveFacility")]
     DisbursalOnInactiveFacility,
+    #[error("CreditFacilityError - DisbursalExceedsUndrawnLimit: {0} > {1}")]
+    DisbursalExceedsUndrawnLimit(UsdCents, UsdCents),
     #[error("CreditFacilityError - PaymentOnClosedFacility")]
     PaymentOnClosedFacility,
 }
@@ -164,6 +166,7 @@ impl ErrorSeverity for CreditFacilityError {
             Self::CollateralError(e) => e.severity(),
             Self::NoAccrualCycleInProgress => Level::WARN,
             Self::DisbursalOnInactiveFacility => Level::WARN,
+            Self::DisbursalExceedsUndrawnLimit(_, _) => Level::WARN,
             Self::PaymentOnClosedFacility => Level::WARN,
         }
     }
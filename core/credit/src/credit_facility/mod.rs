@@ -482,6 +482,19 @@ where
             .await?)
     }
 
+    pub(super) async fn list_by_created_at_without_audit(
+        &self,
+        query: es_entity::PaginatedQueryArgs<CreditFacilitiesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<CreditFacility, CreditFacilitiesByCreatedAtCursor>,
+        CreditFacilityError,
+    > {
+        Ok(self
+            .repo
+            .list_by_created_at(query, ListDirection::Ascending)
+            .await?)
+    }
+
     pub(super) async fn list_by_collateralization_ratio_without_audit(
         &self,
         query: es_entity::PaginatedQueryArgs<CreditFacilitiesByCollateralizationRatioCursor>,
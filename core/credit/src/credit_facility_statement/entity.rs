@@ -0,0 +1,110 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use document_storage::DocumentId;
+use es_entity::*;
+
+use crate::primitives::*;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "CreditFacilityStatementId")]
+pub enum CreditFacilityStatementEvent {
+    Initialized {
+        id: CreditFacilityStatementId,
+        credit_facility_id: CreditFacilityId,
+        customer_id: CustomerId,
+        document_id: DocumentId,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct CreditFacilityStatement {
+    pub id: CreditFacilityStatementId,
+    pub credit_facility_id: CreditFacilityId,
+    pub customer_id: CustomerId,
+    pub document_id: DocumentId,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    events: EntityEvents<CreditFacilityStatementEvent>,
+}
+
+impl TryFromEvents<CreditFacilityStatementEvent> for CreditFacilityStatement {
+    fn try_from_events(
+        events: EntityEvents<CreditFacilityStatementEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = CreditFacilityStatementBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                CreditFacilityStatementEvent::Initialized {
+                    id,
+                    credit_facility_id,
+                    customer_id,
+                    document_id,
+                    period_start,
+                    period_end,
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .credit_facility_id(*credit_facility_id)
+                        .customer_id(*customer_id)
+                        .document_id(*document_id)
+                        .period_start(*period_start)
+                        .period_end(*period_end)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+impl CreditFacilityStatement {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewCreditFacilityStatement {
+    #[builder(setter(into))]
+    pub(super) id: CreditFacilityStatementId,
+    #[builder(setter(into))]
+    pub(super) credit_facility_id: CreditFacilityId,
+    #[builder(setter(into))]
+    pub(super) customer_id: CustomerId,
+    #[builder(setter(into))]
+    pub(super) document_id: DocumentId,
+    pub(super) period_start: NaiveDate,
+    pub(super) period_end: NaiveDate,
+}
+
+impl NewCreditFacilityStatement {
+    pub fn builder() -> NewCreditFacilityStatementBuilder {
+        NewCreditFacilityStatementBuilder::default()
+    }
+}
+
+impl IntoEvents<CreditFacilityStatementEvent> for NewCreditFacilityStatement {
+    fn into_events(self) -> EntityEvents<CreditFacilityStatementEvent> {
+        EntityEvents::init(
+            self.id,
+            [CreditFacilityStatementEvent::Initialized {
+                id: self.id,
+                credit_facility_id: self.credit_facility_id,
+                customer_id: self.customer_id,
+                document_id: self.document_id,
+                period_start: self.period_start,
+                period_end: self.period_end,
+            }],
+        )
+    }
+}
@@ -0,0 +1,54 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use super::repo::{
+    CreditFacilityStatementCreateError, CreditFacilityStatementFindError,
+    CreditFacilityStatementQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum CreditFacilityStatementError {
+    #[error("CreditFacilityStatementError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("CreditFacilityStatementError - Create: {0}")]
+    Create(#[from] CreditFacilityStatementCreateError),
+    #[error("CreditFacilityStatementError - Find: {0}")]
+    Find(#[from] CreditFacilityStatementFindError),
+    #[error("CreditFacilityStatementError - Query: {0}")]
+    Query(#[from] CreditFacilityStatementQueryError),
+    #[error("CreditFacilityStatementError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CreditFacilityStatementError - CreditFacilityHistoryError: {0}")]
+    CreditFacilityHistoryError(#[from] crate::history::error::CreditFacilityHistoryError),
+    #[error("CreditFacilityStatementError - RepaymentPlanError: {0}")]
+    RepaymentPlanError(#[from] crate::repayment_plan::error::CreditFacilityRepaymentPlanError),
+    #[error("CreditFacilityStatementError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("CreditFacilityStatementError - RenderingError: {0}")]
+    RenderingError(#[from] rendering::RenderingError),
+    #[error("CreditFacilityStatementError - DocumentNotReady: {0}")]
+    DocumentNotReady(crate::primitives::CreditFacilityStatementId),
+    #[error(
+        "CreditFacilityStatementError - BalanceUnderflow: history drives a balance negative on {0}"
+    )]
+    BalanceUnderflow(chrono::NaiveDate),
+}
+
+impl ErrorSeverity for CreditFacilityStatementError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::AuthorizationError(e) => e.severity(),
+            Self::CreditFacilityHistoryError(e) => e.severity(),
+            Self::RepaymentPlanError(e) => e.severity(),
+            Self::DocumentStorageError(e) => e.severity(),
+            Self::RenderingError(e) => e.severity(),
+            Self::DocumentNotReady(_) => Level::WARN,
+            Self::BalanceUnderflow(_) => Level::ERROR,
+        }
+    }
+}
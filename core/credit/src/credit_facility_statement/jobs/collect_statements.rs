@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_credit_collateral::{
+    CoreCreditCollateralAction, CoreCreditCollateralObject, public::CoreCreditCollateralEvent,
+};
+use core_custody::{CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject};
+use core_price::CorePriceEvent;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use obix::out::OutboxEventMarker;
+
+use super::generate_statement::GenerateCreditFacilityStatementConfig;
+use crate::{
+    CoreCreditAction, CoreCreditCollectionAction, CoreCreditCollectionEvent,
+    CoreCreditCollectionObject, CoreCreditEvent, CoreCreditObject,
+    credit_facility::{CreditFacilities, CreditFacilitiesByCreatedAtCursor, CreditFacility},
+    credit_facility_statement::CreditFacilityStatements,
+};
+
+const COLLECT_CREDIT_FACILITY_STATEMENTS_JOB: JobType =
+    JobType::new("task.collect-credit-facility-statements");
+const PAGE_SIZE: usize = 100;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectCreditFacilityStatementsConfig {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+}
+
+impl CollectCreditFacilityStatementsConfig {
+    fn covers(&self, facility: &CreditFacility) -> bool {
+        facility.activated_at.date_naive() <= self.period_end
+            && facility
+                .completed_at()
+                .is_none_or(|completed_at| completed_at.date_naive() >= self.period_start)
+    }
+}
+
+pub struct CollectCreditFacilityStatementsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    credit_facilities: Arc<CreditFacilities<Perms, E>>,
+    statements: Arc<CreditFacilityStatements<Perms>>,
+    generate_statement: JobSpawner<GenerateCreditFacilityStatementConfig>,
+}
+
+impl<Perms, E> CollectCreditFacilityStatementsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    pub fn new(
+        credit_facilities: Arc<CreditFacilities<Perms, E>>,
+        statements: Arc<CreditFacilityStatements<Perms>>,
+        generate_statement: JobSpawner<GenerateCreditFacilityStatementConfig>,
+    ) -> Self {
+        Self {
+            credit_facilities,
+            statements,
+            generate_statement,
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for CollectCreditFacilityStatementsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
+        + From<CoreCreditCollectionAction>
+        + From<CoreCreditCollateralAction>
+        + From<GovernanceAction>
+        + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
+        + From<CoreCreditCollectionObject>
+        + From<CoreCreditCollateralObject>
+        + From<GovernanceObject>
+        + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    type Config = CollectCreditFacilityStatementsConfig;
+
+    fn job_type(&self) -> JobType {
+        COLLECT_CREDIT_FACILITY_STATEMENTS_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(CollectCreditFacilityStatementsJobRunner {
+            config: job.config()?,
+            credit_facilities: self.credit_facilities.clone(),
+            statements: self.statements.clone(),
+            generate_statement: self.generate_statement.clone(),
+        }))
+    }
+}
+
+struct CollectCreditFacilityStatementsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    config: CollectCreditFacilityStatementsConfig,
+    credit_facilities: Arc<CreditFacilities<Perms, E>>,
+    statements: Arc<CreditFacilityStatements<Perms>>,
+    generate_statement: JobSpawner<GenerateCreditFacilityStatementConfig>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CollectCreditFacilityStatementsState {
+    after: Option<CreditFacilitiesByCreatedAtCursor>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for CollectCreditFacilityStatementsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
+        + From<CoreCreditCollectionAction>
+        + From<CoreCreditCollateralAction>
+        + From<GovernanceAction>
+        + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
+        + From<CoreCreditCollectionObject>
+        + From<CoreCreditCollateralObject>
+        + From<GovernanceObject>
+        + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    #[instrument(
+        name = "core_credit.collect_credit_facility_statements_job.run",
+        skip(self, current_job),
+        fields(period_end = %self.config.period_end)
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<CollectCreditFacilityStatementsState>()?
+            .unwrap_or_default();
+
+        loop {
+            let page = self
+                .credit_facilities
+                .list_by_created_at_without_audit(es_entity::PaginatedQueryArgs {
+                    first: PAGE_SIZE,
+                    after: state.after.take(),
+                })
+                .await?;
+
+            let mut op = current_job.begin_op().await?;
+            for facility in page
+                .entities
+                .iter()
+                .filter(|facility| self.config.covers(facility))
+            {
+                if let Some(statement) = self
+                    .statements
+                    .create_for_period_in_op(
+                        &mut op,
+                        facility.id,
+                        facility.customer_id,
+                        self.config.period_start,
+                        self.config.period_end,
+                    )
+                    .await?
+                {
+                    self.generate_statement
+                        .spawn_in_op(
+                            &mut op,
+                            JobId::from(statement.id),
+                            GenerateCreditFacilityStatementConfig {
+                                credit_facility_statement_id: statement.id,
+                            },
+                        )
+                        .await?;
+                }
+            }
+
+            state.after = page.end_cursor;
+            current_job
+                .update_execution_state_in_op(&mut op, &state)
+                .await?;
+            op.commit().await?;
+
+            if !page.has_next_page {
+                break;
+            }
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
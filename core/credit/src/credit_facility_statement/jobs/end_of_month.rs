@@ -0,0 +1,51 @@
+use tracing::{Span, instrument};
+
+use chrono::Datelike;
+use core_time_events::CoreTimeEvent;
+use job::{JobId, JobSpawner, JobType};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use super::collect_statements::CollectCreditFacilityStatementsConfig;
+
+pub const CREDIT_FACILITY_STATEMENT_END_OF_MONTH: JobType =
+    JobType::new("outbox.credit-facility-statement-end-of-month");
+
+pub struct CreditFacilityStatementEndOfMonthHandler {
+    collect_statements: JobSpawner<CollectCreditFacilityStatementsConfig>,
+}
+
+impl CreditFacilityStatementEndOfMonthHandler {
+    pub fn new(collect_statements: JobSpawner<CollectCreditFacilityStatementsConfig>) -> Self {
+        Self { collect_statements }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for CreditFacilityStatementEndOfMonthHandler
+where
+    E: OutboxEventMarker<CoreTimeEvent>,
+{
+    #[instrument(name = "core_credit.credit_facility_statement.end_of_month.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ CoreTimeEvent::EndOfMonth { month_end, .. }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.collect_statements
+                .spawn_in_op(
+                    op,
+                    JobId::new(),
+                    CollectCreditFacilityStatementsConfig {
+                        period_start: month_end.with_day(1).expect("day 1 is always valid"),
+                        period_end: *month_end,
+                    },
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
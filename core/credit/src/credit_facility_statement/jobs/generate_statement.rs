@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreCreditAction, CoreCreditObject, credit_facility_statement::CreditFacilityStatements,
+    primitives::CreditFacilityStatementId,
+};
+
+pub const GENERATE_CREDIT_FACILITY_STATEMENT_JOB: JobType =
+    JobType::new("task.generate-credit-facility-statement");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateCreditFacilityStatementConfig {
+    pub credit_facility_statement_id: CreditFacilityStatementId,
+}
+
+pub struct GenerateCreditFacilityStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    statements: Arc<CreditFacilityStatements<Perms>>,
+}
+
+impl<Perms> GenerateCreditFacilityStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(statements: Arc<CreditFacilityStatements<Perms>>) -> Self {
+        Self { statements }
+    }
+}
+
+impl<Perms> JobInitializer for GenerateCreditFacilityStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    type Config = GenerateCreditFacilityStatementConfig;
+
+    fn job_type(&self) -> JobType {
+        GENERATE_CREDIT_FACILITY_STATEMENT_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateCreditFacilityStatementJobRunner {
+            config: job.config()?,
+            statements: self.statements.clone(),
+        }))
+    }
+}
+
+struct GenerateCreditFacilityStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: GenerateCreditFacilityStatementConfig,
+    statements: Arc<CreditFacilityStatements<Perms>>,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for GenerateCreditFacilityStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "core_credit.generate_credit_facility_statement_job.run",
+        skip(self, _current_job),
+        fields(credit_facility_statement_id = %self.config.credit_facility_statement_id)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        self.statements
+            .generate_document(self.config.credit_facility_statement_id)
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
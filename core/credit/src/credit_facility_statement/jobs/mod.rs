@@ -0,0 +1,3 @@
+pub mod collect_statements;
+pub mod end_of_month;
+pub mod generate_statement;
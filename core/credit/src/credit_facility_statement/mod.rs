@@ -0,0 +1,265 @@
+mod entity;
+pub mod error;
+pub(crate) mod jobs;
+mod repo;
+mod summary;
+mod template;
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::{
+    DocumentStatus, DocumentStorage, DocumentType, GeneratedDocumentDownloadLink, ReferenceId,
+};
+
+use crate::{history::Histories, primitives::*, repayment_plan::RepaymentPlans};
+
+pub use entity::CreditFacilityStatement;
+pub(super) use entity::*;
+use error::CreditFacilityStatementError;
+pub(super) use repo::*;
+pub use summary::{
+    CreditFacilityStatementSummary, StatementAmount, StatementCvlPoint, StatementTransaction,
+    StatementTransactionType,
+};
+use template::{CREDIT_FACILITY_STATEMENT_TEMPLATE, CreditFacilityStatementData};
+
+#[cfg(feature = "json-schema")]
+pub use entity::CreditFacilityStatementEvent;
+
+const CREDIT_FACILITY_STATEMENT_DOCUMENT_TYPE: DocumentType =
+    DocumentType::new("credit_facility_statement");
+
+pub struct CreditFacilityStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: Arc<CreditFacilityStatementRepo>,
+    authz: Arc<Perms>,
+    histories: Arc<Histories<Perms>>,
+    repayment_plans: Arc<RepaymentPlans<Perms>>,
+    document_storage: DocumentStorage,
+    renderer: rendering::Renderer,
+}
+
+impl<Perms> Clone for CreditFacilityStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            authz: self.authz.clone(),
+            histories: self.histories.clone(),
+            repayment_plans: self.repayment_plans.clone(),
+            document_storage: self.document_storage.clone(),
+            renderer: self.renderer.clone(),
+        }
+    }
+}
+
+impl<Perms> CreditFacilityStatements<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: Arc<Perms>,
+        histories: Arc<Histories<Perms>>,
+        repayment_plans: Arc<RepaymentPlans<Perms>>,
+        document_storage: &DocumentStorage,
+        gotenberg_config: gotenberg::GotenbergConfig,
+        clock: es_entity::clock::ClockHandle,
+    ) -> Self {
+        Self {
+            repo: Arc::new(CreditFacilityStatementRepo::new(pool, clock)),
+            authz,
+            histories,
+            repayment_plans,
+            document_storage: document_storage.clone(),
+            renderer: rendering::Renderer::new(gotenberg_config),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.credit_facility_statements.list_for_credit_facility",
+        skip(self)
+    )]
+    pub async fn list_for_credit_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<CreditFacilityStatement>, CreditFacilityStatementError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        self.list_for_credit_facility_without_audit(credit_facility_id)
+            .await
+    }
+
+    pub(crate) async fn list_for_credit_facility_without_audit(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<CreditFacilityStatement>, CreditFacilityStatementError> {
+        let mut statements = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_credit_facility_id_by_created_at(
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+
+            statements.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(statements)
+    }
+
+    pub(crate) async fn find_by_id_without_audit(
+        &self,
+        id: CreditFacilityStatementId,
+    ) -> Result<CreditFacilityStatement, CreditFacilityStatementError> {
+        Ok(self.repo.find_by_id(id).await?)
+    }
+
+    /// Returns `None` when the facility already has a statement for the period, so that
+    /// replaying a month-end signal never produces duplicate documents.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.credit_facility_statements.create_for_period_in_op",
+        skip(self, op)
+    )]
+    pub(crate) async fn create_for_period_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        customer_id: CustomerId,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<Option<CreditFacilityStatement>, CreditFacilityStatementError> {
+        if self
+            .repo
+            .maybe_find_for_period_in_op(&mut *op, credit_facility_id, period_end)
+            .await?
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        let filename = format!(
+            "credit_facility_statement_{credit_facility_id}_{}.pdf",
+            period_end.format("%Y-%m")
+        );
+        let document = self
+            .document_storage
+            .create_in_op(
+                op,
+                filename,
+                "application/pdf",
+                ReferenceId::from(credit_facility_id),
+                CREDIT_FACILITY_STATEMENT_DOCUMENT_TYPE,
+            )
+            .await?;
+
+        let new_statement = NewCreditFacilityStatement::builder()
+            .id(CreditFacilityStatementId::new())
+            .credit_facility_id(credit_facility_id)
+            .customer_id(customer_id)
+            .document_id(document.id)
+            .period_start(period_start)
+            .period_end(period_end)
+            .build()
+            .expect("could not build new credit facility statement");
+
+        let statement = self.repo.create_in_op(op, new_statement).await?;
+        Ok(Some(statement))
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.credit_facility_statements.generate_document",
+        skip(self)
+    )]
+    pub(crate) async fn generate_document(
+        &self,
+        id: CreditFacilityStatementId,
+    ) -> Result<(), CreditFacilityStatementError> {
+        let statement = self.repo.find_by_id(id).await?;
+        let mut document = self
+            .document_storage
+            .find_by_id(statement.document_id)
+            .await?;
+        if document.status == DocumentStatus::Active {
+            return Ok(());
+        }
+
+        let history = self
+            .histories
+            .find_for_credit_facility_id_without_audit(statement.credit_facility_id)
+            .await?;
+        let repayment_plan = self
+            .repayment_plans
+            .find_for_credit_facility_id_without_audit(statement.credit_facility_id)
+            .await?;
+        let summary = CreditFacilityStatementSummary::build(
+            statement.period_start,
+            statement.period_end,
+            history,
+            repayment_plan,
+        )?;
+
+        let content = self.renderer.render_template_to_markdown(
+            CREDIT_FACILITY_STATEMENT_TEMPLATE,
+            &CreditFacilityStatementData::new(&statement, summary),
+        )?;
+        let pdf_bytes = self.renderer.render_template_to_pdf(&content).await?;
+
+        self.document_storage
+            .upload(pdf_bytes, &mut document)
+            .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn generate_download_link_without_audit(
+        &self,
+        statement: &CreditFacilityStatement,
+    ) -> Result<GeneratedDocumentDownloadLink, CreditFacilityStatementError> {
+        let document = self
+            .document_storage
+            .find_by_id(statement.document_id)
+            .await?;
+        if document.status != DocumentStatus::Active {
+            return Err(CreditFacilityStatementError::DocumentNotReady(statement.id));
+        }
+
+        Ok(self
+            .document_storage
+            .generate_download_link(statement.document_id)
+            .await?)
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::NaiveDate;
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::entity::*;
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "CreditFacilityStatement",
+    columns(
+        credit_facility_id(
+            ty = "CreditFacilityId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        period_end(ty = "NaiveDate", update(persist = false)),
+    ),
+    tbl_prefix = "core"
+)]
+pub(crate) struct CreditFacilityStatementRepo {
+    pool: PgPool,
+    #[allow(dead_code)]
+    clock: ClockHandle,
+}
+
+impl CreditFacilityStatementRepo {
+    pub(crate) fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+
+    #[tracing::instrument(
+        name = "credit_facility_statement.maybe_find_for_period_in_op",
+        skip_all
+    )]
+    pub async fn maybe_find_for_period_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        credit_facility_id: CreditFacilityId,
+        period_end: NaiveDate,
+    ) -> Result<Option<CreditFacilityStatement>, CreditFacilityStatementFindError> {
+        Ok(es_query!(
+            tbl_prefix = "core",
+            r#"
+                SELECT id FROM core_credit_facility_statements
+                WHERE credit_facility_id = $1 AND period_end = $2"#,
+            credit_facility_id as CreditFacilityId,
+            period_end
+        )
+        .fetch_optional(op)
+        .await?)
+    }
+}
+
+impl From<(CreditFacilityStatementsSortBy, &CreditFacilityStatement)>
+    for credit_facility_statement_cursor::CreditFacilityStatementsCursor
+{
+    fn from(
+        statement_with_sort: (CreditFacilityStatementsSortBy, &CreditFacilityStatement),
+    ) -> Self {
+        let (sort, statement) = statement_with_sort;
+        match sort {
+            CreditFacilityStatementsSortBy::CreatedAt => {
+                credit_facility_statement_cursor::CreditFacilityStatementsByCreatedAtCursor::from(
+                    statement,
+                )
+                .into()
+            }
+            CreditFacilityStatementsSortBy::Id => {
+                credit_facility_statement_cursor::CreditFacilityStatementsByIdCursor::from(
+                    statement,
+                )
+                .into()
+            }
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use core_credit_collateral::CollateralDirection;
+
+use crate::{history::*, primitives::*, repayment_plan::*};
+
+use super::error::CreditFacilityStatementError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum StatementTransactionType {
+    Disbursal,
+    InterestAccrual,
+    Payment,
+    CollateralDeposit,
+    CollateralWithdrawal,
+    CollateralLiquidated,
+    LiquidationProceeds,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StatementAmount {
+    Usd(UsdCents),
+    Btc(Satoshis),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StatementTransaction {
+    pub effective: NaiveDate,
+    pub transaction_type: StatementTransactionType,
+    pub amount: StatementAmount,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StatementCvlPoint {
+    pub effective: NaiveDate,
+    pub price: PriceOfOneBTC,
+    pub collateral: Satoshis,
+    pub outstanding: UsdCents,
+    pub cvl: CVLPct,
+    pub state: CollateralizationState,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityStatementSummary {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+
+    pub opening_outstanding: UsdCents,
+    pub closing_outstanding: UsdCents,
+    pub opening_collateral: Satoshis,
+    pub closing_collateral: Satoshis,
+
+    pub total_disbursed: UsdCents,
+    pub total_interest_accrued: UsdCents,
+    pub total_paid: UsdCents,
+
+    pub transactions: Vec<StatementTransaction>,
+    pub cvl_history: Vec<StatementCvlPoint>,
+    pub unpaid_obligations: Vec<CreditFacilityRepaymentPlanEntry>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Balances {
+    outstanding: UsdCents,
+    collateral: Satoshis,
+}
+
+impl Balances {
+    /// Liquidation proceeds are settled through a regular payment and collateral sent out
+    /// to a liquidation is also recorded as a collateral removal, so those entries only
+    /// appear as statement lines and never move the running balances.
+    fn apply(
+        &mut self,
+        entry: &CreditFacilityHistoryEntry,
+    ) -> Result<(), CreditFacilityStatementError> {
+        let underflow = || CreditFacilityStatementError::BalanceUnderflow(effective_date(entry));
+        match entry {
+            CreditFacilityHistoryEntry::Disbursal(e) => self.outstanding += e.cents,
+            CreditFacilityHistoryEntry::Interest(e) => self.outstanding += e.cents,
+            CreditFacilityHistoryEntry::Payment(e) => {
+                self.outstanding = self
+                    .outstanding
+                    .checked_sub(e.cents)
+                    .ok_or_else(underflow)?
+            }
+            CreditFacilityHistoryEntry::Collateral(e) => match e.direction {
+                CollateralDirection::Add => self.collateral += e.satoshis,
+                CollateralDirection::Remove => {
+                    self.collateral = self
+                        .collateral
+                        .checked_sub(e.satoshis)
+                        .ok_or_else(underflow)?
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+fn effective_date(entry: &CreditFacilityHistoryEntry) -> NaiveDate {
+    sort_key(entry).0
+}
+
+fn sort_key(entry: &CreditFacilityHistoryEntry) -> (NaiveDate, DateTime<Utc>) {
+    match entry {
+        CreditFacilityHistoryEntry::Approved(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::Collateral(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::Liquidation(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::Repayment(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::Collateralization(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::PendingCreditFacilityCollateralization(e) => {
+            (e.effective, e.recorded_at)
+        }
+        CreditFacilityHistoryEntry::Payment(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::Disbursal(e) => (e.effective, e.recorded_at),
+        CreditFacilityHistoryEntry::Interest(e) => (e.effective, e.recorded_at),
+    }
+}
+
+fn transaction_for(entry: &CreditFacilityHistoryEntry) -> Option<StatementTransaction> {
+    let (transaction_type, amount) = match entry {
+        CreditFacilityHistoryEntry::Disbursal(e) => (
+            StatementTransactionType::Disbursal,
+            StatementAmount::Usd(e.cents),
+        ),
+        CreditFacilityHistoryEntry::Interest(e) => (
+            StatementTransactionType::InterestAccrual,
+            StatementAmount::Usd(e.cents),
+        ),
+        CreditFacilityHistoryEntry::Payment(e) => (
+            StatementTransactionType::Payment,
+            StatementAmount::Usd(e.cents),
+        ),
+        CreditFacilityHistoryEntry::Repayment(e) => (
+            StatementTransactionType::LiquidationProceeds,
+            StatementAmount::Usd(e.cents),
+        ),
+        CreditFacilityHistoryEntry::Collateral(e) => (
+            match e.direction {
+                CollateralDirection::Add => StatementTransactionType::CollateralDeposit,
+                CollateralDirection::Remove => StatementTransactionType::CollateralWithdrawal,
+            },
+            StatementAmount::Btc(e.satoshis),
+        ),
+        CreditFacilityHistoryEntry::Liquidation(e) => (
+            StatementTransactionType::CollateralLiquidated,
+            StatementAmount::Btc(e.amount),
+        ),
+        _ => return None,
+    };
+    Some(StatementTransaction {
+        effective: effective_date(entry),
+        transaction_type,
+        amount,
+    })
+}
+
+impl CreditFacilityStatementSummary {
+    pub fn build(
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        mut history: Vec<CreditFacilityHistoryEntry>,
+        repayment_plan: Vec<CreditFacilityRepaymentPlanEntry>,
+    ) -> Result<Self, CreditFacilityStatementError> {
+        history.sort_by_key(sort_key);
+
+        let mut opening = Balances::default();
+        let mut closing = Balances::default();
+        let mut transactions = Vec::new();
+        let mut cvl_history = Vec::new();
+        let mut total_disbursed = UsdCents::ZERO;
+        let mut total_interest_accrued = UsdCents::ZERO;
+        let mut total_paid = UsdCents::ZERO;
+
+        for entry in history.iter() {
+            let effective = effective_date(entry);
+            if effective > period_end {
+                break;
+            }
+            closing.apply(entry)?;
+            if effective < period_start {
+                opening.apply(entry)?;
+                continue;
+            }
+
+            match entry {
+                CreditFacilityHistoryEntry::Disbursal(e) => total_disbursed += e.cents,
+                CreditFacilityHistoryEntry::Interest(e) => total_interest_accrued += e.cents,
+                CreditFacilityHistoryEntry::Payment(e) => total_paid += e.cents,
+                CreditFacilityHistoryEntry::Collateralization(e) => {
+                    let outstanding = e.outstanding_disbursal + e.outstanding_interest;
+                    cvl_history.push(StatementCvlPoint {
+                        effective,
+                        price: e.price,
+                        collateral: e.collateral,
+                        outstanding,
+                        cvl: CVLPct::from_loan_amounts(
+                            e.price.sats_to_cents_round_down(e.collateral),
+                            outstanding,
+                        ),
+                        state: e.state,
+                    });
+                }
+                _ => (),
+            }
+            transactions.extend(transaction_for(entry));
+        }
+
+        let unpaid_obligations = repayment_plan
+            .into_iter()
+            .filter(|entry| entry.status != RepaymentStatus::Paid)
+            .collect();
+
+        Ok(Self {
+            period_start,
+            period_end,
+            opening_outstanding: opening.outstanding,
+            closing_outstanding: closing.outstanding,
+            opening_collateral: opening.collateral,
+            closing_collateral: closing.collateral,
+            total_disbursed,
+            total_interest_accrued,
+            total_paid,
+            transactions,
+            cvl_history,
+            unpaid_obligations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        format!("{s}T12:00:00Z").parse().unwrap()
+    }
+
+    fn disbursal(effective: &str, cents: u64) -> CreditFacilityHistoryEntry {
+        CreditFacilityHistoryEntry::Disbursal(DisbursalExecuted {
+            cents: UsdCents::from(cents),
+            recorded_at: at(effective),
+            effective: date(effective),
+            tx_id: LedgerTxId::new(),
+        })
+    }
+
+    fn interest(effective: &str, cents: u64) -> CreditFacilityHistoryEntry {
+        CreditFacilityHistoryEntry::Interest(InterestAccrualsPosted {
+            cents: UsdCents::from(cents),
+            recorded_at: at(effective),
+            effective: date(effective),
+            days: 31,
+            tx_id: LedgerTxId::new(),
+        })
+    }
+
+    fn payment(effective: &str, cents: u64) -> CreditFacilityHistoryEntry {
+        CreditFacilityHistoryEntry::Payment(IncrementalPayment {
+            cents: UsdCents::from(cents),
+            recorded_at: at(effective),
+            effective: date(effective),
+            payment_id: PaymentAllocationId::new(),
+        })
+    }
+
+    fn collateral(
+        effective: &str,
+        sats: u64,
+        direction: CollateralDirection,
+    ) -> CreditFacilityHistoryEntry {
+        CreditFacilityHistoryEntry::Collateral(CollateralUpdated {
+            satoshis: Satoshis::from(sats),
+            recorded_at: at(effective),
+            effective: date(effective),
+            direction,
+            tx_id: LedgerTxId::new(),
+        })
+    }
+
+    #[test]
+    fn splits_balances_at_period_boundaries() {
+        let history = vec![
+            payment("2025-02-10", 30_000),
+            collateral("2025-01-05", 100_000_000, CollateralDirection::Add),
+            disbursal("2025-01-10", 100_000),
+            interest("2025-01-31", 1_000),
+            interest("2025-02-28", 900),
+            collateral("2025-02-15", 10_000_000, CollateralDirection::Remove),
+            disbursal("2025-03-02", 50_000),
+        ];
+
+        let summary = CreditFacilityStatementSummary::build(
+            date("2025-02-01"),
+            date("2025-02-28"),
+            history,
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(summary.opening_outstanding, UsdCents::from(101_000));
+        assert_eq!(summary.closing_outstanding, UsdCents::from(71_900));
+        assert_eq!(summary.opening_collateral, Satoshis::from(100_000_000));
+        assert_eq!(summary.closing_collateral, Satoshis::from(90_000_000));
+        assert_eq!(summary.total_disbursed, UsdCents::ZERO);
+        assert_eq!(summary.total_interest_accrued, UsdCents::from(900));
+        assert_eq!(summary.total_paid, UsdCents::from(30_000));
+        assert_eq!(summary.transactions.len(), 3);
+        assert_eq!(summary.transactions[0].effective, date("2025-02-10"));
+    }
+
+    #[test]
+    fn records_cvl_history_within_period() {
+        let history = vec![CreditFacilityHistoryEntry::Collateralization(
+            CollateralizationUpdated {
+                state: CollateralizationState::UnderMarginCallThreshold,
+                collateral: Satoshis::from(100_000_000),
+                outstanding_interest: UsdCents::ZERO,
+                outstanding_disbursal: UsdCents::from(5_000_000),
+                recorded_at: at("2025-02-20"),
+                effective: date("2025-02-20"),
+                price: PriceOfOneBTC::new(UsdCents::from(6_000_000)),
+            },
+        )];
+
+        let summary = CreditFacilityStatementSummary::build(
+            date("2025-02-01"),
+            date("2025-02-28"),
+            history,
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(summary.cvl_history.len(), 1);
+        assert_eq!(
+            summary.cvl_history[0].cvl,
+            CVLPct::from_loan_amounts(UsdCents::from(6_000_000), UsdCents::from(5_000_000))
+        );
+        assert!(summary.transactions.is_empty());
+    }
+
+    #[test]
+    fn rejects_history_that_drives_balances_negative() {
+        let history = vec![
+            disbursal("2025-01-10", 100_000),
+            payment("2025-02-10", 150_000),
+        ];
+
+        let res = CreditFacilityStatementSummary::build(
+            date("2025-02-01"),
+            date("2025-02-28"),
+            history,
+            vec![],
+        );
+
+        assert!(matches!(
+            res,
+            Err(CreditFacilityStatementError::BalanceUnderflow(effective))
+                if effective == date("2025-02-10")
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use serde::Serialize;
+
+use crate::repayment_plan::RepaymentType;
+
+use super::{
+    entity::CreditFacilityStatement,
+    summary::{CreditFacilityStatementSummary, StatementAmount, StatementTransactionType},
+};
+
+pub(super) const CREDIT_FACILITY_STATEMENT_TEMPLATE: &str =
+    include_str!("templates/credit_facility_statement.md.hbs");
+
+#[derive(Serialize)]
+struct TransactionLine {
+    effective: String,
+    description: &'static str,
+    amount: String,
+}
+
+#[derive(Serialize)]
+struct CvlLine {
+    effective: String,
+    price: String,
+    collateral: String,
+    outstanding: String,
+    cvl: String,
+    state: String,
+}
+
+#[derive(Serialize)]
+struct ObligationLine {
+    obligation_type: &'static str,
+    due_at: String,
+    status: String,
+    outstanding: String,
+}
+
+/// Data structure for the credit facility statement template
+#[derive(Serialize)]
+pub struct CreditFacilityStatementData {
+    credit_facility_id: String,
+    customer_id: String,
+    period_start: String,
+    period_end: String,
+    opening_outstanding: String,
+    closing_outstanding: String,
+    opening_collateral: String,
+    closing_collateral: String,
+    total_disbursed: String,
+    total_interest_accrued: String,
+    total_paid: String,
+    transactions: Vec<TransactionLine>,
+    cvl_history: Vec<CvlLine>,
+    unpaid_obligations: Vec<ObligationLine>,
+}
+
+fn describe(transaction_type: StatementTransactionType) -> &'static str {
+    match transaction_type {
+        StatementTransactionType::Disbursal => "Disbursal",
+        StatementTransactionType::InterestAccrual => "Interest accrued",
+        StatementTransactionType::Payment => "Payment received",
+        StatementTransactionType::CollateralDeposit => "Collateral deposited",
+        StatementTransactionType::CollateralWithdrawal => "Collateral withdrawn",
+        StatementTransactionType::CollateralLiquidated => "Collateral sent to liquidation",
+        StatementTransactionType::LiquidationProceeds => "Liquidation proceeds received",
+    }
+}
+
+fn format_date(date: chrono::NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+impl CreditFacilityStatementData {
+    pub fn new(
+        statement: &CreditFacilityStatement,
+        summary: CreditFacilityStatementSummary,
+    ) -> Self {
+        Self {
+            credit_facility_id: statement.credit_facility_id.to_string(),
+            customer_id: statement.customer_id.to_string(),
+            period_start: format_date(summary.period_start),
+            period_end: format_date(summary.period_end),
+            opening_outstanding: summary.opening_outstanding.formatted_usd(),
+            closing_outstanding: summary.closing_outstanding.formatted_usd(),
+            opening_collateral: summary.opening_collateral.formatted_btc(),
+            closing_collateral: summary.closing_collateral.formatted_btc(),
+            total_disbursed: summary.total_disbursed.formatted_usd(),
+            total_interest_accrued: summary.total_interest_accrued.formatted_usd(),
+            total_paid: summary.total_paid.formatted_usd(),
+            transactions: summary
+                .transactions
+                .into_iter()
+                .map(|transaction| TransactionLine {
+                    effective: format_date(transaction.effective),
+                    description: describe(transaction.transaction_type),
+                    amount: match transaction.amount {
+                        StatementAmount::Usd(cents) => cents.formatted_usd(),
+                        StatementAmount::Btc(sats) => format!("{} BTC", sats.formatted_btc()),
+                    },
+                })
+                .collect(),
+            cvl_history: summary
+                .cvl_history
+                .into_iter()
+                .map(|point| CvlLine {
+                    effective: format_date(point.effective),
+                    price: format!("${}", point.price),
+                    collateral: point.collateral.formatted_btc(),
+                    outstanding: point.outstanding.formatted_usd(),
+                    cvl: point.cvl.to_string(),
+                    state: point.state.to_string(),
+                })
+                .collect(),
+            unpaid_obligations: summary
+                .unpaid_obligations
+                .into_iter()
+                .map(|entry| ObligationLine {
+                    obligation_type: match entry.repayment_type {
+                        RepaymentType::Disbursal => "Principal",
+                        RepaymentType::Interest => "Interest",
+                    },
+                    due_at: format_date(entry.due_at.into()),
+                    status: format!("{:?}", entry.status),
+                    outstanding: entry.outstanding.formatted_usd(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use es_entity::{EntityEvents, TryFromEvents};
+
+    use super::*;
+    use crate::{credit_facility_statement::entity::CreditFacilityStatementEvent, primitives::*};
+
+    #[test]
+    fn renders_statement_template() {
+        let period_start: NaiveDate = "2025-02-01".parse().unwrap();
+        let period_end: NaiveDate = "2025-02-28".parse().unwrap();
+        let id = CreditFacilityStatementId::new();
+        let statement = CreditFacilityStatement::try_from_events(EntityEvents::init(
+            id,
+            [CreditFacilityStatementEvent::Initialized {
+                id,
+                credit_facility_id: CreditFacilityId::new(),
+                customer_id: CustomerId::new(),
+                document_id: document_storage::DocumentId::new(),
+                period_start,
+                period_end,
+            }],
+        ))
+        .unwrap();
+        let summary =
+            CreditFacilityStatementSummary::build(period_start, period_end, vec![], vec![])
+                .unwrap();
+
+        let data = CreditFacilityStatementData::new(&statement, summary);
+        let rendered = rendering::TemplateRenderer::new()
+            .render(CREDIT_FACILITY_STATEMENT_TEMPLATE, &data)
+            .unwrap();
+
+        assert!(rendered.contains("2025-02-01 to 2025-02-28"));
+        assert!(rendered.contains(&statement.credit_facility_id.to_string()));
+        assert!(rendered.contains("No transactions were recorded during this period."));
+    }
+}
@@ -48,6 +48,14 @@ pub enum CoreCreditError {
     CustodyError(#[from] core_custody::error::CoreCustodyError),
     #[error("CoreCreditError - DisbursalError: {0}")]
     DisbursalError(#[from] super::disbursal::error::DisbursalError),
+    #[error("CoreCreditError - ParticipationError: {0}")]
+    ParticipationError(#[from] super::participation::error::ParticipationError),
+    #[error("CoreCreditError - CreditSimulationError: {0}")]
+    CreditSimulationError(#[from] super::simulation::error::CreditSimulationError),
+    #[error("CoreCreditError - CreditFacilityStatementError: {0}")]
+    CreditFacilityStatementError(
+        #[from] super::credit_facility_statement::error::CreditFacilityStatementError,
+    ),
     #[error("CoreCreditError - InterestAccrualCycleError: {0}")]
     InterestAccrualCycleError(
         #[from] super::interest_accrual_cycle::error::InterestAccrualCycleError,
@@ -94,6 +102,9 @@ impl ErrorSeverity for CoreCreditError {
             Self::CollateralLedgerError(e) => e.severity(),
             Self::CustodyError(e) => e.severity(),
             Self::DisbursalError(e) => e.severity(),
+            Self::ParticipationError(e) => e.severity(),
+            Self::CreditSimulationError(e) => e.severity(),
+            Self::CreditFacilityStatementError(e) => e.severity(),
             Self::InterestAccrualCycleError(e) => e.severity(),
             Self::PriceError(e) => e.severity(),
             Self::GovernanceError(e) => e.severity(),
@@ -31,6 +31,7 @@ where
     disbursals: &'a Disbursals<Perms, E>,
     histories: &'a Histories<Perms>,
     repayment_plans: &'a RepaymentPlans<Perms>,
+    statements: &'a CreditFacilityStatements<Perms>,
     ledger: &'a CreditLedger,
 }
 
@@ -64,6 +65,7 @@ where
         disbursals: &'a Disbursals<Perms, E>,
         history: &'a Histories<Perms>,
         repayment_plans: &'a RepaymentPlans<Perms>,
+        statements: &'a CreditFacilityStatements<Perms>,
         ledger: &'a CreditLedger,
     ) -> Self {
         Self {
@@ -76,6 +78,7 @@ where
             disbursals,
             histories: history,
             repayment_plans,
+            statements,
             ledger,
         }
     }
@@ -134,6 +137,52 @@ where
         Ok(repayment_plan.into_iter().map(T::from).collect())
     }
 
+    pub async fn statements(
+        &self,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<CreditFacilityStatement>, CoreCreditError> {
+        let id = id.into();
+        let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
+
+        self.ensure_credit_facility_access(
+            &credit_facility,
+            CoreCreditObject::credit_facility(id),
+            CoreCreditAction::CREDIT_FACILITY_READ,
+        )
+        .await?;
+
+        Ok(self
+            .statements
+            .list_for_credit_facility_without_audit(id)
+            .await?)
+    }
+
+    pub async fn generate_statement_download_link(
+        &self,
+        statement_id: impl Into<CreditFacilityStatementId> + std::fmt::Debug,
+    ) -> Result<document_storage::GeneratedDocumentDownloadLink, CoreCreditError> {
+        let statement = self
+            .statements
+            .find_by_id_without_audit(statement_id.into())
+            .await?;
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(statement.credit_facility_id)
+            .await?;
+
+        self.ensure_credit_facility_access(
+            &credit_facility,
+            CoreCreditObject::credit_facility(credit_facility.id),
+            CoreCreditAction::CREDIT_FACILITY_READ,
+        )
+        .await?;
+
+        Ok(self
+            .statements
+            .generate_download_link_without_audit(&statement)
+            .await?)
+    }
+
     pub async fn balance(
         &self,
         id: impl Into<CreditFacilityId> + std::fmt::Debug,
@@ -12,6 +12,7 @@ mod constants;
 mod credit_facility_accounts;
 mod disbursal_accounts;
 pub mod error;
+mod participation_accounts;
 mod templates;
 mod velocity;
 
@@ -33,8 +34,9 @@ use crate::{
         CREDIT_FACILITY_ENTITY_TYPE, CREDIT_FACILITY_PROPOSAL_ENTITY_TYPE, CalaAccountId,
         CalaAccountSetId, CreditFacilityId, CustomerType, DisbursalId,
         DisbursedReceivableAccountCategory, DisbursedReceivableAccountType, FacilityDurationType,
-        InterestReceivableAccountType, LedgerOmnibusAccountIds, LedgerTxId,
-        PendingCreditFacilityId, Satoshis, UsdCents, account_sets,
+        InterestReceivableAccountType, LedgerOmnibusAccountIds, LedgerTxId, ObligationType,
+        PARTICIPATION_ENTITY_TYPE, ParticipationId, PendingCreditFacilityId, Satoshis, UsdCents,
+        account_sets,
     },
 };
 
@@ -45,6 +47,7 @@ use constants::*;
 pub use credit_facility_accounts::*;
 pub use disbursal_accounts::*;
 use error::*;
+pub use participation_accounts::*;
 
 #[derive(Clone, Copy)]
 pub struct InternalAccountSetDetails {
@@ -116,6 +119,8 @@ pub struct CreditFacilityInternalAccountSets {
     pub fee_income: InternalAccountSetDetails,
     pub uncovered_outstanding: InternalAccountSetDetails,
     pub payment_holding: InternalAccountSetDetails,
+    pub participation_funded: InternalAccountSetDetails,
+    pub participation_payable: InternalAccountSetDetails,
 }
 
 struct FacilityBalanceIds {
@@ -160,6 +165,8 @@ pub struct CreditLedger {
     liquidation_proceeds_omnibus_account_ids: LedgerOmnibusAccountIds,
     interest_added_to_obligations_omnibus_account_ids: LedgerOmnibusAccountIds,
     payments_made_omnibus_account_ids: LedgerOmnibusAccountIds,
+    participation_funding_omnibus_account_ids: LedgerOmnibusAccountIds,
+    participation_interest_omnibus_account_ids: LedgerOmnibusAccountIds,
     internal_account_sets: CreditFacilityInternalAccountSets,
     credit_facility_control_ids: CreditVelocityControlIds,
     usd: Currency,
@@ -183,6 +190,8 @@ impl CreditLedger {
         templates::ConfirmDisbursal::init(cala).await?;
         templates::CreateCreditFacilityProposal::init(cala).await?;
         templates::InitialDisbursal::init(cala).await?;
+        templates::FundParticipation::init(cala).await?;
+        templates::AllocateParticipationReceipt::init(cala).await?;
 
         let catalog = account_sets::CREDIT_ACCOUNT_SET_CATALOG;
         let summary = catalog.summary();
@@ -323,6 +332,8 @@ impl CreditLedger {
             fee_income: get(&summary.credit_fee_income),
             uncovered_outstanding: get(&summary.credit_uncovered_outstanding),
             payment_holding: get(&summary.credit_payment_holding),
+            participation_funded: get(&summary.credit_participation_funded),
+            participation_payable: get(&summary.credit_participation_payable),
         };
 
         let disbursal_limit_id = velocity::DisbursalLimit::init(cala).await?;
@@ -373,6 +384,11 @@ impl CreditLedger {
                 .account_set_ref],
             payments_made_omnibus_account_ids: omnibus_ids
                 [omnibus.credit_payments_made_omnibus.account_set_ref],
+            participation_funding_omnibus_account_ids: omnibus_ids
+                [omnibus.credit_participation_funding_omnibus.account_set_ref],
+            participation_interest_omnibus_account_ids: omnibus_ids[omnibus
+                .credit_participation_interest_omnibus
+                .account_set_ref],
             internal_account_sets,
             credit_facility_control_ids: CreditVelocityControlIds {
                 disbursal: disbursal_control_id,
@@ -1144,6 +1160,117 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub(crate) async fn create_participation_accounts_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        participation_id: ParticipationId,
+        credit_facility_id: CreditFacilityId,
+        account_ids: ParticipationLedgerAccountIds,
+    ) -> Result<(), CreditLedgerError> {
+        let entity_ref = EntityRef::new(PARTICIPATION_ENTITY_TYPE, participation_id);
+
+        let funded_reference = &format!("credit-participation-funded:{participation_id}");
+        let funded_name = &format!(
+            "Participation Funded Account for Participation {participation_id} in Credit Facility {credit_facility_id}"
+        );
+        self.create_account_in_op(
+            op,
+            account_ids.funded_account_id,
+            self.internal_account_sets.participation_funded,
+            funded_reference,
+            funded_name,
+            funded_name,
+            entity_ref.clone(),
+        )
+        .await?;
+
+        let payable_reference = &format!("credit-participation-payable:{participation_id}");
+        let payable_name = &format!(
+            "Participation Payable Account for Participation {participation_id} in Credit Facility {credit_facility_id}"
+        );
+        self.create_account_in_op(
+            op,
+            account_ids.payable_account_id,
+            self.internal_account_sets.participation_payable,
+            payable_reference,
+            payable_name,
+            payable_name,
+            entity_ref,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn fund_participation_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        participation_id: ParticipationId,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        account_ids: ParticipationLedgerAccountIds,
+        effective: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::FUND_PARTICIPATION_CODE,
+                templates::FundParticipationParams {
+                    entity_id: participation_id.into(),
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    participation_funding_omnibus_account: self
+                        .participation_funding_omnibus_account_ids
+                        .account_id,
+                    participant_funded_account: account_ids.funded_account_id,
+                    funded_amount: amount.to_usd(),
+                    effective,
+                    initiated_by,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Principal receipts reduce the participant's funded position, interest receipts
+    /// are an expense passed through from the participation interest omnibus.
+    pub(crate) async fn allocate_participation_receipt_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        participation_id: ParticipationId,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        obligation_type: ObligationType,
+        account_ids: ParticipationLedgerAccountIds,
+        effective: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), CreditLedgerError> {
+        let receipt_source_account = match obligation_type {
+            ObligationType::Disbursal => account_ids.funded_account_id,
+            ObligationType::Interest => self.participation_interest_omnibus_account_ids.account_id,
+        };
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::ALLOCATE_PARTICIPATION_RECEIPT_CODE,
+                templates::AllocateParticipationReceiptParams {
+                    entity_id: participation_id.into(),
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    receipt_source_account,
+                    participant_payable_account: account_ids.payable_account_id,
+                    receipt_amount: amount.to_usd(),
+                    effective,
+                    initiated_by,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
     pub async fn create_credit_facility_control(
         cala: &CalaLedger,
         id: impl Into<VelocityControlId>,
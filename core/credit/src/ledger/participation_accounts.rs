@@ -0,0 +1,22 @@
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cala_ledger::AccountId as CalaAccountId;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ParticipationLedgerAccountIds {
+    pub funded_account_id: CalaAccountId,
+    pub payable_account_id: CalaAccountId,
+}
+
+impl ParticipationLedgerAccountIds {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            funded_account_id: CalaAccountId::new(),
+            payable_account_id: CalaAccountId::new(),
+        }
+    }
+}
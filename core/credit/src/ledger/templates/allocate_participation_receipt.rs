@@ -0,0 +1,153 @@
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    ledger::error::*,
+    primitives::{CalaAccountId, PARTICIPATION_ENTITY_TYPE},
+};
+
+pub const ALLOCATE_PARTICIPATION_RECEIPT_CODE: &str = "ALLOCATE_PARTICIPATION_RECEIPT";
+
+#[derive(Debug)]
+pub struct AllocateParticipationReceiptParams<S: std::fmt::Display> {
+    pub entity_id: uuid::Uuid,
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub receipt_source_account: CalaAccountId,
+    pub participant_payable_account: CalaAccountId,
+    pub receipt_amount: Decimal,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> AllocateParticipationReceiptParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receipt_source_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("participant_payable_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receipt_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<AllocateParticipationReceiptParams<S>> for Params {
+    fn from(
+        AllocateParticipationReceiptParams {
+            entity_id,
+            journal_id,
+            currency,
+            receipt_source_account,
+            participant_payable_account,
+            receipt_amount,
+            effective,
+            initiated_by,
+        }: AllocateParticipationReceiptParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("receipt_source_account", receipt_source_account);
+        params.insert("participant_payable_account", participant_payable_account);
+        params.insert("receipt_amount", receipt_amount);
+        params.insert("effective", effective);
+        let entity_ref = chart_primitives::EntityRef::new(PARTICIPATION_ENTITY_TYPE, entity_id);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "entity_ref": entity_ref,
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+        params
+    }
+}
+
+pub struct AllocateParticipationReceipt;
+
+impl AllocateParticipationReceipt {
+    #[record_error_severity]
+    #[instrument(name = "ledger.allocate_participation_receipt.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Allocate participant share of a payment receipt'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'ALLOCATE_PARTICIPATION_RECEIPT_DR'")
+                .currency("params.currency")
+                .account_id("params.receipt_source_account")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.receipt_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'ALLOCATE_PARTICIPATION_RECEIPT_CR'")
+                .currency("params.currency")
+                .account_id("params.participant_payable_account")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.receipt_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = AllocateParticipationReceiptParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(ALLOCATE_PARTICIPATION_RECEIPT_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode(_)) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
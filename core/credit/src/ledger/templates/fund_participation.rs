@@ -0,0 +1,156 @@
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    ledger::error::*,
+    primitives::{CalaAccountId, PARTICIPATION_ENTITY_TYPE},
+};
+
+pub const FUND_PARTICIPATION_CODE: &str = "FUND_PARTICIPATION";
+
+#[derive(Debug)]
+pub struct FundParticipationParams<S: std::fmt::Display> {
+    pub entity_id: uuid::Uuid,
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub participation_funding_omnibus_account: CalaAccountId,
+    pub participant_funded_account: CalaAccountId,
+    pub funded_amount: Decimal,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> FundParticipationParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("participation_funding_omnibus_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("participant_funded_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("funded_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<FundParticipationParams<S>> for Params {
+    fn from(
+        FundParticipationParams {
+            entity_id,
+            journal_id,
+            currency,
+            participation_funding_omnibus_account,
+            participant_funded_account,
+            funded_amount,
+            effective,
+            initiated_by,
+        }: FundParticipationParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert(
+            "participation_funding_omnibus_account",
+            participation_funding_omnibus_account,
+        );
+        params.insert("participant_funded_account", participant_funded_account);
+        params.insert("funded_amount", funded_amount);
+        params.insert("effective", effective);
+        let entity_ref = chart_primitives::EntityRef::new(PARTICIPATION_ENTITY_TYPE, entity_id);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "entity_ref": entity_ref,
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+        params
+    }
+}
+
+pub struct FundParticipation;
+
+impl FundParticipation {
+    #[record_error_severity]
+    #[instrument(name = "ledger.fund_participation.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Fund participant share of a disbursal'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'FUND_PARTICIPATION_DR'")
+                .currency("params.currency")
+                .account_id("params.participation_funding_omnibus_account")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.funded_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'FUND_PARTICIPATION_CR'")
+                .currency("params.currency")
+                .account_id("params.participant_funded_account")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.funded_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = FundParticipationParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(FUND_PARTICIPATION_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode(_)) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 mod accrue_interest;
 mod activate_credit_facility;
 mod add_structuring_fee;
+mod allocate_participation_receipt;
 mod cancel_disbursal;
 mod confirm_disbursal;
 mod create_credit_facility_proposal;
+mod fund_participation;
 mod initial_disbursal;
 mod initiate_disbursal;
 mod post_accrued_interest;
@@ -11,9 +13,11 @@ mod post_accrued_interest;
 pub use accrue_interest::*;
 pub use activate_credit_facility::*;
 pub use add_structuring_fee::*;
+pub use allocate_participation_receipt::*;
 pub use cancel_disbursal::*;
 pub use confirm_disbursal::*;
 pub use create_credit_facility_proposal::*;
+pub use fund_participation::*;
 pub use initial_disbursal::*;
 pub use initiate_disbursal::*;
 pub use post_accrued_interest::*;
@@ -4,16 +4,19 @@
 mod chart_of_accounts_integration;
 mod credit_facility;
 mod credit_facility_proposal;
+mod credit_facility_statement;
 mod disbursal;
 pub mod error;
 mod for_subject;
 mod history;
 pub mod ledger;
+mod participation;
 mod pending_credit_facility;
 mod primitives;
 pub mod public;
 mod publisher;
 mod repayment_plan;
+mod simulation;
 
 use std::sync::Arc;
 
@@ -29,6 +32,7 @@ use core_custody::{
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
 use core_price::{CorePriceEvent, Price};
 use core_time_events::CoreTimeEvent;
+use document_storage::DocumentStorage;
 use domain_config::{ExposedDomainConfigsReadOnly, InternalDomainConfigs};
 use es_entity::clock::ClockHandle;
 use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
@@ -48,17 +52,27 @@ pub use credit_facility::jobs::allocate_payment::*;
 pub use credit_facility::*;
 pub use credit_facility_proposal::jobs::approve::*;
 pub use credit_facility_proposal::*;
+pub use credit_facility_statement::error::CreditFacilityStatementError;
+use credit_facility_statement::jobs::{
+    collect_statements::*, end_of_month::*, generate_statement::*,
+};
+pub use credit_facility_statement::*;
 pub use disbursal::jobs::approve::*;
 pub use disbursal::{disbursal_cursor::*, *};
 use error::*;
 use for_subject::CreditFacilitiesForSubject;
 pub use history::*;
 pub use ledger::*;
+pub use participation::error::ParticipationError;
+pub use participation::jobs::{allocate_receipt::*, fund_disbursal::*};
+pub use participation::*;
 pub use pending_credit_facility::*;
 pub use primitives::*;
 pub use public::*;
 use publisher::CreditFacilityPublisher;
 pub use repayment_plan::*;
+pub use simulation::error::CreditSimulationError;
+pub use simulation::*;
 
 use core_credit_collection::{CoreCreditCollection, PaymentLedgerAccountIds};
 
@@ -70,8 +84,9 @@ pub mod event_schema {
     pub use crate::{
         ObligationEvent, PaymentAllocationEvent, PaymentEvent,
         credit_facility::CreditFacilityEvent,
-        credit_facility_proposal::CreditFacilityProposalEvent, disbursal::DisbursalEvent,
-        interest_accrual_cycle::InterestAccrualCycleEvent,
+        credit_facility_proposal::CreditFacilityProposalEvent,
+        credit_facility_statement::CreditFacilityStatementEvent, disbursal::DisbursalEvent,
+        interest_accrual_cycle::InterestAccrualCycleEvent, participation::ParticipationEvent,
         pending_credit_facility::PendingCreditFacilityEvent,
     };
 
@@ -107,6 +122,9 @@ where
     chart_of_accounts_integrations: Arc<ChartOfAccountsIntegrations<Perms>>,
     public_ids: Arc<PublicIds>,
     histories: Arc<Histories<Perms>>,
+    participations: Arc<Participations<Perms>>,
+    simulations: Arc<CreditSimulations<Perms>>,
+    statements: Arc<CreditFacilityStatements<Perms>>,
     clock: ClockHandle,
 }
 
@@ -133,6 +151,9 @@ where
             custody: self.custody.clone(),
             disbursals: self.disbursals.clone(),
             histories: self.histories.clone(),
+            participations: self.participations.clone(),
+            simulations: self.simulations.clone(),
+            statements: self.statements.clone(),
             repayment_plans: self.repayment_plans.clone(),
             governance: self.governance.clone(),
             customer: self.customer.clone(),
@@ -185,6 +206,8 @@ where
         public_ids: &PublicIds,
         domain_configs: &ExposedDomainConfigsReadOnly,
         internal_domain_configs: &InternalDomainConfigs,
+        document_storage: &DocumentStorage,
+        gotenberg_config: gotenberg::GotenbergConfig,
     ) -> Result<Self, CoreCreditError>
     where
         E: OutboxEventMarker<CoreTimeEvent>,
@@ -297,6 +320,29 @@ where
         let repayment_plans_arc =
             Arc::new(RepaymentPlans::init(pool, outbox, jobs, authz_arc.clone()).await?);
 
+        let participations_arc = Arc::new(Participations::new(
+            pool,
+            authz_arc.clone(),
+            ledger_arc.clone(),
+            clock.clone(),
+        ));
+
+        let simulations_arc = Arc::new(CreditSimulations::new(
+            authz_arc.clone(),
+            price_arc.clone(),
+            clock.clone(),
+        ));
+
+        let statements_arc = Arc::new(CreditFacilityStatements::new(
+            pool,
+            authz_arc.clone(),
+            histories_arc.clone(),
+            repayment_plans_arc.clone(),
+            document_storage,
+            gotenberg_config,
+            clock.clone(),
+        ));
+
         let chart_of_accounts_integrations = ChartOfAccountsIntegrations::new(
             authz_arc.clone(),
             ledger_arc.clone(),
@@ -358,6 +404,50 @@ where
             )
             .await?;
 
+        // Register participation funding and receipt allocation handlers + command jobs
+        let fund_participations_spawner = jobs.add_initializer(
+            FundParticipationsJobInitializer::new(participations_arc.clone()),
+        );
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(PARTICIPATION_FUND_DISBURSAL),
+                ParticipationFundDisbursalHandler::new(fund_participations_spawner),
+            )
+            .await?;
+
+        let allocate_participation_receipt_spawner = jobs.add_initializer(
+            AllocateParticipationReceiptJobInitializer::new(participations_arc.clone()),
+        );
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(PARTICIPATION_ALLOCATE_RECEIPT),
+                ParticipationAllocateReceiptHandler::new(allocate_participation_receipt_spawner),
+            )
+            .await?;
+
+        // Register month-end statement handler + collection and generation jobs
+        let generate_statement_spawner = jobs.add_initializer(
+            GenerateCreditFacilityStatementJobInitializer::new(statements_arc.clone()),
+        );
+        let collect_statements_spawner =
+            jobs.add_initializer(CollectCreditFacilityStatementsJobInitializer::new(
+                facilities_arc.clone(),
+                statements_arc.clone(),
+                generate_statement_spawner,
+            ));
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(CREDIT_FACILITY_STATEMENT_END_OF_MONTH),
+                CreditFacilityStatementEndOfMonthHandler::new(collect_statements_spawner),
+            )
+            .await?;
+
         Ok(Self {
             clock,
             authz: authz_arc,
@@ -370,6 +460,9 @@ where
             custody: custody_arc,
             disbursals: disbursals_arc,
             histories: histories_arc,
+            participations: participations_arc,
+            simulations: simulations_arc,
+            statements: statements_arc,
             repayment_plans: repayment_plans_arc,
             governance: governance_arc,
             ledger: ledger_arc,
@@ -417,6 +510,18 @@ where
         self.repayment_plans.as_ref()
     }
 
+    pub fn simulations(&self) -> &CreditSimulations<Perms> {
+        self.simulations.as_ref()
+    }
+
+    pub fn statements(&self) -> &CreditFacilityStatements<Perms> {
+        self.statements.as_ref()
+    }
+
+    pub fn participations(&self) -> &Participations<Perms> {
+        self.participations.as_ref()
+    }
+
     #[record_error_severity]
     #[instrument(name = "credit.find_all_facilities_authorized", skip(self))]
     pub async fn find_all_facilities_authorized<T: From<CreditFacility>>(
@@ -559,6 +664,7 @@ where
             &self.disbursals,
             &self.histories,
             &self.repayment_plans,
+            &self.statements,
             &self.ledger,
         ))
     }
@@ -575,6 +681,35 @@ where
             .await?)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "credit.add_participation", skip(self), fields(credit_facility_id = %credit_facility_id))]
+    pub async fn add_participation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        participant_name: impl Into<String> + std::fmt::Debug,
+        share: ParticipationSharePct,
+    ) -> Result<Participation, CoreCreditError> {
+        self.participations
+            .subject_can_add(sub, credit_facility_id)
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let facility = self
+            .facilities
+            .find_by_id_without_audit_in_op(&mut db, credit_facility_id)
+            .await?;
+        let participation = self
+            .participations
+            .add_in_op(&mut db, &facility, participant_name, share)
+            .await?;
+
+        db.commit().await?;
+
+        Ok(participation)
+    }
+
     #[record_error_severity]
     #[instrument(name = "credit.create_proposal", skip(self),fields(credit_facility_proposal_id = tracing::field::Empty))]
     pub async fn create_facility_proposal(
@@ -652,7 +787,34 @@ where
             .await?
             .expect("audit info missing");
 
-        let now = self.clock.now();
+        let mut db = self.facilities.begin_op().await?;
+
+        let facility = self
+            .facilities
+            .find_by_id_without_audit_in_op(&mut db, credit_facility_id)
+            .await?;
+        let disbursal_credit_account_id = facility.disbursal_credit_account_id;
+
+        self.create_disbursal(db, sub, facility, amount, disbursal_credit_account_id)
+            .await
+    }
+
+    /// Lets the borrower draw on their own facility, paying out to a deposit account of
+    /// their choosing. The disbursal still goes through the regular approval process.
+    #[record_error_severity]
+    #[instrument(name = "credit.request_disbursal", skip(self),fields(credit_facility_id = %credit_facility_id))]
+    pub async fn request_disbursal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        deposit_account_id: impl Into<CalaAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+    ) -> Result<Disbursal, CoreCreditError>
+    where
+        CustomerId: for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let customer_id =
+            CustomerId::try_from(sub).map_err(|_| CoreCreditError::SubjectIsNotCustomer)?;
 
         let mut db = self.facilities.begin_op().await?;
 
@@ -661,6 +823,34 @@ where
             .find_by_id_without_audit_in_op(&mut db, credit_facility_id)
             .await?;
 
+        let authorized = facility.customer_id == customer_id;
+        self.authz
+            .audit()
+            .record_entry(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::DISBURSAL_INITIATE,
+                authorized,
+            )
+            .await?;
+        if !authorized {
+            return Err(CoreCreditError::CustomerMismatchForCreditFacility);
+        }
+
+        self.create_disbursal(db, sub, facility, amount, deposit_account_id.into())
+            .await
+    }
+
+    async fn create_disbursal(
+        &self,
+        mut db: es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        facility: CreditFacility,
+        amount: UsdCents,
+        disbursal_credit_account_id: CalaAccountId,
+    ) -> Result<Disbursal, CoreCreditError> {
+        let now = self.clock.now();
+        let credit_facility_id = facility.id;
         let customer_id = facility.customer_id;
         self.customer
             .find_eligible_for_product_without_audit_in_op(&mut db, customer_id)
@@ -677,6 +867,14 @@ where
             .get_credit_facility_balance(facility.account_ids, collateral_account_id)
             .await?;
 
+        if amount > balance.facility_remaining() {
+            return Err(CreditFacilityError::DisbursalExceedsUndrawnLimit(
+                amount,
+                balance.facility_remaining(),
+            )
+            .into());
+        }
+
         let price = self.price.usd_cents_per_btc().await;
         let cvl = balance.with_added_disbursal(amount).current_cvl(price);
         facility.assert_disbursal_allowed(now, cvl)?;
@@ -702,7 +900,7 @@ where
             .credit_facility_id(credit_facility_id)
             .amount(amount)
             .account_ids(facility.account_ids.into())
-            .disbursal_credit_account_id(facility.disbursal_credit_account_id)
+            .disbursal_credit_account_id(disbursal_credit_account_id)
             .due_date(due_date)
             .overdue_date(overdue_date)
             .liquidation_date(liquidation_date)
@@ -0,0 +1,407 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::{ledger::ParticipationLedgerAccountIds, primitives::*};
+
+use super::error::ParticipationError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ParticipationId")]
+pub enum ParticipationEvent {
+    Initialized {
+        id: ParticipationId,
+        credit_facility_id: CreditFacilityId,
+        participant_name: String,
+        share: ParticipationSharePct,
+        account_ids: ParticipationLedgerAccountIds,
+    },
+    DisbursalFunded {
+        disbursal_id: DisbursalId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    },
+    ReceiptAllocated {
+        payment_allocation_id: PaymentAllocationId,
+        obligation_type: ObligationType,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ParticipationPosting {
+    pub ledger_tx_id: LedgerTxId,
+    pub amount: UsdCents,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Participation {
+    pub id: ParticipationId,
+    pub credit_facility_id: CreditFacilityId,
+    pub participant_name: String,
+    pub share: ParticipationSharePct,
+    pub account_ids: ParticipationLedgerAccountIds,
+    events: EntityEvents<ParticipationEvent>,
+}
+
+impl TryFromEvents<ParticipationEvent> for Participation {
+    fn try_from_events(
+        events: EntityEvents<ParticipationEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = ParticipationBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                ParticipationEvent::Initialized {
+                    id,
+                    credit_facility_id,
+                    participant_name,
+                    share,
+                    account_ids,
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .credit_facility_id(*credit_facility_id)
+                        .participant_name(participant_name.clone())
+                        .share(*share)
+                        .account_ids(*account_ids)
+                }
+                ParticipationEvent::DisbursalFunded { .. } => (),
+                ParticipationEvent::ReceiptAllocated { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+impl Participation {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn total_funded(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                ParticipationEvent::DisbursalFunded { amount, .. } => total + *amount,
+                _ => total,
+            })
+    }
+
+    fn total_received(&self, obligation_type: ObligationType) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                ParticipationEvent::ReceiptAllocated {
+                    amount,
+                    obligation_type: t,
+                    ..
+                } if *t == obligation_type => total + *amount,
+                _ => total,
+            })
+    }
+
+    pub fn total_principal_received(&self) -> UsdCents {
+        self.total_received(ObligationType::Disbursal)
+    }
+
+    pub fn total_interest_received(&self) -> UsdCents {
+        self.total_received(ObligationType::Interest)
+    }
+
+    pub fn outstanding_principal(&self) -> UsdCents {
+        self.total_funded() - self.total_principal_received()
+    }
+
+    pub(super) fn fund_disbursal(
+        &mut self,
+        disbursal_id: DisbursalId,
+        disbursal_amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Idempotent<Option<ParticipationPosting>> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            already_applied: ParticipationEvent::DisbursalFunded { disbursal_id: id, .. }
+                if *id == disbursal_id
+        );
+
+        let amount = self.share.share_of(disbursal_amount);
+        if amount.is_zero() {
+            return Idempotent::Executed(None);
+        }
+
+        let ledger_tx_id = LedgerTxId::new();
+        self.events.push(ParticipationEvent::DisbursalFunded {
+            disbursal_id,
+            ledger_tx_id,
+            amount,
+            effective,
+        });
+
+        Idempotent::Executed(Some(ParticipationPosting {
+            ledger_tx_id,
+            amount,
+        }))
+    }
+
+    /// Principal receipts are capped at the outstanding funded amount so that a
+    /// participant joining after earlier disbursals is never over-allocated.
+    pub(super) fn allocate_receipt(
+        &mut self,
+        payment_allocation_id: PaymentAllocationId,
+        obligation_type: ObligationType,
+        receipt_amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Idempotent<Option<ParticipationPosting>> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            already_applied: ParticipationEvent::ReceiptAllocated { payment_allocation_id: id, .. }
+                if *id == payment_allocation_id
+        );
+
+        let amount = match obligation_type {
+            ObligationType::Disbursal => std::cmp::min(
+                self.share.share_of(receipt_amount),
+                self.outstanding_principal(),
+            ),
+            ObligationType::Interest => self.share.share_of(receipt_amount),
+        };
+        if amount.is_zero() {
+            return Idempotent::Executed(None);
+        }
+
+        let ledger_tx_id = LedgerTxId::new();
+        self.events.push(ParticipationEvent::ReceiptAllocated {
+            payment_allocation_id,
+            obligation_type,
+            ledger_tx_id,
+            amount,
+            effective,
+        });
+
+        Idempotent::Executed(Some(ParticipationPosting {
+            ledger_tx_id,
+            amount,
+        }))
+    }
+
+    pub fn statement_entries(&self) -> Vec<super::statement::ParticipantStatementEntry> {
+        use super::statement::{ParticipantStatementEntry, ParticipantStatementEntryType};
+
+        self.events
+            .iter_persisted()
+            .filter_map(|event| match &event.event {
+                ParticipationEvent::DisbursalFunded {
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                } => Some(ParticipantStatementEntry {
+                    entry_type: ParticipantStatementEntryType::Funding,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    effective: *effective,
+                    recorded_at: event.recorded_at,
+                }),
+                ParticipationEvent::ReceiptAllocated {
+                    obligation_type,
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                } => Some(ParticipantStatementEntry {
+                    entry_type: match obligation_type {
+                        ObligationType::Disbursal => ParticipantStatementEntryType::Principal,
+                        ObligationType::Interest => ParticipantStatementEntryType::Interest,
+                    },
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    effective: *effective,
+                    recorded_at: event.recorded_at,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+pub(super) fn ensure_share_available(
+    existing: &[Participation],
+    share: ParticipationSharePct,
+) -> Result<(), ParticipationError> {
+    let allocated: ParticipationSharePct = existing.iter().map(|p| p.share).sum();
+    if allocated + share > ParticipationSharePct::FULL {
+        return Err(ParticipationError::ShareExceedsFacility { allocated, share });
+    }
+    Ok(())
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewParticipation {
+    #[builder(setter(into))]
+    pub(super) id: ParticipationId,
+    #[builder(setter(into))]
+    pub(super) credit_facility_id: CreditFacilityId,
+    #[builder(setter(into))]
+    pub(super) participant_name: String,
+    pub(super) share: ParticipationSharePct,
+    pub(super) account_ids: ParticipationLedgerAccountIds,
+}
+
+impl NewParticipationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.share {
+            Some(share) if !share.is_valid_share() => {
+                Err("Participation share must be between 0 and 100".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl NewParticipation {
+    pub fn builder() -> NewParticipationBuilder {
+        NewParticipationBuilder::default()
+    }
+}
+
+impl IntoEvents<ParticipationEvent> for NewParticipation {
+    fn into_events(self) -> EntityEvents<ParticipationEvent> {
+        EntityEvents::init(
+            self.id,
+            [ParticipationEvent::Initialized {
+                id: self.id,
+                credit_facility_id: self.credit_facility_id,
+                participant_name: self.participant_name,
+                share: self.share,
+                account_ids: self.account_ids,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn participation_with_share(pct: u64) -> Participation {
+        let id = ParticipationId::new();
+        let events = EntityEvents::init(
+            id,
+            [ParticipationEvent::Initialized {
+                id,
+                credit_facility_id: CreditFacilityId::new(),
+                participant_name: "Partner Bank".to_string(),
+                share: ParticipationSharePct::new(pct),
+                account_ids: ParticipationLedgerAccountIds::new(),
+            }],
+        );
+        Participation::try_from_events(events).unwrap()
+    }
+
+    fn today() -> chrono::NaiveDate {
+        chrono::Utc::now().date_naive()
+    }
+
+    #[test]
+    fn share_pct_rejects_out_of_range() {
+        assert!(ParticipationSharePct::try_from(rust_decimal::Decimal::NEGATIVE_ONE).is_err());
+        assert!(ParticipationSharePct::try_from(rust_decimal::Decimal::from(101)).is_err());
+        assert!(ParticipationSharePct::try_from(rust_decimal::Decimal::from(100)).is_ok());
+    }
+
+    #[test]
+    fn share_of_rounds_down_to_cent() {
+        let share = ParticipationSharePct::new(33);
+        assert_eq!(share.share_of(UsdCents::from(1_001)), UsdCents::from(330));
+    }
+
+    #[test]
+    fn fund_disbursal_records_pro_rata_share() {
+        let mut participation = participation_with_share(25);
+        let posting = participation
+            .fund_disbursal(DisbursalId::new(), UsdCents::from(100_000), today())
+            .unwrap()
+            .unwrap();
+        assert_eq!(posting.amount, UsdCents::from(25_000));
+        assert_eq!(participation.total_funded(), UsdCents::from(25_000));
+    }
+
+    #[test]
+    fn fund_disbursal_is_idempotent() {
+        let mut participation = participation_with_share(25);
+        let disbursal_id = DisbursalId::new();
+        let _ = participation.fund_disbursal(disbursal_id, UsdCents::from(100_000), today());
+        assert!(
+            participation
+                .fund_disbursal(disbursal_id, UsdCents::from(100_000), today())
+                .was_already_applied()
+        );
+        assert_eq!(participation.total_funded(), UsdCents::from(25_000));
+    }
+
+    #[test]
+    fn principal_receipt_capped_at_outstanding() {
+        let mut participation = participation_with_share(50);
+        let _ = participation.fund_disbursal(DisbursalId::new(), UsdCents::from(1_000), today());
+        let posting = participation
+            .allocate_receipt(
+                PaymentAllocationId::new(),
+                ObligationType::Disbursal,
+                UsdCents::from(10_000),
+                today(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(posting.amount, UsdCents::from(500));
+        assert_eq!(participation.outstanding_principal(), UsdCents::ZERO);
+    }
+
+    #[test]
+    fn interest_receipt_not_capped() {
+        let mut participation = participation_with_share(50);
+        let posting = participation
+            .allocate_receipt(
+                PaymentAllocationId::new(),
+                ObligationType::Interest,
+                UsdCents::from(1_000),
+                today(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(posting.amount, UsdCents::from(500));
+        assert_eq!(participation.total_interest_received(), UsdCents::from(500));
+    }
+
+    #[test]
+    fn zero_share_amount_is_not_recorded() {
+        let mut participation = participation_with_share(10);
+        let posting = participation
+            .fund_disbursal(DisbursalId::new(), UsdCents::from(5), today())
+            .unwrap();
+        assert!(posting.is_none());
+        assert_eq!(participation.total_funded(), UsdCents::ZERO);
+    }
+
+    #[test]
+    fn total_share_cannot_exceed_facility() {
+        let existing = vec![participation_with_share(60), participation_with_share(30)];
+        assert!(ensure_share_available(&existing, ParticipationSharePct::new(10)).is_ok());
+        assert!(matches!(
+            ensure_share_available(&existing, ParticipationSharePct::new(11)),
+            Err(ParticipationError::ShareExceedsFacility { .. })
+        ));
+    }
+}
@@ -0,0 +1,59 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::{CreditFacilityId, ParticipationSharePct};
+
+use super::repo::{
+    ParticipationCreateError, ParticipationFindError, ParticipationModifyError,
+    ParticipationQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum ParticipationError {
+    #[error("ParticipationError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ParticipationError - Create: {0}")]
+    Create(#[from] ParticipationCreateError),
+    #[error("ParticipationError - Modify: {0}")]
+    Modify(#[from] ParticipationModifyError),
+    #[error("ParticipationError - Find: {0}")]
+    Find(#[from] ParticipationFindError),
+    #[error("ParticipationError - Query: {0}")]
+    Query(#[from] ParticipationQueryError),
+    #[error("ParticipationError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ParticipationError - CreditLedgerError: {0}")]
+    CreditLedgerError(#[from] crate::ledger::error::CreditLedgerError),
+    #[error("ParticipationError - CreditFacilityNotActive: {0}")]
+    CreditFacilityNotActive(CreditFacilityId),
+    #[error("ParticipationError - InvalidShare: {0}")]
+    InvalidShare(ParticipationSharePct),
+    #[error(
+        "ParticipationError - ShareExceedsFacility: {allocated}% already allocated, cannot add {share}%"
+    )]
+    ShareExceedsFacility {
+        allocated: ParticipationSharePct,
+        share: ParticipationSharePct,
+    },
+    #[error("ParticipationError - CsvError: {0}")]
+    CsvError(String),
+}
+
+impl ErrorSeverity for ParticipationError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::AuthorizationError(e) => e.severity(),
+            Self::CreditLedgerError(e) => e.severity(),
+            Self::CreditFacilityNotActive(_) => Level::WARN,
+            Self::InvalidShare(_) => Level::WARN,
+            Self::ShareExceedsFacility { .. } => Level::WARN,
+            Self::CsvError(_) => Level::ERROR,
+        }
+    }
+}
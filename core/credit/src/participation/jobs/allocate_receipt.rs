@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreCreditAction, CoreCreditCollectionEvent, CoreCreditObject,
+    participation::Participations,
+    primitives::{CreditFacilityId, ObligationType, PaymentAllocationId, UsdCents},
+};
+
+pub const PARTICIPATION_ALLOCATE_RECEIPT: JobType =
+    JobType::new("outbox.participation-allocate-receipt");
+
+pub const PARTICIPATION_ALLOCATE_RECEIPT_COMMAND: JobType =
+    JobType::new("command.credit.participation-allocate-receipt");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AllocateParticipationReceiptConfig {
+    pub credit_facility_id: CreditFacilityId,
+    pub payment_allocation_id: PaymentAllocationId,
+    pub obligation_type: ObligationType,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+}
+
+pub struct AllocateParticipationReceiptJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    participations: Arc<Participations<Perms>>,
+}
+
+impl<Perms> AllocateParticipationReceiptJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(participations: Arc<Participations<Perms>>) -> Self {
+        Self { participations }
+    }
+}
+
+impl<Perms> JobInitializer for AllocateParticipationReceiptJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    type Config = AllocateParticipationReceiptConfig;
+
+    fn job_type(&self) -> JobType {
+        PARTICIPATION_ALLOCATE_RECEIPT_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(AllocateParticipationReceiptJobRunner {
+            config: job.config()?,
+            participations: self.participations.clone(),
+        }))
+    }
+}
+
+struct AllocateParticipationReceiptJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: AllocateParticipationReceiptConfig,
+    participations: Arc<Participations<Perms>>,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for AllocateParticipationReceiptJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "credit.participation_allocate_receipt.process_command",
+        skip_all
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut op = current_job.begin_op().await?;
+
+        self.participations
+            .allocate_receipt_in_op(
+                &mut op,
+                self.config.credit_facility_id,
+                self.config.payment_allocation_id,
+                self.config.obligation_type,
+                self.config.amount,
+                self.config.effective,
+            )
+            .await?;
+
+        Ok(JobCompletion::CompleteWithOp(op))
+    }
+}
+
+pub struct ParticipationAllocateReceiptHandler {
+    allocate_receipt: JobSpawner<AllocateParticipationReceiptConfig>,
+}
+
+impl ParticipationAllocateReceiptHandler {
+    pub fn new(allocate_receipt: JobSpawner<AllocateParticipationReceiptConfig>) -> Self {
+        Self { allocate_receipt }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for ParticipationAllocateReceiptHandler
+where
+    E: OutboxEventMarker<CoreCreditCollectionEvent>,
+{
+    #[instrument(name = "core_credit.participation_allocate_receipt_job.process_message_in_op", parent = None, skip(self, op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, credit_facility_id = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        use CoreCreditCollectionEvent::*;
+
+        if let Some(e @ PaymentAllocationCreated { entity }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+            Span::current().record(
+                "credit_facility_id",
+                tracing::field::display(&entity.beneficiary_id),
+            );
+
+            let credit_facility_id: CreditFacilityId = entity.beneficiary_id.into();
+            self.allocate_receipt
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    AllocateParticipationReceiptConfig {
+                        credit_facility_id,
+                        payment_allocation_id: entity.id,
+                        obligation_type: entity.obligation_type,
+                        amount: entity.amount,
+                        effective: entity.effective,
+                    },
+                    credit_facility_id.to_string(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
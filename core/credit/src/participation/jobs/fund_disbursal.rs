@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject,
+    participation::Participations,
+    primitives::{CreditFacilityId, DisbursalId, UsdCents},
+};
+
+pub const PARTICIPATION_FUND_DISBURSAL: JobType =
+    JobType::new("outbox.participation-fund-disbursal");
+
+pub const PARTICIPATION_FUND_DISBURSAL_COMMAND: JobType =
+    JobType::new("command.credit.participation-fund-disbursal");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundParticipationsConfig {
+    pub credit_facility_id: CreditFacilityId,
+    pub disbursal_id: DisbursalId,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+}
+
+pub struct FundParticipationsJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    participations: Arc<Participations<Perms>>,
+}
+
+impl<Perms> FundParticipationsJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(participations: Arc<Participations<Perms>>) -> Self {
+        Self { participations }
+    }
+}
+
+impl<Perms> JobInitializer for FundParticipationsJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    type Config = FundParticipationsConfig;
+
+    fn job_type(&self) -> JobType {
+        PARTICIPATION_FUND_DISBURSAL_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(FundParticipationsJobRunner {
+            config: job.config()?,
+            participations: self.participations.clone(),
+        }))
+    }
+}
+
+struct FundParticipationsJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: FundParticipationsConfig,
+    participations: Arc<Participations<Perms>>,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for FundParticipationsJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(name = "credit.participation_fund_disbursal.process_command", skip_all)]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut op = current_job.begin_op().await?;
+
+        self.participations
+            .fund_disbursal_in_op(
+                &mut op,
+                self.config.credit_facility_id,
+                self.config.disbursal_id,
+                self.config.amount,
+                self.config.effective,
+            )
+            .await?;
+
+        Ok(JobCompletion::CompleteWithOp(op))
+    }
+}
+
+pub struct ParticipationFundDisbursalHandler {
+    fund_participations: JobSpawner<FundParticipationsConfig>,
+}
+
+impl ParticipationFundDisbursalHandler {
+    pub fn new(fund_participations: JobSpawner<FundParticipationsConfig>) -> Self {
+        Self {
+            fund_participations,
+        }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for ParticipationFundDisbursalHandler
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    #[instrument(name = "core_credit.participation_fund_disbursal_job.process_message_in_op", parent = None, skip(self, op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, credit_facility_id = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        use CoreCreditEvent::*;
+
+        if let Some(e @ DisbursalSettled { entity }) = event.as_event()
+            && let Some(settlement) = &entity.settlement
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+            Span::current().record(
+                "credit_facility_id",
+                tracing::field::display(&entity.credit_facility_id),
+            );
+
+            self.fund_participations
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    FundParticipationsConfig {
+                        credit_facility_id: entity.credit_facility_id,
+                        disbursal_id: entity.id,
+                        amount: entity.amount,
+                        effective: settlement.effective,
+                    },
+                    entity.credit_facility_id.to_string(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
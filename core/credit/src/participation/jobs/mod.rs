@@ -0,0 +1,2 @@
+pub mod allocate_receipt;
+pub mod fund_disbursal;
@@ -0,0 +1,383 @@
+mod entity;
+pub mod error;
+pub(crate) mod jobs;
+mod repo;
+mod statement;
+
+use std::sync::Arc;
+
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use es_entity::AtomicOperation;
+
+use crate::{credit_facility::CreditFacility, ledger::CreditLedger, primitives::*};
+
+pub use entity::Participation;
+pub(super) use entity::*;
+use error::ParticipationError;
+pub(super) use repo::*;
+pub use statement::{
+    ParticipantStatement, ParticipantStatementEntry, ParticipantStatementEntryType,
+};
+
+#[cfg(feature = "json-schema")]
+pub use entity::ParticipationEvent;
+
+pub struct Participations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: Arc<ParticipationRepo>,
+    authz: Arc<Perms>,
+    ledger: Arc<CreditLedger>,
+}
+
+impl<Perms> Clone for Participations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            authz: self.authz.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms> Participations<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: Arc<Perms>,
+        ledger: Arc<CreditLedger>,
+        clock: es_entity::clock::ClockHandle,
+    ) -> Self {
+        Self {
+            repo: Arc::new(ParticipationRepo::new(pool, clock)),
+            authz,
+            ledger,
+        }
+    }
+
+    pub(super) async fn subject_can_add(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<(), ParticipationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_MANAGE_PARTICIPATIONS,
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.participations.add_in_op",
+        skip(self, op, credit_facility),
+        fields(credit_facility_id = %credit_facility.id)
+    )]
+    pub(super) async fn add_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility: &CreditFacility,
+        participant_name: impl Into<String> + std::fmt::Debug,
+        share: ParticipationSharePct,
+    ) -> Result<Participation, ParticipationError> {
+        let credit_facility_id = credit_facility.id;
+        if credit_facility.status() != CreditFacilityStatus::Active {
+            return Err(ParticipationError::CreditFacilityNotActive(
+                credit_facility_id,
+            ));
+        }
+
+        self.lock_credit_facility_in_op(op, credit_facility_id)
+            .await?;
+        let existing = self
+            .list_for_credit_facility_in_op(op, credit_facility_id)
+            .await?;
+        entity::ensure_share_available(&existing, share)?;
+
+        let id = ParticipationId::new();
+        let account_ids = crate::ledger::ParticipationLedgerAccountIds::new();
+        let new_participation = NewParticipation::builder()
+            .id(id)
+            .credit_facility_id(credit_facility_id)
+            .participant_name(participant_name)
+            .share(share)
+            .account_ids(account_ids)
+            .build()
+            .map_err(|_| ParticipationError::InvalidShare(share))?;
+
+        let participation = self.repo.create_in_op(op, new_participation).await?;
+        self.ledger
+            .create_participation_accounts_in_op(op, id, credit_facility_id, account_ids)
+            .await?;
+
+        Ok(participation)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_credit.participations.find_by_id", skip(self))]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ParticipationId> + std::fmt::Debug,
+    ) -> Result<Option<Participation>, ParticipationError> {
+        let Some(participation) = self.repo.maybe_find_by_id(id.into()).await? else {
+            return Ok(None);
+        };
+
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(participation.credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        Ok(Some(participation))
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.participations.list_for_credit_facility",
+        skip(self)
+    )]
+    pub async fn list_for_credit_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<Participation>, ParticipationError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        let mut participations = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_credit_facility_id_by_created_at(
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            participations.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(participations)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_credit.participations.statement", skip(self))]
+    pub async fn statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ParticipationId> + std::fmt::Debug,
+    ) -> Result<Option<ParticipantStatement>, ParticipationError> {
+        Ok(self
+            .find_by_id(sub, id)
+            .await?
+            .as_ref()
+            .map(ParticipantStatement::from))
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_credit.participations.export_statement_csv", skip(self))]
+    pub async fn export_statement_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ParticipationId> + std::fmt::Debug,
+    ) -> Result<Option<Vec<u8>>, ParticipationError> {
+        self.statement(sub, id)
+            .await?
+            .map(|statement| statement.to_csv())
+            .transpose()
+    }
+
+    /// Locks the credit facility row so that concurrent additions to the same
+    /// facility check the remaining share one after the other.
+    async fn lock_credit_facility_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<(), ParticipationError> {
+        sqlx::query("SELECT id FROM core_credit_facilities WHERE id = $1 FOR UPDATE")
+            .bind(credit_facility_id)
+            .execute(op.as_executor())
+            .await?;
+        Ok(())
+    }
+
+    async fn list_for_credit_facility_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<Participation>, ParticipationError> {
+        let mut participations = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_credit_facility_id_by_created_at_in_op(
+                    &mut *op,
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            participations.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(participations)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.participations.fund_disbursal_in_op",
+        skip(self, op),
+        fields(credit_facility_id = %credit_facility_id, disbursal_id = %disbursal_id)
+    )]
+    pub(super) async fn fund_disbursal_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        disbursal_id: DisbursalId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), ParticipationError> {
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                op,
+                PARTICIPATION_ALLOCATION,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_MANAGE_PARTICIPATIONS,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        let initiated_by = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+            PARTICIPATION_ALLOCATION,
+        );
+
+        for mut participation in self
+            .list_for_credit_facility_in_op(op, credit_facility_id)
+            .await?
+        {
+            let es_entity::Idempotent::Executed(Some(posting)) =
+                participation.fund_disbursal(disbursal_id, amount, effective)
+            else {
+                continue;
+            };
+
+            self.repo.update_in_op(op, &mut participation).await?;
+            self.ledger
+                .fund_participation_in_op(
+                    op,
+                    participation.id,
+                    posting.ledger_tx_id,
+                    posting.amount,
+                    participation.account_ids,
+                    effective,
+                    &initiated_by,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit.participations.allocate_receipt_in_op",
+        skip(self, op),
+        fields(credit_facility_id = %credit_facility_id, payment_allocation_id = %payment_allocation_id)
+    )]
+    pub(super) async fn allocate_receipt_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        payment_allocation_id: PaymentAllocationId,
+        obligation_type: ObligationType,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), ParticipationError> {
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                op,
+                PARTICIPATION_ALLOCATION,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_MANAGE_PARTICIPATIONS,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        let initiated_by = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+            PARTICIPATION_ALLOCATION,
+        );
+
+        for mut participation in self
+            .list_for_credit_facility_in_op(op, credit_facility_id)
+            .await?
+        {
+            let es_entity::Idempotent::Executed(Some(posting)) = participation.allocate_receipt(
+                payment_allocation_id,
+                obligation_type,
+                amount,
+                effective,
+            ) else {
+                continue;
+            };
+
+            self.repo.update_in_op(op, &mut participation).await?;
+            self.ledger
+                .allocate_participation_receipt_in_op(
+                    op,
+                    participation.id,
+                    posting.ledger_tx_id,
+                    posting.amount,
+                    obligation_type,
+                    participation.account_ids,
+                    effective,
+                    &initiated_by,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
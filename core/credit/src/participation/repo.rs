@@ -0,0 +1,47 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::entity::*;
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Participation",
+    columns(credit_facility_id(
+        ty = "CreditFacilityId",
+        list_for(by(created_at)),
+        update(persist = false)
+    )),
+    tbl_prefix = "core"
+)]
+pub(crate) struct ParticipationRepo {
+    pool: PgPool,
+    #[allow(dead_code)]
+    clock: ClockHandle,
+}
+
+impl ParticipationRepo {
+    pub(crate) fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
+
+impl From<(ParticipationsSortBy, &Participation)> for participation_cursor::ParticipationsCursor {
+    fn from(participation_with_sort: (ParticipationsSortBy, &Participation)) -> Self {
+        let (sort, participation) = participation_with_sort;
+        match sort {
+            ParticipationsSortBy::CreatedAt => {
+                participation_cursor::ParticipationsByCreatedAtCursor::from(participation).into()
+            }
+            ParticipationsSortBy::Id => {
+                participation_cursor::ParticipationsByIdCursor::from(participation).into()
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::primitives::{
+    CreditFacilityId, LedgerTxId, ParticipationId, ParticipationSharePct, UsdCents,
+};
+
+use super::{entity::Participation, error::ParticipationError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ParticipantStatementEntryType {
+    Funding,
+    Principal,
+    Interest,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParticipantStatementEntry {
+    pub entry_type: ParticipantStatementEntryType,
+    pub ledger_tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParticipantStatement {
+    pub participation_id: ParticipationId,
+    pub credit_facility_id: CreditFacilityId,
+    pub participant_name: String,
+    pub share: ParticipationSharePct,
+    pub total_funded: UsdCents,
+    pub total_principal_received: UsdCents,
+    pub total_interest_received: UsdCents,
+    pub outstanding_principal: UsdCents,
+    pub entries: Vec<ParticipantStatementEntry>,
+}
+
+impl From<&Participation> for ParticipantStatement {
+    fn from(participation: &Participation) -> Self {
+        Self {
+            participation_id: participation.id,
+            credit_facility_id: participation.credit_facility_id,
+            participant_name: participation.participant_name.clone(),
+            share: participation.share,
+            total_funded: participation.total_funded(),
+            total_principal_received: participation.total_principal_received(),
+            total_interest_received: participation.total_interest_received(),
+            outstanding_principal: participation.outstanding_principal(),
+            entries: participation.statement_entries(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ParticipantStatementCsvRow<'a> {
+    participant: &'a str,
+    share_pct: String,
+    effective: chrono::NaiveDate,
+    entry_type: String,
+    amount_usd: String,
+    ledger_tx_id: String,
+    recorded_at: String,
+}
+
+impl ParticipantStatement {
+    pub fn to_csv(&self) -> Result<Vec<u8>, ParticipationError> {
+        let mut wtr = csv::Writer::from_writer(Vec::new());
+
+        for entry in &self.entries {
+            wtr.serialize(ParticipantStatementCsvRow {
+                participant: &self.participant_name,
+                share_pct: self.share.to_string(),
+                effective: entry.effective,
+                entry_type: entry.entry_type.to_string(),
+                amount_usd: entry.amount.to_usd().to_string(),
+                ledger_tx_id: entry.ledger_tx_id.to_string(),
+                recorded_at: entry.recorded_at.to_rfc3339(),
+            })
+            .map_err(|e| ParticipationError::CsvError(e.to_string()))?;
+        }
+
+        wtr.into_inner()
+            .map_err(|e| ParticipationError::CsvError(e.to_string()))
+    }
+}
@@ -89,6 +89,8 @@ pub struct CreditOmnibusAccountSetCatalog {
     pub credit_payments_made_omnibus: CreditOmnibusAccountSetSpec,
     pub credit_facility_omnibus: CreditOmnibusAccountSetSpec,
     pub credit_facility_liquidation_proceeds_omnibus: CreditOmnibusAccountSetSpec,
+    pub credit_participation_funding_omnibus: CreditOmnibusAccountSetSpec,
+    pub credit_participation_interest_omnibus: CreditOmnibusAccountSetSpec,
 }
 
 #[derive(Debug, Clone)]
@@ -139,6 +141,8 @@ pub struct CreditSummaryAccountSetCatalog {
     pub credit_fee_income: CreditSummaryAccountSetSpec,
     pub credit_uncovered_outstanding: CreditSummaryAccountSetSpec,
     pub credit_payment_holding: CreditSummaryAccountSetSpec,
+    pub credit_participation_funded: CreditSummaryAccountSetSpec,
+    pub credit_participation_payable: CreditSummaryAccountSetSpec,
 }
 
 impl CreditAccountSetCatalog {
@@ -150,17 +154,19 @@ impl CreditAccountSetCatalog {
         &self.summary
     }
 
-    pub fn omnibus_specs(&self) -> [CreditOmnibusAccountSetSpec; 5] {
+    pub fn omnibus_specs(&self) -> [CreditOmnibusAccountSetSpec; 7] {
         [
             self.omnibus.credit_collateral_omnibus,
             self.omnibus.credit_interest_added_to_obligations_omnibus,
             self.omnibus.credit_payments_made_omnibus,
             self.omnibus.credit_facility_omnibus,
             self.omnibus.credit_facility_liquidation_proceeds_omnibus,
+            self.omnibus.credit_participation_funding_omnibus,
+            self.omnibus.credit_participation_interest_omnibus,
         ]
     }
 
-    pub fn summary_specs(&self) -> [CreditSummaryAccountSetSpec; 46] {
+    pub fn summary_specs(&self) -> [CreditSummaryAccountSetSpec; 48] {
         [
             self.summary.credit_facility_remaining,
             self.summary.credit_collateral,
@@ -226,6 +232,8 @@ impl CreditAccountSetCatalog {
             self.summary.credit_fee_income,
             self.summary.credit_uncovered_outstanding,
             self.summary.credit_payment_holding,
+            self.summary.credit_participation_funded,
+            self.summary.credit_participation_payable,
         ]
     }
 }
@@ -294,6 +302,36 @@ const CREDIT_FACILITY_LIQUIDATION_PROCEEDS_OMNIBUS: CreditOmnibusAccountSetSpec
         DebitOrCredit::Debit,
     );
 
+const CREDIT_PARTICIPATION_FUNDING_OMNIBUS_NAME: &str =
+    "Credit Participation Funding Omnibus Account Set";
+const CREDIT_PARTICIPATION_FUNDING_OMNIBUS_SET_REF: &str =
+    "credit-participation-funding-omnibus-account-set";
+const CREDIT_PARTICIPATION_FUNDING_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-participation-funding-omnibus-account";
+const CREDIT_PARTICIPATION_FUNDING_OMNIBUS: CreditOmnibusAccountSetSpec =
+    CreditOmnibusAccountSetSpec::new(
+        CREDIT_PARTICIPATION_FUNDING_OMNIBUS_NAME,
+        CREDIT_PARTICIPATION_FUNDING_OMNIBUS_SET_REF,
+        CREDIT_PARTICIPATION_FUNDING_OMNIBUS_ACCOUNT_REF,
+        CreditAccountCategory::Asset,
+        DebitOrCredit::Debit,
+    );
+
+const CREDIT_PARTICIPATION_INTEREST_OMNIBUS_NAME: &str =
+    "Credit Participation Interest Omnibus Account Set";
+const CREDIT_PARTICIPATION_INTEREST_OMNIBUS_SET_REF: &str =
+    "credit-participation-interest-omnibus-account-set";
+const CREDIT_PARTICIPATION_INTEREST_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-participation-interest-omnibus-account";
+const CREDIT_PARTICIPATION_INTEREST_OMNIBUS: CreditOmnibusAccountSetSpec =
+    CreditOmnibusAccountSetSpec::new(
+        CREDIT_PARTICIPATION_INTEREST_OMNIBUS_NAME,
+        CREDIT_PARTICIPATION_INTEREST_OMNIBUS_SET_REF,
+        CREDIT_PARTICIPATION_INTEREST_OMNIBUS_ACCOUNT_REF,
+        CreditAccountCategory::Expenses,
+        DebitOrCredit::Debit,
+    );
+
 // Summary Accounts
 const CREDIT_FACILITY_REMAINING_NAME: &str = "Credit Facility Remaining Account Set";
 const CREDIT_FACILITY_REMAINING_REF: &str = "credit-facility-remaining-account-set";
@@ -823,6 +861,24 @@ const CREDIT_PAYMENT_HOLDING: CreditSummaryAccountSetSpec = CreditSummaryAccount
     DebitOrCredit::Credit,
 );
 
+const CREDIT_PARTICIPATION_FUNDED_NAME: &str = "Credit Participation Funded Account Set";
+const CREDIT_PARTICIPATION_FUNDED_REF: &str = "credit-participation-funded-account-set";
+const CREDIT_PARTICIPATION_FUNDED: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_PARTICIPATION_FUNDED_NAME,
+    CREDIT_PARTICIPATION_FUNDED_REF,
+    CreditAccountCategory::Liability,
+    DebitOrCredit::Credit,
+);
+
+const CREDIT_PARTICIPATION_PAYABLE_NAME: &str = "Credit Participation Payable Account Set";
+const CREDIT_PARTICIPATION_PAYABLE_REF: &str = "credit-participation-payable-account-set";
+const CREDIT_PARTICIPATION_PAYABLE: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_PARTICIPATION_PAYABLE_NAME,
+    CREDIT_PARTICIPATION_PAYABLE_REF,
+    CreditAccountCategory::Liability,
+    DebitOrCredit::Credit,
+);
+
 // Assembles all module-level account set spec constants into the canonical catalog.
 // This is the single source of truth for the credit module's account set definitions.
 pub const CREDIT_ACCOUNT_SET_CATALOG: CreditAccountSetCatalog = CreditAccountSetCatalog {
@@ -832,6 +888,8 @@ pub const CREDIT_ACCOUNT_SET_CATALOG: CreditAccountSetCatalog = CreditAccountSet
         credit_payments_made_omnibus: CREDIT_PAYMENTS_MADE_OMNIBUS,
         credit_facility_omnibus: CREDIT_FACILITY_OMNIBUS,
         credit_facility_liquidation_proceeds_omnibus: CREDIT_FACILITY_LIQUIDATION_PROCEEDS_OMNIBUS,
+        credit_participation_funding_omnibus: CREDIT_PARTICIPATION_FUNDING_OMNIBUS,
+        credit_participation_interest_omnibus: CREDIT_PARTICIPATION_INTEREST_OMNIBUS,
     },
     summary: CreditSummaryAccountSetCatalog {
         credit_facility_remaining: CREDIT_FACILITY_REMAINING,
@@ -904,5 +962,7 @@ pub const CREDIT_ACCOUNT_SET_CATALOG: CreditAccountSetCatalog = CreditAccountSet
         credit_fee_income: CREDIT_FEE_INCOME,
         credit_uncovered_outstanding: CREDIT_UNCOVERED_OUTSTANDING,
         credit_payment_holding: CREDIT_PAYMENT_HOLDING,
+        credit_participation_funded: CREDIT_PARTICIPATION_FUNDED,
+        credit_participation_payable: CREDIT_PARTICIPATION_PAYABLE,
     },
 };
@@ -23,6 +23,8 @@ pub const PENDING_FACILITY_CREATION: audit::SystemActor =
 pub const CREDIT_FACILITY_PAYMENT_ALLOCATION: audit::SystemActor =
     audit::SystemActor::new("credit-facility-payment-allocation");
 pub const DISBURSAL_APPROVAL: audit::SystemActor = audit::SystemActor::new("disbursal-approval");
+pub const PARTICIPATION_ALLOCATION: audit::SystemActor =
+    audit::SystemActor::new("participation-allocation");
 
 pub use cala_ledger::primitives::{
     AccountId as CalaAccountId, AccountSetId as CalaAccountSetId, Currency,
@@ -70,7 +72,9 @@ es_entity::entity_id! {
     DisbursalId,
     ChartOfAccountsIntegrationConfigId,
     InterestAccrualCycleId,
-    FiscalYearId;
+    FiscalYearId,
+    ParticipationId,
+    CreditFacilityStatementId;
 
     CreditFacilityProposalId => PendingCreditFacilityId,
 
@@ -83,9 +87,12 @@ es_entity::entity_id! {
 
     CreditFacilityId => job::JobId,
     InterestAccrualCycleId => job::JobId,
+    CreditFacilityStatementId => job::JobId,
 
     DisbursalId => LedgerTxId,
 
+    CreditFacilityId => document_storage::ReferenceId,
+
     CreditFacilityId => public_id::PublicIdTargetId,
     DisbursalId => public_id::PublicIdTargetId,
 
@@ -106,6 +113,8 @@ pub const CREDIT_FACILITY_PROPOSAL_ENTITY_TYPE: chart_primitives::EntityType =
     chart_primitives::EntityType::new("CreditFacilityProposal");
 pub const DISBURSAL_TRANSACTION_ENTITY_TYPE: chart_primitives::EntityType =
     chart_primitives::EntityType::new("Disbursal");
+pub const PARTICIPATION_ENTITY_TYPE: chart_primitives::EntityType =
+    chart_primitives::EntityType::new("Participation");
 
 pub type CreditFacilityAllOrOne = AllOrOne<CreditFacilityId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
@@ -230,6 +239,10 @@ impl CoreCreditAction {
         CoreCreditAction::CreditFacility(CreditFacilityAction::Complete);
     pub const CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateralizationState);
+    pub const CREDIT_FACILITY_MANAGE_PARTICIPATIONS: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::ManageParticipations);
+    pub const CREDIT_FACILITY_SIMULATE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Simulate);
 
     pub const CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ: Self =
         CoreCreditAction::ChartOfAccountsIntegrationConfig(
@@ -318,19 +331,22 @@ pub enum CreditFacilityAction {
     Complete,
     UpdateCollateralizationState,
     CustomerApprove,
+    ManageParticipations,
+    Simulate,
 }
 
 impl ActionPermission for CreditFacilityAction {
     fn permission_set(&self) -> &'static str {
         match self {
-            Self::Read | Self::List => PERMISSION_SET_CREDIT_VIEWER,
+            Self::Read | Self::List | Self::Simulate => PERMISSION_SET_CREDIT_VIEWER,
             Self::Create
             | Self::ConcludeApprovalProcess
             | Self::Activate
             | Self::RecordInterest
             | Self::Complete
             | Self::CustomerApprove
-            | Self::UpdateCollateralizationState => PERMISSION_SET_CREDIT_WRITER,
+            | Self::UpdateCollateralizationState
+            | Self::ManageParticipations => PERMISSION_SET_CREDIT_WRITER,
         }
     }
 }
@@ -551,3 +567,73 @@ pub enum DisbursedReceivableAccountCategory {
     ShortTerm,
     Overdue,
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "json-schema", schemars(transparent))]
+#[serde(try_from = "rust_decimal::Decimal", into = "rust_decimal::Decimal")]
+pub struct ParticipationSharePct(rust_decimal::Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(ParticipationSharePct);
+
+#[derive(thiserror::Error, Debug)]
+#[error("ParticipationSharePctError - OutOfRange: {0} is not between 0 and 100")]
+pub struct ParticipationSharePctOutOfRange(rust_decimal::Decimal);
+
+impl ParticipationSharePct {
+    pub const ZERO: Self = Self(rust_decimal::Decimal::ZERO);
+    pub const FULL: Self = Self(rust_decimal::Decimal::ONE_HUNDRED);
+
+    pub fn new(pct: u64) -> Self {
+        Self(rust_decimal::Decimal::from(pct))
+    }
+
+    pub fn is_valid_share(&self) -> bool {
+        *self > Self::ZERO && *self <= Self::FULL
+    }
+
+    /// Share of `amount` rounded down to the cent, the lead lender keeps the remainder.
+    pub fn share_of(&self, amount: UsdCents) -> UsdCents {
+        let cents = (rust_decimal::Decimal::from(amount.into_inner()) * self.0
+            / rust_decimal::Decimal::ONE_HUNDRED)
+            .floor();
+        UsdCents::from(u64::try_from(cents).expect("share is never negative"))
+    }
+}
+
+impl std::ops::Add for ParticipationSharePct {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl std::iter::Sum for ParticipationSharePct {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, pct| acc + pct)
+    }
+}
+
+impl TryFrom<rust_decimal::Decimal> for ParticipationSharePct {
+    type Error = ParticipationSharePctOutOfRange;
+
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        if value.is_sign_negative() || value > rust_decimal::Decimal::ONE_HUNDRED {
+            return Err(ParticipationSharePctOutOfRange(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<ParticipationSharePct> for rust_decimal::Decimal {
+    fn from(value: ParticipationSharePct) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for ParticipationSharePct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -40,6 +40,21 @@ pub struct CreditFacilityRepaymentPlan {
 }
 
 impl CreditFacilityRepaymentPlan {
+    pub(crate) fn projected(
+        facility_amount: UsdCents,
+        terms: TermValues,
+        activated_at: DateTime<Utc>,
+    ) -> Self {
+        let mut plan = Self {
+            facility_amount,
+            terms: Some(terms),
+            activated_at: Some(activated_at),
+            ..Default::default()
+        };
+        plan.rebuild_entries(vec![], activated_at);
+        plan
+    }
+
     fn existing_obligations(&self) -> Vec<CreditFacilityRepaymentPlanEntry> {
         self.entries
             .iter()
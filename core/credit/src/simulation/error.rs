@@ -0,0 +1,20 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum CreditSimulationError {
+    #[error("CreditSimulationError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CreditSimulationError - ZeroFacilityAmount")]
+    ZeroFacilityAmount,
+}
+
+impl ErrorSeverity for CreditSimulationError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::AuthorizationError(e) => e.severity(),
+            Self::ZeroFacilityAmount => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+pub mod error;
+mod projection;
+
+use std::sync::Arc;
+
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_price::Price;
+use es_entity::clock::ClockHandle;
+
+use crate::primitives::*;
+
+use error::CreditSimulationError;
+pub use projection::*;
+
+pub struct CreditSimulations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Arc<Perms>,
+    price: Arc<Price>,
+    clock: ClockHandle,
+}
+
+impl<Perms> Clone for CreditSimulations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            price: self.price.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<Perms> CreditSimulations<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    pub fn new(authz: Arc<Perms>, price: Arc<Price>, clock: ClockHandle) -> Self {
+        Self {
+            authz,
+            price,
+            clock,
+        }
+    }
+
+    /// Runs a what-if projection without persisting anything. When `btc_price` is not
+    /// given the latest known price is used.
+    #[record_error_severity]
+    #[instrument(name = "core_credit.simulations.simulate", skip(self, scenarios))]
+    pub async fn simulate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        terms: TermValues,
+        facility_amount: UsdCents,
+        collateral: Satoshis,
+        btc_price: Option<PriceOfOneBTC>,
+        scenarios: Vec<PriceStressScenario>,
+    ) -> Result<CreditTermsSimulation, CreditSimulationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_SIMULATE,
+            )
+            .await?;
+
+        if facility_amount.is_zero() {
+            return Err(CreditSimulationError::ZeroFacilityAmount);
+        }
+
+        let btc_price = match btc_price {
+            Some(price) => price,
+            None => self.price.usd_cents_per_btc().await,
+        };
+
+        Ok(CreditTermsSimulation::project(
+            terms,
+            facility_amount,
+            collateral,
+            btc_price,
+            self.clock.now(),
+            scenarios,
+        ))
+    }
+}
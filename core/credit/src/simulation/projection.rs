@@ -0,0 +1,314 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{primitives::*, repayment_plan::*};
+
+#[derive(Debug, Clone, Copy)]
+pub struct PricePathPoint {
+    pub date: NaiveDate,
+    pub price: PriceOfOneBTC,
+}
+
+#[derive(Debug, Clone)]
+pub struct PriceStressScenario {
+    pub name: String,
+    pub price_path: Vec<PricePathPoint>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StressScenarioPoint {
+    pub date: NaiveDate,
+    pub price: PriceOfOneBTC,
+    pub outstanding: UsdCents,
+    pub collateral_value: UsdCents,
+    pub cvl: CVLPct,
+    pub collateralization_state: CollateralizationState,
+}
+
+#[derive(Debug, Clone)]
+pub struct StressScenarioOutcome {
+    pub name: String,
+    pub points: Vec<StressScenarioPoint>,
+    pub first_margin_call_on: Option<NaiveDate>,
+    pub first_liquidation_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditTermsSimulation {
+    pub terms: TermValues,
+    pub facility_amount: UsdCents,
+    pub collateral: Satoshis,
+    pub btc_price: PriceOfOneBTC,
+    pub starts_at: DateTime<Utc>,
+    pub matures_at: EffectiveDate,
+
+    pub structuring_fee: UsdCents,
+    pub total_interest: UsdCents,
+    pub total_repayment: UsdCents,
+    pub effective_annual_rate: AnnualRatePct,
+
+    pub initial_cvl: CVLPct,
+    pub margin_call_price: Option<PriceOfOneBTC>,
+    pub liquidation_price: Option<PriceOfOneBTC>,
+
+    pub cash_flows: Vec<CreditFacilityRepaymentPlanEntry>,
+    pub stress_scenarios: Vec<StressScenarioOutcome>,
+}
+
+impl CreditTermsSimulation {
+    /// Projects the facility as if it were fully drawn at `starts_at` and every obligation
+    /// were repaid on its due date, never early. Trigger prices and scenario CVLs share
+    /// that outstanding basis; the trigger prices use its peak over the plan.
+    pub fn project(
+        terms: TermValues,
+        facility_amount: UsdCents,
+        collateral: Satoshis,
+        btc_price: PriceOfOneBTC,
+        starts_at: DateTime<Utc>,
+        scenarios: Vec<PriceStressScenario>,
+    ) -> Self {
+        let cash_flows =
+            CreditFacilityRepaymentPlan::projected(facility_amount, terms, starts_at).entries;
+
+        let total_interest = sum_of(&cash_flows, RepaymentType::Interest);
+        let total_disbursed = sum_of(&cash_flows, RepaymentType::Disbursal);
+
+        let initial_cvl = CVLPct::from_loan_amounts(
+            btc_price.sats_to_cents_round_down(collateral),
+            outstanding_on(&cash_flows, starts_at.date_naive()),
+        );
+        let peak_outstanding = peak_outstanding(&cash_flows);
+
+        let stress_scenarios = scenarios
+            .into_iter()
+            .map(|scenario| run_scenario(&terms, collateral, &cash_flows, scenario))
+            .collect();
+
+        Self {
+            terms,
+            facility_amount,
+            collateral,
+            btc_price,
+            starts_at,
+            matures_at: terms.maturity_date(starts_at),
+            structuring_fee: terms.one_time_fee_rate.apply(facility_amount),
+            total_interest,
+            total_repayment: total_disbursed + total_interest,
+            effective_annual_rate: terms.effective_annual_rate(),
+            initial_cvl,
+            margin_call_price: terms.margin_call_price(peak_outstanding, collateral),
+            liquidation_price: terms.liquidation_price(peak_outstanding, collateral),
+            cash_flows,
+            stress_scenarios,
+        }
+    }
+}
+
+fn sum_of(entries: &[CreditFacilityRepaymentPlanEntry], repayment_type: RepaymentType) -> UsdCents {
+    entries
+        .iter()
+        .filter(|entry| entry.repayment_type == repayment_type)
+        .fold(UsdCents::ZERO, |total, entry| total + entry.initial)
+}
+
+/// Amount owed at the end of `date`: every entry that has become effective and whose
+/// due date has not yet passed. Entries due on `date` itself are still outstanding.
+fn outstanding_on(entries: &[CreditFacilityRepaymentPlanEntry], date: NaiveDate) -> UsdCents {
+    entries
+        .iter()
+        .filter(|entry| entry.effective <= date && date <= NaiveDate::from(entry.due_at))
+        .fold(UsdCents::ZERO, |total, entry| total + entry.initial)
+}
+
+fn peak_outstanding(entries: &[CreditFacilityRepaymentPlanEntry]) -> UsdCents {
+    entries
+        .iter()
+        .map(|entry| outstanding_on(entries, entry.effective))
+        .max()
+        .unwrap_or(UsdCents::ZERO)
+}
+
+fn run_scenario(
+    terms: &TermValues,
+    collateral: Satoshis,
+    cash_flows: &[CreditFacilityRepaymentPlanEntry],
+    scenario: PriceStressScenario,
+) -> StressScenarioOutcome {
+    let mut price_path = scenario.price_path;
+    price_path.sort_by_key(|point| point.date);
+
+    let points: Vec<StressScenarioPoint> = price_path
+        .into_iter()
+        .map(|PricePathPoint { date, price }| {
+            let outstanding = outstanding_on(cash_flows, date);
+            let collateral_value = price.sats_to_cents_round_down(collateral);
+            let cvl = CVLPct::from_loan_amounts(collateral_value, outstanding);
+            StressScenarioPoint {
+                date,
+                price,
+                outstanding,
+                collateral_value,
+                cvl,
+                collateralization_state: terms.collateralization(cvl),
+            }
+        })
+        .collect();
+
+    let first_below = |threshold: CVLPct| {
+        points
+            .iter()
+            .find(|point| point.cvl < threshold)
+            .map(|point| point.date)
+    };
+
+    StressScenarioOutcome {
+        name: scenario.name,
+        first_margin_call_on: first_below(terms.margin_call_cvl),
+        first_liquidation_on: first_below(terms.liquidation_cvl),
+        points,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::new(1))
+            .disbursal_policy(DisbursalPolicy::SingleDisbursal)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn starts_at() -> DateTime<Utc> {
+        "2025-01-01T00:00:00Z".parse().unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn price(usd: u64) -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::from(usd * 100))
+    }
+
+    #[test]
+    fn projects_fee_and_interest_cash_flows() {
+        let simulation = CreditTermsSimulation::project(
+            terms(),
+            UsdCents::from(10_000_000),
+            Satoshis::from(300_000_000),
+            price(100_000),
+            starts_at(),
+            vec![],
+        );
+
+        assert_eq!(simulation.structuring_fee, UsdCents::from(100_000));
+        assert!(
+            simulation
+                .cash_flows
+                .iter()
+                .any(|e| e.repayment_type == RepaymentType::Interest)
+        );
+        assert!(!simulation.total_interest.is_zero());
+        assert_eq!(
+            simulation.total_repayment,
+            UsdCents::from(10_100_000) + simulation.total_interest
+        );
+    }
+
+    #[test]
+    fn stress_scenario_flags_margin_call_and_liquidation() {
+        let scenario = PriceStressScenario {
+            name: "crash".to_string(),
+            price_path: vec![
+                PricePathPoint {
+                    date: date("2025-03-01"),
+                    price: price(30_000),
+                },
+                PricePathPoint {
+                    date: date("2025-01-15"),
+                    price: price(100_000),
+                },
+                PricePathPoint {
+                    date: date("2025-02-01"),
+                    price: price(40_000),
+                },
+            ],
+        };
+
+        let simulation = CreditTermsSimulation::project(
+            terms(),
+            UsdCents::from(10_000_000),
+            Satoshis::from(300_000_000),
+            price(100_000),
+            starts_at(),
+            vec![scenario],
+        );
+
+        let outcome = &simulation.stress_scenarios[0];
+        assert_eq!(outcome.points[0].date, date("2025-01-15"));
+        assert_eq!(
+            outcome.points[0].collateralization_state,
+            CollateralizationState::FullyCollateralized
+        );
+        assert_eq!(outcome.first_margin_call_on, Some(date("2025-02-01")));
+        assert_eq!(outcome.first_liquidation_on, Some(date("2025-03-01")));
+    }
+
+    #[test]
+    fn outstanding_nets_out_obligations_past_their_due_date() {
+        let scenario = PriceStressScenario {
+            name: "flat".to_string(),
+            price_path: vec![
+                PricePathPoint {
+                    date: date("2025-01-31"),
+                    price: price(100_000),
+                },
+                PricePathPoint {
+                    date: date("2025-02-01"),
+                    price: price(100_000),
+                },
+                PricePathPoint {
+                    date: date("2025-06-01"),
+                    price: price(100_000),
+                },
+            ],
+        };
+
+        let simulation = CreditTermsSimulation::project(
+            terms(),
+            UsdCents::from(10_000_000),
+            Satoshis::from(300_000_000),
+            price(100_000),
+            starts_at(),
+            vec![scenario],
+        );
+
+        let points = &simulation.stress_scenarios[0].points;
+        let principal_and_fee = UsdCents::from(10_100_000);
+        assert!(points[0].outstanding > principal_and_fee);
+        assert_eq!(points[1].outstanding, principal_and_fee);
+        assert!(points[2].outstanding.is_zero());
+
+        let peak = points[0].outstanding;
+        assert_eq!(
+            simulation.margin_call_price,
+            simulation
+                .terms
+                .margin_call_price(peak, simulation.collateral)
+        );
+    }
+}
@@ -25,6 +25,10 @@ pub struct AnnualRatePct(Decimal);
 async_graphql::scalar!(AnnualRatePct);
 
 impl AnnualRatePct {
+    pub fn into_inner(self) -> Decimal {
+        self.0
+    }
+
     pub fn interest_for_time_period(&self, principal: UsdCents, days: u32) -> UsdCents {
         let cents = principal.to_usd() * Decimal::from(days) * self.0
             / Decimal::from(NUMBER_OF_DAYS_IN_YEAR);
@@ -375,6 +379,42 @@ impl TermValues {
         };
         AnnualRatePct(self.annual_rate.0 + self.one_time_fee_rate.0 * annualization_factor)
     }
+
+    pub fn margin_call_price(
+        &self,
+        outstanding: UsdCents,
+        collateral: Satoshis,
+    ) -> Option<PriceOfOneBTC> {
+        Self::price_at_cvl(self.margin_call_cvl, outstanding, collateral)
+    }
+
+    pub fn liquidation_price(
+        &self,
+        outstanding: UsdCents,
+        collateral: Satoshis,
+    ) -> Option<PriceOfOneBTC> {
+        Self::price_at_cvl(self.liquidation_cvl, outstanding, collateral)
+    }
+
+    fn price_at_cvl(
+        cvl: CVLPct,
+        outstanding: UsdCents,
+        collateral: Satoshis,
+    ) -> Option<PriceOfOneBTC> {
+        let CVLPct::Finite(pct) = cvl else {
+            return None;
+        };
+        if outstanding.is_zero() || collateral.is_zero() {
+            return None;
+        }
+
+        let usd_per_btc = pct / dec!(100) * outstanding.to_usd() / collateral.to_btc();
+        UsdCents::try_from_usd(
+            usd_per_btc.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero),
+        )
+        .ok()
+        .map(PriceOfOneBTC::new)
+    }
 }
 
 impl TermValuesBuilder {
@@ -777,6 +817,44 @@ mod test {
         }
     }
 
+    mod trigger_prices {
+        use super::*;
+
+        #[test]
+        fn margin_call_price_scales_with_outstanding() {
+            let terms = default_terms();
+            let price =
+                terms.margin_call_price(UsdCents::from(10_000_000), Satoshis::from(200_000_000));
+            assert_eq!(price, Some(PriceOfOneBTC::new(UsdCents::from(6_250_000))));
+        }
+
+        #[test]
+        fn liquidation_price_below_margin_call_price() {
+            let terms = default_terms();
+            let outstanding = UsdCents::from(10_000_000);
+            let collateral = Satoshis::from(200_000_000);
+            let liquidation = terms.liquidation_price(outstanding, collateral).unwrap();
+            let margin_call = terms.margin_call_price(outstanding, collateral).unwrap();
+            assert_eq!(liquidation, PriceOfOneBTC::new(UsdCents::from(5_250_000)));
+            assert!(liquidation < margin_call);
+        }
+
+        #[test]
+        fn no_price_without_collateral_or_exposure() {
+            let terms = default_terms();
+            assert!(
+                terms
+                    .margin_call_price(UsdCents::ZERO, Satoshis::from(100_000_000))
+                    .is_none()
+            );
+            assert!(
+                terms
+                    .liquidation_price(UsdCents::from(100), Satoshis::ZERO)
+                    .is_none()
+            );
+        }
+    }
+
     mod collateralization_update {
         use super::*;
 
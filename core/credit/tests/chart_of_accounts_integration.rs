@@ -121,7 +121,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &pool,
         &authz,
         &outbox,
-        document_storage,
+        document_storage.clone(),
         public_ids.clone(),
         &exposed_domain_configs,
         clock.clone(),
@@ -140,11 +140,13 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &public_ids,
         &exposed_domain_configs,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
     let accounting_document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
-    let accounting = CoreAccounting::new(
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -152,7 +154,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         accounting_document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+        &exposed_domain_configs,
+        &price,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let chart_id = accounting
         .chart_of_accounts()
@@ -285,10 +285,11 @@ pub async fn create_test_statements<Perms, E>(
 where
     Perms: authz::PermissionCheck,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Action:
-        From<core_accounting::CoreAccountingAction>,
+        From<core_accounting::CoreAccountingAction> + From<governance::GovernanceAction>,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Object:
-        From<core_accounting::CoreAccountingObject>,
-    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>,
+        From<core_accounting::CoreAccountingObject> + From<governance::GovernanceObject>,
+    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>
+        + obix::out::OutboxEventMarker<governance::GovernanceEvent>,
 {
     let bs = format!("BS-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let pl = format!("PL-{:010}", rand::rng().random_range(0..10_000_000_000u64));
@@ -632,7 +633,7 @@ pub async fn setup() -> anyhow::Result<TestContext> {
         &pool,
         &authz,
         &outbox,
-        document_storage,
+        document_storage.clone(),
         public_ids,
         &domain_configs,
         clock.clone(),
@@ -652,6 +653,8 @@ pub async fn setup() -> anyhow::Result<TestContext> {
         &credit_public_ids,
         &domain_configs,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
@@ -668,6 +671,8 @@ pub async fn setup() -> anyhow::Result<TestContext> {
         &customers,
         &domain_configs,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
@@ -127,7 +127,7 @@ async fn setup_with_clock_control() -> anyhow::Result<(
         &pool,
         &authz,
         &outbox,
-        document_storage,
+        document_storage.clone(),
         public_ids,
         &domain_configs,
         clock.clone(),
@@ -147,6 +147,8 @@ async fn setup_with_clock_control() -> anyhow::Result<(
         &credit_public_ids,
         &domain_configs,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
@@ -163,6 +165,8 @@ async fn setup_with_clock_control() -> anyhow::Result<(
         &customers,
         &domain_configs,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
@@ -43,6 +43,7 @@ pub enum KycLevel {
     Serialize,
     Eq,
     PartialEq,
+    Hash,
     strum::Display,
     strum::EnumString,
 )]
@@ -1,4 +1,5 @@
 use derive_builder::Builder;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -6,7 +7,10 @@ use serde::{Deserialize, Serialize};
 use es_entity::*;
 
 use super::error::DepositAccountError;
-use crate::{ledger::*, primitives::*};
+use crate::{
+    fee::DepositFeeType, ledger::*, primitives::*, product::DepositRatePct,
+    withdrawal_limit::WithdrawalLimits,
+};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -21,6 +25,10 @@ pub enum DepositAccountEvent {
         #[serde(default = "default_account_activity")]
         activity: Activity,
         public_id: PublicId,
+        #[serde(default = "default_account_currency")]
+        currency: CurrencyCode,
+        #[serde(default)]
+        signing_rule: SigningRule,
     },
     ActivityUpdated {
         activity: Activity,
@@ -34,6 +42,115 @@ pub enum DepositAccountEvent {
     Closed {
         status: DepositAccountStatus,
     },
+    DepositProductAssigned {
+        deposit_product_id: DepositProductId,
+        assigned_at: chrono::DateTime<chrono::Utc>,
+    },
+    DailyInterestComputed {
+        computed_on: chrono::NaiveDate,
+        balance: UsdCents,
+        interest: Decimal,
+    },
+    InterestAccrued {
+        accrued_on: chrono::NaiveDate,
+        amount: UsdCents,
+        ledger_tx_id: Option<CalaTransactionId>,
+        #[serde(default)]
+        carried_remainder: Decimal,
+    },
+    InterestPosted {
+        posted_on: chrono::NaiveDate,
+        amount: UsdCents,
+        ledger_tx_id: CalaTransactionId,
+    },
+    EarlyWithdrawalPenaltyCharged {
+        withdrawal_id: WithdrawalId,
+        amount: UsdCents,
+        ledger_tx_id: CalaTransactionId,
+    },
+    FeeCharged {
+        fee_type: DepositFeeType,
+        amount: UsdCents,
+        period_start: Option<chrono::NaiveDate>,
+        withdrawal_id: Option<WithdrawalId>,
+        ledger_tx_id: CalaTransactionId,
+    },
+    FeeReversed {
+        fee_ledger_tx_id: CalaTransactionId,
+        amount: UsdCents,
+        ledger_tx_id: CalaTransactionId,
+    },
+    EscheatmentNoticeIssued {
+        escheatment_date: chrono::NaiveDate,
+    },
+    Escheated {
+        status: DepositAccountStatus,
+        escheated_on: chrono::NaiveDate,
+        amount: UsdCents,
+        ledger_tx_id: Option<CalaTransactionId>,
+    },
+    EscheatedBalanceReclaimed {
+        status: DepositAccountStatus,
+        amount: UsdCents,
+        ledger_tx_id: Option<CalaTransactionId>,
+    },
+    WithdrawalLimitIncreaseRequested {
+        approval_process_id: ApprovalProcessId,
+        limits: WithdrawalLimits,
+    },
+    WithdrawalLimitIncreaseConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    },
+    JointHolderAdded {
+        account_holder_id: DepositAccountHolderId,
+        signing_rule: SigningRule,
+    },
+    JointHolderRemoved {
+        account_holder_id: DepositAccountHolderId,
+    },
+    HolderSigningRuleUpdated {
+        account_holder_id: DepositAccountHolderId,
+        signing_rule: SigningRule,
+    },
+    HolderSuspended {
+        account_holder_id: DepositAccountHolderId,
+    },
+    HolderReinstated {
+        account_holder_id: DepositAccountHolderId,
+    },
+}
+
+/// One of the holders of a deposit account. The primary holder is the one the
+/// account was opened for; joint holders are added afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepositAccountHolder {
+    pub account_holder_id: DepositAccountHolderId,
+    pub signing_rule: SigningRule,
+    pub primary: bool,
+    /// Set while the holder's own deposits are frozen and the account is kept
+    /// open for the remaining holders.
+    pub suspended: bool,
+}
+
+pub struct InterestAccrual {
+    pub amount: UsdCents,
+    pub ledger_tx_id: CalaTransactionId,
+}
+
+pub struct InterestPosting {
+    pub amount: UsdCents,
+    pub ledger_tx_id: CalaTransactionId,
+}
+
+pub struct FeeReversal {
+    pub amount: UsdCents,
+    pub ledger_tx_id: CalaTransactionId,
+}
+
+pub struct EscheatmentTransfer {
+    pub amount: UsdCents,
+    pub ledger_tx_id: CalaTransactionId,
 }
 
 #[derive(EsEntity, Builder)]
@@ -45,6 +162,9 @@ pub struct DepositAccount {
     pub status: DepositAccountStatus,
     pub activity: Activity,
     pub public_id: PublicId,
+    pub currency: CurrencyCode,
+    #[builder(setter(strip_option), default)]
+    pub deposit_product_id: Option<DepositProductId>,
 
     events: EntityEvents<DepositAccountEvent>,
 }
@@ -64,8 +184,26 @@ impl DepositAccount {
         self.status == DepositAccountStatus::Frozen
     }
 
+    pub fn is_escheated(&self) -> bool {
+        self.status == DepositAccountStatus::Escheated
+    }
+
+    pub(crate) fn ensure_currency(
+        &self,
+        currency: CurrencyCode,
+    ) -> Result<(), DepositAccountError> {
+        if self.currency != currency {
+            return Err(DepositAccountError::CurrencyMismatch {
+                id: self.id,
+                expected: currency,
+                actual: self.currency,
+            });
+        }
+        Ok(())
+    }
+
     pub(crate) fn update_activity(&mut self, activity: Activity) -> Idempotent<()> {
-        if self.activity == Activity::Escheatable {
+        if self.is_escheated() {
             return Idempotent::AlreadyApplied;
         }
         idempotency_guard!(
@@ -88,6 +226,9 @@ impl DepositAccount {
         if self.is_closed() {
             return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
         }
+        if self.is_escheated() {
+            return Err(DepositAccountError::CannotUpdateEscheatedAccount(self.id));
+        }
         let status = DepositAccountStatus::Frozen;
         self.events.push(DepositAccountEvent::Frozen { status });
         self.status = status;
@@ -112,6 +253,664 @@ impl DepositAccount {
         Ok(Idempotent::Executed(()))
     }
 
+    pub fn product_assigned_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            DepositAccountEvent::DepositProductAssigned { assigned_at, .. } => Some(*assigned_at),
+            _ => None,
+        })
+    }
+
+    pub fn last_interest_accrual_date(&self) -> Option<chrono::NaiveDate> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            DepositAccountEvent::InterestAccrued { accrued_on, .. } => Some(*accrued_on),
+            _ => None,
+        })
+    }
+
+    pub fn last_interest_computation_date(&self) -> Option<chrono::NaiveDate> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            DepositAccountEvent::DailyInterestComputed { computed_on, .. } => Some(*computed_on),
+            _ => None,
+        })
+    }
+
+    /// Fraction of a cent left over from the previous accrual.
+    fn carried_interest_remainder(&self) -> Decimal {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                DepositAccountEvent::InterestAccrued {
+                    carried_remainder, ..
+                } => Some(*carried_remainder),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Interest accrued since the last posting that has not yet been credited
+    /// to the settled balance.
+    pub fn accrued_interest(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .rev()
+            .take_while(|e| !matches!(e, DepositAccountEvent::InterestPosted { .. }))
+            .fold(UsdCents::ZERO, |total, e| match e {
+                DepositAccountEvent::InterestAccrued { amount, .. } => total + *amount,
+                _ => total,
+            })
+    }
+
+    pub(crate) fn assign_deposit_product(
+        &mut self,
+        deposit_product_id: DepositProductId,
+        assigned_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        match self.deposit_product_id {
+            Some(existing) if existing == deposit_product_id => {
+                return Ok(Idempotent::AlreadyApplied);
+            }
+            Some(_) => return Err(DepositAccountError::DepositProductAlreadyAssigned(self.id)),
+            None => {}
+        }
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        // Deposit products price interest and fees in USD.
+        self.ensure_currency(CurrencyCode::USD)?;
+        self.events
+            .push(DepositAccountEvent::DepositProductAssigned {
+                deposit_product_id,
+                assigned_at,
+            });
+        self.deposit_product_id = Some(deposit_product_id);
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Records one day of simple interest on the end-of-day `balance`. Days
+    /// missed since the previous computation are charged at the same balance.
+    pub(crate) fn compute_daily_interest(
+        &mut self,
+        computed_on: chrono::NaiveDate,
+        balance: UsdCents,
+        rate: DepositRatePct,
+    ) -> Idempotent<()> {
+        let Some(since) = self
+            .last_interest_computation_date()
+            .or_else(|| self.product_assigned_at().map(|at| at.date_naive()))
+        else {
+            return Idempotent::AlreadyApplied;
+        };
+        if computed_on <= since {
+            return Idempotent::AlreadyApplied;
+        }
+
+        let days = u32::try_from((computed_on - since).num_days()).unwrap_or(0);
+        self.events
+            .push(DepositAccountEvent::DailyInterestComputed {
+                computed_on,
+                balance,
+                interest: rate.interest_for_days(balance, days),
+            });
+        Idempotent::Executed(())
+    }
+
+    /// Accrues the interest computed since the previous accrual in whole cents,
+    /// carrying the fraction of a cent forward to the next accrual.
+    pub(crate) fn accrue_interest(
+        &mut self,
+        accrued_on: chrono::NaiveDate,
+    ) -> Idempotent<Option<InterestAccrual>> {
+        let Some(since) = self
+            .last_interest_accrual_date()
+            .or_else(|| self.product_assigned_at().map(|at| at.date_naive()))
+        else {
+            return Idempotent::AlreadyApplied;
+        };
+        if accrued_on <= since {
+            return Idempotent::AlreadyApplied;
+        }
+
+        let total = self.carried_interest_remainder()
+            + self
+                .events
+                .iter_all()
+                .filter_map(|e| match e {
+                    DepositAccountEvent::DailyInterestComputed {
+                        computed_on,
+                        interest,
+                        ..
+                    } if *computed_on > since && *computed_on <= accrued_on => Some(*interest),
+                    _ => None,
+                })
+                .sum::<Decimal>();
+        let whole_cents = total.trunc();
+        let amount = UsdCents::from(whole_cents.to_u64().unwrap_or(0));
+        let ledger_tx_id = (!amount.is_zero()).then(CalaTransactionId::new);
+        self.events.push(DepositAccountEvent::InterestAccrued {
+            accrued_on,
+            amount,
+            ledger_tx_id,
+            carried_remainder: total - whole_cents,
+        });
+        Idempotent::Executed(ledger_tx_id.map(|ledger_tx_id| InterestAccrual {
+            amount,
+            ledger_tx_id,
+        }))
+    }
+
+    pub(crate) fn post_interest(
+        &mut self,
+        posted_on: chrono::NaiveDate,
+    ) -> Idempotent<InterestPosting> {
+        let amount = self.accrued_interest();
+        if amount.is_zero() {
+            return Idempotent::AlreadyApplied;
+        }
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::InterestPosted {
+            posted_on,
+            amount,
+            ledger_tx_id,
+        });
+        Idempotent::Executed(InterestPosting {
+            amount,
+            ledger_tx_id,
+        })
+    }
+
+    pub(crate) fn charge_early_withdrawal_penalty(
+        &mut self,
+        withdrawal_id: WithdrawalId,
+        amount: UsdCents,
+    ) -> Idempotent<CalaTransactionId> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositAccountEvent::EarlyWithdrawalPenaltyCharged { withdrawal_id: existing, .. } if existing == &withdrawal_id
+        );
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events
+            .push(DepositAccountEvent::EarlyWithdrawalPenaltyCharged {
+                withdrawal_id,
+                amount,
+                ledger_tx_id,
+            });
+        Idempotent::Executed(ledger_tx_id)
+    }
+
+    /// Charges a recurring fee once for the period starting on `period_start`.
+    pub(crate) fn charge_periodic_fee(
+        &mut self,
+        fee_type: DepositFeeType,
+        period_start: chrono::NaiveDate,
+        amount: UsdCents,
+    ) -> Idempotent<CalaTransactionId> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositAccountEvent::FeeCharged { fee_type: existing_type, period_start: Some(existing_period), .. } if existing_type == &fee_type && existing_period == &period_start
+        );
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::FeeCharged {
+            fee_type,
+            amount,
+            period_start: Some(period_start),
+            withdrawal_id: None,
+            ledger_tx_id,
+        });
+        Idempotent::Executed(ledger_tx_id)
+    }
+
+    pub(crate) fn charge_withdrawal_fee(
+        &mut self,
+        withdrawal_id: WithdrawalId,
+        amount: UsdCents,
+    ) -> Idempotent<CalaTransactionId> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositAccountEvent::FeeCharged { withdrawal_id: Some(existing), .. } if existing == &withdrawal_id
+        );
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::FeeCharged {
+            fee_type: DepositFeeType::Withdrawal,
+            amount,
+            period_start: None,
+            withdrawal_id: Some(withdrawal_id),
+            ledger_tx_id,
+        });
+        Idempotent::Executed(ledger_tx_id)
+    }
+
+    pub(crate) fn reverse_fee(
+        &mut self,
+        fee_ledger_tx_id: CalaTransactionId,
+    ) -> Result<Idempotent<FeeReversal>, DepositAccountError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositAccountEvent::FeeReversed { fee_ledger_tx_id: existing, .. } if existing == &fee_ledger_tx_id
+        );
+        let amount = self
+            .events
+            .iter_all()
+            .find_map(|e| match e {
+                DepositAccountEvent::FeeCharged {
+                    amount,
+                    ledger_tx_id,
+                    ..
+                } if ledger_tx_id == &fee_ledger_tx_id => Some(*amount),
+                _ => None,
+            })
+            .ok_or(DepositAccountError::FeeNotFound(fee_ledger_tx_id))?;
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::FeeReversed {
+            fee_ledger_tx_id,
+            amount,
+            ledger_tx_id,
+        });
+        Ok(Idempotent::Executed(FeeReversal {
+            amount,
+            ledger_tx_id,
+        }))
+    }
+
+    /// Date on which the balance will be escheated, if a pre-escheatment notice
+    /// has been issued and no activity has been recorded since.
+    pub fn pending_escheatment_date(&self) -> Option<chrono::NaiveDate> {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                DepositAccountEvent::EscheatmentNoticeIssued { escheatment_date } => {
+                    Some(Some(*escheatment_date))
+                }
+                DepositAccountEvent::ActivityUpdated { .. }
+                | DepositAccountEvent::Escheated { .. }
+                | DepositAccountEvent::EscheatedBalanceReclaimed { .. } => Some(None),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Every balance handed over as unclaimed property, including ones reclaimed since.
+    pub fn escheatments(&self) -> impl Iterator<Item = (chrono::NaiveDate, UsdCents)> + '_ {
+        self.events.iter_all().filter_map(|e| match e {
+            DepositAccountEvent::Escheated {
+                escheated_on,
+                amount,
+                ..
+            } => Some((*escheated_on, *amount)),
+            _ => None,
+        })
+    }
+
+    pub fn escheated_balance(&self) -> Option<(chrono::NaiveDate, UsdCents)> {
+        if !self.is_escheated() {
+            return None;
+        }
+        self.events.iter_all().rev().find_map(|e| match e {
+            DepositAccountEvent::Escheated {
+                escheated_on,
+                amount,
+                ..
+            } => Some((*escheated_on, *amount)),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn issue_escheatment_notice(
+        &mut self,
+        escheatment_date: chrono::NaiveDate,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositAccountEvent::EscheatmentNoticeIssued { .. },
+            resets_on: DepositAccountEvent::ActivityUpdated { .. }
+        );
+        if self.activity != Activity::Escheatable || self.status != DepositAccountStatus::Active {
+            return Idempotent::AlreadyApplied;
+        }
+        self.events
+            .push(DepositAccountEvent::EscheatmentNoticeIssued { escheatment_date });
+        Idempotent::Executed(())
+    }
+
+    /// Escheats `amount` once the waiting period of the pending notice has elapsed.
+    pub(crate) fn escheat(
+        &mut self,
+        escheated_on: chrono::NaiveDate,
+        amount: UsdCents,
+    ) -> Idempotent<Option<EscheatmentTransfer>> {
+        if self.status != DepositAccountStatus::Active {
+            return Idempotent::AlreadyApplied;
+        }
+        match self.pending_escheatment_date() {
+            Some(escheatment_date) if escheatment_date <= escheated_on => {}
+            _ => return Idempotent::AlreadyApplied,
+        }
+
+        let ledger_tx_id = (!amount.is_zero()).then(CalaTransactionId::new);
+        let status = DepositAccountStatus::Escheated;
+        self.events.push(DepositAccountEvent::Escheated {
+            status,
+            escheated_on,
+            amount,
+            ledger_tx_id,
+        });
+        self.status = status;
+        Idempotent::Executed(ledger_tx_id.map(|ledger_tx_id| EscheatmentTransfer {
+            amount,
+            ledger_tx_id,
+        }))
+    }
+
+    pub(crate) fn reclaim_escheated_balance(
+        &mut self,
+    ) -> Result<Idempotent<Option<EscheatmentTransfer>>, DepositAccountError> {
+        let Some((_, amount)) = self.escheated_balance() else {
+            return Err(DepositAccountError::AccountNotEscheated(self.id));
+        };
+
+        let ledger_tx_id = (!amount.is_zero()).then(CalaTransactionId::new);
+        let status = DepositAccountStatus::Active;
+        self.events
+            .push(DepositAccountEvent::EscheatedBalanceReclaimed {
+                status,
+                amount,
+                ledger_tx_id,
+            });
+        self.status = status;
+        self.events.push(DepositAccountEvent::ActivityUpdated {
+            activity: Activity::Active,
+        });
+        self.activity = Activity::Active;
+        Ok(Idempotent::Executed(ledger_tx_id.map(|ledger_tx_id| {
+            EscheatmentTransfer {
+                amount,
+                ledger_tx_id,
+            }
+        })))
+    }
+
+    /// Limits requested for this account that are still awaiting governance approval.
+    pub fn pending_withdrawal_limit_increase(
+        &self,
+    ) -> Option<(ApprovalProcessId, WithdrawalLimits)> {
+        for event in self.events.iter_all().rev() {
+            match event {
+                DepositAccountEvent::WithdrawalLimitIncreaseConcluded { .. } => return None,
+                DepositAccountEvent::WithdrawalLimitIncreaseRequested {
+                    approval_process_id,
+                    limits,
+                } => return Some((*approval_process_id, *limits)),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Account specific limits that take precedence over the ones configured for its customer type.
+    pub fn withdrawal_limit_override(&self) -> Option<WithdrawalLimits> {
+        let mut requested = std::collections::HashMap::new();
+        let mut approved = None;
+        for event in self.events.iter_all() {
+            match event {
+                DepositAccountEvent::WithdrawalLimitIncreaseRequested {
+                    approval_process_id,
+                    limits,
+                } => {
+                    requested.insert(*approval_process_id, *limits);
+                }
+                DepositAccountEvent::WithdrawalLimitIncreaseConcluded {
+                    approval_process_id,
+                    approved: true,
+                } => {
+                    approved = requested.get(approval_process_id).copied();
+                }
+                _ => {}
+            }
+        }
+        approved
+    }
+
+    pub fn request_withdrawal_limit_increase(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        limits: WithdrawalLimits,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        if let Some((pending_id, _)) = self.pending_withdrawal_limit_increase() {
+            if pending_id == approval_process_id {
+                return Ok(Idempotent::AlreadyApplied);
+            }
+            return Err(DepositAccountError::WithdrawalLimitIncreaseAlreadyPending(
+                self.id,
+            ));
+        }
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        if self.is_escheated() {
+            return Err(DepositAccountError::CannotUpdateEscheatedAccount(self.id));
+        }
+        self.events
+            .push(DepositAccountEvent::WithdrawalLimitIncreaseRequested {
+                approval_process_id,
+                limits,
+            });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn conclude_withdrawal_limit_increase(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Idempotent<()> {
+        match self.pending_withdrawal_limit_increase() {
+            Some((pending_id, _)) if pending_id == approval_process_id => {}
+            _ => return Idempotent::AlreadyApplied,
+        }
+        self.events
+            .push(DepositAccountEvent::WithdrawalLimitIncreaseConcluded {
+                approval_process_id,
+                approved,
+            });
+        Idempotent::Executed(())
+    }
+
+    /// All holders of the account, primary holder first.
+    pub fn holders(&self) -> Vec<DepositAccountHolder> {
+        let mut holders: Vec<DepositAccountHolder> = Vec::new();
+        for event in self.events.iter_all() {
+            match event {
+                DepositAccountEvent::Initialized {
+                    account_holder_id,
+                    signing_rule,
+                    ..
+                } => holders.push(DepositAccountHolder {
+                    account_holder_id: *account_holder_id,
+                    signing_rule: *signing_rule,
+                    primary: true,
+                    suspended: false,
+                }),
+                DepositAccountEvent::JointHolderAdded {
+                    account_holder_id,
+                    signing_rule,
+                } => holders.push(DepositAccountHolder {
+                    account_holder_id: *account_holder_id,
+                    signing_rule: *signing_rule,
+                    primary: false,
+                    suspended: false,
+                }),
+                DepositAccountEvent::JointHolderRemoved { account_holder_id } => {
+                    holders.retain(|h| h.account_holder_id != *account_holder_id);
+                }
+                DepositAccountEvent::HolderSigningRuleUpdated {
+                    account_holder_id,
+                    signing_rule,
+                } => {
+                    if let Some(holder) = holders
+                        .iter_mut()
+                        .find(|h| h.account_holder_id == *account_holder_id)
+                    {
+                        holder.signing_rule = *signing_rule;
+                    }
+                }
+                DepositAccountEvent::HolderSuspended { account_holder_id }
+                | DepositAccountEvent::HolderReinstated { account_holder_id } => {
+                    if let Some(holder) = holders
+                        .iter_mut()
+                        .find(|h| h.account_holder_id == *account_holder_id)
+                    {
+                        holder.suspended =
+                            matches!(event, DepositAccountEvent::HolderSuspended { .. });
+                    }
+                }
+                _ => {}
+            }
+        }
+        holders
+    }
+
+    pub fn holder(
+        &self,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Option<DepositAccountHolder> {
+        self.holders()
+            .into_iter()
+            .find(|h| h.account_holder_id == account_holder_id)
+    }
+
+    pub fn has_holder(&self, account_holder_id: DepositAccountHolderId) -> bool {
+        self.holder(account_holder_id).is_some()
+    }
+
+    pub fn holder_ids(&self) -> Vec<DepositAccountHolderId> {
+        self.holders()
+            .into_iter()
+            .map(|h| h.account_holder_id)
+            .collect()
+    }
+
+    pub fn is_joint(&self) -> bool {
+        self.holders().len() > 1
+    }
+
+    /// Holders whose signature every withdrawal from this account has to carry.
+    pub fn required_withdrawal_signers(&self) -> Vec<DepositAccountHolderId> {
+        self.holders()
+            .into_iter()
+            .filter(|h| h.signing_rule == SigningRule::AllMustSign)
+            .map(|h| h.account_holder_id)
+            .collect()
+    }
+
+    /// Whether `account_holder_id` can move funds out of the account without
+    /// the signature of any other holder.
+    pub fn can_be_operated_alone_by(&self, account_holder_id: DepositAccountHolderId) -> bool {
+        match self.holder(account_holder_id) {
+            Some(holder) if !holder.suspended => self
+                .required_withdrawal_signers()
+                .iter()
+                .all(|id| *id == account_holder_id),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn add_joint_holder(
+        &mut self,
+        account_holder_id: DepositAccountHolderId,
+        signing_rule: SigningRule,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        if self.has_holder(account_holder_id) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        if self.is_escheated() {
+            return Err(DepositAccountError::CannotUpdateEscheatedAccount(self.id));
+        }
+        self.events.push(DepositAccountEvent::JointHolderAdded {
+            account_holder_id,
+            signing_rule,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn remove_joint_holder(
+        &mut self,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        let Some(holder) = self.holder(account_holder_id) else {
+            return Ok(Idempotent::AlreadyApplied);
+        };
+        if holder.primary {
+            return Err(DepositAccountError::CannotRemovePrimaryHolder(self.id));
+        }
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        self.events
+            .push(DepositAccountEvent::JointHolderRemoved { account_holder_id });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn update_holder_signing_rule(
+        &mut self,
+        account_holder_id: DepositAccountHolderId,
+        signing_rule: SigningRule,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        let holder = self
+            .holder(account_holder_id)
+            .ok_or(DepositAccountError::HolderNotFound(
+                self.id,
+                account_holder_id,
+            ))?;
+        if holder.signing_rule == signing_rule {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        self.events
+            .push(DepositAccountEvent::HolderSigningRuleUpdated {
+                account_holder_id,
+                signing_rule,
+            });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn suspend_holder(
+        &mut self,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Idempotent<()> {
+        match self.holder(account_holder_id) {
+            Some(holder) if !holder.suspended => {}
+            _ => return Idempotent::AlreadyApplied,
+        }
+        self.events
+            .push(DepositAccountEvent::HolderSuspended { account_holder_id });
+        Idempotent::Executed(())
+    }
+
+    pub(crate) fn reinstate_holder(
+        &mut self,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Idempotent<()> {
+        match self.holder(account_holder_id) {
+            Some(holder) if holder.suspended => {}
+            _ => return Idempotent::AlreadyApplied,
+        }
+        self.events
+            .push(DepositAccountEvent::HolderReinstated { account_holder_id });
+        Idempotent::Executed(())
+    }
+
+    pub fn has_suspended_holders(&self) -> bool {
+        self.holders().iter().any(|h| h.suspended)
+    }
+
     pub fn close(&mut self) -> Result<Idempotent<()>, DepositAccountError> {
         idempotency_guard!(
             self.events.iter_all().rev(),
@@ -142,6 +941,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                     activity,
                     public_id,
                     account_ids,
+                    currency,
                     ..
                 } => {
                     builder = builder
@@ -151,6 +951,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                         .status(*status)
                         .activity(*activity)
                         .public_id(public_id.clone())
+                        .currency(*currency)
                 }
                 DepositAccountEvent::ActivityUpdated { activity, .. } => {
                     builder = builder.activity(*activity);
@@ -161,9 +962,30 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                 DepositAccountEvent::Unfrozen { status, .. } => {
                     builder = builder.status(*status);
                 }
-                DepositAccountEvent::Closed { status, .. } => {
+                DepositAccountEvent::Closed { status, .. }
+                | DepositAccountEvent::Escheated { status, .. }
+                | DepositAccountEvent::EscheatedBalanceReclaimed { status, .. } => {
                     builder = builder.status(*status);
                 }
+                DepositAccountEvent::DepositProductAssigned {
+                    deposit_product_id, ..
+                } => {
+                    builder = builder.deposit_product_id(*deposit_product_id);
+                }
+                DepositAccountEvent::DailyInterestComputed { .. }
+                | DepositAccountEvent::InterestAccrued { .. }
+                | DepositAccountEvent::InterestPosted { .. }
+                | DepositAccountEvent::EarlyWithdrawalPenaltyCharged { .. }
+                | DepositAccountEvent::FeeCharged { .. }
+                | DepositAccountEvent::FeeReversed { .. }
+                | DepositAccountEvent::EscheatmentNoticeIssued { .. }
+                | DepositAccountEvent::WithdrawalLimitIncreaseRequested { .. }
+                | DepositAccountEvent::WithdrawalLimitIncreaseConcluded { .. }
+                | DepositAccountEvent::JointHolderAdded { .. }
+                | DepositAccountEvent::JointHolderRemoved { .. }
+                | DepositAccountEvent::HolderSigningRuleUpdated { .. }
+                | DepositAccountEvent::HolderSuspended { .. }
+                | DepositAccountEvent::HolderReinstated { .. } => {}
             }
         }
         builder.events(events).build()
@@ -180,6 +1002,10 @@ pub struct NewDepositAccount {
     pub(super) account_ids: DepositAccountLedgerAccountIds,
     #[builder(setter(into))]
     pub(super) public_id: PublicId,
+    #[builder(default = "CurrencyCode::USD")]
+    pub(super) currency: CurrencyCode,
+    #[builder(default)]
+    pub(super) signing_rule: SigningRule,
     #[builder(setter(skip), default = "Activity::Active")]
     pub(super) activity: Activity,
     #[builder(setter(skip), default)]
@@ -203,6 +1029,8 @@ impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
                 status: DepositAccountStatus::Active,
                 activity: self.activity,
                 public_id: self.public_id,
+                currency: self.currency,
+                signing_rule: self.signing_rule,
             }],
         )
     }
@@ -212,18 +1040,33 @@ fn default_account_activity() -> Activity {
     Activity::Active
 }
 
+fn default_account_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
+
 #[cfg(test)]
 mod tests {
-    use es_entity::{EntityEvents, TryFromEvents as _};
+    use es_entity::{EntityEvents, Idempotent, TryFromEvents as _};
     use public_id::PublicId;
 
-    use crate::{Activity, DepositAccountHolderId, DepositAccountId, DepositAccountStatus};
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        Activity, ApprovalProcessId, CalaTransactionId, CurrencyCode, DepositAccountHolderId,
+        DepositAccountId, DepositAccountStatus, DepositFeeType, DepositProductId, DepositRatePct,
+        SigningRule, UsdCents, WithdrawalId, WithdrawalLimits,
+    };
 
     use super::{
         DepositAccount, DepositAccountError, DepositAccountEvent, DepositAccountLedgerAccountIds,
     };
 
     fn initial_events() -> Vec<DepositAccountEvent> {
+        initial_events_in(CurrencyCode::USD)
+    }
+
+    fn initial_events_in(currency: CurrencyCode) -> Vec<DepositAccountEvent> {
         let id = DepositAccountId::new();
         vec![DepositAccountEvent::Initialized {
             id,
@@ -232,6 +1075,8 @@ mod tests {
             status: DepositAccountStatus::Active,
             activity: Activity::Active,
             public_id: PublicId::new("1"),
+            currency,
+            signing_rule: SigningRule::AnyOneSigns,
         }]
     }
 
@@ -257,29 +1102,143 @@ mod tests {
     }
 
     #[test]
-    fn escheatable_activity_is_terminal() {
+    fn activity_resets_pending_escheatment() {
         let mut account = DepositAccount::try_from_events(EntityEvents::init(
             DepositAccountId::new(),
             initial_events(),
         ))
         .unwrap();
+        let escheatment_date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
 
         assert!(account.update_activity(Activity::Escheatable).did_execute());
-        assert_eq!(account.activity, Activity::Escheatable);
+        assert!(
+            account
+                .issue_escheatment_notice(escheatment_date)
+                .did_execute()
+        );
+        assert!(
+            account
+                .issue_escheatment_notice(escheatment_date)
+                .was_already_applied()
+        );
+        assert_eq!(account.pending_escheatment_date(), Some(escheatment_date));
 
+        assert!(account.update_activity(Activity::Active).did_execute());
+        assert_eq!(account.activity, Activity::Active);
+        assert_eq!(account.pending_escheatment_date(), None);
         assert!(
             account
-                .update_activity(Activity::Inactive)
+                .escheat(escheatment_date, UsdCents::from(1_000))
                 .was_already_applied()
         );
-        assert_eq!(account.activity, Activity::Escheatable);
+    }
+
+    #[test]
+    fn escheated_balance_can_be_reclaimed() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let escheatment_date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let balance = UsdCents::from(1_000);
 
+        assert!(account.update_activity(Activity::Escheatable).did_execute());
+        assert!(
+            account
+                .issue_escheatment_notice(escheatment_date)
+                .did_execute()
+        );
+        assert!(
+            account
+                .escheat(escheatment_date.pred_opt().unwrap(), balance)
+                .was_already_applied()
+        );
+
+        let Idempotent::Executed(Some(transfer)) = account.escheat(escheatment_date, balance)
+        else {
+            panic!("expected balance to be escheated");
+        };
+        assert_eq!(transfer.amount, balance);
+        assert_eq!(account.status, DepositAccountStatus::Escheated);
+        assert_eq!(
+            account.escheated_balance(),
+            Some((escheatment_date, balance))
+        );
         assert!(
             account
                 .update_activity(Activity::Active)
                 .was_already_applied()
         );
-        assert_eq!(account.activity, Activity::Escheatable);
+
+        let Idempotent::Executed(Some(reclaim)) = account.reclaim_escheated_balance().unwrap()
+        else {
+            panic!("expected balance to be reclaimed");
+        };
+        assert_eq!(reclaim.amount, balance);
+        assert_eq!(account.status, DepositAccountStatus::Active);
+        assert_eq!(account.activity, Activity::Active);
+        assert!(matches!(
+            account.reclaim_escheated_balance(),
+            Err(DepositAccountError::AccountNotEscheated(_))
+        ));
+    }
+
+    #[test]
+    fn withdrawal_limit_override_requires_approval() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let limits = WithdrawalLimits {
+            daily: Some(UsdCents::from(500_000)),
+            ..Default::default()
+        };
+
+        let denied_id = ApprovalProcessId::new();
+        assert!(
+            account
+                .request_withdrawal_limit_increase(denied_id, limits)
+                .unwrap()
+                .did_execute()
+        );
+        assert!(matches!(
+            account.request_withdrawal_limit_increase(ApprovalProcessId::new(), limits),
+            Err(DepositAccountError::WithdrawalLimitIncreaseAlreadyPending(
+                _
+            ))
+        ));
+        assert!(
+            account
+                .conclude_withdrawal_limit_increase(denied_id, false)
+                .did_execute()
+        );
+        assert_eq!(account.withdrawal_limit_override(), None);
+
+        let approved_id = ApprovalProcessId::new();
+        assert!(
+            account
+                .request_withdrawal_limit_increase(approved_id, limits)
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(
+            account.pending_withdrawal_limit_increase(),
+            Some((approved_id, limits))
+        );
+        assert!(
+            account
+                .conclude_withdrawal_limit_increase(approved_id, true)
+                .did_execute()
+        );
+        assert!(
+            account
+                .conclude_withdrawal_limit_increase(approved_id, true)
+                .was_already_applied()
+        );
+        assert_eq!(account.pending_withdrawal_limit_increase(), None);
+        assert_eq!(account.withdrawal_limit_override(), Some(limits));
     }
 
     #[test]
@@ -370,4 +1329,283 @@ mod tests {
 
         assert!(account.freeze().unwrap().did_execute());
     }
+
+    #[test]
+    fn accrues_interest_once_per_day_and_posts_accumulated_amount() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let assigned_at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        assert!(
+            account
+                .assign_deposit_product(DepositProductId::new(), assigned_at)
+                .unwrap()
+                .did_execute()
+        );
+
+        let rate = DepositRatePct::from(dec!(3.65));
+        let balance = UsdCents::from(1_000_000);
+        let jan_31 = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        assert!(
+            account
+                .compute_daily_interest(jan_31, balance, rate)
+                .did_execute()
+        );
+        let Idempotent::Executed(Some(accrual)) = account.accrue_interest(jan_31) else {
+            panic!("expected interest to accrue");
+        };
+        assert_eq!(accrual.amount, UsdCents::from(3000));
+        assert!(
+            account
+                .compute_daily_interest(jan_31, balance, rate)
+                .was_already_applied()
+        );
+        assert!(account.accrue_interest(jan_31).was_already_applied());
+
+        let feb_1 = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        let _ = account.compute_daily_interest(feb_1, balance, rate);
+        let _ = account.accrue_interest(feb_1);
+        assert_eq!(account.accrued_interest(), UsdCents::from(3100));
+
+        let Idempotent::Executed(posting) = account.post_interest(feb_1) else {
+            panic!("expected interest to post");
+        };
+        assert_eq!(posting.amount, UsdCents::from(3100));
+        assert_eq!(account.accrued_interest(), UsdCents::ZERO);
+        assert!(account.post_interest(feb_1).was_already_applied());
+    }
+
+    #[test]
+    fn carries_sub_cent_interest_into_later_accruals() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let assigned_at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let _ = account.assign_deposit_product(DepositProductId::new(), assigned_at);
+
+        // $10 at 5% earns about 0.137 cents a day, so daily accruals only
+        // reach a whole cent on the eighth day.
+        let rate = DepositRatePct::from(dec!(5));
+        let balance = UsdCents::from(1_000);
+        for day in 2..=8 {
+            let date = NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+            let _ = account.compute_daily_interest(date, balance, rate);
+            assert!(matches!(
+                account.accrue_interest(date),
+                Idempotent::Executed(None)
+            ));
+        }
+        assert_eq!(account.accrued_interest(), UsdCents::ZERO);
+
+        let jan_9 = NaiveDate::from_ymd_opt(2025, 1, 9).unwrap();
+        let _ = account.compute_daily_interest(jan_9, balance, rate);
+        let Idempotent::Executed(Some(accrual)) = account.accrue_interest(jan_9) else {
+            panic!("expected the carried remainder to add up to a cent");
+        };
+        assert_eq!(accrual.amount, UsdCents::ONE);
+    }
+
+    #[test]
+    fn can_not_reassign_different_deposit_product() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let product_id = DepositProductId::new();
+        let _ = account.assign_deposit_product(product_id, Utc::now());
+        assert!(
+            account
+                .assign_deposit_product(product_id, Utc::now())
+                .unwrap()
+                .was_already_applied()
+        );
+        assert!(matches!(
+            account.assign_deposit_product(DepositProductId::new(), Utc::now()),
+            Err(DepositAccountError::DepositProductAlreadyAssigned(_))
+        ));
+    }
+
+    #[test]
+    fn can_not_assign_deposit_product_to_non_usd_account() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events_in(CurrencyCode::EUR),
+        ))
+        .unwrap();
+        assert_eq!(account.currency, CurrencyCode::EUR);
+        assert!(matches!(
+            account.assign_deposit_product(DepositProductId::new(), Utc::now()),
+            Err(DepositAccountError::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn periodic_fee_is_charged_once_per_period() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let march = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let fee = UsdCents::from(500);
+
+        assert!(
+            account
+                .charge_periodic_fee(DepositFeeType::MonthlyMaintenance, march, fee)
+                .did_execute()
+        );
+        assert!(
+            account
+                .charge_periodic_fee(DepositFeeType::MonthlyMaintenance, march, fee)
+                .was_already_applied()
+        );
+        assert!(
+            account
+                .charge_periodic_fee(DepositFeeType::DormantAccount, march, fee)
+                .did_execute()
+        );
+
+        let april = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        assert!(
+            account
+                .charge_periodic_fee(DepositFeeType::MonthlyMaintenance, april, fee)
+                .did_execute()
+        );
+    }
+
+    #[test]
+    fn charged_fee_can_be_reversed_once() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let Idempotent::Executed(fee_tx_id) =
+            account.charge_withdrawal_fee(WithdrawalId::new(), UsdCents::from(150))
+        else {
+            panic!("expected withdrawal fee to be charged");
+        };
+
+        let Idempotent::Executed(reversal) = account.reverse_fee(fee_tx_id).unwrap() else {
+            panic!("expected fee to be reversed");
+        };
+        assert_eq!(reversal.amount, UsdCents::from(150));
+        assert!(
+            account
+                .reverse_fee(fee_tx_id)
+                .unwrap()
+                .was_already_applied()
+        );
+
+        assert!(matches!(
+            account.reverse_fee(CalaTransactionId::new()),
+            Err(DepositAccountError::FeeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn joint_holders_can_be_added_and_removed() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let primary = account.account_holder_id;
+        let joint = DepositAccountHolderId::new();
+        assert!(!account.is_joint());
+
+        assert!(
+            account
+                .add_joint_holder(joint, SigningRule::AllMustSign)
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            account
+                .add_joint_holder(joint, SigningRule::AllMustSign)
+                .unwrap()
+                .was_already_applied()
+        );
+        assert!(account.is_joint());
+        assert_eq!(account.holder_ids(), vec![primary, joint]);
+        assert_eq!(account.required_withdrawal_signers(), vec![joint]);
+        assert!(!account.can_be_operated_alone_by(primary));
+        assert!(account.can_be_operated_alone_by(joint));
+
+        assert!(matches!(
+            account.remove_joint_holder(primary),
+            Err(DepositAccountError::CannotRemovePrimaryHolder(_))
+        ));
+        assert!(account.remove_joint_holder(joint).unwrap().did_execute());
+        assert!(
+            account
+                .remove_joint_holder(joint)
+                .unwrap()
+                .was_already_applied()
+        );
+        assert!(!account.has_holder(joint));
+        assert!(account.can_be_operated_alone_by(primary));
+    }
+
+    #[test]
+    fn suspended_holder_cannot_operate_account() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let joint = DepositAccountHolderId::new();
+        assert!(
+            account
+                .add_joint_holder(joint, SigningRule::AnyOneSigns)
+                .unwrap()
+                .did_execute()
+        );
+
+        assert!(account.suspend_holder(joint).did_execute());
+        assert!(account.suspend_holder(joint).was_already_applied());
+        assert!(account.has_suspended_holders());
+        assert!(!account.can_be_operated_alone_by(joint));
+
+        assert!(account.reinstate_holder(joint).did_execute());
+        assert!(account.reinstate_holder(joint).was_already_applied());
+        assert!(!account.has_suspended_holders());
+        assert!(account.can_be_operated_alone_by(joint));
+    }
+
+    #[test]
+    fn signing_rule_can_be_updated_for_existing_holders_only() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let primary = account.account_holder_id;
+
+        assert!(
+            account
+                .update_holder_signing_rule(primary, SigningRule::AllMustSign)
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            account
+                .update_holder_signing_rule(primary, SigningRule::AllMustSign)
+                .unwrap()
+                .was_already_applied()
+        );
+        assert_eq!(account.required_withdrawal_signers(), vec![primary]);
+        assert!(matches!(
+            account.update_holder_signing_rule(
+                DepositAccountHolderId::new(),
+                SigningRule::AnyOneSigns
+            ),
+            Err(DepositAccountError::HolderNotFound(..))
+        ));
+    }
 }
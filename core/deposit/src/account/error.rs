@@ -19,12 +19,40 @@ pub enum DepositAccountError {
     Find(#[from] DepositAccountFindError),
     #[error("DepositAccountError - Query: {0}")]
     Query(#[from] DepositAccountQueryError),
+    #[error("DepositAccountError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
     #[error("DepositAccountError - CannotUpdateClosedAccount: {0}")]
     CannotUpdateClosedAccount(crate::DepositAccountId),
     #[error("DepositAccountError - CannotUpdateFrozenAccount")]
     CannotUpdateFrozenAccount(crate::DepositAccountId),
     #[error("DepositAccountError - BalanceIsNotZero")]
     BalanceIsNotZero,
+    #[error("DepositAccountError - DepositProductAlreadyAssigned: {0}")]
+    DepositProductAlreadyAssigned(crate::DepositAccountId),
+    #[error("DepositAccountError - FeeNotFound: {0}")]
+    FeeNotFound(crate::CalaTransactionId),
+    #[error("DepositAccountError - CannotUpdateEscheatedAccount: {0}")]
+    CannotUpdateEscheatedAccount(crate::DepositAccountId),
+    #[error("DepositAccountError - AccountNotEscheated: {0}")]
+    AccountNotEscheated(crate::DepositAccountId),
+    #[error("DepositAccountError - WithdrawalLimitIncreaseAlreadyPending: {0}")]
+    WithdrawalLimitIncreaseAlreadyPending(crate::DepositAccountId),
+    #[error("DepositAccountError - CannotRemovePrimaryHolder: {0}")]
+    CannotRemovePrimaryHolder(crate::DepositAccountId),
+    #[error("DepositAccountError - HolderNotFound: {1} is not a holder of {0}")]
+    HolderNotFound(crate::DepositAccountId, crate::DepositAccountHolderId),
+    #[error("DepositAccountError - HolderSuspended: {1} is suspended on {0}")]
+    HolderSuspended(crate::DepositAccountId, crate::DepositAccountHolderId),
+    #[error("DepositAccountError - HolderCannotActAlone: {1} needs co-signers on {0}")]
+    HolderCannotActAlone(crate::DepositAccountId, crate::DepositAccountHolderId),
+    #[error(
+        "DepositAccountError - CurrencyMismatch: account {id} is held in {actual}, expected {expected}"
+    )]
+    CurrencyMismatch {
+        id: crate::DepositAccountId,
+        expected: crate::CurrencyCode,
+        actual: crate::CurrencyCode,
+    },
 }
 
 impl ErrorSeverity for DepositAccountError {
@@ -35,9 +63,20 @@ impl ErrorSeverity for DepositAccountError {
             Self::Modify(_) => Level::ERROR,
             Self::Find(_) => Level::ERROR,
             Self::Query(_) => Level::ERROR,
+            Self::AuditError(e) => e.severity(),
             Self::CannotUpdateClosedAccount(_) => Level::WARN,
             Self::CannotUpdateFrozenAccount(_) => Level::WARN,
             Self::BalanceIsNotZero => Level::WARN,
+            Self::DepositProductAlreadyAssigned(_) => Level::WARN,
+            Self::FeeNotFound(_) => Level::WARN,
+            Self::CannotUpdateEscheatedAccount(_) => Level::WARN,
+            Self::AccountNotEscheated(_) => Level::WARN,
+            Self::WithdrawalLimitIncreaseAlreadyPending(_) => Level::WARN,
+            Self::CannotRemovePrimaryHolder(_) => Level::WARN,
+            Self::HolderNotFound(..) => Level::WARN,
+            Self::HolderSuspended(..) => Level::WARN,
+            Self::HolderCannotActAlone(..) => Level::WARN,
+            Self::CurrencyMismatch { .. } => Level::WARN,
         }
     }
 }
@@ -2,10 +2,10 @@ mod entity;
 pub mod error;
 mod repo;
 
-pub use entity::DepositAccount;
 #[cfg(feature = "json-schema")]
 pub use entity::DepositAccountEvent;
 pub(crate) use entity::*;
+pub use entity::{DepositAccount, DepositAccountHolder};
 pub use repo::deposit_account_cursor::{DepositAccountsByCreatedAtCursor, DepositAccountsCursor};
 pub(crate) use repo::*;
 pub use repo::{DepositAccountsFilters, DepositAccountsSortBy};
@@ -6,7 +6,8 @@ use obix::out::OutboxEventMarker;
 
 use crate::{
     primitives::{
-        Activity, DepositAccountHolderId, DepositAccountId, DepositAccountStatus, PublicId,
+        Activity, DepositAccountHolderId, DepositAccountId, DepositAccountStatus, DepositProductId,
+        PublicId,
     },
     public::CoreDepositEvent,
     publisher::DepositPublisher,
@@ -25,7 +26,12 @@ use super::{entity::*, error::*};
         ),
         activity(ty = "Activity", list_for),
         public_id(ty = "PublicId", list_by),
-        status(ty = "DepositAccountStatus", list_for, update(accessor = "status"))
+        status(ty = "DepositAccountStatus", list_for, update(accessor = "status")),
+        deposit_product_id(
+            ty = "Option<DepositProductId>",
+            create(persist = false),
+            update(accessor = "deposit_product_id")
+        )
     ),
     tbl_prefix = "core",
     post_persist_hook = "publish_in_op"
@@ -75,7 +81,7 @@ where
             .await
     }
 
-    pub async fn list_account_ids_not_escheatable_in_op(
+    pub async fn list_account_ids_for_activity_evaluation_in_op(
         &self,
         op: &mut impl es_entity::AtomicOperation,
         after: Option<(chrono::DateTime<chrono::Utc>, DepositAccountId)>,
@@ -88,18 +94,170 @@ where
         let rows = sqlx::query!(
             r#"SELECT id AS "id: DepositAccountId", created_at
                FROM core_deposit_accounts
-               WHERE activity != 'escheatable'
+               WHERE status != $4
                  AND (($1::timestamptz IS NULL) OR (created_at, id) > ($1, $2))
                ORDER BY created_at, id
                LIMIT $3"#,
             after_created_at,
             after_id as Option<DepositAccountId>,
             limit,
+            DepositAccountStatus::Escheated as DepositAccountStatus,
         )
         .fetch_all(op.as_executor())
         .await?;
         Ok(rows.into_iter().map(|r| (r.id, r.created_at)).collect())
     }
+
+    pub async fn list_account_ids_pending_escheatment_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        after: Option<DepositAccountId>,
+        limit: i64,
+    ) -> Result<Vec<DepositAccountId>, DepositAccountError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: DepositAccountId"
+               FROM core_deposit_accounts
+               WHERE activity = 'escheatable'
+                 AND status = $3
+                 AND (($1::uuid IS NULL) OR id > $1)
+               ORDER BY id
+               LIMIT $2"#,
+            after as Option<DepositAccountId>,
+            limit,
+            DepositAccountStatus::Active as DepositAccountStatus,
+        )
+        .fetch_all(op.as_executor())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    pub async fn list_account_ids_with_deposit_product_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        after: Option<DepositAccountId>,
+        limit: i64,
+    ) -> Result<Vec<DepositAccountId>, DepositAccountError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: DepositAccountId"
+               FROM core_deposit_accounts
+               WHERE deposit_product_id IS NOT NULL
+                 AND (($1::uuid IS NULL) OR id > $1)
+               ORDER BY id
+               LIMIT $2"#,
+            after as Option<DepositAccountId>,
+            limit,
+        )
+        .fetch_all(op.as_executor())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    pub async fn list_account_ids_for_statements_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        after: Option<DepositAccountId>,
+        limit: i64,
+    ) -> Result<Vec<DepositAccountId>, DepositAccountError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: DepositAccountId"
+               FROM core_deposit_accounts
+               WHERE status != $3
+                 AND (($1::uuid IS NULL) OR id > $1)
+               ORDER BY id
+               LIMIT $2"#,
+            after as Option<DepositAccountId>,
+            limit,
+            DepositAccountStatus::Closed as DepositAccountStatus,
+        )
+        .fetch_all(op.as_executor())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    /// Ids of the accounts with an escheatment recorded between `from` and `until`,
+    /// whether or not the balance has been reclaimed since.
+    pub async fn list_account_ids_escheated_between(
+        &self,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<Vec<DepositAccountId>, DepositAccountError> {
+        let rows = sqlx::query!(
+            r#"SELECT DISTINCT id AS "id!: DepositAccountId"
+               FROM core_deposit_account_events
+               WHERE event_type = 'escheated'
+                 AND (event->>'escheated_on')::date BETWEEN $1 AND $2
+               ORDER BY id"#,
+            from,
+            until,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    pub async fn list_joint_account_ids_for_holder(
+        &self,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Result<Vec<DepositAccountId>, DepositAccountError> {
+        let mut op = self.begin_op().await?;
+        self.list_joint_account_ids_for_holder_in_op(&mut op, account_holder_id)
+            .await
+    }
+
+    /// Ids of the accounts `account_holder_id` holds jointly with their primary holder.
+    pub async fn list_joint_account_ids_for_holder_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Result<Vec<DepositAccountId>, DepositAccountError> {
+        let rows = sqlx::query!(
+            r#"SELECT deposit_account_id AS "id: DepositAccountId"
+               FROM core_deposit_account_joint_holders
+               WHERE account_holder_id = $1
+               ORDER BY created_at, deposit_account_id"#,
+            account_holder_id as DepositAccountHolderId,
+        )
+        .fetch_all(op.as_executor())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    pub async fn insert_joint_holder_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        deposit_account_id: DepositAccountId,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Result<(), DepositAccountError> {
+        let now = self.clock.now();
+        sqlx::query!(
+            r#"INSERT INTO core_deposit_account_joint_holders (deposit_account_id, account_holder_id, created_at)
+               VALUES ($1, $2, $3)
+               ON CONFLICT DO NOTHING"#,
+            deposit_account_id as DepositAccountId,
+            account_holder_id as DepositAccountHolderId,
+            now,
+        )
+        .execute(op.as_executor())
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_joint_holder_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        deposit_account_id: DepositAccountId,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Result<(), DepositAccountError> {
+        sqlx::query!(
+            r#"DELETE FROM core_deposit_account_joint_holders
+               WHERE deposit_account_id = $1 AND account_holder_id = $2"#,
+            deposit_account_id as DepositAccountId,
+            account_holder_id as DepositAccountHolderId,
+        )
+        .execute(op.as_executor())
+        .await?;
+        Ok(())
+    }
 }
 
 impl From<(DepositAccountsSortBy, &DepositAccount)>
@@ -0,0 +1,339 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use document_storage::DocumentId;
+use es_entity::*;
+
+use crate::primitives::{
+    AccountClosureId, BeneficiaryId, DepositAccountId, HoldId, TransferId, UsdCents, WithdrawalId,
+};
+
+use super::error::AccountClosureError;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountClosureStatus {
+    #[default]
+    PayoutPending,
+    Closed,
+    Failed,
+}
+
+/// Where the remaining balance of the account is paid out to before it is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountClosurePayout {
+    DepositAccount {
+        deposit_account_id: DepositAccountId,
+    },
+    Beneficiary {
+        beneficiary_id: BeneficiaryId,
+    },
+}
+
+/// What to do with holds that are still active on the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountClosureHoldTreatment {
+    /// Release all active holds as part of the closure.
+    Release,
+    /// Refuse to close the account while it has active holds.
+    Block,
+}
+
+/// The withdrawal or transfer that moves the remaining balance out of the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountClosurePayoutTransaction {
+    Withdrawal { withdrawal_id: WithdrawalId },
+    Transfer { transfer_id: TransferId },
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "AccountClosureId")]
+pub enum AccountClosureEvent {
+    Initialized {
+        id: AccountClosureId,
+        deposit_account_id: DepositAccountId,
+        payout: AccountClosurePayout,
+        hold_treatment: AccountClosureHoldTreatment,
+        released_hold_ids: Vec<HoldId>,
+        payout_amount: UsdCents,
+        payout_transaction: Option<AccountClosurePayoutTransaction>,
+        status: AccountClosureStatus,
+    },
+    Closed {
+        closing_statement_document_id: DocumentId,
+        closed_at: DateTime<Utc>,
+        status: AccountClosureStatus,
+    },
+    Failed {
+        reason: String,
+        status: AccountClosureStatus,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct AccountClosure {
+    pub id: AccountClosureId,
+    pub deposit_account_id: DepositAccountId,
+    pub payout: AccountClosurePayout,
+    pub hold_treatment: AccountClosureHoldTreatment,
+    pub released_hold_ids: Vec<HoldId>,
+    pub payout_amount: UsdCents,
+    pub payout_transaction: Option<AccountClosurePayoutTransaction>,
+
+    events: EntityEvents<AccountClosureEvent>,
+}
+
+impl AccountClosure {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for account closure")
+    }
+
+    pub fn status(&self) -> AccountClosureStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                AccountClosureEvent::Initialized { status, .. }
+                | AccountClosureEvent::Closed { status, .. }
+                | AccountClosureEvent::Failed { status, .. } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn is_payout_pending(&self) -> bool {
+        self.status() == AccountClosureStatus::PayoutPending
+    }
+
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            AccountClosureEvent::Closed { closed_at, .. } => Some(*closed_at),
+            _ => None,
+        })
+    }
+
+    pub fn closing_statement_document_id(&self) -> Option<DocumentId> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            AccountClosureEvent::Closed {
+                closing_statement_document_id,
+                ..
+            } => Some(*closing_statement_document_id),
+            _ => None,
+        })
+    }
+
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            AccountClosureEvent::Failed { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn close(
+        &mut self,
+        closing_statement_document_id: DocumentId,
+        closed_at: DateTime<Utc>,
+    ) -> Result<Idempotent<()>, AccountClosureError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: AccountClosureEvent::Closed { .. }
+        );
+        if !self.is_payout_pending() {
+            return Err(AccountClosureError::NotPending(self.id));
+        }
+        self.events.push(AccountClosureEvent::Closed {
+            closing_statement_document_id,
+            closed_at,
+            status: AccountClosureStatus::Closed,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn fail(&mut self, reason: String) -> Result<Idempotent<()>, AccountClosureError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: AccountClosureEvent::Failed { .. }
+        );
+        if !self.is_payout_pending() {
+            return Err(AccountClosureError::NotPending(self.id));
+        }
+        self.events.push(AccountClosureEvent::Failed {
+            reason,
+            status: AccountClosureStatus::Failed,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<AccountClosureEvent> for AccountClosure {
+    fn try_from_events(
+        events: EntityEvents<AccountClosureEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = AccountClosureBuilder::default();
+        for event in events.iter_all() {
+            if let AccountClosureEvent::Initialized {
+                id,
+                deposit_account_id,
+                payout,
+                hold_treatment,
+                released_hold_ids,
+                payout_amount,
+                payout_transaction,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .payout(*payout)
+                    .hold_treatment(*hold_treatment)
+                    .released_hold_ids(released_hold_ids.clone())
+                    .payout_amount(*payout_amount)
+                    .payout_transaction(*payout_transaction)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewAccountClosure {
+    #[builder(setter(into))]
+    pub(super) id: AccountClosureId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) payout: AccountClosurePayout,
+    pub(super) hold_treatment: AccountClosureHoldTreatment,
+    pub(super) released_hold_ids: Vec<HoldId>,
+    pub(super) payout_amount: UsdCents,
+    pub(super) payout_transaction: Option<AccountClosurePayoutTransaction>,
+    #[builder(setter(skip), default)]
+    pub(super) status: AccountClosureStatus,
+}
+
+impl NewAccountClosure {
+    pub fn builder() -> NewAccountClosureBuilder {
+        NewAccountClosureBuilder::default()
+    }
+}
+
+impl IntoEvents<AccountClosureEvent> for NewAccountClosure {
+    fn into_events(self) -> EntityEvents<AccountClosureEvent> {
+        EntityEvents::init(
+            self.id,
+            [AccountClosureEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                payout: self.payout,
+                hold_treatment: self.hold_treatment,
+                released_hold_ids: self.released_hold_ids,
+                payout_amount: self.payout_amount,
+                payout_transaction: self.payout_transaction,
+                status: self.status,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn account_closure() -> AccountClosure {
+        let new_closure = NewAccountClosure::builder()
+            .id(AccountClosureId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .payout(AccountClosurePayout::Beneficiary {
+                beneficiary_id: BeneficiaryId::new(),
+            })
+            .hold_treatment(AccountClosureHoldTreatment::Release)
+            .released_hold_ids(vec![])
+            .payout_amount(UsdCents::from(10_000))
+            .payout_transaction(Some(AccountClosurePayoutTransaction::Withdrawal {
+                withdrawal_id: WithdrawalId::new(),
+            }))
+            .build()
+            .unwrap();
+        AccountClosure::try_from_events(new_closure.into_events()).unwrap()
+    }
+
+    #[test]
+    fn close_is_idempotent() {
+        let mut closure = account_closure();
+        assert!(closure.is_payout_pending());
+
+        assert!(
+            closure
+                .close(DocumentId::new(), Utc::now())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            closure
+                .close(DocumentId::new(), Utc::now())
+                .unwrap()
+                .was_already_applied()
+        );
+        assert_eq!(closure.status(), AccountClosureStatus::Closed);
+        assert!(closure.closing_statement_document_id().is_some());
+    }
+
+    #[test]
+    fn cannot_fail_closed_closure() {
+        let mut closure = account_closure();
+        let _ = closure.close(DocumentId::new(), Utc::now()).unwrap();
+
+        assert!(matches!(
+            closure.fail("withdrawal denied".to_string()),
+            Err(AccountClosureError::NotPending(_))
+        ));
+    }
+
+    #[test]
+    fn failed_closure_records_reason() {
+        let mut closure = account_closure();
+
+        assert!(
+            closure
+                .fail("withdrawal denied".to_string())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(closure.status(), AccountClosureStatus::Failed);
+        assert_eq!(closure.failure_reason(), Some("withdrawal denied"));
+        assert!(matches!(
+            closure.close(DocumentId::new(), Utc::now()),
+            Err(AccountClosureError::NotPending(_))
+        ));
+    }
+}
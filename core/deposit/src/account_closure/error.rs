@@ -0,0 +1,57 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::{AccountClosureId, DepositAccountId};
+
+use super::repo::{
+    AccountClosureCreateError, AccountClosureFindError, AccountClosureModifyError,
+    AccountClosureQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum AccountClosureError {
+    #[error("AccountClosureError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AccountClosureError - Create: {0}")]
+    Create(#[from] AccountClosureCreateError),
+    #[error("AccountClosureError - Modify: {0}")]
+    Modify(#[from] AccountClosureModifyError),
+    #[error("AccountClosureError - Find: {0}")]
+    Find(#[from] AccountClosureFindError),
+    #[error("AccountClosureError - Query: {0}")]
+    Query(#[from] AccountClosureQueryError),
+    #[error("AccountClosureError - Csv: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("AccountClosureError - NotPending: {0}")]
+    NotPending(AccountClosureId),
+    #[error("AccountClosureError - AlreadyInProgress: {0}")]
+    AlreadyInProgress(DepositAccountId),
+    #[error("AccountClosureError - ActiveHoldsOnAccount: {0}")]
+    ActiveHoldsOnAccount(DepositAccountId),
+    #[error("AccountClosureError - PendingTransactionsOnAccount: {0}")]
+    PendingTransactionsOnAccount(DepositAccountId),
+    #[error("AccountClosureError - PayoutToSameAccount: {0}")]
+    PayoutToSameAccount(DepositAccountId),
+    #[error("AccountClosureError - TermDepositInLockIn: {0}")]
+    TermDepositInLockIn(DepositAccountId),
+}
+
+impl ErrorSeverity for AccountClosureError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::Csv(_) => Level::ERROR,
+            Self::NotPending(_) => Level::WARN,
+            Self::AlreadyInProgress(_) => Level::WARN,
+            Self::ActiveHoldsOnAccount(_) => Level::WARN,
+            Self::PendingTransactionsOnAccount(_) => Level::WARN,
+            Self::PayoutToSameAccount(_) => Level::WARN,
+            Self::TermDepositInLockIn(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod settle;
+
+pub use settle::*;
@@ -0,0 +1,371 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::DocumentStorage;
+use es_entity::clock::ClockHandle;
+use job::*;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::DepositAccountRepo,
+    account_closure::{
+        ACCOUNT_CLOSING_STATEMENT_DOCUMENT_TYPE, entity::*, repo::AccountClosureRepo,
+        statement::closing_statement_csv,
+    },
+    ledger::DepositLedger,
+    primitives::{ACCOUNT_CLOSURE, AccountClosureId, DepositAccountId},
+    public::CoreDepositEvent,
+    transfer::TransferStatus,
+    withdrawal::WithdrawalStatus,
+};
+
+pub const ACCOUNT_CLOSURE_SETTLEMENT_JOB: JobType =
+    JobType::new("outbox.account-closure-settlement");
+
+pub const SETTLE_ACCOUNT_CLOSURE_COMMAND: JobType =
+    JobType::new("command.deposit.settle-account-closure");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettleAccountClosureConfig {
+    pub account_closure_id: AccountClosureId,
+    pub payout_settled: bool,
+}
+
+/// How long to wait before settling again when the account is not ready to close.
+const SETTLEMENT_RETRY_INTERVAL: chrono::Duration = chrono::Duration::hours(1);
+
+pub type SettleAccountClosureJobSpawner = JobSpawner<SettleAccountClosureConfig>;
+
+/// Waits for the payout of a pending account closure to reach a final state.
+pub struct AccountClosureSettlementHandler {
+    account_closures: AccountClosureRepo,
+    settle_account_closure: SettleAccountClosureJobSpawner,
+}
+
+impl AccountClosureSettlementHandler {
+    pub fn new(
+        account_closures: &AccountClosureRepo,
+        settle_account_closure: SettleAccountClosureJobSpawner,
+    ) -> Self {
+        Self {
+            account_closures: account_closures.clone(),
+            settle_account_closure,
+        }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for AccountClosureSettlementHandler
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[instrument(name = "core_deposit.account_closure_settlement_job.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(e) = event.as_event() else {
+            return Ok(());
+        };
+        let (deposit_account_id, payout_transaction, payout_settled): (
+            DepositAccountId,
+            AccountClosurePayoutTransaction,
+            bool,
+        ) = match e {
+            CoreDepositEvent::WithdrawalConfirmed { entity } => (
+                entity.deposit_account_id,
+                AccountClosurePayoutTransaction::Withdrawal {
+                    withdrawal_id: entity.id,
+                },
+                true,
+            ),
+            CoreDepositEvent::WithdrawalApprovalConcluded { entity }
+                if entity.status == WithdrawalStatus::Denied =>
+            {
+                (
+                    entity.deposit_account_id,
+                    AccountClosurePayoutTransaction::Withdrawal {
+                        withdrawal_id: entity.id,
+                    },
+                    false,
+                )
+            }
+            CoreDepositEvent::WithdrawalCancelled { entity } => (
+                entity.deposit_account_id,
+                AccountClosurePayoutTransaction::Withdrawal {
+                    withdrawal_id: entity.id,
+                },
+                false,
+            ),
+            CoreDepositEvent::TransferCompleted { entity } => (
+                entity.from_account_id,
+                AccountClosurePayoutTransaction::Transfer {
+                    transfer_id: entity.id,
+                },
+                true,
+            ),
+            CoreDepositEvent::TransferApprovalConcluded { entity }
+                if matches!(
+                    entity.status,
+                    TransferStatus::Denied | TransferStatus::Failed
+                ) =>
+            {
+                (
+                    entity.from_account_id,
+                    AccountClosurePayoutTransaction::Transfer {
+                        transfer_id: entity.id,
+                    },
+                    false,
+                )
+            }
+            _ => return Ok(()),
+        };
+
+        let Some(closure) = self
+            .account_closures
+            .maybe_find_pending_for_account(deposit_account_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        if closure.payout_transaction != Some(payout_transaction) {
+            return Ok(());
+        }
+
+        event.inject_trace_parent();
+        Span::current().record("handled", true);
+        Span::current().record("event_type", e.as_ref());
+        self.settle_account_closure
+            .spawn_with_queue_id_in_op(
+                op,
+                JobId::new(),
+                SettleAccountClosureConfig {
+                    account_closure_id: closure.id,
+                    payout_settled,
+                },
+                closure.id.to_string(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+pub struct SettleAccountClosureJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    account_closures: AccountClosureRepo,
+    deposit_accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    document_storage: DocumentStorage,
+    audit: Perms::Audit,
+    clock: ClockHandle,
+}
+
+impl<Perms, E> SettleAccountClosureJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        account_closures: &AccountClosureRepo,
+        deposit_accounts: &DepositAccountRepo<E>,
+        ledger: &DepositLedger,
+        document_storage: &DocumentStorage,
+        audit: &Perms::Audit,
+        clock: ClockHandle,
+    ) -> Self {
+        Self {
+            account_closures: account_closures.clone(),
+            deposit_accounts: deposit_accounts.clone(),
+            ledger: ledger.clone(),
+            document_storage: document_storage.clone(),
+            audit: audit.clone(),
+            clock,
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for SettleAccountClosureJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Config = SettleAccountClosureConfig;
+
+    fn job_type(&self) -> JobType {
+        SETTLE_ACCOUNT_CLOSURE_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(SettleAccountClosureJobRunner::<Perms, E> {
+            config: job.config()?,
+            account_closures: self.account_closures.clone(),
+            deposit_accounts: self.deposit_accounts.clone(),
+            ledger: self.ledger.clone(),
+            document_storage: self.document_storage.clone(),
+            audit: self.audit.clone(),
+            clock: self.clock.clone(),
+        }))
+    }
+}
+
+pub struct SettleAccountClosureJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: SettleAccountClosureConfig,
+    account_closures: AccountClosureRepo,
+    deposit_accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    document_storage: DocumentStorage,
+    audit: Perms::Audit,
+    clock: ClockHandle,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for SettleAccountClosureJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(name = "deposit.settle_account_closure.process_command", skip_all)]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut op = current_job.begin_op().await?;
+        let mut closure = self
+            .account_closures
+            .find_by_id_in_op(&mut op, self.config.account_closure_id)
+            .await?;
+        if !closure.is_payout_pending() {
+            return Ok(JobCompletion::Complete);
+        }
+
+        self.audit
+            .record_system_entry_in_op(
+                &mut op,
+                ACCOUNT_CLOSURE,
+                CoreDepositObject::account_closure(closure.id),
+                CoreDepositAction::ACCOUNT_CLOSURE_SETTLE,
+            )
+            .await?;
+
+        if !self.config.payout_settled {
+            if closure
+                .fail("payout was not completed".to_string())?
+                .did_execute()
+            {
+                self.account_closures
+                    .update_in_op(&mut op, &mut closure)
+                    .await?;
+            }
+            return Ok(JobCompletion::CompleteWithOp(op));
+        }
+
+        let balance = self
+            .ledger
+            .balance_in_op(&mut op, closure.deposit_account_id)
+            .await?;
+        if !balance.pending.is_zero() {
+            // Entries still in flight may settle the remaining balance either way,
+            // so the closure is decided once they have cleared.
+            return Ok(JobCompletion::RescheduleAt(
+                self.clock.now() + SETTLEMENT_RETRY_INTERVAL,
+            ));
+        }
+        if !balance.is_zero() {
+            if closure
+                .fail(format!(
+                    "balance remained after payout (settled {}, pending {}, held {})",
+                    balance.settled.to_usd(),
+                    balance.pending.to_usd(),
+                    balance.held.to_usd()
+                ))?
+                .did_execute()
+            {
+                self.account_closures
+                    .update_in_op(&mut op, &mut closure)
+                    .await?;
+            }
+            return Ok(JobCompletion::CompleteWithOp(op));
+        }
+
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, closure.deposit_account_id)
+            .await?;
+        if account.is_frozen() {
+            // A frozen account cannot be closed; wait for it to be unfrozen
+            // rather than failing a closure whose payout already went out.
+            return Ok(JobCompletion::RescheduleAt(
+                self.clock.now() + SETTLEMENT_RETRY_INTERVAL,
+            ));
+        }
+        match account.close() {
+            Ok(closed) => {
+                if closed.did_execute() {
+                    self.deposit_accounts
+                        .update_in_op(&mut op, &mut account)
+                        .await?;
+                    self.ledger
+                        .lock_account_in_op(&mut op, account.id.into())
+                        .await?;
+                }
+            }
+            Err(e) => {
+                if closure
+                    .fail(format!("deposit account could not be closed: {e}"))?
+                    .did_execute()
+                {
+                    self.account_closures
+                        .update_in_op(&mut op, &mut closure)
+                        .await?;
+                }
+                return Ok(JobCompletion::CompleteWithOp(op));
+            }
+        }
+
+        let closed_at = self.clock.now();
+        let content = closing_statement_csv(&closure, &account, closed_at)?;
+        let mut document = self
+            .document_storage
+            .create_in_op(
+                &mut op,
+                format!("closing-statement-{}.csv", account.public_id),
+                "text/csv",
+                closure.id,
+                ACCOUNT_CLOSING_STATEMENT_DOCUMENT_TYPE,
+            )
+            .await?;
+        self.document_storage
+            .upload_in_op(&mut op, content, &mut document)
+            .await?;
+
+        if closure.close(document.id, closed_at)?.did_execute() {
+            self.account_closures
+                .update_in_op(&mut op, &mut closure)
+                .await?;
+        }
+
+        Ok(JobCompletion::CompleteWithOp(op))
+    }
+}
@@ -0,0 +1,21 @@
+mod entity;
+pub mod error;
+mod jobs;
+mod repo;
+mod statement;
+
+use document_storage::DocumentType;
+
+#[cfg(feature = "json-schema")]
+pub use entity::AccountClosureEvent;
+pub(super) use entity::*;
+pub use entity::{
+    AccountClosure, AccountClosureHoldTreatment, AccountClosurePayout,
+    AccountClosurePayoutTransaction, AccountClosureStatus,
+};
+pub(super) use jobs::*;
+pub use repo::account_closure_cursor::AccountClosuresByCreatedAtCursor;
+pub(super) use repo::*;
+
+pub(crate) const ACCOUNT_CLOSING_STATEMENT_DOCUMENT_TYPE: DocumentType =
+    DocumentType::new("account_closing_statement");
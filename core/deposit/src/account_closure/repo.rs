@@ -0,0 +1,111 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{AccountClosureId, DepositAccountId};
+
+use super::{entity::*, error::AccountClosureError};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "AccountClosure",
+    columns(
+        deposit_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        status(ty = "AccountClosureStatus", list_for, update(accessor = "status()"))
+    ),
+    tbl_prefix = "core"
+)]
+pub struct AccountClosureRepo {
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl AccountClosureRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+
+    /// The closure of `deposit_account_id` that is still waiting for its payout to settle.
+    pub async fn maybe_find_pending_for_account(
+        &self,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<Option<AccountClosure>, AccountClosureError> {
+        let mut query =
+            PaginatedQueryArgs::<account_closure_cursor::AccountClosuresByCreatedAtCursor> {
+                first: 100,
+                after: None,
+            };
+        loop {
+            let PaginatedQueryRet {
+                entities,
+                has_next_page,
+                end_cursor,
+            } = self
+                .list_for_deposit_account_id_by_created_at(
+                    deposit_account_id,
+                    query,
+                    ListDirection::Descending,
+                )
+                .await?;
+            if let Some(closure) = entities
+                .into_iter()
+                .find(|closure| closure.is_payout_pending())
+            {
+                return Ok(Some(closure));
+            }
+            if !has_next_page {
+                return Ok(None);
+            }
+            query = PaginatedQueryArgs {
+                first: 100,
+                after: end_cursor,
+            };
+        }
+    }
+}
+
+mod account_closure_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::AccountClosureStatus;
+
+    impl Type<Postgres> for AccountClosureStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for AccountClosureStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for AccountClosureStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for AccountClosureStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+
+use crate::account::DepositAccount;
+
+use super::{entity::*, error::AccountClosureError};
+
+/// Renders the closing statement handed to the account holder once the account is closed.
+/// It is a two column `field,value` CSV so that it can be read both by people and by tools.
+pub(crate) fn closing_statement_csv(
+    closure: &AccountClosure,
+    account: &DepositAccount,
+    closed_at: DateTime<Utc>,
+) -> Result<Vec<u8>, AccountClosureError> {
+    let (payout_type, payout_destination) = match closure.payout {
+        AccountClosurePayout::DepositAccount { deposit_account_id } => {
+            ("deposit_account", deposit_account_id.to_string())
+        }
+        AccountClosurePayout::Beneficiary { beneficiary_id } => {
+            ("beneficiary", beneficiary_id.to_string())
+        }
+    };
+    let payout_transaction = match closure.payout_transaction {
+        Some(AccountClosurePayoutTransaction::Withdrawal { withdrawal_id }) => {
+            format!("withdrawal:{withdrawal_id}")
+        }
+        Some(AccountClosurePayoutTransaction::Transfer { transfer_id }) => {
+            format!("transfer:{transfer_id}")
+        }
+        None => String::new(),
+    };
+    let released_holds = closure
+        .released_hold_ids
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(["field", "value"])?;
+    for (field, value) in [
+        ("account_closure_id", closure.id.to_string()),
+        ("deposit_account_id", account.id.to_string()),
+        ("deposit_account_public_id", account.public_id.to_string()),
+        ("currency", account.currency.to_string()),
+        ("initiated_at", closure.created_at().to_rfc3339()),
+        ("closed_at", closed_at.to_rfc3339()),
+        ("hold_treatment", closure.hold_treatment.to_string()),
+        ("released_holds", released_holds),
+        ("payout_type", payout_type.to_string()),
+        ("payout_destination", payout_destination),
+        ("payout_transaction", payout_transaction),
+        (
+            "payout_amount",
+            format!("{:.2}", closure.payout_amount.to_usd()),
+        ),
+        ("closing_balance", "0.00".to_string()),
+    ] {
+        writer.write_record([field, value.as_str()])?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| AccountClosureError::Csv(e.into_error().into()))
+}
@@ -0,0 +1,151 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use document_storage::DocumentId;
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, DepositAccountStatementId};
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositAccountStatementFormat {
+    Pdf,
+    Csv,
+}
+
+impl DepositAccountStatementFormat {
+    pub(super) fn content_type(&self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Csv => "text/csv",
+        }
+    }
+
+    pub(super) fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositAccountStatementId")]
+pub enum DepositAccountStatementEvent {
+    Initialized {
+        id: DepositAccountStatementId,
+        deposit_account_id: DepositAccountId,
+        document_id: DocumentId,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        format: DepositAccountStatementFormat,
+        monthly: bool,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct DepositAccountStatement {
+    pub id: DepositAccountStatementId,
+    pub deposit_account_id: DepositAccountId,
+    pub document_id: DocumentId,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub format: DepositAccountStatementFormat,
+    /// Set for the statements produced automatically at the end of every month.
+    pub monthly: bool,
+    events: EntityEvents<DepositAccountStatementEvent>,
+}
+
+impl TryFromEvents<DepositAccountStatementEvent> for DepositAccountStatement {
+    fn try_from_events(
+        events: EntityEvents<DepositAccountStatementEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = DepositAccountStatementBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositAccountStatementEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    document_id,
+                    period_start,
+                    period_end,
+                    format,
+                    monthly,
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .document_id(*document_id)
+                        .period_start(*period_start)
+                        .period_end(*period_end)
+                        .format(*format)
+                        .monthly(*monthly)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+impl DepositAccountStatement {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub(super) fn is_monthly_statement_for(
+        &self,
+        period_end: NaiveDate,
+        format: DepositAccountStatementFormat,
+    ) -> bool {
+        self.monthly && self.period_end == period_end && self.format == format
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositAccountStatement {
+    #[builder(setter(into))]
+    pub(super) id: DepositAccountStatementId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) document_id: DocumentId,
+    pub(super) period_start: NaiveDate,
+    pub(super) period_end: NaiveDate,
+    pub(super) format: DepositAccountStatementFormat,
+    #[builder(default)]
+    pub(super) monthly: bool,
+}
+
+impl NewDepositAccountStatement {
+    pub fn builder() -> NewDepositAccountStatementBuilder {
+        NewDepositAccountStatementBuilder::default()
+    }
+}
+
+impl IntoEvents<DepositAccountStatementEvent> for NewDepositAccountStatement {
+    fn into_events(self) -> EntityEvents<DepositAccountStatementEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositAccountStatementEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                document_id: self.document_id,
+                period_start: self.period_start,
+                period_end: self.period_end,
+                format: self.format,
+                monthly: self.monthly,
+            }],
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::DepositAccountStatementId;
+
+use super::repo::{
+    DepositAccountStatementCreateError, DepositAccountStatementFindError,
+    DepositAccountStatementQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum DepositAccountStatementError {
+    #[error("DepositAccountStatementError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositAccountStatementError - Create: {0}")]
+    Create(#[from] DepositAccountStatementCreateError),
+    #[error("DepositAccountStatementError - Find: {0}")]
+    Find(#[from] DepositAccountStatementFindError),
+    #[error("DepositAccountStatementError - Query: {0}")]
+    Query(#[from] DepositAccountStatementQueryError),
+    #[error("DepositAccountStatementError - Csv: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("DepositAccountStatementError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("DepositAccountStatementError - RenderingError: {0}")]
+    RenderingError(#[from] rendering::RenderingError),
+    #[error("DepositAccountStatementError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
+    #[error("DepositAccountStatementError - DepositAccountError: {0}")]
+    DepositAccountError(#[from] crate::account::error::DepositAccountError),
+    #[error("DepositAccountStatementError - InvalidPeriod: {0} is after {1}")]
+    InvalidPeriod(chrono::NaiveDate, chrono::NaiveDate),
+    #[error("DepositAccountStatementError - DocumentNotReady: {0}")]
+    DocumentNotReady(DepositAccountStatementId),
+}
+
+impl ErrorSeverity for DepositAccountStatementError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::Csv(_) => Level::ERROR,
+            Self::DocumentStorageError(e) => e.severity(),
+            Self::RenderingError(e) => e.severity(),
+            Self::DepositLedgerError(e) => e.severity(),
+            Self::DepositAccountError(e) => e.severity(),
+            Self::InvalidPeriod(..) => Level::WARN,
+            Self::DocumentNotReady(_) => Level::WARN,
+        }
+    }
+}
+
+impl From<crate::account::DepositAccountFindError> for DepositAccountStatementError {
+    fn from(e: crate::account::DepositAccountFindError) -> Self {
+        Self::DepositAccountError(e.into())
+    }
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use job::*;
+use obix::out::OutboxEventMarker;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    account_statement::DepositAccountStatements, primitives::DepositAccountStatementId,
+    public::CoreDepositEvent,
+};
+
+pub const GENERATE_DEPOSIT_ACCOUNT_STATEMENT_COMMAND: JobType =
+    JobType::new("command.deposit.generate-deposit-account-statement");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateDepositAccountStatementConfig {
+    pub deposit_account_statement_id: DepositAccountStatementId,
+}
+
+pub type GenerateDepositAccountStatementJobSpawner =
+    JobSpawner<GenerateDepositAccountStatementConfig>;
+
+pub struct GenerateDepositAccountStatementJobInitializer<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    statements: DepositAccountStatements<E>,
+}
+
+impl<E> GenerateDepositAccountStatementJobInitializer<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(statements: &DepositAccountStatements<E>) -> Self {
+        Self {
+            statements: statements.clone(),
+        }
+    }
+}
+
+impl<E> JobInitializer for GenerateDepositAccountStatementJobInitializer<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Config = GenerateDepositAccountStatementConfig;
+
+    fn job_type(&self) -> JobType {
+        GENERATE_DEPOSIT_ACCOUNT_STATEMENT_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateDepositAccountStatementJobRunner {
+            config: job.config()?,
+            statements: self.statements.clone(),
+        }))
+    }
+}
+
+struct GenerateDepositAccountStatementJobRunner<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: GenerateDepositAccountStatementConfig,
+    statements: DepositAccountStatements<E>,
+}
+
+#[async_trait]
+impl<E> JobRunner for GenerateDepositAccountStatementJobRunner<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "deposit.generate_deposit_account_statement.process_command",
+        skip(self, _current_job),
+        fields(deposit_account_statement_id = %self.config.deposit_account_statement_id)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        self.statements
+            .generate_document(self.config.deposit_account_statement_id)
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -0,0 +1,280 @@
+mod entity;
+pub mod error;
+mod jobs;
+mod repo;
+mod summary;
+mod template;
+
+use chrono::NaiveDate;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use document_storage::{
+    DocumentStatus, DocumentStorage, DocumentType, GeneratedDocumentDownloadLink, ReferenceId,
+};
+use obix::out::OutboxEventMarker;
+
+use crate::{
+    account::{DepositAccount, DepositAccountRepo},
+    ledger::DepositLedger,
+    primitives::{DepositAccountId, DepositAccountStatementId},
+    public::CoreDepositEvent,
+};
+
+#[cfg(feature = "json-schema")]
+pub use entity::DepositAccountStatementEvent;
+pub(super) use entity::*;
+pub use entity::{DepositAccountStatement, DepositAccountStatementFormat};
+use error::DepositAccountStatementError;
+pub(super) use jobs::*;
+pub(super) use repo::*;
+pub use summary::{
+    DepositAccountStatementSummary, DepositStatementEntry, StatementTransactionType,
+};
+use template::{DEPOSIT_ACCOUNT_STATEMENT_TEMPLATE, DepositAccountStatementData};
+
+const DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT_TYPE: DocumentType =
+    DocumentType::new("deposit_account_statement");
+
+pub struct DepositAccountStatements<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: DepositAccountStatementRepo,
+    deposit_accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    document_storage: DocumentStorage,
+    renderer: rendering::Renderer,
+}
+
+impl<E> Clone for DepositAccountStatements<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            deposit_accounts: self.deposit_accounts.clone(),
+            ledger: self.ledger.clone(),
+            document_storage: self.document_storage.clone(),
+            renderer: self.renderer.clone(),
+        }
+    }
+}
+
+impl<E> DepositAccountStatements<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        deposit_accounts: &DepositAccountRepo<E>,
+        ledger: &DepositLedger,
+        document_storage: &DocumentStorage,
+        gotenberg_config: gotenberg::GotenbergConfig,
+        clock: es_entity::clock::ClockHandle,
+    ) -> Self {
+        Self {
+            repo: DepositAccountStatementRepo::new(pool, clock),
+            deposit_accounts: deposit_accounts.clone(),
+            ledger: ledger.clone(),
+            document_storage: document_storage.clone(),
+            renderer: rendering::Renderer::new(gotenberg_config),
+        }
+    }
+
+    pub async fn find_by_id(
+        &self,
+        id: DepositAccountStatementId,
+    ) -> Result<DepositAccountStatement, DepositAccountStatementError> {
+        Ok(self.repo.find_by_id(id).await?)
+    }
+
+    pub async fn list_for_account(
+        &self,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<Vec<DepositAccountStatement>, DepositAccountStatementError> {
+        let mut statements = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_deposit_account_id_by_created_at(
+                    deposit_account_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+
+            statements.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(statements)
+    }
+
+    /// Registers the statement together with its (still empty) document. The content is
+    /// rendered afterwards by the generate statement job.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_deposit.account_statements.create_in_op",
+        skip(self, op, account),
+        fields(deposit_account_id = %account.id)
+    )]
+    pub async fn create_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account: &DepositAccount,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        format: DepositAccountStatementFormat,
+        monthly: bool,
+    ) -> Result<DepositAccountStatement, DepositAccountStatementError> {
+        if period_start > period_end {
+            return Err(DepositAccountStatementError::InvalidPeriod(
+                period_start,
+                period_end,
+            ));
+        }
+
+        let id = DepositAccountStatementId::new();
+        let filename = format!(
+            "deposit-account-statement-{}-{}-{}.{}",
+            account.public_id,
+            period_start.format("%Y%m%d"),
+            period_end.format("%Y%m%d"),
+            format.extension()
+        );
+        let document = self
+            .document_storage
+            .create_in_op(
+                op,
+                filename,
+                format.content_type(),
+                ReferenceId::from(id),
+                DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT_TYPE,
+            )
+            .await?;
+
+        let new_statement = NewDepositAccountStatement::builder()
+            .id(id)
+            .deposit_account_id(account.id)
+            .document_id(document.id)
+            .period_start(period_start)
+            .period_end(period_end)
+            .format(format)
+            .monthly(monthly)
+            .build()
+            .expect("could not build new deposit account statement");
+
+        Ok(self.repo.create_in_op(op, new_statement).await?)
+    }
+
+    /// Creates the monthly PDF and CSV statements that do not exist yet, so that
+    /// replaying a month-end signal never produces duplicate documents.
+    pub async fn create_monthly_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account: &DepositAccount,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<Vec<DepositAccountStatement>, DepositAccountStatementError> {
+        let existing = self
+            .repo
+            .list_for_deposit_account_id_by_created_at_in_op(
+                &mut *op,
+                account.id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?;
+
+        let mut created = Vec::new();
+        for format in [
+            DepositAccountStatementFormat::Pdf,
+            DepositAccountStatementFormat::Csv,
+        ] {
+            if existing
+                .entities
+                .iter()
+                .any(|statement| statement.is_monthly_statement_for(period_end, format))
+            {
+                continue;
+            }
+            created.push(
+                self.create_in_op(op, account, period_start, period_end, format, true)
+                    .await?,
+            );
+        }
+        Ok(created)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_deposit.account_statements.generate_document", skip(self))]
+    pub async fn generate_document(
+        &self,
+        id: DepositAccountStatementId,
+    ) -> Result<(), DepositAccountStatementError> {
+        let statement = self.repo.find_by_id(id).await?;
+        let mut document = self
+            .document_storage
+            .find_by_id(statement.document_id)
+            .await?;
+        if document.status == DocumentStatus::Active {
+            return Ok(());
+        }
+
+        let account = self
+            .deposit_accounts
+            .find_by_id(statement.deposit_account_id)
+            .await?;
+        let (opening_balance, entries) = self
+            .ledger
+            .statement_entries(account.id, statement.period_start, statement.period_end)
+            .await?;
+        let summary = DepositAccountStatementSummary::build(
+            statement.period_start,
+            statement.period_end,
+            opening_balance,
+            entries,
+        );
+        let data = DepositAccountStatementData::new(&account, summary);
+
+        let content = match statement.format {
+            DepositAccountStatementFormat::Pdf => {
+                let markdown = self
+                    .renderer
+                    .render_template_to_markdown(DEPOSIT_ACCOUNT_STATEMENT_TEMPLATE, &data)?;
+                self.renderer.render_template_to_pdf(&markdown).await?
+            }
+            DepositAccountStatementFormat::Csv => data.to_csv()?,
+        };
+
+        self.document_storage.upload(content, &mut document).await?;
+
+        Ok(())
+    }
+
+    pub async fn generate_download_link(
+        &self,
+        statement: &DepositAccountStatement,
+    ) -> Result<GeneratedDocumentDownloadLink, DepositAccountStatementError> {
+        let document = self
+            .document_storage
+            .find_by_id(statement.document_id)
+            .await?;
+        if document.status != DocumentStatus::Active {
+            return Err(DepositAccountStatementError::DocumentNotReady(statement.id));
+        }
+
+        Ok(self
+            .document_storage
+            .generate_download_link(statement.document_id)
+            .await?)
+    }
+}
@@ -0,0 +1,33 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, DepositAccountStatementId};
+
+use super::entity::*;
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "DepositAccountStatement",
+    columns(deposit_account_id(
+        ty = "DepositAccountId",
+        list_for(by(created_at)),
+        update(persist = false)
+    )),
+    tbl_prefix = "core"
+)]
+pub struct DepositAccountStatementRepo {
+    pool: PgPool,
+    #[allow(dead_code)]
+    clock: ClockHandle,
+}
+
+impl DepositAccountStatementRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use cala_ledger::DebitOrCredit;
+use money::SignedUsdCents;
+
+use crate::{
+    history::DepositAccountHistoryEntry,
+    primitives::{CalaTransactionId, UsdCents},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementTransactionType {
+    Deposit,
+    Withdrawal,
+    CancelledWithdrawal,
+    Disbursal,
+    Payment,
+    OutgoingTransfer,
+    IncomingTransfer,
+    Freeze,
+    Unfreeze,
+    InterestAccrual,
+    InterestPosting,
+    EarlyWithdrawalPenalty,
+    FeeCharge,
+    FeeReversal,
+    Escheatment,
+    EscheatmentReclaim,
+    Other,
+}
+
+impl StatementTransactionType {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Deposit => "Deposit",
+            Self::Withdrawal => "Withdrawal",
+            Self::CancelledWithdrawal => "Withdrawal cancelled",
+            Self::Disbursal => "Loan disbursal",
+            Self::Payment => "Loan payment",
+            Self::OutgoingTransfer => "Transfer out",
+            Self::IncomingTransfer => "Transfer in",
+            Self::Freeze => "Account frozen",
+            Self::Unfreeze => "Account unfrozen",
+            Self::InterestAccrual => "Interest accrued",
+            Self::InterestPosting => "Interest paid",
+            Self::EarlyWithdrawalPenalty => "Early withdrawal penalty",
+            Self::FeeCharge => "Fee",
+            Self::FeeReversal => "Fee reversed",
+            Self::Escheatment => "Balance escheated",
+            Self::EscheatmentReclaim => "Escheated balance reclaimed",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl From<&DepositAccountHistoryEntry> for StatementTransactionType {
+    fn from(entry: &DepositAccountHistoryEntry) -> Self {
+        match entry {
+            DepositAccountHistoryEntry::Deposit(_) => Self::Deposit,
+            DepositAccountHistoryEntry::Withdrawal(_) => Self::Withdrawal,
+            DepositAccountHistoryEntry::CancelledWithdrawal(_) => Self::CancelledWithdrawal,
+            DepositAccountHistoryEntry::Disbursal(_) => Self::Disbursal,
+            DepositAccountHistoryEntry::Payment(_) => Self::Payment,
+            DepositAccountHistoryEntry::OutgoingTransfer(_) => Self::OutgoingTransfer,
+            DepositAccountHistoryEntry::IncomingTransfer(_) => Self::IncomingTransfer,
+            DepositAccountHistoryEntry::Freeze(_) => Self::Freeze,
+            DepositAccountHistoryEntry::Unfreeze(_) => Self::Unfreeze,
+            DepositAccountHistoryEntry::InterestAccrual(_) => Self::InterestAccrual,
+            DepositAccountHistoryEntry::InterestPosting(_) => Self::InterestPosting,
+            DepositAccountHistoryEntry::EarlyWithdrawalPenalty(_) => Self::EarlyWithdrawalPenalty,
+            DepositAccountHistoryEntry::FeeCharge(_) => Self::FeeCharge,
+            DepositAccountHistoryEntry::FeeReversal(_) => Self::FeeReversal,
+            DepositAccountHistoryEntry::Escheatment(_) => Self::Escheatment,
+            DepositAccountHistoryEntry::EscheatmentReclaim(_) => Self::EscheatmentReclaim,
+            DepositAccountHistoryEntry::Unknown(_) | DepositAccountHistoryEntry::Ignored => {
+                Self::Other
+            }
+        }
+    }
+}
+
+/// A settled USD entry on the deposit account as read from the ledger.
+#[derive(Debug, Clone, Copy)]
+pub struct DepositStatementEntry {
+    pub tx_id: CalaTransactionId,
+    /// Value date of the transaction the entry belongs to.
+    pub effective: NaiveDate,
+    pub recorded_at: DateTime<Utc>,
+    pub direction: DebitOrCredit,
+    pub amount: UsdCents,
+    pub transaction_type: StatementTransactionType,
+}
+
+impl DepositStatementEntry {
+    /// Deposit accounts are credit normal, so credits grow the balance.
+    fn balance_change(&self) -> SignedUsdCents {
+        let amount = SignedUsdCents::from_usd(self.amount.to_usd());
+        match self.direction {
+            DebitOrCredit::Credit => amount,
+            DebitOrCredit::Debit => SignedUsdCents::ZERO - amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StatementLine {
+    pub effective: NaiveDate,
+    pub tx_id: CalaTransactionId,
+    pub transaction_type: StatementTransactionType,
+    pub credit: UsdCents,
+    pub debit: UsdCents,
+    pub running_balance: SignedUsdCents,
+}
+
+#[derive(Debug, Clone)]
+pub struct DepositAccountStatementSummary {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub opening_balance: SignedUsdCents,
+    pub closing_balance: SignedUsdCents,
+    pub total_credits: UsdCents,
+    pub total_debits: UsdCents,
+    pub lines: Vec<StatementLine>,
+}
+
+impl DepositAccountStatementSummary {
+    /// Builds the statement lines from the entries taking effect within the period,
+    /// ordered by value date, starting from the balance effective before the period.
+    pub fn build(
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        opening_balance: SignedUsdCents,
+        mut entries: Vec<DepositStatementEntry>,
+    ) -> Self {
+        entries.retain(|entry| entry.effective >= period_start && entry.effective <= period_end);
+        entries.sort_by_key(|entry| (entry.effective, entry.recorded_at));
+
+        let mut running_balance = opening_balance;
+        let mut total_credits = UsdCents::ZERO;
+        let mut total_debits = UsdCents::ZERO;
+        let mut lines = Vec::new();
+
+        for entry in entries.iter() {
+            running_balance = running_balance + entry.balance_change();
+            let (credit, debit) = match entry.direction {
+                DebitOrCredit::Credit => (entry.amount, UsdCents::ZERO),
+                DebitOrCredit::Debit => (UsdCents::ZERO, entry.amount),
+            };
+            total_credits += credit;
+            total_debits += debit;
+            lines.push(StatementLine {
+                effective: entry.effective,
+                tx_id: entry.tx_id,
+                transaction_type: entry.transaction_type,
+                credit,
+                debit,
+                running_balance,
+            });
+        }
+
+        Self {
+            period_start,
+            period_end,
+            opening_balance,
+            closing_balance: running_balance,
+            total_credits,
+            total_debits,
+            lines,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn entry(day: &str, direction: DebitOrCredit, cents: u64) -> DepositStatementEntry {
+        backdated_entry(day, day, direction, cents)
+    }
+
+    fn backdated_entry(
+        effective: &str,
+        recorded: &str,
+        direction: DebitOrCredit,
+        cents: u64,
+    ) -> DepositStatementEntry {
+        DepositStatementEntry {
+            tx_id: CalaTransactionId::new(),
+            effective: date(effective),
+            recorded_at: format!("{recorded}T12:00:00Z").parse().unwrap(),
+            direction,
+            amount: UsdCents::from(cents),
+            transaction_type: match direction {
+                DebitOrCredit::Credit => StatementTransactionType::Deposit,
+                DebitOrCredit::Debit => StatementTransactionType::Withdrawal,
+            },
+        }
+    }
+
+    fn usd(cents: i64) -> SignedUsdCents {
+        SignedUsdCents::from_usd(rust_decimal::Decimal::new(cents, 2))
+    }
+
+    #[test]
+    fn carries_running_balance_across_the_period() {
+        let entries = vec![
+            entry("2025-02-10", DebitOrCredit::Debit, 30_000),
+            entry("2025-02-03", DebitOrCredit::Credit, 5_000),
+            entry("2025-03-01", DebitOrCredit::Credit, 1_000),
+        ];
+
+        let summary = DepositAccountStatementSummary::build(
+            date("2025-02-01"),
+            date("2025-02-28"),
+            usd(100_000),
+            entries,
+        );
+
+        assert_eq!(summary.opening_balance, usd(100_000));
+        assert_eq!(summary.closing_balance, usd(75_000));
+        assert_eq!(summary.total_credits, UsdCents::from(5_000));
+        assert_eq!(summary.total_debits, UsdCents::from(30_000));
+        assert_eq!(summary.lines.len(), 2);
+        assert_eq!(summary.lines[0].running_balance, usd(105_000));
+        assert_eq!(summary.lines[1].running_balance, usd(75_000));
+    }
+
+    #[test]
+    fn empty_period_keeps_opening_balance() {
+        let entries = vec![entry("2025-01-05", DebitOrCredit::Credit, 100_000)];
+
+        let summary = DepositAccountStatementSummary::build(
+            date("2025-02-01"),
+            date("2025-02-28"),
+            usd(100_000),
+            entries,
+        );
+
+        assert!(summary.lines.is_empty());
+        assert_eq!(summary.opening_balance, summary.closing_balance);
+        assert_eq!(summary.closing_balance, usd(100_000));
+    }
+
+    #[test]
+    fn orders_lines_by_value_date() {
+        let entries = vec![
+            entry("2025-02-10", DebitOrCredit::Debit, 30_000),
+            backdated_entry("2025-02-05", "2025-02-20", DebitOrCredit::Credit, 5_000),
+            backdated_entry("2025-01-31", "2025-02-02", DebitOrCredit::Credit, 1_000),
+        ];
+
+        let summary = DepositAccountStatementSummary::build(
+            date("2025-02-01"),
+            date("2025-02-28"),
+            usd(100_000),
+            entries,
+        );
+
+        assert_eq!(summary.lines.len(), 2);
+        assert_eq!(summary.lines[0].effective, date("2025-02-05"));
+        assert_eq!(summary.lines[0].running_balance, usd(105_000));
+        assert_eq!(summary.lines[1].running_balance, usd(75_000));
+        assert_eq!(summary.closing_balance, usd(75_000));
+    }
+}
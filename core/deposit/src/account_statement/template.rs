@@ -0,0 +1,116 @@
+use serde::Serialize;
+
+use money::SignedUsdCents;
+
+use crate::{account::DepositAccount, primitives::UsdCents};
+
+use super::{error::DepositAccountStatementError, summary::DepositAccountStatementSummary};
+
+pub(super) const DEPOSIT_ACCOUNT_STATEMENT_TEMPLATE: &str =
+    include_str!("templates/deposit_account_statement.md.hbs");
+
+#[derive(Serialize)]
+struct StatementLineData {
+    effective: String,
+    tx_id: String,
+    description: &'static str,
+    debit: String,
+    credit: String,
+    running_balance: String,
+}
+
+/// Data structure for the deposit account statement template
+#[derive(Serialize)]
+pub struct DepositAccountStatementData {
+    deposit_account_id: String,
+    public_id: String,
+    period_start: String,
+    period_end: String,
+    opening_balance: String,
+    closing_balance: String,
+    total_credits: String,
+    total_debits: String,
+    lines: Vec<StatementLineData>,
+}
+
+fn format_cents(cents: UsdCents) -> String {
+    if cents.is_zero() {
+        String::new()
+    } else {
+        format!("{:.2}", cents.to_usd())
+    }
+}
+
+fn format_balance(balance: SignedUsdCents) -> String {
+    format!("{:.2}", balance.to_usd())
+}
+
+impl DepositAccountStatementData {
+    pub fn new(account: &DepositAccount, summary: DepositAccountStatementSummary) -> Self {
+        Self {
+            deposit_account_id: account.id.to_string(),
+            public_id: account.public_id.to_string(),
+            period_start: summary.period_start.format("%Y-%m-%d").to_string(),
+            period_end: summary.period_end.format("%Y-%m-%d").to_string(),
+            opening_balance: format_balance(summary.opening_balance),
+            closing_balance: format_balance(summary.closing_balance),
+            total_credits: format!("{:.2}", summary.total_credits.to_usd()),
+            total_debits: format!("{:.2}", summary.total_debits.to_usd()),
+            lines: summary
+                .lines
+                .into_iter()
+                .map(|line| StatementLineData {
+                    effective: line.effective.format("%Y-%m-%d").to_string(),
+                    tx_id: line.tx_id.to_string(),
+                    description: line.transaction_type.description(),
+                    debit: format_cents(line.debit),
+                    credit: format_cents(line.credit),
+                    running_balance: format_balance(line.running_balance),
+                })
+                .collect(),
+        }
+    }
+
+    /// The CSV variant opens and closes with balance rows so that the file can be
+    /// reconciled on its own without the summary section of the PDF.
+    pub fn to_csv(&self) -> Result<Vec<u8>, DepositAccountStatementError> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record([
+            "value_date",
+            "transaction_id",
+            "description",
+            "debit",
+            "credit",
+            "balance",
+        ])?;
+        writer.write_record([
+            self.period_start.as_str(),
+            "",
+            "Opening balance",
+            "",
+            "",
+            self.opening_balance.as_str(),
+        ])?;
+        for line in self.lines.iter() {
+            writer.write_record([
+                line.effective.as_str(),
+                line.tx_id.as_str(),
+                line.description,
+                line.debit.as_str(),
+                line.credit.as_str(),
+                line.running_balance.as_str(),
+            ])?;
+        }
+        writer.write_record([
+            self.period_end.as_str(),
+            "",
+            "Closing balance",
+            "",
+            "",
+            self.closing_balance.as_str(),
+        ])?;
+        writer
+            .into_inner()
+            .map_err(|e| DepositAccountStatementError::Csv(e.into_error().into()))
+    }
+}
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum CryptoNetwork {
+    Bitcoin,
+    Ethereum,
+    Tron,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct BankAccountDetails {
+    pub account_holder_name: String,
+    pub bank_name: Option<String>,
+    pub iban: Option<String>,
+    pub account_number: Option<String>,
+    pub aba_routing_number: Option<String>,
+    pub swift_bic: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct CryptoAddressDetails {
+    pub network: CryptoNetwork,
+    pub address: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BeneficiaryDestination {
+    BankAccount(BankAccountDetails),
+    CryptoAddress(CryptoAddressDetails),
+}
+
+impl BeneficiaryDestination {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match self {
+            BeneficiaryDestination::BankAccount(details) => details.validate(),
+            BeneficiaryDestination::CryptoAddress(details) => details.validate(),
+        }
+    }
+
+    /// Single line description of the destination, as shared with transaction monitoring.
+    pub fn payment_details(&self) -> String {
+        match self {
+            BeneficiaryDestination::BankAccount(details) => {
+                let mut parts = vec![details.account_holder_name.clone()];
+                if let Some(iban) = &details.iban {
+                    parts.push(format!("IBAN {iban}"));
+                }
+                if let Some(account_number) = &details.account_number {
+                    parts.push(format!("account {account_number}"));
+                }
+                if let Some(aba) = &details.aba_routing_number {
+                    parts.push(format!("ABA {aba}"));
+                }
+                if let Some(swift) = &details.swift_bic {
+                    parts.push(format!("SWIFT {swift}"));
+                }
+                if let Some(bank_name) = &details.bank_name {
+                    parts.push(bank_name.clone());
+                }
+                parts.join(", ")
+            }
+            BeneficiaryDestination::CryptoAddress(details) => {
+                format!("{}:{}", details.network, details.address)
+            }
+        }
+    }
+
+    pub fn is_crypto(&self) -> bool {
+        matches!(self, BeneficiaryDestination::CryptoAddress(_))
+    }
+}
+
+impl BankAccountDetails {
+    fn validate(&self) -> Result<(), String> {
+        if self.account_holder_name.trim().is_empty() {
+            return Err("Bank account holder name cannot be empty".to_string());
+        }
+        if let Some(iban) = &self.iban
+            && !is_valid_iban(iban)
+        {
+            return Err(format!("Invalid IBAN: {iban}"));
+        }
+        if let Some(aba) = &self.aba_routing_number
+            && !is_valid_aba_routing_number(aba)
+        {
+            return Err(format!("Invalid ABA routing number: {aba}"));
+        }
+        if let Some(swift) = &self.swift_bic
+            && !is_valid_swift_bic(swift)
+        {
+            return Err(format!("Invalid SWIFT/BIC code: {swift}"));
+        }
+        match (&self.iban, &self.account_number) {
+            (None, None) => Err("Either an IBAN or an account number is required".to_string()),
+            (None, Some(_)) if self.aba_routing_number.is_none() && self.swift_bic.is_none() => {
+                Err(
+                    "An account number requires an ABA routing number or a SWIFT/BIC code"
+                        .to_string(),
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl CryptoAddressDetails {
+    fn validate(&self) -> Result<(), String> {
+        let address = self.address.as_str();
+        if address.is_empty() || !address.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("Invalid {} address: {address}", self.network));
+        }
+        let valid = match self.network {
+            CryptoNetwork::Bitcoin => (26..=90).contains(&address.len()),
+            CryptoNetwork::Ethereum => {
+                address.len() == 42
+                    && address.starts_with("0x")
+                    && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+            }
+            CryptoNetwork::Tron => address.len() == 34 && address.starts_with('T'),
+        };
+        if !valid {
+            return Err(format!("Invalid {} address: {address}", self.network));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn is_valid_iban(iban: &str) -> bool {
+    let iban: String = iban.chars().filter(|c| !c.is_whitespace()).collect();
+    if !(15..=34).contains(&iban.len()) || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let (head, tail) = iban.split_at(4);
+    if !head[..2].chars().all(|c| c.is_ascii_uppercase())
+        || !head[2..].chars().all(|c| c.is_ascii_digit())
+    {
+        return false;
+    }
+    let remainder = tail.chars().chain(head.chars()).try_fold(0u32, |acc, c| {
+        let value = c.to_digit(36)?;
+        Some(if value < 10 {
+            (acc * 10 + value) % 97
+        } else {
+            (acc * 100 + value) % 97
+        })
+    });
+    remainder == Some(1)
+}
+
+fn is_valid_aba_routing_number(aba: &str) -> bool {
+    if aba.len() != 9 {
+        return false;
+    }
+    let Some(digits) = aba
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    let checksum: u32 = digits
+        .iter()
+        .zip([3, 7, 1].iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    checksum.is_multiple_of(10)
+}
+
+pub(crate) fn is_valid_swift_bic(swift: &str) -> bool {
+    swift.is_ascii()
+        && (swift.len() == 8 || swift.len() == 11)
+        && swift[..6].chars().all(|c| c.is_ascii_uppercase())
+        && swift[6..].chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bank_account() -> BankAccountDetails {
+        BankAccountDetails {
+            account_holder_name: "Jane Doe".to_string(),
+            bank_name: None,
+            iban: None,
+            account_number: None,
+            aba_routing_number: None,
+            swift_bic: None,
+        }
+    }
+
+    #[test]
+    fn validates_iban_checksum() {
+        assert!(is_valid_iban("DE89 3704 0044 0532 0130 00"));
+        assert!(is_valid_iban("GB29NWBK60161331926819"));
+        assert!(!is_valid_iban("GB29NWBK60161331926818"));
+        assert!(!is_valid_iban("GB29"));
+    }
+
+    #[test]
+    fn validates_aba_routing_number_checksum() {
+        assert!(is_valid_aba_routing_number("021000021"));
+        assert!(!is_valid_aba_routing_number("021000022"));
+        assert!(!is_valid_aba_routing_number("02100002"));
+    }
+
+    #[test]
+    fn account_number_requires_routing() {
+        let mut details = bank_account();
+        assert!(details.validate().is_err());
+
+        details.account_number = Some("123456789".to_string());
+        assert!(details.validate().is_err());
+
+        details.aba_routing_number = Some("021000021".to_string());
+        assert!(details.validate().is_ok());
+    }
+
+    #[test]
+    fn validates_crypto_address_format() {
+        let address = |network, address: &str| CryptoAddressDetails {
+            network,
+            address: address.to_string(),
+        };
+        assert!(
+            address(
+                CryptoNetwork::Ethereum,
+                "0x52908400098527886E0F7030069857D2E4169EE7"
+            )
+            .validate()
+            .is_ok()
+        );
+        assert!(
+            address(
+                CryptoNetwork::Ethereum,
+                "52908400098527886E0F7030069857D2E4169EE7"
+            )
+            .validate()
+            .is_err()
+        );
+        assert!(
+            address(
+                CryptoNetwork::Bitcoin,
+                "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+            )
+            .validate()
+            .is_ok()
+        );
+    }
+}
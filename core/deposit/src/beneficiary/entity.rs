@@ -0,0 +1,329 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{BeneficiaryId, DepositAccountHolderId};
+
+use super::{destination::BeneficiaryDestination, error::BeneficiaryError};
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum BeneficiaryStatus {
+    #[default]
+    PendingVerification,
+    Verified,
+    Rejected,
+    Removed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "BeneficiaryId")]
+pub enum BeneficiaryEvent {
+    Initialized {
+        id: BeneficiaryId,
+        account_holder_id: DepositAccountHolderId,
+        name: String,
+        destination: BeneficiaryDestination,
+        cooling_off_ends_at: DateTime<Utc>,
+        status: BeneficiaryStatus,
+    },
+    Verified {
+        status: BeneficiaryStatus,
+    },
+    VerificationRejected {
+        reason: String,
+        status: BeneficiaryStatus,
+    },
+    Removed {
+        status: BeneficiaryStatus,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Beneficiary {
+    pub id: BeneficiaryId,
+    pub account_holder_id: DepositAccountHolderId,
+    pub name: String,
+    pub destination: BeneficiaryDestination,
+    pub cooling_off_ends_at: DateTime<Utc>,
+
+    events: EntityEvents<BeneficiaryEvent>,
+}
+
+impl Beneficiary {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for beneficiary")
+    }
+
+    pub fn status(&self) -> BeneficiaryStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                BeneficiaryEvent::Initialized { status, .. }
+                | BeneficiaryEvent::Verified { status }
+                | BeneficiaryEvent::VerificationRejected { status, .. }
+                | BeneficiaryEvent::Removed { status } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn rejection_reason(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            BeneficiaryEvent::VerificationRejected { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn is_in_cooling_off_period(&self, now: DateTime<Utc>) -> bool {
+        now < self.cooling_off_ends_at
+    }
+
+    /// Checks that withdrawals from an account held by `account_holder_ids` can be paid out to this beneficiary.
+    pub(crate) fn ensure_usable_by(
+        &self,
+        account_holder_ids: &[DepositAccountHolderId],
+        now: DateTime<Utc>,
+    ) -> Result<(), BeneficiaryError> {
+        if !account_holder_ids.contains(&self.account_holder_id) {
+            return Err(BeneficiaryError::NotOwnedByAccountHolder(self.id));
+        }
+        if self.status() != BeneficiaryStatus::Verified {
+            return Err(BeneficiaryError::NotVerified(self.id));
+        }
+        if self.is_in_cooling_off_period(now) {
+            return Err(BeneficiaryError::CoolingOffPeriodActive(
+                self.id,
+                self.cooling_off_ends_at,
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn verify(&mut self) -> Result<Idempotent<()>, BeneficiaryError> {
+        if self.status() == BeneficiaryStatus::Removed {
+            return Err(BeneficiaryError::AlreadyRemoved(self.id));
+        }
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: BeneficiaryEvent::Verified { .. },
+            resets_on: BeneficiaryEvent::VerificationRejected { .. }
+        );
+        self.events.push(BeneficiaryEvent::Verified {
+            status: BeneficiaryStatus::Verified,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn reject(&mut self, reason: String) -> Result<Idempotent<()>, BeneficiaryError> {
+        if self.status() == BeneficiaryStatus::Removed {
+            return Err(BeneficiaryError::AlreadyRemoved(self.id));
+        }
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: BeneficiaryEvent::VerificationRejected { .. },
+            resets_on: BeneficiaryEvent::Verified { .. }
+        );
+        self.events.push(BeneficiaryEvent::VerificationRejected {
+            reason,
+            status: BeneficiaryStatus::Rejected,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn remove(&mut self) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: BeneficiaryEvent::Removed { .. }
+        );
+        self.events.push(BeneficiaryEvent::Removed {
+            status: BeneficiaryStatus::Removed,
+        });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<BeneficiaryEvent> for Beneficiary {
+    fn try_from_events(
+        events: EntityEvents<BeneficiaryEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = BeneficiaryBuilder::default();
+        for event in events.iter_all() {
+            if let BeneficiaryEvent::Initialized {
+                id,
+                account_holder_id,
+                name,
+                destination,
+                cooling_off_ends_at,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .account_holder_id(*account_holder_id)
+                    .name(name.clone())
+                    .destination(destination.clone())
+                    .cooling_off_ends_at(*cooling_off_ends_at)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewBeneficiary {
+    #[builder(setter(into))]
+    pub(super) id: BeneficiaryId,
+    #[builder(setter(into))]
+    pub(super) account_holder_id: DepositAccountHolderId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    pub(super) destination: BeneficiaryDestination,
+    pub(super) cooling_off_ends_at: DateTime<Utc>,
+    #[builder(setter(skip), default)]
+    pub(super) status: BeneficiaryStatus,
+}
+
+impl NewBeneficiary {
+    pub fn builder() -> NewBeneficiaryBuilder {
+        NewBeneficiaryBuilder::default()
+    }
+}
+
+impl NewBeneficiaryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(name) = self.name.as_ref()
+            && name.trim().is_empty()
+        {
+            return Err("Beneficiary name cannot be empty".to_string());
+        }
+        if let Some(destination) = self.destination.as_ref() {
+            destination.validate()?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<BeneficiaryEvent> for NewBeneficiary {
+    fn into_events(self) -> EntityEvents<BeneficiaryEvent> {
+        EntityEvents::init(
+            self.id,
+            [BeneficiaryEvent::Initialized {
+                id: self.id,
+                account_holder_id: self.account_holder_id,
+                name: self.name,
+                destination: self.destination,
+                cooling_off_ends_at: self.cooling_off_ends_at,
+                status: self.status,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Duration;
+
+    use crate::beneficiary::destination::{CryptoAddressDetails, CryptoNetwork};
+
+    use super::*;
+
+    fn beneficiary(cooling_off_ends_at: DateTime<Utc>) -> Beneficiary {
+        let new_beneficiary = NewBeneficiary::builder()
+            .id(BeneficiaryId::new())
+            .account_holder_id(DepositAccountHolderId::new())
+            .name("Cold storage")
+            .destination(BeneficiaryDestination::CryptoAddress(
+                CryptoAddressDetails {
+                    network: CryptoNetwork::Ethereum,
+                    address: "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+                },
+            ))
+            .cooling_off_ends_at(cooling_off_ends_at)
+            .build()
+            .unwrap();
+        Beneficiary::try_from_events(new_beneficiary.into_events()).unwrap()
+    }
+
+    #[test]
+    fn usable_only_once_verified_and_cooled_off() {
+        let now = Utc::now();
+        let mut beneficiary = beneficiary(now + Duration::hours(24));
+        let holder_id = beneficiary.account_holder_id;
+
+        assert!(matches!(
+            beneficiary.ensure_usable_by(&[holder_id], now),
+            Err(BeneficiaryError::NotVerified(_))
+        ));
+        assert!(beneficiary.verify().unwrap().did_execute());
+        assert!(matches!(
+            beneficiary.ensure_usable_by(&[holder_id], now),
+            Err(BeneficiaryError::CoolingOffPeriodActive(..))
+        ));
+        assert!(
+            beneficiary
+                .ensure_usable_by(&[holder_id], now + Duration::hours(24))
+                .is_ok()
+        );
+        assert!(matches!(
+            beneficiary
+                .ensure_usable_by(&[DepositAccountHolderId::new()], now + Duration::hours(24)),
+            Err(BeneficiaryError::NotOwnedByAccountHolder(_))
+        ));
+    }
+
+    #[test]
+    fn rejected_beneficiary_can_be_verified_again() {
+        let mut beneficiary = beneficiary(Utc::now());
+
+        assert!(beneficiary.verify().unwrap().did_execute());
+        assert!(beneficiary.verify().unwrap().was_already_applied());
+        assert!(
+            beneficiary
+                .reject("name mismatch".to_string())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(beneficiary.status(), BeneficiaryStatus::Rejected);
+        assert_eq!(beneficiary.rejection_reason(), Some("name mismatch"));
+        assert!(beneficiary.verify().unwrap().did_execute());
+        assert_eq!(beneficiary.status(), BeneficiaryStatus::Verified);
+    }
+
+    #[test]
+    fn removed_beneficiary_cannot_be_verified() {
+        let mut beneficiary = beneficiary(Utc::now());
+
+        assert!(beneficiary.remove().did_execute());
+        assert!(beneficiary.remove().was_already_applied());
+        assert!(matches!(
+            beneficiary.verify(),
+            Err(BeneficiaryError::AlreadyRemoved(_))
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::BeneficiaryId;
+
+use super::repo::{
+    BeneficiaryCreateError, BeneficiaryFindError, BeneficiaryModifyError, BeneficiaryQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum BeneficiaryError {
+    #[error("BeneficiaryError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("BeneficiaryError - Create: {0}")]
+    Create(#[from] BeneficiaryCreateError),
+    #[error("BeneficiaryError - Modify: {0}")]
+    Modify(#[from] BeneficiaryModifyError),
+    #[error("BeneficiaryError - Find: {0}")]
+    Find(#[from] BeneficiaryFindError),
+    #[error("BeneficiaryError - Query: {0}")]
+    Query(#[from] BeneficiaryQueryError),
+    #[error("BeneficiaryError - NotOwnedByAccountHolder: {0}")]
+    NotOwnedByAccountHolder(BeneficiaryId),
+    #[error("BeneficiaryError - NotVerified: {0}")]
+    NotVerified(BeneficiaryId),
+    #[error("BeneficiaryError - CoolingOffPeriodActive: {0} can be used from {1}")]
+    CoolingOffPeriodActive(BeneficiaryId, DateTime<Utc>),
+    #[error("BeneficiaryError - AlreadyRemoved: {0}")]
+    AlreadyRemoved(BeneficiaryId),
+    #[error("BeneficiaryError - CoolingOffPeriodOutOfRange: {0} hours")]
+    CoolingOffPeriodOutOfRange(u64),
+}
+
+impl ErrorSeverity for BeneficiaryError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::NotOwnedByAccountHolder(_) => Level::WARN,
+            Self::NotVerified(_) => Level::WARN,
+            Self::CoolingOffPeriodActive(..) => Level::WARN,
+            Self::AlreadyRemoved(_) => Level::WARN,
+            Self::CoolingOffPeriodOutOfRange(_) => Level::ERROR,
+        }
+    }
+}
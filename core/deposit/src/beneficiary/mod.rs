@@ -0,0 +1,12 @@
+mod destination;
+mod entity;
+pub mod error;
+pub mod repo;
+
+pub use destination::*;
+#[cfg(feature = "json-schema")]
+pub use entity::BeneficiaryEvent;
+pub(super) use entity::*;
+pub use entity::{Beneficiary, BeneficiaryStatus};
+pub use repo::beneficiary_cursor::BeneficiariesByCreatedAtCursor;
+pub(super) use repo::*;
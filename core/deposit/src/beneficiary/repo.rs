@@ -0,0 +1,73 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{BeneficiaryId, DepositAccountHolderId};
+
+use super::entity::*;
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Beneficiary",
+    columns(
+        account_holder_id(
+            ty = "DepositAccountHolderId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        status(ty = "BeneficiaryStatus", list_for, update(accessor = "status()"))
+    ),
+    tbl_prefix = "core"
+)]
+pub struct BeneficiaryRepo {
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl BeneficiaryRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
+
+mod beneficiary_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::BeneficiaryStatus;
+
+    impl Type<Postgres> for BeneficiaryStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for BeneficiaryStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for BeneficiaryStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for BeneficiaryStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -22,6 +22,41 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_accounts_frozen_bank_deposit_accounts_parent_code: AccountCode,
     pub chart_of_accounts_frozen_financial_institution_deposit_accounts_parent_code: AccountCode,
     pub chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code: AccountCode,
+    #[serde(default)]
+    pub chart_of_accounts_interest_expense_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_accounts_fee_income_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_accounts_unclaimed_property_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_accounts_fx_position_parent_code: Option<AccountCode>,
+}
+
+impl ChartOfAccountsIntegrationConfig {
+    /// Whether `self` sets parent codes that were added after `existing` was stored,
+    /// leaving every code `existing` already had unchanged.
+    pub(super) fn only_adds_parent_codes_to(&self, existing: &Self) -> bool {
+        let mut unchanged = self.clone();
+        if existing
+            .chart_of_accounts_interest_expense_parent_code
+            .is_none()
+        {
+            unchanged.chart_of_accounts_interest_expense_parent_code = None;
+        }
+        if existing.chart_of_accounts_fee_income_parent_code.is_none() {
+            unchanged.chart_of_accounts_fee_income_parent_code = None;
+        }
+        if existing
+            .chart_of_accounts_unclaimed_property_parent_code
+            .is_none()
+        {
+            unchanged.chart_of_accounts_unclaimed_property_parent_code = None;
+        }
+        if existing.chart_of_accounts_fx_position_parent_code.is_none() {
+            unchanged.chart_of_accounts_fx_position_parent_code = None;
+        }
+        self != existing && &unchanged == existing
+    }
 }
 
 define_internal_config! {
@@ -46,6 +81,15 @@ define_internal_config! {
             CalaAccountSetId,
         pub(crate) frozen_non_domiciled_company_deposit_accounts_parent_account_set_id:
             CalaAccountSetId,
+
+        #[serde(default)]
+        pub(crate) interest_expense_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) fee_income_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) unclaimed_property_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) fx_position_parent_account_set_id: Option<CalaAccountSetId>,
     }
 
     spec {
@@ -77,6 +121,10 @@ impl ResolvedChartOfAccountsIntegrationConfig {
         let deposit = catalog.deposit();
         let frozen = catalog.frozen();
         let omnibus = catalog.omnibus();
+        let interest_expense = catalog.interest_expense();
+        let fee_income = catalog.fee_income();
+        let unclaimed_property = catalog.unclaimed_property();
+        let fx_position = catalog.fx_position();
 
         let omnibus_parent_account_set_id = category_account_set_member_parent_id(
             &config.chart_of_accounts_omnibus_parent_code,
@@ -154,6 +202,34 @@ impl ResolvedChartOfAccountsIntegrationConfig {
                 frozen.non_domiciled_company.account_category,
             )?;
 
+        let interest_expense_parent_account_set_id = config
+            .chart_of_accounts_interest_expense_parent_code
+            .as_ref()
+            .map(|code| {
+                category_account_set_member_parent_id(code, interest_expense.account_category)
+            })
+            .transpose()?;
+
+        let fee_income_parent_account_set_id = config
+            .chart_of_accounts_fee_income_parent_code
+            .as_ref()
+            .map(|code| category_account_set_member_parent_id(code, fee_income.account_category))
+            .transpose()?;
+
+        let unclaimed_property_parent_account_set_id = config
+            .chart_of_accounts_unclaimed_property_parent_code
+            .as_ref()
+            .map(|code| {
+                category_account_set_member_parent_id(code, unclaimed_property.account_category)
+            })
+            .transpose()?;
+
+        let fx_position_parent_account_set_id = config
+            .chart_of_accounts_fx_position_parent_code
+            .as_ref()
+            .map(|code| category_account_set_member_parent_id(code, fx_position.account_category))
+            .transpose()?;
+
         Ok(Self {
             config,
 
@@ -170,6 +246,10 @@ impl ResolvedChartOfAccountsIntegrationConfig {
             frozen_bank_deposit_accounts_parent_account_set_id,
             frozen_financial_institution_deposit_accounts_parent_account_set_id,
             frozen_non_domiciled_company_deposit_accounts_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            fee_income_parent_account_set_id,
+            unclaimed_property_parent_account_set_id,
+            fx_position_parent_account_set_id,
         })
     }
 }
@@ -103,8 +103,12 @@ where
             .domain_configs
             .get::<ResolvedChartOfAccountsIntegrationConfig>()
             .await?;
+        let existing_module_config = existing_module_config.maybe_value();
 
-        if existing_module_config.maybe_value().is_some() {
+        if existing_module_config
+            .as_ref()
+            .is_some_and(|existing| !config.only_adds_parent_codes_to(&existing.config))
+        {
             return Err(ChartOfAccountsIntegrationError::ConfigAlreadySet);
         }
 
@@ -123,13 +127,28 @@ where
             )
             .await?;
 
-        self.ledger
-            .attach_chart_of_accounts_account_sets_in_op(
-                &mut op,
-                &resolved_integration_config,
-                existing_module_config.maybe_value().as_ref(),
-            )
-            .await?;
+        match existing_module_config {
+            // Configs stored before a parent code was introduced only get the newly added
+            // account sets attached; everything else is already in place.
+            Some(existing) => {
+                self.ledger
+                    .attach_added_chart_of_accounts_account_sets_in_op(
+                        &mut op,
+                        &resolved_integration_config,
+                        &existing,
+                    )
+                    .await?
+            }
+            None => {
+                self.ledger
+                    .attach_chart_of_accounts_account_sets_in_op(
+                        &mut op,
+                        &resolved_integration_config,
+                        None,
+                    )
+                    .await?
+            }
+        }
 
         op.commit().await?;
 
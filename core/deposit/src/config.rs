@@ -3,6 +3,8 @@ use domain_config::{DomainConfigError, define_exposed_config};
 
 const DEFAULT_INACTIVE_THRESHOLD_DAYS: u64 = 365;
 const DEFAULT_ESCHEATABLE_THRESHOLD_DAYS: u64 = 3650;
+const DEFAULT_ESCHEATMENT_WAITING_PERIOD_DAYS: u64 = 90;
+const DEFAULT_BENEFICIARY_COOLING_OFF_HOURS: u64 = 24;
 
 define_exposed_config! {
     /// Number of days without account activity before a deposit account is classified as inactive.
@@ -30,6 +32,49 @@ define_exposed_config! {
     }
 }
 
+define_exposed_config! {
+    /// Number of days between the pre-escheatment notice and the transfer of an escheatable
+    /// account's balance to unclaimed property. Any account activity during this period cancels it.
+    pub struct DepositEscheatmentWaitingPeriodDays(u64);
+    spec {
+        key: "deposit-escheatment-waiting-period-days";
+        default: || Some(DEFAULT_ESCHEATMENT_WAITING_PERIOD_DAYS);
+        validate: |value: &u64| validate_positive_threshold(
+            "deposit-escheatment-waiting-period-days",
+            *value,
+        );
+    }
+}
+
+define_exposed_config! {
+    /// Amount in USD cents above which a transfer between deposit accounts requires governance approval.
+    /// When unset, transfers are executed immediately.
+    pub struct DepositTransferApprovalThresholdCents(u64);
+    spec {
+        key: "deposit-transfer-approval-threshold-cents";
+    }
+}
+
+define_exposed_config! {
+    /// Number of hours after a beneficiary is added before withdrawals can be paid out to it.
+    pub struct DepositBeneficiaryCoolingOffHours(u64);
+    spec {
+        key: "deposit-beneficiary-cooling-off-hours";
+        default: || Some(DEFAULT_BENEFICIARY_COOLING_OFF_HOURS);
+    }
+}
+
+define_exposed_config! {
+    /// Whether freezing one holder's deposits only suspends that holder on the joint accounts
+    /// they share with others, leaving the account usable by the remaining holders. When unset
+    /// or false the whole joint account is frozen.
+    pub struct DepositJointAccountSuspendHolderOnFreeze(bool);
+    spec {
+        key: "deposit-joint-account-suspend-holder-on-freeze";
+        default: || Some(false);
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DepositActivityThresholds {
     inactive_threshold_days: u64,
@@ -6,7 +6,10 @@ use serde::{Deserialize, Serialize};
 use es_entity::*;
 use money::UsdCents;
 
-use crate::primitives::{CalaTransactionId, DepositAccountId, DepositId, DepositStatus, PublicId};
+use crate::primitives::{
+    CalaTransactionId, CurrencyCode, DepositAccountId, DepositChannel, DepositId, DepositStatus,
+    Money, PublicId,
+};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -18,6 +21,10 @@ pub enum DepositEvent {
         ledger_tx_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default = "default_deposit_currency")]
+        currency: CurrencyCode,
+        #[serde(default)]
+        channel: DepositChannel,
         reference: String,
         status: DepositStatus,
         public_id: PublicId,
@@ -33,7 +40,7 @@ pub struct DepositReversalData {
     pub entity_id: DepositId,
     pub ledger_tx_id: CalaTransactionId,
     pub credit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub correlation_id: String,
     pub external_id: String,
 }
@@ -43,13 +50,20 @@ pub struct DepositReversalData {
 pub struct Deposit {
     pub id: DepositId,
     pub deposit_account_id: DepositAccountId,
+    /// Minor units of `currency`.
     pub amount: UsdCents,
+    pub currency: CurrencyCode,
+    pub channel: DepositChannel,
     pub reference: String,
     pub public_id: PublicId,
     events: EntityEvents<DepositEvent>,
 }
 
 impl Deposit {
+    pub fn money(&self) -> Money {
+        Money::new(self.currency, self.amount.into_inner())
+    }
+
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.events
             .entity_first_persisted_at()
@@ -84,7 +98,7 @@ impl Deposit {
             entity_id: self.id,
             ledger_tx_id,
             credit_account_id: self.deposit_account_id,
-            amount: self.amount,
+            amount: self.money(),
             correlation_id: self.id.to_string(),
             external_id: format!("lana:deposit:{}:reverted", self.id),
         })
@@ -111,6 +125,8 @@ impl TryFromEvents<DepositEvent> for Deposit {
                     reference,
                     deposit_account_id,
                     amount,
+                    currency,
+                    channel,
                     public_id,
                     ..
                 } => {
@@ -118,6 +134,8 @@ impl TryFromEvents<DepositEvent> for Deposit {
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
                         .amount(*amount)
+                        .currency(*currency)
+                        .channel(*channel)
                         .reference(reference.clone())
                         .public_id(public_id.clone());
                 }
@@ -139,6 +157,10 @@ pub struct NewDeposit {
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
     pub(super) amount: UsdCents,
+    #[builder(default = "CurrencyCode::USD")]
+    pub(super) currency: CurrencyCode,
+    #[builder(default)]
+    pub(super) channel: DepositChannel,
     #[builder(setter(into))]
     pub(super) public_id: PublicId,
     reference: Option<String>,
@@ -179,6 +201,8 @@ impl IntoEvents<DepositEvent> for NewDeposit {
                 ledger_tx_id: self.ledger_transaction_id,
                 deposit_account_id: self.deposit_account_id,
                 amount: self.amount,
+                currency: self.currency,
+                channel: self.channel,
                 status: DepositStatus::Confirmed,
                 public_id: self.public_id,
             }],
@@ -186,6 +210,10 @@ impl IntoEvents<DepositEvent> for NewDeposit {
     }
 }
 
+fn default_deposit_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
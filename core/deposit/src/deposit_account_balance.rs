@@ -1,17 +1,64 @@
-use money::UsdCents;
+use money::{CurrencyCode, Money, UsdCents};
 
 pub struct DepositAccountBalance {
     pub settled: UsdCents,
     pub pending: UsdCents,
+    pub held: UsdCents,
 }
 
 impl DepositAccountBalance {
     pub const ZERO: Self = DepositAccountBalance {
         settled: UsdCents::ZERO,
         pending: UsdCents::ZERO,
+        held: UsdCents::ZERO,
     };
 
     pub fn is_zero(&self) -> bool {
-        self.settled.is_zero() && self.pending.is_zero()
+        self.settled.is_zero() && self.pending.is_zero() && self.held.is_zero()
+    }
+
+    /// Settled funds that are not earmarked by an active hold.
+    pub fn available(&self) -> UsdCents {
+        if self.held >= self.settled {
+            UsdCents::ZERO
+        } else {
+            self.settled - self.held
+        }
+    }
+}
+
+/// Balance of a deposit account in the currency it was opened in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositAccountCurrencyBalance {
+    pub settled: Money,
+    pub pending: Money,
+    pub held: Money,
+}
+
+impl DepositAccountCurrencyBalance {
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self {
+            settled: Money::zero(currency),
+            pending: Money::zero(currency),
+            held: Money::zero(currency),
+        }
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.settled.currency()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.settled.is_zero() && self.pending.is_zero() && self.held.is_zero()
+    }
+
+    /// Settled funds that are not earmarked by an active hold.
+    pub fn available(&self) -> Money {
+        Money::new(
+            self.currency(),
+            self.settled
+                .minor_units()
+                .saturating_sub(self.held.minor_units()),
+        )
     }
 }
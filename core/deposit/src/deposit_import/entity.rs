@@ -0,0 +1,298 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use document_storage::DocumentId;
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, DepositId, DepositImportId, UsdCents};
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositImportStatus {
+    #[default]
+    Processing,
+    Completed,
+}
+
+/// A row of the uploaded file that passed validation and is due to be posted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositImportRow {
+    pub line: u64,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    pub reference: String,
+    pub value_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositImportRowError {
+    pub line: u64,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DepositImportRowOutcome {
+    Posted { deposit_id: DepositId },
+    AlreadyRecorded { deposit_id: DepositId },
+    Failed { reason: String },
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositImportId")]
+pub enum DepositImportEvent {
+    Initialized {
+        id: DepositImportId,
+        document_id: DocumentId,
+        file_name: String,
+        rows: Vec<DepositImportRow>,
+        row_errors: Vec<DepositImportRowError>,
+        status: DepositImportStatus,
+    },
+    RowProcessed {
+        line: u64,
+        outcome: DepositImportRowOutcome,
+    },
+    Completed {
+        status: DepositImportStatus,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct DepositImport {
+    pub id: DepositImportId,
+    pub document_id: DocumentId,
+    pub file_name: String,
+    pub rows: Vec<DepositImportRow>,
+    pub row_errors: Vec<DepositImportRowError>,
+
+    events: EntityEvents<DepositImportEvent>,
+}
+
+impl DepositImport {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for deposit import")
+    }
+
+    pub fn status(&self) -> DepositImportStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                DepositImportEvent::Initialized { status, .. }
+                | DepositImportEvent::Completed { status } => Some(*status),
+                _ => None,
+            })
+            .expect("status should always exist")
+    }
+
+    pub fn outcomes(&self) -> Vec<(u64, &DepositImportRowOutcome)> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                DepositImportEvent::RowProcessed { line, outcome } => Some((*line, outcome)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn processed_rows(&self) -> usize {
+        self.outcomes().len()
+    }
+
+    pub(crate) fn pending_rows(&self) -> Vec<DepositImportRow> {
+        let processed = self.outcomes();
+        self.rows
+            .iter()
+            .filter(|row| !processed.iter().any(|(line, _)| *line == row.line))
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn record_row_outcome(
+        &mut self,
+        line: u64,
+        outcome: DepositImportRowOutcome,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositImportEvent::RowProcessed { line: processed, .. } if *processed == line
+        );
+        self.events
+            .push(DepositImportEvent::RowProcessed { line, outcome });
+        Idempotent::Executed(())
+    }
+
+    pub(crate) fn complete(&mut self) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: DepositImportEvent::Completed { .. }
+        );
+        self.events.push(DepositImportEvent::Completed {
+            status: DepositImportStatus::Completed,
+        });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<DepositImportEvent> for DepositImport {
+    fn try_from_events(
+        events: EntityEvents<DepositImportEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = DepositImportBuilder::default();
+        for event in events.iter_all() {
+            if let DepositImportEvent::Initialized {
+                id,
+                document_id,
+                file_name,
+                rows,
+                row_errors,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .document_id(*document_id)
+                    .file_name(file_name.clone())
+                    .rows(rows.clone())
+                    .row_errors(row_errors.clone())
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositImport {
+    #[builder(setter(into))]
+    pub(super) id: DepositImportId,
+    #[builder(setter(into))]
+    pub(super) document_id: DocumentId,
+    #[builder(setter(into))]
+    pub(super) file_name: String,
+    pub(super) rows: Vec<DepositImportRow>,
+    pub(super) row_errors: Vec<DepositImportRowError>,
+    #[builder(setter(skip), default)]
+    pub(super) status: DepositImportStatus,
+}
+
+impl NewDepositImport {
+    pub fn builder() -> NewDepositImportBuilder {
+        NewDepositImportBuilder::default()
+    }
+}
+
+impl IntoEvents<DepositImportEvent> for NewDepositImport {
+    fn into_events(self) -> EntityEvents<DepositImportEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositImportEvent::Initialized {
+                id: self.id,
+                document_id: self.document_id,
+                file_name: self.file_name,
+                rows: self.rows,
+                row_errors: self.row_errors,
+                status: self.status,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn row(line: u64) -> DepositImportRow {
+        DepositImportRow {
+            line,
+            deposit_account_id: DepositAccountId::new(),
+            amount: UsdCents::from(10_000),
+            reference: format!("wire-{line}"),
+            value_date: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+        }
+    }
+
+    fn deposit_import(rows: Vec<DepositImportRow>) -> DepositImport {
+        let new_import = NewDepositImport::builder()
+            .id(DepositImportId::new())
+            .document_id(DocumentId::new())
+            .file_name("wires.csv")
+            .rows(rows)
+            .row_errors(vec![])
+            .build()
+            .unwrap();
+        DepositImport::try_from_events(new_import.into_events()).unwrap()
+    }
+
+    #[test]
+    fn processed_rows_are_no_longer_pending() {
+        let mut deposit_import = deposit_import(vec![row(2), row(3)]);
+        assert_eq!(deposit_import.pending_rows().len(), 2);
+
+        let _ = deposit_import.record_row_outcome(
+            2,
+            DepositImportRowOutcome::Posted {
+                deposit_id: DepositId::new(),
+            },
+        );
+
+        let pending = deposit_import.pending_rows();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].line, 3);
+        assert_eq!(deposit_import.processed_rows(), 1);
+    }
+
+    #[test]
+    fn row_outcome_is_recorded_once() {
+        let mut deposit_import = deposit_import(vec![row(2)]);
+        let outcome = DepositImportRowOutcome::Failed {
+            reason: "deposit account is Frozen".to_string(),
+        };
+
+        assert!(
+            deposit_import
+                .record_row_outcome(2, outcome.clone())
+                .did_execute()
+        );
+        assert!(
+            deposit_import
+                .record_row_outcome(2, outcome)
+                .was_already_applied()
+        );
+    }
+
+    #[test]
+    fn complete_is_idempotent() {
+        let mut deposit_import = deposit_import(vec![]);
+        assert_eq!(deposit_import.status(), DepositImportStatus::Processing);
+
+        assert!(deposit_import.complete().did_execute());
+        assert!(deposit_import.complete().was_already_applied());
+        assert_eq!(deposit_import.status(), DepositImportStatus::Completed);
+    }
+}
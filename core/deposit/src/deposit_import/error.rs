@@ -0,0 +1,37 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use super::repo::{
+    DepositImportCreateError, DepositImportFindError, DepositImportModifyError,
+    DepositImportQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum DepositImportError {
+    #[error("DepositImportError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositImportError - Create: {0}")]
+    Create(#[from] DepositImportCreateError),
+    #[error("DepositImportError - Modify: {0}")]
+    Modify(#[from] DepositImportModifyError),
+    #[error("DepositImportError - Find: {0}")]
+    Find(#[from] DepositImportFindError),
+    #[error("DepositImportError - Query: {0}")]
+    Query(#[from] DepositImportQueryError),
+    #[error("DepositImportError - InvalidFile: {0}")]
+    InvalidFile(String),
+}
+
+impl ErrorSeverity for DepositImportError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::InvalidFile(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use job::*;
+use obix::out::OutboxEventMarker;
+use public_id::PublicIds;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::DepositAccountRepo,
+    deposit::{DepositRepo, NewDeposit},
+    ledger::DepositLedger,
+    primitives::{
+        CurrencyCode, DEPOSIT_IMPORT, DEPOSIT_REF_TARGET, DepositAccountStatus, DepositChannel,
+        DepositId, DepositImportId,
+    },
+    public::CoreDepositEvent,
+};
+
+use super::{entity::*, repo::DepositImportRepo};
+
+pub const PROCESS_DEPOSIT_IMPORT_COMMAND: JobType =
+    JobType::new("command.deposit.process-deposit-import");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessDepositImportConfig {
+    pub deposit_import_id: DepositImportId,
+}
+
+pub struct ProcessDepositImportJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    deposit_imports: DepositImportRepo<E>,
+    deposit_accounts: DepositAccountRepo<E>,
+    deposits: DepositRepo<E>,
+    ledger: DepositLedger,
+    public_ids: PublicIds,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> ProcessDepositImportJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        deposit_imports: &DepositImportRepo<E>,
+        deposit_accounts: &DepositAccountRepo<E>,
+        deposits: &DepositRepo<E>,
+        ledger: &DepositLedger,
+        public_ids: &PublicIds,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            deposit_imports: deposit_imports.clone(),
+            deposit_accounts: deposit_accounts.clone(),
+            deposits: deposits.clone(),
+            ledger: ledger.clone(),
+            public_ids: public_ids.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for ProcessDepositImportJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Config = ProcessDepositImportConfig;
+
+    fn job_type(&self) -> JobType {
+        PROCESS_DEPOSIT_IMPORT_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ProcessDepositImportJobRunner::<Perms, E> {
+            config: job.config()?,
+            deposit_imports: self.deposit_imports.clone(),
+            deposit_accounts: self.deposit_accounts.clone(),
+            deposits: self.deposits.clone(),
+            ledger: self.ledger.clone(),
+            public_ids: self.public_ids.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct ProcessDepositImportJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: ProcessDepositImportConfig,
+    deposit_imports: DepositImportRepo<E>,
+    deposit_accounts: DepositAccountRepo<E>,
+    deposits: DepositRepo<E>,
+    ledger: DepositLedger,
+    public_ids: PublicIds,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> ProcessDepositImportJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    /// Posts a single row unless a deposit with the same reference was already recorded,
+    /// either by an earlier attempt of this job or through any other channel.
+    /// A recorded deposit only counts as the same one when account and amount match.
+    async fn post_row_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        row: &DepositImportRow,
+    ) -> Result<DepositImportRowOutcome, Box<dyn std::error::Error>> {
+        if let Some(deposit) = self
+            .deposits
+            .maybe_find_by_reference_in_op(&mut *op, row.reference.clone())
+            .await?
+        {
+            if deposit.deposit_account_id != row.deposit_account_id
+                || deposit.money() != row.amount.into()
+            {
+                return Ok(DepositImportRowOutcome::Failed {
+                    reason: format!(
+                        "reference {} is already used by deposit {} for a different account or amount",
+                        row.reference, deposit.id
+                    ),
+                });
+            }
+            return Ok(DepositImportRowOutcome::AlreadyRecorded {
+                deposit_id: deposit.id,
+            });
+        }
+
+        let account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, row.deposit_account_id)
+            .await?;
+        if account.status != DepositAccountStatus::Active {
+            return Ok(DepositImportRowOutcome::Failed {
+                reason: format!("deposit account is {}", account.status),
+            });
+        }
+        if account.currency != CurrencyCode::USD {
+            return Ok(DepositImportRowOutcome::Failed {
+                reason: format!("deposit account currency is {}", account.currency),
+            });
+        }
+
+        self.audit
+            .record_system_entry_in_op(
+                &mut *op,
+                DEPOSIT_IMPORT,
+                CoreDepositObject::all_deposits(),
+                CoreDepositAction::DEPOSIT_CREATE,
+            )
+            .await?;
+
+        let deposit_id = DepositId::new();
+        let public_id = self
+            .public_ids
+            .create_in_op(&mut *op, DEPOSIT_REF_TARGET, deposit_id)
+            .await?;
+        let new_deposit = NewDeposit::builder()
+            .id(deposit_id)
+            .ledger_transaction_id(deposit_id)
+            .deposit_account_id(row.deposit_account_id)
+            .amount(row.amount)
+            .channel(DepositChannel::BankTransfer)
+            .public_id(public_id.id)
+            .reference(Some(row.reference.clone()))
+            .build()?;
+        self.deposits.create_in_op(&mut *op, new_deposit).await?;
+        self.ledger
+            .record_deposit_in_op(
+                &mut *op,
+                deposit_id,
+                row.amount.into(),
+                row.deposit_account_id,
+                row.value_date,
+                &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(DEPOSIT_IMPORT),
+            )
+            .await?;
+
+        Ok(DepositImportRowOutcome::Posted { deposit_id })
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ProcessDepositImportJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(name = "deposit.process_deposit_import.process_command", skip_all)]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut deposit_import = self
+            .deposit_imports
+            .find_by_id(self.config.deposit_import_id)
+            .await?;
+
+        // Every row is committed on its own so that progress is visible while the
+        // import runs and a retried job resumes after the last posted row.
+        for row in deposit_import.pending_rows() {
+            let mut op = self.deposit_imports.begin_op().await?;
+            let outcome = self.post_row_in_op(&mut op, &row).await?;
+            if deposit_import
+                .record_row_outcome(row.line, outcome)
+                .did_execute()
+            {
+                self.deposit_imports
+                    .update_in_op(&mut op, &mut deposit_import)
+                    .await?;
+            }
+            op.commit().await?;
+        }
+
+        if deposit_import.complete().did_execute() {
+            let mut op = self.deposit_imports.begin_op().await?;
+            self.audit
+                .record_system_entry_in_op(
+                    &mut op,
+                    DEPOSIT_IMPORT,
+                    CoreDepositObject::deposit_import(deposit_import.id),
+                    CoreDepositAction::DEPOSIT_IMPORT_PROCESS,
+                )
+                .await?;
+            self.deposit_imports
+                .update_in_op(&mut op, &mut deposit_import)
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
+
+pub type ProcessDepositImportJobSpawner = JobSpawner<ProcessDepositImportConfig>;
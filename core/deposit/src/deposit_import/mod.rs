@@ -0,0 +1,21 @@
+mod entity;
+pub mod error;
+mod job;
+mod parse;
+mod repo;
+
+use document_storage::DocumentType;
+
+#[cfg(feature = "json-schema")]
+pub use entity::DepositImportEvent;
+pub(super) use entity::*;
+pub use entity::{
+    DepositImport, DepositImportRow, DepositImportRowError, DepositImportRowOutcome,
+    DepositImportStatus,
+};
+pub(super) use job::*;
+pub(super) use parse::*;
+pub use repo::deposit_import_cursor::DepositImportsByCreatedAtCursor;
+pub(super) use repo::*;
+
+pub(crate) const DEPOSIT_IMPORT_DOCUMENT_TYPE: DocumentType = DocumentType::new("deposit_import");
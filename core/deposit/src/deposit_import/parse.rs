@@ -0,0 +1,185 @@
+use chrono::NaiveDate;
+use csv::{ReaderBuilder, Trim};
+use rust_decimal::Decimal;
+
+use std::{collections::HashSet, str::FromStr};
+
+use crate::primitives::UsdCents;
+
+use super::{entity::DepositImportRowError, error::DepositImportError};
+
+const COLUMNS: [&str; 4] = ["account_public_id", "amount", "reference", "value_date"];
+
+/// A row that is well formed but whose deposit account has not been resolved yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedDepositImportRow {
+    pub line: u64,
+    pub account_public_id: String,
+    pub amount: UsdCents,
+    pub reference: String,
+    pub value_date: NaiveDate,
+}
+
+/// Parses a deposit import CSV with a header row of `account_public_id,amount,reference,value_date`.
+/// Amounts are in USD and value dates in `YYYY-MM-DD`. Rows that fail validation are reported
+/// by line number instead of failing the whole file.
+pub(crate) fn parse_deposit_import_csv(
+    content: &[u8],
+    today: NaiveDate,
+) -> Result<(Vec<ParsedDepositImportRow>, Vec<DepositImportRowError>), DepositImportError> {
+    let mut reader = ReaderBuilder::new()
+        .flexible(true)
+        .trim(Trim::All)
+        .from_reader(content);
+
+    let headers = reader
+        .headers()
+        .map_err(|e| DepositImportError::InvalidFile(e.to_string()))?;
+    if headers.iter().map(str::to_lowercase).ne(COLUMNS) {
+        return Err(DepositImportError::InvalidFile(format!(
+            "expected header '{}'",
+            COLUMNS.join(",")
+        )));
+    }
+
+    let mut rows = Vec::new();
+    let mut row_errors = Vec::new();
+    let mut references = HashSet::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| DepositImportError::InvalidFile(e.to_string()))?;
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        match parse_row(&record, line, today) {
+            Ok(row) if !references.insert(row.reference.clone()) => {
+                row_errors.push(DepositImportRowError {
+                    line,
+                    message: format!("duplicate reference '{}' in file", row.reference),
+                })
+            }
+            Ok(row) => rows.push(row),
+            Err(message) => row_errors.push(DepositImportRowError { line, message }),
+        }
+    }
+
+    Ok((rows, row_errors))
+}
+
+fn parse_row(
+    record: &csv::StringRecord,
+    line: u64,
+    today: NaiveDate,
+) -> Result<ParsedDepositImportRow, String> {
+    if record.len() != COLUMNS.len() {
+        return Err(format!(
+            "expected {} columns, found {}",
+            COLUMNS.len(),
+            record.len()
+        ));
+    }
+
+    let account_public_id = record[0].to_string();
+    if account_public_id.is_empty() {
+        return Err("account public id is missing".to_string());
+    }
+
+    let amount =
+        Decimal::from_str(&record[1]).map_err(|_| format!("invalid amount '{}'", &record[1]))?;
+    if amount <= Decimal::ZERO {
+        return Err("amount must be positive".to_string());
+    }
+    let amount = UsdCents::try_from_usd(amount)
+        .map_err(|_| format!("amount '{}' has more than two decimals", &record[1]))?;
+
+    let reference = record[2].to_string();
+    if reference.is_empty() {
+        return Err("reference is missing".to_string());
+    }
+
+    let value_date = NaiveDate::parse_from_str(&record[3], "%Y-%m-%d")
+        .map_err(|_| format!("invalid value date '{}'", &record[3]))?;
+    if value_date > today {
+        return Err(format!("value date {value_date} is in the future"));
+    }
+
+    Ok(ParsedDepositImportRow {
+        line,
+        account_public_id,
+        amount,
+        reference,
+        value_date,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 31).unwrap()
+    }
+
+    #[test]
+    fn parses_valid_rows() {
+        let csv = "account_public_id,amount,reference,value_date\n\
+                   1001,1500.25,wire-1,2026-03-30\n\
+                   1002, 20 ,wire-2,2026-03-31\n";
+
+        let (rows, errors) = parse_deposit_import_csv(csv.as_bytes(), today()).unwrap();
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            rows,
+            vec![
+                ParsedDepositImportRow {
+                    line: 2,
+                    account_public_id: "1001".to_string(),
+                    amount: UsdCents::from(150_025),
+                    reference: "wire-1".to_string(),
+                    value_date: NaiveDate::from_ymd_opt(2026, 3, 30).unwrap(),
+                },
+                ParsedDepositImportRow {
+                    line: 3,
+                    account_public_id: "1002".to_string(),
+                    amount: UsdCents::from(2_000),
+                    reference: "wire-2".to_string(),
+                    value_date: today(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_rows_by_line() {
+        let csv = "account_public_id,amount,reference,value_date\n\
+                   1001,abc,wire-1,2026-03-30\n\
+                   1001,-5,wire-2,2026-03-30\n\
+                   1001,1.001,wire-3,2026-03-30\n\
+                   1001,10,,2026-03-30\n\
+                   1001,10,wire-5,30/03/2026\n\
+                   1001,10,wire-6,2026-04-01\n\
+                   ,10,wire-7,2026-03-30\n\
+                   1001,10,wire-8\n\
+                   1001,10,wire-9,2026-03-30\n\
+                   1002,10,wire-9,2026-03-30\n";
+
+        let (rows, errors) = parse_deposit_import_csv(csv.as_bytes(), today()).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].line, 10);
+        assert_eq!(
+            errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![2, 3, 4, 5, 6, 7, 8, 9, 11]
+        );
+    }
+
+    #[test]
+    fn rejects_file_with_unexpected_header() {
+        let csv = "account,amount\n1001,10\n";
+
+        let res = parse_deposit_import_csv(csv.as_bytes(), today());
+
+        assert!(matches!(res, Err(DepositImportError::InvalidFile(_))));
+    }
+}
@@ -0,0 +1,100 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+use obix::out::OutboxEventMarker;
+
+use crate::{primitives::DepositImportId, public::CoreDepositEvent, publisher::DepositPublisher};
+
+use super::entity::*;
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "DepositImport",
+    columns(status(ty = "DepositImportStatus", list_for, update(accessor = "status()"))),
+    tbl_prefix = "core",
+    post_persist_hook = "publish_in_op"
+)]
+pub struct DepositImportRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    publisher: DepositPublisher<E>,
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl<E> Clone for DepositImportRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+            pool: self.pool.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<E> DepositImportRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &DepositPublisher<E>, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+            clock,
+        }
+    }
+
+    async fn publish_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &DepositImport,
+        new_events: es_entity::LastPersisted<'_, DepositImportEvent>,
+    ) -> Result<(), sqlx::Error> {
+        self.publisher
+            .publish_deposit_import_in_op(op, entity, new_events)
+            .await
+    }
+}
+
+mod deposit_import_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::DepositImportStatus;
+
+    impl Type<Postgres> for DepositImportStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for DepositImportStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for DepositImportStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for DepositImportStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -16,6 +16,26 @@ pub enum CoreDepositError {
     DepositError(#[from] crate::deposit::error::DepositError),
     #[error("CoreDepositError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("CoreDepositError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
+    #[error("CoreDepositError - FxConversionError: {0}")]
+    FxConversionError(#[from] crate::fx_conversion::error::FxConversionError),
+    #[error("CoreDepositError - HoldError: {0}")]
+    HoldError(#[from] crate::hold::error::HoldError),
+    #[error("CoreDepositError - BeneficiaryError: {0}")]
+    BeneficiaryError(#[from] crate::beneficiary::error::BeneficiaryError),
+    #[error("CoreDepositError - PaymentFileError: {0}")]
+    PaymentFileError(#[from] crate::payment_file::error::PaymentFileError),
+    #[error("CoreDepositError - DepositImportError: {0}")]
+    DepositImportError(#[from] crate::deposit_import::error::DepositImportError),
+    #[error("CoreDepositError - AccountClosureError: {0}")]
+    AccountClosureError(#[from] crate::account_closure::error::AccountClosureError),
+    #[error("CoreDepositError - DepositAccountStatementError: {0}")]
+    DepositAccountStatementError(
+        #[from] crate::account_statement::error::DepositAccountStatementError,
+    ),
+    #[error("CoreDepositError - DepositProductError: {0}")]
+    DepositProductError(#[from] crate::product::error::DepositProductError),
     #[error("CoreDepositError - DepositLedgerError: {0}")]
     DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
     #[error("CoreDepositError - GovernanceError: {0}")]
@@ -42,14 +62,34 @@ pub enum CoreDepositError {
     DepositAccountFrozen,
     #[error("CoreDepositError - DepositAccountClosed")]
     DepositAccountClosed,
+    #[error("CoreDepositError - DepositAccountEscheated")]
+    DepositAccountEscheated,
+    #[error("CoreDepositError - InsufficientAvailableBalance")]
+    InsufficientAvailableBalance,
+    #[error("CoreDepositError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(crate::primitives::CurrencyCode),
+    #[error("CoreDepositError - ConversionError: {0}")]
+    ConversionError(#[from] money::ConversionError),
     #[error("CoreDepositError - WithdrawalBuilderError: {0}")]
     WithdrawalBuilderError(#[from] super::NewWithdrawalBuilderError),
     #[error("CoreDepositError - DepositBuilderError: {0}")]
     DepositBuilderError(#[from] super::NewDepositBuilderError),
+    #[error("CoreDepositError - TransferBuilderError: {0}")]
+    TransferBuilderError(#[from] super::NewTransferBuilderError),
+    #[error("CoreDepositError - FxConversionBuilderError: {0}")]
+    FxConversionBuilderError(#[from] super::NewFxConversionBuilderError),
+    #[error("CoreDepositError - HoldBuilderError: {0}")]
+    HoldBuilderError(#[from] super::NewHoldBuilderError),
+    #[error("CoreDepositError - BeneficiaryBuilderError: {0}")]
+    BeneficiaryBuilderError(#[from] super::NewBeneficiaryBuilderError),
+    #[error("CoreDepositError - DepositProductBuilderError: {0}")]
+    DepositProductBuilderError(#[from] super::NewDepositProductBuilderError),
     #[error("CoreDepositError - PublicIdError: {0}")]
     PublicIdError(#[from] public_id::PublicIdError),
     #[error("CoreDepositError - DomainConfigError: {0}")]
     DomainConfigError(#[from] domain_config::DomainConfigError),
+    #[error("CoreDepositError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
 }
 
 // Two-hop From impls: repo typed errors -> sub-module error -> CoreDepositError
@@ -126,6 +166,198 @@ impl From<crate::withdrawal::WithdrawalQueryError> for CoreDepositError {
     }
 }
 
+impl From<crate::transfer::TransferCreateError> for CoreDepositError {
+    fn from(e: crate::transfer::TransferCreateError) -> Self {
+        Self::TransferError(e.into())
+    }
+}
+
+impl From<crate::transfer::TransferFindError> for CoreDepositError {
+    fn from(e: crate::transfer::TransferFindError) -> Self {
+        Self::TransferError(e.into())
+    }
+}
+
+impl From<crate::transfer::TransferModifyError> for CoreDepositError {
+    fn from(e: crate::transfer::TransferModifyError) -> Self {
+        Self::TransferError(e.into())
+    }
+}
+
+impl From<crate::transfer::TransferQueryError> for CoreDepositError {
+    fn from(e: crate::transfer::TransferQueryError) -> Self {
+        Self::TransferError(e.into())
+    }
+}
+
+impl From<crate::fx_conversion::FxConversionCreateError> for CoreDepositError {
+    fn from(e: crate::fx_conversion::FxConversionCreateError) -> Self {
+        Self::FxConversionError(e.into())
+    }
+}
+
+impl From<crate::fx_conversion::FxConversionFindError> for CoreDepositError {
+    fn from(e: crate::fx_conversion::FxConversionFindError) -> Self {
+        Self::FxConversionError(e.into())
+    }
+}
+
+impl From<crate::fx_conversion::FxConversionModifyError> for CoreDepositError {
+    fn from(e: crate::fx_conversion::FxConversionModifyError) -> Self {
+        Self::FxConversionError(e.into())
+    }
+}
+
+impl From<crate::fx_conversion::FxConversionQueryError> for CoreDepositError {
+    fn from(e: crate::fx_conversion::FxConversionQueryError) -> Self {
+        Self::FxConversionError(e.into())
+    }
+}
+
+impl From<crate::hold::HoldCreateError> for CoreDepositError {
+    fn from(e: crate::hold::HoldCreateError) -> Self {
+        Self::HoldError(e.into())
+    }
+}
+
+impl From<crate::hold::HoldFindError> for CoreDepositError {
+    fn from(e: crate::hold::HoldFindError) -> Self {
+        Self::HoldError(e.into())
+    }
+}
+
+impl From<crate::hold::HoldModifyError> for CoreDepositError {
+    fn from(e: crate::hold::HoldModifyError) -> Self {
+        Self::HoldError(e.into())
+    }
+}
+
+impl From<crate::hold::HoldQueryError> for CoreDepositError {
+    fn from(e: crate::hold::HoldQueryError) -> Self {
+        Self::HoldError(e.into())
+    }
+}
+
+impl From<crate::beneficiary::BeneficiaryCreateError> for CoreDepositError {
+    fn from(e: crate::beneficiary::BeneficiaryCreateError) -> Self {
+        Self::BeneficiaryError(e.into())
+    }
+}
+
+impl From<crate::beneficiary::BeneficiaryFindError> for CoreDepositError {
+    fn from(e: crate::beneficiary::BeneficiaryFindError) -> Self {
+        Self::BeneficiaryError(e.into())
+    }
+}
+
+impl From<crate::beneficiary::BeneficiaryModifyError> for CoreDepositError {
+    fn from(e: crate::beneficiary::BeneficiaryModifyError) -> Self {
+        Self::BeneficiaryError(e.into())
+    }
+}
+
+impl From<crate::beneficiary::BeneficiaryQueryError> for CoreDepositError {
+    fn from(e: crate::beneficiary::BeneficiaryQueryError) -> Self {
+        Self::BeneficiaryError(e.into())
+    }
+}
+
+impl From<crate::payment_file::PaymentFileCreateError> for CoreDepositError {
+    fn from(e: crate::payment_file::PaymentFileCreateError) -> Self {
+        Self::PaymentFileError(e.into())
+    }
+}
+
+impl From<crate::payment_file::PaymentFileFindError> for CoreDepositError {
+    fn from(e: crate::payment_file::PaymentFileFindError) -> Self {
+        Self::PaymentFileError(e.into())
+    }
+}
+
+impl From<crate::payment_file::PaymentFileModifyError> for CoreDepositError {
+    fn from(e: crate::payment_file::PaymentFileModifyError) -> Self {
+        Self::PaymentFileError(e.into())
+    }
+}
+
+impl From<crate::payment_file::PaymentFileQueryError> for CoreDepositError {
+    fn from(e: crate::payment_file::PaymentFileQueryError) -> Self {
+        Self::PaymentFileError(e.into())
+    }
+}
+
+impl From<crate::deposit_import::DepositImportCreateError> for CoreDepositError {
+    fn from(e: crate::deposit_import::DepositImportCreateError) -> Self {
+        Self::DepositImportError(e.into())
+    }
+}
+
+impl From<crate::deposit_import::DepositImportFindError> for CoreDepositError {
+    fn from(e: crate::deposit_import::DepositImportFindError) -> Self {
+        Self::DepositImportError(e.into())
+    }
+}
+
+impl From<crate::deposit_import::DepositImportModifyError> for CoreDepositError {
+    fn from(e: crate::deposit_import::DepositImportModifyError) -> Self {
+        Self::DepositImportError(e.into())
+    }
+}
+
+impl From<crate::deposit_import::DepositImportQueryError> for CoreDepositError {
+    fn from(e: crate::deposit_import::DepositImportQueryError) -> Self {
+        Self::DepositImportError(e.into())
+    }
+}
+
+impl From<crate::account_closure::AccountClosureCreateError> for CoreDepositError {
+    fn from(e: crate::account_closure::AccountClosureCreateError) -> Self {
+        Self::AccountClosureError(e.into())
+    }
+}
+
+impl From<crate::account_closure::AccountClosureFindError> for CoreDepositError {
+    fn from(e: crate::account_closure::AccountClosureFindError) -> Self {
+        Self::AccountClosureError(e.into())
+    }
+}
+
+impl From<crate::account_closure::AccountClosureModifyError> for CoreDepositError {
+    fn from(e: crate::account_closure::AccountClosureModifyError) -> Self {
+        Self::AccountClosureError(e.into())
+    }
+}
+
+impl From<crate::account_closure::AccountClosureQueryError> for CoreDepositError {
+    fn from(e: crate::account_closure::AccountClosureQueryError) -> Self {
+        Self::AccountClosureError(e.into())
+    }
+}
+
+impl From<crate::product::DepositProductCreateError> for CoreDepositError {
+    fn from(e: crate::product::DepositProductCreateError) -> Self {
+        Self::DepositProductError(e.into())
+    }
+}
+
+impl From<crate::product::DepositProductFindError> for CoreDepositError {
+    fn from(e: crate::product::DepositProductFindError) -> Self {
+        Self::DepositProductError(e.into())
+    }
+}
+
+impl From<crate::product::DepositProductModifyError> for CoreDepositError {
+    fn from(e: crate::product::DepositProductModifyError) -> Self {
+        Self::DepositProductError(e.into())
+    }
+}
+
+impl From<crate::product::DepositProductQueryError> for CoreDepositError {
+    fn from(e: crate::product::DepositProductQueryError) -> Self {
+        Self::DepositProductError(e.into())
+    }
+}
+
 impl CoreDepositError {
     pub fn is_account_already_exists(&self) -> bool {
         matches!(
@@ -135,6 +367,15 @@ impl CoreDepositError {
             ))
         )
     }
+
+    pub fn is_withdrawal_limit_exceeded(&self) -> bool {
+        matches!(
+            self,
+            Self::WithdrawalError(
+                crate::withdrawal::error::WithdrawalError::WithdrawalLimitExceeded { .. }
+            )
+        )
+    }
 }
 
 impl ErrorSeverity for CoreDepositError {
@@ -146,6 +387,15 @@ impl ErrorSeverity for CoreDepositError {
             Self::DepositAccountError(e) => e.severity(),
             Self::DepositError(e) => e.severity(),
             Self::WithdrawalError(e) => e.severity(),
+            Self::TransferError(e) => e.severity(),
+            Self::FxConversionError(e) => e.severity(),
+            Self::HoldError(e) => e.severity(),
+            Self::BeneficiaryError(e) => e.severity(),
+            Self::PaymentFileError(e) => e.severity(),
+            Self::DepositImportError(e) => e.severity(),
+            Self::AccountClosureError(e) => e.severity(),
+            Self::DepositAccountStatementError(e) => e.severity(),
+            Self::DepositProductError(e) => e.severity(),
             Self::DepositLedgerError(e) => e.severity(),
             Self::GovernanceError(e) => e.severity(),
             Self::CustomerError(e) => e.severity(),
@@ -159,10 +409,20 @@ impl ErrorSeverity for CoreDepositError {
             Self::DepositConfigAlreadyExists => Level::WARN,
             Self::DepositAccountFrozen => Level::WARN,
             Self::DepositAccountClosed => Level::WARN,
+            Self::DepositAccountEscheated => Level::WARN,
+            Self::InsufficientAvailableBalance => Level::WARN,
+            Self::UnsupportedCurrency(_) => Level::WARN,
+            Self::ConversionError(e) => e.severity(),
             Self::WithdrawalBuilderError(_) => Level::ERROR,
             Self::DepositBuilderError(_) => Level::ERROR,
+            Self::TransferBuilderError(_) => Level::ERROR,
+            Self::FxConversionBuilderError(_) => Level::ERROR,
+            Self::HoldBuilderError(_) => Level::ERROR,
+            Self::BeneficiaryBuilderError(_) => Level::ERROR,
+            Self::DepositProductBuilderError(_) => Level::ERROR,
             Self::PublicIdError(e) => e.severity(),
             Self::DomainConfigError(e) => e.severity(),
+            Self::DocumentStorageError(e) => e.severity(),
         }
     }
 }
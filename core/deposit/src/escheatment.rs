@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+
+use crate::{
+    account::DepositAccount,
+    primitives::{DepositAccountHolderId, DepositAccountId, PublicId, UsdCents},
+};
+
+/// A line of the unclaimed property remittance report.
+pub struct EscheatmentRemittanceEntry {
+    pub deposit_account_id: DepositAccountId,
+    pub account_holder_id: DepositAccountHolderId,
+    pub public_id: PublicId,
+    pub escheated_on: NaiveDate,
+    pub amount: UsdCents,
+}
+
+impl EscheatmentRemittanceEntry {
+    pub(crate) fn for_period(
+        account: &DepositAccount,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> impl Iterator<Item = Self> + '_ {
+        account
+            .escheatments()
+            .filter(move |(escheated_on, _)| *escheated_on >= from && *escheated_on <= until)
+            .map(|(escheated_on, amount)| Self {
+                deposit_account_id: account.id,
+                account_holder_id: account.account_holder_id,
+                public_id: account.public_id.clone(),
+                escheated_on,
+                amount,
+            })
+    }
+}
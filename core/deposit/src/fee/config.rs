@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use domain_config::define_internal_config;
+
+use super::value::DepositFeeSchedule;
+use crate::primitives::DepositProductId;
+
+define_internal_config! {
+    #[derive(Serialize, Deserialize, Clone, Debug, Default)]
+    pub(crate) struct DepositFeeSchedules {
+        pub(crate) schedules: HashMap<DepositProductId, DepositFeeSchedule>,
+    }
+
+    spec {
+        key: "deposit-fee-schedules";
+        default: || Some(DepositFeeSchedules::default());
+    }
+}
+
+impl DepositFeeSchedules {
+    pub(crate) fn for_product(&self, deposit_product_id: DepositProductId) -> DepositFeeSchedule {
+        self.schedules
+            .get(&deposit_product_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,5 @@
+mod config;
+mod value;
+
+pub(crate) use config::DepositFeeSchedules;
+pub use value::*;
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::primitives::UsdCents;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DepositFeeType {
+    MonthlyMaintenance,
+    Withdrawal,
+    DormantAccount,
+}
+
+impl DepositFeeType {
+    pub fn is_periodic(&self) -> bool {
+        matches!(
+            self,
+            DepositFeeType::MonthlyMaintenance | DepositFeeType::DormantAccount
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositFeeSchedule {
+    #[serde(default)]
+    pub monthly_maintenance_fee: Option<UsdCents>,
+    #[serde(default)]
+    pub withdrawal_fee: Option<UsdCents>,
+    #[serde(default)]
+    pub dormant_account_fee: Option<UsdCents>,
+}
+
+impl DepositFeeSchedule {
+    pub fn fee_for(&self, fee_type: DepositFeeType) -> Option<UsdCents> {
+        let fee = match fee_type {
+            DepositFeeType::MonthlyMaintenance => self.monthly_maintenance_fee,
+            DepositFeeType::Withdrawal => self.withdrawal_fee,
+            DepositFeeType::DormantAccount => self.dormant_account_fee,
+        };
+        fee.filter(|amount| !amount.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_fees_are_not_charged() {
+        let schedule = DepositFeeSchedule {
+            monthly_maintenance_fee: Some(UsdCents::from(500)),
+            withdrawal_fee: Some(UsdCents::ZERO),
+            dormant_account_fee: None,
+        };
+
+        assert_eq!(
+            schedule.fee_for(DepositFeeType::MonthlyMaintenance),
+            Some(UsdCents::from(500))
+        );
+        assert_eq!(schedule.fee_for(DepositFeeType::Withdrawal), None);
+        assert_eq!(schedule.fee_for(DepositFeeType::DormantAccount), None);
+    }
+}
@@ -1,11 +1,13 @@
 use audit::AuditSvc;
 use authz::PermissionCheck;
+use document_storage::GeneratedDocumentDownloadLink;
 use obix::out::OutboxEventMarker;
 use tracing::instrument;
 use tracing_macros::record_error_severity;
 
 use crate::{
     account::*,
+    account_statement::*,
     deposit::*,
     deposit_account_balance::*,
     deposit_account_cursor::DepositAccountsByCreatedAtCursor,
@@ -14,6 +16,7 @@ use crate::{
     ledger::*,
     primitives::*,
     public::*,
+    transfer::*,
     withdrawal::*,
 };
 
@@ -27,6 +30,8 @@ where
     accounts: &'a DepositAccountRepo<E>,
     deposits: &'a DepositRepo<E>,
     withdrawals: &'a WithdrawalRepo<E>,
+    transfers: &'a TransferRepo<E>,
+    account_statements: &'a DepositAccountStatements<E>,
     ledger: &'a DepositLedger,
     authz: &'a Perms,
 }
@@ -46,6 +51,8 @@ where
         accounts: &'a DepositAccountRepo<E>,
         deposits: &'a DepositRepo<E>,
         withdrawals: &'a WithdrawalRepo<E>,
+        transfers: &'a TransferRepo<E>,
+        account_statements: &'a DepositAccountStatements<E>,
         ledger: &'a DepositLedger,
         authz: &'a Perms,
     ) -> Self {
@@ -55,6 +62,8 @@ where
             accounts,
             deposits,
             withdrawals,
+            transfers,
+            account_statements,
             ledger,
             authz,
         }
@@ -87,6 +96,48 @@ where
             .await?)
     }
 
+    /// Joint accounts the subject holds together with the accounts' primary holders.
+    #[record_error_severity]
+    #[instrument(name = "deposit.for_subject.list_joint_accounts", skip(self))]
+    pub async fn list_joint_accounts(&self) -> Result<Vec<DepositAccount>, CoreDepositError> {
+        self.authz
+            .audit()
+            .record_entry(
+                self.sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_LIST,
+                true,
+            )
+            .await?;
+        let ids = self
+            .accounts
+            .list_joint_account_ids_for_holder(self.account_holder_id)
+            .await?;
+        let mut accounts = self.accounts.find_all::<DepositAccount>(&ids).await?;
+        Ok(ids
+            .into_iter()
+            .filter_map(|id| accounts.remove(&id))
+            .collect())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.for_subject.find_account_by_id", skip(self))]
+    pub async fn find_account_by_id(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::deposit_account(account_id),
+            CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+        )
+        .await?;
+
+        Ok(self.accounts.find_by_id(account_id).await?)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.for_subject.account_balance", skip(self))]
     pub async fn account_balance(
@@ -134,6 +185,51 @@ where
         Ok(history)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.for_subject.list_statements_for_account", skip(self))]
+    pub async fn list_statements_for_account(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountStatement>, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::deposit_account(account_id),
+            CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+        )
+        .await?;
+
+        Ok(self.account_statements.list_for_account(account_id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit.for_subject.generate_statement_download_link",
+        skip(self)
+    )]
+    pub async fn generate_statement_download_link(
+        &self,
+        statement_id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let statement = self
+            .account_statements
+            .find_by_id(statement_id.into())
+            .await?;
+
+        self.ensure_account_access(
+            statement.deposit_account_id,
+            CoreDepositObject::deposit_account(statement.deposit_account_id),
+            CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+        )
+        .await?;
+
+        Ok(self
+            .account_statements
+            .generate_download_link(&statement)
+            .await?)
+    }
+
     pub async fn list_deposits_for_account(
         &self,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
@@ -236,6 +332,66 @@ where
         Ok(withdrawal)
     }
 
+    pub async fn list_transfers_for_account(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Transfer>, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::all_transfers(),
+            CoreDepositAction::TRANSFER_LIST,
+        )
+        .await?;
+
+        let mut transfers = self
+            .transfers
+            .list_for_from_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities;
+        transfers.extend(
+            self.transfers
+                .list_for_to_account_id_by_created_at(
+                    account_id,
+                    Default::default(),
+                    es_entity::ListDirection::Descending,
+                )
+                .await?
+                .entities,
+        );
+        transfers.sort_by_key(|transfer| std::cmp::Reverse(transfer.created_at()));
+
+        Ok(transfers)
+    }
+
+    pub async fn find_transfer_by_id(
+        &self,
+        transfer_id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Transfer, CoreDepositError> {
+        let transfer_id = transfer_id.into();
+        let transfer = self.transfers.find_by_id(transfer_id).await?;
+
+        let from_account = self.accounts.find_by_id(transfer.from_account_id).await?;
+        let account_id = if from_account.has_holder(self.account_holder_id) {
+            transfer.from_account_id
+        } else {
+            transfer.to_account_id
+        };
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::transfer(transfer_id),
+            CoreDepositAction::TRANSFER_READ,
+        )
+        .await?;
+
+        Ok(transfer)
+    }
+
     async fn ensure_account_access(
         &self,
         account_id: DepositAccountId,
@@ -244,7 +400,7 @@ where
     ) -> Result<(), CoreDepositError> {
         let account = self.accounts.find_by_id(account_id).await?;
 
-        if account.account_holder_id != self.account_holder_id {
+        if !account.has_holder(self.account_holder_id) {
             self.authz
                 .audit()
                 .record_entry(self.sub, object, action, false)
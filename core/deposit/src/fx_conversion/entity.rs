@@ -0,0 +1,211 @@
+use derive_builder::Builder;
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{CalaTransactionId, CurrencyCode, DepositAccountId, FxConversionId, Money};
+
+use super::error::FxConversionError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "FxConversionId")]
+pub enum FxConversionEvent {
+    Initialized {
+        id: FxConversionId,
+        source_account_id: DepositAccountId,
+        target_account_id: DepositAccountId,
+        source_amount: Money,
+        target_amount: Money,
+        rate: Decimal,
+        reference: String,
+        ledger_tx_id: CalaTransactionId,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct FxConversion {
+    pub id: FxConversionId,
+    pub source_account_id: DepositAccountId,
+    pub target_account_id: DepositAccountId,
+    pub source_amount: Money,
+    pub target_amount: Money,
+    pub rate: Decimal,
+    pub reference: String,
+    pub ledger_tx_id: CalaTransactionId,
+
+    events: EntityEvents<FxConversionEvent>,
+}
+
+impl FxConversion {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for fx conversion")
+    }
+}
+
+impl TryFromEvents<FxConversionEvent> for FxConversion {
+    fn try_from_events(
+        events: EntityEvents<FxConversionEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = FxConversionBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                FxConversionEvent::Initialized {
+                    id,
+                    source_account_id,
+                    target_account_id,
+                    source_amount,
+                    target_amount,
+                    rate,
+                    reference,
+                    ledger_tx_id,
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .source_account_id(*source_account_id)
+                        .target_account_id(*target_account_id)
+                        .source_amount(*source_amount)
+                        .target_amount(*target_amount)
+                        .rate(*rate)
+                        .reference(reference.clone())
+                        .ledger_tx_id(*ledger_tx_id)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+/// Converts `source_amount` into `target_currency` at `rate` (units of target per unit of
+/// source). Fractions below the target currency's smallest unit are dropped.
+pub(crate) fn convert_amount(
+    source_amount: Money,
+    target_currency: CurrencyCode,
+    rate: Decimal,
+) -> Result<Money, FxConversionError> {
+    if rate <= Decimal::ZERO {
+        return Err(FxConversionError::NonPositiveRate(rate));
+    }
+    if source_amount.currency() == target_currency {
+        return Err(FxConversionError::SameCurrency(target_currency));
+    }
+    let target_major = source_amount
+        .to_major()
+        .checked_mul(rate)
+        .ok_or(money::ConversionError::Overflow)?;
+    let target_amount = Money::try_from_major_truncated(target_currency, target_major)?;
+    if source_amount.is_zero() || target_amount.is_zero() {
+        return Err(FxConversionError::ZeroAmount);
+    }
+    Ok(target_amount)
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewFxConversion {
+    #[builder(setter(into))]
+    pub(super) id: FxConversionId,
+    #[builder(setter(into))]
+    pub(super) source_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) target_account_id: DepositAccountId,
+    pub(super) source_amount: Money,
+    pub(super) target_amount: Money,
+    pub(super) rate: Decimal,
+    reference: Option<String>,
+}
+
+impl NewFxConversion {
+    pub fn builder() -> NewFxConversionBuilder {
+        NewFxConversionBuilder::default()
+    }
+
+    fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None | Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewFxConversionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(source), Some(target)) = (self.source_account_id, self.target_account_id)
+            && source == target
+        {
+            return Err("Cannot convert into the same deposit account".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<FxConversionEvent> for NewFxConversion {
+    fn into_events(self) -> EntityEvents<FxConversionEvent> {
+        let reference = self.reference();
+        EntityEvents::init(
+            self.id,
+            [FxConversionEvent::Initialized {
+                id: self.id,
+                source_account_id: self.source_account_id,
+                target_account_id: self.target_account_id,
+                source_amount: self.source_amount,
+                target_amount: self.target_amount,
+                rate: self.rate,
+                reference,
+                ledger_tx_id: self.id.into(),
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn converts_and_truncates_to_target_precision() {
+        let source = Money::new(CurrencyCode::EUR, 10_001);
+        let target = convert_amount(source, CurrencyCode::USD, dec!(1.0857)).unwrap();
+        assert_eq!(target, Money::new(CurrencyCode::USD, 10_858));
+
+        let target = convert_amount(source, CurrencyCode::USDT, dec!(1.0857)).unwrap();
+        assert_eq!(target, Money::new(CurrencyCode::USDT, 108_580_857));
+    }
+
+    #[test]
+    fn rejects_invalid_conversions() {
+        let source = Money::new(CurrencyCode::USD, 100);
+        assert!(matches!(
+            convert_amount(source, CurrencyCode::EUR, Decimal::ZERO),
+            Err(FxConversionError::NonPositiveRate(_))
+        ));
+        assert!(matches!(
+            convert_amount(source, CurrencyCode::USD, dec!(1)),
+            Err(FxConversionError::SameCurrency(_))
+        ));
+        assert!(matches!(
+            convert_amount(source, CurrencyCode::EUR, dec!(0.001)),
+            Err(FxConversionError::ZeroAmount)
+        ));
+    }
+
+    #[test]
+    fn rejects_overflowing_rates() {
+        let source = Money::new(CurrencyCode::BTC, u64::MAX);
+        assert!(matches!(
+            convert_amount(source, CurrencyCode::USD, Decimal::MAX),
+            Err(FxConversionError::ConversionError(
+                money::ConversionError::Overflow
+            ))
+        ));
+    }
+}
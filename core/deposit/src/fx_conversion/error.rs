@@ -0,0 +1,50 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::CurrencyCode;
+
+use super::repo::{
+    FxConversionCreateError, FxConversionFindError, FxConversionModifyError, FxConversionQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum FxConversionError {
+    #[error("FxConversionError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("FxConversionError - Create: {0}")]
+    Create(#[from] FxConversionCreateError),
+    #[error("FxConversionError - Modify: {0}")]
+    Modify(#[from] FxConversionModifyError),
+    #[error("FxConversionError - Find: {0}")]
+    Find(#[from] FxConversionFindError),
+    #[error("FxConversionError - Query: {0}")]
+    Query(#[from] FxConversionQueryError),
+    #[error("FxConversionError - ConversionError: {0}")]
+    ConversionError(#[from] money::ConversionError),
+    #[error("FxConversionError - NonPositiveRate: {0}")]
+    NonPositiveRate(rust_decimal::Decimal),
+    #[error("FxConversionError - SameCurrency: {0}")]
+    SameCurrency(CurrencyCode),
+    #[error("FxConversionError - ZeroAmount")]
+    ZeroAmount,
+    #[error("FxConversionError - AccountHolderMismatch")]
+    AccountHolderMismatch,
+}
+
+impl ErrorSeverity for FxConversionError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::ConversionError(e) => e.severity(),
+            Self::NonPositiveRate(_) => Level::WARN,
+            Self::SameCurrency(_) => Level::WARN,
+            Self::ZeroAmount => Level::WARN,
+            Self::AccountHolderMismatch => Level::WARN,
+        }
+    }
+}
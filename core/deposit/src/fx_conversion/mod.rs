@@ -0,0 +1,10 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub use entity::FxConversion;
+#[cfg(feature = "json-schema")]
+pub use entity::FxConversionEvent;
+pub(super) use entity::*;
+pub use repo::fx_conversion_cursor::FxConversionsByCreatedAtCursor;
+pub(super) use repo::*;
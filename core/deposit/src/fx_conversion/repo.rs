@@ -0,0 +1,39 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, FxConversionId};
+
+use super::entity::*;
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "FxConversion",
+    columns(
+        source_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        target_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        )
+    ),
+    tbl_prefix = "core"
+)]
+pub struct FxConversionRepo {
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl FxConversionRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
@@ -9,8 +9,17 @@ pub enum DepositAccountHistoryEntry {
     CancelledWithdrawal(WithdrawalEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    OutgoingTransfer(TransferEntry),
+    IncomingTransfer(TransferEntry),
     Freeze(FreezeEntry),
     Unfreeze(UnfreezeEntry),
+    InterestAccrual(InterestEntry),
+    InterestPosting(InterestEntry),
+    EarlyWithdrawalPenalty(EarlyWithdrawalPenaltyEntry),
+    FeeCharge(FeeEntry),
+    FeeReversal(FeeEntry),
+    Escheatment(EscheatmentEntry),
+    EscheatmentReclaim(EscheatmentEntry),
     Unknown(UnknownEntry),
     Ignored,
 }
@@ -23,9 +32,18 @@ impl DepositAccountHistoryEntry {
             DepositAccountHistoryEntry::CancelledWithdrawal(entry) => Some(entry.recorded_at),
             DepositAccountHistoryEntry::Disbursal(entry) => Some(entry.recorded_at),
             DepositAccountHistoryEntry::Payment(entry) => Some(entry.recorded_at),
+            DepositAccountHistoryEntry::OutgoingTransfer(entry) => Some(entry.recorded_at),
+            DepositAccountHistoryEntry::IncomingTransfer(entry) => Some(entry.recorded_at),
+            DepositAccountHistoryEntry::EscheatmentReclaim(entry) => Some(entry.recorded_at),
             DepositAccountHistoryEntry::Unknown(entry) => Some(entry.recorded_at),
             DepositAccountHistoryEntry::Freeze(_)
             | DepositAccountHistoryEntry::Unfreeze(_)
+            | DepositAccountHistoryEntry::InterestAccrual(_)
+            | DepositAccountHistoryEntry::InterestPosting(_)
+            | DepositAccountHistoryEntry::EarlyWithdrawalPenalty(_)
+            | DepositAccountHistoryEntry::FeeCharge(_)
+            | DepositAccountHistoryEntry::FeeReversal(_)
+            | DepositAccountHistoryEntry::Escheatment(_)
             | DepositAccountHistoryEntry::Ignored => None,
         }
     }
@@ -55,6 +73,12 @@ pub struct PaymentEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct TransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct FreezeEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -69,6 +93,34 @@ pub struct UnfreezeEntry {
     pub amount: UsdCents,
 }
 
+pub struct InterestEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct EarlyWithdrawalPenaltyEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct FeeEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct EscheatmentEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
 pub struct UnknownEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -81,14 +133,26 @@ const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
 const CONFIRM_DISBURSAL: &str = "CONFIRM_DISBURSAL_SETTLED_CR";
 const RECORD_PAYMENT_ALLOCATION: &str = "RECORD_PAYMENT_ALLOCATION_DR";
 const CONFIRM_INITIAL_DISBURSAL: &str = "SINGLE_DISBURSAL_RECEIVABLE_CR";
+const TRANSFER_DR: &str = "TRANSFER_DR";
+const TRANSFER_CR: &str = "TRANSFER_CR";
 const FREEZE_ACCOUNT_DR: &str = "FREEZE_ACCOUNT_DR";
 const FREEZE_ACCOUNT_CR: &str = "FREEZE_ACCOUNT_CR";
 const UNFREEZE_ACCOUNT_DR: &str = "UNFREEZE_ACCOUNT_DR";
 const UNFREEZE_ACCOUNT_CR: &str = "UNFREEZE_ACCOUNT_CR";
+const ACCRUE_DEPOSIT_INTEREST: &str = "ACCRUE_DEPOSIT_INTEREST_CR";
+const POST_DEPOSIT_INTEREST: &str = "POST_DEPOSIT_INTEREST_SETTLED_CR";
+const EARLY_WITHDRAWAL_PENALTY: &str = "EARLY_WITHDRAWAL_PENALTY_DR";
+const CHARGE_DEPOSIT_FEE: &str = "CHARGE_DEPOSIT_FEE_DR";
+const REVERSE_DEPOSIT_FEE: &str = "REVERSE_DEPOSIT_FEE_CR";
+const ESCHEAT_DEPOSIT_BALANCE: &str = "ESCHEAT_DEPOSIT_BALANCE_DR";
+const RECLAIM_ESCHEATED_BALANCE: &str = "RECLAIM_ESCHEATED_BALANCE_CR";
 
 const IGNORE_INITIATE_WITHDRAW_PENDING: &str = "INITIATE_WITHDRAW_PENDING_CR";
 const IGNORE_CONFIRM_WITHDRAWAL_PENDING: &str = "CONFIRM_WITHDRAW_PENDING_DR";
 const IGNORE_CANCEL_WITHDRAW_PENDING: &str = "CANCEL_WITHDRAW_PENDING_DR";
+const IGNORE_PLACE_HOLD: &str = "PLACE_HOLD_DR";
+const IGNORE_RELEASE_HOLD: &str = "RELEASE_HOLD_CR";
+const IGNORE_POST_DEPOSIT_INTEREST_PENDING: &str = "POST_DEPOSIT_INTEREST_PENDING_DR";
 
 impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
     fn from(entry: cala_ledger::entry::Entry) -> Self {
@@ -123,6 +187,16 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            TRANSFER_DR => DepositAccountHistoryEntry::OutgoingTransfer(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
+            TRANSFER_CR => DepositAccountHistoryEntry::IncomingTransfer(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
             FREEZE_ACCOUNT_DR | FREEZE_ACCOUNT_CR => {
                 let amount = UsdCents::try_from_usd(entry.values().units)
                     .expect("freeze amount should convert to cents");
@@ -143,10 +217,83 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                     amount,
                 })
             }
+            ACCRUE_DEPOSIT_INTEREST => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("interest amount should convert to cents");
+                DepositAccountHistoryEntry::InterestAccrual(InterestEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
+            POST_DEPOSIT_INTEREST => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("interest amount should convert to cents");
+                DepositAccountHistoryEntry::InterestPosting(InterestEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
+            EARLY_WITHDRAWAL_PENALTY => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("penalty amount should convert to cents");
+                DepositAccountHistoryEntry::EarlyWithdrawalPenalty(EarlyWithdrawalPenaltyEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
+            CHARGE_DEPOSIT_FEE => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("fee amount should convert to cents");
+                DepositAccountHistoryEntry::FeeCharge(FeeEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
+            REVERSE_DEPOSIT_FEE => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("fee amount should convert to cents");
+                DepositAccountHistoryEntry::FeeReversal(FeeEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
+            ESCHEAT_DEPOSIT_BALANCE => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("escheated amount should convert to cents");
+                DepositAccountHistoryEntry::Escheatment(EscheatmentEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
+            RECLAIM_ESCHEATED_BALANCE => {
+                let amount = UsdCents::try_from_usd(entry.values().units)
+                    .expect("reclaimed amount should convert to cents");
+                DepositAccountHistoryEntry::EscheatmentReclaim(EscheatmentEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount,
+                })
+            }
 
             IGNORE_CONFIRM_WITHDRAWAL_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_INITIATE_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_CANCEL_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_PLACE_HOLD => DepositAccountHistoryEntry::Ignored,
+            IGNORE_RELEASE_HOLD => DepositAccountHistoryEntry::Ignored,
+            IGNORE_POST_DEPOSIT_INTEREST_PENDING => DepositAccountHistoryEntry::Ignored,
 
             _ => DepositAccountHistoryEntry::Unknown(UnknownEntry {
                 tx_id: entry.values().transaction_id,
@@ -186,6 +333,14 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::OutgoingTransfer(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::IncomingTransfer(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Freeze(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -194,6 +349,34 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::InterestAccrual(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::InterestPosting(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::EarlyWithdrawalPenalty(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::FeeCharge(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::FeeReversal(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::Escheatment(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::EscheatmentReclaim(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Unknown(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -0,0 +1,337 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    CalaTransactionId, CurrencyCode, DepositAccountId, HoldId, Money, UsdCents,
+};
+
+use super::error::HoldError;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum HoldStatus {
+    #[default]
+    Active,
+    Released,
+    Expired,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "HoldId")]
+pub enum HoldEvent {
+    Initialized {
+        id: HoldId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        #[serde(default = "default_hold_currency")]
+        currency: CurrencyCode,
+        reason: String,
+        expires_at: DateTime<Utc>,
+        ledger_tx_id: CalaTransactionId,
+        status: HoldStatus,
+    },
+    Extended {
+        expires_at: DateTime<Utc>,
+    },
+    Released {
+        ledger_tx_id: CalaTransactionId,
+        status: HoldStatus,
+    },
+    Expired {
+        ledger_tx_id: CalaTransactionId,
+        status: HoldStatus,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Hold {
+    pub id: HoldId,
+    pub deposit_account_id: DepositAccountId,
+    /// Minor units of `currency`.
+    pub amount: UsdCents,
+    pub currency: CurrencyCode,
+    pub reason: String,
+    pub expires_at: DateTime<Utc>,
+
+    events: EntityEvents<HoldEvent>,
+}
+
+impl Hold {
+    pub fn money(&self) -> Money {
+        Money::new(self.currency, self.amount.into_inner())
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for hold")
+    }
+
+    pub fn status(&self) -> HoldStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                HoldEvent::Initialized { status, .. } => Some(*status),
+                HoldEvent::Released { status, .. } => Some(*status),
+                HoldEvent::Expired { status, .. } => Some(*status),
+                HoldEvent::Extended { .. } => None,
+            })
+            .expect("status should always exist")
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status() == HoldStatus::Active
+    }
+
+    pub fn ledger_tx_ids(&self) -> Vec<CalaTransactionId> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                HoldEvent::Initialized { ledger_tx_id, .. }
+                | HoldEvent::Released { ledger_tx_id, .. }
+                | HoldEvent::Expired { ledger_tx_id, .. } => Some(*ledger_tx_id),
+                HoldEvent::Extended { .. } => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn extend(
+        &mut self,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Idempotent<()>, HoldError> {
+        if !self.is_active() {
+            return Err(HoldError::NotActive(self.id));
+        }
+        if expires_at == self.expires_at {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+        if expires_at < self.expires_at {
+            return Err(HoldError::ExpiryNotExtended(self.id));
+        }
+        self.events.push(HoldEvent::Extended { expires_at });
+        self.expires_at = expires_at;
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn release(&mut self) -> Idempotent<CalaTransactionId> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: HoldEvent::Released { .. } | HoldEvent::Expired { .. }
+        );
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Released {
+            ledger_tx_id,
+            status: HoldStatus::Released,
+        });
+        Idempotent::Executed(ledger_tx_id)
+    }
+
+    pub(crate) fn expire(&mut self, now: DateTime<Utc>) -> Idempotent<CalaTransactionId> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: HoldEvent::Released { .. } | HoldEvent::Expired { .. }
+        );
+        if self.expires_at > now {
+            return Idempotent::AlreadyApplied;
+        }
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Expired {
+            ledger_tx_id,
+            status: HoldStatus::Expired,
+        });
+        Idempotent::Executed(ledger_tx_id)
+    }
+}
+
+impl TryFromEvents<HoldEvent> for Hold {
+    fn try_from_events(events: EntityEvents<HoldEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = HoldBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                HoldEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    amount,
+                    currency,
+                    reason,
+                    expires_at,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .amount(*amount)
+                        .currency(*currency)
+                        .reason(reason.clone())
+                        .expires_at(*expires_at)
+                }
+                HoldEvent::Extended { expires_at } => builder = builder.expires_at(*expires_at),
+                _ => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewHold {
+    #[builder(setter(into))]
+    pub(super) id: HoldId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(default = "CurrencyCode::USD")]
+    pub(super) currency: CurrencyCode,
+    #[builder(setter(into))]
+    pub(super) reason: String,
+    pub(super) expires_at: DateTime<Utc>,
+    #[builder(setter(skip), default)]
+    pub(super) status: HoldStatus,
+}
+
+impl NewHold {
+    pub fn builder() -> NewHoldBuilder {
+        NewHoldBuilder::default()
+    }
+}
+
+impl NewHoldBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(amount) = self.amount
+            && amount.is_zero()
+        {
+            return Err("Hold amount cannot be zero".to_string());
+        }
+        if let Some(reason) = self.reason.as_ref()
+            && reason.trim().is_empty()
+        {
+            return Err("Hold reason cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<HoldEvent> for NewHold {
+    fn into_events(self) -> EntityEvents<HoldEvent> {
+        EntityEvents::init(
+            self.id,
+            [HoldEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                currency: self.currency,
+                reason: self.reason,
+                expires_at: self.expires_at,
+                ledger_tx_id: self.id.into(),
+                status: self.status,
+            }],
+        )
+    }
+}
+
+fn default_hold_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn hold(expires_at: DateTime<Utc>) -> Hold {
+        let new_hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reason("card authorisation")
+            .expires_at(expires_at)
+            .build()
+            .unwrap();
+        Hold::try_from_events(new_hold.into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_when_zero_amount_is_passed() {
+        let res = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ZERO)
+            .reason("card authorisation")
+            .expires_at(Utc::now())
+            .build();
+
+        assert!(matches!(res, Err(NewHoldBuilderError::ValidationError(_))));
+    }
+
+    #[test]
+    fn extend_requires_later_expiry() {
+        let expires_at = Utc::now();
+        let mut hold = hold(expires_at);
+
+        assert!(matches!(
+            hold.extend(expires_at - Duration::days(1)),
+            Err(HoldError::ExpiryNotExtended(_))
+        ));
+        assert!(hold.extend(expires_at).unwrap().was_already_applied());
+        assert!(
+            hold.extend(expires_at + Duration::days(1))
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(hold.expires_at, expires_at + Duration::days(1));
+    }
+
+    #[test]
+    fn release_is_idempotent() {
+        let mut hold = hold(Utc::now());
+
+        assert!(hold.release().did_execute());
+        assert_eq!(hold.status(), HoldStatus::Released);
+        assert!(hold.release().was_already_applied());
+        assert!(matches!(
+            hold.extend(Utc::now() + Duration::days(1)),
+            Err(HoldError::NotActive(_))
+        ));
+    }
+
+    #[test]
+    fn expires_only_after_expiry() {
+        let expires_at = Utc::now();
+        let mut hold = hold(expires_at);
+
+        assert!(
+            hold.expire(expires_at - Duration::hours(1))
+                .was_already_applied()
+        );
+        assert!(hold.is_active());
+        assert!(hold.expire(expires_at).did_execute());
+        assert_eq!(hold.status(), HoldStatus::Expired);
+        assert!(hold.release().was_already_applied());
+    }
+}
@@ -0,0 +1,39 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::HoldId;
+
+use super::repo::{HoldCreateError, HoldFindError, HoldModifyError, HoldQueryError};
+
+#[derive(Error, Debug)]
+pub enum HoldError {
+    #[error("HoldError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("HoldError - Create: {0}")]
+    Create(#[from] HoldCreateError),
+    #[error("HoldError - Modify: {0}")]
+    Modify(#[from] HoldModifyError),
+    #[error("HoldError - Find: {0}")]
+    Find(#[from] HoldFindError),
+    #[error("HoldError - Query: {0}")]
+    Query(#[from] HoldQueryError),
+    #[error("HoldError - NotActive: {0}")]
+    NotActive(HoldId),
+    #[error("HoldError - ExpiryNotExtended: {0}")]
+    ExpiryNotExtended(HoldId),
+}
+
+impl ErrorSeverity for HoldError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::NotActive(_) => Level::WARN,
+            Self::ExpiryNotExtended(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod entity;
+pub mod error;
+pub mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::HoldEvent;
+pub(super) use entity::*;
+pub use entity::{Hold, HoldStatus};
+pub use repo::hold_cursor::HoldsByCreatedAtCursor;
+pub(super) use repo::*;
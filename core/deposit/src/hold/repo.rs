@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+use obix::out::OutboxEventMarker;
+
+use crate::{
+    primitives::{DepositAccountId, HoldId},
+    public::CoreDepositEvent,
+    publisher::DepositPublisher,
+};
+
+use super::{entity::*, error::HoldError};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "Hold",
+    columns(
+        deposit_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        status(ty = "HoldStatus", list_for, update(accessor = "status()")),
+        expires_at(ty = "chrono::DateTime<chrono::Utc>")
+    ),
+    tbl_prefix = "core",
+    post_persist_hook = "publish_in_op"
+)]
+pub struct HoldRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    publisher: DepositPublisher<E>,
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl<E> Clone for HoldRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+            pool: self.pool.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<E> HoldRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &DepositPublisher<E>, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+            clock,
+        }
+    }
+
+    async fn publish_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Hold,
+        new_events: es_entity::LastPersisted<'_, HoldEvent>,
+    ) -> Result<(), sqlx::Error> {
+        self.publisher
+            .publish_hold_in_op(op, entity, new_events)
+            .await
+    }
+
+    pub async fn list_expired_hold_ids_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        as_of: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<HoldId>, HoldError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: HoldId"
+               FROM core_holds
+               WHERE status = 'active'
+                 AND expires_at <= $1
+               ORDER BY expires_at, id
+               LIMIT $2"#,
+            as_of,
+            limit,
+        )
+        .fetch_all(op.as_executor())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    pub async fn list_active_hold_ids_for_account_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<Vec<HoldId>, HoldError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: HoldId"
+               FROM core_holds
+               WHERE status = 'active'
+                 AND deposit_account_id = $1
+               ORDER BY created_at, id"#,
+            deposit_account_id as DepositAccountId,
+        )
+        .fetch_all(op.as_executor())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+}
+
+mod hold_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::HoldStatus;
+
+    impl Type<Postgres> for HoldStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for HoldStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for HoldStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for HoldStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -21,14 +21,21 @@ use cala_ledger::{
     velocity::{NewVelocityControl, VelocityControlId},
 };
 
+use money::{CurrencyMap, SignedUsdCents};
+
 use crate::{
-    DepositAccount, DepositAccountBalance, DepositReversalData, LedgerOmnibusAccountIds,
-    WithdrawalReversalData,
+    DepositAccount, DepositAccountBalance, DepositAccountCurrencyBalance, DepositReversalData,
+    LedgerOmnibusAccountIds, WithdrawalReversalData,
+    account_statement::{DepositStatementEntry, StatementTransactionType},
     chart_of_accounts_integration::ResolvedChartOfAccountsIntegrationConfig,
     history::DepositAccountHistoryEntry,
     primitives::{
-        CalaAccountId, CalaAccountSetId, DEPOSIT_ACCOUNT_ENTITY_TYPE, DEPOSIT_ACCOUNT_SET_CATALOG,
-        DepositAccountType, DepositId, UsdCents, WithdrawalId,
+        CalaAccountId, CalaAccountSetId, CalaTransactionId, CurrencyCode,
+        DEPOSIT_ACCOUNT_CURRENCIES, DEPOSIT_ACCOUNT_ENTITY_TYPE, DEPOSIT_ACCOUNT_SET_CATALOG,
+        DepositAccountId, DepositAccountType, DepositId, FxConversionId,
+        HOLD_TRANSACTION_ENTITY_TYPE, HoldId, Money, TRANSFER_TRANSACTION_ENTITY_TYPE, TransferId,
+        UsdCents, WithdrawalId,
+        account_sets::{DepositAccountSetCatalogGroup, DepositSummaryAccountSetSpec},
     },
 };
 
@@ -54,14 +61,46 @@ pub struct DepositAccountSets {
     non_domiciled_company: InternalAccountSetDetails,
 }
 
+impl DepositAccountSets {
+    fn new(
+        group: &DepositAccountSetCatalogGroup,
+        ids: &HashMap<&str, InternalAccountSetDetails>,
+    ) -> Self {
+        Self {
+            individual: ids[group.individual.external_ref],
+            government_entity: ids[group.government_entity.external_ref],
+            private_company: ids[group.private_company.external_ref],
+            bank: ids[group.bank.external_ref],
+            financial_institution: ids[group.financial_institution.external_ref],
+            non_domiciled_company: ids[group.non_domiciled_company.external_ref],
+        }
+    }
+
+    fn for_type(&self, deposit_account_type: DepositAccountType) -> InternalAccountSetDetails {
+        match deposit_account_type {
+            DepositAccountType::Individual => self.individual,
+            DepositAccountType::GovernmentEntity => self.government_entity,
+            DepositAccountType::PrivateCompany => self.private_company,
+            DepositAccountType::Bank => self.bank,
+            DepositAccountType::FinancialInstitution => self.financial_institution,
+            DepositAccountType::NonDomiciledCompany => self.non_domiciled_company,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct DepositLedger {
     cala: CalaLedger,
     clock: ClockHandle,
     journal_id: JournalId,
-    deposit_account_sets: DepositAccountSets,
-    frozen_deposit_account_sets: DepositAccountSets,
+    deposit_account_sets: CurrencyMap<DepositAccountSets>,
+    frozen_deposit_account_sets: CurrencyMap<DepositAccountSets>,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
+    interest_expense_account_ids: LedgerOmnibusAccountIds,
+    fee_income_account_ids: LedgerOmnibusAccountIds,
+    unclaimed_property_account_ids: LedgerOmnibusAccountIds,
+    fx_position_account_ids: LedgerOmnibusAccountIds,
+    currencies: CurrencyMap<Currency>,
     usd: Currency,
     deposit_control_id: VelocityControlId,
 }
@@ -83,47 +122,53 @@ impl DepositLedger {
         templates::RevertDeposit::init(cala).await?;
         templates::FreezeAccount::init(cala).await?;
         templates::UnfreezeAccount::init(cala).await?;
+        templates::Transfer::init(cala).await?;
+        templates::PlaceHold::init(cala).await?;
+        templates::ReleaseHold::init(cala).await?;
+        templates::AccrueDepositInterest::init(cala).await?;
+        templates::PostDepositInterest::init(cala).await?;
+        templates::ChargeEarlyWithdrawalPenalty::init(cala).await?;
+        templates::ChargeDepositFee::init(cala).await?;
+        templates::ReverseDepositFee::init(cala).await?;
+        templates::EscheatDepositBalance::init(cala).await?;
+        templates::ReclaimEscheatedBalance::init(cala).await?;
+        templates::FxConversion::init(cala).await?;
 
         let catalog = DEPOSIT_ACCOUNT_SET_CATALOG;
-        let deposit = catalog.deposit();
-        let frozen = catalog.frozen();
 
-        let mut deposit_ids: HashMap<&str, InternalAccountSetDetails> = HashMap::new();
-        for spec in catalog.deposit_specs() {
-            let id = Self::find_or_create_account_set(
+        let mut deposit_account_sets = CurrencyMap::new();
+        let mut frozen_deposit_account_sets = CurrencyMap::new();
+        let mut currencies = CurrencyMap::new();
+        for currency in DEPOSIT_ACCOUNT_CURRENCIES {
+            let deposit_ids = Self::find_or_create_currency_account_sets(
                 cala,
                 journal_id,
-                format!("{journal_id}:{}", spec.external_ref),
-                spec.name.to_string(),
-                spec.normal_balance_type,
+                catalog.deposit_specs(),
+                currency,
             )
             .await?;
-            deposit_ids.insert(
-                spec.external_ref,
-                InternalAccountSetDetails {
-                    id,
-                    normal_balance_type: spec.normal_balance_type,
-                },
+            deposit_account_sets.insert(
+                currency,
+                DepositAccountSets::new(catalog.deposit(), &deposit_ids),
             );
-        }
 
-        let mut frozen_ids: HashMap<&str, InternalAccountSetDetails> = HashMap::new();
-        for spec in catalog.frozen_specs() {
-            let id = Self::find_or_create_account_set(
+            let frozen_ids = Self::find_or_create_currency_account_sets(
                 cala,
                 journal_id,
-                format!("{journal_id}:{}", spec.external_ref),
-                spec.name.to_string(),
-                spec.normal_balance_type,
+                catalog.frozen_specs(),
+                currency,
             )
             .await?;
-            frozen_ids.insert(
-                spec.external_ref,
-                InternalAccountSetDetails {
-                    id,
-                    normal_balance_type: spec.normal_balance_type,
-                },
+            frozen_deposit_account_sets.insert(
+                currency,
+                DepositAccountSets::new(catalog.frozen(), &frozen_ids),
             );
+
+            let ledger_currency = currency
+                .iso()
+                .parse::<Currency>()
+                .map_err(|_| DepositLedgerError::UnsupportedCurrency(currency))?;
+            currencies.insert(currency, ledger_currency);
         }
 
         let mut omnibus_ids: HashMap<&str, LedgerOmnibusAccountIds> = HashMap::new();
@@ -141,6 +186,12 @@ impl DepositLedger {
         }
 
         let deposit_omnibus_account_ids = omnibus_ids[catalog.omnibus().account_set_ref].clone();
+        let interest_expense_account_ids =
+            omnibus_ids[catalog.interest_expense().account_set_ref].clone();
+        let fee_income_account_ids = omnibus_ids[catalog.fee_income().account_set_ref].clone();
+        let unclaimed_property_account_ids =
+            omnibus_ids[catalog.unclaimed_property().account_set_ref].clone();
+        let fx_position_account_ids = omnibus_ids[catalog.fx_position().account_set_ref].clone();
 
         let overdraft_prevention_id = velocity::OverdraftPrevention::init(cala).await?;
 
@@ -155,32 +206,71 @@ impl DepositLedger {
             | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
             Err(e) => return Err(e.into()),
         }
+
         Ok(Self {
             clock,
             cala: cala.clone(),
             journal_id,
-            deposit_account_sets: DepositAccountSets {
-                individual: deposit_ids[deposit.individual.external_ref],
-                government_entity: deposit_ids[deposit.government_entity.external_ref],
-                private_company: deposit_ids[deposit.private_company.external_ref],
-                bank: deposit_ids[deposit.bank.external_ref],
-                financial_institution: deposit_ids[deposit.financial_institution.external_ref],
-                non_domiciled_company: deposit_ids[deposit.non_domiciled_company.external_ref],
-            },
-            frozen_deposit_account_sets: DepositAccountSets {
-                individual: frozen_ids[frozen.individual.external_ref],
-                government_entity: frozen_ids[frozen.government_entity.external_ref],
-                private_company: frozen_ids[frozen.private_company.external_ref],
-                bank: frozen_ids[frozen.bank.external_ref],
-                financial_institution: frozen_ids[frozen.financial_institution.external_ref],
-                non_domiciled_company: frozen_ids[frozen.non_domiciled_company.external_ref],
-            },
+            deposit_account_sets,
+            frozen_deposit_account_sets,
             deposit_omnibus_account_ids,
+            interest_expense_account_ids,
+            fee_income_account_ids,
+            unclaimed_property_account_ids,
+            fx_position_account_ids,
+            currencies,
             deposit_control_id,
             usd: Currency::USD,
         })
     }
 
+    /// USD account sets keep their original references so existing ledgers stay attached;
+    /// every other currency gets its own suffixed copy of the summary sets.
+    async fn find_or_create_currency_account_sets(
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        specs: impl IntoIterator<Item = DepositSummaryAccountSetSpec>,
+        currency: CurrencyCode,
+    ) -> Result<HashMap<&'static str, InternalAccountSetDetails>, DepositLedgerError> {
+        let mut ids = HashMap::new();
+        for spec in specs {
+            let (reference, name) = if currency == CurrencyCode::USD {
+                (
+                    format!("{journal_id}:{}", spec.external_ref),
+                    spec.name.to_string(),
+                )
+            } else {
+                (
+                    format!("{journal_id}:{}:{currency}", spec.external_ref),
+                    format!("{} ({currency})", spec.name),
+                )
+            };
+            let id = Self::find_or_create_account_set(
+                cala,
+                journal_id,
+                reference,
+                name,
+                spec.normal_balance_type,
+            )
+            .await?;
+            ids.insert(
+                spec.external_ref,
+                InternalAccountSetDetails {
+                    id,
+                    normal_balance_type: spec.normal_balance_type,
+                },
+            );
+        }
+        Ok(ids)
+    }
+
+    fn ledger_currency(&self, currency: CurrencyCode) -> Result<Currency, DepositLedgerError> {
+        self.currencies
+            .get(&currency)
+            .copied()
+            .ok_or(DepositLedgerError::UnsupportedCurrency(currency))
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit_ledger.find_or_create_account_set", skip(cala, name), fields(journal_id = %journal_id, reference = %reference, account_set_name = %name))]
     async fn find_or_create_account_set(
@@ -367,77 +457,714 @@ impl DepositLedger {
                 }
             }
 
-            next = ret.into_next_query();
-        }
+            next = ret.into_next_query();
+        }
+
+        Ok(None)
+    }
+
+    /// The settled USD balance effective before `from` together with the settled USD
+    /// entries whose transactions take effect between `from` and `until`. Entries are
+    /// read newest first and only back to `from`, since no transaction takes effect
+    /// after it was recorded.
+    #[record_error_severity]
+    #[instrument(name = "deposit_ledger.statement_entries", skip_all, fields(account_id = tracing::field::Empty))]
+    pub async fn statement_entries(
+        &self,
+        id: impl Into<AccountId>,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<(SignedUsdCents, Vec<DepositStatementEntry>), DepositLedgerError> {
+        let id = id.into();
+        tracing::Span::current().record("account_id", tracing::field::debug(&id));
+
+        let balance_id = (self.journal_id, id, self.usd);
+        let opening_balance = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&[balance_id], from, Some(until))
+            .await?
+            .remove(&balance_id)
+            .map(|range| SignedUsdCents::from_usd(range.open.settled()))
+            .unwrap_or(SignedUsdCents::ZERO);
+
+        let mut settled = Vec::new();
+        let mut next = Some(es_entity::PaginatedQueryArgs::<
+            cala_ledger::entry::EntriesByCreatedAtCursor,
+        > {
+            first: 100,
+            after: None,
+        });
+        'pages: while let Some(query) = next.take() {
+            let mut ret = self
+                .cala
+                .entries()
+                .list_for_account_id(id, query, es_entity::ListDirection::Descending)
+                .await?;
+
+            for entry in ret.entities.drain(..) {
+                if entry.created_at().date_naive() < from {
+                    break 'pages;
+                }
+                if entry.values().layer == cala_ledger::Layer::Settled
+                    && entry.values().currency == self.usd
+                {
+                    settled.push(entry);
+                }
+            }
+
+            next = ret.into_next_query();
+        }
+
+        let tx_ids: Vec<TransactionId> = settled
+            .iter()
+            .map(|entry| entry.values().transaction_id)
+            .collect();
+        let transactions: HashMap<TransactionId, cala_ledger::transaction::Transaction> =
+            self.cala.transactions().find_all(&tx_ids).await?;
+
+        let mut entries = Vec::new();
+        for entry in settled {
+            let tx_id = entry.values().transaction_id;
+            let Some(effective) = transactions.get(&tx_id).map(|tx| tx.effective()) else {
+                continue;
+            };
+            if effective < from || effective > until {
+                continue;
+            }
+            let recorded_at = entry.created_at();
+            let direction = entry.values().direction;
+            let amount = UsdCents::try_from_usd(entry.values().units)?;
+            entries.push(DepositStatementEntry {
+                tx_id,
+                effective,
+                recorded_at,
+                direction,
+                amount,
+                transaction_type: StatementTransactionType::from(
+                    &DepositAccountHistoryEntry::from(entry),
+                ),
+            });
+        }
+
+        Ok((opening_balance, entries))
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.record_deposit_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, credit_account_id = tracing::field::Empty)
+    )]
+    pub async fn record_deposit_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: DepositId,
+        amount: Money,
+        credit_account_id: impl Into<AccountId>,
+        effective_date: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = entity_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let credit_account_id = credit_account_id.into();
+        tracing::Span::current().record(
+            "credit_account_id",
+            tracing::field::debug(&credit_account_id),
+        );
+
+        let params = templates::RecordDepositParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            currency: self.ledger_currency(amount.currency())?,
+            amount: amount.to_major(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            credit_account_id,
+            initiated_by,
+            effective_date,
+        };
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::RECORD_DEPOSIT_CODE, params)
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.initiate_withdrawal_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, credit_account_id = tracing::field::Empty)
+    )]
+    pub async fn initiate_withdrawal_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: WithdrawalId,
+        amount: Money,
+        credit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = entity_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let credit_account_id = credit_account_id.into();
+        tracing::Span::current().record(
+            "credit_account_id",
+            tracing::field::debug(&credit_account_id),
+        );
+
+        let params = templates::InitiateWithdrawParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            credit_account_id,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.transfer_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, debit_account_id = tracing::field::Empty, credit_account_id = tracing::field::Empty)
+    )]
+    pub async fn transfer_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: TransferId,
+        amount: Money,
+        debit_account_id: impl Into<AccountId>,
+        credit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = entity_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let debit_account_id = debit_account_id.into();
+        tracing::Span::current()
+            .record("debit_account_id", tracing::field::debug(&debit_account_id));
+        let credit_account_id = credit_account_id.into();
+        tracing::Span::current().record(
+            "credit_account_id",
+            tracing::field::debug(&credit_account_id),
+        );
+
+        let params = templates::TransferParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            debit_account_id,
+            credit_account_id,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::TRANSFER_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Earmarks the amount of a transfer awaiting approval on the source account, using
+    /// the same encumbrance entries as a hold so it is excluded from the available balance.
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.hold_transfer_funds_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn hold_transfer_funds_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: TransferId,
+        amount: Money,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::PlaceHoldParams {
+            entity_type: TRANSFER_TRANSACTION_ENTITY_TYPE,
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_account_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::PLACE_HOLD_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.release_transfer_funds_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn release_transfer_funds_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: TransferId,
+        amount: Money,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::ReleaseHoldParams {
+            entity_type: TRANSFER_TRANSACTION_ENTITY_TYPE,
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::RELEASE_HOLD_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.fx_conversion_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, source_account_id = tracing::field::Empty, target_account_id = tracing::field::Empty)
+    )]
+    pub async fn fx_conversion_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: FxConversionId,
+        source_amount: Money,
+        target_amount: Money,
+        rate: rust_decimal::Decimal,
+        source_account_id: impl Into<AccountId>,
+        target_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = entity_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let source_account_id = source_account_id.into();
+        tracing::Span::current().record(
+            "source_account_id",
+            tracing::field::debug(&source_account_id),
+        );
+        let target_account_id = target_account_id.into();
+        tracing::Span::current().record(
+            "target_account_id",
+            tracing::field::debug(&target_account_id),
+        );
+
+        let params = templates::FxConversionParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            source_account_id,
+            target_account_id,
+            fx_position_account_id: self.fx_position_account_ids.account_id,
+            source_amount: source_amount.to_major(),
+            source_currency: self.ledger_currency(source_amount.currency())?,
+            target_amount: target_amount.to_major(),
+            target_currency: self.ledger_currency(target_amount.currency())?,
+            rate,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::FX_CONVERSION_CODE, params)
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.place_hold_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn place_hold_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: HoldId,
+        amount: Money,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = entity_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::PlaceHoldParams {
+            entity_type: HOLD_TRANSACTION_ENTITY_TYPE,
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_account_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::PLACE_HOLD_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.release_hold_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn release_hold_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: HoldId,
+        amount: Money,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::ReleaseHoldParams {
+            entity_type: HOLD_TRANSACTION_ENTITY_TYPE,
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::RELEASE_HOLD_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.accrue_deposit_interest_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn accrue_deposit_interest_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: DepositAccountId,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+        effective_date: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::AccrueDepositInterestParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            interest_expense_account_id: self.interest_expense_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date,
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::ACCRUE_DEPOSIT_INTEREST_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.post_deposit_interest_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn post_deposit_interest_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: DepositAccountId,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+        effective_date: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::PostDepositInterestParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            interest_expense_account_id: self.interest_expense_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date,
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::POST_DEPOSIT_INTEREST_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.charge_early_withdrawal_penalty_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn charge_early_withdrawal_penalty_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: WithdrawalId,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::ChargeEarlyWithdrawalPenaltyParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            interest_expense_account_id: self.interest_expense_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CHARGE_EARLY_WITHDRAWAL_PENALTY_CODE,
+                params,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.charge_deposit_fee_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn charge_deposit_fee_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: DepositAccountId,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::ChargeDepositFeeParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            fee_income_account_id: self.fee_income_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::CHARGE_DEPOSIT_FEE_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.reverse_deposit_fee_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
+    )]
+    pub async fn reverse_deposit_fee_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        entity_id: DepositAccountId,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let deposit_account_id = deposit_account_id.into();
+        tracing::Span::current().record(
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
+        );
+
+        let params = templates::ReverseDepositFeeParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            fee_income_account_id: self.fee_income_account_ids.account_id,
+            deposit_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
 
-        Ok(None)
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::REVERSE_DEPOSIT_FEE_CODE, params)
+            .await?;
+
+        Ok(())
     }
 
     #[record_error_severity]
     #[instrument(
-        name = "deposit_ledger.record_deposit_in_op",
+        name = "deposit_ledger.escheat_deposit_balance_in_op",
         skip_all,
-        fields(entity_id = tracing::field::Empty, credit_account_id = tracing::field::Empty)
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
     )]
-    pub async fn record_deposit_in_op(
+    pub async fn escheat_deposit_balance_in_op(
         &self,
         op: &mut es_entity::DbOp<'_>,
-        entity_id: DepositId,
+        tx_id: CalaTransactionId,
+        entity_id: DepositAccountId,
         amount: UsdCents,
-        credit_account_id: impl Into<AccountId>,
+        deposit_account_id: impl Into<AccountId>,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
-        let tx_id = entity_id.into();
         tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
-        let credit_account_id = credit_account_id.into();
+        let deposit_account_id = deposit_account_id.into();
         tracing::Span::current().record(
-            "credit_account_id",
-            tracing::field::debug(&credit_account_id),
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
         );
 
-        let params = templates::RecordDepositParams {
+        let params = templates::EscheatDepositBalanceParams {
             entity_id: entity_id.into(),
             journal_id: self.journal_id,
-            currency: self.usd,
+            unclaimed_property_account_id: self.unclaimed_property_account_ids.account_id,
+            deposit_account_id,
             amount: amount.to_usd(),
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
-            credit_account_id,
+            currency: self.usd,
             initiated_by,
             effective_date: self.clock.today(),
         };
+
         self.cala
-            .post_transaction_in_op(op, tx_id, templates::RECORD_DEPOSIT_CODE, params)
+            .post_transaction_in_op(op, tx_id, templates::ESCHEAT_DEPOSIT_BALANCE_CODE, params)
             .await?;
+
         Ok(())
     }
 
     #[record_error_severity]
     #[instrument(
-        name = "deposit_ledger.initiate_withdrawal_in_op",
+        name = "deposit_ledger.reclaim_escheated_balance_in_op",
         skip_all,
-        fields(entity_id = tracing::field::Empty, credit_account_id = tracing::field::Empty)
+        fields(entity_id = tracing::field::Empty, deposit_account_id = tracing::field::Empty)
     )]
-    pub async fn initiate_withdrawal_in_op(
+    pub async fn reclaim_escheated_balance_in_op(
         &self,
         op: &mut es_entity::DbOp<'_>,
-        entity_id: WithdrawalId,
+        tx_id: CalaTransactionId,
+        entity_id: DepositAccountId,
         amount: UsdCents,
-        credit_account_id: impl Into<AccountId>,
+        deposit_account_id: impl Into<AccountId>,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
-        let tx_id = entity_id.into();
         tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
-        let credit_account_id = credit_account_id.into();
+        let deposit_account_id = deposit_account_id.into();
         tracing::Span::current().record(
-            "credit_account_id",
-            tracing::field::debug(&credit_account_id),
+            "deposit_account_id",
+            tracing::field::debug(&deposit_account_id),
         );
 
-        let params = templates::InitiateWithdrawParams {
+        let params = templates::ReclaimEscheatedBalanceParams {
             entity_id: entity_id.into(),
             journal_id: self.journal_id,
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
-            credit_account_id,
+            unclaimed_property_account_id: self.unclaimed_property_account_ids.account_id,
+            deposit_account_id,
             amount: amount.to_usd(),
             currency: self.usd,
             initiated_by,
@@ -445,7 +1172,7 @@ impl DepositLedger {
         };
 
         self.cala
-            .post_transaction_in_op(op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
+            .post_transaction_in_op(op, tx_id, templates::RECLAIM_ESCHEATED_BALANCE_CODE, params)
             .await?;
 
         Ok(())
@@ -462,7 +1189,7 @@ impl DepositLedger {
         op: &mut es_entity::DbOp<'_>,
         entity_id: WithdrawalId,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
@@ -480,8 +1207,8 @@ impl DepositLedger {
             journal_id: self.journal_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id,
-            amount: amount.to_usd(),
-            currency: self.usd,
+            amount: amount.to_major(),
+            currency: self.ledger_currency(amount.currency())?,
             initiated_by,
             effective_date: self.clock.today(),
         };
@@ -506,8 +1233,8 @@ impl DepositLedger {
             journal_id: self.journal_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: reversal_data.credit_account_id.into(),
-            amount: reversal_data.amount.to_usd(),
-            currency: self.usd,
+            amount: reversal_data.amount.to_major(),
+            currency: self.ledger_currency(reversal_data.amount.currency())?,
             correlation_id: reversal_data.correlation_id,
             external_id: reversal_data.external_id,
             initiated_by,
@@ -541,8 +1268,8 @@ impl DepositLedger {
             credit_account_id: reversal_data.credit_account_id.into(),
             correlation_id: reversal_data.correlation_id,
             external_id: reversal_data.external_id,
-            amount: reversal_data.amount.to_usd(),
-            currency: self.usd,
+            amount: reversal_data.amount.to_major(),
+            currency: self.ledger_currency(reversal_data.amount.currency())?,
             initiated_by,
             effective_date: self.clock.today(),
         };
@@ -575,15 +1302,15 @@ impl DepositLedger {
         account: &DepositAccount,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
-        let balance = self.balance(account.id).await?;
+        let balance = self.currency_balance(account.id, account.currency).await?;
 
         if !balance.settled.is_zero() {
             let params = templates::FreezeAccountParams {
                 journal_id: self.journal_id,
                 account_id: account.account_ids.deposit_account_id,
                 frozen_accounts_account_id: account.account_ids.frozen_deposit_account_id,
-                amount: balance.settled.to_usd(),
-                currency: self.usd,
+                amount: balance.settled.to_major(),
+                currency: self.ledger_currency(account.currency)?,
                 initiated_by,
                 effective_date: self.clock.today(),
             };
@@ -623,7 +1350,10 @@ impl DepositLedger {
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
         let frozen_balance = self
-            .balance(account.account_ids.frozen_deposit_account_id)
+            .currency_balance(
+                account.account_ids.frozen_deposit_account_id,
+                account.currency,
+            )
             .await?;
 
         self.cala
@@ -636,8 +1366,8 @@ impl DepositLedger {
                 journal_id: self.journal_id,
                 account_id: account.account_ids.deposit_account_id,
                 frozen_accounts_account_id: account.account_ids.frozen_deposit_account_id,
-                amount: frozen_balance.settled.to_usd(),
-                currency: self.usd,
+                amount: frozen_balance.settled.to_major(),
+                currency: self.ledger_currency(account.currency)?,
                 initiated_by,
                 effective_date: self.clock.today(),
             };
@@ -679,7 +1409,7 @@ impl DepositLedger {
         entity_id: WithdrawalId,
         tx_id: impl Into<TransactionId>,
         correlation_id: String,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
         external_id: String,
         initiated_by: &impl SystemSubject,
@@ -696,8 +1426,8 @@ impl DepositLedger {
         let params = templates::ConfirmWithdrawParams {
             entity_id: entity_id.into(),
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: self.ledger_currency(amount.currency())?,
+            amount: amount.to_major(),
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id,
             correlation_id,
@@ -723,7 +1453,7 @@ impl DepositLedger {
         op: &mut es_entity::DbOp<'_>,
         entity_id: WithdrawalId,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
@@ -739,8 +1469,8 @@ impl DepositLedger {
         let params = templates::CancelWithdrawParams {
             entity_id: entity_id.into(),
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: self.ledger_currency(amount.currency())?,
+            amount: amount.to_major(),
             credit_account_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             initiated_by,
@@ -770,6 +1500,10 @@ impl DepositLedger {
             Ok(balances) => Ok(DepositAccountBalance {
                 settled: UsdCents::try_from_usd(balances.settled())?,
                 pending: UsdCents::try_from_usd(balances.pending())?,
+                held: UsdCents::try_from_usd(
+                    balances.details.encumbrance.dr_balance
+                        - balances.details.encumbrance.cr_balance,
+                )?,
             }),
             Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
                 Ok(DepositAccountBalance::ZERO)
@@ -778,6 +1512,95 @@ impl DepositLedger {
         }
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit_ledger.balance_in_op", skip_all, fields(account_id = tracing::field::Empty))]
+    pub async fn balance_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        account_id: impl Into<AccountId>,
+    ) -> Result<DepositAccountBalance, DepositLedgerError> {
+        let account_id = account_id.into();
+        tracing::Span::current().record("account_id", tracing::field::debug(&account_id));
+        let balance_id = (self.journal_id, account_id, self.usd);
+        let balances = self
+            .cala
+            .balances()
+            .find_all_in_op(op, &[balance_id])
+            .await?;
+        match balances.get(&balance_id) {
+            Some(balances) => Ok(DepositAccountBalance {
+                settled: UsdCents::try_from_usd(balances.settled())?,
+                pending: UsdCents::try_from_usd(balances.pending())?,
+                held: UsdCents::try_from_usd(
+                    balances.details.encumbrance.dr_balance
+                        - balances.details.encumbrance.cr_balance,
+                )?,
+            }),
+            None => Ok(DepositAccountBalance::ZERO),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit_ledger.currency_balance", skip_all, fields(account_id = tracing::field::Empty, currency = %currency))]
+    pub async fn currency_balance(
+        &self,
+        account_id: impl Into<AccountId>,
+        currency: CurrencyCode,
+    ) -> Result<DepositAccountCurrencyBalance, DepositLedgerError> {
+        let account_id = account_id.into();
+        tracing::Span::current().record("account_id", tracing::field::debug(&account_id));
+        match self
+            .cala
+            .balances()
+            .find(self.journal_id, account_id, self.ledger_currency(currency)?)
+            .await
+        {
+            Ok(balances) => Ok(DepositAccountCurrencyBalance {
+                settled: Money::try_from_major(currency, balances.settled())?,
+                pending: Money::try_from_major(currency, balances.pending())?,
+                held: Money::try_from_major(
+                    currency,
+                    balances.details.encumbrance.dr_balance
+                        - balances.details.encumbrance.cr_balance,
+                )?,
+            }),
+            Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
+                Ok(DepositAccountCurrencyBalance::zero(currency))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit_ledger.currency_balance_in_op", skip_all, fields(account_id = tracing::field::Empty, currency = %currency))]
+    pub async fn currency_balance_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        account_id: impl Into<AccountId>,
+        currency: CurrencyCode,
+    ) -> Result<DepositAccountCurrencyBalance, DepositLedgerError> {
+        let account_id = account_id.into();
+        tracing::Span::current().record("account_id", tracing::field::debug(&account_id));
+        let balance_id = (self.journal_id, account_id, self.ledger_currency(currency)?);
+        let balances = self
+            .cala
+            .balances()
+            .find_all_in_op(op, &[balance_id])
+            .await?;
+        match balances.get(&balance_id) {
+            Some(balances) => Ok(DepositAccountCurrencyBalance {
+                settled: Money::try_from_major(currency, balances.settled())?,
+                pending: Money::try_from_major(currency, balances.pending())?,
+                held: Money::try_from_major(
+                    currency,
+                    balances.details.encumbrance.dr_balance
+                        - balances.details.encumbrance.cr_balance,
+                )?,
+            }),
+            None => Ok(DepositAccountCurrencyBalance::zero(currency)),
+        }
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit_ledger.create_deposit_accounts_in_op", skip_all)]
     pub async fn create_deposit_accounts_in_op(
@@ -787,15 +1610,21 @@ impl DepositLedger {
         deposit_account_type: impl Into<DepositAccountType>,
     ) -> Result<(), DepositLedgerError> {
         let holder_id = account.account_holder_id;
+        let currency = account.currency;
         let deposit_account_type = deposit_account_type.into();
+        let (reference_suffix, name_suffix) = if currency == CurrencyCode::USD {
+            (String::new(), String::new())
+        } else {
+            (format!(":{currency}"), format!(" ({currency})"))
+        };
 
         let entity_ref = EntityRef::new(DEPOSIT_ACCOUNT_ENTITY_TYPE, account.id);
-        let deposit_account_name = format!("Deposit Account {holder_id}");
+        let deposit_account_name = format!("Deposit Account {holder_id}{name_suffix}");
         self.create_account_in_op(
             op,
             account.id,
-            self.deposit_internal_account_set_from_type(deposit_account_type),
-            &format!("deposit-customer-account:{holder_id}"),
+            self.deposit_internal_account_set_from_type(deposit_account_type, currency)?,
+            &format!("deposit-customer-account:{holder_id}{reference_suffix}"),
             &deposit_account_name,
             &deposit_account_name,
             entity_ref.clone(),
@@ -805,12 +1634,13 @@ impl DepositLedger {
         self.add_deposit_control_to_account_in_op(op, account.id)
             .await?;
 
-        let frozen_deposit_account_name = format!("Frozen Deposit Account {holder_id}");
+        let frozen_deposit_account_name =
+            format!("Frozen Deposit Account {holder_id}{name_suffix}");
         self.create_account_in_op(
             op,
             account.account_ids.frozen_deposit_account_id,
-            self.frozen_deposit_internal_account_set_from_type(deposit_account_type),
-            &format!("frozen-deposit-customer-account:{holder_id}"),
+            self.frozen_deposit_internal_account_set_from_type(deposit_account_type, currency)?,
+            &format!("frozen-deposit-customer-account:{holder_id}{reference_suffix}"),
             &frozen_deposit_account_name,
             &frozen_deposit_account_name,
             entity_ref,
@@ -823,39 +1653,25 @@ impl DepositLedger {
     fn deposit_internal_account_set_from_type(
         &self,
         deposit_account_type: DepositAccountType,
-    ) -> InternalAccountSetDetails {
-        match deposit_account_type {
-            DepositAccountType::Individual => self.deposit_account_sets.individual,
-            DepositAccountType::GovernmentEntity => self.deposit_account_sets.government_entity,
-            DepositAccountType::PrivateCompany => self.deposit_account_sets.private_company,
-            DepositAccountType::Bank => self.deposit_account_sets.bank,
-            DepositAccountType::FinancialInstitution => {
-                self.deposit_account_sets.financial_institution
-            }
-            DepositAccountType::NonDomiciledCompany => {
-                self.deposit_account_sets.non_domiciled_company
-            }
-        }
+        currency: CurrencyCode,
+    ) -> Result<InternalAccountSetDetails, DepositLedgerError> {
+        Ok(self
+            .deposit_account_sets
+            .get(&currency)
+            .ok_or(DepositLedgerError::UnsupportedCurrency(currency))?
+            .for_type(deposit_account_type))
     }
 
     fn frozen_deposit_internal_account_set_from_type(
         &self,
         deposit_account_type: DepositAccountType,
-    ) -> InternalAccountSetDetails {
-        match deposit_account_type {
-            DepositAccountType::Individual => self.frozen_deposit_account_sets.individual,
-            DepositAccountType::GovernmentEntity => {
-                self.frozen_deposit_account_sets.government_entity
-            }
-            DepositAccountType::PrivateCompany => self.frozen_deposit_account_sets.private_company,
-            DepositAccountType::Bank => self.frozen_deposit_account_sets.bank,
-            DepositAccountType::FinancialInstitution => {
-                self.frozen_deposit_account_sets.financial_institution
-            }
-            DepositAccountType::NonDomiciledCompany => {
-                self.frozen_deposit_account_sets.non_domiciled_company
-            }
-        }
+        currency: CurrencyCode,
+    ) -> Result<InternalAccountSetDetails, DepositLedgerError> {
+        Ok(self
+            .frozen_deposit_account_sets
+            .get(&currency)
+            .ok_or(DepositLedgerError::UnsupportedCurrency(currency))?
+            .for_type(deposit_account_type))
     }
 
     #[record_error_severity]
@@ -999,6 +1815,10 @@ impl DepositLedger {
             frozen_bank_deposit_accounts_parent_account_set_id,
             frozen_financial_institution_deposit_accounts_parent_account_set_id,
             frozen_non_domiciled_company_deposit_accounts_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            fee_income_parent_account_set_id,
+            unclaimed_property_parent_account_set_id,
+            fx_position_parent_account_set_id,
         } = &new_integration_config;
 
         self.attach_charts_account_set_in_op(
@@ -1009,115 +1829,235 @@ impl DepositLedger {
         )
         .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.deposit_account_sets.individual.id,
-            *individual_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.individual_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+        for currency in DEPOSIT_ACCOUNT_CURRENCIES {
+            let deposit_account_sets = &self.deposit_account_sets[&currency];
+            let frozen_deposit_account_sets = &self.frozen_deposit_account_sets[&currency];
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.deposit_account_sets.government_entity.id,
-            *government_entity_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.government_entity_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                deposit_account_sets.individual.id,
+                *individual_deposit_accounts_parent_account_set_id,
+                old_integration_config
+                    .map(|config| config.individual_deposit_accounts_parent_account_set_id),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.deposit_account_sets.private_company.id,
-            *private_company_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.private_company_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                deposit_account_sets.government_entity.id,
+                *government_entity_deposit_accounts_parent_account_set_id,
+                old_integration_config
+                    .map(|config| config.government_entity_deposit_accounts_parent_account_set_id),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.deposit_account_sets.bank.id,
-            *bank_deposit_accounts_parent_account_set_id,
-            old_integration_config.map(|config| config.bank_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                deposit_account_sets.private_company.id,
+                *private_company_deposit_accounts_parent_account_set_id,
+                old_integration_config
+                    .map(|config| config.private_company_deposit_accounts_parent_account_set_id),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.deposit_account_sets.financial_institution.id,
-            *financial_institution_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.financial_institution_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                deposit_account_sets.bank.id,
+                *bank_deposit_accounts_parent_account_set_id,
+                old_integration_config
+                    .map(|config| config.bank_deposit_accounts_parent_account_set_id),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.deposit_account_sets.non_domiciled_company.id,
-            *non_domiciled_company_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.non_domiciled_company_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                deposit_account_sets.financial_institution.id,
+                *financial_institution_deposit_accounts_parent_account_set_id,
+                old_integration_config.map(|config| {
+                    config.financial_institution_deposit_accounts_parent_account_set_id
+                }),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.frozen_deposit_account_sets.individual.id,
-            *frozen_individual_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.frozen_individual_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                deposit_account_sets.non_domiciled_company.id,
+                *non_domiciled_company_deposit_accounts_parent_account_set_id,
+                old_integration_config.map(|config| {
+                    config.non_domiciled_company_deposit_accounts_parent_account_set_id
+                }),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.frozen_deposit_account_sets.government_entity.id,
-            *frozen_government_entity_deposit_accounts_parent_account_set_id,
-            old_integration_config.map(|config| {
-                config.frozen_government_entity_deposit_accounts_parent_account_set_id
-            }),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                frozen_deposit_account_sets.individual.id,
+                *frozen_individual_deposit_accounts_parent_account_set_id,
+                old_integration_config
+                    .map(|config| config.frozen_individual_deposit_accounts_parent_account_set_id),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.frozen_deposit_account_sets.private_company.id,
-            *frozen_private_company_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.frozen_private_company_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                frozen_deposit_account_sets.government_entity.id,
+                *frozen_government_entity_deposit_accounts_parent_account_set_id,
+                old_integration_config.map(|config| {
+                    config.frozen_government_entity_deposit_accounts_parent_account_set_id
+                }),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.frozen_deposit_account_sets.bank.id,
-            *frozen_bank_deposit_accounts_parent_account_set_id,
-            old_integration_config
-                .map(|config| config.frozen_bank_deposit_accounts_parent_account_set_id),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                frozen_deposit_account_sets.private_company.id,
+                *frozen_private_company_deposit_accounts_parent_account_set_id,
+                old_integration_config.map(|config| {
+                    config.frozen_private_company_deposit_accounts_parent_account_set_id
+                }),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.frozen_deposit_account_sets.financial_institution.id,
-            *frozen_financial_institution_deposit_accounts_parent_account_set_id,
-            old_integration_config.map(|config| {
-                config.frozen_financial_institution_deposit_accounts_parent_account_set_id
-            }),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                frozen_deposit_account_sets.bank.id,
+                *frozen_bank_deposit_accounts_parent_account_set_id,
+                old_integration_config
+                    .map(|config| config.frozen_bank_deposit_accounts_parent_account_set_id),
+            )
+            .await?;
 
-        self.attach_charts_account_set_in_op(
-            op,
-            self.frozen_deposit_account_sets.non_domiciled_company.id,
-            *frozen_non_domiciled_company_deposit_accounts_parent_account_set_id,
-            old_integration_config.map(|config| {
-                config.frozen_non_domiciled_company_deposit_accounts_parent_account_set_id
-            }),
-        )
-        .await?;
+            self.attach_charts_account_set_in_op(
+                op,
+                frozen_deposit_account_sets.financial_institution.id,
+                *frozen_financial_institution_deposit_accounts_parent_account_set_id,
+                old_integration_config.map(|config| {
+                    config.frozen_financial_institution_deposit_accounts_parent_account_set_id
+                }),
+            )
+            .await?;
+
+            self.attach_charts_account_set_in_op(
+                op,
+                frozen_deposit_account_sets.non_domiciled_company.id,
+                *frozen_non_domiciled_company_deposit_accounts_parent_account_set_id,
+                old_integration_config.map(|config| {
+                    config.frozen_non_domiciled_company_deposit_accounts_parent_account_set_id
+                }),
+            )
+            .await?;
+        }
+
+        if let Some(interest_expense_parent_account_set_id) = interest_expense_parent_account_set_id
+        {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.interest_expense_account_ids.account_set_id,
+                *interest_expense_parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.interest_expense_parent_account_set_id),
+            )
+            .await?;
+        }
+
+        if let Some(fee_income_parent_account_set_id) = fee_income_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.fee_income_account_ids.account_set_id,
+                *fee_income_parent_account_set_id,
+                old_integration_config.and_then(|config| config.fee_income_parent_account_set_id),
+            )
+            .await?;
+        }
+
+        if let Some(unclaimed_property_parent_account_set_id) =
+            unclaimed_property_parent_account_set_id
+        {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.unclaimed_property_account_ids.account_set_id,
+                *unclaimed_property_parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.unclaimed_property_parent_account_set_id),
+            )
+            .await?;
+        }
+
+        if let Some(fx_position_parent_account_set_id) = fx_position_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.fx_position_account_ids.account_set_id,
+                *fx_position_parent_account_set_id,
+                old_integration_config.and_then(|config| config.fx_position_parent_account_set_id),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.attach_added_chart_of_accounts_account_sets_in_op",
+        skip_all
+    )]
+    pub(crate) async fn attach_added_chart_of_accounts_account_sets_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        new_integration_config: &ResolvedChartOfAccountsIntegrationConfig,
+        old_integration_config: &ResolvedChartOfAccountsIntegrationConfig,
+    ) -> Result<(), DepositLedgerError> {
+        if let (Some(interest_expense_parent_account_set_id), None) = (
+            new_integration_config.interest_expense_parent_account_set_id,
+            old_integration_config.interest_expense_parent_account_set_id,
+        ) {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.interest_expense_account_ids.account_set_id,
+                interest_expense_parent_account_set_id,
+                None,
+            )
+            .await?;
+        }
+
+        if let (Some(fee_income_parent_account_set_id), None) = (
+            new_integration_config.fee_income_parent_account_set_id,
+            old_integration_config.fee_income_parent_account_set_id,
+        ) {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.fee_income_account_ids.account_set_id,
+                fee_income_parent_account_set_id,
+                None,
+            )
+            .await?;
+        }
+
+        if let (Some(unclaimed_property_parent_account_set_id), None) = (
+            new_integration_config.unclaimed_property_parent_account_set_id,
+            old_integration_config.unclaimed_property_parent_account_set_id,
+        ) {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.unclaimed_property_account_ids.account_set_id,
+                unclaimed_property_parent_account_set_id,
+                None,
+            )
+            .await?;
+        }
+
+        if let (Some(fx_position_parent_account_set_id), None) = (
+            new_integration_config.fx_position_parent_account_set_id,
+            old_integration_config.fx_position_parent_account_set_id,
+        ) {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.fx_position_account_ids.account_set_id,
+                fx_position_parent_account_set_id,
+                None,
+            )
+            .await?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,154 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{
+    ledger::error::*,
+    primitives::{CalaAccountId, DEPOSIT_ACCOUNT_ENTITY_TYPE},
+};
+
+pub const CHARGE_DEPOSIT_FEE_CODE: &str = "CHARGE_DEPOSIT_FEE";
+
+#[derive(Debug)]
+pub struct ChargeDepositFeeParams<S: std::fmt::Display> {
+    pub entity_id: uuid::Uuid,
+    pub journal_id: JournalId,
+    pub fee_income_account_id: CalaAccountId,
+    pub deposit_account_id: CalaAccountId,
+    pub amount: Decimal,
+    pub currency: Currency,
+    pub initiated_by: S,
+    pub effective_date: chrono::NaiveDate,
+}
+
+impl<S: std::fmt::Display> ChargeDepositFeeParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("fee_income_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<ChargeDepositFeeParams<S>> for Params {
+    fn from(
+        ChargeDepositFeeParams {
+            entity_id,
+            journal_id,
+            fee_income_account_id,
+            deposit_account_id,
+            amount,
+            currency,
+            initiated_by,
+            effective_date,
+        }: ChargeDepositFeeParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("fee_income_account_id", fee_income_account_id);
+        params.insert("deposit_account_id", deposit_account_id);
+        params.insert("effective", effective_date);
+        let entity_ref = chart_primitives::EntityRef::new(DEPOSIT_ACCOUNT_ENTITY_TYPE, entity_id);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "entity_ref": entity_ref,
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+
+        params
+    }
+}
+
+pub struct ChargeDepositFee;
+
+impl ChargeDepositFee {
+    #[record_error_severity]
+    #[instrument(name = "ledger.charge_deposit_fee.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Charge a fee on a deposit account'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'CHARGE_DEPOSIT_FEE_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CHARGE_DEPOSIT_FEE_CR'")
+                .currency("params.currency")
+                .account_id("params.fee_income_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ChargeDepositFeeParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CHARGE_DEPOSIT_FEE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode(_)) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
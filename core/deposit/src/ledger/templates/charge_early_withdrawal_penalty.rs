@@ -0,0 +1,155 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{
+    ledger::error::*,
+    primitives::{CalaAccountId, WITHDRAWAL_TRANSACTION_ENTITY_TYPE},
+};
+
+pub const CHARGE_EARLY_WITHDRAWAL_PENALTY_CODE: &str = "CHARGE_EARLY_WITHDRAWAL_PENALTY";
+
+#[derive(Debug)]
+pub struct ChargeEarlyWithdrawalPenaltyParams<S: std::fmt::Display> {
+    pub entity_id: uuid::Uuid,
+    pub journal_id: JournalId,
+    pub interest_expense_account_id: CalaAccountId,
+    pub deposit_account_id: CalaAccountId,
+    pub amount: Decimal,
+    pub currency: Currency,
+    pub initiated_by: S,
+    pub effective_date: chrono::NaiveDate,
+}
+
+impl<S: std::fmt::Display> ChargeEarlyWithdrawalPenaltyParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("interest_expense_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<ChargeEarlyWithdrawalPenaltyParams<S>> for Params {
+    fn from(
+        ChargeEarlyWithdrawalPenaltyParams {
+            entity_id,
+            journal_id,
+            interest_expense_account_id,
+            deposit_account_id,
+            amount,
+            currency,
+            initiated_by,
+            effective_date,
+        }: ChargeEarlyWithdrawalPenaltyParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("interest_expense_account_id", interest_expense_account_id);
+        params.insert("deposit_account_id", deposit_account_id);
+        params.insert("effective", effective_date);
+        let entity_ref =
+            chart_primitives::EntityRef::new(WITHDRAWAL_TRANSACTION_ENTITY_TYPE, entity_id);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "entity_ref": entity_ref,
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+
+        params
+    }
+}
+
+pub struct ChargeEarlyWithdrawalPenalty;
+
+impl ChargeEarlyWithdrawalPenalty {
+    #[record_error_severity]
+    #[instrument(name = "ledger.charge_early_withdrawal_penalty.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Charge an early withdrawal penalty on a term deposit account'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'EARLY_WITHDRAWAL_PENALTY_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'EARLY_WITHDRAWAL_PENALTY_CR'")
+                .currency("params.currency")
+                .account_id("params.interest_expense_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ChargeEarlyWithdrawalPenaltyParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CHARGE_EARLY_WITHDRAWAL_PENALTY_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode(_)) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{
+    ledger::error::*,
+    primitives::{CalaAccountId, FX_CONVERSION_TRANSACTION_ENTITY_TYPE},
+};
+
+pub const FX_CONVERSION_CODE: &str = "FX_CONVERSION";
+
+#[derive(Debug)]
+pub struct FxConversionParams<S: std::fmt::Display> {
+    pub entity_id: uuid::Uuid,
+    pub journal_id: JournalId,
+    pub source_account_id: CalaAccountId,
+    pub target_account_id: CalaAccountId,
+    pub fx_position_account_id: CalaAccountId,
+    pub source_amount: Decimal,
+    pub source_currency: Currency,
+    pub target_amount: Decimal,
+    pub target_currency: Currency,
+    pub rate: Decimal,
+    pub initiated_by: S,
+    pub effective_date: chrono::NaiveDate,
+}
+
+impl<S: std::fmt::Display> FxConversionParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("source_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("target_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("fx_position_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("source_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("source_currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("target_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("target_currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<FxConversionParams<S>> for Params {
+    fn from(
+        FxConversionParams {
+            entity_id,
+            journal_id,
+            source_account_id,
+            target_account_id,
+            fx_position_account_id,
+            source_amount,
+            source_currency,
+            target_amount,
+            target_currency,
+            rate,
+            initiated_by,
+            effective_date,
+        }: FxConversionParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+
+        params.insert("journal_id", journal_id);
+        params.insert("source_account_id", source_account_id);
+        params.insert("target_account_id", target_account_id);
+        params.insert("fx_position_account_id", fx_position_account_id);
+        params.insert("source_amount", source_amount);
+        params.insert("source_currency", source_currency);
+        params.insert("target_amount", target_amount);
+        params.insert("target_currency", target_currency);
+        params.insert("effective", effective_date);
+        let entity_ref =
+            chart_primitives::EntityRef::new(FX_CONVERSION_TRANSACTION_ENTITY_TYPE, entity_id);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "entity_ref": entity_ref,
+                "rate": rate,
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+
+        params
+    }
+}
+
+pub struct FxConversion;
+
+impl FxConversion {
+    #[record_error_severity]
+    #[instrument(name = "ledger.fx_conversion.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Currency conversion between deposit accounts'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'FX_CONVERSION_SOURCE_DR'")
+                .currency("params.source_currency")
+                .account_id("params.source_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.source_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'FX_CONVERSION_SOURCE_CR'")
+                .currency("params.source_currency")
+                .account_id("params.fx_position_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.source_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'FX_CONVERSION_TARGET_DR'")
+                .currency("params.target_currency")
+                .account_id("params.fx_position_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.target_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'FX_CONVERSION_TARGET_CR'")
+                .currency("params.target_currency")
+                .account_id("params.target_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.target_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = FxConversionParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(FX_CONVERSION_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode(_)) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -1,19 +1,41 @@
+mod accrue_deposit_interest;
 mod cancel_withdraw;
+mod charge_deposit_fee;
+mod charge_early_withdrawal_penalty;
 mod confirm_withdraw;
 mod deny_withdraw;
+mod escheat_deposit_balance;
 mod freeze_account;
+mod fx_conversion;
 mod initiate_withdraw;
+mod place_hold;
+mod post_deposit_interest;
+mod reclaim_escheated_balance;
 mod record_deposit;
+mod release_hold;
+mod reverse_deposit_fee;
 mod revert_deposit;
 mod revert_withdraw;
+mod transfer;
 mod unfreeze_account;
 
+pub use accrue_deposit_interest::*;
 pub use cancel_withdraw::*;
+pub use charge_deposit_fee::*;
+pub use charge_early_withdrawal_penalty::*;
 pub use confirm_withdraw::*;
 pub use deny_withdraw::*;
+pub use escheat_deposit_balance::*;
 pub use freeze_account::*;
+pub use fx_conversion::*;
 pub use initiate_withdraw::*;
+pub use place_hold::*;
+pub use post_deposit_interest::*;
+pub use reclaim_escheated_balance::*;
 pub use record_deposit::*;
+pub use release_hold::*;
+pub use reverse_deposit_fee::*;
 pub use revert_deposit::*;
 pub use revert_withdraw::*;
+pub use transfer::*;
 pub use unfreeze_account::*;
@@ -2,31 +2,45 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
 mod account;
+mod account_closure;
+mod account_statement;
+mod beneficiary;
 mod chart_of_accounts_integration;
 mod config;
 mod deposit;
 mod deposit_account_balance;
+mod deposit_import;
 pub mod error;
+mod escheatment;
+mod fee;
 mod for_subject;
+mod fx_conversion;
 mod history;
+mod hold;
 mod ledger;
+mod payment_file;
 mod primitives;
 mod processes;
+mod product;
 pub mod public;
 mod publisher;
+mod transfer;
 mod withdrawal;
+mod withdrawal_limit;
 
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use tracing::instrument;
 use tracing_macros::record_error_severity;
 
-use audit::AuditSvc;
+use audit::{AuditSvc, SystemSubject};
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerId, CustomerObject, Customers};
+use document_storage::{DocumentStorage, GeneratedDocumentDownloadLink};
 use domain_config::{ExposedDomainConfigsReadOnly, InternalDomainConfigs};
+use es_entity::{AtomicOperation, clock::ClockHandle};
 use governance::{Governance, GovernanceEvent};
 use job::Jobs;
 use obix::out::{Outbox, OutboxEventJobConfig, OutboxEventMarker};
@@ -34,42 +48,104 @@ use public_id::PublicIds;
 
 use account::*;
 pub use account::{
-    DepositAccount, DepositAccountsByCreatedAtCursor, DepositAccountsCursor,
+    DepositAccount, DepositAccountHolder, DepositAccountsByCreatedAtCursor, DepositAccountsCursor,
     DepositAccountsFilters, DepositAccountsSortBy, error::DepositAccountError,
 };
+use account_closure::*;
+pub use account_closure::{
+    AccountClosure, AccountClosureHoldTreatment, AccountClosurePayout,
+    AccountClosurePayoutTransaction, AccountClosureStatus, AccountClosuresByCreatedAtCursor,
+    error::AccountClosureError,
+};
+use account_statement::*;
+pub use account_statement::{
+    DepositAccountStatement, DepositAccountStatementFormat, error::DepositAccountStatementError,
+};
+use beneficiary::*;
+pub use beneficiary::{
+    BankAccountDetails, BeneficiariesByCreatedAtCursor, Beneficiary, BeneficiaryDestination,
+    BeneficiaryStatus, CryptoAddressDetails, CryptoNetwork, error::BeneficiaryError,
+};
 use chart_of_accounts_integration::ChartOfAccountsIntegrations;
 pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrationConfig, error::ChartOfAccountsIntegrationError,
 };
 use config::*;
-pub use config::{DepositActivityEscheatableThresholdDays, DepositActivityInactiveThresholdDays};
+pub use config::{
+    DepositActivityEscheatableThresholdDays, DepositActivityInactiveThresholdDays,
+    DepositBeneficiaryCoolingOffHours, DepositEscheatmentWaitingPeriodDays,
+    DepositJointAccountSuspendHolderOnFreeze, DepositTransferApprovalThresholdCents,
+};
 use deposit::*;
 pub use deposit::{
     Deposit, DepositsByCreatedAtCursor, DepositsCursor, DepositsFilters, DepositsSortBy,
 };
-pub use deposit_account_balance::DepositAccountBalance;
+pub use deposit_account_balance::{DepositAccountBalance, DepositAccountCurrencyBalance};
+use deposit_import::*;
+pub use deposit_import::{
+    DepositImport, DepositImportRow, DepositImportRowError, DepositImportRowOutcome,
+    DepositImportStatus, DepositImportsByCreatedAtCursor, error::DepositImportError,
+};
 use error::*;
+pub use escheatment::EscheatmentRemittanceEntry;
+use fee::*;
+pub use fee::{DepositFeeSchedule, DepositFeeType};
 pub use for_subject::DepositsForSubject;
+use fx_conversion::*;
+pub use fx_conversion::{FxConversion, FxConversionsByCreatedAtCursor, error::FxConversionError};
 pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
+use hold::*;
+pub use hold::{Hold, HoldStatus, HoldsByCreatedAtCursor};
 use ledger::*;
+use payment_file::*;
+pub use payment_file::{
+    PaymentFile, PaymentFileStatus, PaymentFilesByCreatedAtCursor, PaymentOriginator,
+    error::PaymentFileError,
+};
 pub use primitives::*;
-pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
+pub use processes::approval::{
+    APPROVE_TRANSFER_PROCESS, APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS, APPROVE_WITHDRAWAL_PROCESS,
+};
 use processes::approval::{
-    ApproveWithdrawal, ExecuteWithdrawApprovalJobInitializer, WITHDRAW_APPROVE_JOB,
-    WithdrawApprovalHandler,
+    ApproveTransfer, ApproveWithdrawal, ApproveWithdrawalLimitIncrease,
+    ExecuteTransferApprovalJobInitializer, ExecuteWithdrawApprovalJobInitializer,
+    ExecuteWithdrawalLimitIncreaseApprovalJobInitializer, TRANSFER_APPROVE_JOB,
+    TransferApprovalHandler, WITHDRAW_APPROVE_JOB, WITHDRAWAL_LIMIT_INCREASE_APPROVE_JOB,
+    WithdrawApprovalHandler, WithdrawalLimitIncreaseApprovalHandler,
+};
+use product::*;
+pub use product::{
+    DepositProduct, DepositProductValues, DepositProductValuesBuilder, DepositProductsByNameCursor,
+    DepositRatePct, InterestFrequency, TermDepositTerms, error::DepositProductError,
 };
 pub use public::*;
 use publisher::DepositPublisher;
+use transfer::*;
+pub use transfer::{
+    Transfer, TransferStatus, TransfersByCreatedAtCursor, TransfersCursor, TransfersFilters,
+    TransfersSortBy,
+};
 use withdrawal::*;
 pub use withdrawal::{
     Withdrawal, WithdrawalStatus, WithdrawalsByAmountCursor, WithdrawalsByCreatedAtCursor,
     WithdrawalsCursor, WithdrawalsFilters, WithdrawalsSortBy,
 };
+use withdrawal_limit::*;
+pub use withdrawal_limit::{WithdrawalLimitPeriod, WithdrawalLimits};
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
     pub use crate::account::DepositAccountEvent;
+    pub use crate::account_closure::AccountClosureEvent;
+    pub use crate::account_statement::DepositAccountStatementEvent;
+    pub use crate::beneficiary::BeneficiaryEvent;
     pub use crate::deposit::DepositEvent;
+    pub use crate::deposit_import::DepositImportEvent;
+    pub use crate::fx_conversion::FxConversionEvent;
+    pub use crate::hold::HoldEvent;
+    pub use crate::payment_file::PaymentFileEvent;
+    pub use crate::product::DepositProductEvent;
+    pub use crate::transfer::TransferEvent;
     pub use crate::withdrawal::WithdrawalEvent;
 }
 
@@ -83,7 +159,20 @@ where
     deposit_accounts: DepositAccountRepo<E>,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    transfers: TransferRepo<E>,
+    fx_conversions: FxConversionRepo,
+    holds: HoldRepo<E>,
+    beneficiaries: BeneficiaryRepo,
+    payment_files: PaymentFileRepo,
+    deposit_imports: DepositImportRepo<E>,
+    account_closures: AccountClosureRepo,
+    account_statements: DepositAccountStatements<E>,
+    deposit_products: DepositProductRepo,
+    process_deposit_import_spawner: ProcessDepositImportJobSpawner,
+    settle_account_closure_spawner: SettleAccountClosureJobSpawner,
+    generate_account_statement_spawner: GenerateDepositAccountStatementJobSpawner,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
+    approve_transfer: ApproveTransfer<Perms, E>,
     ledger: Arc<DepositLedger>,
     cala: CalaLedger,
     authz: Arc<Perms>,
@@ -93,6 +182,9 @@ where
     customers: Customers<Perms, E>,
     chart_of_accounts_integrations: Arc<ChartOfAccountsIntegrations<Perms>>,
     domain_configs: ExposedDomainConfigsReadOnly,
+    internal_domain_configs: Arc<InternalDomainConfigs>,
+    document_storage: DocumentStorage,
+    clock: ClockHandle,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -107,16 +199,32 @@ where
             deposit_accounts: self.deposit_accounts.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            transfers: self.transfers.clone(),
+            fx_conversions: self.fx_conversions.clone(),
+            holds: self.holds.clone(),
+            beneficiaries: self.beneficiaries.clone(),
+            payment_files: self.payment_files.clone(),
+            deposit_imports: self.deposit_imports.clone(),
+            account_closures: self.account_closures.clone(),
+            account_statements: self.account_statements.clone(),
+            deposit_products: self.deposit_products.clone(),
+            process_deposit_import_spawner: self.process_deposit_import_spawner.clone(),
+            settle_account_closure_spawner: self.settle_account_closure_spawner.clone(),
+            generate_account_statement_spawner: self.generate_account_statement_spawner.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
+            approve_transfer: self.approve_transfer.clone(),
             outbox: self.outbox.clone(),
             public_ids: self.public_ids.clone(),
             customers: self.customers.clone(),
             chart_of_accounts_integrations: self.chart_of_accounts_integrations.clone(),
             domain_configs: self.domain_configs.clone(),
+            internal_domain_configs: self.internal_domain_configs.clone(),
+            document_storage: self.document_storage.clone(),
+            clock: self.clock.clone(),
         }
     }
 }
@@ -146,6 +254,8 @@ where
         customers: &Customers<Perms, E>,
         domain_configs: &ExposedDomainConfigsReadOnly,
         internal_domain_configs: &InternalDomainConfigs,
+        document_storage: &DocumentStorage,
+        gotenberg_config: gotenberg::GotenbergConfig,
     ) -> Result<Self, CoreDepositError> {
         let clock = jobs.clock().clone();
 
@@ -155,6 +265,14 @@ where
         let accounts = DepositAccountRepo::new(pool, &publisher, clock.clone());
         let deposits = DepositRepo::new(pool, &publisher, clock.clone());
         let withdrawals = WithdrawalRepo::new(pool, &publisher, clock.clone());
+        let transfers = TransferRepo::new(pool, &publisher, clock.clone());
+        let fx_conversions = FxConversionRepo::new(pool, clock.clone());
+        let holds = HoldRepo::new(pool, &publisher, clock.clone());
+        let beneficiaries = BeneficiaryRepo::new(pool, clock.clone());
+        let payment_files = PaymentFileRepo::new(pool, clock.clone());
+        let deposit_imports = DepositImportRepo::new(pool, &publisher, clock.clone());
+        let account_closures = AccountClosureRepo::new(pool, clock.clone());
+        let deposit_products = DepositProductRepo::new(pool, clock.clone());
         let ledger = DepositLedger::init(cala, journal_id, clock.clone()).await?;
         let ledger_arc = Arc::new(ledger);
         let internal_domain_configs_arc = Arc::new(internal_domain_configs.clone());
@@ -176,6 +294,87 @@ where
 
         governance.init_policy(APPROVE_WITHDRAWAL_PROCESS).await?;
 
+        let approve_transfer = ApproveTransfer::new(&transfers, authz.audit(), ledger_arc.as_ref());
+
+        let execute_transfer_approval_spawner = jobs.add_initializer(
+            ExecuteTransferApprovalJobInitializer::new(&approve_transfer),
+        );
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(TRANSFER_APPROVE_JOB),
+                TransferApprovalHandler::new(execute_transfer_approval_spawner),
+            )
+            .await?;
+
+        governance.init_policy(APPROVE_TRANSFER_PROCESS).await?;
+
+        let approve_withdrawal_limit_increase =
+            ApproveWithdrawalLimitIncrease::<Perms, E>::new(&accounts, authz.audit());
+
+        let execute_withdrawal_limit_increase_approval_spawner =
+            jobs.add_initializer(ExecuteWithdrawalLimitIncreaseApprovalJobInitializer::new(
+                &approve_withdrawal_limit_increase,
+            ));
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(WITHDRAWAL_LIMIT_INCREASE_APPROVE_JOB),
+                WithdrawalLimitIncreaseApprovalHandler::new(
+                    execute_withdrawal_limit_increase_approval_spawner,
+                ),
+            )
+            .await?;
+
+        governance
+            .init_policy(APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS)
+            .await?;
+
+        let process_deposit_import_spawner =
+            jobs.add_initializer(ProcessDepositImportJobInitializer::<Perms, E>::new(
+                &deposit_imports,
+                &accounts,
+                &deposits,
+                ledger_arc.as_ref(),
+                public_ids,
+                authz.audit(),
+            ));
+
+        let settle_account_closure_spawner =
+            jobs.add_initializer(SettleAccountClosureJobInitializer::<Perms, E>::new(
+                &account_closures,
+                &accounts,
+                ledger_arc.as_ref(),
+                document_storage,
+                authz.audit(),
+                clock.clone(),
+            ));
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(ACCOUNT_CLOSURE_SETTLEMENT_JOB),
+                AccountClosureSettlementHandler::new(
+                    &account_closures,
+                    settle_account_closure_spawner.clone(),
+                ),
+            )
+            .await?;
+
+        let account_statements = DepositAccountStatements::new(
+            pool,
+            &accounts,
+            ledger_arc.as_ref(),
+            document_storage,
+            gotenberg_config,
+            clock.clone(),
+        );
+        let generate_account_statement_spawner = jobs.add_initializer(
+            GenerateDepositAccountStatementJobInitializer::new(&account_statements),
+        );
+
         let chart_of_accounts_integrations = ChartOfAccountsIntegrations::new(
             authz_arc.clone(),
             ledger_arc.clone(),
@@ -187,16 +386,32 @@ where
             deposit_accounts: accounts,
             deposits,
             withdrawals,
+            transfers,
+            fx_conversions,
+            holds,
+            beneficiaries,
+            payment_files,
+            deposit_imports,
+            account_closures,
+            account_statements,
+            deposit_products,
+            process_deposit_import_spawner,
+            settle_account_closure_spawner,
+            generate_account_statement_spawner,
             authz: authz_arc,
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
+            approve_transfer,
             ledger: ledger_arc,
             public_ids: public_ids.clone(),
             customers: customers.clone(),
             chart_of_accounts_integrations: chart_of_accounts_integrations_arc.clone(),
             domain_configs: domain_configs.clone(),
+            internal_domain_configs: internal_domain_configs_arc,
+            document_storage: document_storage.clone(),
+            clock,
         };
         Ok(res)
     }
@@ -221,6 +436,8 @@ where
             &self.deposit_accounts,
             &self.deposits,
             &self.withdrawals,
+            &self.transfers,
+            &self.account_statements,
             &self.ledger,
             &self.authz,
         ))
@@ -232,6 +449,18 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         holder_id: impl Into<DepositAccountHolderId> + Copy + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        self.create_account_in_currency(sub, holder_id, CurrencyCode::USD)
+            .await
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.create_account_in_currency", skip(self))]
+    pub async fn create_account_in_currency(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        holder_id: impl Into<DepositAccountHolderId> + Copy + std::fmt::Debug,
+        currency: CurrencyCode,
     ) -> Result<DepositAccount, CoreDepositError> {
         self.authz
             .enforce_permission(
@@ -241,6 +470,10 @@ where
             )
             .await?;
 
+        if !DEPOSIT_ACCOUNT_CURRENCIES.contains(&currency) {
+            return Err(CoreDepositError::UnsupportedCurrency(currency));
+        }
+
         let customer_id = CustomerId::from(holder_id.into());
 
         let account_id = DepositAccountId::new();
@@ -263,6 +496,7 @@ where
             .account_holder_id(holder_id)
             .account_ids(account_ids)
             .public_id(public_id.id)
+            .currency(currency)
             .build()
             .expect("Could not build new account");
 
@@ -365,10 +599,31 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reference: Option<String>,
+    ) -> Result<Deposit, CoreDepositError> {
+        self.record_deposit_with_channel(
+            sub,
+            deposit_account_id,
+            amount,
+            DepositChannel::default(),
+            reference,
+        )
+        .await
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.record_deposit_with_channel", skip(self))]
+    pub async fn record_deposit_with_channel(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        channel: DepositChannel,
         reference: Option<String>,
     ) -> Result<Deposit, CoreDepositError> {
         let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
         self.authz
             .enforce_permission(
                 sub,
@@ -377,6 +632,8 @@ where
             )
             .await?;
         self.check_account_active(deposit_account_id).await?;
+        self.check_account_currency(deposit_account_id, amount.currency())
+            .await?;
         let deposit_id = DepositId::new();
         let mut op = self.deposits.begin_op().await?;
         let public_id = self
@@ -388,13 +645,22 @@ where
             .id(deposit_id)
             .ledger_transaction_id(deposit_id)
             .deposit_account_id(deposit_account_id)
-            .amount(amount)
+            .amount(amount.minor_units())
+            .currency(amount.currency())
+            .channel(channel)
             .public_id(public_id.id)
             .reference(reference)
             .build()?;
         let deposit = self.deposits.create_in_op(&mut op, new_deposit).await?;
         self.ledger
-            .record_deposit_in_op(&mut op, deposit_id, amount, deposit_account_id, sub)
+            .record_deposit_in_op(
+                &mut op,
+                deposit_id,
+                amount,
+                deposit_account_id,
+                self.clock.today(),
+                sub,
+            )
             .await?;
         op.commit().await?;
         Ok(deposit)
@@ -406,10 +672,12 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
+        beneficiary_id: impl Into<BeneficiaryId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
         reference: Option<String>,
     ) -> Result<Withdrawal, CoreDepositError> {
         let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
         self.authz
             .enforce_permission(
                 sub,
@@ -418,25 +686,78 @@ where
             )
             .await?;
         self.check_account_active(deposit_account_id).await?;
-        let withdrawal_id = WithdrawalId::new();
+        let account = self.deposit_accounts.find_by_id(deposit_account_id).await?;
+        account.ensure_currency(amount.currency())?;
+        let beneficiary = self.beneficiaries.find_by_id(beneficiary_id.into()).await?;
+        beneficiary.ensure_usable_by(&account.holder_ids(), self.clock.now())?;
+        let penalty = self
+            .early_withdrawal_penalty(&account, UsdCents::from(amount.minor_units()))
+            .await?
+            .unwrap_or(UsdCents::ZERO);
+        let fee = self
+            .fee_schedule_for(&account)
+            .await?
+            .and_then(|schedule| schedule.fee_for(DepositFeeType::Withdrawal))
+            .unwrap_or(UsdCents::ZERO);
+        let withdrawal_limits = if account.currency == CurrencyCode::USD {
+            self.withdrawal_limits_for(&account).await?
+        } else {
+            WithdrawalLimits::default()
+        };
+        // Penalties and fees are only charged on USD accounts, so they are always in
+        // the currency of the withdrawal.
+        let required =
+            amount.checked_add(Money::new(amount.currency(), (penalty + fee).into_inner()))?;
         let mut op = self.withdrawals.begin_op().await?;
+        self.check_available_balance_in_op(&mut op, deposit_account_id, required)
+            .await?;
+        self.check_withdrawal_limits_in_op(
+            &mut op,
+            deposit_account_id,
+            UsdCents::from(amount.minor_units()),
+            withdrawal_limits,
+        )
+        .await?;
+        let withdrawal = self
+            .create_withdrawal_in_op(&mut op, sub, &account, beneficiary, amount, reference)
+            .await?;
+        op.commit().await?;
+
+        Ok(withdrawal)
+    }
+
+    async fn create_withdrawal_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account: &DepositAccount,
+        beneficiary: Beneficiary,
+        amount: Money,
+        reference: Option<String>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let deposit_account_id = account.id;
+        let withdrawal_id = WithdrawalId::new();
         let public_id = self
             .public_ids
-            .create_in_op(&mut op, WITHDRAWAL_REF_TARGET, withdrawal_id)
+            .create_in_op(&mut *op, WITHDRAWAL_REF_TARGET, withdrawal_id)
             .await?;
 
         let new_withdrawal = NewWithdrawal::builder()
             .id(withdrawal_id)
             .deposit_account_id(deposit_account_id)
-            .amount(amount)
+            .amount(amount.minor_units())
+            .currency(amount.currency())
             .approval_process_id(withdrawal_id)
             .public_id(public_id.id)
+            .beneficiary_id(beneficiary.id)
+            .destination(beneficiary.destination)
+            .required_signers(account.required_withdrawal_signers())
             .reference(reference)
             .build()?;
 
         self.governance
             .start_process_in_op(
-                &mut op,
+                &mut *op,
                 withdrawal_id,
                 withdrawal_id.to_string(),
                 APPROVE_WITHDRAWAL_PROCESS,
@@ -444,199 +765,2220 @@ where
             .await?;
         let withdrawal = self
             .withdrawals
-            .create_in_op(&mut op, new_withdrawal)
+            .create_in_op(&mut *op, new_withdrawal)
             .await?;
 
         self.ledger
-            .initiate_withdrawal_in_op(&mut op, withdrawal_id, amount, deposit_account_id, sub)
+            .initiate_withdrawal_in_op(&mut *op, withdrawal_id, amount, deposit_account_id, sub)
             .await?;
 
-        op.commit().await?;
-
         Ok(withdrawal)
     }
 
     #[record_error_severity]
-    #[instrument(name = "deposit.revert_deposit", skip(self))]
-    pub async fn revert_deposit(
+    #[instrument(name = "deposit.initiate_transfer", skip(self))]
+    pub async fn initiate_transfer(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-        deposit_id: impl Into<DepositId> + std::fmt::Debug,
-    ) -> Result<Deposit, CoreDepositError> {
-        let id = deposit_id.into();
+        from_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        to_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
         self.authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::deposit(id),
-                CoreDepositAction::DEPOSIT_REVERT,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_INITIATE,
             )
             .await?;
+        self.create_transfer(
+            sub,
+            from_account_id.into(),
+            to_account_id.into(),
+            amount.into(),
+            reference,
+        )
+        .await
+    }
 
-        let mut op = self.deposits.begin_op().await?;
-        let mut deposit = self.deposits.find_by_id_in_op(&mut op, id).await?;
-        self.check_account_active(deposit.deposit_account_id)
+    #[record_error_severity]
+    #[instrument(name = "deposit.request_transfer", skip(self))]
+    pub async fn request_transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        to_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError>
+    where
+        DepositAccountHolderId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let from_account_id = from_account_id.into();
+        let holder_id = DepositAccountHolderId::try_from(sub)
+            .map_err(|_| CoreDepositError::SubjectIsNotDepositAccountHolder)?;
+        let account = self.deposit_accounts.find_by_id(from_account_id).await?;
+        let is_holder = account.has_holder(holder_id);
+        self.authz
+            .audit()
+            .record_entry(
+                sub,
+                CoreDepositObject::deposit_account(from_account_id),
+                CoreDepositAction::TRANSFER_INITIATE,
+                is_holder,
+            )
             .await?;
-
-        if let es_entity::Idempotent::Executed(deposit_reversal_data) = deposit.revert() {
-            self.deposits.update_in_op(&mut op, &mut deposit).await?;
-            self.ledger
-                .revert_deposit_in_op(&mut op, deposit_reversal_data, sub)
-                .await?;
-            op.commit().await?;
+        if !is_holder {
+            return Err(CoreDepositError::DepositAccountNotFound);
         }
-
-        Ok(deposit)
+        if !account.can_be_operated_alone_by(holder_id) {
+            return Err(DepositAccountError::HolderCannotActAlone(account.id, holder_id).into());
+        }
+        self.create_transfer(
+            sub,
+            from_account_id,
+            to_account_id.into(),
+            amount.into(),
+            reference,
+        )
+        .await
     }
 
-    #[record_error_severity]
-    #[instrument(name = "deposit.revert_withdrawal", skip(self))]
-    pub async fn revert_withdrawal(
+    async fn create_transfer(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-        withdrawal_id: impl Into<WithdrawalId> + std::fmt::Debug,
-    ) -> Result<Withdrawal, CoreDepositError> {
-        let id = withdrawal_id.into();
-        self.authz
-            .enforce_permission(
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: Money,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        self.check_account_active(from_account_id).await?;
+        self.check_account_active(to_account_id).await?;
+        self.check_account_currency(from_account_id, amount.currency())
+            .await?;
+        self.check_account_currency(to_account_id, amount.currency())
+            .await?;
+
+        let mut op = self.transfers.begin_op().await?;
+        self.check_available_balance_in_op(&mut op, from_account_id, amount)
+            .await?;
+        let transfer = self
+            .create_transfer_in_op(
+                &mut op,
                 sub,
-                CoreDepositObject::withdrawal(id),
-                CoreDepositAction::WITHDRAWAL_REVERT,
+                from_account_id,
+                to_account_id,
+                amount,
+                reference,
             )
             .await?;
+        op.commit().await?;
 
-        let mut op = self.withdrawals.begin_op().await?;
-        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut op, id).await?;
+        Ok(transfer)
+    }
 
-        self.check_account_active(withdrawal.deposit_account_id)
+    async fn create_transfer_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: Money,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        // The approval threshold is configured in USD cents and only applies to USD transfers.
+        let requires_approval = amount.currency() == CurrencyCode::USD
+            && self
+                .domain_configs
+                .get_without_audit::<DepositTransferApprovalThresholdCents>()
+                .await?
+                .maybe_value()
+                .is_some_and(|threshold| amount.minor_units() > threshold);
+
+        let transfer_id = TransferId::new();
+        let public_id = self
+            .public_ids
+            .create_in_op(&mut *op, TRANSFER_REF_TARGET, transfer_id)
             .await?;
 
-        if let Ok(es_entity::Idempotent::Executed(withdrawal_reversal_data)) = withdrawal.revert() {
-            self.withdrawals
-                .update_in_op(&mut op, &mut withdrawal)
+        let new_transfer = NewTransfer::builder()
+            .id(transfer_id)
+            .from_account_id(from_account_id)
+            .to_account_id(to_account_id)
+            .amount(amount.minor_units())
+            .currency(amount.currency())
+            .approval_process_id(requires_approval.then(|| transfer_id.into()))
+            .public_id(public_id.id)
+            .reference(reference)
+            .build()?;
+
+        if requires_approval {
+            self.governance
+                .start_process_in_op(
+                    &mut *op,
+                    transfer_id,
+                    transfer_id.to_string(),
+                    APPROVE_TRANSFER_PROCESS,
+                )
                 .await?;
+        }
+
+        let mut transfer = self.transfers.create_in_op(&mut *op, new_transfer).await?;
+
+        if requires_approval && let es_entity::Idempotent::Executed(tx_id) = transfer.hold_funds() {
+            self.transfers.update_in_op(&mut *op, &mut transfer).await?;
             self.ledger
-                .revert_withdrawal_in_op(&mut op, withdrawal_reversal_data, sub)
+                .hold_transfer_funds_in_op(
+                    &mut *op,
+                    tx_id,
+                    transfer.id,
+                    transfer.money(),
+                    transfer.from_account_id,
+                    sub,
+                )
                 .await?;
-            op.commit().await?;
         }
 
-        Ok(withdrawal)
+        if !requires_approval && transfer.complete()?.did_execute() {
+            self.transfers.update_in_op(&mut *op, &mut transfer).await?;
+            self.ledger
+                .transfer_in_op(
+                    &mut *op,
+                    transfer.id,
+                    transfer.money(),
+                    transfer.from_account_id,
+                    transfer.to_account_id,
+                    sub,
+                )
+                .await?;
+        }
+
+        Ok(transfer)
     }
 
+    /// Moves funds between two accounts of the same holder held in different currencies.
+    /// The caller supplies the rate as units of the target currency per unit of the source.
     #[record_error_severity]
-    #[instrument(name = "deposit.confirm_withdrawal", skip(self))]
-    pub async fn confirm_withdrawal(
+    #[instrument(name = "deposit.convert_currency", skip(self))]
+    pub async fn convert_currency(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-        withdrawal_id: impl Into<WithdrawalId> + std::fmt::Debug,
-    ) -> Result<Withdrawal, CoreDepositError> {
-        let id = withdrawal_id.into();
+        source_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        target_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        source_amount: Money,
+        rate: rust_decimal::Decimal,
+        reference: Option<String>,
+    ) -> Result<FxConversion, CoreDepositError> {
+        let source_account_id = source_account_id.into();
+        let target_account_id = target_account_id.into();
         self.authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::withdrawal(id),
-                CoreDepositAction::WITHDRAWAL_CONFIRM,
+                CoreDepositObject::all_fx_conversions(),
+                CoreDepositAction::FX_CONVERSION_CREATE,
             )
             .await?;
-        let mut op = self.withdrawals.begin_op().await?;
-        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut op, id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
+        self.check_account_active(source_account_id).await?;
+        self.check_account_active(target_account_id).await?;
+
+        let source_account = self.deposit_accounts.find_by_id(source_account_id).await?;
+        let target_account = self.deposit_accounts.find_by_id(target_account_id).await?;
+        if source_account.account_holder_id != target_account.account_holder_id {
+            return Err(FxConversionError::AccountHolderMismatch.into());
+        }
+        source_account.ensure_currency(source_amount.currency())?;
+        let target_amount = convert_amount(source_amount, target_account.currency, rate)?;
+
+        let new_fx_conversion = NewFxConversion::builder()
+            .id(FxConversionId::new())
+            .source_account_id(source_account_id)
+            .target_account_id(target_account_id)
+            .source_amount(source_amount)
+            .target_amount(target_amount)
+            .rate(rate)
+            .reference(reference)
+            .build()?;
+
+        let mut op = self.fx_conversions.begin_op().await?;
+        self.check_available_balance_in_op(&mut op, source_account_id, source_amount)
             .await?;
-        let es_entity::Idempotent::Executed(tx_id) = withdrawal.confirm()? else {
-            return Ok(withdrawal);
-        };
-        self.withdrawals
-            .update_in_op(&mut op, &mut withdrawal)
+        let fx_conversion = self
+            .fx_conversions
+            .create_in_op(&mut op, new_fx_conversion)
             .await?;
-
         self.ledger
-            .confirm_withdrawal_in_op(
+            .fx_conversion_in_op(
                 &mut op,
-                id,
-                tx_id,
-                withdrawal.id.to_string(),
-                withdrawal.amount,
-                withdrawal.deposit_account_id,
-                format!("lana:withdraw:{}:confirm", withdrawal.id),
+                fx_conversion.id,
+                fx_conversion.source_amount,
+                fx_conversion.target_amount,
+                fx_conversion.rate,
+                source_account_id,
+                target_account_id,
                 sub,
             )
             .await?;
-
         op.commit().await?;
 
-        Ok(withdrawal)
+        Ok(fx_conversion)
     }
 
     #[record_error_severity]
-    #[instrument(name = "deposit.cancel_withdrawal", skip(self))]
-    #[es_entity::retry_on_concurrent_modification(any_error = true)]
-    pub async fn cancel_withdrawal(
+    #[instrument(name = "deposit.find_fx_conversion_by_id", skip(self))]
+    pub async fn find_fx_conversion_by_id(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-        withdrawal_id: impl es_entity::RetryableInto<WithdrawalId>,
-    ) -> Result<Withdrawal, CoreDepositError> {
-        let id = withdrawal_id.into();
+        id: impl Into<FxConversionId> + std::fmt::Debug,
+    ) -> Result<Option<FxConversion>, CoreDepositError> {
+        let id = id.into();
         self.authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::withdrawal(id),
-                CoreDepositAction::WITHDRAWAL_CANCEL,
+                CoreDepositObject::fx_conversion(id),
+                CoreDepositAction::FX_CONVERSION_READ,
             )
             .await?;
-        let mut op = self.withdrawals.begin_op().await?;
-        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut op, id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
-            .await?;
-        let es_entity::Idempotent::Executed(tx_id) = withdrawal.cancel()? else {
-            return Ok(withdrawal);
-        };
-        self.withdrawals
-            .update_in_op(&mut op, &mut withdrawal)
-            .await?;
-        self.ledger
-            .cancel_withdrawal_in_op(
-                &mut op,
-                id,
-                tx_id,
-                withdrawal.amount,
-                withdrawal.deposit_account_id,
+
+        Ok(self.fx_conversions.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_fx_conversions_authorized", skip(self))]
+    pub async fn find_all_fx_conversions_authorized<T: From<FxConversion>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[FxConversionId],
+    ) -> Result<std::collections::HashMap<FxConversionId, T>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
                 sub,
+                CoreDepositObject::all_fx_conversions(),
+                CoreDepositAction::FX_CONVERSION_READ,
             )
             .await?;
-        op.commit().await?;
-        Ok(withdrawal)
+        Ok(self.fx_conversions.find_all(ids).await?)
     }
 
     #[record_error_severity]
-    #[instrument(name = "deposit.freeze_account", skip(self))]
-    pub async fn freeze_account(
+    #[instrument(name = "deposit.list_fx_conversions_for_account", skip(self))]
+    pub async fn list_fx_conversions_for_account(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-    ) -> Result<DepositAccount, CoreDepositError> {
+    ) -> Result<Vec<FxConversion>, CoreDepositError> {
         let account_id = account_id.into();
         self.authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::deposit_account(account_id),
-                CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+                CoreDepositObject::all_fx_conversions(),
+                CoreDepositAction::FX_CONVERSION_LIST,
             )
             .await?;
+        let mut conversions = self
+            .fx_conversions
+            .list_for_source_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities;
+        conversions.extend(
+            self.fx_conversions
+                .list_for_target_account_id_by_created_at(
+                    account_id,
+                    Default::default(),
+                    es_entity::ListDirection::Descending,
+                )
+                .await?
+                .entities,
+        );
+        conversions.sort_by_key(|conversion| std::cmp::Reverse(conversion.created_at()));
+        Ok(conversions)
+    }
 
-        let mut op = self.deposit_accounts.begin_op().await?;
-        let mut account = self
-            .deposit_accounts
-            .find_by_id_in_op(&mut op, account_id)
-            .await?;
-
-        if account.freeze()?.did_execute() {
-            self.deposit_accounts
-                .update_in_op(&mut op, &mut account)
-                .await?;
+    #[record_error_severity]
+    #[instrument(name = "deposit.place_hold", skip(self))]
+    pub async fn place_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reason: String,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Hold, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_PLACE,
+            )
+            .await?;
+        self.check_account_active(deposit_account_id).await?;
+        self.check_account_currency(deposit_account_id, amount.currency())
+            .await?;
+
+        let new_hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(deposit_account_id)
+            .amount(amount.minor_units())
+            .currency(amount.currency())
+            .reason(reason)
+            .expires_at(expires_at)
+            .build()?;
+
+        let mut op = self.holds.begin_op().await?;
+        self.check_available_balance_in_op(&mut op, deposit_account_id, amount)
+            .await?;
+        let hold = self.holds.create_in_op(&mut op, new_hold).await?;
+        self.ledger
+            .place_hold_in_op(&mut op, hold.id, hold.money(), hold.deposit_account_id, sub)
+            .await?;
+        op.commit().await?;
+
+        Ok(hold)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.extend_hold", skip(self))]
+    pub async fn extend_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Hold, CoreDepositError> {
+        let id = hold_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_EXTEND,
+            )
+            .await?;
+
+        let mut hold = self.holds.find_by_id(id).await?;
+        if hold.extend(expires_at)?.did_execute() {
+            self.holds.update(&mut hold).await?;
+        }
+
+        Ok(hold)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.release_hold", skip(self))]
+    pub async fn release_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Hold, CoreDepositError> {
+        let id = hold_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_RELEASE,
+            )
+            .await?;
+
+        let mut op = self.holds.begin_op().await?;
+        let mut hold = self.holds.find_by_id_in_op(&mut op, id).await?;
+        if let es_entity::Idempotent::Executed(tx_id) = hold.release() {
+            self.holds.update_in_op(&mut op, &mut hold).await?;
+            self.ledger
+                .release_hold_in_op(
+                    &mut op,
+                    tx_id,
+                    hold.id,
+                    hold.money(),
+                    hold.deposit_account_id,
+                    sub,
+                )
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(hold)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.release_expired_holds_in_op", skip(self, op), fields(as_of = %as_of))]
+    pub async fn release_expired_holds_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        as_of: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, CoreDepositError> {
+        let ids = self
+            .holds
+            .list_expired_hold_ids_in_op(&mut *op, as_of, limit)
+            .await?;
+        let system = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(HOLD_EXPIRY);
+        for id in ids.iter() {
+            let mut hold = self.holds.find_by_id_in_op(&mut *op, *id).await?;
+            if let es_entity::Idempotent::Executed(tx_id) = hold.expire(as_of) {
+                self.authz
+                    .audit()
+                    .record_system_entry_in_op(
+                        &mut *op,
+                        HOLD_EXPIRY,
+                        CoreDepositObject::hold(hold.id),
+                        CoreDepositAction::HOLD_RELEASE,
+                    )
+                    .await?;
+                self.holds.update_in_op(&mut *op, &mut hold).await?;
+                self.ledger
+                    .release_hold_in_op(
+                        &mut *op,
+                        tx_id,
+                        hold.id,
+                        hold.money(),
+                        hold.deposit_account_id,
+                        &system,
+                    )
+                    .await?;
+            }
+        }
+        Ok(ids.len())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_hold_by_id", skip(self))]
+    pub async fn find_hold_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Option<Hold>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_READ,
+            )
+            .await?;
+
+        Ok(self.holds.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_holds_for_account", skip(self))]
+    pub async fn list_holds_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<HoldsByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<Hold, HoldsByCreatedAtCursor>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_LIST,
+            )
+            .await?;
+        Ok(self
+            .holds
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await?)
+    }
+
+    /// Registers a withdrawal destination for `account_holder_id`. It can only be used once
+    /// verified and after the configured cooling-off period has passed.
+    #[record_error_severity]
+    #[instrument(name = "deposit.create_beneficiary", skip(self))]
+    pub async fn create_beneficiary(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        name: String,
+        destination: BeneficiaryDestination,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let account_holder_id = account_holder_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_beneficiaries(),
+                CoreDepositAction::BENEFICIARY_CREATE,
+            )
+            .await?;
+
+        let cooling_off_hours = self
+            .domain_configs
+            .get_without_audit::<DepositBeneficiaryCoolingOffHours>()
+            .await?
+            .value();
+        let cooling_off_ends_at = i64::try_from(cooling_off_hours)
+            .ok()
+            .and_then(chrono::Duration::try_hours)
+            .and_then(|cooling_off| self.clock.now().checked_add_signed(cooling_off))
+            .ok_or(BeneficiaryError::CoolingOffPeriodOutOfRange(
+                cooling_off_hours,
+            ))?;
+        let new_beneficiary = NewBeneficiary::builder()
+            .id(BeneficiaryId::new())
+            .account_holder_id(account_holder_id)
+            .name(name)
+            .destination(destination)
+            .cooling_off_ends_at(cooling_off_ends_at)
+            .build()?;
+
+        Ok(self.beneficiaries.create(new_beneficiary).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.verify_beneficiary", skip(self))]
+    pub async fn verify_beneficiary(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        beneficiary_id: impl Into<BeneficiaryId> + std::fmt::Debug,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let id = beneficiary_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::BENEFICIARY_VERIFY,
+            )
+            .await?;
+
+        let mut beneficiary = self.beneficiaries.find_by_id(id).await?;
+        if beneficiary.verify()?.did_execute() {
+            self.beneficiaries.update(&mut beneficiary).await?;
+        }
+
+        Ok(beneficiary)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.reject_beneficiary", skip(self))]
+    pub async fn reject_beneficiary(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        beneficiary_id: impl Into<BeneficiaryId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let id = beneficiary_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::BENEFICIARY_VERIFY,
+            )
+            .await?;
+
+        let mut beneficiary = self.beneficiaries.find_by_id(id).await?;
+        if beneficiary.reject(reason)?.did_execute() {
+            self.beneficiaries.update(&mut beneficiary).await?;
+        }
+
+        Ok(beneficiary)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.remove_beneficiary", skip(self))]
+    pub async fn remove_beneficiary(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        beneficiary_id: impl Into<BeneficiaryId> + std::fmt::Debug,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let id = beneficiary_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::BENEFICIARY_REMOVE,
+            )
+            .await?;
+
+        let mut beneficiary = self.beneficiaries.find_by_id(id).await?;
+        if beneficiary.remove().did_execute() {
+            self.beneficiaries.update(&mut beneficiary).await?;
+        }
+
+        Ok(beneficiary)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_beneficiary_by_id", skip(self))]
+    pub async fn find_beneficiary_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<BeneficiaryId> + std::fmt::Debug,
+    ) -> Result<Option<Beneficiary>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::BENEFICIARY_READ,
+            )
+            .await?;
+
+        Ok(self.beneficiaries.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_beneficiaries_for_account_holder", skip(self))]
+    pub async fn list_beneficiaries_for_account_holder(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<BeneficiariesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<Beneficiary, BeneficiariesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        let account_holder_id = account_holder_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_beneficiaries(),
+                CoreDepositAction::BENEFICIARY_LIST,
+            )
+            .await?;
+        Ok(self
+            .beneficiaries
+            .list_for_account_holder_id_by_created_at(
+                account_holder_id,
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_beneficiaries", skip(self))]
+    pub async fn find_all_beneficiaries<T: From<Beneficiary>>(
+        &self,
+        ids: &[BeneficiaryId],
+    ) -> Result<std::collections::HashMap<BeneficiaryId, T>, CoreDepositError> {
+        Ok(self.beneficiaries.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_beneficiaries_authorized", skip(self))]
+    pub async fn find_all_beneficiaries_authorized<T: From<Beneficiary>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[BeneficiaryId],
+    ) -> Result<std::collections::HashMap<BeneficiaryId, T>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_beneficiaries(),
+                CoreDepositAction::BENEFICIARY_READ,
+            )
+            .await?;
+        Ok(self.beneficiaries.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_payment_originator", skip(self))]
+    pub async fn find_payment_originator(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Option<PaymentOriginator>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_READ,
+            )
+            .await?;
+
+        Ok(self
+            .internal_domain_configs
+            .get::<DepositPaymentOriginator>()
+            .await?
+            .maybe_value()
+            .map(|config| config.originator))
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.update_payment_originator", skip(self))]
+    pub async fn update_payment_originator(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        originator: PaymentOriginator,
+    ) -> Result<PaymentOriginator, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_CONFIGURE_ORIGINATOR,
+            )
+            .await?;
+
+        originator
+            .validate()
+            .map_err(PaymentFileError::InvalidOriginator)?;
+        self.internal_domain_configs
+            .update::<DepositPaymentOriginator>(DepositPaymentOriginator {
+                originator: originator.clone(),
+            })
+            .await?;
+
+        Ok(originator)
+    }
+
+    /// Collects approved withdrawals to bank accounts that have not been sent yet into a
+    /// pain.001 credit transfer file. Returns `None` when there is nothing to send.
+    #[record_error_severity]
+    #[instrument(name = "deposit.generate_withdrawal_payment_file", skip(self))]
+    pub async fn generate_withdrawal_payment_file(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Option<PaymentFile>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_GENERATE,
+            )
+            .await?;
+
+        let originator = self
+            .internal_domain_configs
+            .get::<DepositPaymentOriginator>()
+            .await?
+            .maybe_value()
+            .ok_or(PaymentFileError::OriginatorNotConfigured)?
+            .originator;
+
+        let mut withdrawals = Vec::new();
+        let mut query = es_entity::PaginatedQueryArgs::<WithdrawalsByCreatedAtCursor> {
+            first: 100,
+            after: None,
+        };
+        loop {
+            let es_entity::PaginatedQueryRet {
+                entities,
+                has_next_page,
+                end_cursor,
+            } = self
+                .withdrawals
+                .list_for_status_by_created_at(
+                    WithdrawalStatus::PendingConfirmation,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            // Payment files are USD credit transfers.
+            withdrawals.extend(entities.into_iter().filter(|withdrawal| {
+                withdrawal.currency == CurrencyCode::USD
+                    && matches!(
+                        withdrawal.destination,
+                        Some(BeneficiaryDestination::BankAccount(_))
+                    )
+            }));
+            if !has_next_page {
+                break;
+            }
+            query = es_entity::PaginatedQueryArgs {
+                first: 100,
+                after: end_cursor,
+            };
+        }
+        if withdrawals.is_empty() {
+            return Ok(None);
+        }
+
+        let payment_file_id = PaymentFileId::new();
+        let message_id = uuid::Uuid::from(payment_file_id).simple().to_string();
+        let now = self.clock.now();
+        let transfers = withdrawals
+            .iter()
+            .filter_map(|withdrawal| match &withdrawal.destination {
+                Some(BeneficiaryDestination::BankAccount(creditor)) => Some(CreditTransfer {
+                    end_to_end_id: uuid::Uuid::from(withdrawal.id).simple().to_string(),
+                    amount: withdrawal.amount,
+                    creditor,
+                    remittance_information: &withdrawal.reference,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let content = credit_transfer_initiation(
+            &message_id,
+            now,
+            now.date_naive(),
+            &originator,
+            &transfers,
+        )?;
+
+        let mut op = self.payment_files.begin_op().await?;
+        let mut document = self
+            .document_storage
+            .create_in_op(
+                &mut op,
+                format!("pain001_{message_id}.xml"),
+                "application/xml",
+                payment_file_id,
+                PAYMENT_FILE_DOCUMENT_TYPE,
+            )
+            .await?;
+        self.document_storage
+            .upload_in_op(&mut op, content.into_bytes(), &mut document)
+            .await?;
+
+        for withdrawal in withdrawals.iter_mut() {
+            if withdrawal.payment_sent(payment_file_id)?.did_execute() {
+                self.withdrawals.update_in_op(&mut op, withdrawal).await?;
+            }
+        }
+
+        let new_payment_file = NewPaymentFile::builder()
+            .id(payment_file_id)
+            .message_id(message_id)
+            .document_id(document.id)
+            .withdrawal_ids(withdrawals.iter().map(|withdrawal| withdrawal.id).collect())
+            .control_sum(
+                withdrawals
+                    .iter()
+                    .fold(UsdCents::ZERO, |sum, withdrawal| sum + withdrawal.amount),
+            )
+            .build()
+            .expect("could not build new payment file");
+        let payment_file = self
+            .payment_files
+            .create_in_op(&mut op, new_payment_file)
+            .await?;
+
+        op.commit().await?;
+
+        Ok(Some(payment_file))
+    }
+
+    /// Imports a pain.002 payment status report for a previously generated payment file,
+    /// confirming accepted withdrawals and cancelling or reverting rejected ones.
+    #[record_error_severity]
+    #[instrument(name = "deposit.import_payment_status_report", skip(self, content))]
+    pub async fn import_payment_status_report(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        content: Vec<u8>,
+    ) -> Result<PaymentFile, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_IMPORT_STATUS_REPORT,
+            )
+            .await?;
+
+        let report = PaymentStatusReport::parse(&content)?;
+
+        let mut op = self.payment_files.begin_op().await?;
+        let mut payment_file = self
+            .payment_files
+            .maybe_find_by_message_id_in_op(&mut op, report.original_message_id.clone())
+            .await?
+            .ok_or_else(|| {
+                PaymentFileError::UnknownOriginalMessageId(report.original_message_id.clone())
+            })?;
+        let mut document = self
+            .document_storage
+            .create_in_op(
+                &mut op,
+                format!("pain002_{}.xml", payment_file.message_id),
+                "application/xml",
+                payment_file.id,
+                PAYMENT_STATUS_REPORT_DOCUMENT_TYPE,
+            )
+            .await?;
+
+        let es_entity::Idempotent::Executed(outcome) =
+            payment_file.record_status_report(document.id, &report)?
+        else {
+            return Ok(payment_file);
+        };
+
+        for id in outcome.accepted {
+            let withdrawal = self.withdrawals.find_by_id_in_op(&mut op, id).await?;
+            if withdrawal.status() == WithdrawalStatus::PaymentSent {
+                self.confirm_withdrawal_in_op(&mut op, sub, id).await?;
+            }
+        }
+        for id in outcome.rejected {
+            let withdrawal = self.withdrawals.find_by_id_in_op(&mut op, id).await?;
+            match withdrawal.status() {
+                WithdrawalStatus::Confirmed => {
+                    self.revert_withdrawal_in_op(&mut op, sub, id).await?;
+                }
+                WithdrawalStatus::PaymentSent => {
+                    self.cancel_withdrawal_in_op(&mut op, sub, id).await?;
+                }
+                _ => (),
+            }
+        }
+
+        self.document_storage
+            .upload_in_op(&mut op, content, &mut document)
+            .await?;
+        self.payment_files
+            .update_in_op(&mut op, &mut payment_file)
+            .await?;
+        op.commit().await?;
+
+        Ok(payment_file)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_payment_file_by_id", skip(self))]
+    pub async fn find_payment_file_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentFileId> + std::fmt::Debug,
+    ) -> Result<Option<PaymentFile>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_file(id),
+                CoreDepositAction::PAYMENT_FILE_READ,
+            )
+            .await?;
+
+        Ok(self.payment_files.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_payment_files", skip(self))]
+    pub async fn list_payment_files(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<PaymentFilesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<PaymentFile, PaymentFilesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .payment_files
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_payment_files_authorized", skip(self))]
+    pub async fn find_all_payment_files_authorized<T: From<PaymentFile>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[PaymentFileId],
+    ) -> Result<std::collections::HashMap<PaymentFileId, T>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_READ,
+            )
+            .await?;
+        Ok(self.payment_files.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.generate_payment_file_download_link", skip(self))]
+    pub async fn generate_payment_file_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentFileId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_file(id),
+                CoreDepositAction::PAYMENT_FILE_READ,
+            )
+            .await?;
+
+        let payment_file = self.payment_files.find_by_id(id).await?;
+        Ok(self
+            .document_storage
+            .generate_download_link(payment_file.document_id)
+            .await?)
+    }
+
+    /// Validates an uploaded CSV of incoming deposits and schedules the valid rows to be
+    /// posted in the background. Rows whose reference was already recorded are skipped.
+    #[record_error_severity]
+    #[instrument(name = "deposit.import_deposits", skip(self, content))]
+    pub async fn import_deposits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        file_name: String,
+        content: Vec<u8>,
+    ) -> Result<DepositImport, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_imports(),
+                CoreDepositAction::DEPOSIT_IMPORT_CREATE,
+            )
+            .await?;
+
+        let (parsed_rows, mut row_errors) = parse_deposit_import_csv(&content, self.clock.today())?;
+
+        let mut rows = Vec::with_capacity(parsed_rows.len());
+        for row in parsed_rows {
+            let account = self
+                .deposit_accounts
+                .maybe_find_by_public_id(public_id::PublicId::new(row.account_public_id.clone()))
+                .await?;
+            let message = match account {
+                None => Some(format!(
+                    "deposit account '{}' not found",
+                    row.account_public_id
+                )),
+                Some(account) if account.status != DepositAccountStatus::Active => Some(format!(
+                    "deposit account '{}' is {}",
+                    row.account_public_id, account.status
+                )),
+                Some(account) if account.currency != CurrencyCode::USD => Some(format!(
+                    "deposit account '{}' is not denominated in USD",
+                    row.account_public_id
+                )),
+                Some(account) => {
+                    rows.push(DepositImportRow {
+                        line: row.line,
+                        deposit_account_id: account.id,
+                        amount: row.amount,
+                        reference: row.reference,
+                        value_date: row.value_date,
+                    });
+                    None
+                }
+            };
+            if let Some(message) = message {
+                row_errors.push(DepositImportRowError {
+                    line: row.line,
+                    message,
+                });
+            }
+        }
+        row_errors.sort_by_key(|e| e.line);
+
+        let deposit_import_id = DepositImportId::new();
+        let mut op = self.deposit_imports.begin_op().await?;
+        let mut document = self
+            .document_storage
+            .create_in_op(
+                &mut op,
+                file_name.clone(),
+                "text/csv",
+                deposit_import_id,
+                DEPOSIT_IMPORT_DOCUMENT_TYPE,
+            )
+            .await?;
+        self.document_storage
+            .upload_in_op(&mut op, content, &mut document)
+            .await?;
+
+        let new_deposit_import = NewDepositImport::builder()
+            .id(deposit_import_id)
+            .document_id(document.id)
+            .file_name(file_name)
+            .rows(rows)
+            .row_errors(row_errors)
+            .build()
+            .expect("could not build new deposit import");
+        let deposit_import = self
+            .deposit_imports
+            .create_in_op(&mut op, new_deposit_import)
+            .await?;
+
+        self.process_deposit_import_spawner
+            .spawn_in_op(
+                &mut op,
+                job::JobId::from(uuid::Uuid::from(deposit_import_id)),
+                ProcessDepositImportConfig { deposit_import_id },
+            )
+            .await?;
+
+        op.commit().await?;
+
+        Ok(deposit_import)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_deposit_import_by_id", skip(self))]
+    pub async fn find_deposit_import_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositImportId> + std::fmt::Debug,
+    ) -> Result<Option<DepositImport>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_import(id),
+                CoreDepositAction::DEPOSIT_IMPORT_READ,
+            )
+            .await?;
+
+        Ok(self.deposit_imports.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_deposit_imports", skip(self))]
+    pub async fn list_deposit_imports(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<DepositImportsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<DepositImport, DepositImportsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_imports(),
+                CoreDepositAction::DEPOSIT_IMPORT_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .deposit_imports
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_deposit_imports_authorized", skip(self))]
+    pub async fn find_all_deposit_imports_authorized<T: From<DepositImport>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[DepositImportId],
+    ) -> Result<std::collections::HashMap<DepositImportId, T>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_imports(),
+                CoreDepositAction::DEPOSIT_IMPORT_READ,
+            )
+            .await?;
+        Ok(self.deposit_imports.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.create_deposit_product", skip(self))]
+    pub async fn create_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        name: String,
+        values: DepositProductValues,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
+            )
+            .await?;
+
+        let new_product = NewDepositProduct::builder()
+            .id(DepositProductId::new())
+            .name(name)
+            .values(values)
+            .build()?;
+
+        Ok(self.deposit_products.create(new_product).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.update_deposit_product", skip(self))]
+    pub async fn update_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        values: DepositProductValues,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE,
+            )
+            .await?;
+
+        let mut product = self.deposit_products.find_by_id(id).await?;
+        if product.update_values(values).did_execute() {
+            self.deposit_products.update(&mut product).await?;
+        }
+
+        Ok(product)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_deposit_product_by_id", skip(self))]
+    pub async fn find_deposit_product_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<Option<DepositProduct>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_READ,
+            )
+            .await?;
+
+        Ok(self.deposit_products.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_deposit_products", skip(self))]
+    pub async fn list_deposit_products(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<DepositProductsByNameCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<DepositProduct, DepositProductsByNameCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .deposit_products
+            .list_by_name(query, es_entity::ListDirection::Ascending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.assign_deposit_product", skip(self))]
+    pub async fn assign_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        deposit_product_id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let deposit_product_id = deposit_product_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_PRODUCT,
+            )
+            .await?;
+
+        let product = self.deposit_products.find_by_id(deposit_product_id).await?;
+        let mut account = self.deposit_accounts.find_by_id(deposit_account_id).await?;
+        if account
+            .assign_deposit_product(product.id, self.clock.now())?
+            .did_execute()
+        {
+            self.deposit_accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit.list_account_ids_with_deposit_product_in_op",
+        skip(self, op)
+    )]
+    pub async fn list_account_ids_with_deposit_product_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        after: Option<DepositAccountId>,
+        limit: i64,
+    ) -> Result<Vec<DepositAccountId>, CoreDepositError> {
+        Ok(self
+            .deposit_accounts
+            .list_account_ids_with_deposit_product_in_op(op, after, limit)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.accrue_interest_in_op", skip(self, op), fields(%account_id, closing_time = %closing_time))]
+    pub async fn accrue_interest_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account_id: DepositAccountId,
+        closing_time: DateTime<Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, account_id)
+            .await?;
+        let Some(product_id) = account.deposit_product_id else {
+            return Ok(());
+        };
+        if account.is_closed() {
+            return Ok(());
+        }
+        let product = self.deposit_products.find_by_id(product_id).await?;
+        let values = product.values;
+        let closing_date = closing_time.date_naive();
+        let system =
+            <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(DEPOSIT_INTEREST);
+
+        // Interest is earned on each day's closing balance net of holds, read in
+        // the same op so it cannot drift from the accrual that books it.
+        let balance = self.ledger.balance_in_op(&mut *op, account.id).await?;
+        let mut updated = account
+            .compute_daily_interest(closing_date, balance.available(), values.annual_rate)
+            .did_execute();
+        if values.accrual_frequency.is_period_end(closing_date)
+            && let es_entity::Idempotent::Executed(accrual) = account.accrue_interest(closing_date)
+        {
+            updated = true;
+            if let Some(accrual) = accrual {
+                self.authz
+                    .audit()
+                    .record_system_entry_in_op(
+                        &mut *op,
+                        DEPOSIT_INTEREST,
+                        CoreDepositObject::deposit_account(account.id),
+                        CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+                    )
+                    .await?;
+                self.ledger
+                    .accrue_deposit_interest_in_op(
+                        &mut *op,
+                        accrual.ledger_tx_id,
+                        account.id,
+                        accrual.amount,
+                        account.id,
+                        closing_date,
+                        &system,
+                    )
+                    .await?;
+            }
+        }
+
+        if values.posting_frequency.is_period_end(closing_date)
+            && let es_entity::Idempotent::Executed(posting) = account.post_interest(closing_date)
+        {
+            updated = true;
+            self.ledger
+                .post_deposit_interest_in_op(
+                    &mut *op,
+                    posting.ledger_tx_id,
+                    account.id,
+                    posting.amount,
+                    account.id,
+                    closing_date,
+                    &system,
+                )
+                .await?;
+        }
+
+        if updated {
+            self.deposit_accounts.update_in_op(op, &mut account).await?;
+        }
+        Ok(())
+    }
+
+    /// Computes, accrues and posts the interest earned up to `as_of` so that it is
+    /// part of the settled balance. Returns whether `account` has new events.
+    async fn post_outstanding_interest_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account: &mut DepositAccount,
+        as_of: chrono::NaiveDate,
+    ) -> Result<bool, CoreDepositError> {
+        let Some(product_id) = account.deposit_product_id else {
+            return Ok(false);
+        };
+        let product = self.deposit_products.find_by_id(product_id).await?;
+        let system =
+            <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(DEPOSIT_INTEREST);
+
+        let balance = self.ledger.balance_in_op(&mut *op, account.id).await?;
+        let mut updated = account
+            .compute_daily_interest(as_of, balance.available(), product.values.annual_rate)
+            .did_execute();
+        if let es_entity::Idempotent::Executed(accrual) = account.accrue_interest(as_of) {
+            updated = true;
+            if let Some(accrual) = accrual {
+                self.authz
+                    .audit()
+                    .record_system_entry_in_op(
+                        &mut *op,
+                        DEPOSIT_INTEREST,
+                        CoreDepositObject::deposit_account(account.id),
+                        CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+                    )
+                    .await?;
+                self.ledger
+                    .accrue_deposit_interest_in_op(
+                        &mut *op,
+                        accrual.ledger_tx_id,
+                        account.id,
+                        accrual.amount,
+                        account.id,
+                        as_of,
+                        &system,
+                    )
+                    .await?;
+            }
+        }
+        if let es_entity::Idempotent::Executed(posting) = account.post_interest(as_of) {
+            updated = true;
+            self.ledger
+                .post_deposit_interest_in_op(
+                    &mut *op,
+                    posting.ledger_tx_id,
+                    account.id,
+                    posting.amount,
+                    account.id,
+                    as_of,
+                    &system,
+                )
+                .await?;
+        }
+        Ok(updated)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_deposit_fee_schedule", skip(self))]
+    pub async fn find_deposit_fee_schedule(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_product_id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<DepositFeeSchedule, CoreDepositError> {
+        let deposit_product_id = deposit_product_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(deposit_product_id),
+                CoreDepositAction::DEPOSIT_PRODUCT_READ,
+            )
+            .await?;
+
+        let schedules = self
+            .internal_domain_configs
+            .get::<DepositFeeSchedules>()
+            .await?
+            .value();
+        Ok(schedules.for_product(deposit_product_id))
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.update_deposit_fee_schedule", skip(self))]
+    pub async fn update_deposit_fee_schedule(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_product_id: impl Into<DepositProductId> + std::fmt::Debug,
+        schedule: DepositFeeSchedule,
+    ) -> Result<DepositFeeSchedule, CoreDepositError> {
+        let deposit_product_id = deposit_product_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(deposit_product_id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE,
+            )
+            .await?;
+
+        let product = self.deposit_products.find_by_id(deposit_product_id).await?;
+        let mut schedules = self
+            .internal_domain_configs
+            .get::<DepositFeeSchedules>()
+            .await?
+            .value();
+        schedules.schedules.insert(product.id, schedule);
+        self.internal_domain_configs
+            .update::<DepositFeeSchedules>(schedules)
+            .await?;
+
+        Ok(schedule)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.charge_periodic_fees_in_op", skip(self, op), fields(%account_id, closing_time = %closing_time))]
+    pub async fn charge_periodic_fees_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account_id: DepositAccountId,
+        closing_time: DateTime<Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, account_id)
+            .await?;
+        if account.status != DepositAccountStatus::Active {
+            return Ok(());
+        }
+        let Some(schedule) = self.fee_schedule_for(&account).await? else {
+            return Ok(());
+        };
+        let closing_date = closing_time.date_naive();
+        let period_start = closing_date
+            .with_day(1)
+            .expect("first day of month is valid");
+
+        let mut fees = vec![DepositFeeType::MonthlyMaintenance];
+        if account.activity == Activity::Inactive {
+            fees.push(DepositFeeType::DormantAccount);
+        }
+
+        let system = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(DEPOSIT_FEES);
+        let mut available = self
+            .ledger
+            .balance_in_op(&mut *op, account.id)
+            .await?
+            .available();
+        let mut updated = false;
+        for fee_type in fees {
+            let Some(amount) = schedule.fee_for(fee_type) else {
+                continue;
+            };
+            if available < amount {
+                tracing::warn!(
+                    deposit_account_id = %account.id,
+                    %fee_type,
+                    "Skipping fee, insufficient available balance"
+                );
+                continue;
+            }
+            if let es_entity::Idempotent::Executed(ledger_tx_id) =
+                account.charge_periodic_fee(fee_type, period_start, amount)
+            {
+                updated = true;
+                available -= amount;
+                self.authz
+                    .audit()
+                    .record_system_entry_in_op(
+                        &mut *op,
+                        DEPOSIT_FEES,
+                        CoreDepositObject::deposit_account(account.id),
+                        CoreDepositAction::DEPOSIT_ACCOUNT_CHARGE_FEE,
+                    )
+                    .await?;
+                self.ledger
+                    .charge_deposit_fee_in_op(
+                        &mut *op,
+                        ledger_tx_id,
+                        account.id,
+                        amount,
+                        account.id,
+                        &system,
+                    )
+                    .await?;
+            }
+        }
+
+        if updated {
+            self.deposit_accounts.update_in_op(op, &mut account).await?;
+        }
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.charge_withdrawal_fee_in_op", skip(self, op), fields(%withdrawal_id))]
+    pub async fn charge_withdrawal_fee_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        withdrawal_id: WithdrawalId,
+    ) -> Result<(), CoreDepositError> {
+        let withdrawal = self
+            .withdrawals
+            .find_by_id_in_op(&mut *op, withdrawal_id)
+            .await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, withdrawal.deposit_account_id)
+            .await?;
+        let Some(amount) = self
+            .fee_schedule_for(&account)
+            .await?
+            .and_then(|schedule| schedule.fee_for(DepositFeeType::Withdrawal))
+        else {
+            return Ok(());
+        };
+        if self
+            .ledger
+            .balance_in_op(&mut *op, account.id)
+            .await?
+            .available()
+            < amount
+        {
+            tracing::warn!(
+                deposit_account_id = %account.id,
+                %withdrawal_id,
+                "Skipping withdrawal fee, insufficient available balance"
+            );
+            return Ok(());
+        }
+
+        let es_entity::Idempotent::Executed(ledger_tx_id) =
+            account.charge_withdrawal_fee(withdrawal.id, amount)
+        else {
+            return Ok(());
+        };
+        self.deposit_accounts
+            .update_in_op(&mut *op, &mut account)
+            .await?;
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut *op,
+                DEPOSIT_FEES,
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_CHARGE_FEE,
+            )
+            .await?;
+        let system = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(DEPOSIT_FEES);
+        self.ledger
+            .charge_deposit_fee_in_op(op, ledger_tx_id, account.id, amount, account.id, &system)
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_withdrawal_limits", skip(self))]
+    pub async fn find_withdrawal_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_type: Option<CustomerType>,
+    ) -> Result<WithdrawalLimits, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_WITHDRAWAL_LIMITS,
+            )
+            .await?;
+
+        let limits = self
+            .internal_domain_configs
+            .get::<DepositWithdrawalLimits>()
+            .await?
+            .value();
+        Ok(limits.configured(customer_type))
+    }
+
+    /// Sets the withdrawal limits of a customer type, or the default limits when `customer_type` is `None`.
+    #[record_error_severity]
+    #[instrument(name = "deposit.update_withdrawal_limits", skip(self))]
+    pub async fn update_withdrawal_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_type: Option<CustomerType>,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Result<WithdrawalLimits, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_WITHDRAWAL_LIMITS,
+            )
+            .await?;
+
+        let mut limits = self
+            .internal_domain_configs
+            .get::<DepositWithdrawalLimits>()
+            .await?
+            .value();
+        limits.set(customer_type, withdrawal_limits);
+        self.internal_domain_configs
+            .update::<DepositWithdrawalLimits>(limits)
+            .await?;
+
+        Ok(withdrawal_limits)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.withdrawal_limits_for_account", skip(self))]
+    pub async fn withdrawal_limits_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<WithdrawalLimits, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_WITHDRAWAL_LIMITS,
+            )
+            .await?;
+
+        let account = self.deposit_accounts.find_by_id(deposit_account_id).await?;
+        self.withdrawal_limits_for(&account).await
+    }
+
+    /// Requests account specific withdrawal limits, which only take effect once approved
+    /// through the `withdrawal-limit-increase` governance process.
+    #[record_error_severity]
+    #[instrument(name = "deposit.request_withdrawal_limit_increase", skip(self))]
+    pub async fn request_withdrawal_limit_increase(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_REQUEST_WITHDRAWAL_LIMIT_INCREASE,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, deposit_account_id)
+            .await?;
+        let approval_process_id = ApprovalProcessId::new();
+        if account
+            .request_withdrawal_limit_increase(approval_process_id, withdrawal_limits)?
+            .did_execute()
+        {
+            self.governance
+                .start_process_in_op(
+                    &mut op,
+                    approval_process_id,
+                    account.id.to_string(),
+                    APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS,
+                )
+                .await?;
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.reverse_deposit_fee", skip(self))]
+    pub async fn reverse_deposit_fee(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        fee_ledger_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let fee_ledger_tx_id = fee_ledger_tx_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_REVERSE_FEE,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, deposit_account_id)
+            .await?;
+        if let es_entity::Idempotent::Executed(reversal) = account.reverse_fee(fee_ledger_tx_id)? {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            self.ledger
+                .reverse_deposit_fee_in_op(
+                    &mut op,
+                    reversal.ledger_tx_id,
+                    account.id,
+                    reversal.amount,
+                    account.id,
+                    sub,
+                )
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.revert_deposit", skip(self))]
+    pub async fn revert_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_id: impl Into<DepositId> + std::fmt::Debug,
+    ) -> Result<Deposit, CoreDepositError> {
+        let id = deposit_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit(id),
+                CoreDepositAction::DEPOSIT_REVERT,
+            )
+            .await?;
+
+        let mut op = self.deposits.begin_op().await?;
+        let mut deposit = self.deposits.find_by_id_in_op(&mut op, id).await?;
+        self.check_account_active(deposit.deposit_account_id)
+            .await?;
+
+        if let es_entity::Idempotent::Executed(deposit_reversal_data) = deposit.revert() {
+            self.deposits.update_in_op(&mut op, &mut deposit).await?;
+            self.ledger
+                .revert_deposit_in_op(&mut op, deposit_reversal_data, sub)
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(deposit)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.revert_withdrawal", skip(self))]
+    pub async fn revert_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        withdrawal_id: impl Into<WithdrawalId> + std::fmt::Debug,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let id = withdrawal_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal(id),
+                CoreDepositAction::WITHDRAWAL_REVERT,
+            )
+            .await?;
+
+        let mut op = self.withdrawals.begin_op().await?;
+        let withdrawal = self.revert_withdrawal_in_op(&mut op, sub, id).await?;
+        op.commit().await?;
+
+        Ok(withdrawal)
+    }
+
+    async fn revert_withdrawal_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: WithdrawalId,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut *op, id).await?;
+
+        self.check_account_active(withdrawal.deposit_account_id)
+            .await?;
+
+        if let Ok(es_entity::Idempotent::Executed(withdrawal_reversal_data)) = withdrawal.revert() {
+            self.withdrawals
+                .update_in_op(&mut *op, &mut withdrawal)
+                .await?;
+            self.ledger
+                .revert_withdrawal_in_op(op, withdrawal_reversal_data, sub)
+                .await?;
+        }
+
+        Ok(withdrawal)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.confirm_withdrawal", skip(self))]
+    pub async fn confirm_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        withdrawal_id: impl Into<WithdrawalId> + std::fmt::Debug,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let id = withdrawal_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal(id),
+                CoreDepositAction::WITHDRAWAL_CONFIRM,
+            )
+            .await?;
+        let mut op = self.withdrawals.begin_op().await?;
+        let withdrawal = self.confirm_withdrawal_in_op(&mut op, sub, id).await?;
+        op.commit().await?;
+
+        Ok(withdrawal)
+    }
+
+    async fn confirm_withdrawal_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: WithdrawalId,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut *op, id).await?;
+        self.check_account_active(withdrawal.deposit_account_id)
+            .await?;
+        let es_entity::Idempotent::Executed(tx_id) = withdrawal.confirm()? else {
+            return Ok(withdrawal);
+        };
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, withdrawal.deposit_account_id)
+            .await?;
+        let penalty = self
+            .early_withdrawal_penalty(&account, withdrawal.amount)
+            .await?;
+        if let Some(penalty) = penalty {
+            self.check_available_balance_in_op(&mut *op, account.id, penalty.into())
+                .await?;
+        }
+        self.withdrawals
+            .update_in_op(&mut *op, &mut withdrawal)
+            .await?;
+
+        self.ledger
+            .confirm_withdrawal_in_op(
+                &mut *op,
+                id,
+                tx_id,
+                withdrawal.id.to_string(),
+                withdrawal.money(),
+                withdrawal.deposit_account_id,
+                format!("lana:withdraw:{}:confirm", withdrawal.id),
+                sub,
+            )
+            .await?;
+
+        if let Some(penalty) = penalty
+            && let es_entity::Idempotent::Executed(penalty_tx_id) =
+                account.charge_early_withdrawal_penalty(withdrawal.id, penalty)
+        {
+            self.deposit_accounts
+                .update_in_op(&mut *op, &mut account)
+                .await?;
+            self.ledger
+                .charge_early_withdrawal_penalty_in_op(
+                    op,
+                    penalty_tx_id,
+                    withdrawal.id,
+                    penalty,
+                    account.id,
+                    sub,
+                )
+                .await?;
+        }
+
+        Ok(withdrawal)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.cancel_withdrawal", skip(self))]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn cancel_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        withdrawal_id: impl es_entity::RetryableInto<WithdrawalId>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let id = withdrawal_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal(id),
+                CoreDepositAction::WITHDRAWAL_CANCEL,
+            )
+            .await?;
+        let mut op = self.withdrawals.begin_op().await?;
+        let withdrawal = self.cancel_withdrawal_in_op(&mut op, sub, id).await?;
+        op.commit().await?;
+        Ok(withdrawal)
+    }
+
+    async fn cancel_withdrawal_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: WithdrawalId,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut *op, id).await?;
+        self.check_account_active(withdrawal.deposit_account_id)
+            .await?;
+        let es_entity::Idempotent::Executed(tx_id) = withdrawal.cancel()? else {
+            return Ok(withdrawal);
+        };
+        self.withdrawals
+            .update_in_op(&mut *op, &mut withdrawal)
+            .await?;
+        self.ledger
+            .cancel_withdrawal_in_op(
+                op,
+                id,
+                tx_id,
+                withdrawal.money(),
+                withdrawal.deposit_account_id,
+                sub,
+            )
+            .await?;
+        Ok(withdrawal)
+    }
+
+    /// Records that `holder_id` signed a withdrawal from a joint account whose
+    /// signing rules require it.
+    #[record_error_severity]
+    #[instrument(name = "deposit.sign_withdrawal", skip(self))]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn sign_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        withdrawal_id: impl es_entity::RetryableInto<WithdrawalId>,
+        holder_id: impl es_entity::RetryableInto<DepositAccountHolderId>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let id = withdrawal_id.into();
+        let holder_id = holder_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal(id),
+                CoreDepositAction::WITHDRAWAL_RECORD_SIGNATURE,
+            )
+            .await?;
+        let mut op = self.withdrawals.begin_op().await?;
+        let mut withdrawal = self.withdrawals.find_by_id_in_op(&mut op, id).await?;
+        let account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, withdrawal.deposit_account_id)
+            .await?;
+        match account.holder(holder_id) {
+            Some(holder) if holder.suspended => {
+                return Err(DepositAccountError::HolderSuspended(account.id, holder_id).into());
+            }
+            Some(_) => {}
+            None => return Err(DepositAccountError::HolderNotFound(account.id, holder_id).into()),
+        }
+        if withdrawal.sign(holder_id)?.did_execute() {
+            self.withdrawals
+                .update_in_op(&mut op, &mut withdrawal)
+                .await?;
+            op.commit().await?;
+        }
+        Ok(withdrawal)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.freeze_account", skip(self))]
+    pub async fn freeze_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, account_id)
+            .await?;
+
+        if account.freeze()?.did_execute() {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
             self.ledger
                 .freeze_account_in_op(&mut op, &account, sub)
                 .await?;
-
+
+            op.commit().await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.unfreeze_account", skip(self))]
+    pub async fn unfreeze_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UNFREEZE,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, account_id)
+            .await?;
+
+        if account.unfreeze()?.did_execute() {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            self.ledger
+                .unfreeze_account_in_op(&mut op, &account, sub)
+                .await?;
+
+            op.commit().await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.add_joint_account_holder", skip(self))]
+    pub async fn add_joint_account_holder(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        signing_rule: SigningRule,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let holder_id = holder_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_HOLDERS,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        self.customers
+            .find_eligible_for_product_without_audit_in_op(&mut op, CustomerId::from(holder_id))
+            .await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, account_id)
+            .await?;
+
+        if account
+            .add_joint_holder(holder_id, signing_rule)?
+            .did_execute()
+        {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            self.deposit_accounts
+                .insert_joint_holder_in_op(&mut op, account_id, holder_id)
+                .await?;
             op.commit().await?;
         }
 
@@ -644,18 +2986,20 @@ where
     }
 
     #[record_error_severity]
-    #[instrument(name = "deposit.unfreeze_account", skip(self))]
-    pub async fn unfreeze_account(
+    #[instrument(name = "deposit.remove_joint_account_holder", skip(self))]
+    pub async fn remove_joint_account_holder(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
     ) -> Result<DepositAccount, CoreDepositError> {
         let account_id = account_id.into();
+        let holder_id = holder_id.into();
         self.authz
             .enforce_permission(
                 sub,
                 CoreDepositObject::deposit_account(account_id),
-                CoreDepositAction::DEPOSIT_ACCOUNT_UNFREEZE,
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_HOLDERS,
             )
             .await?;
 
@@ -665,14 +3009,13 @@ where
             .find_by_id_in_op(&mut op, account_id)
             .await?;
 
-        if account.unfreeze()?.did_execute() {
+        if account.remove_joint_holder(holder_id)?.did_execute() {
             self.deposit_accounts
                 .update_in_op(&mut op, &mut account)
                 .await?;
-            self.ledger
-                .unfreeze_account_in_op(&mut op, &account, sub)
+            self.deposit_accounts
+                .delete_joint_holder_in_op(&mut op, account_id, holder_id)
                 .await?;
-
             op.commit().await?;
         }
 
@@ -680,23 +3023,42 @@ where
     }
 
     #[record_error_severity]
-    #[instrument(name = "deposit.freeze_accounts_for_holder_in_op", skip(self, op))]
-    pub async fn freeze_accounts_for_holder_in_op(
+    #[instrument(name = "deposit.update_account_holder_signing_rule", skip(self))]
+    pub async fn update_account_holder_signing_rule(
         &self,
-        op: &mut es_entity::DbOp<'static>,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
         holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
-    ) -> Result<(), CoreDepositError> {
-        let holder_id = holder_id.into();
+        signing_rule: SigningRule,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
         self.authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::all_deposit_accounts(),
-                CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_HOLDERS,
             )
             .await?;
 
-        let accounts = self
+        let mut account = self.deposit_accounts.find_by_id(account_id).await?;
+        if account
+            .update_holder_signing_rule(holder_id.into(), signing_rule)?
+            .did_execute()
+        {
+            self.deposit_accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
+    /// Accounts `holder_id` is the primary holder of, followed by the joint
+    /// accounts they hold with others.
+    async fn list_accounts_for_holder_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'static>,
+        holder_id: DepositAccountHolderId,
+    ) -> Result<Vec<DepositAccount>, CoreDepositError> {
+        let mut accounts = self
             .deposit_accounts
             .list_for_account_holder_id_by_id_in_op(
                 &mut *op,
@@ -704,23 +3066,128 @@ where
                 Default::default(),
                 Default::default(),
             )
+            .await?
+            .entities;
+        let joint_account_ids = self
+            .deposit_accounts
+            .list_joint_account_ids_for_holder_in_op(&mut *op, holder_id)
+            .await?;
+        for id in joint_account_ids {
+            accounts.push(self.deposit_accounts.find_by_id_in_op(&mut *op, id).await?);
+        }
+        Ok(accounts)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.freeze_accounts_for_holder_in_op", skip(self, op))]
+    pub async fn freeze_accounts_for_holder_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'static>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+    ) -> Result<(), CoreDepositError> {
+        let holder_id = holder_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+            )
             .await?;
 
-        for mut account in accounts.entities.into_iter() {
-            match account.freeze() {
-                Ok(result) if result.did_execute() => {
-                    self.deposit_accounts.update_in_op(op, &mut account).await?;
-                    self.ledger.freeze_account_in_op(op, &account, sub).await?;
+        let suspend_holder_only = self
+            .domain_configs
+            .get_without_audit::<DepositJointAccountSuspendHolderOnFreeze>()
+            .await?
+            .value();
+
+        let accounts = self.list_accounts_for_holder_in_op(op, holder_id).await?;
+        for mut account in accounts.into_iter() {
+            // Suspending the holder on a joint account records who caused the freeze,
+            // so the account is only unfrozen once every frozen holder is released.
+            let suspended = account.is_joint() && account.suspend_holder(holder_id).did_execute();
+            let frozen = if account.is_joint() && suspend_holder_only {
+                false
+            } else {
+                match account.freeze() {
+                    Ok(result) => result.did_execute(),
+                    Err(DepositAccountError::CannotUpdateClosedAccount(_)) => {
+                        tracing::warn!(
+                            account_id = %account.id,
+                            "Skipping freeze for account that cannot be frozen"
+                        );
+                        continue;
+                    }
+                    Err(e) => return Err(e.into()),
                 }
-                Err(DepositAccountError::CannotUpdateClosedAccount(_)) => {
-                    tracing::warn!(
-                        account_id = %account.id,
-                        "Skipping freeze for account that cannot be frozen"
-                    );
+            };
+            if suspended || frozen {
+                self.deposit_accounts.update_in_op(op, &mut account).await?;
+            }
+            if frozen {
+                self.ledger.freeze_account_in_op(op, &account, sub).await?;
+            } else if suspended {
+                // The account stays usable, but withdrawals waiting for the suspended
+                // holder's signature could never complete.
+                self.cancel_withdrawals_awaiting_signature_in_op(op, sub, account.id, holder_id)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn cancel_withdrawals_awaiting_signature_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: DepositAccountId,
+        holder_id: DepositAccountHolderId,
+    ) -> Result<(), CoreDepositError> {
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .withdrawals
+                .list_for_deposit_account_id_by_created_at_in_op(
+                    &mut *op,
+                    account_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            for withdrawal in res.entities.iter_mut() {
+                if withdrawal.status() != WithdrawalStatus::PendingSignatures
+                    || !withdrawal.missing_signers().contains(&holder_id)
+                {
                     continue;
                 }
-                Err(e) => return Err(e.into()),
-                Ok(_) => continue,
+                let es_entity::Idempotent::Executed(tx_id) = withdrawal.cancel()? else {
+                    continue;
+                };
+                self.authz
+                    .audit()
+                    .record_entry_in_op(
+                        &mut *op,
+                        sub,
+                        CoreDepositObject::withdrawal(withdrawal.id),
+                        CoreDepositAction::WITHDRAWAL_CANCEL,
+                        true,
+                    )
+                    .await?;
+                self.withdrawals.update_in_op(&mut *op, withdrawal).await?;
+                self.ledger
+                    .cancel_withdrawal_in_op(
+                        &mut *op,
+                        withdrawal.id,
+                        tx_id,
+                        withdrawal.money(),
+                        withdrawal.deposit_account_id,
+                        sub,
+                    )
+                    .await?;
+            }
+            match res.into_next_query() {
+                Some(next) => query = next,
+                None => break,
             }
         }
         Ok(())
@@ -743,76 +3210,514 @@ where
             )
             .await?;
 
-        let accounts = self
+        let accounts = self.list_accounts_for_holder_in_op(op, holder_id).await?;
+        for mut account in accounts.into_iter() {
+            let reinstated = account.reinstate_holder(holder_id).did_execute();
+            let unfrozen = if account.has_suspended_holders() {
+                // Another holder of the joint account is still frozen.
+                false
+            } else {
+                match account.unfreeze() {
+                    Ok(result) => result.did_execute(),
+                    Err(DepositAccountError::CannotUpdateClosedAccount(_)) => {
+                        tracing::warn!(
+                            account_id = %account.id,
+                            "Skipping unfreeze for closed account"
+                        );
+                        continue;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            };
+            if reinstated || unfrozen {
+                self.deposit_accounts.update_in_op(op, &mut account).await?;
+            }
+            if unfrozen {
+                self.ledger
+                    .unfreeze_account_in_op(op, &account, sub)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.close_account", skip(self))]
+    pub async fn close_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_CLOSE,
+            )
+            .await?;
+        let account = self.deposit_accounts.find_by_id(account_id).await?;
+        let balance = self
+            .ledger
+            .currency_balance(account_id, account.currency)
+            .await?;
+        if !balance.is_zero() {
+            return Err(DepositAccountError::BalanceIsNotZero.into());
+        }
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let mut account = self
             .deposit_accounts
-            .list_for_account_holder_id_by_id_in_op(
-                &mut *op,
-                holder_id,
-                Default::default(),
-                Default::default(),
+            .find_by_id_in_op(&mut op, account_id)
+            .await?;
+
+        if account.close()?.did_execute() {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            self.ledger
+                .lock_account_in_op(&mut op, account_id.into())
+                .await?;
+
+            op.commit().await?;
+        }
+
+        Ok(account)
+    }
+
+    /// Pays out the remaining balance of `account_id` to `payout` and closes the account
+    /// once the payout has settled. Active holds are released or block the closure
+    /// depending on `hold_treatment`.
+    #[record_error_severity]
+    #[instrument(name = "deposit.initiate_account_closure", skip(self))]
+    pub async fn initiate_account_closure(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        payout: AccountClosurePayout,
+        hold_treatment: AccountClosureHoldTreatment,
+    ) -> Result<AccountClosure, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_account_closures(),
+                CoreDepositAction::ACCOUNT_CLOSURE_INITIATE,
             )
             .await?;
+        self.check_account_active(account_id).await?;
+        let account = self.deposit_accounts.find_by_id(account_id).await?;
+        account.ensure_currency(CurrencyCode::USD)?;
+        if self
+            .account_closures
+            .maybe_find_pending_for_account(account_id)
+            .await?
+            .is_some()
+        {
+            return Err(AccountClosureError::AlreadyInProgress(account_id).into());
+        }
+
+        let beneficiary = match payout {
+            AccountClosurePayout::Beneficiary { beneficiary_id } => {
+                let beneficiary = self.beneficiaries.find_by_id(beneficiary_id).await?;
+                beneficiary.ensure_usable_by(&account.holder_ids(), self.clock.now())?;
+                Some(beneficiary)
+            }
+            AccountClosurePayout::DepositAccount { deposit_account_id } => {
+                if deposit_account_id == account_id {
+                    return Err(AccountClosureError::PayoutToSameAccount(account_id).into());
+                }
+                self.check_account_active(deposit_account_id).await?;
+                self.check_account_currency(deposit_account_id, CurrencyCode::USD)
+                    .await?;
+                None
+            }
+        };
+
+        let account_closure_id = AccountClosureId::new();
+        let mut op = self.account_closures.begin_op().await?;
+
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, account_id)
+            .await?;
+        if self
+            .post_outstanding_interest_in_op(&mut op, &mut account, self.clock.today())
+            .await?
+        {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+        }
+
+        // The payout is read in the same op as the interest it includes so that
+        // nothing posted concurrently is left behind on the closed account.
+        let balance = self.ledger.balance_in_op(&mut op, account_id).await?;
+        if !balance.pending.is_zero() {
+            return Err(AccountClosureError::PendingTransactionsOnAccount(account_id).into());
+        }
+        if hold_treatment == AccountClosureHoldTreatment::Block && !balance.held.is_zero() {
+            return Err(AccountClosureError::ActiveHoldsOnAccount(account_id).into());
+        }
+        let payout_amount = balance.settled;
+        if !payout_amount.is_zero()
+            && self
+                .early_withdrawal_penalty(&account, payout_amount)
+                .await?
+                .is_some()
+        {
+            return Err(AccountClosureError::TermDepositInLockIn(account_id).into());
+        }
 
-        for mut account in accounts.entities.into_iter() {
-            match account.unfreeze() {
-                Ok(result) if result.did_execute() => {
-                    self.deposit_accounts.update_in_op(op, &mut account).await?;
+        let active_hold_ids = self
+            .holds
+            .list_active_hold_ids_for_account_in_op(&mut op, account_id)
+            .await?;
+        if !active_hold_ids.is_empty() {
+            if hold_treatment == AccountClosureHoldTreatment::Block {
+                return Err(AccountClosureError::ActiveHoldsOnAccount(account_id).into());
+            }
+            self.authz
+                .audit()
+                .record_entry_in_op(
+                    &mut op,
+                    sub,
+                    CoreDepositObject::account_closure(account_closure_id),
+                    CoreDepositAction::ACCOUNT_CLOSURE_RELEASE_HOLDS,
+                    true,
+                )
+                .await?;
+            for id in active_hold_ids.iter() {
+                let mut hold = self.holds.find_by_id_in_op(&mut op, *id).await?;
+                if let es_entity::Idempotent::Executed(tx_id) = hold.release() {
+                    self.holds.update_in_op(&mut op, &mut hold).await?;
                     self.ledger
-                        .unfreeze_account_in_op(op, &account, sub)
+                        .release_hold_in_op(
+                            &mut op,
+                            tx_id,
+                            hold.id,
+                            hold.money(),
+                            hold.deposit_account_id,
+                            sub,
+                        )
                         .await?;
                 }
-                Err(DepositAccountError::CannotUpdateClosedAccount(_)) => {
-                    tracing::warn!(
-                        account_id = %account.id,
-                        "Skipping unfreeze for closed account"
-                    );
-                    continue;
+            }
+        }
+
+        let reference = Some(format!("account-closure:{account_closure_id}"));
+        let payout_transaction = if payout_amount.is_zero() {
+            None
+        } else {
+            self.authz
+                .audit()
+                .record_entry_in_op(
+                    &mut op,
+                    sub,
+                    CoreDepositObject::account_closure(account_closure_id),
+                    CoreDepositAction::ACCOUNT_CLOSURE_INITIATE_PAYOUT,
+                    true,
+                )
+                .await?;
+            match payout {
+                AccountClosurePayout::Beneficiary { .. } => {
+                    let beneficiary = beneficiary.expect("beneficiary resolved above");
+                    // The closing payout drains the account, so the holder's periodic
+                    // withdrawal limits are not applied to it.
+                    let withdrawal = self
+                        .create_withdrawal_in_op(
+                            &mut op,
+                            sub,
+                            &account,
+                            beneficiary,
+                            payout_amount.into(),
+                            reference,
+                        )
+                        .await?;
+                    Some(AccountClosurePayoutTransaction::Withdrawal {
+                        withdrawal_id: withdrawal.id,
+                    })
+                }
+                AccountClosurePayout::DepositAccount { deposit_account_id } => {
+                    let transfer = self
+                        .create_transfer_in_op(
+                            &mut op,
+                            sub,
+                            account_id,
+                            deposit_account_id,
+                            payout_amount.into(),
+                            reference,
+                        )
+                        .await?;
+                    Some(AccountClosurePayoutTransaction::Transfer {
+                        transfer_id: transfer.id,
+                    })
                 }
-                Err(e) => return Err(e.into()),
-                Ok(_) => continue,
             }
+        };
+
+        let new_account_closure = NewAccountClosure::builder()
+            .id(account_closure_id)
+            .deposit_account_id(account_id)
+            .payout(payout)
+            .hold_treatment(hold_treatment)
+            .released_hold_ids(active_hold_ids)
+            .payout_amount(payout_amount)
+            .payout_transaction(payout_transaction)
+            .build()
+            .expect("could not build new account closure");
+        let account_closure = self
+            .account_closures
+            .create_in_op(&mut op, new_account_closure)
+            .await?;
+
+        if payout_transaction.is_none() {
+            self.settle_account_closure_spawner
+                .spawn_with_queue_id_in_op(
+                    &mut op,
+                    job::JobId::new(),
+                    SettleAccountClosureConfig {
+                        account_closure_id,
+                        payout_settled: true,
+                    },
+                    account_closure_id.to_string(),
+                )
+                .await?;
         }
-        Ok(())
+
+        op.commit().await?;
+
+        Ok(account_closure)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_account_closure_by_id", skip(self))]
+    pub async fn find_account_closure_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountClosureId> + std::fmt::Debug,
+    ) -> Result<Option<AccountClosure>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::account_closure(id),
+                CoreDepositAction::ACCOUNT_CLOSURE_READ,
+            )
+            .await?;
+
+        Ok(self.account_closures.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_account_closures_for_account", skip(self))]
+    pub async fn list_account_closures_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<AccountClosure>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_account_closures(),
+                CoreDepositAction::ACCOUNT_CLOSURE_LIST,
+            )
+            .await?;
+        Ok(self
+            .account_closures
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_account_closures_authorized", skip(self))]
+    pub async fn find_all_account_closures_authorized<T: From<AccountClosure>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[AccountClosureId],
+    ) -> Result<std::collections::HashMap<AccountClosureId, T>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_account_closures(),
+                CoreDepositAction::ACCOUNT_CLOSURE_READ,
+            )
+            .await?;
+        Ok(self.account_closures.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit.generate_account_closing_statement_download_link",
+        skip(self)
+    )]
+    pub async fn generate_account_closing_statement_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountClosureId> + std::fmt::Debug,
+    ) -> Result<Option<GeneratedDocumentDownloadLink>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::account_closure(id),
+                CoreDepositAction::ACCOUNT_CLOSURE_READ,
+            )
+            .await?;
+
+        let account_closure = self.account_closures.find_by_id(id).await?;
+        let Some(document_id) = account_closure.closing_statement_document_id() else {
+            return Ok(None);
+        };
+        Ok(Some(
+            self.document_storage
+                .generate_download_link(document_id)
+                .await?,
+        ))
+    }
+
+    /// Creates a statement for any date range. The document is rendered in the background,
+    /// its download link becomes available once the generation job has completed.
+    #[record_error_severity]
+    #[instrument(name = "deposit.generate_account_statement", skip(self))]
+    pub async fn generate_account_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        period_start: chrono::NaiveDate,
+        period_end: chrono::NaiveDate,
+        format: DepositAccountStatementFormat,
+    ) -> Result<DepositAccountStatement, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_GENERATE_STATEMENT,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, account_id)
+            .await?;
+        let statement = self
+            .account_statements
+            .create_in_op(&mut op, &account, period_start, period_end, format, false)
+            .await?;
+        self.generate_account_statement_spawner
+            .spawn_in_op(
+                &mut op,
+                job::JobId::from(statement.id),
+                GenerateDepositAccountStatementConfig {
+                    deposit_account_statement_id: statement.id,
+                },
+            )
+            .await?;
+        op.commit().await?;
+
+        Ok(statement)
     }
 
     #[record_error_severity]
-    #[instrument(name = "deposit.close_account", skip(self))]
-    pub async fn close_account(
+    #[instrument(name = "deposit.list_account_statements", skip(self))]
+    pub async fn list_account_statements(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-    ) -> Result<DepositAccount, CoreDepositError> {
+    ) -> Result<Vec<DepositAccountStatement>, CoreDepositError> {
         let account_id = account_id.into();
         self.authz
             .enforce_permission(
                 sub,
                 CoreDepositObject::deposit_account(account_id),
-                CoreDepositAction::DEPOSIT_ACCOUNT_CLOSE,
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ,
             )
             .await?;
-        let balance = self.ledger.balance(account_id).await?;
-        if !balance.is_zero() {
-            return Err(DepositAccountError::BalanceIsNotZero.into());
-        }
 
-        let mut op = self.deposit_accounts.begin_op().await?;
-        let mut account = self
+        Ok(self.account_statements.list_for_account(account_id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.generate_account_statement_download_link", skip(self))]
+    pub async fn generate_account_statement_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+            )
+            .await?;
+        let statement = self.account_statements.find_by_id(id.into()).await?;
+
+        Ok(self
+            .account_statements
+            .generate_download_link(&statement)
+            .await?)
+    }
+
+    pub async fn list_account_ids_for_statements_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        after: Option<DepositAccountId>,
+        limit: i64,
+    ) -> Result<Vec<DepositAccountId>, CoreDepositError> {
+        Ok(self
             .deposit_accounts
-            .find_by_id_in_op(&mut op, account_id)
+            .list_account_ids_for_statements_in_op(op, after, limit)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.create_monthly_account_statements_in_op", skip(self, op), fields(%account_id, %month_end))]
+    pub async fn create_monthly_account_statements_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account_id: DepositAccountId,
+        month_end: chrono::NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        let account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, account_id)
             .await?;
+        let period_start = month_end.with_day(1).expect("day 1 is always valid");
 
-        if account.close()?.did_execute() {
-            self.deposit_accounts
-                .update_in_op(&mut op, &mut account)
+        let statements = self
+            .account_statements
+            .create_monthly_in_op(op, &account, period_start, month_end)
+            .await?;
+        for statement in statements {
+            self.authz
+                .audit()
+                .record_system_entry_in_op(
+                    &mut *op,
+                    DEPOSIT_ACCOUNT_STATEMENTS,
+                    CoreDepositObject::deposit_account(account.id),
+                    CoreDepositAction::DEPOSIT_ACCOUNT_GENERATE_STATEMENT,
+                )
                 .await?;
-            self.ledger
-                .lock_account_in_op(&mut op, account_id.into())
+            self.generate_account_statement_spawner
+                .spawn_in_op(
+                    &mut *op,
+                    job::JobId::from(statement.id),
+                    GenerateDepositAccountStatementConfig {
+                        deposit_account_statement_id: statement.id,
+                    },
+                )
                 .await?;
-
-            op.commit().await?;
         }
-
-        Ok(account)
+        Ok(())
     }
 
     #[record_error_severity]
@@ -836,6 +3741,29 @@ where
         Ok(balance)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.account_currency_balance", skip(self))]
+    pub async fn account_currency_balance(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccountCurrencyBalance, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+            )
+            .await?;
+
+        let account = self.deposit_accounts.find_by_id(account_id).await?;
+        Ok(self
+            .ledger
+            .currency_balance(account_id, account.currency)
+            .await?)
+    }
+
     async fn last_activity_date_for_account(
         &self,
         account: &DepositAccount,
@@ -888,10 +3816,10 @@ where
 
     #[record_error_severity]
     #[instrument(
-        name = "deposit.list_account_ids_not_escheatable_in_op",
+        name = "deposit.list_account_ids_for_activity_evaluation_in_op",
         skip(self, op)
     )]
-    pub async fn list_account_ids_not_escheatable_in_op(
+    pub async fn list_account_ids_for_activity_evaluation_in_op(
         &self,
         op: &mut es_entity::DbOp<'_>,
         after: Option<(DateTime<Utc>, DepositAccountId)>,
@@ -899,7 +3827,7 @@ where
     ) -> Result<Vec<(DepositAccountId, DateTime<Utc>)>, CoreDepositError> {
         Ok(self
             .deposit_accounts
-            .list_account_ids_not_escheatable_in_op(op, after, limit)
+            .list_account_ids_for_activity_evaluation_in_op(op, after, limit)
             .await?)
     }
 
@@ -922,12 +3850,165 @@ where
             inactive_date,
             escheatable_date,
         );
-        if account.update_activity(activity).did_execute() {
+        let mut updated = account.update_activity(activity).did_execute();
+        if account.activity == Activity::Escheatable {
+            let waiting_period_days = self
+                .domain_configs
+                .get_without_audit::<DepositEscheatmentWaitingPeriodDays>()
+                .await?
+                .value();
+            let escheatment_date =
+                closing_time.date_naive() + chrono::Days::new(waiting_period_days);
+            updated |= account
+                .issue_escheatment_notice(escheatment_date)
+                .did_execute();
+        }
+        if updated {
             self.deposit_accounts.update_in_op(op, &mut account).await?;
         }
         Ok(())
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit.list_account_ids_pending_escheatment_in_op",
+        skip(self, op)
+    )]
+    pub async fn list_account_ids_pending_escheatment_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        after: Option<DepositAccountId>,
+        limit: i64,
+    ) -> Result<Vec<DepositAccountId>, CoreDepositError> {
+        Ok(self
+            .deposit_accounts
+            .list_account_ids_pending_escheatment_in_op(op, after, limit)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.escheat_account_in_op", skip(self, op), fields(%account_id, closing_time = %closing_time))]
+    pub async fn escheat_account_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account_id: DepositAccountId,
+        closing_time: DateTime<Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut *op, account_id)
+            .await?;
+        let available = self.ledger.balance(account.id).await?.available();
+        let es_entity::Idempotent::Executed(transfer) =
+            account.escheat(closing_time.date_naive(), available)
+        else {
+            return Ok(());
+        };
+        self.deposit_accounts
+            .update_in_op(&mut *op, &mut account)
+            .await?;
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut *op,
+                DEPOSIT_ESCHEATMENT,
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ESCHEAT,
+            )
+            .await?;
+        if let Some(transfer) = transfer {
+            let system = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                DEPOSIT_ESCHEATMENT,
+            );
+            self.ledger
+                .escheat_deposit_balance_in_op(
+                    op,
+                    transfer.ledger_tx_id,
+                    account.id,
+                    transfer.amount,
+                    account.id,
+                    &system,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.reclaim_escheated_balance", skip(self))]
+    pub async fn reclaim_escheated_balance(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_RECLAIM_ESCHEATED_BALANCE,
+            )
+            .await?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        let mut account = self
+            .deposit_accounts
+            .find_by_id_in_op(&mut op, deposit_account_id)
+            .await?;
+        if let es_entity::Idempotent::Executed(transfer) = account.reclaim_escheated_balance()? {
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            if let Some(transfer) = transfer {
+                self.ledger
+                    .reclaim_escheated_balance_in_op(
+                        &mut op,
+                        transfer.ledger_tx_id,
+                        account.id,
+                        transfer.amount,
+                        account.id,
+                        sub,
+                    )
+                    .await?;
+            }
+            op.commit().await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.escheatment_remittance_report", skip(self))]
+    pub async fn escheatment_remittance_report(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<Vec<EscheatmentRemittanceEntry>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_LIST,
+            )
+            .await?;
+
+        let ids = self
+            .deposit_accounts
+            .list_account_ids_escheated_between(from, until)
+            .await?;
+        let accounts: std::collections::HashMap<_, DepositAccount> =
+            self.deposit_accounts.find_all(&ids).await?;
+        let mut entries: Vec<_> = ids
+            .iter()
+            .filter_map(|id| accounts.get(id))
+            .flat_map(|account| EscheatmentRemittanceEntry::for_period(account, from, until))
+            .collect();
+        entries.sort_by_key(|entry| entry.escheated_on);
+
+        Ok(entries)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.find_deposit_by_id", skip(self))]
     pub async fn find_deposit_by_id(
@@ -966,6 +4047,82 @@ where
         Ok(self.withdrawals.maybe_find_by_id(id).await?)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_transfer_by_id", skip(self))]
+    pub async fn find_transfer_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        Ok(self.transfers.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_transfer_by_public_id", skip(self))]
+    pub async fn find_transfer_by_public_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        public_id: impl Into<public_id::PublicId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        Ok(self
+            .transfers
+            .maybe_find_by_public_id(public_id.into())
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_transfers_authorized", skip(self))]
+    pub async fn find_all_transfers_authorized<T: From<Transfer>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[TransferId],
+    ) -> Result<std::collections::HashMap<TransferId, T>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_transfers", skip(self))]
+    pub async fn list_transfers(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<TransfersCursor>,
+        filter: TransfersFilters,
+        sort: es_entity::Sort<TransfersSortBy>,
+    ) -> Result<es_entity::PaginatedQueryRet<Transfer, TransfersCursor>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        Ok(self.transfers.list_for_filters(filter, sort, query).await?)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.find_deposit_by_public_id", skip(self))]
     pub async fn find_deposit_by_public_id(
@@ -1279,7 +4436,145 @@ where
         match account.status {
             DepositAccountStatus::Frozen => Err(CoreDepositError::DepositAccountFrozen),
             DepositAccountStatus::Closed => Err(CoreDepositError::DepositAccountClosed),
+            DepositAccountStatus::Escheated => Err(CoreDepositError::DepositAccountEscheated),
             DepositAccountStatus::Active => Ok(()),
         }
     }
+
+    /// Penalties are charged in USD, so only USD accounts are penalised.
+    async fn early_withdrawal_penalty(
+        &self,
+        account: &DepositAccount,
+        amount: UsdCents,
+    ) -> Result<Option<UsdCents>, CoreDepositError> {
+        if account.currency != CurrencyCode::USD {
+            return Ok(None);
+        }
+        let (Some(product_id), Some(assigned_at)) =
+            (account.deposit_product_id, account.product_assigned_at())
+        else {
+            return Ok(None);
+        };
+        let product = self.deposit_products.find_by_id(product_id).await?;
+        Ok(product
+            .values
+            .term
+            .filter(|term| self.clock.now() < term.lock_in_ends_at(assigned_at))
+            .map(|term| term.early_withdrawal_penalty_rate.apply(amount)))
+    }
+
+    async fn withdrawal_limits_for(
+        &self,
+        account: &DepositAccount,
+    ) -> Result<WithdrawalLimits, CoreDepositError> {
+        let customer = self
+            .customers
+            .find_by_id_without_audit(CustomerId::from(account.account_holder_id))
+            .await?;
+        let limits = self
+            .internal_domain_configs
+            .get::<DepositWithdrawalLimits>()
+            .await?
+            .value()
+            .for_customer_type(customer.customer_type);
+        Ok(account
+            .withdrawal_limit_override()
+            .map(|account_limits| account_limits.or(limits))
+            .unwrap_or(limits))
+    }
+
+    /// Fee schedules are denominated in USD, so accounts held in other currencies are
+    /// never charged fees.
+    async fn fee_schedule_for(
+        &self,
+        account: &DepositAccount,
+    ) -> Result<Option<DepositFeeSchedule>, CoreDepositError> {
+        if account.currency != CurrencyCode::USD {
+            return Ok(None);
+        }
+        let Some(product_id) = account.deposit_product_id else {
+            return Ok(None);
+        };
+        let schedules = self
+            .internal_domain_configs
+            .get::<DepositFeeSchedules>()
+            .await?
+            .value();
+        Ok(Some(schedules.for_product(product_id)))
+    }
+
+    async fn check_account_currency(
+        &self,
+        deposit_account_id: DepositAccountId,
+        currency: CurrencyCode,
+    ) -> Result<(), CoreDepositError> {
+        let account = self.deposit_accounts.find_by_id(deposit_account_id).await?;
+        account.ensure_currency(currency)?;
+        Ok(())
+    }
+
+    /// Expects the deposit account row to be locked by `check_available_balance_in_op`
+    /// so that concurrent withdrawals are counted one after the other.
+    async fn check_withdrawal_limits_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Result<(), CoreDepositError> {
+        let today = self.clock.today();
+        let Some(earliest) = withdrawal_limits.earliest_window_start(today) else {
+            return Ok(());
+        };
+        let mut withdrawn = Vec::new();
+        let mut query = Default::default();
+        'pages: loop {
+            let res = self
+                .withdrawals
+                .list_for_deposit_account_id_by_created_at_in_op(
+                    &mut *op,
+                    deposit_account_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            for withdrawal in res.entities.iter() {
+                let date = withdrawal.created_at().date_naive();
+                if date < earliest {
+                    break 'pages;
+                }
+                if withdrawal.counts_towards_withdrawal_limits() {
+                    withdrawn.push((date, withdrawal.amount));
+                }
+            }
+            match res.into_next_query() {
+                Some(next) => query = next,
+                None => break,
+            }
+        }
+        withdrawal_limits.check(today, amount, &withdrawn)?;
+        Ok(())
+    }
+
+    /// Locks the deposit account row before reading its balance so that concurrent
+    /// operations debiting the same account are checked one after the other.
+    async fn check_available_balance_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        deposit_account_id: DepositAccountId,
+        amount: Money,
+    ) -> Result<(), CoreDepositError> {
+        sqlx::query("SELECT id FROM core_deposit_accounts WHERE id = $1 FOR UPDATE")
+            .bind(deposit_account_id)
+            .execute(op.as_executor())
+            .await?;
+        let balance = self
+            .ledger
+            .currency_balance_in_op(&mut *op, deposit_account_id, amount.currency())
+            .await?;
+        if balance.available().minor_units() < amount.minor_units() {
+            return Err(CoreDepositError::InsufficientAvailableBalance);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use domain_config::define_internal_config;
+
+use super::originator::PaymentOriginator;
+
+define_internal_config! {
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub(crate) struct DepositPaymentOriginator {
+        pub(crate) originator: PaymentOriginator,
+    }
+
+    spec {
+        key: "deposit-payment-originator";
+    }
+}
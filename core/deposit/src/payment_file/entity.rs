@@ -0,0 +1,392 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use document_storage::DocumentId;
+use es_entity::*;
+
+use crate::primitives::{PaymentFileId, UsdCents, WithdrawalId};
+
+use super::{
+    error::PaymentFileError,
+    pain002::{PaymentStatusCode, PaymentStatusReport},
+};
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum PaymentFileStatus {
+    #[default]
+    Sent,
+    PartiallyProcessed,
+    Processed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "PaymentFileId")]
+pub enum PaymentFileEvent {
+    Initialized {
+        id: PaymentFileId,
+        message_id: String,
+        document_id: DocumentId,
+        withdrawal_ids: Vec<WithdrawalId>,
+        control_sum: UsdCents,
+        status: PaymentFileStatus,
+    },
+    StatusReportImported {
+        document_id: DocumentId,
+        accepted: Vec<WithdrawalId>,
+        rejected: Vec<WithdrawalId>,
+        status: PaymentFileStatus,
+    },
+}
+
+/// Withdrawals whose outcome changed with an imported status report.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct PaymentStatusOutcome {
+    pub accepted: Vec<WithdrawalId>,
+    pub rejected: Vec<WithdrawalId>,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct PaymentFile {
+    pub id: PaymentFileId,
+    pub message_id: String,
+    pub document_id: DocumentId,
+    pub withdrawal_ids: Vec<WithdrawalId>,
+    pub control_sum: UsdCents,
+
+    events: EntityEvents<PaymentFileEvent>,
+}
+
+impl PaymentFile {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for payment file")
+    }
+
+    pub fn status(&self) -> PaymentFileStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                PaymentFileEvent::Initialized { status, .. }
+                | PaymentFileEvent::StatusReportImported { status, .. } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn status_report_document_ids(&self) -> Vec<DocumentId> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                PaymentFileEvent::StatusReportImported { document_id, .. } => Some(*document_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn accepted_withdrawal_ids(&self) -> Vec<WithdrawalId> {
+        let rejected = self.rejected_withdrawal_ids();
+        self.events
+            .iter_all()
+            .flat_map(|e| match e {
+                PaymentFileEvent::StatusReportImported { accepted, .. } => accepted.as_slice(),
+                _ => &[],
+            })
+            .filter(|id| !rejected.contains(id))
+            .copied()
+            .collect()
+    }
+
+    pub fn rejected_withdrawal_ids(&self) -> Vec<WithdrawalId> {
+        self.events
+            .iter_all()
+            .flat_map(|e| match e {
+                PaymentFileEvent::StatusReportImported { rejected, .. } => rejected.as_slice(),
+                _ => &[],
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Applies a pain.002 status report. Acceptances are only taken for withdrawals without an
+    /// outcome yet, while a rejection also applies to previously accepted withdrawals so that
+    /// returned payments get reverted.
+    pub(crate) fn record_status_report(
+        &mut self,
+        document_id: DocumentId,
+        report: &PaymentStatusReport,
+    ) -> Result<Idempotent<PaymentStatusOutcome>, PaymentFileError> {
+        if report.original_message_id != self.message_id {
+            return Err(PaymentFileError::UnknownOriginalMessageId(
+                report.original_message_id.clone(),
+            ));
+        }
+
+        let statuses = if report.transactions.is_empty() {
+            report
+                .group_status
+                .map(|status| {
+                    self.withdrawal_ids
+                        .iter()
+                        .map(|id| (*id, status))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        } else {
+            report
+                .transactions
+                .iter()
+                .map(|transaction| {
+                    let id = self
+                        .withdrawal_id_for(&transaction.end_to_end_id)
+                        .ok_or_else(|| {
+                            PaymentFileError::UnknownEndToEndId(
+                                self.id,
+                                transaction.end_to_end_id.clone(),
+                            )
+                        })?;
+                    Ok((id, transaction.status))
+                })
+                .collect::<Result<Vec<_>, PaymentFileError>>()?
+        };
+
+        let previously_accepted = self.accepted_withdrawal_ids();
+        let previously_rejected = self.rejected_withdrawal_ids();
+        let mut outcome = PaymentStatusOutcome::default();
+        for (id, status) in statuses {
+            match status {
+                PaymentStatusCode::Settled
+                    if !previously_accepted.contains(&id)
+                        && !previously_rejected.contains(&id)
+                        && !outcome.accepted.contains(&id) =>
+                {
+                    outcome.accepted.push(id)
+                }
+                PaymentStatusCode::Rejected
+                    if !previously_rejected.contains(&id) && !outcome.rejected.contains(&id) =>
+                {
+                    outcome.accepted.retain(|accepted| *accepted != id);
+                    outcome.rejected.push(id)
+                }
+                _ => (),
+            }
+        }
+
+        if outcome == PaymentStatusOutcome::default() {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        let processed = self.withdrawal_ids.iter().all(|id| {
+            previously_accepted.contains(id)
+                || previously_rejected.contains(id)
+                || outcome.accepted.contains(id)
+                || outcome.rejected.contains(id)
+        });
+        self.events.push(PaymentFileEvent::StatusReportImported {
+            document_id,
+            accepted: outcome.accepted.clone(),
+            rejected: outcome.rejected.clone(),
+            status: if processed {
+                PaymentFileStatus::Processed
+            } else {
+                PaymentFileStatus::PartiallyProcessed
+            },
+        });
+
+        Ok(Idempotent::Executed(outcome))
+    }
+
+    fn withdrawal_id_for(&self, end_to_end_id: &str) -> Option<WithdrawalId> {
+        let id = end_to_end_id.parse::<uuid::Uuid>().ok()?;
+        self.withdrawal_ids
+            .iter()
+            .find(|withdrawal_id| uuid::Uuid::from(**withdrawal_id) == id)
+            .copied()
+    }
+}
+
+impl TryFromEvents<PaymentFileEvent> for PaymentFile {
+    fn try_from_events(
+        events: EntityEvents<PaymentFileEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = PaymentFileBuilder::default();
+        for event in events.iter_all() {
+            if let PaymentFileEvent::Initialized {
+                id,
+                message_id,
+                document_id,
+                withdrawal_ids,
+                control_sum,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .message_id(message_id.clone())
+                    .document_id(*document_id)
+                    .withdrawal_ids(withdrawal_ids.clone())
+                    .control_sum(*control_sum)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewPaymentFile {
+    #[builder(setter(into))]
+    pub(super) id: PaymentFileId,
+    #[builder(setter(into))]
+    pub(super) message_id: String,
+    pub(super) document_id: DocumentId,
+    pub(super) withdrawal_ids: Vec<WithdrawalId>,
+    pub(super) control_sum: UsdCents,
+    #[builder(setter(skip), default)]
+    pub(super) status: PaymentFileStatus,
+}
+
+impl NewPaymentFile {
+    pub fn builder() -> NewPaymentFileBuilder {
+        NewPaymentFileBuilder::default()
+    }
+}
+
+impl IntoEvents<PaymentFileEvent> for NewPaymentFile {
+    fn into_events(self) -> EntityEvents<PaymentFileEvent> {
+        EntityEvents::init(
+            self.id,
+            [PaymentFileEvent::Initialized {
+                id: self.id,
+                message_id: self.message_id,
+                document_id: self.document_id,
+                withdrawal_ids: self.withdrawal_ids,
+                control_sum: self.control_sum,
+                status: self.status,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::pain002::TransactionStatus;
+    use super::*;
+
+    fn payment_file(withdrawal_ids: Vec<WithdrawalId>) -> PaymentFile {
+        let new_payment_file = NewPaymentFile::builder()
+            .id(PaymentFileId::new())
+            .message_id("msg1")
+            .document_id(DocumentId::new())
+            .withdrawal_ids(withdrawal_ids)
+            .control_sum(UsdCents::from(200))
+            .build()
+            .unwrap();
+        PaymentFile::try_from_events(new_payment_file.into_events()).unwrap()
+    }
+
+    fn report(transactions: Vec<(WithdrawalId, PaymentStatusCode)>) -> PaymentStatusReport {
+        PaymentStatusReport {
+            original_message_id: "msg1".to_string(),
+            group_status: None,
+            transactions: transactions
+                .into_iter()
+                .map(|(id, status)| TransactionStatus {
+                    end_to_end_id: uuid::Uuid::from(id).simple().to_string(),
+                    status,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn acceptance_then_rejection_of_same_withdrawal() {
+        let first = WithdrawalId::new();
+        let second = WithdrawalId::new();
+        let mut payment_file = payment_file(vec![first, second]);
+
+        let outcome = payment_file
+            .record_status_report(
+                DocumentId::new(),
+                &report(vec![(first, PaymentStatusCode::Settled)]),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(outcome.accepted, vec![first]);
+        assert_eq!(payment_file.status(), PaymentFileStatus::PartiallyProcessed);
+
+        assert!(
+            payment_file
+                .record_status_report(
+                    DocumentId::new(),
+                    &report(vec![(first, PaymentStatusCode::Settled)]),
+                )
+                .unwrap()
+                .was_already_applied()
+        );
+
+        let outcome = payment_file
+            .record_status_report(
+                DocumentId::new(),
+                &report(vec![
+                    (first, PaymentStatusCode::Rejected),
+                    (second, PaymentStatusCode::Settled),
+                ]),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(outcome.rejected, vec![first]);
+        assert_eq!(outcome.accepted, vec![second]);
+        assert_eq!(payment_file.status(), PaymentFileStatus::Processed);
+        assert_eq!(payment_file.accepted_withdrawal_ids(), vec![second]);
+    }
+
+    #[test]
+    fn group_rejection_applies_to_all_withdrawals() {
+        let ids = vec![WithdrawalId::new(), WithdrawalId::new()];
+        let mut payment_file = payment_file(ids.clone());
+        let mut report = report(vec![]);
+        report.group_status = Some(PaymentStatusCode::Rejected);
+
+        let outcome = payment_file
+            .record_status_report(DocumentId::new(), &report)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(outcome.rejected, ids);
+        assert_eq!(payment_file.status(), PaymentFileStatus::Processed);
+    }
+
+    #[test]
+    fn unknown_end_to_end_id_is_rejected() {
+        let mut payment_file = payment_file(vec![WithdrawalId::new()]);
+
+        assert!(matches!(
+            payment_file.record_status_report(
+                DocumentId::new(),
+                &report(vec![(WithdrawalId::new(), PaymentStatusCode::Settled)]),
+            ),
+            Err(PaymentFileError::UnknownEndToEndId(..))
+        ));
+    }
+}
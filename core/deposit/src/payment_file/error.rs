@@ -0,0 +1,53 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::PaymentFileId;
+
+use super::repo::{
+    PaymentFileCreateError, PaymentFileFindError, PaymentFileModifyError, PaymentFileQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum PaymentFileError {
+    #[error("PaymentFileError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("PaymentFileError - Create: {0}")]
+    Create(#[from] PaymentFileCreateError),
+    #[error("PaymentFileError - Modify: {0}")]
+    Modify(#[from] PaymentFileModifyError),
+    #[error("PaymentFileError - Find: {0}")]
+    Find(#[from] PaymentFileFindError),
+    #[error("PaymentFileError - Query: {0}")]
+    Query(#[from] PaymentFileQueryError),
+    #[error("PaymentFileError - OriginatorNotConfigured")]
+    OriginatorNotConfigured,
+    #[error("PaymentFileError - InvalidOriginator: {0}")]
+    InvalidOriginator(String),
+    #[error("PaymentFileError - InvalidStatusReport: {0}")]
+    InvalidStatusReport(String),
+    #[error("PaymentFileError - UnknownOriginalMessageId: {0}")]
+    UnknownOriginalMessageId(String),
+    #[error("PaymentFileError - UnknownEndToEndId: {1} is not part of payment file {0}")]
+    UnknownEndToEndId(PaymentFileId, String),
+    #[error("PaymentFileError - MissingCreditorAccount: no IBAN or account number for {0}")]
+    MissingCreditorAccount(String),
+}
+
+impl ErrorSeverity for PaymentFileError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::OriginatorNotConfigured => Level::WARN,
+            Self::InvalidOriginator(_) => Level::WARN,
+            Self::InvalidStatusReport(_) => Level::WARN,
+            Self::UnknownOriginalMessageId(_) => Level::WARN,
+            Self::UnknownEndToEndId(..) => Level::WARN,
+            Self::MissingCreditorAccount(_) => Level::ERROR,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+mod config;
+mod entity;
+pub mod error;
+mod originator;
+mod pain001;
+mod pain002;
+mod repo;
+
+use document_storage::DocumentType;
+
+pub(crate) use config::DepositPaymentOriginator;
+#[cfg(feature = "json-schema")]
+pub use entity::PaymentFileEvent;
+pub(super) use entity::*;
+pub use entity::{PaymentFile, PaymentFileStatus};
+pub use originator::PaymentOriginator;
+pub(super) use pain001::*;
+pub(super) use pain002::*;
+pub use repo::payment_file_cursor::PaymentFilesByCreatedAtCursor;
+pub(super) use repo::*;
+
+pub(crate) const PAYMENT_FILE_DOCUMENT_TYPE: DocumentType =
+    DocumentType::new("withdrawal_payment_file");
+pub(crate) const PAYMENT_STATUS_REPORT_DOCUMENT_TYPE: DocumentType =
+    DocumentType::new("payment_status_report");
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::beneficiary::{is_valid_iban, is_valid_swift_bic};
+
+/// The institution's own account that outgoing credit transfers are debited from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentOriginator {
+    pub name: String,
+    pub iban: String,
+    pub bic: String,
+}
+
+impl PaymentOriginator {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Payment originator name cannot be empty".to_string());
+        }
+        if !is_valid_iban(&self.iban) {
+            return Err(format!("Invalid IBAN: {}", self.iban));
+        }
+        if !is_valid_swift_bic(&self.bic) {
+            return Err(format!("Invalid SWIFT/BIC code: {}", self.bic));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use xml_utils::escape;
+
+use std::fmt::Write;
+
+use crate::{beneficiary::BankAccountDetails, primitives::UsdCents};
+
+use super::{error::PaymentFileError, originator::PaymentOriginator};
+
+const PAIN_001_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.09";
+const CURRENCY: &str = "USD";
+
+pub(crate) struct CreditTransfer<'a> {
+    pub end_to_end_id: String,
+    pub amount: UsdCents,
+    pub creditor: &'a BankAccountDetails,
+    pub remittance_information: &'a str,
+}
+
+/// Renders a pain.001 customer credit transfer initiation with a single payment
+/// information block debiting the originator's account.
+pub(crate) fn credit_transfer_initiation(
+    message_id: &str,
+    created_at: DateTime<Utc>,
+    requested_execution_date: NaiveDate,
+    originator: &PaymentOriginator,
+    transfers: &[CreditTransfer<'_>],
+) -> Result<String, PaymentFileError> {
+    let number_of_transactions = transfers.len();
+    let control_sum = format_amount(
+        transfers
+            .iter()
+            .fold(UsdCents::ZERO, |sum, transfer| sum + transfer.amount),
+    );
+
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = write!(xml, r#"<Document xmlns="{PAIN_001_NAMESPACE}">"#);
+    xml.push_str("<CstmrCdtTrfInitn>");
+
+    xml.push_str("<GrpHdr>");
+    write_element(&mut xml, "MsgId", message_id);
+    write_element(
+        &mut xml,
+        "CreDtTm",
+        &created_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+    );
+    write_element(&mut xml, "NbOfTxs", &number_of_transactions.to_string());
+    write_element(&mut xml, "CtrlSum", &control_sum);
+    xml.push_str("<InitgPty>");
+    write_element(&mut xml, "Nm", &originator.name);
+    xml.push_str("</InitgPty>");
+    xml.push_str("</GrpHdr>");
+
+    xml.push_str("<PmtInf>");
+    write_element(&mut xml, "PmtInfId", message_id);
+    write_element(&mut xml, "PmtMtd", "TRF");
+    write_element(&mut xml, "NbOfTxs", &number_of_transactions.to_string());
+    write_element(&mut xml, "CtrlSum", &control_sum);
+    xml.push_str("<ReqdExctnDt>");
+    write_element(
+        &mut xml,
+        "Dt",
+        &requested_execution_date.format("%Y-%m-%d").to_string(),
+    );
+    xml.push_str("</ReqdExctnDt>");
+    xml.push_str("<Dbtr>");
+    write_element(&mut xml, "Nm", &originator.name);
+    xml.push_str("</Dbtr>");
+    xml.push_str("<DbtrAcct><Id>");
+    write_element(&mut xml, "IBAN", &normalize(&originator.iban));
+    xml.push_str("</Id>");
+    write_element(&mut xml, "Ccy", CURRENCY);
+    xml.push_str("</DbtrAcct>");
+    xml.push_str("<DbtrAgt><FinInstnId>");
+    write_element(&mut xml, "BICFI", &originator.bic);
+    xml.push_str("</FinInstnId></DbtrAgt>");
+
+    for transfer in transfers {
+        write_credit_transfer(&mut xml, transfer)?;
+    }
+
+    xml.push_str("</PmtInf>");
+    xml.push_str("</CstmrCdtTrfInitn>");
+    xml.push_str("</Document>");
+    Ok(xml)
+}
+
+fn write_credit_transfer(
+    xml: &mut String,
+    transfer: &CreditTransfer<'_>,
+) -> Result<(), PaymentFileError> {
+    let creditor = transfer.creditor;
+
+    xml.push_str("<CdtTrfTxInf>");
+    xml.push_str("<PmtId>");
+    write_element(xml, "InstrId", &transfer.end_to_end_id);
+    write_element(xml, "EndToEndId", &transfer.end_to_end_id);
+    xml.push_str("</PmtId>");
+    let _ = write!(
+        xml,
+        r#"<Amt><InstdAmt Ccy="{CURRENCY}">{}</InstdAmt></Amt>"#,
+        format_amount(transfer.amount)
+    );
+
+    if creditor.swift_bic.is_some() || creditor.aba_routing_number.is_some() {
+        xml.push_str("<CdtrAgt><FinInstnId>");
+        if let Some(bic) = &creditor.swift_bic {
+            write_element(xml, "BICFI", bic);
+        }
+        if let Some(aba) = &creditor.aba_routing_number {
+            xml.push_str("<ClrSysMmbId><ClrSysId>");
+            write_element(xml, "Cd", "USABA");
+            xml.push_str("</ClrSysId>");
+            write_element(xml, "MmbId", aba);
+            xml.push_str("</ClrSysMmbId>");
+        }
+        if let Some(bank_name) = &creditor.bank_name {
+            write_element(xml, "Nm", bank_name);
+        }
+        xml.push_str("</FinInstnId></CdtrAgt>");
+    }
+
+    xml.push_str("<Cdtr>");
+    write_element(xml, "Nm", &creditor.account_holder_name);
+    xml.push_str("</Cdtr>");
+    xml.push_str("<CdtrAcct><Id>");
+    match (&creditor.iban, &creditor.account_number) {
+        (Some(iban), _) => write_element(xml, "IBAN", &normalize(iban)),
+        (None, Some(account_number)) => {
+            xml.push_str("<Othr>");
+            write_element(xml, "Id", account_number);
+            xml.push_str("</Othr>");
+        }
+        (None, None) => {
+            return Err(PaymentFileError::MissingCreditorAccount(
+                transfer.end_to_end_id.clone(),
+            ));
+        }
+    }
+    xml.push_str("</Id></CdtrAcct>");
+
+    if !transfer.remittance_information.is_empty() {
+        xml.push_str("<RmtInf>");
+        write_element(xml, "Ustrd", transfer.remittance_information);
+        xml.push_str("</RmtInf>");
+    }
+    xml.push_str("</CdtTrfTxInf>");
+    Ok(())
+}
+
+fn write_element(xml: &mut String, tag: &str, value: &str) {
+    let _ = write!(xml, "<{tag}>{}</{tag}>", escape(value));
+}
+
+fn format_amount(amount: UsdCents) -> String {
+    format!("{:.2}", amount.to_usd())
+}
+
+fn normalize(iban: &str) -> String {
+    iban.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn originator() -> PaymentOriginator {
+        PaymentOriginator {
+            name: "Lana Bank".to_string(),
+            iban: "DE89 3704 0044 0532 0130 00".to_string(),
+            bic: "COBADEFFXXX".to_string(),
+        }
+    }
+
+    #[test]
+    fn renders_group_header_and_transactions() {
+        let iban_creditor = BankAccountDetails {
+            account_holder_name: "Jane & John Doe".to_string(),
+            bank_name: None,
+            iban: Some("GB29NWBK60161331926819".to_string()),
+            account_number: None,
+            aba_routing_number: None,
+            swift_bic: None,
+        };
+        let aba_creditor = BankAccountDetails {
+            account_holder_name: "Acme Corp".to_string(),
+            bank_name: Some("Chase".to_string()),
+            iban: None,
+            account_number: Some("123456789".to_string()),
+            aba_routing_number: Some("021000021".to_string()),
+            swift_bic: None,
+        };
+        let transfers = [
+            CreditTransfer {
+                end_to_end_id: "e2e1".to_string(),
+                amount: UsdCents::from(12_345),
+                creditor: &iban_creditor,
+                remittance_information: "rent",
+            },
+            CreditTransfer {
+                end_to_end_id: "e2e2".to_string(),
+                amount: UsdCents::from(100),
+                creditor: &aba_creditor,
+                remittance_information: "",
+            },
+        ];
+
+        let xml = credit_transfer_initiation(
+            "msg1",
+            Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            &originator(),
+            &transfers,
+        )
+        .unwrap();
+
+        assert!(xml.contains("<MsgId>msg1</MsgId><CreDtTm>2024-01-02T03:04:05Z</CreDtTm>"));
+        assert!(xml.contains("<NbOfTxs>2</NbOfTxs><CtrlSum>124.45</CtrlSum>"));
+        assert!(xml.contains("<DbtrAcct><Id><IBAN>DE89370400440532013000</IBAN></Id>"));
+        assert!(xml.contains("<Nm>Jane &amp; John Doe</Nm>"));
+        assert!(xml.contains(r#"<InstdAmt Ccy="USD">123.45</InstdAmt>"#));
+        assert!(xml.contains("<MmbId>021000021</MmbId>"));
+        assert!(xml.contains("<Othr><Id>123456789</Id></Othr>"));
+        assert_eq!(xml.matches("<RmtInf>").count(), 1);
+    }
+
+    #[test]
+    fn rejects_creditor_without_account() {
+        let creditor = BankAccountDetails {
+            account_holder_name: "Jane Doe".to_string(),
+            bank_name: None,
+            iban: None,
+            account_number: None,
+            aba_routing_number: None,
+            swift_bic: Some("COBADEFFXXX".to_string()),
+        };
+        let transfers = [CreditTransfer {
+            end_to_end_id: "e2e1".to_string(),
+            amount: UsdCents::from(100),
+            creditor: &creditor,
+            remittance_information: "",
+        }];
+
+        assert!(matches!(
+            credit_transfer_initiation(
+                "msg1",
+                Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+                &originator(),
+                &transfers,
+            ),
+            Err(PaymentFileError::MissingCreditorAccount(id)) if id == "e2e1"
+        ));
+    }
+}
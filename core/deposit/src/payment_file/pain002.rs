@@ -0,0 +1,228 @@
+use roxmltree::{Document, Node};
+
+use super::error::PaymentFileError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PaymentStatusCode {
+    Settled,
+    Rejected,
+    Pending,
+}
+
+impl PaymentStatusCode {
+    /// Only a completed settlement on the debtor account (ACSC) is final. Earlier acceptances
+    /// such as ACCP or ACSP can still be followed by a rejection.
+    fn from_code(code: &str) -> Self {
+        match code {
+            "ACSC" => PaymentStatusCode::Settled,
+            "RJCT" => PaymentStatusCode::Rejected,
+            _ => PaymentStatusCode::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TransactionStatus {
+    pub end_to_end_id: String,
+    pub status: PaymentStatusCode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PaymentStatusReport {
+    pub original_message_id: String,
+    pub group_status: Option<PaymentStatusCode>,
+    pub transactions: Vec<TransactionStatus>,
+}
+
+impl PaymentStatusReport {
+    /// Parses the parts of a pain.002 customer payment status report needed to settle the
+    /// original pain.001. Elements are matched on their local name, whatever the namespace.
+    pub(crate) fn parse(content: &[u8]) -> Result<Self, PaymentFileError> {
+        let xml = std::str::from_utf8(content)
+            .map_err(|e| PaymentFileError::InvalidStatusReport(e.to_string()))?;
+        let document = Document::parse(xml)
+            .map_err(|e| PaymentFileError::InvalidStatusReport(e.to_string()))?;
+
+        let report = child(document.root_element(), "CstmrPmtStsRpt").ok_or_else(|| {
+            PaymentFileError::InvalidStatusReport("missing CstmrPmtStsRpt".to_string())
+        })?;
+        let group = child(report, "OrgnlGrpInfAndSts").ok_or_else(|| {
+            PaymentFileError::InvalidStatusReport("missing OrgnlGrpInfAndSts".to_string())
+        })?;
+        let original_message_id = child_text(group, "OrgnlMsgId").ok_or_else(|| {
+            PaymentFileError::InvalidStatusReport("missing OrgnlMsgId".to_string())
+        })?;
+        let group_status =
+            child_text(group, "GrpSts").map(|code| PaymentStatusCode::from_code(&code));
+
+        let transactions = children(report, "OrgnlPmtInfAndSts")
+            .flat_map(|payment_information| children(payment_information, "TxInfAndSts"))
+            .map(|transaction| {
+                let end_to_end_id =
+                    child_text(transaction, "OrgnlEndToEndId").ok_or_else(|| {
+                        PaymentFileError::InvalidStatusReport("missing OrgnlEndToEndId".to_string())
+                    })?;
+                let status = child_text(transaction, "TxSts")
+                    .map(|code| PaymentStatusCode::from_code(&code))
+                    .or(group_status)
+                    .ok_or_else(|| {
+                        PaymentFileError::InvalidStatusReport(format!(
+                            "missing TxSts for {end_to_end_id}"
+                        ))
+                    })?;
+                Ok(TransactionStatus {
+                    end_to_end_id,
+                    status,
+                })
+            })
+            .collect::<Result<Vec<_>, PaymentFileError>>()?;
+
+        Ok(Self {
+            original_message_id,
+            group_status,
+            transactions,
+        })
+    }
+}
+
+fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn child<'a, 'input: 'a>(node: Node<'a, 'input>, name: &'static str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+fn child_text(node: Node<'_, '_>, name: &'static str) -> Option<String> {
+    child(node, name)
+        .and_then(|element| element.text())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_transaction_statuses() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Document xmlns="urn:iso:std:iso:20022:tech:xsd:pain.002.001.10">
+              <CstmrPmtStsRpt>
+                <GrpHdr><MsgId>report1</MsgId></GrpHdr>
+                <OrgnlGrpInfAndSts>
+                  <OrgnlMsgId>msg1</OrgnlMsgId>
+                  <OrgnlMsgNmId>pain.001.001.09</OrgnlMsgNmId>
+                  <GrpSts>PART</GrpSts>
+                </OrgnlGrpInfAndSts>
+                <OrgnlPmtInfAndSts>
+                  <TxInfAndSts>
+                    <OrgnlEndToEndId>e2e1</OrgnlEndToEndId>
+                    <TxSts>ACSC</TxSts>
+                  </TxInfAndSts>
+                  <TxInfAndSts>
+                    <OrgnlEndToEndId>e2e2</OrgnlEndToEndId>
+                    <TxSts>RJCT</TxSts>
+                    <StsRsnInf><Rsn><Cd>AC04</Cd></Rsn></StsRsnInf>
+                  </TxInfAndSts>
+                  <TxInfAndSts>
+                    <OrgnlEndToEndId>e2e3</OrgnlEndToEndId>
+                    <TxSts>PDNG</TxSts>
+                  </TxInfAndSts>
+                </OrgnlPmtInfAndSts>
+              </CstmrPmtStsRpt>
+            </Document>"#;
+
+        let report = PaymentStatusReport::parse(xml.as_bytes()).unwrap();
+
+        assert_eq!(report.original_message_id, "msg1");
+        assert_eq!(report.group_status, Some(PaymentStatusCode::Pending));
+        assert_eq!(
+            report
+                .transactions
+                .iter()
+                .map(|t| (t.end_to_end_id.as_str(), t.status))
+                .collect::<Vec<_>>(),
+            vec![
+                ("e2e1", PaymentStatusCode::Settled),
+                ("e2e2", PaymentStatusCode::Rejected),
+                ("e2e3", PaymentStatusCode::Pending),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_group_level_rejection() {
+        let xml = r#"<Document><CstmrPmtStsRpt>
+            <OrgnlGrpInfAndSts><OrgnlMsgId>msg1</OrgnlMsgId><GrpSts>RJCT</GrpSts></OrgnlGrpInfAndSts>
+            </CstmrPmtStsRpt></Document>"#;
+
+        let report = PaymentStatusReport::parse(xml.as_bytes()).unwrap();
+
+        assert_eq!(report.group_status, Some(PaymentStatusCode::Rejected));
+        assert!(report.transactions.is_empty());
+    }
+
+    #[test]
+    fn only_completed_settlement_is_final() {
+        let xml = r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pain.002.001.10"><CstmrPmtStsRpt>
+            <OrgnlGrpInfAndSts><OrgnlMsgId>msg1</OrgnlMsgId></OrgnlGrpInfAndSts>
+            <OrgnlPmtInfAndSts>
+              <TxInfAndSts><OrgnlEndToEndId>e2e1</OrgnlEndToEndId><TxSts>ACCP</TxSts></TxInfAndSts>
+              <TxInfAndSts><OrgnlEndToEndId>e2e2</OrgnlEndToEndId><TxSts>ACSP</TxSts></TxInfAndSts>
+              <TxInfAndSts><OrgnlEndToEndId>e2e3</OrgnlEndToEndId><TxSts>ACSC</TxSts></TxInfAndSts>
+            </OrgnlPmtInfAndSts>
+            </CstmrPmtStsRpt></Document>"#;
+
+        let report = PaymentStatusReport::parse(xml.as_bytes()).unwrap();
+
+        assert_eq!(
+            report
+                .transactions
+                .iter()
+                .map(|t| t.status)
+                .collect::<Vec<_>>(),
+            vec![
+                PaymentStatusCode::Pending,
+                PaymentStatusCode::Pending,
+                PaymentStatusCode::Settled,
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_prefixed_elements_and_entities() {
+        let xml = r#"<p:Document xmlns:p="urn:iso:std:iso:20022:tech:xsd:pain.002.001.10"><p:CstmrPmtStsRpt>
+            <p:OrgnlGrpInfAndSts><p:OrgnlMsgId>msg&amp;1</p:OrgnlMsgId><p:GrpSts>RJCT</p:GrpSts></p:OrgnlGrpInfAndSts>
+            </p:CstmrPmtStsRpt></p:Document>"#;
+
+        let report = PaymentStatusReport::parse(xml.as_bytes()).unwrap();
+
+        assert_eq!(report.original_message_id, "msg&1");
+        assert_eq!(report.group_status, Some(PaymentStatusCode::Rejected));
+    }
+
+    #[test]
+    fn rejects_malformed_documents() {
+        let xml = "<Document><CstmrPmtStsRpt></Document>";
+
+        assert!(matches!(
+            PaymentStatusReport::parse(xml.as_bytes()),
+            Err(PaymentFileError::InvalidStatusReport(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_documents_without_original_message_id() {
+        let xml = "<Document><CstmrPmtStsRpt><OrgnlGrpInfAndSts/></CstmrPmtStsRpt></Document>";
+
+        assert!(matches!(
+            PaymentStatusReport::parse(xml.as_bytes()),
+            Err(PaymentFileError::InvalidStatusReport(_))
+        ));
+    }
+}
@@ -4,6 +4,8 @@ use cala_ledger::DebitOrCredit;
 pub enum DepositAccountCategory {
     Asset,
     Liability,
+    Revenue,
+    Expenses,
 }
 
 impl From<DepositAccountCategory> for chart_primitives::AccountCategory {
@@ -11,6 +13,8 @@ impl From<DepositAccountCategory> for chart_primitives::AccountCategory {
         match value {
             DepositAccountCategory::Asset => Self::Asset,
             DepositAccountCategory::Liability => Self::Liability,
+            DepositAccountCategory::Revenue => Self::Revenue,
+            DepositAccountCategory::Expenses => Self::Expenses,
         }
     }
 }
@@ -71,6 +75,10 @@ pub struct DepositAccountSetCatalog {
     deposit: DepositAccountSetCatalogGroup,
     frozen: DepositAccountSetCatalogGroup,
     omnibus: DepositOmnibusAccountSetSpec,
+    interest_expense: DepositOmnibusAccountSetSpec,
+    fee_income: DepositOmnibusAccountSetSpec,
+    unclaimed_property: DepositOmnibusAccountSetSpec,
+    fx_position: DepositOmnibusAccountSetSpec,
 }
 
 #[derive(Debug, Clone)]
@@ -96,6 +104,18 @@ impl DepositAccountSetCatalog {
         &self.omnibus
     }
 
+    pub fn interest_expense(&self) -> &DepositOmnibusAccountSetSpec {
+        &self.interest_expense
+    }
+
+    pub fn fee_income(&self) -> &DepositOmnibusAccountSetSpec {
+        &self.fee_income
+    }
+
+    pub fn unclaimed_property(&self) -> &DepositOmnibusAccountSetSpec {
+        &self.unclaimed_property
+    }
+
     pub fn deposit_specs(&self) -> [DepositSummaryAccountSetSpec; 6] {
         [
             self.deposit.individual,
@@ -118,8 +138,18 @@ impl DepositAccountSetCatalog {
         ]
     }
 
-    pub fn omnibus_specs(&self) -> [DepositOmnibusAccountSetSpec; 1] {
-        [self.omnibus]
+    pub fn fx_position(&self) -> &DepositOmnibusAccountSetSpec {
+        &self.fx_position
+    }
+
+    pub fn omnibus_specs(&self) -> [DepositOmnibusAccountSetSpec; 5] {
+        [
+            self.omnibus,
+            self.interest_expense,
+            self.fee_income,
+            self.unclaimed_property,
+            self.fx_position,
+        ]
     }
 }
 
@@ -265,6 +295,54 @@ const DEPOSIT_OMNIBUS_ACCOUNT_SET: DepositOmnibusAccountSetSpec = DepositOmnibus
     DebitOrCredit::Debit,
 );
 
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME: &str = "Deposit Interest Expense Account Set";
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF: &str = "deposit-interest-expense-account-set";
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF: &str = "deposit-interest-expense-account";
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET: DepositOmnibusAccountSetSpec =
+    DepositOmnibusAccountSetSpec::new(
+        DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME,
+        DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF,
+        DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF,
+        DepositAccountCategory::Expenses,
+        DebitOrCredit::Debit,
+    );
+
+const DEPOSIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Deposit Fee Income Account Set";
+const DEPOSIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "deposit-fee-income-account-set";
+const DEPOSIT_FEE_INCOME_ACCOUNT_REF: &str = "deposit-fee-income-account";
+const DEPOSIT_FEE_INCOME_ACCOUNT_SET: DepositOmnibusAccountSetSpec =
+    DepositOmnibusAccountSetSpec::new(
+        DEPOSIT_FEE_INCOME_ACCOUNT_SET_NAME,
+        DEPOSIT_FEE_INCOME_ACCOUNT_SET_REF,
+        DEPOSIT_FEE_INCOME_ACCOUNT_REF,
+        DepositAccountCategory::Revenue,
+        DebitOrCredit::Credit,
+    );
+
+const DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_SET_NAME: &str = "Deposit Unclaimed Property Account Set";
+const DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_SET_REF: &str = "deposit-unclaimed-property-account-set";
+const DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_REF: &str = "deposit-unclaimed-property-account";
+const DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_SET: DepositOmnibusAccountSetSpec =
+    DepositOmnibusAccountSetSpec::new(
+        DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_SET_NAME,
+        DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_SET_REF,
+        DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_REF,
+        DepositAccountCategory::Liability,
+        DebitOrCredit::Credit,
+    );
+
+const DEPOSIT_FX_POSITION_ACCOUNT_SET_NAME: &str = "Deposit FX Position Account Set";
+const DEPOSIT_FX_POSITION_ACCOUNT_SET_REF: &str = "deposit-fx-position-account-set";
+const DEPOSIT_FX_POSITION_ACCOUNT_REF: &str = "deposit-fx-position-account";
+const DEPOSIT_FX_POSITION_ACCOUNT_SET: DepositOmnibusAccountSetSpec =
+    DepositOmnibusAccountSetSpec::new(
+        DEPOSIT_FX_POSITION_ACCOUNT_SET_NAME,
+        DEPOSIT_FX_POSITION_ACCOUNT_SET_REF,
+        DEPOSIT_FX_POSITION_ACCOUNT_REF,
+        DepositAccountCategory::Asset,
+        DebitOrCredit::Debit,
+    );
+
 pub const DEPOSIT_ACCOUNT_SET_CATALOG: DepositAccountSetCatalog = DepositAccountSetCatalog {
     deposit: DepositAccountSetCatalogGroup {
         individual: DEPOSIT_INDIVIDUAL_ACCOUNT_SET,
@@ -283,4 +361,8 @@ pub const DEPOSIT_ACCOUNT_SET_CATALOG: DepositAccountSetCatalog = DepositAccount
         non_domiciled_company: FROZEN_DEPOSIT_NON_DOMICILED_COMPANY_ACCOUNT_SET,
     },
     omnibus: DEPOSIT_OMNIBUS_ACCOUNT_SET,
+    interest_expense: DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET,
+    fee_income: DEPOSIT_FEE_INCOME_ACCOUNT_SET,
+    unclaimed_property: DEPOSIT_UNCLAIMED_PROPERTY_ACCOUNT_SET,
+    fx_position: DEPOSIT_FX_POSITION_ACCOUNT_SET,
 };
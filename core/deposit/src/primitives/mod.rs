@@ -13,6 +13,14 @@ use std::{fmt::Display, str::FromStr};
 use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 
 pub const DEPOSIT_APPROVAL: audit::SystemActor = audit::SystemActor::new("deposit-approval");
+pub const HOLD_EXPIRY: audit::SystemActor = audit::SystemActor::new("hold-expiry");
+pub const DEPOSIT_INTEREST: audit::SystemActor = audit::SystemActor::new("deposit-interest");
+pub const DEPOSIT_FEES: audit::SystemActor = audit::SystemActor::new("deposit-fees");
+pub const DEPOSIT_ESCHEATMENT: audit::SystemActor = audit::SystemActor::new("deposit-escheatment");
+pub const DEPOSIT_IMPORT: audit::SystemActor = audit::SystemActor::new("deposit-import");
+pub const ACCOUNT_CLOSURE: audit::SystemActor = audit::SystemActor::new("account-closure");
+pub const DEPOSIT_ACCOUNT_STATEMENTS: audit::SystemActor =
+    audit::SystemActor::new("deposit-account-statements");
 
 pub use chart_primitives::ChartId;
 pub use core_customer::CustomerType;
@@ -29,7 +37,16 @@ es_entity::entity_id! {
     DepositAccountId,
     WithdrawalId,
     ChartOfAccountsIntegrationConfigId,
-    DepositId;
+    DepositId,
+    TransferId,
+    HoldId,
+    BeneficiaryId,
+    PaymentFileId,
+    FxConversionId,
+    DepositImportId,
+    AccountClosureId,
+    DepositAccountStatementId,
+    DepositProductId;
 
     DepositAccountHolderId => core_customer::CustomerId,
     DepositAccountId => CalaAccountId,
@@ -38,10 +55,28 @@ es_entity::entity_id! {
     DepositId => CalaTransactionId,
     WithdrawalId => CalaTransactionId,
     WithdrawalId => public_id::PublicIdTargetId,
-    WithdrawalId => ApprovalProcessId
+    WithdrawalId => ApprovalProcessId,
+    TransferId => CalaTransactionId,
+    TransferId => public_id::PublicIdTargetId,
+    TransferId => ApprovalProcessId,
+    HoldId => CalaTransactionId,
+    FxConversionId => CalaTransactionId,
+    PaymentFileId => document_storage::ReferenceId,
+    DepositImportId => document_storage::ReferenceId,
+    AccountClosureId => document_storage::ReferenceId,
+    DepositAccountStatementId => document_storage::ReferenceId,
+    DepositAccountStatementId => job::JobId
 }
 
-pub use money::UsdCents;
+pub use money::{CurrencyCode, Money, UsdCents};
+
+/// Currencies a deposit account can be opened in.
+pub const DEPOSIT_ACCOUNT_CURRENCIES: [CurrencyCode; 4] = [
+    CurrencyCode::USD,
+    CurrencyCode::EUR,
+    CurrencyCode::USDT,
+    CurrencyCode::USDC,
+];
 
 #[derive(
     Debug,
@@ -74,12 +109,26 @@ pub const DEPOSIT_TRANSACTION_ENTITY_TYPE: chart_primitives::EntityType =
     chart_primitives::EntityType::new("Deposit");
 pub const WITHDRAWAL_TRANSACTION_ENTITY_TYPE: chart_primitives::EntityType =
     chart_primitives::EntityType::new("Withdrawal");
+pub const TRANSFER_TRANSACTION_ENTITY_TYPE: chart_primitives::EntityType =
+    chart_primitives::EntityType::new("Transfer");
+pub const HOLD_TRANSACTION_ENTITY_TYPE: chart_primitives::EntityType =
+    chart_primitives::EntityType::new("Hold");
+pub const FX_CONVERSION_TRANSACTION_ENTITY_TYPE: chart_primitives::EntityType =
+    chart_primitives::EntityType::new("FxConversion");
 
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
+pub type TransferAllOrOne = AllOrOne<TransferId>;
+pub type HoldAllOrOne = AllOrOne<HoldId>;
+pub type DepositProductAllOrOne = AllOrOne<DepositProductId>;
+pub type BeneficiaryAllOrOne = AllOrOne<BeneficiaryId>;
+pub type PaymentFileAllOrOne = AllOrOne<PaymentFileId>;
+pub type FxConversionAllOrOne = AllOrOne<FxConversionId>;
+pub type DepositImportAllOrOne = AllOrOne<DepositImportId>;
+pub type AccountClosureAllOrOne = AllOrOne<AccountClosureId>;
 
 permission_sets_macro::permission_sets! {
     DepositViewer("Can view deposit accounts, balances, transaction history, and withdrawal details"),
@@ -94,6 +143,8 @@ pub const DEPOSIT_REF_TARGET: public_id::PublicIdTargetType =
     public_id::PublicIdTargetType::new("deposit");
 pub const WITHDRAWAL_REF_TARGET: public_id::PublicIdTargetType =
     public_id::PublicIdTargetType::new("withdrawal");
+pub const TRANSFER_REF_TARGET: public_id::PublicIdTargetType =
+    public_id::PublicIdTargetType::new("transfer");
 
 #[derive(Debug, Clone)]
 pub struct LedgerOmnibusAccountIds {
@@ -109,6 +160,14 @@ pub enum CoreDepositObject {
     Deposit(DepositAllOrOne),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
+    Transfer(TransferAllOrOne),
+    Hold(HoldAllOrOne),
+    DepositProduct(DepositProductAllOrOne),
+    Beneficiary(BeneficiaryAllOrOne),
+    PaymentFile(PaymentFileAllOrOne),
+    FxConversion(FxConversionAllOrOne),
+    DepositImport(DepositImportAllOrOne),
+    AccountClosure(AccountClosureAllOrOne),
 }
 
 impl CoreDepositObject {
@@ -136,6 +195,70 @@ impl CoreDepositObject {
         CoreDepositObject::Withdrawal(AllOrOne::ById(id))
     }
 
+    pub fn all_transfers() -> Self {
+        CoreDepositObject::Transfer(AllOrOne::All)
+    }
+
+    pub fn transfer(id: TransferId) -> Self {
+        CoreDepositObject::Transfer(AllOrOne::ById(id))
+    }
+
+    pub fn all_holds() -> Self {
+        CoreDepositObject::Hold(AllOrOne::All)
+    }
+
+    pub fn hold(id: HoldId) -> Self {
+        CoreDepositObject::Hold(AllOrOne::ById(id))
+    }
+
+    pub fn all_deposit_products() -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::All)
+    }
+
+    pub fn deposit_product(id: DepositProductId) -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::ById(id))
+    }
+
+    pub fn all_beneficiaries() -> Self {
+        CoreDepositObject::Beneficiary(AllOrOne::All)
+    }
+
+    pub fn beneficiary(id: BeneficiaryId) -> Self {
+        CoreDepositObject::Beneficiary(AllOrOne::ById(id))
+    }
+
+    pub fn all_payment_files() -> Self {
+        CoreDepositObject::PaymentFile(AllOrOne::All)
+    }
+
+    pub fn payment_file(id: PaymentFileId) -> Self {
+        CoreDepositObject::PaymentFile(AllOrOne::ById(id))
+    }
+
+    pub fn all_fx_conversions() -> Self {
+        CoreDepositObject::FxConversion(AllOrOne::All)
+    }
+
+    pub fn fx_conversion(id: FxConversionId) -> Self {
+        CoreDepositObject::FxConversion(AllOrOne::ById(id))
+    }
+
+    pub fn all_deposit_imports() -> Self {
+        CoreDepositObject::DepositImport(AllOrOne::All)
+    }
+
+    pub fn deposit_import(id: DepositImportId) -> Self {
+        CoreDepositObject::DepositImport(AllOrOne::ById(id))
+    }
+
+    pub fn all_account_closures() -> Self {
+        CoreDepositObject::AccountClosure(AllOrOne::All)
+    }
+
+    pub fn account_closure(id: AccountClosureId) -> Self {
+        CoreDepositObject::AccountClosure(AllOrOne::ById(id))
+    }
+
     pub fn chart_of_accounts_integration() -> Self {
         CoreDepositObject::ChartOfAccountsIntegrationConfig(AllOrOne::All)
     }
@@ -149,6 +272,14 @@ impl Display for CoreDepositObject {
             DepositAccount(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Deposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Withdrawal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Transfer(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Hold(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            DepositProduct(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Beneficiary(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            PaymentFile(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            FxConversion(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            DepositImport(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            AccountClosure(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
@@ -179,6 +310,54 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::Withdrawal(obj_ref)
             }
+            Transfer => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Transfer(obj_ref)
+            }
+            Hold => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Hold(obj_ref)
+            }
+            DepositProduct => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositProduct(obj_ref)
+            }
+            Beneficiary => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Beneficiary(obj_ref)
+            }
+            PaymentFile => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::PaymentFile(obj_ref)
+            }
+            FxConversion => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::FxConversion(obj_ref)
+            }
+            DepositImport => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositImport(obj_ref)
+            }
+            AccountClosure => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::AccountClosure(obj_ref)
+            }
             ChartOfAccountsIntegrationConfig => {
                 let obj_ref = id
                     .parse()
@@ -198,6 +377,14 @@ pub enum CoreDepositAction {
     Deposit(DepositAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
+    Transfer(TransferAction),
+    Hold(HoldAction),
+    DepositProduct(DepositProductAction),
+    Beneficiary(BeneficiaryAction),
+    PaymentFile(PaymentFileAction),
+    FxConversion(FxConversionAction),
+    DepositImport(DepositImportAction),
+    AccountClosure(AccountClosureAction),
 }
 
 impl CoreDepositAction {
@@ -217,6 +404,32 @@ impl CoreDepositAction {
         CoreDepositAction::DepositAccount(DepositAccountAction::Unfreeze);
     pub const DEPOSIT_ACCOUNT_CLOSE: Self =
         CoreDepositAction::DepositAccount(DepositAccountAction::Close);
+    pub const DEPOSIT_ACCOUNT_ASSIGN_PRODUCT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AssignProduct);
+    pub const DEPOSIT_ACCOUNT_ACCRUE_INTEREST: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AccrueInterest);
+    pub const DEPOSIT_ACCOUNT_CHARGE_FEE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::ChargeFee);
+    pub const DEPOSIT_ACCOUNT_REVERSE_FEE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::ReverseFee);
+    pub const DEPOSIT_ACCOUNT_ESCHEAT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Escheat);
+    pub const DEPOSIT_ACCOUNT_RECLAIM_ESCHEATED_BALANCE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::ReclaimEscheatedBalance);
+    pub const DEPOSIT_ACCOUNT_READ_WITHDRAWAL_LIMITS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::ReadWithdrawalLimits);
+    pub const DEPOSIT_ACCOUNT_UPDATE_WITHDRAWAL_LIMITS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::UpdateWithdrawalLimits);
+    pub const DEPOSIT_ACCOUNT_REQUEST_WITHDRAWAL_LIMIT_INCREASE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::RequestWithdrawalLimitIncrease);
+    pub const DEPOSIT_ACCOUNT_CONCLUDE_WITHDRAWAL_LIMIT_INCREASE_APPROVAL: Self =
+        CoreDepositAction::DepositAccount(
+            DepositAccountAction::ConcludeWithdrawalLimitIncreaseApproval,
+        );
+    pub const DEPOSIT_ACCOUNT_UPDATE_HOLDERS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::UpdateHolders);
+    pub const DEPOSIT_ACCOUNT_GENERATE_STATEMENT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::GenerateStatement);
 
     pub const DEPOSIT_CREATE: Self = CoreDepositAction::Deposit(DepositAction::Create);
     pub const DEPOSIT_READ: Self = CoreDepositAction::Deposit(DepositAction::Read);
@@ -240,6 +453,71 @@ impl CoreDepositAction {
     pub const WITHDRAWAL_READ: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Read);
     pub const WITHDRAWAL_LIST: Self = CoreDepositAction::Withdrawal(WithdrawalAction::List);
     pub const WITHDRAWAL_REVERT: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Revert);
+    pub const WITHDRAWAL_RECORD_SIGNATURE: Self =
+        CoreDepositAction::Withdrawal(WithdrawalAction::RecordSignature);
+
+    pub const TRANSFER_INITIATE: Self = CoreDepositAction::Transfer(TransferAction::Initiate);
+    pub const TRANSFER_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess);
+    pub const TRANSFER_READ: Self = CoreDepositAction::Transfer(TransferAction::Read);
+    pub const TRANSFER_LIST: Self = CoreDepositAction::Transfer(TransferAction::List);
+
+    pub const HOLD_PLACE: Self = CoreDepositAction::Hold(HoldAction::Place);
+    pub const HOLD_EXTEND: Self = CoreDepositAction::Hold(HoldAction::Extend);
+    pub const HOLD_RELEASE: Self = CoreDepositAction::Hold(HoldAction::Release);
+    pub const HOLD_READ: Self = CoreDepositAction::Hold(HoldAction::Read);
+    pub const HOLD_LIST: Self = CoreDepositAction::Hold(HoldAction::List);
+
+    pub const DEPOSIT_PRODUCT_CREATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Create);
+    pub const DEPOSIT_PRODUCT_UPDATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Update);
+    pub const DEPOSIT_PRODUCT_READ: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Read);
+    pub const DEPOSIT_PRODUCT_LIST: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::List);
+
+    pub const BENEFICIARY_CREATE: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Create);
+    pub const BENEFICIARY_VERIFY: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Verify);
+    pub const BENEFICIARY_REMOVE: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Remove);
+    pub const BENEFICIARY_READ: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Read);
+    pub const BENEFICIARY_LIST: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::List);
+
+    pub const PAYMENT_FILE_GENERATE: Self =
+        CoreDepositAction::PaymentFile(PaymentFileAction::Generate);
+    pub const PAYMENT_FILE_IMPORT_STATUS_REPORT: Self =
+        CoreDepositAction::PaymentFile(PaymentFileAction::ImportStatusReport);
+    pub const PAYMENT_FILE_READ: Self = CoreDepositAction::PaymentFile(PaymentFileAction::Read);
+    pub const PAYMENT_FILE_LIST: Self = CoreDepositAction::PaymentFile(PaymentFileAction::List);
+    pub const PAYMENT_FILE_CONFIGURE_ORIGINATOR: Self =
+        CoreDepositAction::PaymentFile(PaymentFileAction::ConfigureOriginator);
+
+    pub const FX_CONVERSION_CREATE: Self =
+        CoreDepositAction::FxConversion(FxConversionAction::Create);
+    pub const FX_CONVERSION_READ: Self = CoreDepositAction::FxConversion(FxConversionAction::Read);
+    pub const FX_CONVERSION_LIST: Self = CoreDepositAction::FxConversion(FxConversionAction::List);
+
+    pub const DEPOSIT_IMPORT_CREATE: Self =
+        CoreDepositAction::DepositImport(DepositImportAction::Create);
+    pub const DEPOSIT_IMPORT_PROCESS: Self =
+        CoreDepositAction::DepositImport(DepositImportAction::Process);
+    pub const DEPOSIT_IMPORT_READ: Self =
+        CoreDepositAction::DepositImport(DepositImportAction::Read);
+    pub const DEPOSIT_IMPORT_LIST: Self =
+        CoreDepositAction::DepositImport(DepositImportAction::List);
+
+    pub const ACCOUNT_CLOSURE_INITIATE: Self =
+        CoreDepositAction::AccountClosure(AccountClosureAction::Initiate);
+    pub const ACCOUNT_CLOSURE_RELEASE_HOLDS: Self =
+        CoreDepositAction::AccountClosure(AccountClosureAction::ReleaseHolds);
+    pub const ACCOUNT_CLOSURE_INITIATE_PAYOUT: Self =
+        CoreDepositAction::AccountClosure(AccountClosureAction::InitiatePayout);
+    pub const ACCOUNT_CLOSURE_SETTLE: Self =
+        CoreDepositAction::AccountClosure(AccountClosureAction::Settle);
+    pub const ACCOUNT_CLOSURE_READ: Self =
+        CoreDepositAction::AccountClosure(AccountClosureAction::Read);
+    pub const ACCOUNT_CLOSURE_LIST: Self =
+        CoreDepositAction::AccountClosure(AccountClosureAction::List);
 
     pub fn actions() -> Vec<ActionMapping> {
         use CoreDepositActionDiscriminants::*;
@@ -258,6 +536,14 @@ impl CoreDepositAction {
                     ChartOfAccountsIntegrationConfigAction
                 ),
                 Withdrawal => map_action!(deposit, Withdrawal, WithdrawalAction),
+                Transfer => map_action!(deposit, Transfer, TransferAction),
+                Hold => map_action!(deposit, Hold, HoldAction),
+                DepositProduct => map_action!(deposit, DepositProduct, DepositProductAction),
+                Beneficiary => map_action!(deposit, Beneficiary, BeneficiaryAction),
+                PaymentFile => map_action!(deposit, PaymentFile, PaymentFileAction),
+                FxConversion => map_action!(deposit, FxConversion, FxConversionAction),
+                DepositImport => map_action!(deposit, DepositImport, DepositImportAction),
+                AccountClosure => map_action!(deposit, AccountClosure, AccountClosureAction),
             })
             .collect()
     }
@@ -272,6 +558,14 @@ impl Display for CoreDepositAction {
             Deposit(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
+            Transfer(action) => action.fmt(f),
+            Hold(action) => action.fmt(f),
+            DepositProduct(action) => action.fmt(f),
+            Beneficiary(action) => action.fmt(f),
+            PaymentFile(action) => action.fmt(f),
+            FxConversion(action) => action.fmt(f),
+            DepositImport(action) => action.fmt(f),
+            AccountClosure(action) => action.fmt(f),
         }
     }
 }
@@ -289,6 +583,14 @@ impl FromStr for CoreDepositAction {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Withdrawal => CoreDepositAction::from(action.parse::<WithdrawalAction>()?),
+            Transfer => CoreDepositAction::from(action.parse::<TransferAction>()?),
+            Hold => CoreDepositAction::from(action.parse::<HoldAction>()?),
+            DepositProduct => CoreDepositAction::from(action.parse::<DepositProductAction>()?),
+            Beneficiary => CoreDepositAction::from(action.parse::<BeneficiaryAction>()?),
+            PaymentFile => CoreDepositAction::from(action.parse::<PaymentFileAction>()?),
+            FxConversion => CoreDepositAction::from(action.parse::<FxConversionAction>()?),
+            DepositImport => CoreDepositAction::from(action.parse::<DepositImportAction>()?),
+            AccountClosure => CoreDepositAction::from(action.parse::<AccountClosureAction>()?),
         };
 
         Ok(res)
@@ -307,15 +609,42 @@ pub enum DepositAccountAction {
     Freeze,
     Unfreeze,
     Close,
+    AssignProduct,
+    AccrueInterest,
+    ChargeFee,
+    ReverseFee,
+    Escheat,
+    ReclaimEscheatedBalance,
+    ReadWithdrawalLimits,
+    UpdateWithdrawalLimits,
+    RequestWithdrawalLimitIncrease,
+    ConcludeWithdrawalLimitIncreaseApproval,
+    UpdateHolders,
+    GenerateStatement,
 }
 
 impl ActionPermission for DepositAccountAction {
     fn permission_set(&self) -> &'static str {
         match self {
-            Self::Read | Self::List | Self::ReadBalance | Self::ReadTxHistory => {
-                PERMISSION_SET_DEPOSIT_VIEWER
-            }
-            Self::Create | Self::UpdateStatus | Self::Close => PERMISSION_SET_DEPOSIT_WRITER,
+            Self::Read
+            | Self::List
+            | Self::ReadBalance
+            | Self::ReadTxHistory
+            | Self::ReadWithdrawalLimits => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create
+            | Self::UpdateStatus
+            | Self::Close
+            | Self::AssignProduct
+            | Self::AccrueInterest
+            | Self::ChargeFee
+            | Self::ReverseFee
+            | Self::Escheat
+            | Self::ReclaimEscheatedBalance
+            | Self::UpdateWithdrawalLimits
+            | Self::RequestWithdrawalLimitIncrease
+            | Self::ConcludeWithdrawalLimitIncreaseApproval
+            | Self::UpdateHolders
+            | Self::GenerateStatement => PERMISSION_SET_DEPOSIT_WRITER,
             Self::Freeze => PERMISSION_SET_DEPOSIT_FREEZE,
             Self::Unfreeze => PERMISSION_SET_DEPOSIT_UNFREEZE,
         }
@@ -362,6 +691,7 @@ pub enum WithdrawalAction {
     Read,
     List,
     Revert,
+    RecordSignature,
 }
 
 impl ActionPermission for WithdrawalAction {
@@ -372,7 +702,8 @@ impl ActionPermission for WithdrawalAction {
             | Self::Initiate
             | Self::ConcludeApprovalProcess
             | Self::Confirm
-            | Self::Revert => PERMISSION_SET_DEPOSIT_WRITER,
+            | Self::Revert
+            | Self::RecordSignature => PERMISSION_SET_DEPOSIT_WRITER,
         }
     }
 }
@@ -383,6 +714,206 @@ impl From<WithdrawalAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TransferAction {
+    Initiate,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl ActionPermission for TransferAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Initiate | Self::ConcludeApprovalProcess => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<TransferAction> for CoreDepositAction {
+    fn from(action: TransferAction) -> Self {
+        CoreDepositAction::Transfer(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum HoldAction {
+    Place,
+    Extend,
+    Release,
+    Read,
+    List,
+}
+
+impl ActionPermission for HoldAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Place | Self::Extend | Self::Release => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<HoldAction> for CoreDepositAction {
+    fn from(action: HoldAction) -> Self {
+        CoreDepositAction::Hold(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositProductAction {
+    Create,
+    Update,
+    Read,
+    List,
+}
+
+impl ActionPermission for DepositProductAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create | Self::Update => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<DepositProductAction> for CoreDepositAction {
+    fn from(action: DepositProductAction) -> Self {
+        CoreDepositAction::DepositProduct(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BeneficiaryAction {
+    Create,
+    Verify,
+    Remove,
+    Read,
+    List,
+}
+
+impl ActionPermission for BeneficiaryAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create | Self::Verify | Self::Remove => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<BeneficiaryAction> for CoreDepositAction {
+    fn from(action: BeneficiaryAction) -> Self {
+        CoreDepositAction::Beneficiary(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PaymentFileAction {
+    Generate,
+    ImportStatusReport,
+    Read,
+    List,
+    ConfigureOriginator,
+}
+
+impl ActionPermission for PaymentFileAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Generate | Self::ImportStatusReport | Self::ConfigureOriginator => {
+                PERMISSION_SET_DEPOSIT_WRITER
+            }
+        }
+    }
+}
+
+impl From<PaymentFileAction> for CoreDepositAction {
+    fn from(action: PaymentFileAction) -> Self {
+        CoreDepositAction::PaymentFile(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum FxConversionAction {
+    Create,
+    Read,
+    List,
+}
+
+impl ActionPermission for FxConversionAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<FxConversionAction> for CoreDepositAction {
+    fn from(action: FxConversionAction) -> Self {
+        CoreDepositAction::FxConversion(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositImportAction {
+    Create,
+    Process,
+    Read,
+    List,
+}
+
+impl ActionPermission for DepositImportAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create | Self::Process => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<DepositImportAction> for CoreDepositAction {
+    fn from(action: DepositImportAction) -> Self {
+        CoreDepositAction::DepositImport(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountClosureAction {
+    Initiate,
+    ReleaseHolds,
+    InitiatePayout,
+    Settle,
+    Read,
+    List,
+}
+
+impl ActionPermission for AccountClosureAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Initiate | Self::ReleaseHolds | Self::InitiatePayout | Self::Settle => {
+                PERMISSION_SET_DEPOSIT_WRITER
+            }
+        }
+    }
+}
+
+impl From<AccountClosureAction> for CoreDepositAction {
+    fn from(action: AccountClosureAction) -> Self {
+        CoreDepositAction::AccountClosure(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ChartOfAccountsIntegrationConfigAction {
@@ -425,6 +956,31 @@ pub enum DepositAccountStatus {
     Active,
     Frozen,
     Closed,
+    Escheated,
+}
+
+/// How a holder of a joint deposit account takes part in authorising withdrawals.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SigningRule {
+    /// The holder's signature alone is enough to authorise a withdrawal.
+    #[default]
+    AnyOneSigns,
+    /// Every withdrawal has to carry this holder's signature.
+    AllMustSign,
 }
 
 #[derive(Clone, Copy)]
@@ -470,3 +1026,24 @@ pub enum DepositStatus {
     Confirmed,
     Reverted,
 }
+
+/// How the funds of a deposit reached the bank.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum DepositChannel {
+    #[default]
+    Cash,
+    BankTransfer,
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{ApprovalProcessId, GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use obix::out::OutboxEventMarker;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject, primitives::DepositAccountId, public::CoreDepositEvent,
+};
+
+use super::ApproveWithdrawalLimitIncrease;
+
+pub const EXECUTE_WITHDRAWAL_LIMIT_INCREASE_APPROVAL_COMMAND: JobType =
+    JobType::new("command.deposit.execute-withdrawal-limit-increase-approval");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteWithdrawalLimitIncreaseApprovalConfig {
+    pub approval_process_id: ApprovalProcessId,
+    pub deposit_account_id: DepositAccountId,
+    pub approved: bool,
+}
+
+pub struct ExecuteWithdrawalLimitIncreaseApprovalJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    process: ApproveWithdrawalLimitIncrease<Perms, E>,
+}
+
+impl<Perms, E> ExecuteWithdrawalLimitIncreaseApprovalJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(process: &ApproveWithdrawalLimitIncrease<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for ExecuteWithdrawalLimitIncreaseApprovalJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    type Config = ExecuteWithdrawalLimitIncreaseApprovalConfig;
+
+    fn job_type(&self) -> JobType {
+        EXECUTE_WITHDRAWAL_LIMIT_INCREASE_APPROVAL_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ExecuteWithdrawalLimitIncreaseApprovalJobRunner {
+            config: job.config()?,
+            process: self.process.clone(),
+        }))
+    }
+}
+
+pub struct ExecuteWithdrawalLimitIncreaseApprovalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    config: ExecuteWithdrawalLimitIncreaseApprovalConfig,
+    process: ApproveWithdrawalLimitIncrease<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ExecuteWithdrawalLimitIncreaseApprovalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "deposit.execute_withdrawal_limit_increase_approval.process_command",
+        skip_all
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut op = current_job.begin_op().await?;
+        self.process
+            .execute_withdrawal_limit_increase_approval_in_op(
+                &mut op,
+                self.config.deposit_account_id,
+                self.config.approval_process_id,
+                self.config.approved,
+            )
+            .await?;
+        Ok(JobCompletion::CompleteWithOp(op))
+    }
+}
@@ -1,5 +1,9 @@
+mod execute_transfer_approval;
 mod execute_withdraw_approval;
+mod execute_withdrawal_limit_increase_approval;
+mod transfer_approval;
 mod withdraw_approval;
+mod withdrawal_limit_increase_approval;
 
 use authz::PermissionCheck;
 use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
@@ -11,17 +15,26 @@ use governance::Governance;
 use obix::out::OutboxEventMarker;
 
 use crate::{
-    CoreDepositAction, CoreDepositObject, WithdrawalAction,
+    CoreDepositAction, CoreDepositObject, TransferAction, WithdrawalAction,
+    account::{DepositAccount, DepositAccountRepo, error::DepositAccountError},
     ledger::DepositLedger,
-    primitives::WithdrawalId,
+    primitives::{ApprovalProcessId, DepositAccountId, TransferId, WithdrawalId},
     public::CoreDepositEvent,
+    transfer::{Transfer, error::TransferError, repo::TransferRepo},
     withdrawal::{Withdrawal, error::WithdrawalError, repo::WithdrawalRepo},
 };
 
+pub use execute_transfer_approval::*;
 pub use execute_withdraw_approval::*;
+pub use execute_withdrawal_limit_increase_approval::*;
+pub use transfer_approval::*;
 pub use withdraw_approval::*;
+pub use withdrawal_limit_increase_approval::*;
 
 pub const APPROVE_WITHDRAWAL_PROCESS: ApprovalProcessType = ApprovalProcessType::new("withdraw");
+pub const APPROVE_TRANSFER_PROCESS: ApprovalProcessType = ApprovalProcessType::new("transfer");
+pub const APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("withdrawal-limit-increase");
 
 pub struct ApproveWithdrawal<Perms, E>
 where
@@ -96,7 +109,7 @@ where
                         &mut *op,
                         withdraw.id,
                         denied_tx_id,
-                        withdraw.amount,
+                        withdraw.money(),
                         withdraw.deposit_account_id,
                         &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
                             crate::primitives::DEPOSIT_APPROVAL,
@@ -112,3 +125,190 @@ where
         Ok(withdraw)
     }
 }
+
+pub struct ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: TransferRepo<E>,
+    audit: Perms::Audit,
+    ledger: DepositLedger,
+}
+
+impl<Perms, E> Clone for ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            audit: self.audit.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(repo: &TransferRepo<E>, audit: &Perms::Audit, ledger: &DepositLedger) -> Self {
+        Self {
+            repo: repo.clone(),
+            audit: audit.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_deposit.transfer_approval.execute", skip(self, op))]
+    pub async fn execute_transfer_approval_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: TransferId,
+        approved: bool,
+    ) -> Result<Transfer, TransferError> {
+        let mut transfer = self.repo.find_by_id_in_op(&mut *op, id).await?;
+        self.audit
+            .record_system_entry_in_op(
+                &mut *op,
+                crate::primitives::DEPOSIT_APPROVAL,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess),
+            )
+            .await?;
+        if transfer
+            .approval_process_concluded(approved)
+            .was_already_applied()
+        {
+            return Ok(transfer);
+        }
+
+        let system = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+            crate::primitives::DEPOSIT_APPROVAL,
+        );
+
+        // Transfers awaiting approval earmark their amount on the source account,
+        // so the release and the transfer post together and cannot be outspent.
+        // Transfers initiated before funds were held still need the balance check.
+        let funds_held = transfer.holds_funds();
+        if let es_entity::Idempotent::Executed(tx_id) = transfer.release_funds() {
+            self.ledger
+                .release_transfer_funds_in_op(
+                    &mut *op,
+                    tx_id,
+                    transfer.id,
+                    transfer.money(),
+                    transfer.from_account_id,
+                    &system,
+                )
+                .await?;
+        }
+
+        if approved {
+            let sufficient_funds = funds_held
+                || self
+                    .ledger
+                    .balance(transfer.from_account_id)
+                    .await?
+                    .available()
+                    >= transfer.amount;
+            if !sufficient_funds {
+                let _ = transfer.fail();
+            } else if transfer.complete()?.did_execute() {
+                self.ledger
+                    .transfer_in_op(
+                        &mut *op,
+                        transfer.id,
+                        transfer.money(),
+                        transfer.from_account_id,
+                        transfer.to_account_id,
+                        &system,
+                    )
+                    .await?;
+            }
+        }
+
+        self.repo.update_in_op(&mut *op, &mut transfer).await?;
+        Ok(transfer)
+    }
+}
+
+pub struct ApproveWithdrawalLimitIncrease<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: DepositAccountRepo<E>,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> Clone for ApproveWithdrawalLimitIncrease<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveWithdrawalLimitIncrease<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(repo: &DepositAccountRepo<E>, audit: &Perms::Audit) -> Self {
+        Self {
+            repo: repo.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_deposit.withdrawal_limit_increase_approval.execute",
+        skip(self, op)
+    )]
+    pub async fn execute_withdrawal_limit_increase_approval_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        deposit_account_id: DepositAccountId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<DepositAccount, DepositAccountError> {
+        let mut account = self
+            .repo
+            .find_by_id_in_op(&mut *op, deposit_account_id)
+            .await?;
+        self.audit
+            .record_system_entry_in_op(
+                &mut *op,
+                crate::primitives::DEPOSIT_APPROVAL,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_CONCLUDE_WITHDRAWAL_LIMIT_INCREASE_APPROVAL,
+            )
+            .await?;
+        if account
+            .conclude_withdrawal_limit_increase(approval_process_id, approved)
+            .did_execute()
+        {
+            self.repo.update_in_op(&mut *op, &mut account).await?;
+        }
+        Ok(account)
+    }
+}
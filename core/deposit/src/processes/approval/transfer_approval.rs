@@ -0,0 +1,54 @@
+use tracing::{Span, instrument};
+
+use governance::GovernanceEvent;
+use job::{JobId, JobSpawner, JobType};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use super::ExecuteTransferApprovalConfig;
+
+pub const TRANSFER_APPROVE_JOB: JobType = JobType::new("outbox.transfer-approval");
+
+pub struct TransferApprovalHandler {
+    execute_transfer_approval: JobSpawner<ExecuteTransferApprovalConfig>,
+}
+
+impl TransferApprovalHandler {
+    pub fn new(execute_transfer_approval: JobSpawner<ExecuteTransferApprovalConfig>) -> Self {
+        Self {
+            execute_transfer_approval,
+        }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for TransferApprovalHandler
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(name = "core_deposit.transfer_approval_job.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, process_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ GovernanceEvent::ApprovalProcessConcluded { entity }) = event.as_event()
+            && entity.process_type == super::APPROVE_TRANSFER_PROCESS
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+            Span::current().record("process_type", entity.process_type.to_string());
+            self.execute_transfer_approval
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    ExecuteTransferApprovalConfig {
+                        approval_process_id: entity.id,
+                        approved: entity.status.is_approved(),
+                    },
+                    entity.id.to_string(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use tracing::{Span, instrument};
+
+use governance::GovernanceEvent;
+use job::{JobId, JobSpawner, JobType};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use super::ExecuteWithdrawalLimitIncreaseApprovalConfig;
+
+pub const WITHDRAWAL_LIMIT_INCREASE_APPROVE_JOB: JobType =
+    JobType::new("outbox.withdrawal-limit-increase-approval");
+
+pub struct WithdrawalLimitIncreaseApprovalHandler {
+    execute_withdrawal_limit_increase_approval:
+        JobSpawner<ExecuteWithdrawalLimitIncreaseApprovalConfig>,
+}
+
+impl WithdrawalLimitIncreaseApprovalHandler {
+    pub fn new(
+        execute_withdrawal_limit_increase_approval: JobSpawner<
+            ExecuteWithdrawalLimitIncreaseApprovalConfig,
+        >,
+    ) -> Self {
+        Self {
+            execute_withdrawal_limit_increase_approval,
+        }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for WithdrawalLimitIncreaseApprovalHandler
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(name = "core_deposit.withdrawal_limit_increase_approval_job.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, process_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ GovernanceEvent::ApprovalProcessConcluded { entity }) = event.as_event()
+            && entity.process_type == super::APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+            Span::current().record("process_type", entity.process_type.to_string());
+            self.execute_withdrawal_limit_increase_approval
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    ExecuteWithdrawalLimitIncreaseApprovalConfig {
+                        approval_process_id: entity.id,
+                        deposit_account_id: entity.target_ref.parse()?,
+                        approved: entity.status.is_approved(),
+                    },
+                    entity.id.to_string(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -10,6 +10,8 @@ pub enum ProcessError {
     Sqlx(#[from] sqlx::Error),
     #[error("ProcessError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("ProcessError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
     #[error("ProcessError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
 }
@@ -20,6 +22,7 @@ impl ErrorSeverity for ProcessError {
             Self::GovernanceError(e) => e.severity(),
             Self::Sqlx(_) => Level::ERROR,
             Self::WithdrawalError(e) => e.severity(),
+            Self::TransferError(e) => e.severity(),
             Self::AuditError(e) => e.severity(),
         }
     }
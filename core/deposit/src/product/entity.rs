@@ -0,0 +1,136 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::DepositProductId;
+
+use super::value::DepositProductValues;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositProductId")]
+pub enum DepositProductEvent {
+    Initialized {
+        id: DepositProductId,
+        name: String,
+        values: DepositProductValues,
+    },
+    ValuesUpdated {
+        values: DepositProductValues,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct DepositProduct {
+    pub id: DepositProductId,
+    pub name: String,
+    pub values: DepositProductValues,
+    events: EntityEvents<DepositProductEvent>,
+}
+
+impl DepositProduct {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("DepositProduct has never been persisted")
+    }
+
+    pub(crate) fn update_values(&mut self, new_values: DepositProductValues) -> Idempotent<()> {
+        if self.values == new_values {
+            return Idempotent::AlreadyApplied;
+        }
+
+        self.events
+            .push(DepositProductEvent::ValuesUpdated { values: new_values });
+        self.values = new_values;
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<DepositProductEvent> for DepositProduct {
+    fn try_from_events(
+        events: EntityEvents<DepositProductEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = DepositProductBuilder::default();
+
+        for event in events.iter_all() {
+            match event {
+                DepositProductEvent::Initialized { id, name, values } => {
+                    builder = builder.id(*id).name(name.clone()).values(*values);
+                }
+                DepositProductEvent::ValuesUpdated { values } => {
+                    builder = builder.values(*values);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositProduct {
+    #[builder(setter(into))]
+    pub(super) id: DepositProductId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    pub(super) values: DepositProductValues,
+}
+
+impl NewDepositProduct {
+    pub fn builder() -> NewDepositProductBuilder {
+        NewDepositProductBuilder::default()
+    }
+}
+
+impl IntoEvents<DepositProductEvent> for NewDepositProduct {
+    fn into_events(self) -> EntityEvents<DepositProductEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositProductEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                values: self.values,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::product::value::InterestFrequency;
+
+    fn values(rate: rust_decimal::Decimal) -> DepositProductValues {
+        DepositProductValues::builder()
+            .annual_rate(rate)
+            .accrual_frequency(InterestFrequency::Daily)
+            .posting_frequency(InterestFrequency::Monthly)
+            .build()
+            .unwrap()
+    }
+
+    fn product() -> DepositProduct {
+        let new = NewDepositProduct::builder()
+            .id(DepositProductId::new())
+            .name("Savings")
+            .values(values(dec!(2)))
+            .build()
+            .unwrap();
+        DepositProduct::try_from_events(new.into_events()).unwrap()
+    }
+
+    #[test]
+    fn update_values_is_idempotent() {
+        let mut product = product();
+        assert!(product.update_values(values(dec!(2))).was_already_applied());
+        assert!(product.update_values(values(dec!(3))).did_execute());
+        assert_eq!(product.values.annual_rate.into_inner(), dec!(3));
+    }
+}
@@ -0,0 +1,57 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use super::{
+    repo::{
+        DepositProductColumn, DepositProductCreateError, DepositProductFindError,
+        DepositProductModifyError, DepositProductQueryError,
+    },
+    value::InterestFrequency,
+};
+
+#[derive(Error, Debug)]
+pub enum DepositProductError {
+    #[error("DepositProductError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositProductError - Create: {0}")]
+    Create(DepositProductCreateError),
+    #[error("DepositProductError - Modify: {0}")]
+    Modify(#[from] DepositProductModifyError),
+    #[error("DepositProductError - Find: {0}")]
+    Find(#[from] DepositProductFindError),
+    #[error("DepositProductError - Query: {0}")]
+    Query(#[from] DepositProductQueryError),
+    #[error("DepositProductError - UninitializedField: {0}")]
+    UninitializedField(#[from] derive_builder::UninitializedFieldError),
+    #[error(
+        "DepositProductError - PostingMoreFrequentThanAccrual: posting {0} is more frequent than accrual {1}"
+    )]
+    PostingMoreFrequentThanAccrual(InterestFrequency, InterestFrequency),
+    #[error("DepositProductError - DuplicateDepositProductName")]
+    DuplicateDepositProductName,
+}
+
+impl From<DepositProductCreateError> for DepositProductError {
+    fn from(error: DepositProductCreateError) -> Self {
+        if error.was_duplicate_by(DepositProductColumn::Name) {
+            return Self::DuplicateDepositProductName;
+        }
+        Self::Create(error)
+    }
+}
+
+impl ErrorSeverity for DepositProductError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::UninitializedField(_) => Level::ERROR,
+            Self::PostingMoreFrequentThanAccrual(..) => Level::WARN,
+            Self::DuplicateDepositProductName => Level::WARN,
+        }
+    }
+}
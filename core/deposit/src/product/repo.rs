@@ -0,0 +1,28 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::DepositProductId;
+
+use super::entity::*;
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "DepositProduct",
+    columns(name(ty = "String", list_by)),
+    tbl_prefix = "core"
+)]
+pub struct DepositProductRepo {
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl DepositProductRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use chrono::{Datelike, NaiveDate};
+use derive_builder::Builder;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::primitives::UsdCents;
+
+use super::error::DepositProductError;
+
+const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct DepositRatePct(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(DepositRatePct);
+
+impl DepositRatePct {
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    pub fn into_inner(self) -> Decimal {
+        self.0
+    }
+
+    /// Simple interest on `principal` for `days`, in unrounded cents. Callers
+    /// book whole cents and carry the fraction into the next accrual.
+    pub fn interest_for_days(&self, principal: UsdCents, days: u32) -> Decimal {
+        principal.to_usd() * Decimal::from(days) * self.0 / Decimal::from(NUMBER_OF_DAYS_IN_YEAR)
+    }
+
+    pub fn apply(&self, amount: UsdCents) -> UsdCents {
+        let fee = (amount.to_usd() * (self.0 / Decimal::ONE_HUNDRED))
+            .round_dp_with_strategy(2, RoundingStrategy::AwayFromZero);
+
+        UsdCents::try_from_usd(fee).expect("Unexpected negative number")
+    }
+}
+
+impl From<Decimal> for DepositRatePct {
+    fn from(value: Decimal) -> Self {
+        DepositRatePct(value)
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InterestFrequency {
+    Daily,
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+impl InterestFrequency {
+    pub fn is_period_end(&self, date: NaiveDate) -> bool {
+        let is_month_end = date
+            .succ_opt()
+            .map(|next| next.month() != date.month())
+            .unwrap_or(true);
+        match self {
+            InterestFrequency::Daily => true,
+            InterestFrequency::Monthly => is_month_end,
+            InterestFrequency::Quarterly => is_month_end && date.month().is_multiple_of(3),
+            InterestFrequency::Annually => is_month_end && date.month() == 12,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct TermDepositTerms {
+    pub lock_in_period_days: u32,
+    pub early_withdrawal_penalty_rate: DepositRatePct,
+}
+
+impl TermDepositTerms {
+    pub fn lock_in_ends_at(
+        &self,
+        opened_at: chrono::DateTime<chrono::Utc>,
+    ) -> chrono::DateTime<chrono::Utc> {
+        opened_at + chrono::Duration::days(i64::from(self.lock_in_period_days))
+    }
+}
+
+#[derive(Builder, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[builder(build_fn(validate = "Self::validate", error = "DepositProductError"))]
+pub struct DepositProductValues {
+    #[builder(setter(into))]
+    pub annual_rate: DepositRatePct,
+    pub accrual_frequency: InterestFrequency,
+    pub posting_frequency: InterestFrequency,
+    #[builder(setter(into, strip_option), default)]
+    pub term: Option<TermDepositTerms>,
+}
+
+impl DepositProductValues {
+    pub fn builder() -> DepositProductValuesBuilder {
+        DepositProductValuesBuilder::default()
+    }
+
+    pub fn is_term_deposit(&self) -> bool {
+        self.term.is_some()
+    }
+}
+
+impl DepositProductValuesBuilder {
+    fn validate(&self) -> Result<(), DepositProductError> {
+        if let (Some(accrual), Some(posting)) = (self.accrual_frequency, self.posting_frequency)
+            && posting < accrual
+        {
+            return Err(DepositProductError::PostingMoreFrequentThanAccrual(
+                posting, accrual,
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn interest_for_days_keeps_fractional_cents() {
+        let rate = DepositRatePct::from(dec!(5));
+        let interest = rate.interest_for_days(UsdCents::from(1_000_000), 30);
+        assert_eq!(interest.trunc(), dec!(4109));
+        assert!(interest.fract() > dec!(0.58));
+    }
+
+    #[test]
+    fn period_end_per_frequency() {
+        assert!(InterestFrequency::Daily.is_period_end(date(2025, 3, 14)));
+        assert!(!InterestFrequency::Monthly.is_period_end(date(2025, 3, 14)));
+        assert!(InterestFrequency::Monthly.is_period_end(date(2024, 2, 29)));
+        assert!(InterestFrequency::Quarterly.is_period_end(date(2025, 6, 30)));
+        assert!(!InterestFrequency::Quarterly.is_period_end(date(2025, 5, 31)));
+        assert!(InterestFrequency::Annually.is_period_end(date(2025, 12, 31)));
+        assert!(!InterestFrequency::Annually.is_period_end(date(2025, 11, 30)));
+    }
+
+    #[test]
+    fn posting_cannot_be_more_frequent_than_accrual() {
+        let res = DepositProductValues::builder()
+            .annual_rate(dec!(3))
+            .accrual_frequency(InterestFrequency::Monthly)
+            .posting_frequency(InterestFrequency::Daily)
+            .build();
+        assert!(matches!(
+            res,
+            Err(DepositProductError::PostingMoreFrequentThanAccrual(..))
+        ));
+    }
+}
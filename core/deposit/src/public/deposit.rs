@@ -5,7 +5,7 @@ use schemars::JsonSchema;
 
 use crate::{
     deposit::Deposit,
-    primitives::{DepositAccountId, DepositId, UsdCents},
+    primitives::{CurrencyCode, DepositAccountId, DepositChannel, DepositId, UsdCents},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +14,10 @@ pub struct PublicDeposit {
     pub id: DepositId,
     pub deposit_account_id: DepositAccountId,
     pub amount: UsdCents,
+    #[serde(default = "default_currency")]
+    pub currency: CurrencyCode,
+    #[serde(default)]
+    pub channel: DepositChannel,
 }
 
 impl From<&Deposit> for PublicDeposit {
@@ -22,6 +26,12 @@ impl From<&Deposit> for PublicDeposit {
             id: entity.id,
             deposit_account_id: entity.deposit_account_id,
             amount: entity.amount,
+            currency: entity.currency,
+            channel: entity.channel,
         }
     }
 }
+
+fn default_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
@@ -13,6 +13,7 @@ use crate::{
 pub struct PublicDepositAccount {
     pub id: DepositAccountId,
     pub account_holder_id: DepositAccountHolderId,
+    pub pending_escheatment_date: Option<chrono::NaiveDate>,
 }
 
 impl From<&DepositAccount> for PublicDepositAccount {
@@ -20,6 +21,7 @@ impl From<&DepositAccount> for PublicDepositAccount {
         PublicDepositAccount {
             id: entity.id,
             account_holder_id: entity.account_holder_id,
+            pending_escheatment_date: entity.pending_escheatment_date(),
         }
     }
 }
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    deposit_import::{DepositImport, DepositImportStatus},
+    primitives::DepositImportId,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PublicDepositImport {
+    pub id: DepositImportId,
+    pub status: DepositImportStatus,
+    pub total_rows: usize,
+    pub processed_rows: usize,
+}
+
+impl From<&DepositImport> for PublicDepositImport {
+    fn from(entity: &DepositImport) -> Self {
+        PublicDepositImport {
+            id: entity.id,
+            status: entity.status(),
+            total_rows: entity.rows.len(),
+            processed_rows: entity.processed_rows(),
+        }
+    }
+}
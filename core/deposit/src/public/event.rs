@@ -3,15 +3,26 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 
-use super::{PublicDeposit, PublicDepositAccount, PublicWithdrawal};
+use super::{
+    PublicDeposit, PublicDepositAccount, PublicDepositImport, PublicHold, PublicTransfer,
+    PublicWithdrawal,
+};
 
 #[derive(Debug, Serialize, Deserialize, strum::AsRefStr)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum CoreDepositEvent {
     DepositAccountCreated { entity: PublicDepositAccount },
+    DepositAccountEscheatmentNoticeIssued { entity: PublicDepositAccount },
     DepositInitialized { entity: PublicDeposit },
     WithdrawalConfirmed { entity: PublicWithdrawal },
     WithdrawalApprovalConcluded { entity: PublicWithdrawal },
+    WithdrawalCancelled { entity: PublicWithdrawal },
     DepositReverted { entity: PublicDeposit },
+    TransferCompleted { entity: PublicTransfer },
+    TransferApprovalConcluded { entity: PublicTransfer },
+    HoldPlaced { entity: PublicHold },
+    HoldReleased { entity: PublicHold },
+    DepositImportProgressed { entity: PublicDepositImport },
+    DepositImportCompleted { entity: PublicDepositImport },
 }
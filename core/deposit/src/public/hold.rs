@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    hold::{Hold, HoldStatus},
+    primitives::{CurrencyCode, DepositAccountId, HoldId, UsdCents},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PublicHold {
+    pub id: HoldId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    #[serde(default = "default_currency")]
+    pub currency: CurrencyCode,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub status: HoldStatus,
+}
+
+impl From<&Hold> for PublicHold {
+    fn from(entity: &Hold) -> Self {
+        PublicHold {
+            id: entity.id,
+            deposit_account_id: entity.deposit_account_id,
+            amount: entity.amount,
+            currency: entity.currency,
+            expires_at: entity.expires_at,
+            status: entity.status(),
+        }
+    }
+}
+
+fn default_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
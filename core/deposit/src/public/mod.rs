@@ -1,9 +1,15 @@
 mod deposit;
 mod deposit_account;
+mod deposit_import;
 mod event;
+mod hold;
+mod transfer;
 mod withdrawal;
 
 pub use deposit::*;
 pub use deposit_account::*;
+pub use deposit_import::*;
 pub use event::*;
+pub use hold::*;
+pub use transfer::*;
 pub use withdrawal::*;
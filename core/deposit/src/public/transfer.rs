@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    primitives::{CurrencyCode, DepositAccountId, TransferId, UsdCents},
+    transfer::{Transfer, TransferStatus},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PublicTransfer {
+    pub id: TransferId,
+    pub from_account_id: DepositAccountId,
+    pub to_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    #[serde(default = "default_currency")]
+    pub currency: CurrencyCode,
+    pub status: TransferStatus,
+}
+
+impl From<&Transfer> for PublicTransfer {
+    fn from(entity: &Transfer) -> Self {
+        PublicTransfer {
+            id: entity.id,
+            from_account_id: entity.from_account_id,
+            to_account_id: entity.to_account_id,
+            amount: entity.amount,
+            currency: entity.currency,
+            status: entity.status(),
+        }
+    }
+}
+
+fn default_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
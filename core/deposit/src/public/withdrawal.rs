@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use schemars::JsonSchema;
 
 use crate::{
-    primitives::{DepositAccountId, UsdCents, WithdrawalId},
+    beneficiary::BeneficiaryDestination,
+    primitives::{BeneficiaryId, CurrencyCode, DepositAccountId, UsdCents, WithdrawalId},
     withdrawal::{Withdrawal, WithdrawalStatus},
 };
 
@@ -14,7 +15,13 @@ pub struct PublicWithdrawal {
     pub id: WithdrawalId,
     pub deposit_account_id: DepositAccountId,
     pub amount: UsdCents,
+    #[serde(default = "default_currency")]
+    pub currency: CurrencyCode,
     pub status: WithdrawalStatus,
+    #[serde(default)]
+    pub beneficiary_id: Option<BeneficiaryId>,
+    #[serde(default)]
+    pub destination: Option<BeneficiaryDestination>,
 }
 
 impl From<&Withdrawal> for PublicWithdrawal {
@@ -23,7 +30,14 @@ impl From<&Withdrawal> for PublicWithdrawal {
             id: entity.id,
             deposit_account_id: entity.deposit_account_id,
             amount: entity.amount,
+            currency: entity.currency,
             status: entity.status(),
+            beneficiary_id: entity.beneficiary_id,
+            destination: entity.destination.clone(),
         }
     }
 }
+
+fn default_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
@@ -1,9 +1,13 @@
 use obix::out::{Outbox, OutboxEventMarker};
 
 use crate::{
-    CoreDepositEvent, PublicDeposit, PublicDepositAccount, PublicWithdrawal,
+    CoreDepositEvent, PublicDeposit, PublicDepositAccount, PublicDepositImport, PublicHold,
+    PublicTransfer, PublicWithdrawal,
     account::{DepositAccount, DepositAccountEvent},
     deposit::{Deposit, DepositEvent},
+    deposit_import::{DepositImport, DepositImportEvent},
+    hold::{Hold, HoldEvent},
+    transfer::{Transfer, TransferEvent},
     withdrawal::{Withdrawal, WithdrawalEvent},
 };
 
@@ -47,6 +51,11 @@ where
                 Initialized { .. } => Some(CoreDepositEvent::DepositAccountCreated {
                     entity: PublicDepositAccount::from(entity),
                 }),
+                EscheatmentNoticeIssued { .. } => {
+                    Some(CoreDepositEvent::DepositAccountEscheatmentNoticeIssued {
+                        entity: PublicDepositAccount::from(entity),
+                    })
+                }
                 _ => None,
             })
             .collect::<Vec<_>>();
@@ -73,6 +82,9 @@ where
                         entity: PublicWithdrawal::from(entity),
                     })
                 }
+                Cancelled { .. } => Some(CoreDepositEvent::WithdrawalCancelled {
+                    entity: PublicWithdrawal::from(entity),
+                }),
                 _ => None,
             })
             .collect::<Vec<_>>();
@@ -104,4 +116,78 @@ where
             .await?;
         Ok(())
     }
+
+    pub async fn publish_transfer_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), sqlx::Error> {
+        use TransferEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Completed { .. } => Some(CoreDepositEvent::TransferCompleted {
+                    entity: PublicTransfer::from(entity),
+                }),
+                ApprovalProcessConcluded { .. } => {
+                    Some(CoreDepositEvent::TransferApprovalConcluded {
+                        entity: PublicTransfer::from(entity),
+                    })
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(op, publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_hold_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Hold,
+        new_events: es_entity::LastPersisted<'_, HoldEvent>,
+    ) -> Result<(), sqlx::Error> {
+        use HoldEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Initialized { .. } => Some(CoreDepositEvent::HoldPlaced {
+                    entity: PublicHold::from(entity),
+                }),
+                Released { .. } | Expired { .. } => Some(CoreDepositEvent::HoldReleased {
+                    entity: PublicHold::from(entity),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(op, publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_deposit_import_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &DepositImport,
+        new_events: es_entity::LastPersisted<'_, DepositImportEvent>,
+    ) -> Result<(), sqlx::Error> {
+        use DepositImportEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                RowProcessed { .. } => Some(CoreDepositEvent::DepositImportProgressed {
+                    entity: PublicDepositImport::from(entity),
+                }),
+                Completed { .. } => Some(CoreDepositEvent::DepositImportCompleted {
+                    entity: PublicDepositImport::from(entity),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(op, publish_events)
+            .await?;
+        Ok(())
+    }
 }
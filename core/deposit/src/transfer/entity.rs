@@ -0,0 +1,438 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    ApprovalProcessId, CalaTransactionId, CurrencyCode, DepositAccountId, Money, PublicId,
+    TransferId, UsdCents,
+};
+
+use super::error::TransferError;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum TransferStatus {
+    #[default]
+    PendingApproval,
+    Completed,
+    Denied,
+    Failed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TransferId")]
+pub enum TransferEvent {
+    Initialized {
+        id: TransferId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: UsdCents,
+        #[serde(default = "default_transfer_currency")]
+        currency: CurrencyCode,
+        reference: String,
+        approval_process_id: Option<ApprovalProcessId>,
+        status: TransferStatus,
+        public_id: PublicId,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        status: TransferStatus,
+    },
+    Completed {
+        ledger_tx_id: CalaTransactionId,
+        status: TransferStatus,
+    },
+    Failed {
+        status: TransferStatus,
+    },
+    FundsHeld {
+        ledger_tx_id: CalaTransactionId,
+    },
+    FundsReleased {
+        ledger_tx_id: CalaTransactionId,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Transfer {
+    pub id: TransferId,
+    pub from_account_id: DepositAccountId,
+    pub to_account_id: DepositAccountId,
+    /// Minor units of `currency`.
+    pub amount: UsdCents,
+    pub currency: CurrencyCode,
+    pub reference: String,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    pub public_id: PublicId,
+
+    events: EntityEvents<TransferEvent>,
+}
+
+impl Transfer {
+    pub fn money(&self) -> Money {
+        Money::new(self.currency, self.amount.into_inner())
+    }
+
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for transfer")
+    }
+
+    pub fn requires_approval(&self) -> bool {
+        self.approval_process_id.is_some()
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                TransferEvent::Initialized { status, .. } => Some(*status),
+                TransferEvent::ApprovalProcessConcluded { status, .. } => Some(*status),
+                TransferEvent::Completed { status, .. } => Some(*status),
+                TransferEvent::Failed { status } => Some(*status),
+                TransferEvent::FundsHeld { .. } | TransferEvent::FundsReleased { .. } => None,
+            })
+            .expect("status should always exist")
+    }
+
+    pub fn ledger_tx_ids(&self) -> Vec<CalaTransactionId> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                TransferEvent::Completed { ledger_tx_id, .. }
+                | TransferEvent::FundsHeld { ledger_tx_id }
+                | TransferEvent::FundsReleased { ledger_tx_id } => Some(*ledger_tx_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| {
+            if let TransferEvent::ApprovalProcessConcluded { approved, .. } = e {
+                Some(*approved)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Whether the transfer amount is currently earmarked on the source account.
+    pub fn holds_funds(&self) -> bool {
+        let mut held = false;
+        for event in self.events.iter_all() {
+            match event {
+                TransferEvent::FundsHeld { .. } => held = true,
+                TransferEvent::FundsReleased { .. } => held = false,
+                _ => {}
+            }
+        }
+        held
+    }
+
+    pub fn hold_funds(&mut self) -> Idempotent<CalaTransactionId> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            already_applied: TransferEvent::FundsHeld { .. }
+        );
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(TransferEvent::FundsHeld { ledger_tx_id });
+        Idempotent::Executed(ledger_tx_id)
+    }
+
+    pub fn release_funds(&mut self) -> Idempotent<CalaTransactionId> {
+        if !self.holds_funds() {
+            return Idempotent::AlreadyApplied;
+        }
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events
+            .push(TransferEvent::FundsReleased { ledger_tx_id });
+        Idempotent::Executed(ledger_tx_id)
+    }
+
+    pub fn approval_process_concluded(&mut self, approved: bool) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            already_applied: TransferEvent::ApprovalProcessConcluded { .. }
+        );
+        let status = if approved {
+            TransferStatus::PendingApproval
+        } else {
+            TransferStatus::Denied
+        };
+        self.events.push(TransferEvent::ApprovalProcessConcluded {
+            approval_process_id: self.id.into(),
+            approved,
+            status,
+        });
+        Idempotent::Executed(())
+    }
+
+    pub fn complete(&mut self) -> Result<Idempotent<CalaTransactionId>, TransferError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: TransferEvent::Completed { .. }
+        );
+
+        if self.requires_approval() && self.is_approved_or_denied() != Some(true) {
+            return Err(TransferError::NotApproved(self.id));
+        }
+
+        if self.status() == TransferStatus::Failed {
+            return Err(TransferError::AlreadyFailed(self.id));
+        }
+
+        let ledger_tx_id = self.id.into();
+        self.events.push(TransferEvent::Completed {
+            ledger_tx_id,
+            status: TransferStatus::Completed,
+        });
+
+        Ok(Idempotent::Executed(ledger_tx_id))
+    }
+
+    pub fn fail(&mut self) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: TransferEvent::Failed { .. }
+        );
+        self.events.push(TransferEvent::Failed {
+            status: TransferStatus::Failed,
+        });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<TransferEvent> for Transfer {
+    fn try_from_events(events: EntityEvents<TransferEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = TransferBuilder::default();
+        for event in events.iter_all() {
+            if let TransferEvent::Initialized {
+                id,
+                from_account_id,
+                to_account_id,
+                amount,
+                currency,
+                reference,
+                approval_process_id,
+                public_id,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .from_account_id(*from_account_id)
+                    .to_account_id(*to_account_id)
+                    .amount(*amount)
+                    .currency(*currency)
+                    .reference(reference.clone())
+                    .approval_process_id(*approval_process_id)
+                    .public_id(public_id.clone())
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewTransfer {
+    #[builder(setter(into))]
+    pub(super) id: TransferId,
+    #[builder(setter(into))]
+    pub(super) from_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) to_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(default = "CurrencyCode::USD")]
+    pub(super) currency: CurrencyCode,
+    #[builder(default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    #[builder(setter(into))]
+    pub(super) public_id: PublicId,
+    reference: Option<String>,
+    #[builder(setter(skip), default)]
+    pub(super) status: TransferStatus,
+}
+
+impl NewTransfer {
+    pub fn builder() -> NewTransferBuilder {
+        NewTransferBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewTransferBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(amount) = self.amount
+            && amount.is_zero()
+        {
+            return Err("Transfer amount cannot be zero".to_string());
+        }
+        if let (Some(from), Some(to)) = (self.from_account_id, self.to_account_id)
+            && from == to
+        {
+            return Err("Cannot transfer to the same deposit account".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<TransferEvent> for NewTransfer {
+    fn into_events(self) -> EntityEvents<TransferEvent> {
+        EntityEvents::init(
+            self.id,
+            [TransferEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                from_account_id: self.from_account_id,
+                to_account_id: self.to_account_id,
+                amount: self.amount,
+                currency: self.currency,
+                approval_process_id: self.approval_process_id,
+                status: self.status,
+                public_id: self.public_id,
+            }],
+        )
+    }
+}
+
+fn default_transfer_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn new_transfer(approval_process_id: Option<ApprovalProcessId>) -> NewTransfer {
+        NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .approval_process_id(approval_process_id)
+            .reference(None)
+            .public_id(PublicId::new("test-public-id"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn errors_when_transferring_to_same_account() {
+        let account_id = DepositAccountId::new();
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(account_id)
+            .to_account_id(account_id)
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .public_id(PublicId::new("test-public-id"))
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn errors_when_zero_amount_is_passed() {
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::ZERO)
+            .reference(None)
+            .public_id(PublicId::new("test-public-id"))
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn completes_without_approval_when_not_required() {
+        let mut transfer = Transfer::try_from_events(new_transfer(None).into_events()).unwrap();
+
+        assert!(transfer.complete().unwrap().did_execute());
+        assert_eq!(transfer.status(), TransferStatus::Completed);
+        assert!(transfer.complete().unwrap().was_already_applied());
+    }
+
+    #[test]
+    fn requires_approval_before_completing() {
+        let id = ApprovalProcessId::new();
+        let mut transfer = Transfer::try_from_events(new_transfer(Some(id)).into_events()).unwrap();
+
+        assert!(matches!(
+            transfer.complete(),
+            Err(TransferError::NotApproved(_))
+        ));
+
+        let _ = transfer.approval_process_concluded(true);
+        assert!(transfer.complete().unwrap().did_execute());
+    }
+
+    #[test]
+    fn cannot_complete_denied_transfer() {
+        let id = ApprovalProcessId::new();
+        let mut transfer = Transfer::try_from_events(new_transfer(Some(id)).into_events()).unwrap();
+
+        let _ = transfer.approval_process_concluded(false);
+        assert_eq!(transfer.status(), TransferStatus::Denied);
+        assert!(matches!(
+            transfer.complete(),
+            Err(TransferError::NotApproved(_))
+        ));
+    }
+
+    #[test]
+    fn releases_held_funds_once() {
+        let id = ApprovalProcessId::new();
+        let mut transfer = Transfer::try_from_events(new_transfer(Some(id)).into_events()).unwrap();
+
+        assert!(transfer.release_funds().was_already_applied());
+        assert!(transfer.hold_funds().did_execute());
+        assert!(transfer.hold_funds().was_already_applied());
+        assert!(transfer.holds_funds());
+        assert_eq!(transfer.status(), TransferStatus::PendingApproval);
+
+        assert!(transfer.release_funds().did_execute());
+        assert!(!transfer.holds_funds());
+        assert!(transfer.release_funds().was_already_applied());
+        assert_eq!(transfer.ledger_tx_ids().len(), 2);
+    }
+}
@@ -0,0 +1,47 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::TransferId;
+
+use super::repo::{
+    TransferCreateError, TransferFindError, TransferModifyError, TransferQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("TransferError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TransferError - Create: {0}")]
+    Create(#[from] TransferCreateError),
+    #[error("TransferError - Modify: {0}")]
+    Modify(#[from] TransferModifyError),
+    #[error("TransferError - Find: {0}")]
+    Find(#[from] TransferFindError),
+    #[error("TransferError - Query: {0}")]
+    Query(#[from] TransferQueryError),
+    #[error("TransferError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
+    #[error("TransferError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("TransferError - NotApproved: {0}")]
+    NotApproved(TransferId),
+    #[error("TransferError - AlreadyFailed: {0}")]
+    AlreadyFailed(TransferId),
+}
+
+impl ErrorSeverity for TransferError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::DepositLedgerError(e) => e.severity(),
+            Self::AuditError(e) => e.severity(),
+            Self::NotApproved(_) => Level::WARN,
+            Self::AlreadyFailed(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod entity;
+pub mod error;
+pub mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::TransferEvent;
+pub(super) use entity::*;
+pub use entity::{Transfer, TransferStatus};
+pub use repo::transfer_cursor::{TransfersByCreatedAtCursor, TransfersCursor};
+pub(super) use repo::*;
+pub use repo::{TransfersFilters, TransfersSortBy};
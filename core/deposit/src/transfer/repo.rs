@@ -0,0 +1,140 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+use obix::out::OutboxEventMarker;
+
+use money::UsdCents;
+
+use crate::{
+    primitives::{ApprovalProcessId, DepositAccountId, PublicId, TransferId},
+    public::CoreDepositEvent,
+    publisher::DepositPublisher,
+};
+
+use super::entity::*;
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "Transfer",
+    columns(
+        from_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        to_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        approval_process_id(ty = "Option<ApprovalProcessId>", update(persist = false)),
+        reference(ty = "String", create(accessor = "reference()")),
+        public_id(ty = "PublicId", list_by),
+        status(ty = "TransferStatus", list_for, update(accessor = "status()")),
+        amount(ty = "UsdCents", list_by, update(persist = false))
+    ),
+    tbl_prefix = "core",
+    post_persist_hook = "publish_in_op"
+)]
+pub struct TransferRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    publisher: DepositPublisher<E>,
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl<E> Clone for TransferRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+            pool: self.pool.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<E> TransferRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &DepositPublisher<E>, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+            clock,
+        }
+    }
+
+    async fn publish_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), sqlx::Error> {
+        self.publisher
+            .publish_transfer_in_op(op, entity, new_events)
+            .await
+    }
+}
+
+impl From<(TransfersSortBy, &Transfer)> for transfer_cursor::TransfersCursor {
+    fn from(transfer_with_sort: (TransfersSortBy, &Transfer)) -> Self {
+        let (sort, transfer) = transfer_with_sort;
+        match sort {
+            TransfersSortBy::CreatedAt => {
+                transfer_cursor::TransfersByCreatedAtCursor::from(transfer).into()
+            }
+            TransfersSortBy::Id => transfer_cursor::TransfersByIdCursor::from(transfer).into(),
+            TransfersSortBy::PublicId => {
+                transfer_cursor::TransfersByPublicIdCursor::from(transfer).into()
+            }
+            TransfersSortBy::Amount => {
+                transfer_cursor::TransfersByAmountCursor::from(transfer).into()
+            }
+        }
+    }
+}
+
+mod transfer_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::TransferStatus;
+
+    impl Type<Postgres> for TransferStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for TransferStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for TransferStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for TransferStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
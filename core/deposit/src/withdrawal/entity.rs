@@ -5,8 +5,12 @@ use serde::{Deserialize, Serialize};
 
 use es_entity::*;
 
-use crate::primitives::{
-    ApprovalProcessId, CalaTransactionId, DepositAccountId, PublicId, UsdCents, WithdrawalId,
+use crate::{
+    beneficiary::BeneficiaryDestination,
+    primitives::{
+        ApprovalProcessId, BeneficiaryId, CalaTransactionId, CurrencyCode, DepositAccountHolderId,
+        DepositAccountId, Money, PaymentFileId, PublicId, UsdCents, WithdrawalId,
+    },
 };
 
 use super::error::WithdrawalError;
@@ -26,9 +30,11 @@ use super::error::WithdrawalError;
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub enum WithdrawalStatus {
+    PendingSignatures,
     #[default]
     PendingApproval,
     PendingConfirmation,
+    PaymentSent,
     Confirmed,
     Denied,
     Cancelled,
@@ -45,16 +51,32 @@ pub enum WithdrawalEvent {
         ledger_tx_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default = "default_withdrawal_currency")]
+        currency: CurrencyCode,
         reference: String,
         approval_process_id: ApprovalProcessId,
         status: WithdrawalStatus,
         public_id: PublicId,
+        #[serde(default)]
+        beneficiary_id: Option<BeneficiaryId>,
+        #[serde(default)]
+        destination: Option<BeneficiaryDestination>,
+        #[serde(default)]
+        required_signers: Vec<DepositAccountHolderId>,
+    },
+    Signed {
+        account_holder_id: DepositAccountHolderId,
+        status: WithdrawalStatus,
     },
     ApprovalProcessConcluded {
         approval_process_id: ApprovalProcessId,
         approved: bool,
         status: WithdrawalStatus,
     },
+    PaymentSent {
+        payment_file_id: PaymentFileId,
+        status: WithdrawalStatus,
+    },
     Denied {
         ledger_tx_id: CalaTransactionId,
         status: WithdrawalStatus,
@@ -79,11 +101,23 @@ pub struct Withdrawal {
     pub id: WithdrawalId,
     pub deposit_account_id: DepositAccountId,
     pub reference: String,
+    /// Minor units of `currency`.
     pub amount: UsdCents,
+    pub currency: CurrencyCode,
     pub approval_process_id: ApprovalProcessId,
     pub public_id: PublicId,
+    #[builder(default)]
+    pub beneficiary_id: Option<BeneficiaryId>,
+    /// Snapshot of the beneficiary's destination details at the time the withdrawal was initiated.
+    #[builder(default)]
+    pub destination: Option<BeneficiaryDestination>,
+    /// Joint account holders whose signature the withdrawal needs before it can be paid out.
+    #[builder(default)]
+    pub required_signers: Vec<DepositAccountHolderId>,
     #[builder(setter(strip_option), default)]
     pub cancelled_tx_id: Option<CalaTransactionId>,
+    #[builder(setter(strip_option), default)]
+    pub payment_file_id: Option<PaymentFileId>,
 
     events: EntityEvents<WithdrawalEvent>,
 }
@@ -93,12 +127,16 @@ pub struct WithdrawalReversalData {
     pub entity_id: WithdrawalId,
     pub ledger_tx_id: CalaTransactionId,
     pub credit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub correlation_id: String,
     pub external_id: String,
 }
 
 impl Withdrawal {
+    pub fn money(&self) -> Money {
+        Money::new(self.currency, self.amount.into_inner())
+    }
+
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.events
             .entity_first_persisted_at()
@@ -117,6 +155,10 @@ impl Withdrawal {
             _ => (),
         }
 
+        if !self.missing_signers().is_empty() {
+            return Err(WithdrawalError::MissingSignatures(self.id));
+        }
+
         if self.is_cancelled() {
             return Err(WithdrawalError::AlreadyCancelled(self.id));
         }
@@ -130,6 +172,29 @@ impl Withdrawal {
         Ok(Idempotent::Executed(ledger_tx_id))
     }
 
+    /// Records that the withdrawal was included in an outgoing payment file.
+    pub fn payment_sent(
+        &mut self,
+        payment_file_id: PaymentFileId,
+    ) -> Result<Idempotent<()>, WithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: WithdrawalEvent::PaymentSent { .. }
+        );
+
+        if self.status() != WithdrawalStatus::PendingConfirmation {
+            return Err(WithdrawalError::NotPendingConfirmation(self.id));
+        }
+
+        self.events.push(WithdrawalEvent::PaymentSent {
+            payment_file_id,
+            status: WithdrawalStatus::PaymentSent,
+        });
+        self.payment_file_id = Some(payment_file_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
     pub fn revert(&mut self) -> Result<Idempotent<WithdrawalReversalData>, WithdrawalError> {
         idempotency_guard!(
             self.events.iter_all().rev(),
@@ -154,7 +219,7 @@ impl Withdrawal {
         Ok(Idempotent::Executed(WithdrawalReversalData {
             entity_id: self.id,
             ledger_tx_id,
-            amount: self.amount,
+            amount: self.money(),
             credit_account_id: self.deposit_account_id,
             correlation_id: self.id.to_string(),
             external_id: format!("lana:withdraw:{}:reverted", self.id),
@@ -181,6 +246,61 @@ impl Withdrawal {
         Ok(Idempotent::Executed(ledger_tx_id))
     }
 
+    pub fn signers(&self) -> Vec<DepositAccountHolderId> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                WithdrawalEvent::Signed {
+                    account_holder_id, ..
+                } => Some(*account_holder_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn missing_signers(&self) -> Vec<DepositAccountHolderId> {
+        let signers = self.signers();
+        self.required_signers
+            .iter()
+            .filter(|id| !signers.contains(id))
+            .copied()
+            .collect()
+    }
+
+    /// Records the signature of one of the holders required to sign. Once the last
+    /// signature is in, the withdrawal moves on to wherever the approval process left it.
+    pub fn sign(
+        &mut self,
+        account_holder_id: DepositAccountHolderId,
+    ) -> Result<Idempotent<()>, WithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: WithdrawalEvent::Signed { account_holder_id: existing, .. } if existing == &account_holder_id
+        );
+        if !self.required_signers.contains(&account_holder_id) {
+            return Err(WithdrawalError::SignatureNotRequired(
+                self.id,
+                account_holder_id,
+            ));
+        }
+        if self.status() != WithdrawalStatus::PendingSignatures {
+            return Err(WithdrawalError::NotPendingSignatures(self.id));
+        }
+
+        let complete = self.missing_signers() == [account_holder_id];
+        let status = match (complete, self.is_approved_or_denied()) {
+            (false, _) => WithdrawalStatus::PendingSignatures,
+            (true, Some(true)) => WithdrawalStatus::PendingConfirmation,
+            (true, _) => WithdrawalStatus::PendingApproval,
+        };
+        self.events.push(WithdrawalEvent::Signed {
+            account_holder_id,
+            status,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
     fn is_confirmed(&self) -> bool {
         self.events
             .iter_all()
@@ -214,12 +334,22 @@ impl Withdrawal {
                 WithdrawalEvent::Reverted { status, .. } => *status,
                 WithdrawalEvent::Denied { status, .. } => *status,
                 WithdrawalEvent::ApprovalProcessConcluded { status, .. } => *status,
+                WithdrawalEvent::PaymentSent { status, .. } => *status,
+                WithdrawalEvent::Signed { status, .. } => *status,
                 WithdrawalEvent::Initialized { status, .. } => *status,
             })
             .next()
             .expect("status should always exist")
     }
 
+    /// Denied, cancelled and reverted withdrawals no longer count towards the account's withdrawal limits.
+    pub fn counts_towards_withdrawal_limits(&self) -> bool {
+        !matches!(
+            self.status(),
+            WithdrawalStatus::Denied | WithdrawalStatus::Cancelled | WithdrawalStatus::Reverted
+        )
+    }
+
     pub fn ledger_tx_ids(&self) -> Vec<CalaTransactionId> {
         self.events
             .iter_all()
@@ -230,6 +360,8 @@ impl Withdrawal {
                 WithdrawalEvent::Cancelled { ledger_tx_id, .. } => Some(*ledger_tx_id),
                 WithdrawalEvent::Reverted { ledger_tx_id, .. } => Some(*ledger_tx_id),
                 WithdrawalEvent::ApprovalProcessConcluded { .. } => None,
+                WithdrawalEvent::PaymentSent { .. } => None,
+                WithdrawalEvent::Signed { .. } => None,
             })
             .collect()
     }
@@ -242,10 +374,14 @@ impl Withdrawal {
             self.events.iter_all(),
             already_applied: WithdrawalEvent::ApprovalProcessConcluded { .. }
         );
-        let status = if approved {
-            WithdrawalStatus::PendingConfirmation
-        } else {
-            WithdrawalStatus::Denied
+        // A withdrawal cancelled while awaiting approval has already released its funds.
+        if self.is_cancelled() {
+            return Idempotent::AlreadyApplied;
+        }
+        let status = match (approved, self.missing_signers().is_empty()) {
+            (false, _) => WithdrawalStatus::Denied,
+            (true, false) => WithdrawalStatus::PendingSignatures,
+            (true, true) => WithdrawalStatus::PendingConfirmation,
         };
         self.events.push(WithdrawalEvent::ApprovalProcessConcluded {
             approval_process_id: self.id.into(),
@@ -279,21 +415,32 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                     reference,
                     deposit_account_id,
                     amount,
+                    currency,
                     approval_process_id,
                     public_id,
+                    beneficiary_id,
+                    destination,
+                    required_signers,
                     ..
                 } => {
                     builder = builder
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
                         .amount(*amount)
+                        .currency(*currency)
                         .reference(reference.clone())
                         .approval_process_id(*approval_process_id)
                         .public_id(public_id.clone())
+                        .beneficiary_id(*beneficiary_id)
+                        .destination(destination.clone())
+                        .required_signers(required_signers.clone())
                 }
                 WithdrawalEvent::Cancelled { ledger_tx_id, .. } => {
                     builder = builder.cancelled_tx_id(*ledger_tx_id)
                 }
+                WithdrawalEvent::PaymentSent {
+                    payment_file_id, ..
+                } => builder = builder.payment_file_id(*payment_file_id),
                 _ => (),
             }
         }
@@ -310,10 +457,17 @@ pub struct NewWithdrawal {
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
     pub(super) amount: UsdCents,
+    #[builder(default = "CurrencyCode::USD")]
+    pub(super) currency: CurrencyCode,
     #[builder(setter(into))]
     pub(super) approval_process_id: ApprovalProcessId,
     #[builder(setter(into))]
     pub(super) public_id: PublicId,
+    #[builder(setter(into))]
+    pub(super) beneficiary_id: BeneficiaryId,
+    pub(super) destination: BeneficiaryDestination,
+    #[builder(default)]
+    pub(super) required_signers: Vec<DepositAccountHolderId>,
     reference: Option<String>,
     #[builder(setter(skip), default)]
     pub(super) status: WithdrawalStatus,
@@ -352,18 +506,39 @@ impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
                 ledger_tx_id: self.id.into(),
                 deposit_account_id: self.deposit_account_id,
                 amount: self.amount,
+                currency: self.currency,
                 approval_process_id: self.approval_process_id,
-                status: WithdrawalStatus::PendingApproval,
+                status: if self.required_signers.is_empty() {
+                    WithdrawalStatus::PendingApproval
+                } else {
+                    WithdrawalStatus::PendingSignatures
+                },
                 public_id: self.public_id,
+                beneficiary_id: Some(self.beneficiary_id),
+                destination: Some(self.destination),
+                required_signers: self.required_signers,
             }],
         )
     }
 }
 
+fn default_withdrawal_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
+
 #[cfg(test)]
 mod test {
+    use crate::beneficiary::{CryptoAddressDetails, CryptoNetwork};
+
     use super::*;
 
+    fn destination() -> BeneficiaryDestination {
+        BeneficiaryDestination::CryptoAddress(CryptoAddressDetails {
+            network: CryptoNetwork::Ethereum,
+            address: "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+        })
+    }
+
     #[test]
     fn errors_when_zero_amount_withdrawal_amount_is_passed() {
         let withdrawal = NewWithdrawal::builder()
@@ -373,6 +548,8 @@ mod test {
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
             .build();
 
         assert!(matches!(
@@ -389,6 +566,8 @@ mod test {
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
             .build();
 
         assert!(matches!(
@@ -406,6 +585,8 @@ mod test {
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
             .build();
 
         assert!(withdrawal.is_ok());
@@ -419,6 +600,8 @@ mod test {
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
             .build()
             .unwrap();
 
@@ -428,6 +611,41 @@ mod test {
         withdrawal
     }
 
+    #[test]
+    fn payment_sent_requires_approval_and_can_be_confirmed() {
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(WithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .approval_process_id(ApprovalProcessId::new())
+            .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
+            .build()
+            .unwrap();
+
+        let mut withdrawal = Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap();
+        let payment_file_id = PaymentFileId::new();
+        assert!(matches!(
+            withdrawal.payment_sent(payment_file_id),
+            Err(WithdrawalError::NotPendingConfirmation(_))
+        ));
+
+        withdrawal.approval_process_concluded(true).unwrap();
+        assert!(
+            withdrawal
+                .payment_sent(payment_file_id)
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PaymentSent);
+        assert_eq!(withdrawal.payment_file_id, Some(payment_file_id));
+
+        assert!(withdrawal.confirm().unwrap().did_execute());
+        assert_eq!(withdrawal.status(), WithdrawalStatus::Confirmed);
+    }
+
     #[test]
     fn can_revert_confirmed_withdrawal() {
         let mut withdrawal = create_confirmed_withdrawal();
@@ -447,6 +665,8 @@ mod test {
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
             .build()
             .unwrap();
 
@@ -476,6 +696,8 @@ mod test {
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
             .build()
             .unwrap();
 
@@ -486,4 +708,67 @@ mod test {
 
         assert!(matches!(result, Err(WithdrawalError::NotConfirmed(_))));
     }
+
+    #[test]
+    fn joint_withdrawal_waits_for_all_required_signatures() {
+        let first = DepositAccountHolderId::new();
+        let second = DepositAccountHolderId::new();
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(WithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .approval_process_id(ApprovalProcessId::new())
+            .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
+            .required_signers(vec![first, second])
+            .build()
+            .unwrap();
+        let mut withdrawal = Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap();
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PendingSignatures);
+
+        assert!(withdrawal.approval_process_concluded(true).did_execute());
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PendingSignatures);
+        assert!(matches!(
+            withdrawal.confirm(),
+            Err(WithdrawalError::MissingSignatures(_))
+        ));
+
+        assert!(matches!(
+            withdrawal.sign(DepositAccountHolderId::new()),
+            Err(WithdrawalError::SignatureNotRequired(..))
+        ));
+        assert!(withdrawal.sign(first).unwrap().did_execute());
+        assert!(withdrawal.sign(first).unwrap().was_already_applied());
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PendingSignatures);
+        assert!(withdrawal.sign(second).unwrap().did_execute());
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PendingConfirmation);
+        assert!(withdrawal.confirm().unwrap().did_execute());
+    }
+
+    #[test]
+    fn cancelled_joint_withdrawal_ignores_approval_conclusion() {
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(WithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .approval_process_id(ApprovalProcessId::new())
+            .public_id(PublicId::new("test-public-id"))
+            .beneficiary_id(BeneficiaryId::new())
+            .destination(destination())
+            .required_signers(vec![DepositAccountHolderId::new()])
+            .build()
+            .unwrap();
+        let mut withdrawal = Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap();
+        assert!(withdrawal.cancel().unwrap().did_execute());
+
+        assert!(
+            withdrawal
+                .approval_process_concluded(false)
+                .was_already_applied()
+        );
+        assert_eq!(withdrawal.status(), WithdrawalStatus::Cancelled);
+    }
 }
@@ -2,7 +2,10 @@ use thiserror::Error;
 use tracing::Level;
 use tracing_utils::ErrorSeverity;
 
-use crate::primitives::WithdrawalId;
+use crate::{
+    primitives::{DepositAccountHolderId, UsdCents, WithdrawalId},
+    withdrawal_limit::WithdrawalLimitPeriod,
+};
 
 use super::repo::{
     WithdrawalCreateError, WithdrawalFindError, WithdrawalModifyError, WithdrawalQueryError,
@@ -32,6 +35,24 @@ pub enum WithdrawalError {
     AuditError(#[from] audit::error::AuditError),
     #[error("WithdrawalError - NotConfirmed: {0}")]
     NotConfirmed(WithdrawalId),
+    #[error("WithdrawalError - NotPendingConfirmation: {0}")]
+    NotPendingConfirmation(WithdrawalId),
+    #[error("WithdrawalError - NotPendingSignatures: {0}")]
+    NotPendingSignatures(WithdrawalId),
+    #[error("WithdrawalError - MissingSignatures: {0}")]
+    MissingSignatures(WithdrawalId),
+    #[error("WithdrawalError - SignatureNotRequired: {1} does not sign for {0}")]
+    SignatureNotRequired(WithdrawalId, DepositAccountHolderId),
+    #[error(
+        "WithdrawalError - WithdrawalLimitExceeded: {period} withdrawal limit of {} exceeded, {} remaining in the current window",
+        .limit.formatted_usd(),
+        .remaining.formatted_usd()
+    )]
+    WithdrawalLimitExceeded {
+        period: WithdrawalLimitPeriod,
+        limit: UsdCents,
+        remaining: UsdCents,
+    },
 }
 
 impl ErrorSeverity for WithdrawalError {
@@ -48,6 +69,11 @@ impl ErrorSeverity for WithdrawalError {
             Self::NotApproved(_) => Level::WARN,
             Self::AuditError(e) => e.severity(),
             Self::NotConfirmed(_) => Level::WARN,
+            Self::NotPendingConfirmation(_) => Level::WARN,
+            Self::NotPendingSignatures(_) => Level::WARN,
+            Self::MissingSignatures(_) => Level::WARN,
+            Self::SignatureNotRequired(..) => Level::WARN,
+            Self::WithdrawalLimitExceeded { .. } => Level::WARN,
         }
     }
 }
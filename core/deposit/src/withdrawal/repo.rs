@@ -27,7 +27,11 @@ use super::entity::*;
         cancelled_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
         reference(ty = "String", create(accessor = "reference()")),
         public_id(ty = "PublicId", list_by),
-        status(ty = "WithdrawalStatus", list_for, update(accessor = "status()")),
+        status(
+            ty = "WithdrawalStatus",
+            list_for(by(created_at)),
+            update(accessor = "status()")
+        ),
         amount(ty = "UsdCents", list_by, update(persist = false))
     ),
     tbl_prefix = "core",
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use core_customer::CustomerType;
+use domain_config::define_internal_config;
+
+use super::value::WithdrawalLimits;
+
+define_internal_config! {
+    #[derive(Serialize, Deserialize, Clone, Debug, Default)]
+    pub(crate) struct DepositWithdrawalLimits {
+        pub(crate) default_limits: WithdrawalLimits,
+        pub(crate) by_customer_type: HashMap<CustomerType, WithdrawalLimits>,
+    }
+
+    spec {
+        key: "deposit-withdrawal-limits";
+        default: || Some(DepositWithdrawalLimits::default());
+    }
+}
+
+impl DepositWithdrawalLimits {
+    /// Limits configured for `customer_type`, falling back to the default limits for unset periods.
+    pub(crate) fn for_customer_type(&self, customer_type: CustomerType) -> WithdrawalLimits {
+        self.configured(Some(customer_type)).or(self.default_limits)
+    }
+
+    pub(crate) fn configured(&self, customer_type: Option<CustomerType>) -> WithdrawalLimits {
+        match customer_type {
+            Some(customer_type) => self
+                .by_customer_type
+                .get(&customer_type)
+                .copied()
+                .unwrap_or_default(),
+            None => self.default_limits,
+        }
+    }
+
+    pub(crate) fn set(&mut self, customer_type: Option<CustomerType>, limits: WithdrawalLimits) {
+        match customer_type {
+            Some(customer_type) => {
+                self.by_customer_type.insert(customer_type, limits);
+            }
+            None => self.default_limits = limits,
+        }
+    }
+}
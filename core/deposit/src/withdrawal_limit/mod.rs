@@ -0,0 +1,5 @@
+mod config;
+mod value;
+
+pub(crate) use config::DepositWithdrawalLimits;
+pub use value::*;
@@ -0,0 +1,196 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{primitives::UsdCents, withdrawal::error::WithdrawalError};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WithdrawalLimitPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl WithdrawalLimitPeriod {
+    pub const ALL: [WithdrawalLimitPeriod; 3] = [
+        WithdrawalLimitPeriod::Daily,
+        WithdrawalLimitPeriod::Weekly,
+        WithdrawalLimitPeriod::Monthly,
+    ];
+
+    /// First day of the calendar window `date` falls in. Weeks are ISO 8601 weeks.
+    pub fn window_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            WithdrawalLimitPeriod::Daily => date,
+            WithdrawalLimitPeriod::Weekly => {
+                date - chrono::Days::new(u64::from(date.weekday().num_days_from_monday()))
+            }
+            WithdrawalLimitPeriod::Monthly => date.with_day(1).expect("first day of month"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalLimits {
+    #[serde(default)]
+    pub daily: Option<UsdCents>,
+    #[serde(default)]
+    pub weekly: Option<UsdCents>,
+    #[serde(default)]
+    pub monthly: Option<UsdCents>,
+}
+
+impl WithdrawalLimits {
+    pub fn limit_for(&self, period: WithdrawalLimitPeriod) -> Option<UsdCents> {
+        match period {
+            WithdrawalLimitPeriod::Daily => self.daily,
+            WithdrawalLimitPeriod::Weekly => self.weekly,
+            WithdrawalLimitPeriod::Monthly => self.monthly,
+        }
+    }
+
+    /// Takes the limit of each period from `self`, falling back to `fallback` where unset.
+    pub fn or(self, fallback: WithdrawalLimits) -> Self {
+        Self {
+            daily: self.daily.or(fallback.daily),
+            weekly: self.weekly.or(fallback.weekly),
+            monthly: self.monthly.or(fallback.monthly),
+        }
+    }
+
+    /// Earliest date a withdrawal can have been made on and still count towards one
+    /// of the limits on `today`.
+    pub fn earliest_window_start(&self, today: NaiveDate) -> Option<NaiveDate> {
+        WithdrawalLimitPeriod::ALL
+            .into_iter()
+            .filter(|period| self.limit_for(*period).is_some())
+            .map(|period| period.window_start(today))
+            .min()
+    }
+
+    /// Checks `amount` against every limit, given the `(date, amount)` of the withdrawals
+    /// that already count towards them.
+    pub fn check(
+        &self,
+        today: NaiveDate,
+        amount: UsdCents,
+        withdrawn: &[(NaiveDate, UsdCents)],
+    ) -> Result<(), WithdrawalError> {
+        for period in WithdrawalLimitPeriod::ALL {
+            let Some(limit) = self.limit_for(period) else {
+                continue;
+            };
+            let window_start = period.window_start(today);
+            let used = withdrawn
+                .iter()
+                .filter(|(date, _)| period.window_start(*date) == window_start)
+                .fold(UsdCents::ZERO, |used, (_, amount)| used + *amount);
+            if used + amount > limit {
+                return Err(WithdrawalError::WithdrawalLimitExceeded {
+                    period,
+                    limit,
+                    remaining: limit.checked_sub(used).unwrap_or(UsdCents::ZERO),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekly_window_follows_iso_weeks_across_year_end() {
+        let date = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2026, 12, 28).unwrap();
+
+        assert_eq!(WithdrawalLimitPeriod::Daily.window_start(date), date);
+        assert_eq!(WithdrawalLimitPeriod::Weekly.window_start(date), monday);
+        assert_eq!(
+            WithdrawalLimitPeriod::Monthly.window_start(date),
+            NaiveDate::from_ymd_opt(2026, 12, 1).unwrap()
+        );
+        assert_eq!(
+            WithdrawalLimitPeriod::Weekly
+                .window_start(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()),
+            monday
+        );
+    }
+
+    #[test]
+    fn check_only_counts_withdrawals_in_the_current_window() {
+        let today = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+        let limits = WithdrawalLimits {
+            daily: Some(UsdCents::from(1_000)),
+            weekly: Some(UsdCents::from(2_000)),
+            ..Default::default()
+        };
+        let withdrawn = [
+            (today, UsdCents::from(600)),
+            (
+                NaiveDate::from_ymd_opt(2026, 12, 28).unwrap(),
+                UsdCents::from(1_000),
+            ),
+            (
+                NaiveDate::from_ymd_opt(2026, 12, 27).unwrap(),
+                UsdCents::from(5_000),
+            ),
+        ];
+
+        assert!(limits.check(today, UsdCents::from(400), &withdrawn).is_ok());
+        assert!(matches!(
+            limits.check(today, UsdCents::from(500), &withdrawn),
+            Err(WithdrawalError::WithdrawalLimitExceeded {
+                period: WithdrawalLimitPeriod::Daily,
+                remaining,
+                ..
+            }) if remaining == UsdCents::from(400)
+        ));
+        assert_eq!(
+            limits.earliest_window_start(today),
+            NaiveDate::from_ymd_opt(2026, 12, 28)
+        );
+    }
+
+    #[test]
+    fn unset_periods_fall_back() {
+        let account = WithdrawalLimits {
+            daily: Some(UsdCents::from(5_000)),
+            ..Default::default()
+        };
+        let customer_type = WithdrawalLimits {
+            daily: Some(UsdCents::from(1_000)),
+            monthly: Some(UsdCents::from(20_000)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            account.or(customer_type),
+            WithdrawalLimits {
+                daily: Some(UsdCents::from(5_000)),
+                weekly: None,
+                monthly: Some(UsdCents::from(20_000)),
+            }
+        );
+    }
+}
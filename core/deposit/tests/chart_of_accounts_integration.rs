@@ -25,6 +25,10 @@ const DEPOSIT_ACCOUNTS_CSV: &str = r#"
 25,,,Non Domiciled Individual Deposit Accounts,,
 26,,,Government Entity Deposit Accounts,,
 27,,,Frozen Deposit Accounts,,
+61,,,Deposit Interest Expense,,
+41,,,Deposit Fee Income,,
+28,,,Unclaimed Property,,
+12,,,Foreign Currency Position,,
 "#;
 
 async fn assert_deposit_pairs(
@@ -124,10 +128,13 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &customers,
         &exposed_domain_configs,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let price = core_price::Price::new(&outbox);
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -135,7 +142,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+        &exposed_domain_configs,
+        &price,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let chart_id = accounting
         .chart_of_accounts()
@@ -190,8 +201,27 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code: "27"
             .parse()
             .unwrap(),
+        chart_of_accounts_interest_expense_parent_code: Some("61".parse().unwrap()),
+        chart_of_accounts_fee_income_parent_code: Some("41".parse().unwrap()),
+        chart_of_accounts_unclaimed_property_parent_code: Some("28".parse().unwrap()),
+        chart_of_accounts_fx_position_parent_code: Some("12".parse().unwrap()),
     };
 
+    // Configs stored before a parent code existed can be completed later on
+    deposit
+        .chart_of_accounts_integrations()
+        .set_config(
+            &DummySubject,
+            &chart,
+            ChartOfAccountsIntegrationConfig {
+                chart_of_accounts_interest_expense_parent_code: None,
+                chart_of_accounts_fx_position_parent_code: None,
+                chart_of_accounts_unclaimed_property_parent_code: None,
+                chart_of_accounts_fee_income_parent_code: None,
+                ..chart_of_accounts_config.clone()
+            },
+        )
+        .await?;
     deposit
         .chart_of_accounts_integrations()
         .set_config(&DummySubject, &chart, chart_of_accounts_config.clone())
@@ -207,11 +237,45 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     let deposit_catalog = catalog.deposit();
     let frozen = catalog.frozen();
     let omnibus = catalog.omnibus();
+    let interest_expense = catalog.interest_expense();
+    let fee_income = catalog.fee_income();
+    let unclaimed_property = catalog.unclaimed_property();
+    let fx_position = catalog.fx_position();
 
-    let omnibus_pairs = [(
-        &chart_of_accounts_config.chart_of_accounts_omnibus_parent_code,
-        *omnibus,
-    )];
+    let omnibus_pairs = [
+        (
+            &chart_of_accounts_config.chart_of_accounts_omnibus_parent_code,
+            *omnibus,
+        ),
+        (
+            chart_of_accounts_config
+                .chart_of_accounts_interest_expense_parent_code
+                .as_ref()
+                .expect("interest expense parent code"),
+            *interest_expense,
+        ),
+        (
+            chart_of_accounts_config
+                .chart_of_accounts_fee_income_parent_code
+                .as_ref()
+                .expect("fee income parent code"),
+            *fee_income,
+        ),
+        (
+            chart_of_accounts_config
+                .chart_of_accounts_unclaimed_property_parent_code
+                .as_ref()
+                .expect("unclaimed property parent code"),
+            *unclaimed_property,
+        ),
+        (
+            chart_of_accounts_config
+                .chart_of_accounts_fx_position_parent_code
+                .as_ref()
+                .expect("fx position parent code"),
+            *fx_position,
+        ),
+    ];
     assert_omnibus_pairs(&cala, &chart, &omnibus_account_set_ids, &omnibus_pairs).await?;
 
     let deposit_pairs = [
@@ -284,7 +348,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         .list_members_by_created_at(account_set_id, Default::default())
         .await?;
 
-    assert_eq!(res.entities.len(), 1);
+    // The omnibus and foreign currency position parents
+    assert_eq!(res.entities.len(), 2);
 
     // Immutable: setting config again should fail
     let err = deposit
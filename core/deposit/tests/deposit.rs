@@ -75,7 +75,7 @@ async fn setup_at(start: DateTime<Utc>) -> anyhow::Result<(TestSetup, ClockContr
         &pool,
         &authz,
         &outbox,
-        document_storage,
+        document_storage.clone(),
         public_ids.clone(),
         &exposed_domain_configs_readonly,
         clock.clone(),
@@ -93,6 +93,8 @@ async fn setup_at(start: DateTime<Utc>) -> anyhow::Result<(TestSetup, ClockContr
         &customers,
         &exposed_domain_configs_readonly,
         &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
     )
     .await?;
 
@@ -287,6 +289,7 @@ async fn withdrawal_confirmed_publishes_event() -> anyhow::Result<()> {
         .await?;
 
     let account = deposit.create_account(&DummySubject, customer.id).await?;
+    let beneficiary = helpers::create_verified_beneficiary(&deposit, customer.id).await?;
     let deposit_amount = UsdCents::try_from_usd(dec!(1000000)).unwrap();
     deposit
         .record_deposit(&DummySubject, account.id, deposit_amount, None)
@@ -294,7 +297,13 @@ async fn withdrawal_confirmed_publishes_event() -> anyhow::Result<()> {
 
     let withdrawal_amount = UsdCents::try_from_usd(dec!(500000)).unwrap();
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            beneficiary.id,
+            withdrawal_amount,
+            None,
+        )
         .await?;
 
     // Withdrawal approval is concluded asynchronously via the governance → outbox → jobs pipeline.
@@ -384,7 +393,7 @@ async fn deposit_reverted_publishes_event() -> anyhow::Result<()> {
 }
 
 /// Test helper that exercises the same code path as the fan-out jobs:
-/// collect non-escheatable accounts, then evaluate and update each one.
+/// collect accounts eligible for evaluation, then evaluate and update each one.
 async fn apply_activity_reclassifications(
     deposit: &TestDeposit,
     pool: &sqlx::PgPool,
@@ -392,7 +401,7 @@ async fn apply_activity_reclassifications(
 ) -> anyhow::Result<()> {
     let mut op = es_entity::DbOp::init(pool).await?;
     let accounts = deposit
-        .list_account_ids_not_escheatable_in_op(&mut op, None, 100)
+        .list_account_ids_for_activity_evaluation_in_op(&mut op, None, 100)
         .await?;
     for (account_id, _) in accounts {
         deposit
@@ -519,9 +528,16 @@ async fn deposit_account_activity_ignores_internal_freeze_and_unfreeze_transacti
 async fn deposit_account_activity_updates_from_ledger_activity_or_creation_date()
 -> anyhow::Result<()> {
     let start = Utc.with_ymd_and_hms(2015, 1, 1, 0, 0, 0).unwrap();
-    let ((deposit, customers, _outbox, _jobs, _domain_configs, pool), clock_ctrl) =
+    let ((deposit, customers, _outbox, _jobs, domain_configs, pool), clock_ctrl) =
         setup_at(start).await?;
 
+    domain_configs
+        .update::<DepositActivityInactiveThresholdDays>(&DummySubject, 365)
+        .await?;
+    domain_configs
+        .update::<DepositActivityEscheatableThresholdDays>(&DummySubject, 3650)
+        .await?;
+
     let customer = customers
         .create_customer_bypassing_kyc(
             &DummySubject,
@@ -553,7 +569,7 @@ async fn deposit_account_activity_updates_from_ledger_activity_or_creation_date(
     apply_activity_reclassifications(&deposit, &pool, now).await?;
 
     let account = deposit.find_account_by_id_without_audit(account.id).await?;
-    assert_eq!(account.activity, Activity::Escheatable);
+    assert_eq!(account.activity, Activity::Inactive);
 
     clock_ctrl
         .advance(std::time::Duration::from_secs(370 * 24 * 60 * 60))
@@ -569,7 +585,7 @@ async fn deposit_account_activity_updates_from_ledger_activity_or_creation_date(
     apply_activity_reclassifications(&deposit, &pool, now).await?;
 
     let account = deposit.find_account_by_id_without_audit(account.id).await?;
-    assert_eq!(account.activity, Activity::Escheatable);
+    assert_eq!(account.activity, Activity::Active);
 
     Ok(())
 }
@@ -578,9 +594,16 @@ async fn deposit_account_activity_updates_from_ledger_activity_or_creation_date(
 #[serial_test::file_serial(core_deposit_activity_status)]
 async fn deposit_account_activity_updates_from_withdrawal_history() -> anyhow::Result<()> {
     let start = Utc.with_ymd_and_hms(2015, 1, 1, 0, 0, 0).unwrap();
-    let ((deposit, customers, _outbox, _jobs, _domain_configs, pool), clock_ctrl) =
+    let ((deposit, customers, _outbox, _jobs, domain_configs, pool), clock_ctrl) =
         setup_at(start).await?;
 
+    domain_configs
+        .update::<DepositActivityInactiveThresholdDays>(&DummySubject, 365)
+        .await?;
+    domain_configs
+        .update::<DepositActivityEscheatableThresholdDays>(&DummySubject, 3650)
+        .await?;
+
     let customer = customers
         .create_customer_bypassing_kyc(
             &DummySubject,
@@ -591,6 +614,7 @@ async fn deposit_account_activity_updates_from_withdrawal_history() -> anyhow::R
         .await?;
 
     let account = deposit.create_account(&DummySubject, customer.id).await?;
+    let beneficiary = helpers::create_verified_beneficiary(&deposit, customer.id).await?;
     deposit
         .record_deposit(
             &DummySubject,
@@ -614,6 +638,7 @@ async fn deposit_account_activity_updates_from_withdrawal_history() -> anyhow::R
         .initiate_withdrawal(
             &DummySubject,
             account.id,
+            beneficiary.id,
             UsdCents::try_from_usd(dec!(100)).unwrap(),
             None,
         )
@@ -621,7 +646,7 @@ async fn deposit_account_activity_updates_from_withdrawal_history() -> anyhow::R
     apply_activity_reclassifications(&deposit, &pool, now).await?;
 
     let account = deposit.find_account_by_id_without_audit(account.id).await?;
-    assert_eq!(account.activity, Activity::Escheatable);
+    assert_eq!(account.activity, Activity::Inactive);
 
     clock_ctrl
         .advance(std::time::Duration::from_secs(370 * 24 * 60 * 60))
@@ -630,6 +655,7 @@ async fn deposit_account_activity_updates_from_withdrawal_history() -> anyhow::R
         .initiate_withdrawal(
             &DummySubject,
             account.id,
+            beneficiary.id,
             UsdCents::try_from_usd(dec!(100)).unwrap(),
             None,
         )
@@ -637,7 +663,202 @@ async fn deposit_account_activity_updates_from_withdrawal_history() -> anyhow::R
     apply_activity_reclassifications(&deposit, &pool, now).await?;
 
     let account = deposit.find_account_by_id_without_audit(account.id).await?;
-    assert_eq!(account.activity, Activity::Escheatable);
+    assert_eq!(account.activity, Activity::Active);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn charge_and_reverse_periodic_fee() -> anyhow::Result<()> {
+    let (deposit, customers, _outbox, _jobs, _domain_configs, pool) = setup().await?;
+
+    let customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let account = deposit.create_account(&DummySubject, customer.id).await?;
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    let product = deposit
+        .create_deposit_product(
+            &DummySubject,
+            format!("Fee product {}", Uuid::new_v4()),
+            DepositProductValues::builder()
+                .annual_rate(dec!(0))
+                .accrual_frequency(InterestFrequency::Monthly)
+                .posting_frequency(InterestFrequency::Monthly)
+                .build()?,
+        )
+        .await?;
+    deposit
+        .assign_deposit_product(&DummySubject, account.id, product.id)
+        .await?;
+    let fee = UsdCents::from(500);
+    deposit
+        .update_deposit_fee_schedule(
+            &DummySubject,
+            product.id,
+            DepositFeeSchedule {
+                monthly_maintenance_fee: Some(fee),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let mut op = es_entity::DbOp::init(&pool).await?;
+    deposit
+        .charge_periodic_fees_in_op(&mut op, account.id, Utc::now())
+        .await?;
+    op.commit().await?;
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, deposit_amount - fee);
+
+    let history = deposit
+        .account_history(
+            &DummySubject,
+            account.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    let fee_tx_id = history
+        .entities
+        .iter()
+        .find_map(|entry| match entry {
+            DepositAccountHistoryEntry::FeeCharge(entry) => Some(entry.tx_id),
+            _ => None,
+        })
+        .expect("fee charge should be in the history");
+
+    deposit
+        .reverse_deposit_fee(&DummySubject, account.id, fee_tx_id)
+        .await?;
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, deposit_amount);
+
+    // Reversing the same fee again is a no-op.
+    deposit
+        .reverse_deposit_fee(&DummySubject, account.id, fee_tx_id)
+        .await?;
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, deposit_amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_usd_account_moves_funds_in_its_own_currency() -> anyhow::Result<()> {
+    let (deposit, customers, _outbox, _jobs, _domain_configs, _pool) = setup().await?;
+
+    let sender = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let recipient = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let from = deposit
+        .create_account_in_currency(&DummySubject, sender.id, CurrencyCode::EUR)
+        .await?;
+    let to = deposit
+        .create_account_in_currency(&DummySubject, recipient.id, CurrencyCode::EUR)
+        .await?;
+
+    let usd_deposit = deposit
+        .record_deposit(
+            &DummySubject,
+            from.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        usd_deposit,
+        Err(error::CoreDepositError::DepositAccountError(
+            DepositAccountError::CurrencyMismatch { .. }
+        ))
+    ));
+
+    let recorded = deposit
+        .record_deposit(
+            &DummySubject,
+            from.id,
+            Money::try_from_major(CurrencyCode::EUR, dec!(100))?,
+            None,
+        )
+        .await?;
+    assert_eq!(recorded.currency, CurrencyCode::EUR);
+
+    deposit
+        .place_hold(
+            &DummySubject,
+            from.id,
+            Money::try_from_major(CurrencyCode::EUR, dec!(30))?,
+            "legal hold".to_string(),
+            Utc::now() + Duration::days(1),
+        )
+        .await?;
+    let balance = deposit
+        .account_currency_balance(&DummySubject, from.id)
+        .await?;
+    assert_eq!(
+        balance.available(),
+        Money::try_from_major(CurrencyCode::EUR, dec!(70))?
+    );
+
+    let over_available = deposit
+        .initiate_transfer(
+            &DummySubject,
+            from.id,
+            to.id,
+            Money::try_from_major(CurrencyCode::EUR, dec!(80))?,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        over_available,
+        Err(error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            from.id,
+            to.id,
+            Money::try_from_major(CurrencyCode::EUR, dec!(50))?,
+            None,
+        )
+        .await?;
+    assert_eq!(transfer.status(), TransferStatus::Completed);
+
+    let balance = deposit
+        .account_currency_balance(&DummySubject, to.id)
+        .await?;
+    assert_eq!(
+        balance.settled,
+        Money::try_from_major(CurrencyCode::EUR, dec!(50))?
+    );
+    let balance = deposit.account_balance(&DummySubject, to.id).await?;
+    assert_eq!(balance.settled, UsdCents::ZERO);
 
     Ok(())
 }
@@ -2,14 +2,26 @@
 
 use std::collections::HashMap;
 
-use cala_ledger::{CalaLedger, JournalId, account_set::AccountSetMemberId};
+use cala_ledger::{CalaLedger, CalaLedgerConfig, JournalId, account_set::AccountSetMemberId};
+use cloud_storage::{Storage, config::StorageConfig};
 use core_accounting::{AccountCode, AccountingBaseConfig, CalaAccountSetId, Chart, CoreAccounting};
-use core_customer::AllowManualConversion;
-use core_deposit::{DepositOmnibusAccountSetSpec, DepositSummaryAccountSetSpec};
+use core_customer::{AllowManualConversion, Customers};
+use core_deposit::{
+    Beneficiary, BeneficiaryDestination, CoreDeposit, CryptoAddressDetails, CryptoNetwork,
+    DepositAccountHolderId, DepositBeneficiaryCoolingOffHours, DepositOmnibusAccountSetSpec,
+    DepositSummaryAccountSetSpec,
+};
+use document_storage::DocumentStorage;
 use domain_config::{
     EncryptionConfig, ExposedDomainConfigs, ExposedDomainConfigsReadOnly, InternalDomainConfigs,
 };
+use es_entity::clock::ClockHandle;
 use rand::RngExt;
+
+pub type TestPerms = authz::dummy::DummyPerms<action::DummyAction, object::DummyObject>;
+pub type TestDeposit = CoreDeposit<TestPerms, event::DummyEvent>;
+pub type TestCustomers = Customers<TestPerms, event::DummyEvent>;
+
 pub async fn init_pool() -> anyhow::Result<sqlx::PgPool> {
     let pg_con = std::env::var("PG_CON").unwrap();
     let pool = sqlx::PgPool::connect(&pg_con).await?;
@@ -32,9 +44,105 @@ pub async fn init_domain_configs(
     let _ = exposed
         .update::<AllowManualConversion>(&authz::dummy::DummySubject, true)
         .await;
+    // Let beneficiaries be used straight away - all tests want the same value (0)
+    let _ = exposed
+        .update::<DepositBeneficiaryCoolingOffHours>(&authz::dummy::DummySubject, 0)
+        .await;
     Ok((internal, exposed, exposed_readonly))
 }
 
+/// Wires deposits and customers up with their own outbox, ledger and job service.
+/// The jobs handle is returned so that it outlives the test body.
+pub async fn init_deposit(
+    pool: &sqlx::PgPool,
+    clock: ClockHandle,
+) -> anyhow::Result<(TestDeposit, TestCustomers, job::Jobs)> {
+    let outbox = obix::Outbox::<event::DummyEvent>::init(
+        pool,
+        obix::MailboxConfig::builder()
+            .clock(clock.clone())
+            .build()?,
+    )
+    .await?;
+    let authz = TestPerms::new();
+    let governance = governance::Governance::new(pool, &authz, &outbox, clock.clone(), None);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .clock(clock.clone())
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .clock(clock.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(pool, &storage, clock.clone());
+    let journal_id = init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(pool);
+
+    let (internal_domain_configs, _writable_domain_configs, exposed_domain_configs) =
+        init_domain_configs(pool, &authz).await?;
+
+    let customers = Customers::new(
+        pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        &exposed_domain_configs,
+        clock.clone(),
+    );
+
+    let deposit = CoreDeposit::init(
+        pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+        &document_storage,
+        gotenberg::GotenbergConfig::default(),
+    )
+    .await?;
+
+    Ok((deposit, customers, jobs))
+}
+
+pub async fn create_verified_beneficiary(
+    deposit: &CoreDeposit<
+        authz::dummy::DummyPerms<action::DummyAction, object::DummyObject>,
+        event::DummyEvent,
+    >,
+    account_holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+) -> anyhow::Result<Beneficiary> {
+    let beneficiary = deposit
+        .create_beneficiary(
+            &authz::dummy::DummySubject,
+            account_holder_id,
+            "Test wallet".to_string(),
+            BeneficiaryDestination::CryptoAddress(CryptoAddressDetails {
+                network: CryptoNetwork::Ethereum,
+                address: "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+            }),
+        )
+        .await?;
+    Ok(deposit
+        .verify_beneficiary(&authz::dummy::DummySubject, beneficiary.id)
+        .await?)
+}
+
 pub async fn init_journal(cala: &CalaLedger) -> anyhow::Result<cala_ledger::JournalId> {
     use cala_ledger::journal::*;
 
@@ -186,10 +294,11 @@ pub async fn create_test_statements<Perms, E>(
 where
     Perms: authz::PermissionCheck,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Action:
-        From<core_accounting::CoreAccountingAction>,
+        From<core_accounting::CoreAccountingAction> + From<governance::GovernanceAction>,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Object:
-        From<core_accounting::CoreAccountingObject>,
-    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>,
+        From<core_accounting::CoreAccountingObject> + From<governance::GovernanceObject>,
+    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>
+        + obix::out::OutboxEventMarker<governance::GovernanceEvent>,
 {
     let bs = format!("BS-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let pl = format!("PL-{:010}", rand::rng().random_range(0..10_000_000_000u64));
@@ -326,6 +435,8 @@ pub mod event {
     use core_accounting::CoreAccountingEvent;
     use core_customer::CoreCustomerEvent;
     use core_deposit::CoreDepositEvent;
+    use core_price::CorePriceEvent;
+    use core_time_events::CoreTimeEvent;
     use governance::GovernanceEvent;
 
     #[derive(Debug, Serialize, Deserialize, obix::OutboxEvent)]
@@ -335,6 +446,8 @@ pub mod event {
         CoreCustomer(CoreCustomerEvent),
         CoreAccounting(CoreAccountingEvent),
         Governance(GovernanceEvent),
+        CoreTimeEvent(CoreTimeEvent),
+        CorePrice(CorePriceEvent),
         #[serde(other)]
         Unknown,
     }
@@ -4,77 +4,16 @@ use rust_decimal_macros::dec;
 use uuid::Uuid;
 
 use authz::dummy::DummySubject;
-use cala_ledger::{CalaLedger, CalaLedgerConfig};
-use cloud_storage::{Storage, config::StorageConfig};
-use core_customer::{CustomerType, Customers};
+use core_customer::CustomerType;
 use core_deposit::*;
-use document_storage::DocumentStorage;
 use es_entity::clock::ClockHandle;
 
-use helpers::{action, event, object};
-
 #[tokio::test]
 async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     let pool = helpers::init_pool().await?;
     let (clock, _) = ClockHandle::manual();
 
-    let outbox = obix::Outbox::<event::DummyEvent>::init(
-        &pool,
-        obix::MailboxConfig::builder()
-            .clock(clock.clone())
-            .build()?,
-    )
-    .await?;
-    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
-    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone(), None);
-
-    let cala_config = CalaLedgerConfig::builder()
-        .pool(pool.clone())
-        .exec_migrations(false)
-        .clock(clock.clone())
-        .build()?;
-    let cala = CalaLedger::init(cala_config).await?;
-    let mut jobs = job::Jobs::init(
-        job::JobSvcConfig::builder()
-            .pool(pool.clone())
-            .clock(clock.clone())
-            .build()
-            .unwrap(),
-    )
-    .await?;
-
-    let storage = Storage::new(&StorageConfig::default());
-    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
-    let journal_id = helpers::init_journal(&cala).await?;
-    let public_ids = public_id::PublicIds::new(&pool);
-
-    let (internal_domain_configs, _writable_domain_configs, exposed_domain_configs) =
-        helpers::init_domain_configs(&pool, &authz).await?;
-
-    let customers = Customers::new(
-        &pool,
-        &authz,
-        &outbox,
-        document_storage.clone(),
-        public_ids.clone(),
-        &exposed_domain_configs,
-        clock.clone(),
-    );
-
-    let deposit = CoreDeposit::init(
-        &pool,
-        &authz,
-        &outbox,
-        &governance,
-        &mut jobs,
-        &cala,
-        journal_id,
-        &public_ids,
-        &customers,
-        &exposed_domain_configs,
-        &internal_domain_configs,
-    )
-    .await?;
+    let (deposit, customers, _jobs) = helpers::init_deposit(&pool, clock).await?;
 
     let customer = customers
         .create_customer_bypassing_kyc(
@@ -86,6 +25,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         .await?;
 
     let account = deposit.create_account(&DummySubject, customer.id).await?;
+    let beneficiary = helpers::create_verified_beneficiary(&deposit, customer.id).await?;
 
     let deposit_amount = UsdCents::try_from_usd(dec!(1000000)).unwrap();
 
@@ -96,17 +36,29 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     // overdraw
     let withdrawal_amount = UsdCents::try_from_usd(dec!(5000000)).unwrap();
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            beneficiary.id,
+            withdrawal_amount,
+            None,
+        )
         .await;
     assert!(matches!(
         withdrawal,
-        Err(core_deposit::error::CoreDepositError::DepositLedgerError(_))
+        Err(core_deposit::error::CoreDepositError::InsufficientAvailableBalance)
     ));
 
     let withdrawal_amount = UsdCents::try_from_usd(dec!(500000)).unwrap();
 
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            beneficiary.id,
+            withdrawal_amount,
+            None,
+        )
         .await?;
 
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
@@ -121,3 +73,82 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn cancelled_withdrawal_frees_withdrawal_limit() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::manual();
+
+    let (deposit, customers, _jobs) = helpers::init_deposit(&pool, clock).await?;
+
+    let limits = WithdrawalLimits {
+        daily: Some(UsdCents::try_from_usd(dec!(1000)).unwrap()),
+        weekly: None,
+        monthly: None,
+    };
+    deposit
+        .update_withdrawal_limits(
+            &DummySubject,
+            Some(CustomerType::NonDomiciledCompany),
+            limits,
+        )
+        .await?;
+
+    let customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::NonDomiciledCompany,
+        )
+        .await?;
+
+    let account = deposit.create_account(&DummySubject, customer.id).await?;
+    let beneficiary = helpers::create_verified_beneficiary(&deposit, customer.id).await?;
+
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(5000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let withdrawal = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            beneficiary.id,
+            UsdCents::try_from_usd(dec!(800)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let over_limit = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            beneficiary.id,
+            UsdCents::try_from_usd(dec!(300)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(over_limit.is_err_and(|e| e.is_withdrawal_limit_exceeded()));
+
+    deposit
+        .cancel_withdrawal(&DummySubject, withdrawal.id)
+        .await?;
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            beneficiary.id,
+            UsdCents::try_from_usd(dec!(300)).unwrap(),
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
@@ -22,6 +22,7 @@ fn extension_for_content_type(content_type: &str) -> &'static str {
     match content_type {
         "application/pdf" => ".pdf",
         "text/csv" => ".csv",
+        "application/xml" => ".xml",
         _ => "",
     }
 }
@@ -60,6 +60,11 @@ impl Price {
         }
     }
 
+    /// Latest price received, without waiting for one to arrive.
+    pub fn maybe_usd_cents_per_btc(&self) -> Option<PriceOfOneBTC> {
+        *self.receiver.borrow()
+    }
+
     fn spawn_price_listener<E>(
         tx: watch::Sender<Option<PriceOfOneBTC>>,
         outbox: Outbox<E>,
@@ -26,6 +26,12 @@ pub enum ReportError {
     NotFound,
 }
 
+impl From<crate::report::ReportCreateError> for ReportError {
+    fn from(e: crate::report::ReportCreateError) -> Self {
+        ReportError::ReportError(crate::report::error::ReportError::from(e))
+    }
+}
+
 impl From<crate::report::ReportFindError> for ReportError {
     fn from(e: crate::report::ReportFindError) -> Self {
         ReportError::ReportError(crate::report::error::ReportError::from(e))
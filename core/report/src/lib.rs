@@ -301,6 +301,72 @@ where
         Ok(download_link)
     }
 
+    /// Stores a report generated in-house as a completed manual run, so it is listed and
+    /// downloaded like the reports produced by Dagster.
+    #[record_error_severity]
+    #[tracing::instrument(name = "report.record_generated_report", skip(self, report), fields(subject = %sub, report_definition_id = %report.report_definition_id))]
+    pub async fn record_generated_report(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        report: GeneratedReport,
+    ) -> Result<ReportRun, ReportError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                ReportObject::all_reports(),
+                CoreReportAction::REPORT_GENERATE,
+            )
+            .await?;
+
+        let report_run_id = ReportRunId::new();
+        let external_id = format!("lana-{report_run_id}");
+        let path_in_bucket = format!(
+            "reports/{}/{}/{}.{}",
+            report.norm,
+            report_run_id,
+            report.name,
+            report.format.as_str()
+        );
+        self.report_file_storage
+            .upload(
+                report.content,
+                &path_in_bucket,
+                report.format.content_type(),
+            )
+            .await?;
+
+        let new_run = NewReportRun::builder()
+            .id(report_run_id)
+            .external_id(external_id.clone())
+            .state(ReportRunState::Success)
+            .requested_report(Some(RequestedReport {
+                report_definition_id: report.report_definition_id,
+                norm: report.norm.clone(),
+                name: report.name.clone(),
+            }))
+            .requested_as_of_date(report.as_of_date)
+            .build()
+            .expect("all required fields set");
+        let new_report = NewReport::builder()
+            .external_id(format!("{}_{}_{}", external_id, report.norm, report.name))
+            .run_id(report_run_id)
+            .name(report.name)
+            .norm(report.norm)
+            .files(vec![ReportFile {
+                extension: report.format.as_str().to_string(),
+                path_in_bucket,
+            }])
+            .build()
+            .expect("all required fields set");
+
+        let mut db = self.report_runs.begin_op().await?;
+        let report_run = self.report_runs.create_in_op(&mut db, new_run).await?;
+        self.reports.create_in_op(&mut db, new_report).await?;
+        db.commit().await?;
+
+        Ok(report_run)
+    }
+
     #[record_error_severity]
     #[tracing::instrument(name = "report.reports_sync", skip(self), fields(job_id = tracing::field::Empty))]
     pub async fn reports_sync(
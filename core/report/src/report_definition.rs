@@ -64,6 +64,14 @@ impl ReportOutputFormat {
             Self::Xml => "xml",
         }
     }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Txt => "text/plain",
+            Self::Xml => "text/xml",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -118,6 +126,17 @@ impl ReportDefinition {
     }
 }
 
+/// A report file produced within Lana itself rather than by a Dagster run.
+#[derive(Debug, Clone)]
+pub struct GeneratedReport {
+    pub report_definition_id: ReportDefinitionId,
+    pub norm: String,
+    pub name: String,
+    pub as_of_date: Option<NaiveDate>,
+    pub format: ReportOutputFormat,
+    pub content: Vec<u8>,
+}
+
 #[derive(Debug, Deserialize)]
 struct ReportsFile {
     report_jobs: Vec<RawReportDefinition>,
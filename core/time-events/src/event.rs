@@ -14,4 +14,10 @@ pub enum CoreTimeEvent {
         #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
         timezone: chrono_tz::Tz,
     },
+    EndOfMonth {
+        month_end: NaiveDate,
+        closing_time: DateTime<Utc>,
+        #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+        timezone: chrono_tz::Tz,
+    },
 }
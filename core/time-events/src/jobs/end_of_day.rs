@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use job::{
     CurrentJob, Job, JobCompletion, JobInitializer, JobRunner, JobSpawner, JobType, RetrySettings,
 };
@@ -137,6 +137,18 @@ where
                             },
                         )
                         .await?;
+                    if is_last_day_of_month(day) {
+                        self.outbox
+                            .publish_persisted_in_op(
+                                &mut op,
+                                CoreTimeEvent::EndOfMonth {
+                                    month_end: day,
+                                    closing_time: closing_dt,
+                                    timezone,
+                                },
+                            )
+                            .await?;
+                    }
                     state.last_published_day = Some(day);
                     current_job
                         .update_execution_state_in_op(&mut op, &state)
@@ -166,3 +178,22 @@ where
         Ok(JobCompletion::RescheduleAt(schedule.next_closing()))
     }
 }
+
+fn is_last_day_of_month(day: NaiveDate) -> bool {
+    day.succ_opt()
+        .is_none_or(|next_day| next_day.month() != day.month())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_last_day_of_month() {
+        let day = |s: &str| s.parse::<NaiveDate>().unwrap();
+        assert!(is_last_day_of_month(day("2024-02-29")));
+        assert!(is_last_day_of_month(day("2025-12-31")));
+        assert!(!is_last_day_of_month(day("2025-02-27")));
+        assert!(!is_last_day_of_month(day("2025-01-01")));
+    }
+}
@@ -3,19 +3,26 @@ mod migration;
 
 use colored::*;
 
+use aml::event_schema::AmlAlertEvent;
 use core_access::event_schema::{PermissionSetEvent, RoleEvent, UserEvent};
 use core_accounting::event_schema::{
     ChartEvent, ChartNodeEvent, FiscalYearEvent, ManualTransactionEvent,
+    ManualTransactionScheduleEvent,
 };
 use core_credit::event_schema::{
-    CollateralEvent, CreditFacilityEvent, CreditFacilityProposalEvent, DisbursalEvent,
-    InterestAccrualCycleEvent, LiquidationEvent, ObligationEvent, PaymentAllocationEvent,
-    PaymentEvent, PendingCreditFacilityEvent,
+    CollateralEvent, CreditFacilityEvent, CreditFacilityProposalEvent,
+    CreditFacilityStatementEvent, DisbursalEvent, InterestAccrualCycleEvent, LiquidationEvent,
+    ObligationEvent, ParticipationEvent, PaymentAllocationEvent, PaymentEvent,
+    PendingCreditFacilityEvent,
 };
 use core_credit_terms::TermsTemplateEvent;
 use core_custody::event_schema::{CustodianEvent, WalletEvent};
 use core_customer::event_schema::{CustomerEvent, PartyEvent, ProspectEvent};
-use core_deposit::event_schema::{DepositAccountEvent, DepositEvent, WithdrawalEvent};
+use core_deposit::event_schema::{
+    AccountClosureEvent, BeneficiaryEvent, DepositAccountEvent, DepositAccountStatementEvent,
+    DepositEvent, DepositImportEvent, DepositProductEvent, FxConversionEvent, HoldEvent,
+    PaymentFileEvent, TransferEvent, WithdrawalEvent,
+};
 use core_report::event_schema::{ReportEvent, ReportRunEvent};
 use document_storage::event_schema::DocumentEvent;
 use domain_config::event_schema::DomainConfigEvent;
@@ -464,6 +471,7 @@ pub fn update_schemas(
         SchemaInfo {
             name: "ManualTransactionEvent",
             filename: "manual_transaction_event_schema.json",
+            toggle_events: vec!["ApprovalProcessConcluded"],
             generate_schema: || serde_json::to_value(schema_for!(ManualTransactionEvent)).unwrap(),
             ..Default::default()
         },
@@ -497,6 +505,119 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(ReportRunEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "ParticipationEvent",
+            filename: "participation_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec![
+                    "DisbursalFunded".to_string(),
+                    "ReceiptAllocated".to_string(),
+                ],
+                remove_events: vec![],
+            }],
+            generate_schema: || serde_json::to_value(schema_for!(ParticipationEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "CreditFacilityStatementEvent",
+            filename: "credit_facility_statement_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(CreditFacilityStatementEvent)).unwrap()
+            },
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "TransferEvent",
+            filename: "transfer_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec![
+                    "Completed".to_string(),
+                    "FundsHeld".to_string(),
+                    "FundsReleased".to_string(),
+                ],
+                remove_events: vec![],
+            }],
+            generate_schema: || serde_json::to_value(schema_for!(TransferEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "HoldEvent",
+            filename: "hold_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec![
+                    "Initialized".to_string(),
+                    "Released".to_string(),
+                    "Expired".to_string(),
+                ],
+                remove_events: vec![],
+            }],
+            generate_schema: || serde_json::to_value(schema_for!(HoldEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "DepositProductEvent",
+            filename: "deposit_product_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(DepositProductEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "BeneficiaryEvent",
+            filename: "beneficiary_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(BeneficiaryEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "PaymentFileEvent",
+            filename: "payment_file_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(PaymentFileEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "FxConversionEvent",
+            filename: "fx_conversion_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(FxConversionEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "DepositImportEvent",
+            filename: "deposit_import_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(DepositImportEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "AccountClosureEvent",
+            filename: "account_closure_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(AccountClosureEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "DepositAccountStatementEvent",
+            filename: "deposit_account_statement_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(DepositAccountStatementEvent)).unwrap()
+            },
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "AmlAlertEvent",
+            filename: "aml_alert_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(AmlAlertEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "ManualTransactionScheduleEvent",
+            filename: "manual_transaction_schedule_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(ManualTransactionScheduleEvent)).unwrap()
+            },
+            ..Default::default()
+        },
     ];
 
     // First, detect which schemas have changed
@@ -0,0 +1,180 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    beneficiary::Beneficiary, deposit_account::DepositAccount, loader::LanaDataLoader,
+    transfer::Transfer, withdrawal::Withdrawal,
+};
+
+pub use lana_app::deposit::{
+    AccountClosure as DomainAccountClosure, AccountClosureHoldTreatment,
+    AccountClosurePayout as DomainAccountClosurePayout,
+    AccountClosurePayoutTransaction as DomainAccountClosurePayoutTransaction, AccountClosureStatus,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("accountClosureId".to_string())
+)]
+pub struct AccountClosure {
+    account_closure_id: UUID,
+    deposit_account_id: UUID,
+    hold_treatment: AccountClosureHoldTreatment,
+    payout_amount: UsdCents,
+    status: AccountClosureStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainAccountClosure>,
+}
+
+impl From<DomainAccountClosure> for AccountClosure {
+    fn from(closure: DomainAccountClosure) -> Self {
+        AccountClosure {
+            account_closure_id: UUID::from(closure.id),
+            deposit_account_id: UUID::from(closure.deposit_account_id),
+            hold_treatment: closure.hold_treatment,
+            payout_amount: closure.payout_amount,
+            status: closure.status(),
+            created_at: closure.created_at().into(),
+            entity: Arc::new(closure),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AccountClosure {
+    async fn closed_at(&self) -> Option<Timestamp> {
+        self.entity.closed_at().map(Into::into)
+    }
+
+    async fn failure_reason(&self) -> Option<&str> {
+        self.entity.failure_reason()
+    }
+
+    async fn released_hold_ids(&self) -> Vec<UUID> {
+        self.entity
+            .released_hold_ids
+            .iter()
+            .map(|id| UUID::from(*id))
+            .collect()
+    }
+
+    async fn deposit_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit account not found"))?;
+        Ok(account)
+    }
+
+    async fn payout_deposit_account(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<DepositAccount>> {
+        let DomainAccountClosurePayout::DepositAccount { deposit_account_id } = self.entity.payout
+        else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_account_id).await?)
+    }
+
+    async fn payout_beneficiary(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<Beneficiary>> {
+        let DomainAccountClosurePayout::Beneficiary { beneficiary_id } = self.entity.payout else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(beneficiary_id).await?)
+    }
+
+    async fn payout_withdrawal(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<Withdrawal>> {
+        let Some(DomainAccountClosurePayoutTransaction::Withdrawal { withdrawal_id }) =
+            self.entity.payout_transaction
+        else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(withdrawal_id).await?)
+    }
+
+    async fn payout_transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Transfer>> {
+        let Some(DomainAccountClosurePayoutTransaction::Transfer { transfer_id }) =
+            self.entity.payout_transaction
+        else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(transfer_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct AccountClosureDepositAccountPayoutInput {
+    pub deposit_account_id: UUID,
+}
+
+#[derive(InputObject)]
+pub struct AccountClosureBeneficiaryPayoutInput {
+    pub beneficiary_id: UUID,
+}
+
+#[derive(OneofObject)]
+pub enum AccountClosurePayoutInput {
+    DepositAccount(AccountClosureDepositAccountPayoutInput),
+    Beneficiary(AccountClosureBeneficiaryPayoutInput),
+}
+
+impl From<AccountClosurePayoutInput> for DomainAccountClosurePayout {
+    fn from(input: AccountClosurePayoutInput) -> Self {
+        match input {
+            AccountClosurePayoutInput::DepositAccount(payout) => {
+                DomainAccountClosurePayout::DepositAccount {
+                    deposit_account_id: payout.deposit_account_id.into(),
+                }
+            }
+            AccountClosurePayoutInput::Beneficiary(payout) => {
+                DomainAccountClosurePayout::Beneficiary {
+                    beneficiary_id: payout.beneficiary_id.into(),
+                }
+            }
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountClosureInitiateInput {
+    pub deposit_account_id: UUID,
+    pub payout: AccountClosurePayoutInput,
+    pub hold_treatment: AccountClosureHoldTreatment,
+}
+crate::mutation_payload! { DepositAccountClosureInitiatePayload, account_closure: AccountClosure }
+
+#[derive(InputObject)]
+pub struct AccountClosingStatementDownloadLinkGenerateInput {
+    pub account_closure_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct AccountClosingStatementDownloadLinkGeneratePayload {
+    link: Option<String>,
+}
+
+impl From<Option<lana_app::document::GeneratedDocumentDownloadLink>>
+    for AccountClosingStatementDownloadLinkGeneratePayload
+{
+    fn from(value: Option<lana_app::document::GeneratedDocumentDownloadLink>) -> Self {
+        Self {
+            link: value.map(|value| value.link),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use async_graphql::*;
+
+use lana_app::cash_flow::{
+    CashFlowSection, CashFlowStatement as DomainCashFlowStatement,
+    CashFlowStatementLine as DomainCashFlowStatementLine,
+};
+
+use crate::primitives::*;
+
+use super::{
+    AccountCode, BtcLedgerAccountBalanceRange, ChartOfAccounts,
+    LedgerAccountBalanceRangeByCurrency, LedgerAccountCsvDocument, UsdLedgerAccountBalanceRange,
+};
+
+#[derive(Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct CashFlowStatement {
+    pub name: String,
+    #[graphql(skip)]
+    pub entity: Arc<DomainCashFlowStatement>,
+}
+
+impl From<DomainCashFlowStatement> for CashFlowStatement {
+    fn from(cash_flow: DomainCashFlowStatement) -> Self {
+        Self {
+            name: cash_flow.name.to_string(),
+            entity: Arc::new(cash_flow),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CashFlowStatement {
+    async fn total(&self) -> LedgerAccountBalanceRangeByCurrency {
+        LedgerAccountBalanceRangeByCurrency {
+            usd: self
+                .entity
+                .usd_balance_range
+                .as_ref()
+                .map(UsdLedgerAccountBalanceRange::from)
+                .unwrap_or_default(),
+            btc: self
+                .entity
+                .btc_balance_range
+                .as_ref()
+                .map(BtcLedgerAccountBalanceRange::from)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// First line of the operating activities, which include it.
+    async fn net_income(&self) -> CashFlowStatementLine {
+        CashFlowStatementLine::from(self.entity.net_income.clone())
+    }
+
+    async fn operating_activities(&self) -> CashFlowStatementLine {
+        CashFlowStatementLine::from(self.entity.operating_activities.clone())
+    }
+
+    async fn investing_activities(&self) -> CashFlowStatementLine {
+        CashFlowStatementLine::from(self.entity.investing_activities.clone())
+    }
+
+    async fn financing_activities(&self) -> CashFlowStatementLine {
+        CashFlowStatementLine::from(self.entity.financing_activities.clone())
+    }
+
+    /// Opening and closing cash, reconciled by the net change in cash in `total`.
+    async fn cash(&self) -> CashFlowStatementLine {
+        CashFlowStatementLine::from(self.entity.cash.clone())
+    }
+}
+
+#[derive(Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct CashFlowStatementLine {
+    ledger_account_id: UUID,
+    name: String,
+
+    #[graphql(skip)]
+    entity: Arc<DomainCashFlowStatementLine>,
+}
+
+impl From<DomainCashFlowStatementLine> for CashFlowStatementLine {
+    fn from(line: DomainCashFlowStatementLine) -> Self {
+        Self {
+            ledger_account_id: UUID::from(line.id),
+            name: line.name.clone(),
+            entity: Arc::new(line),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CashFlowStatementLine {
+    async fn balance_range(&self) -> LedgerAccountBalanceRangeByCurrency {
+        LedgerAccountBalanceRangeByCurrency {
+            usd: self
+                .entity
+                .usd_balance_range
+                .as_ref()
+                .map(UsdLedgerAccountBalanceRange::from)
+                .unwrap_or_default(),
+            btc: self
+                .entity
+                .btc_balance_range
+                .as_ref()
+                .map(BtcLedgerAccountBalanceRange::from)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CashFlowSectionChartNodeAddInput {
+    pub code: AccountCode,
+    pub section: CashFlowSection,
+}
+crate::mutation_payload! { CashFlowSectionChartNodeAddPayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(InputObject)]
+pub struct CashFlowSectionChartNodeRemoveInput {
+    pub code: AccountCode,
+    pub section: CashFlowSection,
+}
+crate::mutation_payload! { CashFlowSectionChartNodeRemovePayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(InputObject)]
+pub struct CashFlowStatementCsvCreateInput {
+    pub from: Date,
+    pub until: Date,
+}
+crate::mutation_payload! { CashFlowStatementCsvCreatePayload, cash_flow_statement_csv_document: LedgerAccountCsvDocument }
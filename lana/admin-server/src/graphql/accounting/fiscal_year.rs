@@ -83,6 +83,7 @@ impl FiscalYear {
 pub struct FiscalMonthClosure {
     closed_as_of: Date,
     closed_at: Timestamp,
+    btc_revaluation_skipped: bool,
 }
 
 impl From<DomainFiscalMonthClosure> for FiscalMonthClosure {
@@ -90,6 +91,7 @@ impl From<DomainFiscalMonthClosure> for FiscalMonthClosure {
         FiscalMonthClosure {
             closed_as_of: closure.closed_as_of.into(),
             closed_at: closure.closed_at.into(),
+            btc_revaluation_skipped: closure.btc_revaluation_skipped,
         }
     }
 }
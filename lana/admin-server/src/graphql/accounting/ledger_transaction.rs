@@ -4,7 +4,10 @@ use lana_app::primitives::Subject;
 pub use lana_app::{
     accounting::ledger_transaction::LedgerTransactionCursor,
     credit::DISBURSAL_TRANSACTION_ENTITY_TYPE,
-    deposit::{DEPOSIT_TRANSACTION_ENTITY_TYPE, WITHDRAWAL_TRANSACTION_ENTITY_TYPE},
+    deposit::{
+        DEPOSIT_TRANSACTION_ENTITY_TYPE, FX_CONVERSION_TRANSACTION_ENTITY_TYPE,
+        TRANSFER_TRANSACTION_ENTITY_TYPE, WITHDRAWAL_TRANSACTION_ENTITY_TYPE,
+    },
 };
 
 pub type DomainLedgerTransaction =
@@ -13,7 +16,7 @@ pub type DomainLedgerTransaction =
 use crate::{
     graphql::{
         access::User, audit::System, credit_facility::CreditFacilityDisbursal, deposit::Deposit,
-        loader::*, withdrawal::Withdrawal,
+        fx_conversion::FxConversion, loader::*, transfer::Transfer, withdrawal::Withdrawal,
     },
     primitives::*,
 };
@@ -36,6 +39,8 @@ pub struct LedgerTransaction {
 pub enum LedgerTransactionEntity {
     Deposit(Deposit),
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
+    FxConversion(FxConversion),
     Disbursal(CreditFacilityDisbursal),
 }
 
@@ -74,6 +79,20 @@ impl LedgerTransaction {
                     .ok_or_else(|| Error::new("Withdrawal not found"))?;
                 Some(LedgerTransactionEntity::Withdrawal(withdrawal))
             }
+            entity_type if entity_type == &TRANSFER_TRANSACTION_ENTITY_TYPE => {
+                let transfer = loader
+                    .load_one(TransferId::from(entity_ref.entity_id))
+                    .await?
+                    .ok_or_else(|| Error::new("Transfer not found"))?;
+                Some(LedgerTransactionEntity::Transfer(transfer))
+            }
+            entity_type if entity_type == &FX_CONVERSION_TRANSACTION_ENTITY_TYPE => {
+                let fx_conversion = loader
+                    .load_one(FxConversionId::from(entity_ref.entity_id))
+                    .await?
+                    .ok_or_else(|| Error::new("FX conversion not found"))?;
+                Some(LedgerTransactionEntity::FxConversion(fx_conversion))
+            }
             entity_type if entity_type == &DISBURSAL_TRANSACTION_ENTITY_TYPE => {
                 let disbursal = loader
                     .load_one(DisbursalId::from(entity_ref.entity_id))
@@ -107,6 +126,38 @@ impl LedgerTransaction {
         }
     }
 
+    async fn reversal_of(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let links = app
+            .accounting()
+            .find_manual_transaction_reversal_links(sub, self.entity.id)
+            .await?;
+        let Some(id) = links.reversal_of else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+
+    async fn reversed_by(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let links = app
+            .accounting()
+            .find_manual_transaction_reversal_links(sub, self.entity.id)
+            .await?;
+        let Some(id) = links.reversed_by else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+
     async fn entries(&self) -> Vec<LedgerEntry> {
         self.entity
             .entries
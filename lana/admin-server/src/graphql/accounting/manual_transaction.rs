@@ -1,14 +1,131 @@
 use async_graphql::*;
 
-pub use lana_app::accounting::manual_transaction::ManualEntryInput;
+pub use lana_app::accounting::{
+    ManualTransaction as DomainManualTransaction, ManualTransactionStatus,
+    ManualTransactionsByCreatedAtCursor, manual_transaction::ManualEntryInput,
+};
 
-use crate::graphql::primitives::*;
-use crate::primitives::Date;
+use crate::graphql::{approval_process::ApprovalProcess, loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
 
 use lana_app::primitives::DebitOrCredit;
 
 use super::ledger_transaction::LedgerTransaction;
 
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("manualTransactionId".to_string())
+)]
+pub struct ManualTransaction {
+    manual_transaction_id: UUID,
+    ledger_transaction_id: UUID,
+    approval_process_id: Option<UUID>,
+    status: ManualTransactionStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainManualTransaction>,
+}
+
+impl From<DomainManualTransaction> for ManualTransaction {
+    fn from(manual_transaction: DomainManualTransaction) -> Self {
+        Self {
+            manual_transaction_id: UUID::from(manual_transaction.id),
+            ledger_transaction_id: UUID::from(manual_transaction.ledger_transaction_id),
+            approval_process_id: manual_transaction.approval_process_id.map(UUID::from),
+            status: manual_transaction.status(),
+            created_at: manual_transaction.created_at().into(),
+            entity: Arc::new(manual_transaction),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransaction {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn description(&self) -> &str {
+        &self.entity.description
+    }
+
+    async fn draft(&self) -> Option<ManualTransactionDraft> {
+        self.entity.draft.as_ref().map(ManualTransactionDraft::from)
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .ok_or_else(|| Error::new("Approval process not found"))?;
+        Ok(Some(process))
+    }
+
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        if !self.entity.is_posted() {
+            return Ok(None);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_transaction_id).await?)
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ManualTransactionDraft {
+    effective: Date,
+    entries: Vec<ManualTransactionDraftEntry>,
+}
+
+impl From<&lana_app::accounting::manual_transaction::ManualTransactionDraft>
+    for ManualTransactionDraft
+{
+    fn from(draft: &lana_app::accounting::manual_transaction::ManualTransactionDraft) -> Self {
+        Self {
+            effective: draft.effective.into(),
+            entries: draft
+                .entries
+                .iter()
+                .map(ManualTransactionDraftEntry::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ManualTransactionDraftEntry {
+    ledger_account_id: UUID,
+    amount: Decimal,
+    currency: String,
+    direction: DebitOrCredit,
+    description: String,
+}
+
+impl From<&lana_app::accounting::manual_transaction::ManualTransactionEntry>
+    for ManualTransactionDraftEntry
+{
+    fn from(entry: &lana_app::accounting::manual_transaction::ManualTransactionEntry) -> Self {
+        Self {
+            ledger_account_id: UUID::from(entry.account_id),
+            amount: entry.amount.into(),
+            currency: entry.currency.to_string(),
+            direction: entry.direction,
+            description: entry.description.clone(),
+        }
+    }
+}
+
 #[derive(InputObject)]
 pub struct LedgerManualTransactionExecuteInput {
     pub description: String,
@@ -16,7 +133,56 @@ pub struct LedgerManualTransactionExecuteInput {
     pub effective: Date,
     pub entries: Vec<ManualTransactionEntryInput>,
 }
-crate::mutation_payload! { LedgerManualTransactionExecutePayload, transaction: LedgerTransaction }
+
+#[derive(SimpleObject)]
+pub struct LedgerManualTransactionExecutePayload {
+    manual_transaction: ManualTransaction,
+    transaction: Option<LedgerTransaction>,
+}
+
+impl LedgerManualTransactionExecutePayload {
+    pub fn new(
+        manual_transaction: ManualTransaction,
+        transaction: Option<LedgerTransaction>,
+    ) -> Self {
+        Self {
+            manual_transaction,
+            transaction,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct LedgerManualTransactionDraftUpdateInput {
+    pub manual_transaction_id: UUID,
+    pub description: String,
+    pub effective: Date,
+    pub entries: Vec<ManualTransactionEntryInput>,
+}
+crate::mutation_payload! { LedgerManualTransactionDraftUpdatePayload, manual_transaction: ManualTransaction }
+
+#[derive(InputObject)]
+pub struct LedgerManualTransactionReverseInput {
+    pub ledger_transaction_id: UUID,
+    pub effective: Option<Date>,
+}
+#[derive(SimpleObject)]
+pub struct LedgerManualTransactionReversePayload {
+    manual_transaction: ManualTransaction,
+    transaction: Option<LedgerTransaction>,
+}
+
+impl LedgerManualTransactionReversePayload {
+    pub fn new(
+        manual_transaction: ManualTransaction,
+        transaction: Option<LedgerTransaction>,
+    ) -> Self {
+        Self {
+            manual_transaction,
+            transaction,
+        }
+    }
+}
 
 #[derive(InputObject)]
 pub struct ManualTransactionEntryInput {
@@ -0,0 +1,138 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::{
+    ManualTransactionSchedule as DomainManualTransactionSchedule,
+    ManualTransactionScheduleExecution as DomainManualTransactionScheduleExecution,
+    ManualTransactionScheduleFrequency, ManualTransactionScheduleStatus,
+    ManualTransactionSchedulesByCreatedAtCursor,
+};
+
+use crate::graphql::loader::LanaDataLoader;
+use crate::primitives::*;
+
+use super::manual_transaction::{
+    ManualTransaction, ManualTransactionDraftEntry, ManualTransactionEntryInput,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("manualTransactionScheduleId".to_string())
+)]
+pub struct ManualTransactionSchedule {
+    manual_transaction_schedule_id: UUID,
+    frequency: ManualTransactionScheduleFrequency,
+    start_date: Date,
+    end_date: Option<Date>,
+    auto_reverse: bool,
+    status: ManualTransactionScheduleStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainManualTransactionSchedule>,
+}
+
+impl From<DomainManualTransactionSchedule> for ManualTransactionSchedule {
+    fn from(schedule: DomainManualTransactionSchedule) -> Self {
+        Self {
+            manual_transaction_schedule_id: UUID::from(schedule.id),
+            frequency: schedule.frequency,
+            start_date: schedule.start_date.into(),
+            end_date: schedule.end_date.map(Into::into),
+            auto_reverse: schedule.auto_reverse,
+            status: schedule.status(),
+            created_at: schedule.created_at().into(),
+            entity: Arc::new(schedule),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransactionSchedule {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn description(&self) -> &str {
+        &self.entity.description
+    }
+
+    async fn entries(&self) -> Vec<ManualTransactionDraftEntry> {
+        self.entity
+            .entries
+            .iter()
+            .map(ManualTransactionDraftEntry::from)
+            .collect()
+    }
+
+    async fn executions(&self) -> Vec<ManualTransactionScheduleExecution> {
+        self.entity
+            .executions()
+            .into_iter()
+            .map(ManualTransactionScheduleExecution::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct ManualTransactionScheduleExecution {
+    period_end: Date,
+    reverse_on: Option<Date>,
+
+    #[graphql(skip)]
+    entity: DomainManualTransactionScheduleExecution,
+}
+
+impl From<DomainManualTransactionScheduleExecution> for ManualTransactionScheduleExecution {
+    fn from(execution: DomainManualTransactionScheduleExecution) -> Self {
+        Self {
+            period_end: execution.period_end.into(),
+            reverse_on: execution.reverse_on.map(Into::into),
+            entity: execution,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransactionScheduleExecution {
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<ManualTransaction> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        loader
+            .load_one(self.entity.manual_transaction_id)
+            .await?
+            .ok_or_else(|| Error::new("Manual transaction not found"))
+    }
+
+    async fn reversal(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let Some(reversal_id) = self.entity.reversal_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(reversal_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct ManualTransactionScheduleCreateInput {
+    pub reference: Option<String>,
+    pub description: String,
+    pub frequency: ManualTransactionScheduleFrequency,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+    pub auto_reverse: bool,
+    pub entries: Vec<ManualTransactionEntryInput>,
+}
+crate::mutation_payload! { ManualTransactionScheduleCreatePayload, manual_transaction_schedule: ManualTransactionSchedule }
+
+#[derive(InputObject)]
+pub struct ManualTransactionScheduleCancelInput {
+    pub manual_transaction_schedule_id: UUID,
+}
+crate::mutation_payload! { ManualTransactionScheduleCancelPayload, manual_transaction_schedule: ManualTransactionSchedule }
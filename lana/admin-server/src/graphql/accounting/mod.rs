@@ -1,4 +1,5 @@
 mod balance_sheet;
+mod cash_flow;
 mod chart_of_accounts;
 mod csv;
 mod fiscal_year;
@@ -6,11 +7,13 @@ mod ledger_account;
 mod ledger_entry;
 mod ledger_transaction;
 mod manual_transaction;
+mod manual_transaction_schedule;
 mod profit_and_loss;
 mod transaction_templates;
 mod trial_balance;
 
 pub use balance_sheet::*;
+pub use cash_flow::*;
 pub use chart_of_accounts::*;
 pub use csv::*;
 pub use fiscal_year::*;
@@ -18,6 +21,7 @@ pub use ledger_account::*;
 pub use ledger_entry::*;
 pub use ledger_transaction::*;
 pub use manual_transaction::*;
+pub use manual_transaction_schedule::*;
 pub use profit_and_loss::*;
 pub use transaction_templates::*;
 pub use trial_balance::*;
@@ -0,0 +1,147 @@
+use async_graphql::{connection::*, *};
+
+use crate::primitives::*;
+
+use super::{
+    customer::Customer,
+    event_timeline::{self, EventTimelineCursor, EventTimelineEntry},
+    loader::LanaDataLoader,
+    reports::ReportRun,
+};
+
+pub use lana_app::aml::{
+    AmlAlert as DomainAmlAlert, AmlAlertStatus, AmlAlertsByCreatedAtCursor, AmlRule,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("amlAlertId".to_string())
+)]
+pub struct AmlAlert {
+    aml_alert_id: UUID,
+    customer_id: UUID,
+    account_id: UUID,
+    rule: AmlRule,
+    status: AmlAlertStatus,
+    amount: UsdCents,
+    details: String,
+    detected_at: Timestamp,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainAmlAlert>,
+}
+
+impl From<DomainAmlAlert> for AmlAlert {
+    fn from(alert: DomainAmlAlert) -> Self {
+        AmlAlert {
+            aml_alert_id: UUID::from(alert.id),
+            customer_id: UUID::from(alert.customer_id),
+            account_id: UUID::from(alert.account_id),
+            rule: alert.rule,
+            status: alert.status(),
+            amount: alert.amount,
+            details: alert.details.clone(),
+            detected_at: alert.detected_at.into(),
+            created_at: alert.created_at().into(),
+            entity: Arc::new(alert),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AmlAlert {
+    async fn transaction_ids(&self) -> Vec<UUID> {
+        self.entity
+            .transaction_ids
+            .iter()
+            .map(|id| UUID::from(*id))
+            .collect()
+    }
+
+    async fn notes(&self) -> Vec<&str> {
+        self.entity.notes()
+    }
+
+    async fn resolution_reason(&self) -> Option<&str> {
+        self.entity.resolution_reason()
+    }
+
+    async fn customer_frozen(&self) -> bool {
+        self.entity.customer_frozen()
+    }
+
+    async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let customer = loader
+            .load_one(self.entity.customer_id)
+            .await?
+            .ok_or_else(|| Error::new("Customer not found"))?;
+        Ok(customer)
+    }
+
+    async fn event_history(
+        &self,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<EventTimelineCursor, EventTimelineEntry, EmptyFields, EmptyFields>,
+    > {
+        use es_entity::EsEntity as _;
+        event_timeline::events_to_connection(self.entity.events(), first, after)
+    }
+}
+
+#[derive(InputObject)]
+pub struct AmlAlertReviewStartInput {
+    pub aml_alert_id: UUID,
+}
+crate::mutation_payload! { AmlAlertReviewStartPayload, aml_alert: AmlAlert }
+
+#[derive(InputObject)]
+pub struct AmlAlertNoteAddInput {
+    pub aml_alert_id: UUID,
+    pub note: String,
+}
+crate::mutation_payload! { AmlAlertNoteAddPayload, aml_alert: AmlAlert }
+
+#[derive(InputObject)]
+pub struct AmlAlertEscalateInput {
+    pub aml_alert_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { AmlAlertEscalatePayload, aml_alert: AmlAlert }
+
+#[derive(InputObject)]
+pub struct AmlAlertDismissInput {
+    pub aml_alert_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { AmlAlertDismissPayload, aml_alert: AmlAlert }
+
+#[derive(InputObject)]
+pub struct AmlAlertCustomerFreezeInput {
+    pub aml_alert_id: UUID,
+}
+crate::mutation_payload! { AmlAlertCustomerFreezePayload, aml_alert: AmlAlert }
+
+#[derive(InputObject)]
+pub struct AmlAlertSarDraftGenerateInput {
+    pub aml_alert_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct AmlAlertSarDraftGeneratePayload {
+    pub report_run: ReportRun,
+}
+
+#[derive(InputObject)]
+pub struct AmlCurrencyTransactionReportGenerateInput {
+    pub date: Date,
+}
+
+#[derive(SimpleObject)]
+pub struct AmlCurrencyTransactionReportGeneratePayload {
+    pub report_run: ReportRun,
+}
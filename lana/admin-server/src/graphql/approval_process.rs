@@ -3,9 +3,10 @@ use async_graphql::{connection::*, *};
 use crate::primitives::*;
 
 use super::{
-    access::User, approval_rules::*, credit_facility::*, event_timeline,
-    event_timeline::EventTimelineCursor, event_timeline::EventTimelineEntry,
-    loader::LanaDataLoader, policy::*, withdrawal::*,
+    access::User, accounting::ManualTransaction, approval_rules::*, credit_facility::*,
+    deposit_account::DepositAccount, event_timeline, event_timeline::EventTimelineCursor,
+    event_timeline::EventTimelineEntry, loader::LanaDataLoader, policy::*, transfer::*,
+    withdrawal::*,
 };
 
 pub use lana_app::governance::{
@@ -154,6 +155,18 @@ impl ApprovalProcess {
                     .ok_or_else(|| Error::new("Withdrawal not found"))?;
                 Ok(ApprovalProcessTarget::Withdrawal(withdrawal))
             }
+            ApprovalProcessType::TransferApproval => {
+                let id = self
+                    .entity
+                    .target_ref()
+                    .parse::<TransferId>()
+                    .map_err(|_| Error::new("Invalid transfer target ref"))?;
+                let transfer = loader
+                    .load_one(id)
+                    .await?
+                    .ok_or_else(|| Error::new("Transfer not found"))?;
+                Ok(ApprovalProcessTarget::Transfer(transfer))
+            }
             ApprovalProcessType::CreditFacilityProposalApproval => {
                 let id = self
                     .entity
@@ -180,6 +193,30 @@ impl ApprovalProcess {
                     .ok_or_else(|| Error::new("Disbursal not found"))?;
                 Ok(ApprovalProcessTarget::CreditFacilityDisbursal(disbursal))
             }
+            ApprovalProcessType::WithdrawalLimitIncreaseApproval => {
+                let id = self
+                    .entity
+                    .target_ref()
+                    .parse::<DepositAccountId>()
+                    .map_err(|_| Error::new("Invalid deposit account target ref"))?;
+                let deposit_account = loader
+                    .load_one(id)
+                    .await?
+                    .ok_or_else(|| Error::new("Deposit account not found"))?;
+                Ok(ApprovalProcessTarget::DepositAccount(deposit_account))
+            }
+            ApprovalProcessType::ManualTransactionApproval => {
+                let id = self
+                    .entity
+                    .target_ref()
+                    .parse::<ManualTransactionId>()
+                    .map_err(|_| Error::new("Invalid manual transaction target ref"))?;
+                let manual_transaction = loader
+                    .load_one(id)
+                    .await?
+                    .ok_or_else(|| Error::new("Manual transaction not found"))?;
+                Ok(ApprovalProcessTarget::ManualTransaction(manual_transaction))
+            }
         }
     }
 }
@@ -188,18 +225,27 @@ impl ApprovalProcess {
 #[allow(clippy::enum_variant_names)]
 pub enum ApprovalProcessType {
     WithdrawalApproval,
+    TransferApproval,
     DisbursalApproval,
     CreditFacilityProposalApproval,
+    WithdrawalLimitIncreaseApproval,
+    ManualTransactionApproval,
 }
 
 impl From<&DomainApprovalProcessType> for ApprovalProcessType {
     fn from(process_type: &DomainApprovalProcessType) -> Self {
         if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_PROCESS {
             Self::WithdrawalApproval
+        } else if process_type == &lana_app::governance::APPROVE_TRANSFER_PROCESS {
+            Self::TransferApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
         } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS {
             Self::CreditFacilityProposalApproval
+        } else if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS {
+            Self::WithdrawalLimitIncreaseApproval
+        } else if process_type == &lana_app::governance::APPROVE_MANUAL_TRANSACTION_PROCESS {
+            Self::ManualTransactionApproval
         } else {
             panic!("Unknown approval process type: {process_type:?}");
         }
@@ -234,8 +280,11 @@ impl ApprovalProcessVoter {
 #[derive(async_graphql::Union)]
 pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
     CreditFacilityProposal(CreditFacilityProposal),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
+    DepositAccount(DepositAccount),
+    ManualTransaction(ManualTransaction),
 }
 
 #[derive(InputObject)]
@@ -0,0 +1,179 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::customer::Customer;
+
+pub use lana_app::deposit::{
+    BankAccountDetails as DomainBankAccountDetails, BeneficiariesByCreatedAtCursor,
+    Beneficiary as DomainBeneficiary, BeneficiaryDestination as DomainBeneficiaryDestination,
+    BeneficiaryStatus, CryptoAddressDetails as DomainCryptoAddressDetails, CryptoNetwork,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("beneficiaryId".to_string())
+)]
+pub struct Beneficiary {
+    beneficiary_id: UUID,
+    customer_id: UUID,
+    name: String,
+    destination: BeneficiaryDestination,
+    status: BeneficiaryStatus,
+    cooling_off_ends_at: Timestamp,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainBeneficiary>,
+}
+
+impl From<DomainBeneficiary> for Beneficiary {
+    fn from(beneficiary: DomainBeneficiary) -> Self {
+        Beneficiary {
+            beneficiary_id: UUID::from(beneficiary.id),
+            customer_id: UUID::from(beneficiary.account_holder_id),
+            name: beneficiary.name.clone(),
+            destination: beneficiary.destination.clone().into(),
+            status: beneficiary.status(),
+            cooling_off_ends_at: beneficiary.cooling_off_ends_at.into(),
+            created_at: beneficiary.created_at().into(),
+            entity: Arc::new(beneficiary),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Beneficiary {
+    async fn rejection_reason(&self) -> Option<&str> {
+        self.entity.rejection_reason()
+    }
+
+    async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let customer = app
+            .customers()
+            .find_by_id(sub, self.entity.account_holder_id)
+            .await?
+            .ok_or_else(|| Error::new("Customer not found"))?;
+
+        Ok(Customer::from(customer))
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct BankAccountDestination {
+    account_holder_name: String,
+    bank_name: Option<String>,
+    iban: Option<String>,
+    account_number: Option<String>,
+    aba_routing_number: Option<String>,
+    swift_bic: Option<String>,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct CryptoAddressDestination {
+    network: CryptoNetwork,
+    address: String,
+}
+
+#[derive(Union, Clone)]
+pub enum BeneficiaryDestination {
+    BankAccount(BankAccountDestination),
+    CryptoAddress(CryptoAddressDestination),
+}
+
+impl From<DomainBeneficiaryDestination> for BeneficiaryDestination {
+    fn from(destination: DomainBeneficiaryDestination) -> Self {
+        match destination {
+            DomainBeneficiaryDestination::BankAccount(details) => {
+                BeneficiaryDestination::BankAccount(BankAccountDestination {
+                    account_holder_name: details.account_holder_name,
+                    bank_name: details.bank_name,
+                    iban: details.iban,
+                    account_number: details.account_number,
+                    aba_routing_number: details.aba_routing_number,
+                    swift_bic: details.swift_bic,
+                })
+            }
+            DomainBeneficiaryDestination::CryptoAddress(details) => {
+                BeneficiaryDestination::CryptoAddress(CryptoAddressDestination {
+                    network: details.network,
+                    address: details.address,
+                })
+            }
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct BankAccountDestinationInput {
+    pub account_holder_name: String,
+    pub bank_name: Option<String>,
+    pub iban: Option<String>,
+    pub account_number: Option<String>,
+    pub aba_routing_number: Option<String>,
+    pub swift_bic: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct CryptoAddressDestinationInput {
+    pub network: CryptoNetwork,
+    pub address: String,
+}
+
+#[derive(OneofObject)]
+pub enum BeneficiaryDestinationInput {
+    BankAccount(BankAccountDestinationInput),
+    CryptoAddress(CryptoAddressDestinationInput),
+}
+
+impl From<BeneficiaryDestinationInput> for DomainBeneficiaryDestination {
+    fn from(input: BeneficiaryDestinationInput) -> Self {
+        match input {
+            BeneficiaryDestinationInput::BankAccount(details) => {
+                DomainBeneficiaryDestination::BankAccount(DomainBankAccountDetails {
+                    account_holder_name: details.account_holder_name,
+                    bank_name: details.bank_name,
+                    iban: details.iban,
+                    account_number: details.account_number,
+                    aba_routing_number: details.aba_routing_number,
+                    swift_bic: details.swift_bic,
+                })
+            }
+            BeneficiaryDestinationInput::CryptoAddress(details) => {
+                DomainBeneficiaryDestination::CryptoAddress(DomainCryptoAddressDetails {
+                    network: details.network,
+                    address: details.address,
+                })
+            }
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct BeneficiaryCreateInput {
+    pub customer_id: UUID,
+    pub name: String,
+    pub destination: BeneficiaryDestinationInput,
+}
+crate::mutation_payload! { BeneficiaryCreatePayload, beneficiary: Beneficiary }
+
+#[derive(InputObject)]
+pub struct BeneficiaryVerifyInput {
+    pub beneficiary_id: UUID,
+}
+crate::mutation_payload! { BeneficiaryVerifyPayload, beneficiary: Beneficiary }
+
+#[derive(InputObject)]
+pub struct BeneficiaryRejectInput {
+    pub beneficiary_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { BeneficiaryRejectPayload, beneficiary: Beneficiary }
+
+#[derive(InputObject)]
+pub struct BeneficiaryRemoveInput {
+    pub beneficiary_id: UUID,
+}
+crate::mutation_payload! { BeneficiaryRemovePayload, beneficiary: Beneficiary }
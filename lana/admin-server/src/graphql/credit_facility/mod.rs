@@ -4,10 +4,12 @@ pub(super) mod disbursal;
 mod history;
 mod ledger_accounts;
 mod liquidation;
+mod participation;
 pub(super) mod payment_allocation;
 mod pending_facility;
 mod proposal;
 mod repayment;
+mod simulation;
 
 use async_graphql::{connection::*, *};
 
@@ -40,9 +42,11 @@ pub use disbursal::*;
 pub use history::*;
 use ledger_accounts::*;
 pub use liquidation::*;
+pub use participation::*;
 pub use pending_facility::*;
 pub use proposal::*;
 pub use repayment::*;
+pub use simulation::*;
 
 #[derive(SimpleObject, Clone)]
 #[graphql(
@@ -174,6 +178,24 @@ impl CreditFacility {
             .collect())
     }
 
+    async fn participations(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityParticipation>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let participations = app
+            .credit()
+            .participations()
+            .list_for_credit_facility(sub, self.entity.id)
+            .await?;
+
+        Ok(participations
+            .into_iter()
+            .map(CreditFacilityParticipation::from)
+            .collect())
+    }
+
     async fn user_can_update_collateral(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         Ok(app
@@ -0,0 +1,73 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::{
+    credit::Participation as DomainParticipation, primitives::ParticipationSharePct,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("creditFacilityParticipationId".to_string())
+)]
+pub struct CreditFacilityParticipation {
+    credit_facility_participation_id: UUID,
+    credit_facility_id: UUID,
+    participant_name: String,
+    share: ParticipationSharePct,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainParticipation>,
+}
+
+impl From<DomainParticipation> for CreditFacilityParticipation {
+    fn from(participation: DomainParticipation) -> Self {
+        Self {
+            credit_facility_participation_id: UUID::from(participation.id),
+            credit_facility_id: UUID::from(participation.credit_facility_id),
+            participant_name: participation.participant_name.clone(),
+            share: participation.share,
+            created_at: participation.created_at().into(),
+            entity: Arc::new(participation),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityParticipation {
+    async fn total_funded(&self) -> UsdCents {
+        self.entity.total_funded()
+    }
+
+    async fn total_principal_received(&self) -> UsdCents {
+        self.entity.total_principal_received()
+    }
+
+    async fn total_interest_received(&self) -> UsdCents {
+        self.entity.total_interest_received()
+    }
+
+    async fn outstanding_principal(&self) -> UsdCents {
+        self.entity.outstanding_principal()
+    }
+
+    async fn statement_csv(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<String>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let csv = app
+            .credit()
+            .participations()
+            .export_statement_csv(sub, self.entity.id)
+            .await?;
+        Ok(csv.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityParticipationAddInput {
+    pub credit_facility_id: UUID,
+    pub participant_name: String,
+    pub share: ParticipationSharePct,
+}
+crate::mutation_payload! { CreditFacilityParticipationAddPayload, participation: CreditFacilityParticipation }
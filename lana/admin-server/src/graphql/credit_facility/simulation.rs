@@ -0,0 +1,144 @@
+use async_graphql::*;
+
+use crate::{graphql::terms::*, primitives::*};
+
+pub use lana_app::{
+    credit::{
+        CreditTermsSimulation as DomainCreditTermsSimulation, PricePathPoint, PriceStressScenario,
+        StressScenarioOutcome, StressScenarioPoint,
+    },
+    price::PriceOfOneBTC,
+};
+
+use super::CreditFacilityRepaymentPlanEntry;
+
+#[derive(InputObject)]
+pub struct PricePathPointInput {
+    pub date: Date,
+    pub usd_cents_per_btc: UsdCents,
+}
+
+impl From<PricePathPointInput> for PricePathPoint {
+    fn from(input: PricePathPointInput) -> Self {
+        Self {
+            date: input.date.into_inner(),
+            price: PriceOfOneBTC::new(input.usd_cents_per_btc),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PriceStressScenarioInput {
+    pub name: String,
+    pub price_path: Vec<PricePathPointInput>,
+}
+
+impl From<PriceStressScenarioInput> for PriceStressScenario {
+    fn from(input: PriceStressScenarioInput) -> Self {
+        Self {
+            name: input.name,
+            price_path: input.price_path.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditTermsSimulateInput {
+    pub terms: TermsInput,
+    pub facility_amount: UsdCents,
+    pub collateral: Satoshis,
+    pub usd_cents_per_btc: Option<UsdCents>,
+    #[graphql(default)]
+    pub price_scenarios: Vec<PriceStressScenarioInput>,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditTermsStressScenarioPoint {
+    date: Date,
+    usd_cents_per_btc: UsdCents,
+    outstanding: UsdCents,
+    collateral_value: UsdCents,
+    cvl: CVLPct,
+    collateralization_state: CollateralizationState,
+}
+
+impl From<StressScenarioPoint> for CreditTermsStressScenarioPoint {
+    fn from(point: StressScenarioPoint) -> Self {
+        Self {
+            date: point.date.into(),
+            usd_cents_per_btc: point.price.into_inner(),
+            outstanding: point.outstanding,
+            collateral_value: point.collateral_value,
+            cvl: point.cvl.into(),
+            collateralization_state: point.collateralization_state,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CreditTermsStressScenarioOutcome {
+    name: String,
+    points: Vec<CreditTermsStressScenarioPoint>,
+    first_margin_call_on: Option<Date>,
+    first_liquidation_on: Option<Date>,
+}
+
+impl From<StressScenarioOutcome> for CreditTermsStressScenarioOutcome {
+    fn from(outcome: StressScenarioOutcome) -> Self {
+        Self {
+            name: outcome.name,
+            points: outcome.points.into_iter().map(Into::into).collect(),
+            first_margin_call_on: outcome.first_margin_call_on.map(Date::from),
+            first_liquidation_on: outcome.first_liquidation_on.map(Date::from),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CreditTermsSimulation {
+    credit_facility_terms: TermValues,
+    facility_amount: UsdCents,
+    collateral: Satoshis,
+    usd_cents_per_btc: UsdCents,
+    starts_at: Timestamp,
+    matures_at: Date,
+    structuring_fee: UsdCents,
+    total_interest: UsdCents,
+    total_repayment: UsdCents,
+    effective_annual_rate: AnnualRatePct,
+    initial_cvl: CVLPct,
+    margin_call_usd_cents_per_btc: Option<UsdCents>,
+    liquidation_usd_cents_per_btc: Option<UsdCents>,
+    cash_flows: Vec<CreditFacilityRepaymentPlanEntry>,
+    stress_scenarios: Vec<CreditTermsStressScenarioOutcome>,
+}
+
+impl From<DomainCreditTermsSimulation> for CreditTermsSimulation {
+    fn from(simulation: DomainCreditTermsSimulation) -> Self {
+        Self {
+            credit_facility_terms: simulation.terms.into(),
+            facility_amount: simulation.facility_amount,
+            collateral: simulation.collateral,
+            usd_cents_per_btc: simulation.btc_price.into_inner(),
+            starts_at: simulation.starts_at.into(),
+            matures_at: Date::from(chrono::NaiveDate::from(simulation.matures_at)),
+            structuring_fee: simulation.structuring_fee,
+            total_interest: simulation.total_interest,
+            total_repayment: simulation.total_repayment,
+            effective_annual_rate: simulation.effective_annual_rate,
+            initial_cvl: simulation.initial_cvl.into(),
+            margin_call_usd_cents_per_btc: simulation
+                .margin_call_price
+                .map(|price| price.into_inner()),
+            liquidation_usd_cents_per_btc: simulation
+                .liquidation_price
+                .map(|price| price.into_inner()),
+            cash_flows: simulation.cash_flows.into_iter().map(Into::into).collect(),
+            stress_scenarios: simulation
+                .stress_scenarios
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
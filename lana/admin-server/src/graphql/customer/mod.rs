@@ -1,11 +1,12 @@
 use crate::primitives::*;
 use async_graphql::{
-    connection::{Connection, EmptyFields},
+    connection::{Connection, Edge, EmptyFields, query},
     *,
 };
 use lana_app::public_id::PublicId;
 
 use super::{
+    beneficiary::{BeneficiariesByCreatedAtCursor, Beneficiary},
     credit_facility::*,
     deposit_account::*,
     document::CustomerDocument,
@@ -115,6 +116,42 @@ impl Customer {
         Ok(party.personal_info.clone())
     }
 
+    async fn beneficiaries(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<BeneficiariesByCreatedAtCursor, Beneficiary, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        query(
+            after,
+            None,
+            Some(first),
+            None,
+            |after, _, first, _| async move {
+                let first = first.expect("First always exists");
+                let query_args = es_entity::PaginatedQueryArgs { first, after };
+                let res = app
+                    .deposits()
+                    .list_beneficiaries_for_account_holder(sub, self.customer_id, query_args)
+                    .await?;
+
+                let mut connection = Connection::new(false, res.has_next_page);
+                connection
+                    .edges
+                    .extend(res.entities.into_iter().map(|beneficiary| {
+                        let cursor = BeneficiariesByCreatedAtCursor::from(&beneficiary);
+                        Edge::new(cursor, Beneficiary::from(beneficiary))
+                    }));
+                Ok::<_, async_graphql::Error>(connection)
+            },
+        )
+        .await
+    }
+
     async fn deposit_account(
         &self,
         ctx: &Context<'_>,
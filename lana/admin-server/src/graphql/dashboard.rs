@@ -1,8 +1,20 @@
 use async_graphql::*;
 
-use lana_app::dashboard::DashboardValues;
+use lana_app::{
+    customer::CustomerType,
+    dashboard::{
+        BorrowerConcentration, CustomerTypeExposure, CvlDistributionBucket, DashboardValues,
+        DaysPastDueBucket, PortfolioRisk as DomainPortfolioRisk, PriceShockOutcome,
+    },
+};
 
-use crate::primitives::{Satoshis, UsdCents};
+use crate::{
+    graphql::{
+        primitives::Decimal,
+        terms::{AnnualRatePct, CVLPct},
+    },
+    primitives::{Satoshis, UUID, UsdCents},
+};
 
 #[derive(SimpleObject)]
 pub struct Dashboard {
@@ -10,6 +22,7 @@ pub struct Dashboard {
     pending_facilities: u32,
     total_disbursed: UsdCents,
     total_collateral: Satoshis,
+    portfolio_risk: PortfolioRisk,
 }
 
 impl From<DashboardValues> for Dashboard {
@@ -19,6 +32,152 @@ impl From<DashboardValues> for Dashboard {
             pending_facilities: values.pending_facilities,
             total_disbursed: values.total_disbursed,
             total_collateral: values.total_collateral,
+            portfolio_risk: PortfolioRisk(values.portfolio_risk),
+        }
+    }
+}
+
+pub struct PortfolioRisk(DomainPortfolioRisk);
+
+#[Object]
+impl PortfolioRisk {
+    async fn exposure_by_customer_type(&self) -> Vec<PortfolioCustomerTypeExposure> {
+        self.0
+            .exposure_by_customer_type()
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    async fn top_borrowers(
+        &self,
+        #[graphql(default = 10)] first: i32,
+    ) -> Vec<PortfolioBorrowerConcentration> {
+        self.0
+            .top_borrowers(first.max(0) as usize)
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    async fn cvl_distribution(&self) -> Vec<PortfolioCvlBucket> {
+        self.0
+            .cvl_distribution()
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    async fn days_past_due_ageing(&self) -> Vec<PortfolioDaysPastDueBucket> {
+        self.0
+            .days_past_due_ageing()
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    async fn weighted_average_annual_rate(&self) -> Option<AnnualRatePct> {
+        self.0.weighted_average_annual_rate()
+    }
+
+    async fn weighted_average_days_to_maturity(&self) -> Option<Decimal> {
+        self.0
+            .weighted_average_days_to_maturity()
+            .map(Decimal::from)
+    }
+
+    async fn price_shocks(&self) -> Vec<PortfolioPriceShock> {
+        self.0.price_shocks().into_iter().map(Into::into).collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioCustomerTypeExposure {
+    customer_type: CustomerType,
+    facilities: u32,
+    outstanding: UsdCents,
+}
+
+impl From<CustomerTypeExposure> for PortfolioCustomerTypeExposure {
+    fn from(exposure: CustomerTypeExposure) -> Self {
+        Self {
+            customer_type: exposure.customer_type,
+            facilities: exposure.facilities,
+            outstanding: exposure.outstanding,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioBorrowerConcentration {
+    customer_id: UUID,
+    outstanding: UsdCents,
+    share_pct: Decimal,
+}
+
+impl From<BorrowerConcentration> for PortfolioBorrowerConcentration {
+    fn from(borrower: BorrowerConcentration) -> Self {
+        Self {
+            customer_id: UUID::from(borrower.customer_id),
+            outstanding: borrower.outstanding,
+            share_pct: borrower.share_pct.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioCvlBucket {
+    min_cvl: CVLPct,
+    max_cvl: Option<CVLPct>,
+    facilities: u32,
+    outstanding: UsdCents,
+}
+
+impl From<CvlDistributionBucket> for PortfolioCvlBucket {
+    fn from(bucket: CvlDistributionBucket) -> Self {
+        Self {
+            min_cvl: bucket.min_cvl.into(),
+            max_cvl: bucket.max_cvl.map(Into::into),
+            facilities: bucket.facilities,
+            outstanding: bucket.outstanding,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioDaysPastDueBucket {
+    min_days: u32,
+    max_days: Option<u32>,
+    facilities: u32,
+    outstanding: UsdCents,
+}
+
+impl From<DaysPastDueBucket> for PortfolioDaysPastDueBucket {
+    fn from(bucket: DaysPastDueBucket) -> Self {
+        Self {
+            min_days: bucket.min_days,
+            max_days: bucket.max_days,
+            facilities: bucket.facilities,
+            outstanding: bucket.outstanding,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioPriceShock {
+    shock_pct: u64,
+    usd_cents_per_btc: UsdCents,
+    margin_call_facilities: u32,
+    liquidation_facilities: u32,
+}
+
+impl From<PriceShockOutcome> for PortfolioPriceShock {
+    fn from(outcome: PriceShockOutcome) -> Self {
+        Self {
+            shock_pct: outcome.shock_pct,
+            usd_cents_per_btc: outcome.price.into_inner(),
+            margin_call_facilities: outcome.margin_call_facilities,
+            liquidation_facilities: outcome.liquidation_facilities,
         }
     }
 }
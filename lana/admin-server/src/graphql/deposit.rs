@@ -4,19 +4,24 @@ use es_entity::Sort;
 use crate::{graphql::accounting::LedgerTransaction, primitives::*};
 
 use super::{
+    customer::CustomerType,
     event_timeline::{self, EventTimelineCursor, EventTimelineEntry},
     loader::LanaDataLoader,
     primitives::SortDirection,
 };
 
-pub use super::deposit_account::{DepositAccount, DepositAccountsFilter, DepositAccountsSort};
+pub use super::deposit_account::{
+    DepositAccount, DepositAccountsFilter, DepositAccountsSort, DomainWithdrawalLimits,
+    EscheatmentRemittanceEntry, SigningRule, WithdrawalLimits,
+};
 
 pub use lana_app::{
     deposit::{
         Deposit as DomainDeposit, DepositAccountsCursor,
         DepositAccountsFilters as DomainDepositAccountsFilters,
-        DepositAccountsSortBy as DomainDepositAccountsSortBy, DepositStatus, DepositsCursor,
-        DepositsFilters as DomainDepositsFilters, DepositsSortBy as DomainDepositsSortBy,
+        DepositAccountsSortBy as DomainDepositAccountsSortBy, DepositChannel, DepositStatus,
+        DepositsCursor, DepositsFilters as DomainDepositsFilters,
+        DepositsSortBy as DomainDepositsSortBy,
     },
     public_id::PublicId,
 };
@@ -30,6 +35,7 @@ pub struct Deposit {
     deposit_id: UUID,
     account_id: UUID,
     amount: UsdCents,
+    currency: CurrencyCode,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -42,6 +48,7 @@ impl From<DomainDeposit> for Deposit {
             deposit_id: UUID::from(deposit.id),
             account_id: UUID::from(deposit.deposit_account_id),
             amount: deposit.amount,
+            currency: deposit.currency,
             created_at: deposit.created_at().into(),
 
             entity: Arc::new(deposit),
@@ -63,6 +70,10 @@ impl Deposit {
         self.entity.status()
     }
 
+    async fn channel(&self) -> DepositChannel {
+        self.entity.channel
+    }
+
     async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let account = loader
@@ -99,6 +110,7 @@ impl Deposit {
 pub struct DepositRecordInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
+    pub channel: Option<DepositChannel>,
     pub reference: Option<String>,
 }
 crate::mutation_payload! { DepositRecordPayload, deposit: Deposit }
@@ -106,6 +118,7 @@ crate::mutation_payload! { DepositRecordPayload, deposit: Deposit }
 #[derive(InputObject)]
 pub struct DepositAccountCreateInput {
     pub customer_id: UUID,
+    pub currency: Option<CurrencyCode>,
 }
 crate::mutation_payload! { DepositAccountCreatePayload, deposit_account: DepositAccount }
 
@@ -127,12 +140,59 @@ pub struct DepositAccountUnfreezeInput {
 }
 crate::mutation_payload! { DepositAccountUnfreezePayload, deposit_account: DepositAccount }
 
+#[derive(InputObject)]
+pub struct DepositAccountJointHolderAddInput {
+    pub deposit_account_id: UUID,
+    pub customer_id: UUID,
+    pub signing_rule: SigningRule,
+}
+crate::mutation_payload! { DepositAccountJointHolderAddPayload, deposit_account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountJointHolderRemoveInput {
+    pub deposit_account_id: UUID,
+    pub customer_id: UUID,
+}
+crate::mutation_payload! { DepositAccountJointHolderRemovePayload, deposit_account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountHolderSigningRuleUpdateInput {
+    pub deposit_account_id: UUID,
+    pub customer_id: UUID,
+    pub signing_rule: SigningRule,
+}
+crate::mutation_payload! { DepositAccountHolderSigningRuleUpdatePayload, deposit_account: DepositAccount }
+
 #[derive(InputObject)]
 pub struct DepositAccountCloseInput {
     pub deposit_account_id: UUID,
 }
 crate::mutation_payload! { DepositAccountClosePayload, deposit_account: DepositAccount }
 
+#[derive(InputObject)]
+pub struct DepositAccountEscheatedBalanceReclaimInput {
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { DepositAccountEscheatedBalanceReclaimPayload, deposit_account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountWithdrawalLimitIncreaseRequestInput {
+    pub deposit_account_id: UUID,
+    pub daily: Option<UsdCents>,
+    pub weekly: Option<UsdCents>,
+    pub monthly: Option<UsdCents>,
+}
+crate::mutation_payload! { DepositAccountWithdrawalLimitIncreaseRequestPayload, deposit_account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositWithdrawalLimitsUpdateInput {
+    pub customer_type: Option<CustomerType>,
+    pub daily: Option<UsdCents>,
+    pub weekly: Option<UsdCents>,
+    pub monthly: Option<UsdCents>,
+}
+crate::mutation_payload! { DepositWithdrawalLimitsUpdatePayload, withdrawal_limits: WithdrawalLimits }
+
 #[derive(InputObject)]
 pub struct DepositsFilter {
     pub status: Option<DepositStatus>,
@@ -5,18 +5,26 @@ use crate::primitives::*;
 
 pub use lana_app::deposit::{
     Activity, DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
-    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry, DepositAccountStatus,
+    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    DepositAccountHolder as DomainDepositAccountHolder, DepositAccountStatus,
     DepositAccountsSortBy as DomainDepositAccountsSortBy,
+    EscheatmentRemittanceEntry as DomainEscheatmentRemittanceEntry, HoldsByCreatedAtCursor,
+    SigningRule, WithdrawalLimits as DomainWithdrawalLimits,
 };
 
 use super::{
+    account_closure::AccountClosure,
     accounting::LedgerAccount,
     customer::Customer,
     deposit::*,
     deposit_account_history::*,
+    deposit_account_statement::DepositAccountStatement,
+    deposit_product::DepositProduct,
     event_timeline::{self, EventTimelineCursor, EventTimelineEntry},
+    fx_conversion::FxConversion,
+    hold::Hold,
     loader::LanaDataLoader,
-    primitives::SortDirection,
+    primitives::{Decimal, SortDirection},
     withdrawal::*,
 };
 
@@ -31,6 +39,8 @@ pub struct DepositAccount {
     created_at: Timestamp,
     status: DepositAccountStatus,
     activity: Activity,
+    currency: CurrencyCode,
+    pending_escheatment_date: Option<Date>,
 
     #[graphql(skip)]
     pub(super) entity: Arc<DomainDepositAccount>,
@@ -44,6 +54,8 @@ impl From<DomainDepositAccount> for DepositAccount {
             created_at: account.created_at().into(),
             status: account.status,
             activity: account.activity,
+            currency: account.currency,
+            pending_escheatment_date: account.pending_escheatment_date().map(Date::from),
 
             entity: Arc::new(account),
         }
@@ -54,6 +66,8 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -61,6 +75,48 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            held: balance.held,
+            available: balance.available(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountCurrencyBalance {
+    currency: CurrencyCode,
+    settled: Decimal,
+    pending: Decimal,
+    held: Decimal,
+    available: Decimal,
+}
+
+impl From<lana_app::deposit::DepositAccountCurrencyBalance> for DepositAccountCurrencyBalance {
+    fn from(balance: lana_app::deposit::DepositAccountCurrencyBalance) -> Self {
+        Self {
+            currency: balance.currency(),
+            settled: balance.settled.to_major().into(),
+            pending: balance.pending.to_major().into(),
+            held: balance.held.to_major().into(),
+            available: balance.available().to_major().into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountHolder {
+    customer_id: UUID,
+    signing_rule: SigningRule,
+    primary: bool,
+    suspended: bool,
+}
+
+impl From<DomainDepositAccountHolder> for DepositAccountHolder {
+    fn from(holder: DomainDepositAccountHolder) -> Self {
+        Self {
+            customer_id: holder.account_holder_id.into(),
+            signing_rule: holder.signing_rule,
+            primary: holder.primary,
+            suspended: holder.suspended,
         }
     }
 }
@@ -115,6 +171,60 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn holds(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<HoldsByCreatedAtCursor, Hold, EmptyFields, EmptyFields>>
+    {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        query(
+            after,
+            None,
+            Some(first),
+            None,
+            |after, _, first, _| async move {
+                let first = first.expect("First always exists");
+                let query_args = es_entity::PaginatedQueryArgs { first, after };
+                let res = app
+                    .deposits()
+                    .list_holds_for_account(sub, self.entity.id, query_args)
+                    .await?;
+
+                let mut connection = Connection::new(false, res.has_next_page);
+                connection
+                    .edges
+                    .extend(res.entities.into_iter().map(|hold| {
+                        let cursor = HoldsByCreatedAtCursor::from(&hold);
+                        Edge::new(cursor, Hold::from(hold))
+                    }));
+                Ok::<_, async_graphql::Error>(connection)
+            },
+        )
+        .await
+    }
+
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let Some(product_id) = self.entity.deposit_product_id else {
+            return Ok(None);
+        };
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let product = app
+            .deposits()
+            .find_deposit_product_by_id(sub, product_id)
+            .await?;
+        Ok(product.map(DepositProduct::from))
+    }
+
+    async fn accrued_interest(&self) -> UsdCents {
+        self.entity.accrued_interest()
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -165,6 +275,59 @@ impl DepositAccount {
         Ok(DepositAccountBalance::from(balance))
     }
 
+    async fn currency_balance(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<DepositAccountCurrencyBalance> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let balance = app
+            .deposits()
+            .account_currency_balance(sub, self.entity.id)
+            .await?;
+        Ok(DepositAccountCurrencyBalance::from(balance))
+    }
+
+    async fn closures(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<AccountClosure>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let closures = app
+            .deposits()
+            .list_account_closures_for_account(sub, self.entity.id)
+            .await?;
+        Ok(closures.into_iter().map(AccountClosure::from).collect())
+    }
+
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .deposits()
+            .list_account_statements(sub, self.entity.id)
+            .await?;
+        Ok(statements
+            .into_iter()
+            .map(DepositAccountStatement::from)
+            .collect())
+    }
+
+    async fn fx_conversions(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<FxConversion>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let conversions = app
+            .deposits()
+            .list_fx_conversions_for_account(sub, self.entity.id)
+            .await?;
+        Ok(conversions.into_iter().map(FxConversion::from).collect())
+    }
+
+    async fn holders(&self) -> Vec<DepositAccountHolder> {
+        self.entity
+            .holders()
+            .into_iter()
+            .map(DepositAccountHolder::from)
+            .collect()
+    }
+
     async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let customer = app
@@ -187,6 +350,24 @@ impl DepositAccount {
         event_timeline::events_to_connection(self.entity.events(), first, after)
     }
 
+    async fn withdrawal_limits(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<WithdrawalLimits> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let limits = app
+            .deposits()
+            .withdrawal_limits_for_account(sub, self.entity.id)
+            .await?;
+        Ok(limits.into())
+    }
+
+    async fn pending_withdrawal_limit_increase(&self) -> Option<WithdrawalLimits> {
+        self.entity
+            .pending_withdrawal_limit_increase()
+            .map(|(_, limits)| limits.into())
+    }
+
     async fn ledger_accounts(&self) -> DepositAccountLedgerAccounts {
         DepositAccountLedgerAccounts {
             deposit_account_id: self.entity.account_ids.deposit_account_id.into(),
@@ -195,6 +376,44 @@ impl DepositAccount {
     }
 }
 
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalLimits {
+    daily: Option<UsdCents>,
+    weekly: Option<UsdCents>,
+    monthly: Option<UsdCents>,
+}
+
+impl From<DomainWithdrawalLimits> for WithdrawalLimits {
+    fn from(limits: DomainWithdrawalLimits) -> Self {
+        Self {
+            daily: limits.daily,
+            weekly: limits.weekly,
+            monthly: limits.monthly,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct EscheatmentRemittanceEntry {
+    deposit_account_id: UUID,
+    customer_id: UUID,
+    public_id: PublicId,
+    escheated_on: Date,
+    amount: UsdCents,
+}
+
+impl From<DomainEscheatmentRemittanceEntry> for EscheatmentRemittanceEntry {
+    fn from(entry: DomainEscheatmentRemittanceEntry) -> Self {
+        Self {
+            deposit_account_id: entry.deposit_account_id.into(),
+            customer_id: entry.account_holder_id.into(),
+            public_id: entry.public_id,
+            escheated_on: entry.escheated_on.into(),
+            amount: entry.amount,
+        }
+    }
+}
+
 #[derive(InputObject)]
 pub struct DepositAccountsFilter {
     pub status: Option<DepositAccountStatus>,
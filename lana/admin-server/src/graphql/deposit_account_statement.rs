@@ -0,0 +1,74 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    DepositAccountStatement as DomainDepositAccountStatement, DepositAccountStatementFormat,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("depositAccountStatementId".to_string())
+)]
+pub struct DepositAccountStatement {
+    deposit_account_statement_id: UUID,
+    deposit_account_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    format: DepositAccountStatementFormat,
+    monthly: bool,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositAccountStatement>,
+}
+
+impl From<DomainDepositAccountStatement> for DepositAccountStatement {
+    fn from(statement: DomainDepositAccountStatement) -> Self {
+        DepositAccountStatement {
+            deposit_account_statement_id: UUID::from(statement.id),
+            deposit_account_id: UUID::from(statement.deposit_account_id),
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            format: statement.format,
+            monthly: statement.monthly,
+            created_at: statement.created_at().into(),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositAccountStatement {
+    async fn document_id(&self) -> UUID {
+        UUID::from(self.entity.document_id)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountStatementGenerateInput {
+    pub deposit_account_id: UUID,
+    pub period_start: Date,
+    pub period_end: Date,
+    pub format: DepositAccountStatementFormat,
+}
+crate::mutation_payload! { DepositAccountStatementGeneratePayload, deposit_account_statement: DepositAccountStatement }
+
+#[derive(InputObject)]
+pub struct DepositAccountStatementDownloadLinkGenerateInput {
+    pub deposit_account_statement_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountStatementDownloadLinkGeneratePayload {
+    link: String,
+}
+
+impl From<lana_app::document::GeneratedDocumentDownloadLink>
+    for DepositAccountStatementDownloadLinkGeneratePayload
+{
+    fn from(value: lana_app::document::GeneratedDocumentDownloadLink) -> Self {
+        Self { link: value.link }
+    }
+}
@@ -20,6 +20,10 @@ pub struct DepositAccountModuleConfig {
     chart_of_accounts_frozen_bank_deposit_accounts_parent_code: Option<String>,
     chart_of_accounts_frozen_financial_institution_deposit_accounts_parent_code: Option<String>,
     chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code: Option<String>,
+    chart_of_accounts_interest_expense_parent_code: Option<String>,
+    chart_of_accounts_fee_income_parent_code: Option<String>,
+    chart_of_accounts_unclaimed_property_parent_code: Option<String>,
+    chart_of_accounts_fx_position_parent_code: Option<String>,
 
     #[graphql(skip)]
     pub(super) _entity: Arc<DomainChartOfAccountsIntegrationConfig>,
@@ -92,6 +96,22 @@ impl From<DomainChartOfAccountsIntegrationConfig> for DepositAccountModuleConfig
                     .chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code
                     .to_string(),
             ),
+            chart_of_accounts_interest_expense_parent_code: values
+                .chart_of_accounts_interest_expense_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_accounts_fee_income_parent_code: values
+                .chart_of_accounts_fee_income_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_accounts_unclaimed_property_parent_code: values
+                .chart_of_accounts_unclaimed_property_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_accounts_fx_position_parent_code: values
+                .chart_of_accounts_fx_position_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
 
             _entity: Arc::new(values),
         }
@@ -113,5 +133,9 @@ pub struct DepositAccountModuleConfigureInput {
     pub chart_of_accounts_frozen_bank_deposit_accounts_parent_code: String,
     pub chart_of_accounts_frozen_financial_institution_deposit_accounts_parent_code: String,
     pub chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code: String,
+    pub chart_of_accounts_interest_expense_parent_code: Option<String>,
+    pub chart_of_accounts_fee_income_parent_code: Option<String>,
+    pub chart_of_accounts_unclaimed_property_parent_code: Option<String>,
+    pub chart_of_accounts_fx_position_parent_code: Option<String>,
 }
 crate::mutation_payload! { DepositAccountModuleConfigurePayload, deposit_account_config: DepositAccountModuleConfig }
@@ -0,0 +1,133 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit::Deposit, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{
+    DepositImport as DomainDepositImport, DepositImportRowOutcome as DomainDepositImportRowOutcome,
+    DepositImportStatus, DepositImportsByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("depositImportId".to_string())
+)]
+pub struct DepositImport {
+    deposit_import_id: UUID,
+    file_name: String,
+    status: DepositImportStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositImport>,
+}
+
+impl From<DomainDepositImport> for DepositImport {
+    fn from(deposit_import: DomainDepositImport) -> Self {
+        DepositImport {
+            deposit_import_id: UUID::from(deposit_import.id),
+            file_name: deposit_import.file_name.clone(),
+            status: deposit_import.status(),
+            created_at: deposit_import.created_at().into(),
+            entity: Arc::new(deposit_import),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositImport {
+    async fn total_rows(&self) -> usize {
+        self.entity.rows.len()
+    }
+
+    async fn processed_rows(&self) -> usize {
+        self.entity.processed_rows()
+    }
+
+    async fn row_errors(&self) -> Vec<DepositImportRowError> {
+        self.entity
+            .row_errors
+            .iter()
+            .map(|e| DepositImportRowError {
+                line: e.line,
+                message: e.message.clone(),
+            })
+            .collect()
+    }
+
+    async fn row_results(&self) -> Vec<DepositImportRowResult> {
+        self.entity
+            .outcomes()
+            .into_iter()
+            .filter_map(|(line, outcome)| {
+                let row = self.entity.rows.iter().find(|row| row.line == line)?;
+                let (status, deposit_id, failure_reason) = match outcome {
+                    DomainDepositImportRowOutcome::Posted { deposit_id } => {
+                        (DepositImportRowStatus::Posted, Some(*deposit_id), None)
+                    }
+                    DomainDepositImportRowOutcome::AlreadyRecorded { deposit_id } => (
+                        DepositImportRowStatus::AlreadyRecorded,
+                        Some(*deposit_id),
+                        None,
+                    ),
+                    DomainDepositImportRowOutcome::Failed { reason } => {
+                        (DepositImportRowStatus::Failed, None, Some(reason.clone()))
+                    }
+                };
+                Some(DepositImportRowResult {
+                    line,
+                    reference: row.reference.clone(),
+                    amount: row.amount,
+                    status,
+                    failure_reason,
+                    deposit_id,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DepositImportRowError {
+    line: u64,
+    message: String,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum DepositImportRowStatus {
+    Posted,
+    AlreadyRecorded,
+    Failed,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct DepositImportRowResult {
+    line: u64,
+    reference: String,
+    amount: UsdCents,
+    status: DepositImportRowStatus,
+    failure_reason: Option<String>,
+
+    #[graphql(skip)]
+    deposit_id: Option<DepositId>,
+}
+
+#[ComplexObject]
+impl DepositImportRowResult {
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Deposit>> {
+        let Some(deposit_id) = self.deposit_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositImportCreateInput {
+    pub file: Upload,
+}
+crate::mutation_payload! { DepositImportCreatePayload, deposit_import: DepositImport }
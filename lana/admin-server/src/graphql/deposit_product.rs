@@ -0,0 +1,177 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::deposit_account::DepositAccount;
+
+pub use lana_app::deposit::{
+    DepositFeeSchedule as DomainDepositFeeSchedule, DepositProduct as DomainDepositProduct,
+    DepositProductValues as DomainDepositProductValues, DepositProductsByNameCursor,
+    DepositRatePct, InterestFrequency, TermDepositTerms,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct DepositProductValues {
+    annual_rate: DepositRatePct,
+    accrual_frequency: InterestFrequency,
+    posting_frequency: InterestFrequency,
+    lock_in_period_days: Option<u32>,
+    early_withdrawal_penalty_rate: Option<DepositRatePct>,
+}
+
+impl From<DomainDepositProductValues> for DepositProductValues {
+    fn from(values: DomainDepositProductValues) -> Self {
+        Self {
+            annual_rate: values.annual_rate,
+            accrual_frequency: values.accrual_frequency,
+            posting_frequency: values.posting_frequency,
+            lock_in_period_days: values.term.map(|term| term.lock_in_period_days),
+            early_withdrawal_penalty_rate: values
+                .term
+                .map(|term| term.early_withdrawal_penalty_rate),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct DepositFeeSchedule {
+    monthly_maintenance_fee: Option<UsdCents>,
+    withdrawal_fee: Option<UsdCents>,
+    dormant_account_fee: Option<UsdCents>,
+}
+
+impl From<DomainDepositFeeSchedule> for DepositFeeSchedule {
+    fn from(schedule: DomainDepositFeeSchedule) -> Self {
+        Self {
+            monthly_maintenance_fee: schedule.monthly_maintenance_fee,
+            withdrawal_fee: schedule.withdrawal_fee,
+            dormant_account_fee: schedule.dormant_account_fee,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositProduct {
+    deposit_product_id: UUID,
+    values: DepositProductValues,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositProduct>,
+}
+
+impl From<DomainDepositProduct> for DepositProduct {
+    fn from(product: DomainDepositProduct) -> Self {
+        Self {
+            deposit_product_id: product.id.into(),
+            values: product.values.into(),
+            created_at: product.created_at().into(),
+            entity: Arc::new(product),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositProduct {
+    async fn name(&self) -> &str {
+        &self.entity.name
+    }
+
+    async fn is_term_deposit(&self) -> bool {
+        self.entity.values.is_term_deposit()
+    }
+
+    async fn fee_schedule(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositFeeSchedule> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let schedule = app
+            .deposits()
+            .find_deposit_fee_schedule(sub, self.entity.id)
+            .await?;
+        Ok(schedule.into())
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositProductValuesInput {
+    pub annual_rate: DepositRatePct,
+    pub accrual_frequency: InterestFrequency,
+    pub posting_frequency: InterestFrequency,
+    pub lock_in_period_days: Option<u32>,
+    pub early_withdrawal_penalty_rate: Option<DepositRatePct>,
+}
+
+pub fn build_deposit_product_values(
+    values: DepositProductValuesInput,
+) -> async_graphql::Result<DomainDepositProductValues> {
+    let mut builder = DomainDepositProductValues::builder();
+    builder
+        .annual_rate(values.annual_rate)
+        .accrual_frequency(values.accrual_frequency)
+        .posting_frequency(values.posting_frequency);
+    match (
+        values.lock_in_period_days,
+        values.early_withdrawal_penalty_rate,
+    ) {
+        (Some(lock_in_period_days), Some(early_withdrawal_penalty_rate)) => {
+            builder.term(TermDepositTerms {
+                lock_in_period_days,
+                early_withdrawal_penalty_rate,
+            });
+        }
+        (None, None) => {}
+        _ => {
+            return Err(Error::new(
+                "lockInPeriodDays and earlyWithdrawalPenaltyRate must be provided together",
+            ));
+        }
+    }
+    Ok(builder.build()?)
+}
+
+#[derive(InputObject)]
+pub struct DepositProductCreateInput {
+    pub name: String,
+    pub values: DepositProductValuesInput,
+}
+crate::mutation_payload! { DepositProductCreatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductUpdateInput {
+    pub deposit_product_id: UUID,
+    pub values: DepositProductValuesInput,
+}
+crate::mutation_payload! { DepositProductUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositAccountProductAssignInput {
+    pub deposit_account_id: UUID,
+    pub deposit_product_id: UUID,
+}
+crate::mutation_payload! { DepositAccountProductAssignPayload, deposit_account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositProductFeeScheduleUpdateInput {
+    pub deposit_product_id: UUID,
+    pub monthly_maintenance_fee: Option<UsdCents>,
+    pub withdrawal_fee: Option<UsdCents>,
+    pub dormant_account_fee: Option<UsdCents>,
+}
+crate::mutation_payload! { DepositProductFeeScheduleUpdatePayload, deposit_product: DepositProduct }
+
+impl From<DepositProductFeeScheduleUpdateInput> for DomainDepositFeeSchedule {
+    fn from(input: DepositProductFeeScheduleUpdateInput) -> Self {
+        Self {
+            monthly_maintenance_fee: input.monthly_maintenance_fee,
+            withdrawal_fee: input.withdrawal_fee,
+            dormant_account_fee: input.dormant_account_fee,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountFeeReverseInput {
+    pub deposit_account_id: UUID,
+    pub ledger_transaction_id: UUID,
+}
+crate::mutation_payload! { DepositAccountFeeReversePayload, deposit_account: DepositAccount }
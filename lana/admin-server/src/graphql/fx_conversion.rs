@@ -0,0 +1,91 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    accounting::LedgerTransaction, deposit_account::DepositAccount, loader::LanaDataLoader,
+    primitives::Decimal,
+};
+
+pub use lana_app::deposit::FxConversion as DomainFxConversion;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("fxConversionId".to_string())
+)]
+pub struct FxConversion {
+    fx_conversion_id: UUID,
+    source_account_id: UUID,
+    target_account_id: UUID,
+    source_currency: CurrencyCode,
+    source_amount: Decimal,
+    target_currency: CurrencyCode,
+    target_amount: Decimal,
+    rate: Decimal,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainFxConversion>,
+}
+
+impl From<DomainFxConversion> for FxConversion {
+    fn from(conversion: DomainFxConversion) -> Self {
+        FxConversion {
+            fx_conversion_id: UUID::from(conversion.id),
+            source_account_id: conversion.source_account_id.into(),
+            target_account_id: conversion.target_account_id.into(),
+            source_currency: conversion.source_amount.currency(),
+            source_amount: conversion.source_amount.to_major().into(),
+            target_currency: conversion.target_amount.currency(),
+            target_amount: conversion.target_amount.to_major().into(),
+            rate: conversion.rate.into(),
+            created_at: conversion.created_at().into(),
+            entity: Arc::new(conversion),
+        }
+    }
+}
+
+#[ComplexObject]
+impl FxConversion {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn source_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.source_account_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit account not found"))?;
+        Ok(account)
+    }
+
+    async fn target_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.target_account_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit account not found"))?;
+        Ok(account)
+    }
+
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_tx_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountCurrencyConvertInput {
+    pub source_account_id: UUID,
+    pub target_account_id: UUID,
+    pub currency: CurrencyCode,
+    pub amount: Decimal,
+    pub rate: Decimal,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { DepositAccountCurrencyConvertPayload, fx_conversion: FxConversion }
@@ -0,0 +1,73 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{Hold as DomainHold, HoldStatus};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Hold {
+    hold_id: UUID,
+    deposit_account_id: UUID,
+    amount: UsdCents,
+    currency: CurrencyCode,
+    reason: String,
+    expires_at: Timestamp,
+    status: HoldStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainHold>,
+}
+
+impl From<DomainHold> for Hold {
+    fn from(hold: DomainHold) -> Self {
+        Hold {
+            hold_id: UUID::from(hold.id),
+            deposit_account_id: UUID::from(hold.deposit_account_id),
+            amount: hold.amount,
+            currency: hold.currency,
+            reason: hold.reason.clone(),
+            expires_at: hold.expires_at.into(),
+            status: hold.status(),
+            created_at: hold.created_at().into(),
+            entity: Arc::new(hold),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Hold {
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit account not found"))?;
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountHoldPlaceInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub reason: String,
+    pub expires_at: Timestamp,
+}
+crate::mutation_payload! { DepositAccountHoldPlacePayload, hold: Hold }
+
+#[derive(InputObject)]
+pub struct DepositAccountHoldExtendInput {
+    pub hold_id: UUID,
+    pub expires_at: Timestamp,
+}
+crate::mutation_payload! { DepositAccountHoldExtendPayload, hold: Hold }
+
+#[derive(InputObject)]
+pub struct DepositAccountHoldReleaseInput {
+    pub hold_id: UUID,
+}
+crate::mutation_payload! { DepositAccountHoldReleasePayload, hold: Hold }
@@ -11,6 +11,7 @@ use lana_app::{
         Chart, FiscalYearId, LedgerAccountId, TransactionTemplateId, csv::AccountingCsvDocumentId,
         error::CoreAccountingError,
     },
+    aml::{AmlAlertId, error::AmlError},
     app::LanaApp,
     custody::error::CoreCustodyError,
     customer::{CustomerDocumentId, Party, PartyId},
@@ -23,9 +24,11 @@ use lana_app::{
 use crate::primitives::*;
 
 use super::{
-    access::*, accounting::*, approval_process::*, committee::*, credit_facility::*, custody::*,
-    customer::*, deposit::*, deposit_account::*, document::*, domain_config::*, policy::*,
-    price_provider::*, prospect::*, reports::*, terms_template::*, withdrawal::*,
+    access::*, account_closure::*, accounting::*, aml_alert::*, approval_process::*,
+    beneficiary::*, committee::*, credit_facility::*, custody::*, customer::*, deposit::*,
+    deposit_account::*, deposit_import::*, document::*, domain_config::*, fx_conversion::*,
+    payment_file::*, policy::*, price_provider::*, prospect::*, reports::*, terms_template::*,
+    transfer::*, withdrawal::*,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -211,6 +214,23 @@ impl Loader<CustomerId> for LanaLoader {
     }
 }
 
+impl Loader<AmlAlertId> for LanaLoader {
+    type Value = AmlAlert;
+    type Error = Arc<AmlError>;
+
+    #[instrument(name = "loader.aml_alerts", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[AmlAlertId],
+    ) -> Result<HashMap<AmlAlertId, AmlAlert>, Self::Error> {
+        self.app
+            .aml()
+            .find_all_alerts_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<ProspectId> for LanaLoader {
     type Value = Prospect;
     type Error = Arc<lana_app::customer::error::CustomerError>;
@@ -316,6 +336,23 @@ impl Loader<WithdrawalId> for LanaLoader {
     }
 }
 
+impl Loader<TransferId> for LanaLoader {
+    type Value = Transfer;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.transfers", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[TransferId],
+    ) -> Result<HashMap<TransferId, Transfer>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_transfers_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositId> for LanaLoader {
     type Value = Deposit;
     type Error = Arc<CoreDepositError>;
@@ -330,6 +367,91 @@ impl Loader<DepositId> for LanaLoader {
     }
 }
 
+impl Loader<BeneficiaryId> for LanaLoader {
+    type Value = Beneficiary;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.beneficiaries", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[BeneficiaryId],
+    ) -> Result<HashMap<BeneficiaryId, Beneficiary>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_beneficiaries_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<PaymentFileId> for LanaLoader {
+    type Value = PaymentFile;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.payment_files", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[PaymentFileId],
+    ) -> Result<HashMap<PaymentFileId, PaymentFile>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_payment_files_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<AccountClosureId> for LanaLoader {
+    type Value = AccountClosure;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.account_closures", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[AccountClosureId],
+    ) -> Result<HashMap<AccountClosureId, AccountClosure>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_account_closures_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<DepositImportId> for LanaLoader {
+    type Value = DepositImport;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.deposit_imports", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[DepositImportId],
+    ) -> Result<HashMap<DepositImportId, DepositImport>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_deposit_imports_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<FxConversionId> for LanaLoader {
+    type Value = FxConversion;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.fx_conversions", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[FxConversionId],
+    ) -> Result<HashMap<FxConversionId, FxConversion>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_fx_conversions_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositAccountId> for LanaLoader {
     type Value = DepositAccount;
     type Error = Arc<CoreDepositError>;
@@ -364,6 +486,40 @@ impl Loader<LedgerTransactionId> for LanaLoader {
     }
 }
 
+impl Loader<ManualTransactionId> for LanaLoader {
+    type Value = ManualTransaction;
+    type Error = Arc<CoreAccountingError>;
+
+    #[instrument(name = "loader.manual_transactions", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[ManualTransactionId],
+    ) -> Result<HashMap<ManualTransactionId, Self::Value>, Self::Error> {
+        self.app
+            .accounting()
+            .find_all_manual_transactions_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<ManualTransactionScheduleId> for LanaLoader {
+    type Value = ManualTransactionSchedule;
+    type Error = Arc<CoreAccountingError>;
+
+    #[instrument(name = "loader.manual_transaction_schedules", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[ManualTransactionScheduleId],
+    ) -> Result<HashMap<ManualTransactionScheduleId, Self::Value>, Self::Error> {
+        self.app
+            .accounting()
+            .find_all_manual_transaction_schedules_authorized(&self.sub, keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<TransactionTemplateId> for LanaLoader {
     type Value = TransactionTemplate;
     type Error = Arc<CoreAccountingError>;
@@ -1,7 +1,10 @@
+mod account_closure;
 mod accounting;
+mod aml_alert;
 mod approval_process;
 mod approval_rules;
 mod audit;
+mod beneficiary;
 mod build_info;
 mod committee;
 mod contract_creation;
@@ -13,12 +16,18 @@ mod dashboard;
 mod deposit;
 mod deposit_account;
 mod deposit_account_history;
+mod deposit_account_statement;
 mod deposit_config;
+mod deposit_import;
+mod deposit_product;
 mod document;
 mod domain_config;
 mod event_timeline;
+mod fx_conversion;
+mod hold;
 mod loader;
 mod me;
+mod payment_file;
 mod price;
 mod price_provider;
 mod primitives;
@@ -29,6 +38,7 @@ mod sumsub;
 mod terms;
 mod terms_template;
 mod time;
+mod transfer;
 mod withdrawal;
 #[macro_use]
 pub(crate) mod macros;
@@ -0,0 +1,142 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{loader::LanaDataLoader, withdrawal::Withdrawal};
+
+pub use lana_app::deposit::{
+    PaymentFile as DomainPaymentFile, PaymentFileStatus, PaymentFilesByCreatedAtCursor,
+    PaymentOriginator as DomainPaymentOriginator,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("paymentFileId".to_string())
+)]
+pub struct PaymentFile {
+    payment_file_id: UUID,
+    message_id: String,
+    status: PaymentFileStatus,
+    control_sum: UsdCents,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainPaymentFile>,
+}
+
+impl From<DomainPaymentFile> for PaymentFile {
+    fn from(payment_file: DomainPaymentFile) -> Self {
+        PaymentFile {
+            payment_file_id: UUID::from(payment_file.id),
+            message_id: payment_file.message_id.clone(),
+            status: payment_file.status(),
+            control_sum: payment_file.control_sum,
+            created_at: payment_file.created_at().into(),
+            entity: Arc::new(payment_file),
+        }
+    }
+}
+
+#[ComplexObject]
+impl PaymentFile {
+    async fn withdrawals(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Withdrawal>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let loaded = loader
+            .load_many(self.entity.withdrawal_ids.iter().copied())
+            .await?;
+
+        Ok(self
+            .entity
+            .withdrawal_ids
+            .iter()
+            .filter_map(|id| loaded.get(id).cloned())
+            .collect())
+    }
+
+    async fn accepted_withdrawal_ids(&self) -> Vec<UUID> {
+        self.entity
+            .accepted_withdrawal_ids()
+            .into_iter()
+            .map(UUID::from)
+            .collect()
+    }
+
+    async fn rejected_withdrawal_ids(&self) -> Vec<UUID> {
+        self.entity
+            .rejected_withdrawal_ids()
+            .into_iter()
+            .map(UUID::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct PaymentOriginator {
+    name: String,
+    iban: String,
+    bic: String,
+}
+
+impl From<DomainPaymentOriginator> for PaymentOriginator {
+    fn from(originator: DomainPaymentOriginator) -> Self {
+        Self {
+            name: originator.name,
+            iban: originator.iban,
+            bic: originator.bic,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PaymentOriginatorUpdateInput {
+    pub name: String,
+    pub iban: String,
+    pub bic: String,
+}
+
+impl From<PaymentOriginatorUpdateInput> for DomainPaymentOriginator {
+    fn from(input: PaymentOriginatorUpdateInput) -> Self {
+        Self {
+            name: input.name,
+            iban: input.iban,
+            bic: input.bic,
+        }
+    }
+}
+crate::mutation_payload! { PaymentOriginatorUpdatePayload, payment_originator: PaymentOriginator }
+
+#[derive(SimpleObject)]
+pub struct PaymentFileGeneratePayload {
+    payment_file: Option<PaymentFile>,
+}
+
+impl From<Option<PaymentFile>> for PaymentFileGeneratePayload {
+    fn from(payment_file: Option<PaymentFile>) -> Self {
+        Self { payment_file }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PaymentStatusReportImportInput {
+    pub file: Upload,
+}
+crate::mutation_payload! { PaymentStatusReportImportPayload, payment_file: PaymentFile }
+
+#[derive(InputObject)]
+pub struct PaymentFileDownloadLinkGenerateInput {
+    pub payment_file_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentFileDownloadLinkGeneratePayload {
+    link: String,
+}
+
+impl From<lana_app::document::GeneratedDocumentDownloadLink>
+    for PaymentFileDownloadLinkGeneratePayload
+{
+    fn from(value: lana_app::document::GeneratedDocumentDownloadLink) -> Self {
+        Self { link: value.link }
+    }
+}
@@ -6,6 +6,7 @@ use crate::graphql::{
     deposit::Deposit,
     deposit_account::DepositAccount,
     prospect::Prospect,
+    transfer::Transfer,
     withdrawal::Withdrawal,
 };
 
@@ -16,6 +17,7 @@ pub enum PublicIdTarget {
     DepositAccount(DepositAccount),
     Deposit(Deposit),
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
     CreditFacility(CreditFacility),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
 }
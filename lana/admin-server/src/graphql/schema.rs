@@ -16,7 +16,8 @@ use lana_app::price::CorePriceEvent;
 use lana_app::report::CoreReportEvent;
 use lana_app::{
     accounting_init::constants::{
-        BALANCE_SHEET_NAME, PROFIT_AND_LOSS_STATEMENT_NAME, TRIAL_BALANCE_STATEMENT_NAME,
+        BALANCE_SHEET_NAME, CASH_FLOW_STATEMENT_NAME, PROFIT_AND_LOSS_STATEMENT_NAME,
+        TRIAL_BALANCE_STATEMENT_NAME,
     },
     app::LanaApp,
     credit::{
@@ -33,11 +34,13 @@ use lana_app::{
 use crate::primitives::*;
 
 use super::{
-    access::*, accounting::*, approval_process::*, audit::*, build_info::BuildInfo, committee::*,
-    contract_creation::*, credit_config::*, credit_facility::*, custody::*, customer::*,
-    dashboard::*, deposit::*, deposit_config::*, document::*, domain_config::*, loader::*, me::*,
+    access::*, account_closure::*, accounting::*, aml_alert::*, approval_process::*, audit::*,
+    beneficiary::*, build_info::BuildInfo, committee::*, contract_creation::*, credit_config::*,
+    credit_facility::*, custody::*, customer::*, dashboard::*, deposit::*,
+    deposit_account_statement::*, deposit_config::*, deposit_import::*, deposit_product::*,
+    document::*, domain_config::*, fx_conversion::*, hold::*, loader::*, me::*, payment_file::*,
     policy::*, price::*, price_provider::*, prospect::*, public_id::*, reports::*, sumsub::*,
-    terms::build_term_values, terms_template::*, time::*, withdrawal::*,
+    terms::build_term_values, terms_template::*, time::*, transfer::*, withdrawal::*,
 };
 
 pub struct Query;
@@ -255,6 +258,120 @@ impl Query {
         )
     }
 
+    async fn account_closure(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AccountClosure>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            AccountClosure,
+            ctx,
+            app.deposits().find_account_closure_by_id(sub, id)
+        )
+    }
+
+    async fn deposit_import(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<DepositImport>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            DepositImport,
+            ctx,
+            app.deposits().find_deposit_import_by_id(sub, id)
+        )
+    }
+
+    async fn deposit_imports(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<DepositImportsByCreatedAtCursor, DepositImport, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            DepositImportsByCreatedAtCursor,
+            DepositImport,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_deposit_imports(sub, query)
+        )
+    }
+
+    async fn aml_alert(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AmlAlert>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(AmlAlert, ctx, app.aml().find_alert_by_id(sub, id))
+    }
+
+    async fn aml_alerts(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<AmlAlertsByCreatedAtCursor, AmlAlert, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            AmlAlertsByCreatedAtCursor,
+            AmlAlert,
+            ctx,
+            after,
+            first,
+            |query| app.aml().list_alerts(sub, query)
+        )
+    }
+
+    async fn payment_file(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<PaymentFile>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            PaymentFile,
+            ctx,
+            app.deposits().find_payment_file_by_id(sub, id)
+        )
+    }
+
+    async fn payment_files(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<PaymentFilesByCreatedAtCursor, PaymentFile, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            PaymentFilesByCreatedAtCursor,
+            PaymentFile,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_payment_files(sub, query)
+        )
+    }
+
+    async fn payment_originator(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PaymentOriginator>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let originator = app.deposits().find_payment_originator(sub).await?;
+        Ok(originator.map(PaymentOriginator::from))
+    }
+
     async fn withdrawal(
         &self,
         ctx: &Context<'_>,
@@ -309,6 +426,69 @@ impl Query {
         )
     }
 
+    async fn fx_conversion(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<FxConversion>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            FxConversion,
+            ctx,
+            app.deposits().find_fx_conversion_by_id(sub, id)
+        )
+    }
+
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Transfer, ctx, app.deposits().find_transfer_by_id(sub, id))
+    }
+
+    async fn transfer_by_public_id(
+        &self,
+        ctx: &Context<'_>,
+        id: PublicId,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            Transfer,
+            ctx,
+            app.deposits().find_transfer_by_public_id(sub, id)
+        )
+    }
+
+    async fn transfers(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+        #[graphql(default_with = "Some(TransfersSort::default())")] sort: Option<TransfersSort>,
+        filter: Option<TransfersFilter>,
+    ) -> async_graphql::Result<Connection<TransfersCursor, Transfer, EmptyFields, EmptyFields>>
+    {
+        let filter = DomainTransfersFilters {
+            status: filter.as_ref().and_then(|f| f.status),
+            ..Default::default()
+        };
+        let sort = sort.unwrap_or_default();
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_combo_cursor!(
+            TransfersCursor,
+            Transfer,
+            DomainTransfersSortBy::from(sort),
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .list_transfers(sub, query, filter, sort.into())
+        )
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -327,6 +507,19 @@ impl Query {
         )
     }
 
+    async fn beneficiary(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Beneficiary>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            Beneficiary,
+            ctx,
+            app.deposits().find_beneficiary_by_id(sub, id)
+        )
+    }
+
     async fn deposit_account(
         &self,
         ctx: &Context<'_>,
@@ -353,6 +546,36 @@ impl Query {
         )
     }
 
+    async fn deposit_withdrawal_limits(
+        &self,
+        ctx: &Context<'_>,
+        customer_type: Option<CustomerType>,
+    ) -> async_graphql::Result<WithdrawalLimits> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let limits = app
+            .deposits()
+            .find_withdrawal_limits(sub, customer_type)
+            .await?;
+        Ok(limits.into())
+    }
+
+    async fn escheatment_remittance_report(
+        &self,
+        ctx: &Context<'_>,
+        from: Date,
+        until: Date,
+    ) -> async_graphql::Result<Vec<EscheatmentRemittanceEntry>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let entries = app
+            .deposits()
+            .escheatment_remittance_report(sub, from.into_inner(), until.into_inner())
+            .await?;
+        Ok(entries
+            .into_iter()
+            .map(EscheatmentRemittanceEntry::from)
+            .collect())
+    }
+
     async fn deposit_accounts(
         &self,
         ctx: &Context<'_>,
@@ -412,6 +635,53 @@ impl Query {
         )
     }
 
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let product = app
+            .deposits()
+            .find_deposit_product_by_id(sub, DepositProductId::from(id))
+            .await?;
+        Ok(product.map(DepositProduct::from))
+    }
+
+    async fn deposit_products(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<DepositProductsByNameCursor, DepositProduct, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        query(
+            after,
+            None,
+            Some(first),
+            None,
+            |after, _, first, _| async move {
+                let first = first.expect("First always exists");
+                let res = app
+                    .deposits()
+                    .list_deposit_products(sub, es_entity::PaginatedQueryArgs { first, after })
+                    .await?;
+
+                let mut connection = Connection::new(false, res.has_next_page);
+                connection
+                    .edges
+                    .extend(res.entities.into_iter().map(|product| {
+                        let cursor = DepositProductsByNameCursor::from(&product);
+                        Edge::new(cursor, DepositProduct::from(product))
+                    }));
+                Ok::<_, async_graphql::Error>(connection)
+            },
+        )
+        .await
+    }
+
     async fn terms_template(
         &self,
         ctx: &Context<'_>,
@@ -587,6 +857,30 @@ impl Query {
         )
     }
 
+    /// Projects cash flows, effective rate and collateral trigger prices for the
+    /// given terms without creating a proposal.
+    async fn credit_terms_simulate(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditTermsSimulateInput,
+    ) -> async_graphql::Result<CreditTermsSimulation> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let terms = build_term_values(input.terms)?;
+        let simulation = app
+            .credit()
+            .simulations()
+            .simulate(
+                sub,
+                terms,
+                input.facility_amount,
+                input.collateral,
+                input.usd_cents_per_btc.map(PriceOfOneBTC::new),
+                input.price_scenarios.into_iter().map(Into::into).collect(),
+            )
+            .await?;
+        Ok(CreditTermsSimulation::from(simulation))
+    }
+
     async fn disbursal(
         &self,
         ctx: &Context<'_>,
@@ -943,6 +1237,90 @@ impl Query {
         )
     }
 
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ManualTransaction,
+            ctx,
+            app.accounting()
+                .manual_transactions()
+                .find_manual_transaction_by_id(sub, id)
+        )
+    }
+
+    async fn manual_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .manual_transactions()
+                .list_manual_transactions(sub, query)
+        )
+    }
+
+    async fn manual_transaction_schedule(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ManualTransactionSchedule>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ManualTransactionSchedule,
+            ctx,
+            app.accounting()
+                .manual_transaction_schedules()
+                .find_by_id(sub, id)
+        )
+    }
+
+    async fn manual_transaction_schedules(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            ManualTransactionSchedulesByCreatedAtCursor,
+            ManualTransactionSchedule,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ManualTransactionSchedulesByCreatedAtCursor,
+            ManualTransactionSchedule,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .manual_transaction_schedules()
+                .list(sub, query)
+        )
+    }
+
     async fn ledger_entries(
         &self,
         ctx: &Context<'_>,
@@ -1105,16 +1483,36 @@ impl Query {
         Ok(ProfitAndLossStatement::new(profit_and_loss, from, until))
     }
 
-    async fn realtime_price(&self, ctx: &Context<'_>) -> async_graphql::Result<RealtimePrice> {
-        let app = ctx.data_unchecked::<LanaApp>();
-        let usd_cents_per_btc = app.price().usd_cents_per_btc().await;
-        Ok(usd_cents_per_btc.into())
-    }
-
-    async fn audit(
+    async fn cash_flow_statement(
         &self,
         ctx: &Context<'_>,
-        first: i32,
+        from: Date,
+        until: Date,
+    ) -> async_graphql::Result<CashFlowStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let cash_flow = app
+            .accounting()
+            .cash_flow_statements()
+            .cash_flow_statement(
+                sub,
+                CASH_FLOW_STATEMENT_NAME.to_string(),
+                from.into_inner(),
+                until.into_inner(),
+            )
+            .await?;
+        Ok(CashFlowStatement::from(cash_flow))
+    }
+
+    async fn realtime_price(&self, ctx: &Context<'_>) -> async_graphql::Result<RealtimePrice> {
+        let app = ctx.data_unchecked::<LanaApp>();
+        let usd_cents_per_btc = app.price().usd_cents_per_btc().await;
+        Ok(usd_cents_per_btc.into())
+    }
+
+    async fn audit(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
         after: Option<String>,
         subject: Option<AuditSubjectId>,
         authorized: Option<bool>,
@@ -1246,6 +1644,10 @@ impl Query {
                 .withdrawal(ctx, public_id.target_id.into())
                 .await?
                 .map(PublicIdTarget::Withdrawal),
+            "transfer" => self
+                .transfer(ctx, public_id.target_id.into())
+                .await?
+                .map(PublicIdTarget::Transfer),
             "credit_facility" => self
                 .credit_facility(ctx, public_id.target_id.into())
                 .await?
@@ -1640,6 +2042,10 @@ impl Mutation {
             chart_of_accounts_frozen_bank_deposit_accounts_parent_code,
             chart_of_accounts_frozen_financial_institution_deposit_accounts_parent_code,
             chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code,
+            chart_of_accounts_interest_expense_parent_code,
+            chart_of_accounts_fee_income_parent_code,
+            chart_of_accounts_unclaimed_property_parent_code,
+            chart_of_accounts_fx_position_parent_code,
         } = input;
 
         let config_values = lana_app::deposit::ChartOfAccountsIntegrationConfig {
@@ -1671,6 +2077,20 @@ impl Mutation {
                 chart_of_accounts_frozen_non_domiciled_company_deposit_accounts_parent_code
                     .parse()?,
             chart_of_accounts_omnibus_parent_code: chart_of_accounts_omnibus_parent_code.parse()?,
+            chart_of_accounts_interest_expense_parent_code:
+                chart_of_accounts_interest_expense_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_accounts_fee_income_parent_code: chart_of_accounts_fee_income_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+            chart_of_accounts_unclaimed_property_parent_code:
+                chart_of_accounts_unclaimed_property_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_accounts_fx_position_parent_code: chart_of_accounts_fx_position_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
         };
 
         let config = app
@@ -1695,14 +2115,55 @@ impl Mutation {
             entries.push(entry.try_into()?);
         }
 
+        let manual_transaction = ManualTransaction::from(
+            app.accounting()
+                .submit_manual_transaction(
+                    sub,
+                    CHART_REF.0,
+                    input.reference,
+                    input.description,
+                    input.effective.into_inner(),
+                    entries,
+                )
+                .await?,
+        );
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        loader
+            .feed_one(manual_transaction.entity.id, manual_transaction.clone())
+            .await;
+        let transaction = if manual_transaction.entity.is_posted() {
+            loader
+                .load_one(manual_transaction.entity.ledger_transaction_id)
+                .await?
+        } else {
+            None
+        };
+        Ok(LedgerManualTransactionExecutePayload::new(
+            manual_transaction,
+            transaction,
+        ))
+    }
+
+    async fn ledger_manual_transaction_draft_update(
+        &self,
+        ctx: &Context<'_>,
+        input: LedgerManualTransactionDraftUpdateInput,
+    ) -> async_graphql::Result<LedgerManualTransactionDraftUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut entries = Vec::with_capacity(input.entries.len());
+        for entry in input.entries.into_iter() {
+            entries.push(entry.try_into()?);
+        }
+
         exec_mutation!(
-            LedgerManualTransactionExecutePayload,
-            LedgerTransaction,
+            LedgerManualTransactionDraftUpdatePayload,
+            ManualTransaction,
             ctx,
-            app.accounting().execute_manual_transaction(
+            app.accounting().update_manual_transaction_draft(
                 sub,
                 CHART_REF.0,
-                input.reference,
+                input.manual_transaction_id,
                 input.description,
                 input.effective.into_inner(),
                 entries
@@ -1710,157 +2171,872 @@ impl Mutation {
         )
     }
 
-    async fn deposit_record(
+    async fn ledger_manual_transaction_reverse(
+        &self,
+        ctx: &Context<'_>,
+        input: LedgerManualTransactionReverseInput,
+    ) -> async_graphql::Result<LedgerManualTransactionReversePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let reversal = ManualTransaction::from(
+            app.accounting()
+                .reverse_manual_transaction(
+                    sub,
+                    input.ledger_transaction_id,
+                    input.effective.map(|d| d.into_inner()),
+                )
+                .await?,
+        );
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        loader.feed_one(reversal.entity.id, reversal.clone()).await;
+        let transaction = if reversal.entity.is_posted() {
+            loader
+                .load_one(reversal.entity.ledger_transaction_id)
+                .await?
+        } else {
+            None
+        };
+        Ok(LedgerManualTransactionReversePayload::new(
+            reversal,
+            transaction,
+        ))
+    }
+
+    async fn manual_transaction_schedule_create(
+        &self,
+        ctx: &Context<'_>,
+        input: ManualTransactionScheduleCreateInput,
+    ) -> async_graphql::Result<ManualTransactionScheduleCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut entries = Vec::with_capacity(input.entries.len());
+        for entry in input.entries.into_iter() {
+            entries.push(entry.try_into()?);
+        }
+
+        exec_mutation!(
+            ManualTransactionScheduleCreatePayload,
+            ManualTransactionSchedule,
+            ctx,
+            app.accounting().manual_transaction_schedules().create(
+                sub,
+                CHART_REF.0,
+                input.reference,
+                input.description,
+                input.frequency,
+                input.start_date.into_inner(),
+                input.end_date.map(|d| d.into_inner()),
+                input.auto_reverse,
+                entries
+            )
+        )
+    }
+
+    async fn manual_transaction_schedule_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: ManualTransactionScheduleCancelInput,
+    ) -> async_graphql::Result<ManualTransactionScheduleCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ManualTransactionScheduleCancelPayload,
+            ManualTransactionSchedule,
+            ctx,
+            app.accounting()
+                .manual_transaction_schedules()
+                .cancel(sub, input.manual_transaction_schedule_id)
+        )
+    }
+
+    async fn deposit_record(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositRecordInput,
+    ) -> async_graphql::Result<DepositRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            DepositRecordPayload,
+            Deposit,
+            ctx,
+            app.deposits().record_deposit_with_channel(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.channel.unwrap_or_default(),
+                input.reference
+            )
+        )
+    }
+
+    async fn deposit_import_create(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositImportCreateInput,
+    ) -> async_graphql::Result<DepositImportCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?;
+        let mut data = Vec::new();
+        file.content.read_to_end(&mut data)?;
+
+        exec_mutation!(
+            DepositImportCreatePayload,
+            DepositImport,
+            ctx,
+            app.deposits().import_deposits(sub, file.filename, data)
+        )
+    }
+
+    async fn beneficiary_create(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryCreateInput,
+    ) -> async_graphql::Result<BeneficiaryCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BeneficiaryCreatePayload,
+            Beneficiary,
+            ctx,
+            app.deposits().create_beneficiary(
+                sub,
+                input.customer_id,
+                input.name,
+                input.destination.into()
+            )
+        )
+    }
+
+    async fn beneficiary_verify(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryVerifyInput,
+    ) -> async_graphql::Result<BeneficiaryVerifyPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BeneficiaryVerifyPayload,
+            Beneficiary,
+            ctx,
+            app.deposits().verify_beneficiary(sub, input.beneficiary_id)
+        )
+    }
+
+    async fn beneficiary_reject(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryRejectInput,
+    ) -> async_graphql::Result<BeneficiaryRejectPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BeneficiaryRejectPayload,
+            Beneficiary,
+            ctx,
+            app.deposits()
+                .reject_beneficiary(sub, input.beneficiary_id, input.reason)
+        )
+    }
+
+    async fn beneficiary_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryRemoveInput,
+    ) -> async_graphql::Result<BeneficiaryRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BeneficiaryRemovePayload,
+            Beneficiary,
+            ctx,
+            app.deposits().remove_beneficiary(sub, input.beneficiary_id)
+        )
+    }
+
+    async fn payment_originator_update(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentOriginatorUpdateInput,
+    ) -> async_graphql::Result<PaymentOriginatorUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let originator = app
+            .deposits()
+            .update_payment_originator(sub, input.into())
+            .await?;
+        Ok(PaymentOriginatorUpdatePayload::from(
+            PaymentOriginator::from(originator),
+        ))
+    }
+
+    async fn payment_file_generate(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<PaymentFileGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because no payment file is generated without pending withdrawals
+        let payment_file = app
+            .deposits()
+            .generate_withdrawal_payment_file(sub)
+            .await?
+            .map(PaymentFile::from);
+        if let Some(payment_file) = &payment_file {
+            let loader = ctx.data_unchecked::<LanaDataLoader>();
+            loader
+                .feed_one(payment_file.entity.id, payment_file.clone())
+                .await;
+        }
+        Ok(PaymentFileGeneratePayload::from(payment_file))
+    }
+
+    async fn payment_status_report_import(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentStatusReportImportInput,
+    ) -> async_graphql::Result<PaymentStatusReportImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?.content;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+
+        exec_mutation!(
+            PaymentStatusReportImportPayload,
+            PaymentFile,
+            ctx,
+            app.deposits().import_payment_status_report(sub, data)
+        )
+    }
+
+    async fn payment_file_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentFileDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<PaymentFileDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because PaymentFileDownloadLinkGeneratePayload is non standard
+        let link = app
+            .deposits()
+            .generate_payment_file_download_link(sub, input.payment_file_id)
+            .await?;
+        Ok(PaymentFileDownloadLinkGeneratePayload::from(link))
+    }
+
+    async fn withdrawal_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalInitiateInput,
+    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalInitiatePayload,
+            Withdrawal,
+            ctx,
+            app.deposits().initiate_withdrawal(
+                sub,
+                input.deposit_account_id,
+                input.beneficiary_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    async fn transfer_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferInitiateInput,
+    ) -> async_graphql::Result<TransferInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TransferInitiatePayload,
+            Transfer,
+            ctx,
+            app.deposits().initiate_transfer(
+                sub,
+                input.from_account_id,
+                input.to_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    async fn withdrawal_confirm(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalConfirmInput,
+    ) -> async_graphql::Result<WithdrawalConfirmPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            WithdrawalConfirmPayload,
+            Withdrawal,
+            ctx,
+            app.deposits().confirm_withdrawal(sub, input.withdrawal_id)
+        )
+    }
+
+    async fn withdrawal_sign(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalSignInput,
+    ) -> async_graphql::Result<WithdrawalSignPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalSignPayload,
+            Withdrawal,
+            ctx,
+            app.deposits()
+                .sign_withdrawal(sub, input.withdrawal_id, input.customer_id)
+        )
+    }
+
+    async fn withdrawal_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalCancelInput,
+    ) -> async_graphql::Result<WithdrawalCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalCancelPayload,
+            Withdrawal,
+            ctx,
+            app.deposits().cancel_withdrawal(sub, input.withdrawal_id)
+        )
+    }
+
+    async fn withdrawal_revert(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalRevertInput,
+    ) -> async_graphql::Result<WithdrawalRevertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalRevertPayload,
+            Withdrawal,
+            ctx,
+            app.deposits().revert_withdrawal(sub, input.withdrawal_id)
+        )
+    }
+
+    async fn deposit_revert(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositRevertInput,
+    ) -> async_graphql::Result<DepositRevertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositRevertPayload,
+            Deposit,
+            ctx,
+            app.deposits().revert_deposit(sub, input.deposit_id)
+        )
+    }
+
+    async fn deposit_account_create(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountCreateInput,
+    ) -> async_graphql::Result<DepositAccountCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            DepositAccountCreatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().create_account_in_currency(
+                sub,
+                input.customer_id,
+                input.currency.unwrap_or(CurrencyCode::USD)
+            )
+        )
+    }
+
+    async fn deposit_account_currency_convert(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountCurrencyConvertInput,
+    ) -> async_graphql::Result<DepositAccountCurrencyConvertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amount =
+            lana_app::primitives::Money::try_from_major(input.currency, input.amount.into())?;
+        exec_mutation!(
+            DepositAccountCurrencyConvertPayload,
+            FxConversion,
+            ctx,
+            app.deposits().convert_currency(
+                sub,
+                input.source_account_id,
+                input.target_account_id,
+                amount,
+                input.rate.into(),
+                input.reference
+            )
+        )
+    }
+
+    async fn deposit_account_freeze(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountFreezeInput,
+    ) -> async_graphql::Result<DepositAccountFreezePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountFreezePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().freeze_account(sub, input.deposit_account_id)
+        )
+    }
+
+    async fn deposit_account_unfreeze(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountUnfreezeInput,
+    ) -> async_graphql::Result<DepositAccountUnfreezePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountUnfreezePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .unfreeze_account(sub, input.deposit_account_id)
+        )
+    }
+
+    async fn deposit_account_joint_holder_add(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountJointHolderAddInput,
+    ) -> async_graphql::Result<DepositAccountJointHolderAddPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountJointHolderAddPayload,
+            DepositAccount,
+            ctx,
+            app.deposits().add_joint_account_holder(
+                sub,
+                input.deposit_account_id,
+                input.customer_id,
+                input.signing_rule
+            )
+        )
+    }
+
+    async fn deposit_account_joint_holder_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountJointHolderRemoveInput,
+    ) -> async_graphql::Result<DepositAccountJointHolderRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountJointHolderRemovePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().remove_joint_account_holder(
+                sub,
+                input.deposit_account_id,
+                input.customer_id
+            )
+        )
+    }
+
+    async fn deposit_account_holder_signing_rule_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountHolderSigningRuleUpdateInput,
+    ) -> async_graphql::Result<DepositAccountHolderSigningRuleUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountHolderSigningRuleUpdatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().update_account_holder_signing_rule(
+                sub,
+                input.deposit_account_id,
+                input.customer_id,
+                input.signing_rule
+            )
+        )
+    }
+
+    async fn deposit_account_close(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountCloseInput,
+    ) -> async_graphql::Result<DepositAccountClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountClosePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().close_account(sub, input.deposit_account_id)
+        )
+    }
+
+    async fn deposit_account_closure_initiate(
         &self,
         ctx: &Context<'_>,
-        input: DepositRecordInput,
-    ) -> async_graphql::Result<DepositRecordPayload> {
+        input: DepositAccountClosureInitiateInput,
+    ) -> async_graphql::Result<DepositAccountClosureInitiatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
-
         exec_mutation!(
-            DepositRecordPayload,
-            Deposit,
+            DepositAccountClosureInitiatePayload,
+            AccountClosure,
             ctx,
-            app.deposits().record_deposit(
+            app.deposits().initiate_account_closure(
                 sub,
                 input.deposit_account_id,
-                input.amount,
-                input.reference
+                input.payout.into(),
+                input.hold_treatment
             )
         )
     }
 
-    async fn withdrawal_initiate(
+    async fn account_closing_statement_download_link_generate(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalInitiateInput,
-    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        input: AccountClosingStatementDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<AccountClosingStatementDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because AccountClosingStatementDownloadLinkGeneratePayload is non standard
+        let link = app
+            .deposits()
+            .generate_account_closing_statement_download_link(sub, input.account_closure_id)
+            .await?;
+        Ok(AccountClosingStatementDownloadLinkGeneratePayload::from(
+            link,
+        ))
+    }
+
+    async fn aml_alert_review_start(
+        &self,
+        ctx: &Context<'_>,
+        input: AmlAlertReviewStartInput,
+    ) -> async_graphql::Result<AmlAlertReviewStartPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            WithdrawalInitiatePayload,
-            Withdrawal,
+            AmlAlertReviewStartPayload,
+            AmlAlert,
             ctx,
-            app.deposits().initiate_withdrawal(
-                sub,
-                input.deposit_account_id,
-                input.amount,
-                input.reference
-            )
+            app.aml().start_alert_review(sub, input.aml_alert_id)
         )
     }
 
-    async fn withdrawal_confirm(
+    async fn aml_alert_note_add(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalConfirmInput,
-    ) -> async_graphql::Result<WithdrawalConfirmPayload> {
+        input: AmlAlertNoteAddInput,
+    ) -> async_graphql::Result<AmlAlertNoteAddPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
-
         exec_mutation!(
-            WithdrawalConfirmPayload,
-            Withdrawal,
+            AmlAlertNoteAddPayload,
+            AmlAlert,
             ctx,
-            app.deposits().confirm_withdrawal(sub, input.withdrawal_id)
+            app.aml()
+                .add_alert_note(sub, input.aml_alert_id, input.note)
         )
     }
 
-    async fn withdrawal_cancel(
+    async fn aml_alert_escalate(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalCancelInput,
-    ) -> async_graphql::Result<WithdrawalCancelPayload> {
+        input: AmlAlertEscalateInput,
+    ) -> async_graphql::Result<AmlAlertEscalatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            WithdrawalCancelPayload,
-            Withdrawal,
+            AmlAlertEscalatePayload,
+            AmlAlert,
             ctx,
-            app.deposits().cancel_withdrawal(sub, input.withdrawal_id)
+            app.aml()
+                .escalate_alert(sub, input.aml_alert_id, input.reason)
         )
     }
 
-    async fn withdrawal_revert(
+    async fn aml_alert_dismiss(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalRevertInput,
-    ) -> async_graphql::Result<WithdrawalRevertPayload> {
+        input: AmlAlertDismissInput,
+    ) -> async_graphql::Result<AmlAlertDismissPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            WithdrawalRevertPayload,
-            Withdrawal,
+            AmlAlertDismissPayload,
+            AmlAlert,
             ctx,
-            app.deposits().revert_withdrawal(sub, input.withdrawal_id)
+            app.aml()
+                .dismiss_alert(sub, input.aml_alert_id, input.reason)
         )
     }
 
-    async fn deposit_revert(
+    async fn aml_alert_customer_freeze(
         &self,
         ctx: &Context<'_>,
-        input: DepositRevertInput,
-    ) -> async_graphql::Result<DepositRevertPayload> {
+        input: AmlAlertCustomerFreezeInput,
+    ) -> async_graphql::Result<AmlAlertCustomerFreezePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            DepositRevertPayload,
-            Deposit,
+            AmlAlertCustomerFreezePayload,
+            AmlAlert,
             ctx,
-            app.deposits().revert_deposit(sub, input.deposit_id)
+            app.aml().freeze_customer_for_alert(sub, input.aml_alert_id)
         )
     }
 
-    async fn deposit_account_create(
+    async fn aml_alert_sar_draft_generate(
         &self,
         ctx: &Context<'_>,
-        input: DepositAccountCreateInput,
-    ) -> async_graphql::Result<DepositAccountCreatePayload> {
+        input: AmlAlertSarDraftGenerateInput,
+    ) -> async_graphql::Result<AmlAlertSarDraftGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let report_run = app
+            .aml()
+            .generate_suspicious_activity_report_draft(sub, input.aml_alert_id)
+            .await?;
+        Ok(AmlAlertSarDraftGeneratePayload {
+            report_run: report_run.into(),
+        })
+    }
+
+    async fn aml_currency_transaction_report_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: AmlCurrencyTransactionReportGenerateInput,
+    ) -> async_graphql::Result<AmlCurrencyTransactionReportGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let report_run = app
+            .aml()
+            .generate_currency_transaction_report(sub, input.date.into())
+            .await?;
+        Ok(AmlCurrencyTransactionReportGeneratePayload {
+            report_run: report_run.into(),
+        })
+    }
+
+    async fn deposit_account_statement_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountStatementGenerateInput,
+    ) -> async_graphql::Result<DepositAccountStatementGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let statement = app
+            .deposits()
+            .generate_account_statement(
+                sub,
+                input.deposit_account_id,
+                input.period_start.into_inner(),
+                input.period_end.into_inner(),
+                input.format,
+            )
+            .await?;
+        Ok(DepositAccountStatementGeneratePayload::from(
+            DepositAccountStatement::from(statement),
+        ))
+    }
+
+    async fn deposit_account_statement_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountStatementDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<DepositAccountStatementDownloadLinkGeneratePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because DepositAccountStatementDownloadLinkGeneratePayload is non standard
+        let link = app
+            .deposits()
+            .generate_account_statement_download_link(sub, input.deposit_account_statement_id)
+            .await?;
+        Ok(DepositAccountStatementDownloadLinkGeneratePayload::from(
+            link,
+        ))
+    }
 
+    async fn deposit_account_escheated_balance_reclaim(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountEscheatedBalanceReclaimInput,
+    ) -> async_graphql::Result<DepositAccountEscheatedBalanceReclaimPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            DepositAccountCreatePayload,
+            DepositAccountEscheatedBalanceReclaimPayload,
             DepositAccount,
             ctx,
-            app.deposits().create_account(sub, input.customer_id)
+            app.deposits()
+                .reclaim_escheated_balance(sub, input.deposit_account_id)
         )
     }
 
-    async fn deposit_account_freeze(
+    async fn deposit_account_withdrawal_limit_increase_request(
         &self,
         ctx: &Context<'_>,
-        input: DepositAccountFreezeInput,
-    ) -> async_graphql::Result<DepositAccountFreezePayload> {
+        input: DepositAccountWithdrawalLimitIncreaseRequestInput,
+    ) -> async_graphql::Result<DepositAccountWithdrawalLimitIncreaseRequestPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            DepositAccountFreezePayload,
+            DepositAccountWithdrawalLimitIncreaseRequestPayload,
             DepositAccount,
             ctx,
-            app.deposits().freeze_account(sub, input.deposit_account_id)
+            app.deposits().request_withdrawal_limit_increase(
+                sub,
+                DepositAccountId::from(input.deposit_account_id),
+                DomainWithdrawalLimits {
+                    daily: input.daily,
+                    weekly: input.weekly,
+                    monthly: input.monthly,
+                }
+            )
         )
     }
 
-    async fn deposit_account_unfreeze(
+    async fn deposit_withdrawal_limits_update(
         &self,
         ctx: &Context<'_>,
-        input: DepositAccountUnfreezeInput,
-    ) -> async_graphql::Result<DepositAccountUnfreezePayload> {
+        input: DepositWithdrawalLimitsUpdateInput,
+    ) -> async_graphql::Result<DepositWithdrawalLimitsUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let limits = app
+            .deposits()
+            .update_withdrawal_limits(
+                sub,
+                input.customer_type,
+                DomainWithdrawalLimits {
+                    daily: input.daily,
+                    weekly: input.weekly,
+                    monthly: input.monthly,
+                },
+            )
+            .await?;
+        Ok(DepositWithdrawalLimitsUpdatePayload::from(
+            WithdrawalLimits::from(limits),
+        ))
+    }
+
+    async fn deposit_account_hold_place(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountHoldPlaceInput,
+    ) -> async_graphql::Result<DepositAccountHoldPlacePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let hold = app
+            .deposits()
+            .place_hold(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.reason,
+                input.expires_at.into_inner(),
+            )
+            .await?;
+        Ok(DepositAccountHoldPlacePayload::from(Hold::from(hold)))
+    }
+
+    async fn deposit_account_hold_extend(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountHoldExtendInput,
+    ) -> async_graphql::Result<DepositAccountHoldExtendPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let hold = app
+            .deposits()
+            .extend_hold(sub, input.hold_id, input.expires_at.into_inner())
+            .await?;
+        Ok(DepositAccountHoldExtendPayload::from(Hold::from(hold)))
+    }
+
+    async fn deposit_account_hold_release(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountHoldReleaseInput,
+    ) -> async_graphql::Result<DepositAccountHoldReleasePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let hold = app.deposits().release_hold(sub, input.hold_id).await?;
+        Ok(DepositAccountHoldReleasePayload::from(Hold::from(hold)))
+    }
+
+    async fn deposit_product_create(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductCreateInput,
+    ) -> async_graphql::Result<DepositProductCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let values = build_deposit_product_values(input.values)?;
+        let product = app
+            .deposits()
+            .create_deposit_product(sub, input.name, values)
+            .await?;
+        Ok(DepositProductCreatePayload::from(DepositProduct::from(
+            product,
+        )))
+    }
+
+    async fn deposit_product_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductUpdateInput,
+    ) -> async_graphql::Result<DepositProductUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let values = build_deposit_product_values(input.values)?;
+        let product = app
+            .deposits()
+            .update_deposit_product(
+                sub,
+                DepositProductId::from(input.deposit_product_id),
+                values,
+            )
+            .await?;
+        Ok(DepositProductUpdatePayload::from(DepositProduct::from(
+            product,
+        )))
+    }
+
+    async fn deposit_product_fee_schedule_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductFeeScheduleUpdateInput,
+    ) -> async_graphql::Result<DepositProductFeeScheduleUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let deposit_product_id = DepositProductId::from(input.deposit_product_id);
+        app.deposits()
+            .update_deposit_fee_schedule(sub, deposit_product_id, input.into())
+            .await?;
+        let product = app
+            .deposits()
+            .find_deposit_product_by_id(sub, deposit_product_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit product not found"))?;
+        Ok(DepositProductFeeScheduleUpdatePayload::from(
+            DepositProduct::from(product),
+        ))
+    }
+
+    async fn deposit_account_product_assign(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountProductAssignInput,
+    ) -> async_graphql::Result<DepositAccountProductAssignPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            DepositAccountUnfreezePayload,
+            DepositAccountProductAssignPayload,
             DepositAccount,
             ctx,
-            app.deposits()
-                .unfreeze_account(sub, input.deposit_account_id)
+            app.deposits().assign_deposit_product(
+                sub,
+                DepositAccountId::from(input.deposit_account_id),
+                DepositProductId::from(input.deposit_product_id)
+            )
         )
     }
 
-    async fn deposit_account_close(
+    async fn deposit_account_fee_reverse(
         &self,
         ctx: &Context<'_>,
-        input: DepositAccountCloseInput,
-    ) -> async_graphql::Result<DepositAccountClosePayload> {
+        input: DepositAccountFeeReverseInput,
+    ) -> async_graphql::Result<DepositAccountFeeReversePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            DepositAccountClosePayload,
+            DepositAccountFeeReversePayload,
             DepositAccount,
             ctx,
-            app.deposits().close_account(sub, input.deposit_account_id)
+            app.deposits().reverse_deposit_fee(
+                sub,
+                DepositAccountId::from(input.deposit_account_id),
+                input.ledger_transaction_id
+            )
         )
     }
 
@@ -2245,6 +3421,26 @@ impl Mutation {
         )
     }
 
+    async fn credit_facility_participation_add(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityParticipationAddInput,
+    ) -> async_graphql::Result<CreditFacilityParticipationAddPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let participation = app
+            .credit()
+            .add_participation(
+                sub,
+                input.credit_facility_id.into(),
+                input.participant_name,
+                input.share,
+            )
+            .await?;
+        Ok(CreditFacilityParticipationAddPayload::from(
+            CreditFacilityParticipation::from(participation),
+        ))
+    }
+
     async fn credit_facility_complete(
         &self,
         ctx: &Context<'_>,
@@ -2654,6 +3850,69 @@ impl Mutation {
         )
     }
 
+    async fn cash_flow_section_chart_node_add(
+        &self,
+        ctx: &Context<'_>,
+        input: CashFlowSectionChartNodeAddInput,
+    ) -> async_graphql::Result<CashFlowSectionChartNodeAddPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CashFlowSectionChartNodeAddPayload,
+            ChartOfAccounts,
+            ChartId,
+            ctx,
+            app.accounting().add_chart_node_to_cash_flow_section(
+                sub,
+                CHART_REF.0,
+                CASH_FLOW_STATEMENT_NAME,
+                input.code.try_into()?,
+                input.section,
+            )
+        )
+    }
+
+    async fn cash_flow_section_chart_node_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: CashFlowSectionChartNodeRemoveInput,
+    ) -> async_graphql::Result<CashFlowSectionChartNodeRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CashFlowSectionChartNodeRemovePayload,
+            ChartOfAccounts,
+            ChartId,
+            ctx,
+            app.accounting().remove_chart_node_from_cash_flow_section(
+                sub,
+                CHART_REF.0,
+                CASH_FLOW_STATEMENT_NAME,
+                input.code.try_into()?,
+                input.section,
+            )
+        )
+    }
+
+    async fn cash_flow_statement_csv_create(
+        &self,
+        ctx: &Context<'_>,
+        input: CashFlowStatementCsvCreateInput,
+    ) -> async_graphql::Result<CashFlowStatementCsvCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let csv = app
+            .accounting()
+            .csvs()
+            .create_cash_flow_csv(
+                sub,
+                CASH_FLOW_STATEMENT_NAME.to_string(),
+                input.from.into_inner(),
+                input.until.into_inner(),
+            )
+            .await?;
+
+        let csv_document = LedgerAccountCsvDocument::from(csv);
+        Ok(CashFlowStatementCsvCreatePayload::from(csv_document))
+    }
+
     async fn ledger_account_csv_create(
         &self,
         ctx: &Context<'_>,
@@ -2900,6 +4159,38 @@ impl Subscription {
         }))
     }
 
+    async fn deposit_import_updated(
+        &self,
+        ctx: &Context<'_>,
+        deposit_import_id: UUID,
+    ) -> async_graphql::Result<impl Stream<Item = DepositImport>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let id = DepositImportId::from(deposit_import_id);
+
+        app.deposits()
+            .find_deposit_import_by_id(sub, id)
+            .await?
+            .ok_or_else(|| Error::new("DepositImport not found"))?;
+
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let outbox_stream = app.outbox().listen_persisted(None);
+        Ok(outbox_stream.filter_map(move |event| async move {
+            let payload = event.payload.as_ref()?;
+            let deposit_event: &CoreDepositEvent = payload.as_event()?;
+            let matches = matches!(
+                deposit_event,
+                CoreDepositEvent::DepositImportProgressed { entity }
+                | CoreDepositEvent::DepositImportCompleted { entity }
+                if entity.id == id
+            );
+            if matches {
+                loader.load_one(id).await.ok().flatten()
+            } else {
+                None
+            }
+        }))
+    }
+
     async fn disbursal_updated(
         &self,
         ctx: &Context<'_>,
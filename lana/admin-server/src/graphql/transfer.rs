@@ -0,0 +1,181 @@
+use async_graphql::{connection::*, *};
+use es_entity::Sort;
+
+use crate::primitives::*;
+
+use super::{
+    accounting::LedgerTransaction,
+    approval_process::ApprovalProcess,
+    deposit_account::DepositAccount,
+    event_timeline::{self, EventTimelineCursor, EventTimelineEntry},
+    loader::LanaDataLoader,
+    primitives::SortDirection,
+};
+
+pub use lana_app::{
+    deposit::{
+        Transfer as DomainTransfer, TransferStatus, TransfersCursor,
+        TransfersFilters as DomainTransfersFilters, TransfersSortBy as DomainTransfersSortBy,
+    },
+    public_id::PublicId,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(
+    complex,
+    directive = crate::graphql::entity_key::entity_key::apply("transferId".to_string())
+)]
+pub struct Transfer {
+    transfer_id: UUID,
+    from_account_id: UUID,
+    to_account_id: UUID,
+    approval_process_id: Option<UUID>,
+    amount: UsdCents,
+    currency: CurrencyCode,
+    status: TransferStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<DomainTransfer> for Transfer {
+    fn from(transfer: DomainTransfer) -> Self {
+        Transfer {
+            created_at: transfer.created_at().into(),
+            transfer_id: UUID::from(transfer.id),
+            from_account_id: transfer.from_account_id.into(),
+            to_account_id: transfer.to_account_id.into(),
+            approval_process_id: transfer.approval_process_id.map(UUID::from),
+            amount: transfer.amount,
+            currency: transfer.currency,
+            status: transfer.status(),
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn public_id(&self) -> &PublicId {
+        &self.entity.public_id
+    }
+
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .ok_or_else(|| Error::new("Approval process not found"))?;
+        Ok(Some(process))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    async fn from_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.from_account_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit account not found"))?;
+        Ok(account)
+    }
+
+    async fn to_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.to_account_id)
+            .await?
+            .ok_or_else(|| Error::new("Deposit account not found"))?;
+        Ok(account)
+    }
+
+    async fn event_history(
+        &self,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<EventTimelineCursor, EventTimelineEntry, EmptyFields, EmptyFields>,
+    > {
+        use es_entity::EsEntity as _;
+        event_timeline::events_to_connection(self.entity.events(), first, after)
+    }
+
+    async fn ledger_transactions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<LedgerTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let tx_ids = self.entity.ledger_tx_ids();
+        let loaded_transactions = loader.load_many(tx_ids.iter().copied()).await?;
+
+        Ok(tx_ids
+            .iter()
+            .filter_map(|id| loaded_transactions.get(id).cloned())
+            .collect())
+    }
+}
+
+#[derive(InputObject)]
+pub struct TransferInitiateInput {
+    pub from_account_id: UUID,
+    pub to_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { TransferInitiatePayload, transfer: Transfer }
+
+#[derive(InputObject)]
+pub struct TransfersFilter {
+    pub status: Option<TransferStatus>,
+}
+
+#[derive(async_graphql::Enum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransfersSortBy {
+    #[default]
+    CreatedAt,
+    Amount,
+    PublicId,
+}
+
+impl From<TransfersSortBy> for DomainTransfersSortBy {
+    fn from(by: TransfersSortBy) -> Self {
+        match by {
+            TransfersSortBy::CreatedAt => DomainTransfersSortBy::CreatedAt,
+            TransfersSortBy::Amount => DomainTransfersSortBy::Amount,
+            TransfersSortBy::PublicId => DomainTransfersSortBy::PublicId,
+        }
+    }
+}
+
+#[derive(InputObject, Default, Debug, Clone, Copy)]
+pub struct TransfersSort {
+    #[graphql(default)]
+    pub by: TransfersSortBy,
+    #[graphql(default)]
+    pub direction: SortDirection,
+}
+
+impl From<TransfersSort> for Sort<DomainTransfersSortBy> {
+    fn from(sort: TransfersSort) -> Self {
+        Self {
+            by: sort.by.into(),
+            direction: sort.direction.into(),
+        }
+    }
+}
+
+impl From<TransfersSort> for DomainTransfersSortBy {
+    fn from(sort: TransfersSort) -> Self {
+        sort.by.into()
+    }
+}
@@ -6,9 +6,11 @@ use crate::primitives::*;
 use super::{
     accounting::LedgerTransaction,
     approval_process::ApprovalProcess,
+    beneficiary::{Beneficiary, BeneficiaryDestination},
     deposit_account::DepositAccount,
     event_timeline::{self, EventTimelineCursor, EventTimelineEntry},
     loader::LanaDataLoader,
+    payment_file::PaymentFile,
     primitives::SortDirection,
 };
 
@@ -31,6 +33,7 @@ pub struct Withdrawal {
     account_id: UUID,
     approval_process_id: UUID,
     amount: UsdCents,
+    currency: CurrencyCode,
     status: WithdrawalStatus,
     created_at: Timestamp,
 
@@ -46,6 +49,7 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             withdrawal_id: UUID::from(withdraw.id),
             approval_process_id: UUID::from(withdraw.approval_process_id),
             amount: withdraw.amount,
+            currency: withdraw.currency,
             status: withdraw.status(),
             entity: Arc::new(withdraw),
         }
@@ -62,6 +66,41 @@ impl Withdrawal {
         &self.entity.reference
     }
 
+    async fn destination(&self) -> Option<BeneficiaryDestination> {
+        self.entity
+            .destination
+            .clone()
+            .map(BeneficiaryDestination::from)
+    }
+
+    async fn beneficiary(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Beneficiary>> {
+        let Some(beneficiary_id) = self.entity.beneficiary_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(beneficiary_id).await?)
+    }
+
+    async fn payment_file(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<PaymentFile>> {
+        let Some(payment_file_id) = self.entity.payment_file_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(payment_file_id).await?)
+    }
+
+    async fn required_signer_ids(&self) -> Vec<UUID> {
+        self.entity
+            .required_signers
+            .iter()
+            .map(|id| UUID::from(*id))
+            .collect()
+    }
+
+    async fn signer_ids(&self) -> Vec<UUID> {
+        self.entity.signers().into_iter().map(UUID::from).collect()
+    }
+
     async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let process = loader
@@ -109,6 +148,7 @@ impl Withdrawal {
 #[derive(InputObject)]
 pub struct WithdrawalInitiateInput {
     pub deposit_account_id: UUID,
+    pub beneficiary_id: UUID,
     pub amount: UsdCents,
     pub reference: Option<String>,
 }
@@ -120,6 +160,13 @@ pub struct WithdrawalConfirmInput {
 }
 crate::mutation_payload! { WithdrawalConfirmPayload, withdrawal: Withdrawal }
 
+#[derive(InputObject)]
+pub struct WithdrawalSignInput {
+    pub withdrawal_id: UUID,
+    pub customer_id: UUID,
+}
+crate::mutation_payload! { WithdrawalSignPayload, withdrawal: Withdrawal }
+
 #[derive(InputObject)]
 pub struct WithdrawalCancelInput {
     pub withdrawal_id: UUID,
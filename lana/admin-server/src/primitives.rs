@@ -8,13 +8,15 @@ pub use lana_app::price::PriceProviderId;
 pub use lana_app::{
     accounting::LedgerAccountId,
     primitives::{
-        ApprovalProcessId, ChartId, CollateralDirection, CollateralId, CommitteeId,
-        CreditFacilityId, CreditFacilityProposalId, CreditFacilityProposalStatus, CustodianId,
-        CustomerDocumentId, CustomerId, DepositAccountId, DepositId, DisbursalId, DisbursalStatus,
-        FiscalYearId, LedgerTransactionId, LiquidationId, PendingCreditFacilityId,
+        AccountClosureId, ApprovalProcessId, BeneficiaryId, ChartId, CollateralDirection,
+        CollateralId, CommitteeId, CreditFacilityId, CreditFacilityProposalId,
+        CreditFacilityProposalStatus, CurrencyCode, CustodianId, CustomerDocumentId, CustomerId,
+        DepositAccountId, DepositId, DepositImportId, DepositProductId, DisbursalId,
+        DisbursalStatus, FiscalYearId, FxConversionId, LedgerTransactionId, LiquidationId,
+        ManualTransactionId, ManualTransactionScheduleId, PaymentFileId, PendingCreditFacilityId,
         PendingCreditFacilityStatus, PermissionSetId, PolicyId, ProspectId, RoleId, Satoshis,
-        SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, UsdCents, UserId, WalletId,
-        WithdrawalId,
+        SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, TransferId, UsdCents, UserId,
+        WalletId, WithdrawalId,
     },
     terms::{CollateralizationState, PendingCreditFacilityCollateralizationState},
 };
@@ -0,0 +1,347 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{AmlAlertId, AmlRule, CustomerId, UsdCents};
+
+use super::error::AmlAlertError;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum AmlAlertStatus {
+    #[default]
+    Open,
+    UnderReview,
+    Escalated,
+    Dismissed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "AmlAlertId")]
+pub enum AmlAlertEvent {
+    Initialized {
+        id: AmlAlertId,
+        customer_id: CustomerId,
+        account_id: uuid::Uuid,
+        rule: AmlRule,
+        amount: UsdCents,
+        transaction_ids: Vec<uuid::Uuid>,
+        details: String,
+        detected_at: DateTime<Utc>,
+        status: AmlAlertStatus,
+    },
+    ReviewStarted {
+        status: AmlAlertStatus,
+    },
+    NoteAdded {
+        note: String,
+    },
+    Escalated {
+        reason: String,
+        status: AmlAlertStatus,
+    },
+    Dismissed {
+        reason: String,
+        status: AmlAlertStatus,
+    },
+    CustomerFrozen {},
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct AmlAlert {
+    pub id: AmlAlertId,
+    pub customer_id: CustomerId,
+    /// The deposit account or credit facility the triggering transaction was recorded on.
+    pub account_id: uuid::Uuid,
+    pub rule: AmlRule,
+    pub amount: UsdCents,
+    pub transaction_ids: Vec<uuid::Uuid>,
+    pub details: String,
+    pub detected_at: DateTime<Utc>,
+
+    events: EntityEvents<AmlAlertEvent>,
+}
+
+impl AmlAlert {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for aml alert")
+    }
+
+    pub fn status(&self) -> AmlAlertStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                AmlAlertEvent::Initialized { status, .. }
+                | AmlAlertEvent::ReviewStarted { status }
+                | AmlAlertEvent::Escalated { status, .. }
+                | AmlAlertEvent::Dismissed { status, .. } => Some(*status),
+                _ => None,
+            })
+            .expect("status should always exist")
+    }
+
+    pub fn notes(&self) -> Vec<&str> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                AmlAlertEvent::NoteAdded { note } => Some(note.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn resolution_reason(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            AmlAlertEvent::Escalated { reason, .. } | AmlAlertEvent::Dismissed { reason, .. } => {
+                Some(reason.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    pub fn customer_frozen(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, AmlAlertEvent::CustomerFrozen { .. }))
+    }
+
+    pub(crate) fn start_review(&mut self) -> Result<Idempotent<()>, AmlAlertError> {
+        match self.status() {
+            AmlAlertStatus::Open => (),
+            AmlAlertStatus::UnderReview => return Ok(Idempotent::AlreadyApplied),
+            _ => return Err(AmlAlertError::AlreadyResolved(self.id)),
+        }
+        self.events.push(AmlAlertEvent::ReviewStarted {
+            status: AmlAlertStatus::UnderReview,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn add_note(&mut self, note: String) -> Result<(), AmlAlertError> {
+        if note.trim().is_empty() {
+            return Err(AmlAlertError::EmptyNote);
+        }
+        self.events.push(AmlAlertEvent::NoteAdded { note });
+        Ok(())
+    }
+
+    pub(crate) fn escalate(&mut self, reason: String) -> Result<Idempotent<()>, AmlAlertError> {
+        match self.status() {
+            AmlAlertStatus::Open | AmlAlertStatus::UnderReview => (),
+            AmlAlertStatus::Escalated => return Ok(Idempotent::AlreadyApplied),
+            AmlAlertStatus::Dismissed => return Err(AmlAlertError::AlreadyResolved(self.id)),
+        }
+        self.events.push(AmlAlertEvent::Escalated {
+            reason,
+            status: AmlAlertStatus::Escalated,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn dismiss(&mut self, reason: String) -> Result<Idempotent<()>, AmlAlertError> {
+        match self.status() {
+            AmlAlertStatus::Open | AmlAlertStatus::UnderReview => (),
+            AmlAlertStatus::Dismissed => return Ok(Idempotent::AlreadyApplied),
+            AmlAlertStatus::Escalated => return Err(AmlAlertError::AlreadyResolved(self.id)),
+        }
+        self.events.push(AmlAlertEvent::Dismissed {
+            reason,
+            status: AmlAlertStatus::Dismissed,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Checks that the customer flagged by this alert may still be frozen from it.
+    pub(crate) fn ensure_can_freeze_customer(&self) -> Result<(), AmlAlertError> {
+        if self.status() == AmlAlertStatus::Dismissed {
+            return Err(AmlAlertError::AlreadyResolved(self.id));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn record_customer_frozen(&mut self) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            already_applied: AmlAlertEvent::CustomerFrozen { .. }
+        );
+        self.events.push(AmlAlertEvent::CustomerFrozen {});
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<AmlAlertEvent> for AmlAlert {
+    fn try_from_events(events: EntityEvents<AmlAlertEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = AmlAlertBuilder::default();
+        for event in events.iter_all() {
+            if let AmlAlertEvent::Initialized {
+                id,
+                customer_id,
+                account_id,
+                rule,
+                amount,
+                transaction_ids,
+                details,
+                detected_at,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .customer_id(*customer_id)
+                    .account_id(*account_id)
+                    .rule(*rule)
+                    .amount(*amount)
+                    .transaction_ids(transaction_ids.clone())
+                    .details(details.clone())
+                    .detected_at(*detected_at)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewAmlAlert {
+    #[builder(setter(into))]
+    pub(super) id: AmlAlertId,
+    #[builder(setter(into))]
+    pub(super) customer_id: CustomerId,
+    pub(super) account_id: uuid::Uuid,
+    pub(super) rule: AmlRule,
+    pub(super) amount: UsdCents,
+    pub(super) transaction_ids: Vec<uuid::Uuid>,
+    #[builder(setter(into))]
+    pub(super) details: String,
+    pub(super) detected_at: DateTime<Utc>,
+    #[builder(setter(skip), default)]
+    pub(super) status: AmlAlertStatus,
+}
+
+impl NewAmlAlert {
+    pub fn builder() -> NewAmlAlertBuilder {
+        NewAmlAlertBuilder::default()
+    }
+}
+
+impl IntoEvents<AmlAlertEvent> for NewAmlAlert {
+    fn into_events(self) -> EntityEvents<AmlAlertEvent> {
+        EntityEvents::init(
+            self.id,
+            [AmlAlertEvent::Initialized {
+                id: self.id,
+                customer_id: self.customer_id,
+                account_id: self.account_id,
+                rule: self.rule,
+                amount: self.amount,
+                transaction_ids: self.transaction_ids,
+                details: self.details,
+                detected_at: self.detected_at,
+                status: self.status,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn alert() -> AmlAlert {
+        let new_alert = NewAmlAlert::builder()
+            .id(AmlAlertId::new())
+            .customer_id(CustomerId::new())
+            .account_id(uuid::Uuid::new_v4())
+            .rule(AmlRule::LargeCash)
+            .amount(UsdCents::from(2_000_000))
+            .transaction_ids(vec![uuid::Uuid::new_v4()])
+            .details("Inflow of $20000.00")
+            .detected_at(Utc::now())
+            .build()
+            .unwrap();
+        AmlAlert::try_from_events(new_alert.into_events()).unwrap()
+    }
+
+    #[test]
+    fn review_then_dismiss() {
+        let mut alert = alert();
+        assert_eq!(alert.status(), AmlAlertStatus::Open);
+
+        assert!(alert.start_review().unwrap().did_execute());
+        assert!(alert.start_review().unwrap().was_already_applied());
+        alert.add_note("Customer sold a car".to_string()).unwrap();
+        assert!(
+            alert
+                .dismiss("Documented source of funds".to_string())
+                .unwrap()
+                .did_execute()
+        );
+
+        assert_eq!(alert.status(), AmlAlertStatus::Dismissed);
+        assert_eq!(alert.notes(), vec!["Customer sold a car"]);
+        assert_eq!(
+            alert.resolution_reason(),
+            Some("Documented source of funds")
+        );
+        assert!(matches!(
+            alert.escalate("late".to_string()),
+            Err(AmlAlertError::AlreadyResolved(_))
+        ));
+        assert!(matches!(
+            alert.ensure_can_freeze_customer(),
+            Err(AmlAlertError::AlreadyResolved(_))
+        ));
+    }
+
+    #[test]
+    fn escalated_alert_can_freeze_customer_once() {
+        let mut alert = alert();
+
+        assert!(
+            alert
+                .escalate("Filed SAR".to_string())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(matches!(
+            alert.dismiss("changed mind".to_string()),
+            Err(AmlAlertError::AlreadyResolved(_))
+        ));
+        assert!(alert.ensure_can_freeze_customer().is_ok());
+        assert!(alert.record_customer_frozen().did_execute());
+        assert!(alert.record_customer_frozen().was_already_applied());
+        assert!(alert.customer_frozen());
+    }
+
+    #[test]
+    fn empty_note_is_rejected() {
+        let mut alert = alert();
+        assert!(matches!(
+            alert.add_note("  ".to_string()),
+            Err(AmlAlertError::EmptyNote)
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::AmlAlertId;
+
+use super::repo::{
+    AmlAlertCreateError, AmlAlertFindError, AmlAlertModifyError, AmlAlertQueryError,
+};
+
+#[derive(Error, Debug)]
+pub enum AmlAlertError {
+    #[error("AmlAlertError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AmlAlertError - Create: {0}")]
+    Create(#[from] AmlAlertCreateError),
+    #[error("AmlAlertError - Modify: {0}")]
+    Modify(#[from] AmlAlertModifyError),
+    #[error("AmlAlertError - Find: {0}")]
+    Find(#[from] AmlAlertFindError),
+    #[error("AmlAlertError - Query: {0}")]
+    Query(#[from] AmlAlertQueryError),
+    #[error("AmlAlertError - AlreadyResolved: {0}")]
+    AlreadyResolved(AmlAlertId),
+    #[error("AmlAlertError - EmptyNote")]
+    EmptyNote,
+}
+
+impl ErrorSeverity for AmlAlertError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Create(_) => Level::ERROR,
+            Self::Modify(_) => Level::ERROR,
+            Self::Find(_) => Level::ERROR,
+            Self::Query(_) => Level::ERROR,
+            Self::AlreadyResolved(_) => Level::WARN,
+            Self::EmptyNote => Level::WARN,
+        }
+    }
+}
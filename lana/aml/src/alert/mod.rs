@@ -0,0 +1,10 @@
+mod entity;
+pub mod error;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::AmlAlertEvent;
+pub(crate) use entity::*;
+pub use entity::{AmlAlert, AmlAlertStatus};
+pub use repo::aml_alert_cursor::AmlAlertsByCreatedAtCursor;
+pub(crate) use repo::*;
@@ -0,0 +1,140 @@
+use domain_config::{DomainConfigError, define_exposed_config};
+
+const DEFAULT_LARGE_CASH_THRESHOLD_CENTS: u64 = 1_000_000;
+const DEFAULT_STRUCTURING_MARGIN_PERCENT: u64 = 10;
+const DEFAULT_STRUCTURING_WINDOW_DAYS: u64 = 7;
+const DEFAULT_STRUCTURING_MIN_TRANSACTIONS: u64 = 3;
+const DEFAULT_RAPID_MOVEMENT_WINDOW_HOURS: u64 = 48;
+const DEFAULT_RAPID_MOVEMENT_MIN_AMOUNT_CENTS: u64 = 500_000;
+const DEFAULT_RAPID_MOVEMENT_OUTFLOW_PERCENT: u64 = 80;
+const DEFAULT_DORMANT_ACCOUNT_DAYS: u64 = 180;
+const DEFAULT_CURRENCY_TRANSACTION_REPORT_THRESHOLD_CENTS: u64 = 1_000_000;
+const DEFAULT_SUSPICIOUS_ACTIVITY_LOOKBACK_DAYS: u64 = 90;
+
+define_exposed_config! {
+    /// Amount in USD cents at or above which a single inflow raises a large cash alert.
+    /// Also the threshold that structuring monitoring looks for deposits just below.
+    pub struct AmlLargeCashThresholdCents(u64);
+    spec {
+        key: "aml-large-cash-threshold-cents";
+        default: || Some(DEFAULT_LARGE_CASH_THRESHOLD_CENTS);
+        validate: |value: &u64| validate_positive("aml-large-cash-threshold-cents", *value);
+    }
+}
+
+define_exposed_config! {
+    /// How far below the large cash threshold, as a percentage of it, an inflow still counts
+    /// towards structuring.
+    pub struct AmlStructuringMarginPercent(u64);
+    spec {
+        key: "aml-structuring-margin-percent";
+        default: || Some(DEFAULT_STRUCTURING_MARGIN_PERCENT);
+        validate: |value: &u64| validate_percent("aml-structuring-margin-percent", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Number of days over which inflows just below the large cash threshold are counted.
+    pub struct AmlStructuringWindowDays(u64);
+    spec {
+        key: "aml-structuring-window-days";
+        default: || Some(DEFAULT_STRUCTURING_WINDOW_DAYS);
+        validate: |value: &u64| validate_positive("aml-structuring-window-days", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Number of inflows just below the large cash threshold within the window that raises a
+    /// structuring alert.
+    pub struct AmlStructuringMinTransactions(u64);
+    spec {
+        key: "aml-structuring-min-transactions";
+        default: || Some(DEFAULT_STRUCTURING_MIN_TRANSACTIONS);
+        validate: |value: &u64| validate_positive("aml-structuring-min-transactions", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Number of hours after an inflow during which withdrawing it counts as rapid movement.
+    pub struct AmlRapidMovementWindowHours(u64);
+    spec {
+        key: "aml-rapid-movement-window-hours";
+        default: || Some(DEFAULT_RAPID_MOVEMENT_WINDOW_HOURS);
+        validate: |value: &u64| validate_positive("aml-rapid-movement-window-hours", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Minimum amount in USD cents that has to flow in within the window before rapid movement
+    /// is considered.
+    pub struct AmlRapidMovementMinAmountCents(u64);
+    spec {
+        key: "aml-rapid-movement-min-amount-cents";
+        default: || Some(DEFAULT_RAPID_MOVEMENT_MIN_AMOUNT_CENTS);
+        validate: |value: &u64| validate_positive("aml-rapid-movement-min-amount-cents", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Share of the inflows within the window, as a percentage, that has to be withdrawn again
+    /// to raise a rapid movement alert.
+    pub struct AmlRapidMovementOutflowPercent(u64);
+    spec {
+        key: "aml-rapid-movement-outflow-percent";
+        default: || Some(DEFAULT_RAPID_MOVEMENT_OUTFLOW_PERCENT);
+        validate: |value: &u64| validate_percent("aml-rapid-movement-outflow-percent", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Number of days without activity after which a transaction on an account raises a
+    /// dormant account alert.
+    pub struct AmlDormantAccountDays(u64);
+    spec {
+        key: "aml-dormant-account-days";
+        default: || Some(DEFAULT_DORMANT_ACCOUNT_DAYS);
+        validate: |value: &u64| validate_positive("aml-dormant-account-days", *value);
+    }
+}
+
+define_exposed_config! {
+    /// Daily total in USD cents of a customer's cash deposits at or above which the customer is
+    /// included in the currency transaction report.
+    pub struct AmlCurrencyTransactionReportThresholdCents(u64);
+    spec {
+        key: "aml-currency-transaction-report-threshold-cents";
+        default: || Some(DEFAULT_CURRENCY_TRANSACTION_REPORT_THRESHOLD_CENTS);
+        validate: |value: &u64| {
+            validate_positive("aml-currency-transaction-report-threshold-cents", *value)
+        };
+    }
+}
+
+define_exposed_config! {
+    /// Number of days of a flagged customer's activity, before the alert was raised, that a
+    /// suspicious activity report draft covers.
+    pub struct AmlSuspiciousActivityLookbackDays(u64);
+    spec {
+        key: "aml-suspicious-activity-lookback-days";
+        default: || Some(DEFAULT_SUSPICIOUS_ACTIVITY_LOOKBACK_DAYS);
+        validate: |value: &u64| validate_positive("aml-suspicious-activity-lookback-days", *value);
+    }
+}
+
+fn validate_positive(key: &str, value: u64) -> Result<(), DomainConfigError> {
+    if value == 0 {
+        return Err(DomainConfigError::InvalidState(format!(
+            "{key} must be greater than zero"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_percent(key: &str, value: u64) -> Result<(), DomainConfigError> {
+    if value == 0 || value > 100 {
+        return Err(DomainConfigError::InvalidState(format!(
+            "{key} must be between 1 and 100, got {value}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::alert::error::AmlAlertError;
+
+#[derive(Error, Debug)]
+pub enum AmlError {
+    #[error("AmlError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AmlError - JobError: {0}")]
+    Job(#[from] ::job::error::JobError),
+    #[error("AmlError - Authorization: {0}")]
+    Authorization(#[from] authz::error::AuthorizationError),
+    #[error("AmlError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("AmlError - DomainConfigError: {0}")]
+    DomainConfigError(#[from] domain_config::DomainConfigError),
+    #[error("AmlError - AmlAlertError: {0}")]
+    AmlAlertError(#[from] AmlAlertError),
+    #[error("AmlError - CustomerError: {0}")]
+    CustomerError(#[from] core_customer::error::CustomerError),
+    #[error("AmlError - ReportError: {0}")]
+    ReportError(#[from] core_report::ReportError),
+    #[error("AmlError - RegisterEventHandler: {0}")]
+    RegisterEventHandler(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl ErrorSeverity for AmlError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Job(_) => Level::ERROR,
+            Self::Authorization(e) => e.severity(),
+            Self::AuditError(e) => e.severity(),
+            Self::DomainConfigError(e) => e.severity(),
+            Self::AmlAlertError(e) => e.severity(),
+            Self::CustomerError(e) => e.severity(),
+            Self::ReportError(e) => e.severity(),
+            Self::RegisterEventHandler(_) => Level::ERROR,
+        }
+    }
+}
+
+impl From<crate::alert::AmlAlertCreateError> for AmlError {
+    fn from(e: crate::alert::AmlAlertCreateError) -> Self {
+        Self::AmlAlertError(e.into())
+    }
+}
+
+impl From<crate::alert::AmlAlertFindError> for AmlError {
+    fn from(e: crate::alert::AmlAlertFindError) -> Self {
+        Self::AmlAlertError(e.into())
+    }
+}
+
+impl From<crate::alert::AmlAlertModifyError> for AmlError {
+    fn from(e: crate::alert::AmlAlertModifyError) -> Self {
+        Self::AmlAlertError(e.into())
+    }
+}
+
+impl From<crate::alert::AmlAlertQueryError> for AmlError {
+    fn from(e: crate::alert::AmlAlertQueryError) -> Self {
+        Self::AmlAlertError(e.into())
+    }
+}
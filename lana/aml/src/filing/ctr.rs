@@ -0,0 +1,176 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use std::collections::HashMap;
+
+use crate::{
+    monitoring::RecordedTransaction,
+    primitives::{CustomerId, UsdCents},
+};
+
+use super::{FilingSubject, usd, xml::XmlWriter};
+
+pub(crate) const CURRENCY_TRANSACTION_REPORT_ID: &str = "currency_transaction_report";
+/// The report is an internal export of the data a currency transaction report is prepared
+/// from, not the regulator's filing format, and is namespaced accordingly.
+const CURRENCY_TRANSACTION_EXPORT_NAMESPACE: &str =
+    "urn:lana:internal-export:aml:currency-transaction";
+
+/// A customer's cash deposits over one day.
+#[derive(Debug)]
+pub(crate) struct CustomerCashActivity {
+    pub customer_id: CustomerId,
+    pub cash_in: UsdCents,
+    pub transactions: Vec<RecordedTransaction>,
+}
+
+impl CustomerCashActivity {
+    fn new(customer_id: CustomerId) -> Self {
+        Self {
+            customer_id,
+            cash_in: UsdCents::ZERO,
+            transactions: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, transaction: RecordedTransaction) {
+        self.cash_in += transaction.amount;
+        self.transactions.push(transaction);
+    }
+}
+
+/// Aggregates one day of cash transactions per customer and keeps the customers whose cash
+/// deposits reach the reporting threshold.
+pub(crate) fn reportable_cash_activity(
+    transactions: Vec<RecordedTransaction>,
+    threshold: UsdCents,
+) -> Vec<CustomerCashActivity> {
+    let mut order = Vec::new();
+    let mut activities: HashMap<CustomerId, CustomerCashActivity> = HashMap::new();
+    for transaction in transactions
+        .into_iter()
+        .filter(|transaction| transaction.kind.is_cash())
+    {
+        activities
+            .entry(transaction.customer_id)
+            .or_insert_with(|| {
+                order.push(transaction.customer_id);
+                CustomerCashActivity::new(transaction.customer_id)
+            })
+            .add(transaction);
+    }
+    order
+        .into_iter()
+        .filter_map(|customer_id| activities.remove(&customer_id))
+        .filter(|activity| activity.cash_in >= threshold)
+        .collect()
+}
+
+pub(crate) fn currency_transaction_report_xml(
+    date: NaiveDate,
+    threshold: UsdCents,
+    generated_at: DateTime<Utc>,
+    activities: &[CustomerCashActivity],
+    subjects: &HashMap<CustomerId, FilingSubject>,
+) -> String {
+    let mut writer = XmlWriter::new(
+        "internal_currency_transaction_export",
+        CURRENCY_TRANSACTION_EXPORT_NAMESPACE,
+        &[
+            ("report_date", date.to_string()),
+            ("threshold", usd(threshold)),
+            ("generated_at", generated_at.to_rfc3339()),
+        ],
+    );
+    for activity in activities {
+        writer.start("customer_report");
+        if let Some(subject) = subjects.get(&activity.customer_id) {
+            subject.write(&mut writer);
+        }
+        writer.element("total_cash_in", usd(activity.cash_in));
+        writer.start("transactions");
+        for transaction in &activity.transactions {
+            writer.start("transaction");
+            writer.element("transaction_id", transaction.id);
+            writer.element("account_id", transaction.account_id);
+            writer.element("type", transaction.kind);
+            writer.element("amount", usd(transaction.amount));
+            writer.element("recorded_at", transaction.recorded_at.to_rfc3339());
+            writer.end();
+        }
+        writer.end();
+        writer.end();
+    }
+    writer.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::primitives::AmlTransactionKind;
+
+    use super::*;
+
+    fn transaction(
+        customer_id: CustomerId,
+        kind: AmlTransactionKind,
+        amount: u64,
+    ) -> RecordedTransaction {
+        RecordedTransaction {
+            id: uuid::Uuid::new_v4(),
+            customer_id,
+            account_id: uuid::Uuid::new_v4(),
+            kind,
+            amount: UsdCents::from(amount),
+            recorded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn aggregates_cash_deposits_per_customer() {
+        let structured = CustomerId::new();
+        let small = CustomerId::new();
+        let wired = CustomerId::new();
+        let transactions = vec![
+            transaction(structured, AmlTransactionKind::CashDeposit, 600_000),
+            transaction(small, AmlTransactionKind::CashDeposit, 900_000),
+            transaction(structured, AmlTransactionKind::CashDeposit, 500_000),
+            transaction(small, AmlTransactionKind::Withdrawal, 900_000),
+            transaction(small, AmlTransactionKind::CreditPayment, 2_000_000),
+            transaction(wired, AmlTransactionKind::Deposit, 5_000_000),
+            transaction(wired, AmlTransactionKind::Withdrawal, 5_000_000),
+        ];
+
+        let activities = reportable_cash_activity(transactions, UsdCents::from(1_000_000));
+
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0].customer_id, structured);
+        assert_eq!(activities[0].cash_in, UsdCents::from(1_100_000));
+        assert_eq!(activities[0].transactions.len(), 2);
+    }
+
+    #[test]
+    fn renders_customer_reports() {
+        let customer_id = CustomerId::new();
+        let activities = reportable_cash_activity(
+            vec![transaction(
+                customer_id,
+                AmlTransactionKind::CashDeposit,
+                1_250_000,
+            )],
+            UsdCents::from(1_000_000),
+        );
+
+        let xml = currency_transaction_report_xml(
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            UsdCents::from(1_000_000),
+            Utc::now(),
+            &activities,
+            &HashMap::new(),
+        );
+
+        assert!(xml.contains("report_date=\"2024-03-01\" threshold=\"10000.00\""));
+        assert!(xml.contains("<total_cash_in>12500.00</total_cash_in>"));
+        assert!(xml.contains(CURRENCY_TRANSACTION_EXPORT_NAMESPACE));
+        assert!(xml.contains("<type>cash-deposit</type>"));
+        assert_eq!(xml.matches("<customer_report>").count(), 1);
+    }
+}
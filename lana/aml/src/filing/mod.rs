@@ -0,0 +1,65 @@
+mod ctr;
+mod sar;
+mod xml;
+
+use core_customer::{Customer, Party};
+
+use crate::primitives::{CustomerId, UsdCents};
+
+pub(crate) use ctr::*;
+pub(crate) use sar::*;
+use xml::XmlWriter;
+
+/// Norm under which AML filings are listed among the regulatory reports.
+pub(crate) const AML_FILING_NORM: &str = "aml";
+
+/// Identifying details of the customer a filing is about.
+pub(crate) struct FilingSubject {
+    customer_id: CustomerId,
+    public_id: String,
+    customer_type: String,
+    email: String,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    company_name: Option<String>,
+    date_of_birth: Option<String>,
+    nationality: Option<String>,
+    address: Option<String>,
+}
+
+impl FilingSubject {
+    pub fn new(customer: &Customer, party: &Party) -> Self {
+        let info = party.personal_info.as_ref();
+        Self {
+            customer_id: customer.id,
+            public_id: customer.public_id.to_string(),
+            customer_type: customer.customer_type.to_string(),
+            email: party.email.clone(),
+            first_name: info.map(|info| info.first_name.clone()),
+            last_name: info.map(|info| info.last_name.clone()),
+            company_name: info.and_then(|info| info.company_name.clone()),
+            date_of_birth: info.and_then(|info| info.date_of_birth.clone()),
+            nationality: info.and_then(|info| info.nationality.clone()),
+            address: info.and_then(|info| info.address.clone()),
+        }
+    }
+
+    fn write(&self, writer: &mut XmlWriter) {
+        writer.start("subject");
+        writer.element("customer_id", self.customer_id);
+        writer.element("public_id", &self.public_id);
+        writer.element("customer_type", &self.customer_type);
+        writer.optional_element("first_name", self.first_name.as_ref());
+        writer.optional_element("last_name", self.last_name.as_ref());
+        writer.optional_element("company_name", self.company_name.as_ref());
+        writer.optional_element("date_of_birth", self.date_of_birth.as_ref());
+        writer.optional_element("nationality", self.nationality.as_ref());
+        writer.optional_element("address", self.address.as_ref());
+        writer.element("email", &self.email);
+        writer.end();
+    }
+}
+
+fn usd(amount: UsdCents) -> String {
+    format!("{:.2}", amount.to_usd())
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    alert::AmlAlert,
+    monitoring::RecordedTransaction,
+    primitives::{AmlAlertId, AmlTransactionKind, UsdCents},
+};
+
+use super::{FilingSubject, usd, xml::XmlWriter};
+
+pub(crate) const SUSPICIOUS_ACTIVITY_REPORT_ID: &str = "suspicious_activity_report_draft";
+const SUSPICIOUS_ACTIVITY_REPORT_NAMESPACE: &str = "urn:lana:aml:suspicious-activity-report";
+
+/// Everything a suspicious activity report draft is pre-filled from.
+pub(crate) struct SuspiciousActivity<'a> {
+    pub subject: FilingSubject,
+    pub triggering_alert_id: AmlAlertId,
+    pub alerts: &'a [AmlAlert],
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub transactions: &'a [RecordedTransaction],
+}
+
+impl SuspiciousActivity<'_> {
+    fn total(&self, include: impl Fn(AmlTransactionKind) -> bool) -> (usize, UsdCents) {
+        self.transactions
+            .iter()
+            .filter(|transaction| include(transaction.kind))
+            .fold((0, UsdCents::ZERO), |(count, mut total), transaction| {
+                total += transaction.amount;
+                (count + 1, total)
+            })
+    }
+
+    fn narrative(&self) -> String {
+        let (deposits, deposited) = self.total(|kind| kind.is_deposit());
+        let (withdrawals, withdrawn) = self.total(|kind| kind == AmlTransactionKind::Withdrawal);
+        let (payments, paid) = self.total(|kind| kind == AmlTransactionKind::CreditPayment);
+        let mut narrative = format!(
+            "Between {} and {} the customer made {deposits} deposit(s) totalling {}, \
+             {withdrawals} withdrawal(s) totalling {} and {payments} credit payment(s) \
+             totalling {}.",
+            self.period_start.date_naive(),
+            self.period_end.date_naive(),
+            deposited.formatted_usd(),
+            withdrawn.formatted_usd(),
+            paid.formatted_usd(),
+        );
+        for alert in self.alerts {
+            narrative.push_str(&format!(
+                " On {} transaction monitoring raised a {} alert: {}.",
+                alert.detected_at.date_naive(),
+                alert.rule,
+                alert.details,
+            ));
+        }
+        narrative
+    }
+
+    pub fn to_xml(&self, generated_at: DateTime<Utc>) -> String {
+        let mut writer = XmlWriter::new(
+            "suspicious_activity_report",
+            SUSPICIOUS_ACTIVITY_REPORT_NAMESPACE,
+            &[
+                ("status", "draft".to_string()),
+                ("generated_at", generated_at.to_rfc3339()),
+            ],
+        );
+        self.subject.write(&mut writer);
+
+        writer.start("activity_period");
+        writer.element("start", self.period_start.to_rfc3339());
+        writer.element("end", self.period_end.to_rfc3339());
+        writer.end();
+
+        writer.start("alerts");
+        for alert in self.alerts {
+            writer.start("alert");
+            writer.element("alert_id", alert.id);
+            writer.element("triggering", alert.id == self.triggering_alert_id);
+            writer.element("rule", alert.rule);
+            writer.element("status", alert.status());
+            writer.element("account_id", alert.account_id);
+            writer.element("amount", usd(alert.amount));
+            writer.element("detected_at", alert.detected_at.to_rfc3339());
+            writer.element("details", &alert.details);
+            writer.start("notes");
+            for note in alert.notes() {
+                writer.element("note", note);
+            }
+            writer.end();
+            writer.optional_element("resolution_reason", alert.resolution_reason());
+            writer.end();
+        }
+        writer.end();
+
+        writer.start("transactions");
+        for transaction in self.transactions {
+            writer.start("transaction");
+            writer.element("transaction_id", transaction.id);
+            writer.element("account_id", transaction.account_id);
+            writer.element("type", transaction.kind);
+            writer.element("amount", usd(transaction.amount));
+            writer.element("recorded_at", transaction.recorded_at.to_rfc3339());
+            writer.end();
+        }
+        writer.end();
+
+        writer.element("narrative", self.narrative());
+        writer.finish()
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use xml_utils::escape;
+
+/// Writes the nested, namespaced XML documents submitted to the regulator.
+pub(crate) struct XmlWriter {
+    buf: String,
+    open: Vec<&'static str>,
+}
+
+impl XmlWriter {
+    pub fn new(root: &'static str, namespace: &str, attributes: &[(&str, String)]) -> Self {
+        let mut buf = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        buf.push_str(&format!("<{root} xmlns=\"{}\"", escape(namespace)));
+        for (name, value) in attributes {
+            buf.push_str(&format!(" {name}=\"{}\"", escape(value)));
+        }
+        buf.push_str(">\n");
+        Self {
+            buf,
+            open: vec![root],
+        }
+    }
+
+    pub fn start(&mut self, name: &'static str) {
+        self.indent();
+        self.buf.push_str(&format!("<{name}>\n"));
+        self.open.push(name);
+    }
+
+    pub fn end(&mut self) {
+        let name = self.open.pop().expect("no open element");
+        self.indent();
+        self.buf.push_str(&format!("</{name}>\n"));
+    }
+
+    pub fn element(&mut self, name: &str, value: impl Display) {
+        self.indent();
+        self.buf.push_str(&format!(
+            "<{name}>{}</{name}>\n",
+            escape(&value.to_string())
+        ));
+    }
+
+    pub fn optional_element(&mut self, name: &str, value: Option<impl Display>) {
+        match value {
+            Some(value) => self.element(name, value),
+            None => {
+                self.indent();
+                self.buf.push_str(&format!("<{name}/>\n"));
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> String {
+        while !self.open.is_empty() {
+            self.end();
+        }
+        self.buf
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.open.len() {
+            self.buf.push_str("  ");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn writes_nested_escaped_elements() {
+        let mut writer = XmlWriter::new("report", "urn:test", &[("date", "2024-01-01".into())]);
+        writer.start("customer");
+        writer.element("name", "Smith & <Sons>");
+        writer.optional_element("address", None::<&str>);
+        let xml = writer.finish();
+
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <report xmlns=\"urn:test\" date=\"2024-01-01\">\n  \
+             <customer>\n    \
+             <name>Smith &amp; &lt;Sons&gt;</name>\n    \
+             <address/>\n  \
+             </customer>\n\
+             </report>\n"
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use chrono::{DateTime, Duration, Utc};
+use es_entity::DbOp;
+use tracing::Span;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_credit::CoreCreditEvent;
+use core_credit_collection::CoreCreditCollectionEvent;
+use core_deposit::{CoreDepositEvent, CurrencyCode, DepositChannel};
+use domain_config::ExposedDomainConfigsReadOnly;
+use job::JobType;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use crate::{
+    alert::{AmlAlertRepo, NewAmlAlert},
+    config::*,
+    error::AmlError,
+    monitoring::MonitoringRepo,
+    primitives::*,
+    rules::{MonitoredTransaction, MonitoringSettings, TransactionDirection},
+};
+
+pub const AML_TRANSACTION_MONITORING_JOB: JobType =
+    JobType::new("outbox.aml-transaction-monitoring");
+
+pub struct TransactionMonitoringHandler<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    alerts: AmlAlertRepo,
+    monitoring: MonitoringRepo,
+    domain_configs: ExposedDomainConfigsReadOnly,
+}
+
+impl<Perms> TransactionMonitoringHandler<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<AmlModuleAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<AmlModuleObject>,
+{
+    pub fn new(
+        authz: &Perms,
+        alerts: &AmlAlertRepo,
+        monitoring: &MonitoringRepo,
+        domain_configs: &ExposedDomainConfigsReadOnly,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            alerts: alerts.clone(),
+            monitoring: monitoring.clone(),
+            domain_configs: domain_configs.clone(),
+        }
+    }
+
+    async fn settings(&self) -> Result<MonitoringSettings, AmlError> {
+        let large_cash_threshold = self
+            .domain_configs
+            .get_without_audit::<AmlLargeCashThresholdCents>()
+            .await?
+            .value();
+        let structuring_margin_percent = self
+            .domain_configs
+            .get_without_audit::<AmlStructuringMarginPercent>()
+            .await?
+            .value();
+        let structuring_window_days = self
+            .domain_configs
+            .get_without_audit::<AmlStructuringWindowDays>()
+            .await?
+            .value();
+        let structuring_min_transactions = self
+            .domain_configs
+            .get_without_audit::<AmlStructuringMinTransactions>()
+            .await?
+            .value();
+        let rapid_movement_window_hours = self
+            .domain_configs
+            .get_without_audit::<AmlRapidMovementWindowHours>()
+            .await?
+            .value();
+        let rapid_movement_min_amount = self
+            .domain_configs
+            .get_without_audit::<AmlRapidMovementMinAmountCents>()
+            .await?
+            .value();
+        let rapid_movement_outflow_percent = self
+            .domain_configs
+            .get_without_audit::<AmlRapidMovementOutflowPercent>()
+            .await?
+            .value();
+        let dormant_account_days = self
+            .domain_configs
+            .get_without_audit::<AmlDormantAccountDays>()
+            .await?
+            .value();
+
+        Ok(MonitoringSettings {
+            large_cash_threshold: UsdCents::from(large_cash_threshold),
+            structuring_margin_percent,
+            structuring_window: days(structuring_window_days),
+            structuring_min_transactions: usize::try_from(structuring_min_transactions)
+                .unwrap_or(usize::MAX),
+            rapid_movement_window: hours(rapid_movement_window_hours),
+            rapid_movement_min_amount: UsdCents::from(rapid_movement_min_amount),
+            rapid_movement_outflow_percent,
+            dormant_after: days(dormant_account_days),
+        })
+    }
+
+    async fn open_account(
+        &self,
+        op: &mut DbOp<'_>,
+        account_id: uuid::Uuid,
+        customer_id: CustomerId,
+        opened_at: DateTime<Utc>,
+    ) -> Result<(), AmlError> {
+        self.monitoring
+            .register_account_in_tx(op.tx_mut(), account_id, customer_id)
+            .await?;
+        let mut activity = self
+            .monitoring
+            .load_activity_in_tx(op.tx_mut(), customer_id)
+            .await?;
+        activity.open_account(account_id, opened_at);
+        self.monitoring
+            .persist_activity_in_tx(op.tx_mut(), customer_id, &activity)
+            .await?;
+        Ok(())
+    }
+
+    async fn record_transaction(
+        &self,
+        op: &mut DbOp<'_>,
+        kind: AmlTransactionKind,
+        tx: MonitoredTransaction,
+    ) -> Result<(), AmlError> {
+        let Some(customer_id) = self
+            .monitoring
+            .find_customer_for_account_in_tx(op.tx_mut(), tx.account_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        let settings = self.settings().await?;
+        let detected_at = tx.recorded_at;
+
+        self.monitoring
+            .record_transaction_in_tx(op.tx_mut(), customer_id, kind, &tx)
+            .await?;
+
+        let mut activity = self
+            .monitoring
+            .load_activity_in_tx(op.tx_mut(), customer_id)
+            .await?;
+        let hits = activity.record(tx, &settings);
+        self.monitoring
+            .persist_activity_in_tx(op.tx_mut(), customer_id, &activity)
+            .await?;
+
+        for hit in hits {
+            let new_alert = NewAmlAlert::builder()
+                .id(AmlAlertId::new())
+                .customer_id(customer_id)
+                .account_id(hit.account_id)
+                .rule(hit.rule)
+                .amount(hit.amount)
+                .transaction_ids(hit.transaction_ids)
+                .details(hit.details)
+                .detected_at(detected_at)
+                .build()
+                .expect("Could not build new aml alert");
+            let alert = self.alerts.create_in_op(&mut *op, new_alert).await?;
+            self.authz
+                .audit()
+                .record_system_entry_in_op(
+                    &mut *op,
+                    AML_TRANSACTION_MONITORING,
+                    AmlModuleObject::alert(alert.id),
+                    AmlModuleAction::ALERT_CREATE,
+                )
+                .await?;
+            tracing::info!(alert_id = %alert.id, rule = %alert.rule, "aml alert raised");
+        }
+        Ok(())
+    }
+
+    async fn revert_transaction(
+        &self,
+        op: &mut DbOp<'_>,
+        account_id: uuid::Uuid,
+        transaction_id: uuid::Uuid,
+    ) -> Result<(), AmlError> {
+        let Some(customer_id) = self
+            .monitoring
+            .find_customer_for_account_in_tx(op.tx_mut(), account_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        let mut activity = self
+            .monitoring
+            .load_activity_in_tx(op.tx_mut(), customer_id)
+            .await?;
+        self.monitoring
+            .mark_transaction_reverted_in_tx(op.tx_mut(), transaction_id)
+            .await?;
+        if activity.remove_transaction(transaction_id) {
+            self.monitoring
+                .persist_activity_in_tx(op.tx_mut(), customer_id, &activity)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for TransactionMonitoringHandler<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<AmlModuleAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<AmlModuleObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>,
+{
+    #[tracing::instrument(name = "outbox.aml.transaction_monitoring.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e) = event.as_event::<CoreDepositEvent>() {
+            let handled = match e {
+                CoreDepositEvent::DepositAccountCreated { entity } => {
+                    self.open_account(
+                        op,
+                        entity.id.into(),
+                        CustomerId::from(entity.account_holder_id),
+                        event.recorded_at,
+                    )
+                    .await?;
+                    true
+                }
+                // Monitoring thresholds are configured in USD.
+                CoreDepositEvent::DepositInitialized { entity }
+                    if entity.currency == CurrencyCode::USD =>
+                {
+                    let kind = match entity.channel {
+                        DepositChannel::Cash => AmlTransactionKind::CashDeposit,
+                        DepositChannel::BankTransfer => AmlTransactionKind::Deposit,
+                    };
+                    self.record_transaction(
+                        op,
+                        kind,
+                        MonitoredTransaction {
+                            id: entity.id.into(),
+                            account_id: entity.deposit_account_id.into(),
+                            direction: TransactionDirection::Inflow,
+                            amount: entity.amount,
+                            recorded_at: event.recorded_at,
+                        },
+                    )
+                    .await?;
+                    true
+                }
+                CoreDepositEvent::DepositReverted { entity } => {
+                    self.revert_transaction(op, entity.deposit_account_id.into(), entity.id.into())
+                        .await?;
+                    true
+                }
+                CoreDepositEvent::WithdrawalConfirmed { entity }
+                    if entity.currency == CurrencyCode::USD =>
+                {
+                    self.record_transaction(
+                        op,
+                        AmlTransactionKind::Withdrawal,
+                        MonitoredTransaction {
+                            id: entity.id.into(),
+                            account_id: entity.deposit_account_id.into(),
+                            direction: TransactionDirection::Outflow,
+                            amount: entity.amount,
+                            recorded_at: event.recorded_at,
+                        },
+                    )
+                    .await?;
+                    true
+                }
+                _ => false,
+            };
+            if handled {
+                event.inject_trace_parent();
+                Span::current().record("handled", true);
+                Span::current().record("event_type", e.as_ref());
+            }
+        }
+
+        if let Some(e @ CoreCreditEvent::FacilityActivated { entity }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.open_account(
+                op,
+                entity.id.into(),
+                entity.customer_id,
+                entity.activated_at,
+            )
+            .await?;
+        }
+
+        if let Some(e @ CoreCreditCollectionEvent::PaymentCreated { entity }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.record_transaction(
+                op,
+                AmlTransactionKind::CreditPayment,
+                MonitoredTransaction {
+                    id: entity.id.into(),
+                    account_id: entity.beneficiary_id.into(),
+                    direction: TransactionDirection::Inflow,
+                    amount: entity.amount,
+                    recorded_at: entity.recorded_at,
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn days(days: u64) -> Duration {
+    i64::try_from(days)
+        .ok()
+        .and_then(Duration::try_days)
+        .unwrap_or(Duration::MAX)
+}
+
+fn hours(hours: u64) -> Duration {
+    i64::try_from(hours)
+        .ok()
+        .and_then(Duration::try_hours)
+        .unwrap_or(Duration::MAX)
+}
@@ -0,0 +1,442 @@
+#![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
+#![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
+
+mod alert;
+mod config;
+pub mod error;
+mod filing;
+mod job;
+mod monitoring;
+mod primitives;
+mod rules;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+use tracing::instrument;
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_credit::CoreCreditEvent;
+use core_credit_collection::CoreCreditCollectionEvent;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
+use core_deposit::CoreDepositEvent;
+use core_report::{
+    CoreReportAction, CoreReportEvent, CoreReports, GeneratedReport, ReportDefinitionId,
+    ReportObject, ReportOutputFormat, ReportRun,
+};
+use domain_config::ExposedDomainConfigsReadOnly;
+use obix::out::{OutboxEventJobConfig, OutboxEventMarker};
+use tracing_macros::record_error_severity;
+
+use alert::*;
+pub use alert::{AmlAlert, AmlAlertStatus, AmlAlertsByCreatedAtCursor, error::AmlAlertError};
+pub use config::*;
+use error::*;
+use filing::*;
+use job::*;
+use monitoring::*;
+pub use primitives::*;
+
+#[cfg(feature = "json-schema")]
+pub mod event_schema {
+    pub use crate::alert::AmlAlertEvent;
+}
+
+pub struct Aml<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCustomerEvent> + OutboxEventMarker<CoreReportEvent>,
+{
+    authz: Perms,
+    alerts: AmlAlertRepo,
+    monitoring: MonitoringRepo,
+    customers: Customers<Perms, E>,
+    reports: CoreReports<Perms, E>,
+    domain_configs: ExposedDomainConfigsReadOnly,
+    clock: ClockHandle,
+}
+
+impl<Perms, E> Clone for Aml<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCustomerEvent> + OutboxEventMarker<CoreReportEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            alerts: self.alerts.clone(),
+            monitoring: self.monitoring.clone(),
+            customers: self.customers.clone(),
+            reports: self.reports.clone(),
+            domain_configs: self.domain_configs.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<Perms, E> Aml<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<AmlModuleAction> + From<CoreCustomerAction> + From<CoreReportAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<AmlModuleObject> + From<CustomerObject> + From<ReportObject>,
+    E: OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<CoreReportEvent>
+        + OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>,
+{
+    #[record_error_severity]
+    #[instrument(name = "aml.init", skip_all)]
+    pub async fn init(
+        pool: &PgPool,
+        authz: &Perms,
+        jobs: &mut ::job::Jobs,
+        outbox: &obix::Outbox<E>,
+        customers: &Customers<Perms, E>,
+        reports: &CoreReports<Perms, E>,
+        domain_configs: &ExposedDomainConfigsReadOnly,
+        clock: ClockHandle,
+    ) -> Result<Self, AmlError> {
+        let alerts = AmlAlertRepo::new(pool, clock.clone());
+        let monitoring = MonitoringRepo::new(pool);
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(AML_TRANSACTION_MONITORING_JOB),
+                TransactionMonitoringHandler::new(authz, &alerts, &monitoring, domain_configs),
+            )
+            .await?;
+
+        Ok(Self {
+            authz: authz.clone(),
+            alerts,
+            monitoring,
+            customers: customers.clone(),
+            reports: reports.clone(),
+            domain_configs: domain_configs.clone(),
+            clock,
+        })
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.find_alert_by_id", skip(self))]
+    pub async fn find_alert_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+    ) -> Result<Option<AmlAlert>, AmlError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(sub, AmlModuleObject::alert(id), AmlModuleAction::ALERT_READ)
+            .await?;
+        Ok(self.alerts.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.find_all_alerts_authorized", skip(self))]
+    pub async fn find_all_alerts_authorized<T: From<AmlAlert>>(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ids: &[AmlAlertId],
+    ) -> Result<std::collections::HashMap<AmlAlertId, T>, AmlError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AmlModuleObject::all_alerts(),
+                AmlModuleAction::ALERT_READ,
+            )
+            .await?;
+        Ok(self.alerts.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.list_alerts", skip(self))]
+    pub async fn list_alerts(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<AmlAlertsByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<AmlAlert, AmlAlertsByCreatedAtCursor>, AmlError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AmlModuleObject::all_alerts(),
+                AmlModuleAction::ALERT_LIST,
+            )
+            .await?;
+        Ok(self
+            .alerts
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.list_alerts_for_customer", skip(self))]
+    pub async fn list_alerts_for_customer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug,
+    ) -> Result<Vec<AmlAlert>, AmlError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AmlModuleObject::all_alerts(),
+                AmlModuleAction::ALERT_LIST,
+            )
+            .await?;
+        Ok(self
+            .alerts
+            .list_for_customer_id_by_created_at(
+                customer_id.into(),
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.start_alert_review", skip(self))]
+    pub async fn start_alert_review(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+    ) -> Result<AmlAlert, AmlError> {
+        let mut alert = self.find_alert_for_triage(sub, id.into()).await?;
+        if alert.start_review()?.did_execute() {
+            self.alerts.update(&mut alert).await?;
+        }
+        Ok(alert)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.add_alert_note", skip(self, note))]
+    pub async fn add_alert_note(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+        note: String,
+    ) -> Result<AmlAlert, AmlError> {
+        let mut alert = self.find_alert_for_triage(sub, id.into()).await?;
+        alert.add_note(note)?;
+        self.alerts.update(&mut alert).await?;
+        Ok(alert)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.escalate_alert", skip(self))]
+    pub async fn escalate_alert(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<AmlAlert, AmlError> {
+        let mut alert = self.find_alert_for_triage(sub, id.into()).await?;
+        if alert.escalate(reason)?.did_execute() {
+            self.alerts.update(&mut alert).await?;
+        }
+        Ok(alert)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "aml.dismiss_alert", skip(self))]
+    pub async fn dismiss_alert(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<AmlAlert, AmlError> {
+        let mut alert = self.find_alert_for_triage(sub, id.into()).await?;
+        if alert.dismiss(reason)?.did_execute() {
+            self.alerts.update(&mut alert).await?;
+        }
+        Ok(alert)
+    }
+
+    /// Freezes the customer flagged by the alert through the regular customer freeze,
+    /// which requires the subject to also hold the customer freeze permission.
+    #[record_error_severity]
+    #[instrument(name = "aml.freeze_customer_for_alert", skip(self))]
+    pub async fn freeze_customer_for_alert(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+    ) -> Result<AmlAlert, AmlError> {
+        let mut alert = self.find_alert_for_triage(sub, id.into()).await?;
+        alert.ensure_can_freeze_customer()?;
+        self.customers
+            .freeze_customer(sub, alert.customer_id)
+            .await?;
+        if alert.record_customer_frozen().did_execute() {
+            self.alerts.update(&mut alert).await?;
+        }
+        Ok(alert)
+    }
+
+    /// Generates the currency transaction report for `date`, covering every customer whose cash
+    /// deposits on that day add up to the reporting threshold. The report is an internal export
+    /// to prepare the filing from, not the regulator's filing format.
+    #[record_error_severity]
+    #[instrument(name = "aml.generate_currency_transaction_report", skip(self))]
+    pub async fn generate_currency_transaction_report(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        date: NaiveDate,
+    ) -> Result<ReportRun, AmlError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AmlModuleObject::all_filings(),
+                AmlModuleAction::FILING_GENERATE,
+            )
+            .await?;
+
+        let threshold = UsdCents::from(
+            self.domain_configs
+                .get_without_audit::<AmlCurrencyTransactionReportThresholdCents>()
+                .await?
+                .value(),
+        );
+        let day_start = date.and_time(NaiveTime::MIN).and_utc();
+        let transactions = self
+            .monitoring
+            .list_transactions_between(day_start, day_start + Duration::days(1))
+            .await?;
+        let activities = reportable_cash_activity(transactions, threshold);
+
+        let mut subjects = HashMap::new();
+        for activity in &activities {
+            subjects.insert(
+                activity.customer_id,
+                self.filing_subject(activity.customer_id).await?,
+            );
+        }
+        let content = currency_transaction_report_xml(
+            date,
+            threshold,
+            self.clock.now(),
+            &activities,
+            &subjects,
+        );
+
+        Ok(self
+            .reports
+            .record_generated_report(
+                sub,
+                GeneratedReport {
+                    report_definition_id: ReportDefinitionId::from_parts(
+                        AML_FILING_NORM,
+                        CURRENCY_TRANSACTION_REPORT_ID,
+                    ),
+                    norm: AML_FILING_NORM.to_string(),
+                    name: CURRENCY_TRANSACTION_REPORT_ID.to_string(),
+                    as_of_date: Some(date),
+                    format: ReportOutputFormat::Xml,
+                    content: content.into_bytes(),
+                },
+            )
+            .await?)
+    }
+
+    /// Generates a suspicious activity report draft for the customer flagged by the alert,
+    /// pre-filled with their alerts and their activity over the configured lookback period.
+    #[record_error_severity]
+    #[instrument(name = "aml.generate_suspicious_activity_report_draft", skip(self))]
+    pub async fn generate_suspicious_activity_report_draft(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AmlAlertId> + std::fmt::Debug,
+    ) -> Result<ReportRun, AmlError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                AmlModuleObject::all_filings(),
+                AmlModuleAction::FILING_GENERATE,
+            )
+            .await?;
+        let alert = self.alerts.find_by_id(id).await?;
+
+        let lookback_days = self
+            .domain_configs
+            .get_without_audit::<AmlSuspiciousActivityLookbackDays>()
+            .await?
+            .value();
+        let period_end = self.clock.now();
+        let period_start = i64::try_from(lookback_days)
+            .ok()
+            .and_then(Duration::try_days)
+            .and_then(|lookback| alert.detected_at.checked_sub_signed(lookback))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let alerts = self
+            .alerts
+            .list_for_customer_id_by_created_at(
+                alert.customer_id,
+                Default::default(),
+                es_entity::ListDirection::Ascending,
+            )
+            .await?
+            .entities;
+        let transactions = self
+            .monitoring
+            .list_transactions_for_customer(alert.customer_id, period_start, period_end)
+            .await?;
+
+        let activity = SuspiciousActivity {
+            subject: self.filing_subject(alert.customer_id).await?,
+            triggering_alert_id: alert.id,
+            alerts: &alerts,
+            period_start,
+            period_end,
+            transactions: &transactions,
+        };
+        let content = activity.to_xml(period_end);
+
+        Ok(self
+            .reports
+            .record_generated_report(
+                sub,
+                GeneratedReport {
+                    report_definition_id: ReportDefinitionId::from_parts(
+                        AML_FILING_NORM,
+                        SUSPICIOUS_ACTIVITY_REPORT_ID,
+                    ),
+                    norm: AML_FILING_NORM.to_string(),
+                    name: SUSPICIOUS_ACTIVITY_REPORT_ID.to_string(),
+                    as_of_date: Some(period_end.date_naive()),
+                    format: ReportOutputFormat::Xml,
+                    content: content.into_bytes(),
+                },
+            )
+            .await?)
+    }
+
+    async fn filing_subject(&self, customer_id: CustomerId) -> Result<FilingSubject, AmlError> {
+        let customer = self.customers.find_by_id_without_audit(customer_id).await?;
+        let party = self
+            .customers
+            .find_party_by_customer_id_without_audit(customer_id)
+            .await?;
+        Ok(FilingSubject::new(&customer, &party))
+    }
+
+    async fn find_alert_for_triage(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: AmlAlertId,
+    ) -> Result<AmlAlert, AmlError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AmlModuleObject::alert(id),
+                AmlModuleAction::ALERT_TRIAGE,
+            )
+            .await?;
+        Ok(self.alerts.find_by_id(id).await?)
+    }
+}
@@ -0,0 +1,249 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    error::*,
+    primitives::{AmlTransactionKind, CustomerId, UsdCents},
+    rules::{CustomerActivity, MonitoredTransaction},
+};
+
+/// A monitored transaction as kept for regulatory filings.
+#[derive(Debug, Clone)]
+pub struct RecordedTransaction {
+    pub id: uuid::Uuid,
+    pub customer_id: CustomerId,
+    pub account_id: uuid::Uuid,
+    pub kind: AmlTransactionKind,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Projection of monitored accounts, recent customer activity and the transactions
+/// reported in filings, maintained by the transaction monitoring handler within its
+/// own transaction.
+#[derive(Clone)]
+pub struct MonitoringRepo {
+    pool: PgPool,
+}
+
+impl MonitoringRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.register_account_in_tx", skip_all)]
+    pub async fn register_account_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        account_id: uuid::Uuid,
+        customer_id: CustomerId,
+    ) -> Result<(), AmlError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO core_aml_monitored_accounts (id, customer_id)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+            account_id,
+            customer_id as CustomerId,
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.find_customer_for_account_in_tx", skip_all)]
+    pub async fn find_customer_for_account_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        account_id: uuid::Uuid,
+    ) -> Result<Option<CustomerId>, AmlError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT customer_id as "customer_id: CustomerId"
+            FROM core_aml_monitored_accounts
+            WHERE id = $1
+            "#,
+            account_id
+        )
+        .fetch_optional(&mut **tx)
+        .await?;
+        Ok(row.map(|row| row.customer_id))
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.load_activity_in_tx", skip_all)]
+    pub async fn load_activity_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        customer_id: CustomerId,
+    ) -> Result<CustomerActivity, AmlError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT activity_json
+            FROM core_aml_customer_activities
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            customer_id as CustomerId
+        )
+        .fetch_optional(&mut **tx)
+        .await?;
+        let res = if let Some(row) = row {
+            serde_json::from_value(row.activity_json)
+                .expect("Could not de-serialize customer activity")
+        } else {
+            CustomerActivity::default()
+        };
+        Ok(res)
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.persist_activity_in_tx", skip_all)]
+    pub async fn persist_activity_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        customer_id: CustomerId,
+        activity: &CustomerActivity,
+    ) -> Result<(), AmlError> {
+        let activity =
+            serde_json::to_value(activity).expect("Could not serialize customer activity");
+        sqlx::query!(
+            r#"
+            INSERT INTO core_aml_customer_activities (id, activity_json)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO UPDATE
+            SET activity_json = $2, modified_at = NOW()
+            "#,
+            customer_id as CustomerId,
+            activity
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.record_transaction_in_tx", skip_all)]
+    pub async fn record_transaction_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        customer_id: CustomerId,
+        kind: AmlTransactionKind,
+        transaction: &MonitoredTransaction,
+    ) -> Result<(), AmlError> {
+        let amount = i64::try_from(transaction.amount.into_inner())
+            .expect("transaction amount should fit into i64");
+        sqlx::query!(
+            r#"
+            INSERT INTO core_aml_transactions (id, customer_id, account_id, kind, amount, recorded_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+            transaction.id,
+            customer_id as CustomerId,
+            transaction.account_id,
+            kind.to_string(),
+            amount,
+            transaction.recorded_at,
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.mark_transaction_reverted_in_tx", skip_all)]
+    pub async fn mark_transaction_reverted_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        transaction_id: uuid::Uuid,
+    ) -> Result<(), AmlError> {
+        sqlx::query!(
+            r#"
+            UPDATE core_aml_transactions
+            SET reverted = TRUE
+            WHERE id = $1
+            "#,
+            transaction_id,
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.list_transactions_between", skip(self))]
+    pub async fn list_transactions_between(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<RecordedTransaction>, AmlError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, customer_id as "customer_id: CustomerId", account_id, kind, amount, recorded_at
+            FROM core_aml_transactions
+            WHERE NOT reverted AND recorded_at >= $1 AND recorded_at < $2
+            ORDER BY recorded_at, id
+            "#,
+            from,
+            until,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| RecordedTransaction {
+                id: row.id,
+                customer_id: row.customer_id,
+                account_id: row.account_id,
+                kind: row
+                    .kind
+                    .parse()
+                    .expect("Could not parse aml transaction kind"),
+                amount: UsdCents::from(row.amount as u64),
+                recorded_at: row.recorded_at,
+            })
+            .collect())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "aml.monitoring.list_transactions_for_customer", skip(self))]
+    pub async fn list_transactions_for_customer(
+        &self,
+        customer_id: CustomerId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<RecordedTransaction>, AmlError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, customer_id as "customer_id: CustomerId", account_id, kind, amount, recorded_at
+            FROM core_aml_transactions
+            WHERE customer_id = $1 AND NOT reverted AND recorded_at >= $2 AND recorded_at < $3
+            ORDER BY recorded_at, id
+            "#,
+            customer_id as CustomerId,
+            from,
+            until,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| RecordedTransaction {
+                id: row.id,
+                customer_id: row.customer_id,
+                account_id: row.account_id,
+                kind: row
+                    .kind
+                    .parse()
+                    .expect("Could not parse aml transaction kind"),
+                amount: UsdCents::from(row.amount as u64),
+                recorded_at: row.recorded_at,
+            })
+            .collect())
+    }
+}
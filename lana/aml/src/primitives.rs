@@ -0,0 +1,229 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
+
+pub use core_customer::CustomerId;
+pub use core_report::ReportRunId;
+pub use money::UsdCents;
+
+pub const AML_TRANSACTION_MONITORING: audit::SystemActor =
+    audit::SystemActor::new("aml-transaction-monitoring");
+
+es_entity::entity_id!(AmlAlertId);
+
+pub type AmlAlertAllOrOne = AllOrOne<AmlAlertId>;
+pub type AmlFilingAllOrOne = AllOrOne<ReportRunId>;
+
+permission_sets_macro::permission_sets! {
+    AmlViewer("Can view AML alerts and their case history"),
+    AmlWriter("Can triage AML alerts, freeze the customers they flag and generate regulatory filings"),
+}
+
+/// The monitoring rule that raised an alert.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Hash,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum AmlRule {
+    /// Several inflows just below the large cash threshold within a short window.
+    Structuring,
+    /// Funds withdrawn shortly after they were deposited.
+    RapidMovement,
+    /// A single inflow at or above the large cash threshold.
+    LargeCash,
+    /// A transaction on an account that has been inactive for a long time.
+    DormantAccountActivity,
+}
+
+/// The kind of monitored transaction, as reported in regulatory filings.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AmlTransactionKind {
+    /// A deposit paid in as cash.
+    CashDeposit,
+    /// A deposit received through a bank transfer.
+    Deposit,
+    Withdrawal,
+    CreditPayment,
+}
+
+impl AmlTransactionKind {
+    /// Whether the transaction was made in cash and therefore counts towards currency
+    /// transaction reporting. Withdrawals are always paid out to a beneficiary's bank
+    /// account or crypto address and never are.
+    pub fn is_cash(&self) -> bool {
+        matches!(self, Self::CashDeposit)
+    }
+
+    pub fn is_deposit(&self) -> bool {
+        matches!(self, Self::CashDeposit | Self::Deposit)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
+#[strum_discriminants(derive(strum::Display, strum::EnumString))]
+#[strum_discriminants(strum(serialize_all = "kebab-case"))]
+pub enum AmlModuleObject {
+    Alert(AmlAlertAllOrOne),
+    Filing(AmlFilingAllOrOne),
+}
+
+impl AmlModuleObject {
+    pub const fn all_alerts() -> Self {
+        Self::Alert(AllOrOne::All)
+    }
+
+    pub const fn alert(id: AmlAlertId) -> Self {
+        Self::Alert(AllOrOne::ById(id))
+    }
+
+    pub const fn all_filings() -> Self {
+        Self::Filing(AllOrOne::All)
+    }
+}
+
+impl Display for AmlModuleObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let discriminant = AmlModuleObjectDiscriminants::from(self);
+        use AmlModuleObject::*;
+        match self {
+            Alert(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Filing(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+        }
+    }
+}
+
+impl FromStr for AmlModuleObject {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (entity, id) = s.split_once('/').expect("missing slash");
+        use AmlModuleObjectDiscriminants::*;
+        let res = match entity.parse().expect("invalid entity") {
+            Alert => {
+                let obj_ref = id.parse().map_err(|_| "could not parse AmlModuleObject")?;
+                AmlModuleObject::Alert(obj_ref)
+            }
+            Filing => {
+                let obj_ref = id.parse().map_err(|_| "could not parse AmlModuleObject")?;
+                AmlModuleObject::Filing(obj_ref)
+            }
+        };
+        Ok(res)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
+#[strum_discriminants(derive(strum::Display, strum::EnumString, strum::VariantArray))]
+#[strum_discriminants(strum(serialize_all = "kebab-case"))]
+pub enum AmlModuleAction {
+    Alert(AmlAlertAction),
+    Filing(AmlFilingAction),
+}
+
+impl AmlModuleAction {
+    pub const ALERT_CREATE: Self = AmlModuleAction::Alert(AmlAlertAction::Create);
+    pub const ALERT_READ: Self = AmlModuleAction::Alert(AmlAlertAction::Read);
+    pub const ALERT_LIST: Self = AmlModuleAction::Alert(AmlAlertAction::List);
+    pub const ALERT_TRIAGE: Self = AmlModuleAction::Alert(AmlAlertAction::Triage);
+
+    pub const FILING_GENERATE: Self = AmlModuleAction::Filing(AmlFilingAction::Generate);
+
+    pub fn actions() -> Vec<ActionMapping> {
+        use AmlModuleActionDiscriminants::*;
+        use strum::VariantArray;
+
+        AmlModuleActionDiscriminants::VARIANTS
+            .iter()
+            .flat_map(|&discriminant| match discriminant {
+                Alert => map_action!(aml, Alert, AmlAlertAction),
+                Filing => map_action!(aml, Filing, AmlFilingAction),
+            })
+            .collect()
+    }
+}
+
+impl Display for AmlModuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", AmlModuleActionDiscriminants::from(self))?;
+        use AmlModuleAction::*;
+        match self {
+            Alert(action) => action.fmt(f),
+            Filing(action) => action.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AmlModuleAction {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (entity, action) = s.split_once(':').expect("missing colon");
+        use AmlModuleActionDiscriminants::*;
+        let res = match entity.parse()? {
+            Alert => AmlModuleAction::from(action.parse::<AmlAlertAction>()?),
+            Filing => AmlModuleAction::from(action.parse::<AmlFilingAction>()?),
+        };
+        Ok(res)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AmlAlertAction {
+    Create,
+    Read,
+    List,
+    Triage,
+}
+
+impl ActionPermission for AmlAlertAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_AML_VIEWER,
+            Self::Create | Self::Triage => PERMISSION_SET_AML_WRITER,
+        }
+    }
+}
+
+impl From<AmlAlertAction> for AmlModuleAction {
+    fn from(action: AmlAlertAction) -> Self {
+        AmlModuleAction::Alert(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AmlFilingAction {
+    Generate,
+}
+
+impl ActionPermission for AmlFilingAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Generate => PERMISSION_SET_AML_WRITER,
+        }
+    }
+}
+
+impl From<AmlFilingAction> for AmlModuleAction {
+    fn from(action: AmlFilingAction) -> Self {
+        AmlModuleAction::Filing(action)
+    }
+}
@@ -0,0 +1,504 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::primitives::{AmlRule, UsdCents};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TransactionDirection {
+    Inflow,
+    Outflow,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct MonitoredTransaction {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub direction: TransactionDirection,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MonitoringSettings {
+    pub large_cash_threshold: UsdCents,
+    pub structuring_margin_percent: u64,
+    pub structuring_window: Duration,
+    pub structuring_min_transactions: usize,
+    pub rapid_movement_window: Duration,
+    pub rapid_movement_min_amount: UsdCents,
+    pub rapid_movement_outflow_percent: u64,
+    pub dormant_after: Duration,
+}
+
+impl MonitoringSettings {
+    fn retention(&self) -> Duration {
+        self.structuring_window.max(self.rapid_movement_window)
+    }
+
+    fn structuring_floor(&self) -> u64 {
+        let threshold = self.large_cash_threshold.into_inner();
+        threshold - threshold * self.structuring_margin_percent / 100
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RuleHit {
+    pub rule: AmlRule,
+    pub account_id: Uuid,
+    pub amount: UsdCents,
+    pub transaction_ids: Vec<Uuid>,
+    pub details: String,
+}
+
+/// Recent activity of a single customer across all of their monitored accounts.
+///
+/// Only transactions within the longest rule window are retained. Rule cooldowns are
+/// tracked per account so a hit on one account does not mask another.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct CustomerActivity {
+    transactions: Vec<MonitoredTransaction>,
+    last_activity_at: HashMap<Uuid, DateTime<Utc>>,
+    last_hit_at: HashMap<Uuid, HashMap<AmlRule, DateTime<Utc>>>,
+}
+
+impl CustomerActivity {
+    pub fn open_account(&mut self, account_id: Uuid, opened_at: DateTime<Utc>) {
+        self.last_activity_at.entry(account_id).or_insert(opened_at);
+    }
+
+    pub fn remove_transaction(&mut self, id: Uuid) -> bool {
+        let before = self.transactions.len();
+        self.transactions.retain(|tx| tx.id != id);
+        before != self.transactions.len()
+    }
+
+    /// Adds `tx` to the activity and returns the rules it trips.
+    /// Recording the same transaction twice is a no-op.
+    pub fn record(
+        &mut self,
+        tx: MonitoredTransaction,
+        settings: &MonitoringSettings,
+    ) -> Vec<RuleHit> {
+        if self.transactions.iter().any(|t| t.id == tx.id) {
+            return Vec::new();
+        }
+
+        let mut hits = Vec::new();
+        if let Some(hit) = self.check_dormant_account(&tx, settings) {
+            hits.push(hit);
+        }
+        let last_activity_at = self
+            .last_activity_at
+            .entry(tx.account_id)
+            .or_insert(tx.recorded_at);
+        *last_activity_at = (*last_activity_at).max(tx.recorded_at);
+
+        let now = tx.recorded_at;
+        self.transactions.push(tx.clone());
+        self.transactions
+            .retain(|t| t.recorded_at >= now - settings.retention());
+
+        match tx.direction {
+            TransactionDirection::Inflow => {
+                if tx.amount >= settings.large_cash_threshold {
+                    hits.push(RuleHit {
+                        rule: AmlRule::LargeCash,
+                        account_id: tx.account_id,
+                        amount: tx.amount,
+                        transaction_ids: vec![tx.id],
+                        details: format!(
+                            "Inflow of {} is at or above the large cash threshold of {}",
+                            tx.amount.formatted_usd(),
+                            settings.large_cash_threshold.formatted_usd()
+                        ),
+                    });
+                }
+                if let Some(hit) = self.check_structuring(&tx, settings) {
+                    hits.push(hit);
+                }
+            }
+            TransactionDirection::Outflow => {
+                if let Some(hit) = self.check_rapid_movement(&tx, settings) {
+                    hits.push(hit);
+                }
+            }
+        }
+
+        for hit in hits.iter() {
+            self.last_hit_at
+                .entry(hit.account_id)
+                .or_default()
+                .insert(hit.rule, now);
+        }
+        hits
+    }
+
+    fn check_dormant_account(
+        &self,
+        tx: &MonitoredTransaction,
+        settings: &MonitoringSettings,
+    ) -> Option<RuleHit> {
+        let last_activity_at = self.last_activity_at.get(&tx.account_id)?;
+        let inactive_for = tx.recorded_at - *last_activity_at;
+        if inactive_for < settings.dormant_after {
+            return None;
+        }
+        Some(RuleHit {
+            rule: AmlRule::DormantAccountActivity,
+            account_id: tx.account_id,
+            amount: tx.amount,
+            transaction_ids: vec![tx.id],
+            details: format!(
+                "Transaction of {} after {} days without activity",
+                tx.amount.formatted_usd(),
+                inactive_for.num_days()
+            ),
+        })
+    }
+
+    fn check_structuring(
+        &self,
+        tx: &MonitoredTransaction,
+        settings: &MonitoringSettings,
+    ) -> Option<RuleHit> {
+        let window_start = tx.recorded_at - settings.structuring_window;
+        if self.hit_since(AmlRule::Structuring, tx.account_id, window_start) {
+            return None;
+        }
+        let floor = settings.structuring_floor();
+        let threshold = settings.large_cash_threshold.into_inner();
+        let candidates: Vec<_> = self
+            .inflows_since(window_start)
+            .filter(|t| (floor..threshold).contains(&t.amount.into_inner()))
+            .collect();
+        if candidates.len() < settings.structuring_min_transactions
+            || !candidates.iter().any(|t| t.id == tx.id)
+        {
+            return None;
+        }
+        Some(RuleHit {
+            rule: AmlRule::Structuring,
+            account_id: tx.account_id,
+            amount: sum(candidates.iter().map(|t| t.amount)),
+            transaction_ids: candidates.iter().map(|t| t.id).collect(),
+            details: format!(
+                "{} inflows between {} and {} within {} days",
+                candidates.len(),
+                UsdCents::from(floor).formatted_usd(),
+                settings.large_cash_threshold.formatted_usd(),
+                settings.structuring_window.num_days()
+            ),
+        })
+    }
+
+    fn check_rapid_movement(
+        &self,
+        tx: &MonitoredTransaction,
+        settings: &MonitoringSettings,
+    ) -> Option<RuleHit> {
+        let window_start = tx.recorded_at - settings.rapid_movement_window;
+        if self.hit_since(AmlRule::RapidMovement, tx.account_id, window_start) {
+            return None;
+        }
+        let inflows: Vec<_> = self.inflows_since(window_start).collect();
+        let total_in = sum(inflows.iter().map(|t| t.amount));
+        if total_in.is_zero() || total_in < settings.rapid_movement_min_amount {
+            return None;
+        }
+        let outflows: Vec<_> = self
+            .transactions
+            .iter()
+            .filter(|t| {
+                t.direction == TransactionDirection::Outflow && t.recorded_at >= window_start
+            })
+            .collect();
+        let total_out = sum(outflows.iter().map(|t| t.amount));
+        if u128::from(total_out.into_inner()) * 100
+            < u128::from(total_in.into_inner())
+                * u128::from(settings.rapid_movement_outflow_percent)
+        {
+            return None;
+        }
+        Some(RuleHit {
+            rule: AmlRule::RapidMovement,
+            account_id: tx.account_id,
+            amount: total_out,
+            transaction_ids: inflows
+                .iter()
+                .chain(outflows.iter())
+                .map(|t| t.id)
+                .collect(),
+            details: format!(
+                "{} of {} deposited was withdrawn again within {} hours",
+                total_out.formatted_usd(),
+                total_in.formatted_usd(),
+                settings.rapid_movement_window.num_hours()
+            ),
+        })
+    }
+
+    fn inflows_since(&self, since: DateTime<Utc>) -> impl Iterator<Item = &MonitoredTransaction> {
+        self.transactions
+            .iter()
+            .filter(move |t| t.direction == TransactionDirection::Inflow && t.recorded_at >= since)
+    }
+
+    fn hit_since(&self, rule: AmlRule, account_id: Uuid, since: DateTime<Utc>) -> bool {
+        self.last_hit_at
+            .get(&account_id)
+            .and_then(|hits| hits.get(&rule))
+            .is_some_and(|at| *at >= since)
+    }
+}
+
+fn sum(amounts: impl Iterator<Item = UsdCents>) -> UsdCents {
+    amounts.fold(UsdCents::ZERO, |acc, amount| acc + amount)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn settings() -> MonitoringSettings {
+        MonitoringSettings {
+            large_cash_threshold: UsdCents::from(1_000_000),
+            structuring_margin_percent: 10,
+            structuring_window: Duration::days(7),
+            structuring_min_transactions: 3,
+            rapid_movement_window: Duration::hours(48),
+            rapid_movement_min_amount: UsdCents::from(500_000),
+            rapid_movement_outflow_percent: 80,
+            dormant_after: Duration::days(180),
+        }
+    }
+
+    fn tx(
+        account_id: Uuid,
+        direction: TransactionDirection,
+        amount: u64,
+        recorded_at: DateTime<Utc>,
+    ) -> MonitoredTransaction {
+        MonitoredTransaction {
+            id: Uuid::new_v4(),
+            account_id,
+            direction,
+            amount: UsdCents::from(amount),
+            recorded_at,
+        }
+    }
+
+    fn rules(hits: &[RuleHit]) -> Vec<AmlRule> {
+        hits.iter().map(|hit| hit.rule).collect()
+    }
+
+    #[test]
+    fn large_cash_inflow() {
+        let settings = settings();
+        let account_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(account_id, now);
+
+        let hits = activity.record(
+            tx(account_id, TransactionDirection::Inflow, 999_999, now),
+            &settings,
+        );
+        assert!(hits.is_empty());
+
+        let hits = activity.record(
+            tx(account_id, TransactionDirection::Inflow, 1_000_000, now),
+            &settings,
+        );
+        assert_eq!(rules(&hits), vec![AmlRule::LargeCash]);
+    }
+
+    #[test]
+    fn structuring_just_below_threshold() {
+        let settings = settings();
+        let account_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(account_id, now);
+
+        for days in 0..2 {
+            let hits = activity.record(
+                tx(
+                    account_id,
+                    TransactionDirection::Inflow,
+                    950_000,
+                    now + Duration::days(days),
+                ),
+                &settings,
+            );
+            assert!(hits.is_empty());
+        }
+        let hits = activity.record(
+            tx(
+                account_id,
+                TransactionDirection::Inflow,
+                900_000,
+                now + Duration::days(2),
+            ),
+            &settings,
+        );
+        assert_eq!(rules(&hits), vec![AmlRule::Structuring]);
+        assert_eq!(hits[0].transaction_ids.len(), 3);
+        assert_eq!(hits[0].amount, UsdCents::from(2_800_000));
+
+        let hits = activity.record(
+            tx(
+                account_id,
+                TransactionDirection::Inflow,
+                950_000,
+                now + Duration::days(3),
+            ),
+            &settings,
+        );
+        assert!(hits.is_empty(), "should not re-raise within the window");
+    }
+
+    #[test]
+    fn structuring_cooldown_is_per_account() {
+        let settings = settings();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(first, now);
+        activity.open_account(second, now);
+
+        for days in 0..3 {
+            activity.record(
+                tx(
+                    first,
+                    TransactionDirection::Inflow,
+                    950_000,
+                    now + Duration::days(days),
+                ),
+                &settings,
+            );
+        }
+        let hits = activity.record(
+            tx(
+                second,
+                TransactionDirection::Inflow,
+                950_000,
+                now + Duration::days(3),
+            ),
+            &settings,
+        );
+        assert_eq!(rules(&hits), vec![AmlRule::Structuring]);
+        assert_eq!(hits[0].account_id, second);
+    }
+
+    #[test]
+    fn structuring_ignores_inflows_outside_window() {
+        let settings = settings();
+        let account_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(account_id, now);
+
+        for days in [0, 8, 16] {
+            let hits = activity.record(
+                tx(
+                    account_id,
+                    TransactionDirection::Inflow,
+                    950_000,
+                    now + Duration::days(days),
+                ),
+                &settings,
+            );
+            assert!(hits.is_empty());
+        }
+    }
+
+    #[test]
+    fn rapid_in_out_movement() {
+        let settings = settings();
+        let account_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(account_id, now);
+
+        activity.record(
+            tx(account_id, TransactionDirection::Inflow, 600_000, now),
+            &settings,
+        );
+        let hits = activity.record(
+            tx(
+                account_id,
+                TransactionDirection::Outflow,
+                400_000,
+                now + Duration::hours(1),
+            ),
+            &settings,
+        );
+        assert!(hits.is_empty());
+
+        let hits = activity.record(
+            tx(
+                account_id,
+                TransactionDirection::Outflow,
+                100_000,
+                now + Duration::hours(2),
+            ),
+            &settings,
+        );
+        assert_eq!(rules(&hits), vec![AmlRule::RapidMovement]);
+        assert_eq!(hits[0].amount, UsdCents::from(500_000));
+    }
+
+    #[test]
+    fn dormant_account_activity() {
+        let settings = settings();
+        let dormant = Uuid::new_v4();
+        let active = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(dormant, now);
+        activity.open_account(active, now + Duration::days(100));
+
+        let later = now + Duration::days(200);
+        let hits = activity.record(
+            tx(active, TransactionDirection::Inflow, 10_000, later),
+            &settings,
+        );
+        assert!(hits.is_empty());
+
+        let hits = activity.record(
+            tx(dormant, TransactionDirection::Inflow, 10_000, later),
+            &settings,
+        );
+        assert_eq!(rules(&hits), vec![AmlRule::DormantAccountActivity]);
+        assert_eq!(hits[0].account_id, dormant);
+
+        let hits = activity.record(
+            tx(
+                dormant,
+                TransactionDirection::Inflow,
+                10_000,
+                later + Duration::days(1),
+            ),
+            &settings,
+        );
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn recording_twice_is_idempotent() {
+        let settings = settings();
+        let account_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut activity = CustomerActivity::default();
+        activity.open_account(account_id, now);
+
+        let large = tx(account_id, TransactionDirection::Inflow, 2_000_000, now);
+        assert_eq!(activity.record(large.clone(), &settings).len(), 1);
+        assert!(activity.record(large, &settings).is_empty());
+    }
+}
@@ -154,12 +154,29 @@ CREATE TABLE core_prospect_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_deposit_products (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_product_events (
+  id UUID NOT NULL REFERENCES core_deposit_products(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_deposit_accounts (
   id UUID PRIMARY KEY,
   account_holder_id UUID NOT NULL,
   public_id VARCHAR NOT NULL REFERENCES core_public_ids(id),
   status VARCHAR NOT NULL,
   activity VARCHAR NOT NULL DEFAULT 'active',
+  deposit_product_id UUID REFERENCES core_deposit_products(id),
   created_at TIMESTAMPTZ NOT NULL
 );
 
@@ -173,6 +190,14 @@ CREATE TABLE core_deposit_account_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_deposit_account_joint_holders (
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  account_holder_id UUID NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (deposit_account_id, account_holder_id)
+);
+CREATE INDEX idx_core_deposit_account_joint_holders_account_holder_id ON core_deposit_account_joint_holders(account_holder_id);
+
 CREATE TABLE core_deposits (
   id UUID PRIMARY KEY,
   deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
@@ -215,6 +240,155 @@ CREATE TABLE core_withdrawal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_transfers (
+  id UUID PRIMARY KEY,
+  from_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  to_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  approval_process_id UUID REFERENCES core_approval_processes(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  public_id VARCHAR NOT NULL REFERENCES core_public_ids(id),
+  status VARCHAR NOT NULL,
+  amount BIGINT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_transfers_from_account_id ON core_transfers(from_account_id);
+CREATE INDEX idx_core_transfers_to_account_id ON core_transfers(to_account_id);
+
+CREATE TABLE core_transfer_events (
+  id UUID NOT NULL REFERENCES core_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_fx_conversions (
+  id UUID PRIMARY KEY,
+  source_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  target_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_fx_conversions_source_account_id ON core_fx_conversions(source_account_id);
+CREATE INDEX idx_core_fx_conversions_target_account_id ON core_fx_conversions(target_account_id);
+
+CREATE TABLE core_fx_conversion_events (
+  id UUID NOT NULL REFERENCES core_fx_conversions(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_holds (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  status VARCHAR NOT NULL,
+  expires_at TIMESTAMPTZ NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_holds_deposit_account_id ON core_holds(deposit_account_id);
+CREATE INDEX idx_core_holds_status_expires_at ON core_holds(status, expires_at);
+
+CREATE TABLE core_hold_events (
+  id UUID NOT NULL REFERENCES core_holds(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_beneficiaries (
+  id UUID PRIMARY KEY,
+  account_holder_id UUID NOT NULL,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_beneficiaries_account_holder_id ON core_beneficiaries(account_holder_id);
+
+CREATE TABLE core_beneficiary_events (
+  id UUID NOT NULL REFERENCES core_beneficiaries(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_payment_files (
+  id UUID PRIMARY KEY,
+  message_id VARCHAR NOT NULL UNIQUE,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_payment_file_events (
+  id UUID NOT NULL REFERENCES core_payment_files(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_deposit_imports (
+  id UUID PRIMARY KEY,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_import_events (
+  id UUID NOT NULL REFERENCES core_deposit_imports(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_account_closures (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_account_closures_deposit_account_id ON core_account_closures(deposit_account_id);
+
+CREATE TABLE core_account_closure_events (
+  id UUID NOT NULL REFERENCES core_account_closures(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_deposit_account_statements (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_deposit_account_statements_deposit_account_id ON core_deposit_account_statements(deposit_account_id);
+
+CREATE TABLE core_deposit_account_statement_events (
+  id UUID NOT NULL REFERENCES core_deposit_account_statements(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_terms_templates (
   id UUID PRIMARY KEY,
   name VARCHAR NOT NULL UNIQUE,
@@ -477,6 +651,42 @@ CREATE TABLE core_disbursal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_participations (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_participations_credit_facility_id ON core_participations(credit_facility_id);
+
+CREATE TABLE core_participation_events (
+  id UUID NOT NULL REFERENCES core_participations(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_credit_facility_statements (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
+  period_end DATE NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(credit_facility_id, period_end)
+);
+CREATE INDEX idx_core_credit_facility_statements_credit_facility_id ON core_credit_facility_statements(credit_facility_id);
+
+CREATE TABLE core_credit_facility_statement_events (
+  id UUID NOT NULL REFERENCES core_credit_facility_statements(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_interest_accrual_cycles (
   id UUID PRIMARY KEY,
   credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
@@ -688,3 +898,62 @@ CREATE TABLE dashboards (
   created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
   modified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
+
+CREATE TABLE core_aml_alerts (
+  id UUID PRIMARY KEY,
+  customer_id UUID NOT NULL REFERENCES core_customers(id),
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_aml_alerts_customer_id ON core_aml_alerts(customer_id);
+
+CREATE TABLE core_aml_alert_events (
+  id UUID NOT NULL REFERENCES core_aml_alerts(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_aml_monitored_accounts (
+  id UUID PRIMARY KEY,
+  customer_id UUID NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE core_aml_customer_activities (
+  id UUID PRIMARY KEY,
+  activity_json JSONB NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  modified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE core_aml_transactions (
+  id UUID PRIMARY KEY,
+  customer_id UUID NOT NULL,
+  account_id UUID NOT NULL,
+  kind VARCHAR NOT NULL,
+  amount BIGINT NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  reverted BOOLEAN NOT NULL DEFAULT FALSE
+);
+CREATE INDEX idx_core_aml_transactions_recorded_at ON core_aml_transactions(recorded_at);
+CREATE INDEX idx_core_aml_transactions_customer_id_recorded_at ON core_aml_transactions(customer_id, recorded_at);
+
+CREATE TABLE core_manual_transaction_schedules (
+  id UUID PRIMARY KEY,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_manual_transaction_schedule_events (
+  id UUID NOT NULL REFERENCES core_manual_transaction_schedules(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
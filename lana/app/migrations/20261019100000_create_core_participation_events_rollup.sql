@@ -0,0 +1,150 @@
+-- Auto-generated rollup table for ParticipationEvent
+CREATE TABLE core_participation_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  account_ids JSONB,
+  amount BIGINT,
+  credit_facility_id UUID,
+  disbursal_id UUID,
+  effective VARCHAR,
+  obligation_type VARCHAR,
+  participant_name VARCHAR,
+  payment_allocation_id UUID,
+  share VARCHAR,
+
+  -- Collection rollups
+  ledger_tx_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for ParticipationEvent
+CREATE OR REPLACE FUNCTION core_participation_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_participation_events_rollup%ROWTYPE;
+  new_row core_participation_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_participation_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'disbursal_funded', 'receipt_allocated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_ids := (NEW.event -> 'account_ids');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.disbursal_id := (NEW.event ->> 'disbursal_id')::UUID;
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.obligation_type := (NEW.event ->> 'obligation_type');
+    new_row.participant_name := (NEW.event ->> 'participant_name');
+    new_row.payment_allocation_id := (NEW.event ->> 'payment_allocation_id')::UUID;
+    new_row.share := (NEW.event ->> 'share');
+  ELSE
+    -- Default all fields to current values
+    new_row.account_ids := current_row.account_ids;
+    new_row.amount := current_row.amount;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.disbursal_id := current_row.disbursal_id;
+    new_row.effective := current_row.effective;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.obligation_type := current_row.obligation_type;
+    new_row.participant_name := current_row.participant_name;
+    new_row.payment_allocation_id := current_row.payment_allocation_id;
+    new_row.share := current_row.share;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_ids := (NEW.event -> 'account_ids');
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.participant_name := (NEW.event ->> 'participant_name');
+      new_row.share := (NEW.event ->> 'share');
+    WHEN 'disbursal_funded' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.disbursal_id := (NEW.event ->> 'disbursal_id')::UUID;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'receipt_allocated' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.obligation_type := (NEW.event ->> 'obligation_type');
+      new_row.payment_allocation_id := (NEW.event ->> 'payment_allocation_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_participation_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    account_ids,
+    amount,
+    credit_facility_id,
+    disbursal_id,
+    effective,
+    ledger_tx_ids,
+    obligation_type,
+    participant_name,
+    payment_allocation_id,
+    share
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.account_ids,
+    new_row.amount,
+    new_row.credit_facility_id,
+    new_row.disbursal_id,
+    new_row.effective,
+    new_row.ledger_tx_ids,
+    new_row.obligation_type,
+    new_row.participant_name,
+    new_row.payment_allocation_id,
+    new_row.share
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for ParticipationEvent
+CREATE TRIGGER core_participation_events_rollup_trigger
+  AFTER INSERT ON core_participation_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_participation_events_rollup_trigger();
@@ -0,0 +1,108 @@
+-- Auto-generated rollup table for CreditFacilityStatementEvent
+CREATE TABLE core_credit_facility_statement_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  credit_facility_id UUID,
+  customer_id UUID,
+  document_id UUID,
+  period_end VARCHAR,
+  period_start VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for CreditFacilityStatementEvent
+CREATE OR REPLACE FUNCTION core_credit_facility_statement_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_credit_facility_statement_events_rollup%ROWTYPE;
+  new_row core_credit_facility_statement_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_credit_facility_statement_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+    new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+    new_row.period_end := (NEW.event ->> 'period_end');
+    new_row.period_start := (NEW.event ->> 'period_start');
+  ELSE
+    -- Default all fields to current values
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.customer_id := current_row.customer_id;
+    new_row.document_id := current_row.document_id;
+    new_row.period_end := current_row.period_end;
+    new_row.period_start := current_row.period_start;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.period_end := (NEW.event ->> 'period_end');
+      new_row.period_start := (NEW.event ->> 'period_start');
+  END CASE;
+
+  INSERT INTO core_credit_facility_statement_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    credit_facility_id,
+    customer_id,
+    document_id,
+    period_end,
+    period_start
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.credit_facility_id,
+    new_row.customer_id,
+    new_row.document_id,
+    new_row.period_end,
+    new_row.period_start
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for CreditFacilityStatementEvent
+CREATE TRIGGER core_credit_facility_statement_events_rollup_trigger
+  AFTER INSERT ON core_credit_facility_statement_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_credit_facility_statement_events_rollup_trigger();
@@ -0,0 +1,28 @@
+CREATE TABLE dashboard_facility_exposures (
+  id UUID PRIMARY KEY,
+  customer_id UUID NOT NULL,
+  terms JSONB NOT NULL,
+  activated_at TIMESTAMPTZ,
+  collateral BIGINT NOT NULL DEFAULT 0,
+  outstanding BIGINT NOT NULL DEFAULT 0,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  modified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE dashboard_open_obligations (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL,
+  due_at DATE NOT NULL,
+  outstanding BIGINT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  modified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_dashboard_open_obligations_credit_facility_id ON dashboard_open_obligations(credit_facility_id);
+
+-- Rebuild the dashboard so the portfolio state and the new exposure tables are
+-- backfilled: dropping the projection job makes it re-register on startup and replay
+-- the outbox from the beginning.
+DELETE FROM dashboards;
+DELETE FROM job_executions WHERE job_type = 'outbox.dashboard-projection';
+DELETE FROM job_events WHERE id IN (SELECT id FROM jobs WHERE job_type = 'outbox.dashboard-projection');
+DELETE FROM jobs WHERE job_type = 'outbox.dashboard-projection';
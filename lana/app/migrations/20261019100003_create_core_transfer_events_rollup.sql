@@ -0,0 +1,150 @@
+-- Auto-generated rollup table for TransferEvent
+CREATE TABLE core_transfer_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  approval_process_id UUID,
+  approved BOOLEAN,
+  from_account_id UUID,
+  public_id VARCHAR,
+  reference VARCHAR,
+  status VARCHAR,
+  to_account_id UUID,
+
+  -- Collection rollups
+  ledger_tx_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for TransferEvent
+CREATE OR REPLACE FUNCTION core_transfer_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_transfer_events_rollup%ROWTYPE;
+  new_row core_transfer_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_transfer_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'completed', 'failed', 'funds_held', 'funds_released') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.from_account_id := (NEW.event ->> 'from_account_id')::UUID;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.status := (NEW.event ->> 'status');
+    new_row.to_account_id := (NEW.event ->> 'to_account_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
+    new_row.from_account_id := current_row.from_account_id;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.public_id := current_row.public_id;
+    new_row.reference := current_row.reference;
+    new_row.status := current_row.status;
+    new_row.to_account_id := current_row.to_account_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.from_account_id := (NEW.event ->> 'from_account_id')::UUID;
+      new_row.public_id := (NEW.event ->> 'public_id');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.status := (NEW.event ->> 'status');
+      new_row.to_account_id := (NEW.event ->> 'to_account_id')::UUID;
+    WHEN 'approval_process_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'completed' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'failed' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'funds_held' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'funds_released' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+  END CASE;
+
+  INSERT INTO core_transfer_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    amount,
+    approval_process_id,
+    approved,
+    from_account_id,
+    ledger_tx_ids,
+    public_id,
+    reference,
+    status,
+    to_account_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.amount,
+    new_row.approval_process_id,
+    new_row.approved,
+    new_row.from_account_id,
+    new_row.ledger_tx_ids,
+    new_row.public_id,
+    new_row.reference,
+    new_row.status,
+    new_row.to_account_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for TransferEvent
+CREATE TRIGGER core_transfer_events_rollup_trigger
+  AFTER INSERT ON core_transfer_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_transfer_events_rollup_trigger();
@@ -0,0 +1,120 @@
+-- Auto-generated rollup table for BeneficiaryEvent
+CREATE TABLE core_beneficiary_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  account_holder_id UUID,
+  cooling_off_ends_at TIMESTAMPTZ,
+  destination JSONB,
+  name VARCHAR,
+  reason VARCHAR,
+  status VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for BeneficiaryEvent
+CREATE OR REPLACE FUNCTION core_beneficiary_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_beneficiary_events_rollup%ROWTYPE;
+  new_row core_beneficiary_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_beneficiary_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'verified', 'verification_rejected', 'removed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.cooling_off_ends_at := (NEW.event ->> 'cooling_off_ends_at')::TIMESTAMPTZ;
+    new_row.destination := (NEW.event -> 'destination');
+    new_row.name := (NEW.event ->> 'name');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.status := (NEW.event ->> 'status');
+  ELSE
+    -- Default all fields to current values
+    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.cooling_off_ends_at := current_row.cooling_off_ends_at;
+    new_row.destination := current_row.destination;
+    new_row.name := current_row.name;
+    new_row.reason := current_row.reason;
+    new_row.status := current_row.status;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.cooling_off_ends_at := (NEW.event ->> 'cooling_off_ends_at')::TIMESTAMPTZ;
+      new_row.destination := (NEW.event -> 'destination');
+      new_row.name := (NEW.event ->> 'name');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'verified' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'verification_rejected' THEN
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'removed' THEN
+      new_row.status := (NEW.event ->> 'status');
+  END CASE;
+
+  INSERT INTO core_beneficiary_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    account_holder_id,
+    cooling_off_ends_at,
+    destination,
+    name,
+    reason,
+    status
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.account_holder_id,
+    new_row.cooling_off_ends_at,
+    new_row.destination,
+    new_row.name,
+    new_row.reason,
+    new_row.status
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for BeneficiaryEvent
+CREATE TRIGGER core_beneficiary_events_rollup_trigger
+  AFTER INSERT ON core_beneficiary_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_beneficiary_events_rollup_trigger();
@@ -0,0 +1,123 @@
+-- Auto-generated rollup table for PaymentFileEvent
+CREATE TABLE core_payment_file_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  accepted JSONB,
+  control_sum BIGINT,
+  document_id UUID,
+  message_id VARCHAR,
+  rejected JSONB,
+  status VARCHAR,
+  withdrawal_ids JSONB
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for PaymentFileEvent
+CREATE OR REPLACE FUNCTION core_payment_file_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_payment_file_events_rollup%ROWTYPE;
+  new_row core_payment_file_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_payment_file_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'status_report_imported') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.accepted := (NEW.event -> 'accepted');
+    new_row.control_sum := (NEW.event ->> 'control_sum')::BIGINT;
+    new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+    new_row.message_id := (NEW.event ->> 'message_id');
+    new_row.rejected := (NEW.event -> 'rejected');
+    new_row.status := (NEW.event ->> 'status');
+    new_row.withdrawal_ids := (NEW.event -> 'withdrawal_ids');
+  ELSE
+    -- Default all fields to current values
+    new_row.accepted := current_row.accepted;
+    new_row.control_sum := current_row.control_sum;
+    new_row.document_id := current_row.document_id;
+    new_row.message_id := current_row.message_id;
+    new_row.rejected := current_row.rejected;
+    new_row.status := current_row.status;
+    new_row.withdrawal_ids := current_row.withdrawal_ids;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.control_sum := (NEW.event ->> 'control_sum')::BIGINT;
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.message_id := (NEW.event ->> 'message_id');
+      new_row.status := (NEW.event ->> 'status');
+      new_row.withdrawal_ids := (NEW.event -> 'withdrawal_ids');
+    WHEN 'status_report_imported' THEN
+      new_row.accepted := (NEW.event -> 'accepted');
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.rejected := (NEW.event -> 'rejected');
+      new_row.status := (NEW.event ->> 'status');
+  END CASE;
+
+  INSERT INTO core_payment_file_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    accepted,
+    control_sum,
+    document_id,
+    message_id,
+    rejected,
+    status,
+    withdrawal_ids
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.accepted,
+    new_row.control_sum,
+    new_row.document_id,
+    new_row.message_id,
+    new_row.rejected,
+    new_row.status,
+    new_row.withdrawal_ids
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for PaymentFileEvent
+CREATE TRIGGER core_payment_file_events_rollup_trigger
+  AFTER INSERT ON core_payment_file_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_payment_file_events_rollup_trigger();
@@ -0,0 +1,138 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for HoldEvent
+CREATE TABLE core_hold_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  currency VARCHAR,
+  deposit_account_id UUID,
+  expires_at TIMESTAMPTZ,
+  reason VARCHAR,
+  status VARCHAR,
+
+  -- Collection rollups
+  ledger_tx_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_hold_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_hold_events_rollup ADD COLUMN IF NOT EXISTS currency VARCHAR;
+
+
+-- Auto-generated trigger function for HoldEvent
+CREATE OR REPLACE FUNCTION core_hold_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_hold_events_rollup%ROWTYPE;
+  new_row core_hold_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_hold_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'extended', 'released', 'expired') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.currency := (NEW.event ->> 'currency');
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.status := (NEW.event ->> 'status');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.currency := current_row.currency;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.expires_at := current_row.expires_at;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.reason := current_row.reason;
+    new_row.status := current_row.status;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.currency := (NEW.event ->> 'currency');
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'extended' THEN
+      new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+    WHEN 'released' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'expired' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.status := (NEW.event ->> 'status');
+  END CASE;
+
+  INSERT INTO core_hold_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    amount,
+    currency,
+    deposit_account_id,
+    expires_at,
+    ledger_tx_ids,
+    reason,
+    status
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.amount,
+    new_row.currency,
+    new_row.deposit_account_id,
+    new_row.expires_at,
+    new_row.ledger_tx_ids,
+    new_row.reason,
+    new_row.status
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
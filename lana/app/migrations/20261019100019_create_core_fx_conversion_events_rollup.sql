@@ -0,0 +1,120 @@
+-- Auto-generated rollup table for FxConversionEvent
+CREATE TABLE core_fx_conversion_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  ledger_tx_id UUID,
+  rate VARCHAR,
+  reference VARCHAR,
+  source_account_id UUID,
+  source_amount JSONB,
+  target_account_id UUID,
+  target_amount JSONB
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for FxConversionEvent
+CREATE OR REPLACE FUNCTION core_fx_conversion_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_fx_conversion_events_rollup%ROWTYPE;
+  new_row core_fx_conversion_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_fx_conversion_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.rate := (NEW.event ->> 'rate');
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.source_account_id := (NEW.event ->> 'source_account_id')::UUID;
+    new_row.source_amount := (NEW.event -> 'source_amount');
+    new_row.target_account_id := (NEW.event ->> 'target_account_id')::UUID;
+    new_row.target_amount := (NEW.event -> 'target_amount');
+  ELSE
+    -- Default all fields to current values
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.rate := current_row.rate;
+    new_row.reference := current_row.reference;
+    new_row.source_account_id := current_row.source_account_id;
+    new_row.source_amount := current_row.source_amount;
+    new_row.target_account_id := current_row.target_account_id;
+    new_row.target_amount := current_row.target_amount;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.rate := (NEW.event ->> 'rate');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.source_account_id := (NEW.event ->> 'source_account_id')::UUID;
+      new_row.source_amount := (NEW.event -> 'source_amount');
+      new_row.target_account_id := (NEW.event ->> 'target_account_id')::UUID;
+      new_row.target_amount := (NEW.event -> 'target_amount');
+  END CASE;
+
+  INSERT INTO core_fx_conversion_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    ledger_tx_id,
+    rate,
+    reference,
+    source_account_id,
+    source_amount,
+    target_account_id,
+    target_amount
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.ledger_tx_id,
+    new_row.rate,
+    new_row.reference,
+    new_row.source_account_id,
+    new_row.source_amount,
+    new_row.target_account_id,
+    new_row.target_amount
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for FxConversionEvent
+CREATE TRIGGER core_fx_conversion_events_rollup_trigger
+  AFTER INSERT ON core_fx_conversion_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_fx_conversion_events_rollup_trigger();
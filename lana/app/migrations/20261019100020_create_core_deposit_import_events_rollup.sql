@@ -0,0 +1,123 @@
+-- Auto-generated rollup table for DepositImportEvent
+CREATE TABLE core_deposit_import_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  document_id UUID,
+  file_name VARCHAR,
+  line BIGINT,
+  outcome JSONB,
+  row_errors JSONB,
+  rows JSONB,
+  status VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for DepositImportEvent
+CREATE OR REPLACE FUNCTION core_deposit_import_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_import_events_rollup%ROWTYPE;
+  new_row core_deposit_import_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_import_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'row_processed', 'completed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+    new_row.file_name := (NEW.event ->> 'file_name');
+    new_row.line := (NEW.event ->> 'line')::BIGINT;
+    new_row.outcome := (NEW.event -> 'outcome');
+    new_row.row_errors := (NEW.event -> 'row_errors');
+    new_row.rows := (NEW.event -> 'rows');
+    new_row.status := (NEW.event ->> 'status');
+  ELSE
+    -- Default all fields to current values
+    new_row.document_id := current_row.document_id;
+    new_row.file_name := current_row.file_name;
+    new_row.line := current_row.line;
+    new_row.outcome := current_row.outcome;
+    new_row.row_errors := current_row.row_errors;
+    new_row.rows := current_row.rows;
+    new_row.status := current_row.status;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.file_name := (NEW.event ->> 'file_name');
+      new_row.row_errors := (NEW.event -> 'row_errors');
+      new_row.rows := (NEW.event -> 'rows');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'row_processed' THEN
+      new_row.line := (NEW.event ->> 'line')::BIGINT;
+      new_row.outcome := (NEW.event -> 'outcome');
+    WHEN 'completed' THEN
+      new_row.status := (NEW.event ->> 'status');
+  END CASE;
+
+  INSERT INTO core_deposit_import_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    document_id,
+    file_name,
+    line,
+    outcome,
+    row_errors,
+    rows,
+    status
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.document_id,
+    new_row.file_name,
+    new_row.line,
+    new_row.outcome,
+    new_row.row_errors,
+    new_row.rows,
+    new_row.status
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for DepositImportEvent
+CREATE TRIGGER core_deposit_import_events_rollup_trigger
+  AFTER INSERT ON core_deposit_import_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_deposit_import_events_rollup_trigger();
@@ -0,0 +1,142 @@
+-- Auto-generated rollup table for AccountClosureEvent
+CREATE TABLE core_account_closure_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  closed_at TIMESTAMPTZ,
+  closing_statement_document_id UUID,
+  deposit_account_id UUID,
+  hold_treatment JSONB,
+  payout JSONB,
+  payout_amount BIGINT,
+  payout_transaction JSONB,
+  reason VARCHAR,
+  released_hold_ids JSONB,
+  status VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for AccountClosureEvent
+CREATE OR REPLACE FUNCTION core_account_closure_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_account_closure_events_rollup%ROWTYPE;
+  new_row core_account_closure_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_account_closure_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'closed', 'failed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.closed_at := (NEW.event ->> 'closed_at')::TIMESTAMPTZ;
+    new_row.closing_statement_document_id := (NEW.event ->> 'closing_statement_document_id')::UUID;
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.hold_treatment := (NEW.event -> 'hold_treatment');
+    new_row.payout := (NEW.event -> 'payout');
+    new_row.payout_amount := (NEW.event ->> 'payout_amount')::BIGINT;
+    new_row.payout_transaction := (NEW.event -> 'payout_transaction');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.released_hold_ids := (NEW.event -> 'released_hold_ids');
+    new_row.status := (NEW.event ->> 'status');
+  ELSE
+    -- Default all fields to current values
+    new_row.closed_at := current_row.closed_at;
+    new_row.closing_statement_document_id := current_row.closing_statement_document_id;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.hold_treatment := current_row.hold_treatment;
+    new_row.payout := current_row.payout;
+    new_row.payout_amount := current_row.payout_amount;
+    new_row.payout_transaction := current_row.payout_transaction;
+    new_row.reason := current_row.reason;
+    new_row.released_hold_ids := current_row.released_hold_ids;
+    new_row.status := current_row.status;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.hold_treatment := (NEW.event -> 'hold_treatment');
+      new_row.payout := (NEW.event -> 'payout');
+      new_row.payout_amount := (NEW.event ->> 'payout_amount')::BIGINT;
+      new_row.payout_transaction := (NEW.event -> 'payout_transaction');
+      new_row.released_hold_ids := (NEW.event -> 'released_hold_ids');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'closed' THEN
+      new_row.closed_at := (NEW.event ->> 'closed_at')::TIMESTAMPTZ;
+      new_row.closing_statement_document_id := (NEW.event ->> 'closing_statement_document_id')::UUID;
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'failed' THEN
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.status := (NEW.event ->> 'status');
+  END CASE;
+
+  INSERT INTO core_account_closure_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    closed_at,
+    closing_statement_document_id,
+    deposit_account_id,
+    hold_treatment,
+    payout,
+    payout_amount,
+    payout_transaction,
+    reason,
+    released_hold_ids,
+    status
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.closed_at,
+    new_row.closing_statement_document_id,
+    new_row.deposit_account_id,
+    new_row.hold_treatment,
+    new_row.payout,
+    new_row.payout_amount,
+    new_row.payout_transaction,
+    new_row.reason,
+    new_row.released_hold_ids,
+    new_row.status
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for AccountClosureEvent
+CREATE TRIGGER core_account_closure_events_rollup_trigger
+  AFTER INSERT ON core_account_closure_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_account_closure_events_rollup_trigger();
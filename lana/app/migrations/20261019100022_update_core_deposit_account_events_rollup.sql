@@ -0,0 +1,285 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for DepositAccountEvent
+CREATE TABLE core_deposit_account_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  account_holder_id UUID,
+  account_ids JSONB,
+  accrued_on VARCHAR,
+  activity VARCHAR,
+  amount BIGINT,
+  approval_process_id UUID,
+  approved BOOLEAN,
+  assigned_at TIMESTAMPTZ,
+  balance BIGINT,
+  carried_remainder VARCHAR,
+  computed_on VARCHAR,
+  currency VARCHAR,
+  deposit_product_id UUID,
+  escheated_on VARCHAR,
+  escheatment_date VARCHAR,
+  fee_ledger_tx_id UUID,
+  fee_type VARCHAR,
+  interest VARCHAR,
+  ledger_tx_id UUID,
+  limits JSONB,
+  period_start VARCHAR,
+  posted_on VARCHAR,
+  public_id VARCHAR,
+  signing_rule JSONB,
+  status VARCHAR,
+  withdrawal_id UUID
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_deposit_account_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS signing_rule JSONB;
+
+
+-- Auto-generated trigger function for DepositAccountEvent
+CREATE OR REPLACE FUNCTION core_deposit_account_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_account_events_rollup%ROWTYPE;
+  new_row core_deposit_account_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_account_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'activity_updated', 'frozen', 'unfrozen', 'closed', 'deposit_product_assigned', 'daily_interest_computed', 'interest_accrued', 'interest_posted', 'early_withdrawal_penalty_charged', 'fee_charged', 'fee_reversed', 'escheatment_notice_issued', 'escheated', 'escheated_balance_reclaimed', 'withdrawal_limit_increase_requested', 'withdrawal_limit_increase_concluded', 'joint_holder_added', 'joint_holder_removed', 'holder_signing_rule_updated', 'holder_suspended', 'holder_reinstated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.account_ids := (NEW.event -> 'account_ids');
+    new_row.accrued_on := (NEW.event ->> 'accrued_on');
+    new_row.activity := (NEW.event ->> 'activity');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.assigned_at := (NEW.event ->> 'assigned_at')::TIMESTAMPTZ;
+    new_row.balance := (NEW.event ->> 'balance')::BIGINT;
+    new_row.carried_remainder := (NEW.event ->> 'carried_remainder');
+    new_row.computed_on := (NEW.event ->> 'computed_on');
+    new_row.currency := (NEW.event ->> 'currency');
+    new_row.deposit_product_id := (NEW.event ->> 'deposit_product_id')::UUID;
+    new_row.escheated_on := (NEW.event ->> 'escheated_on');
+    new_row.escheatment_date := (NEW.event ->> 'escheatment_date');
+    new_row.fee_ledger_tx_id := (NEW.event ->> 'fee_ledger_tx_id')::UUID;
+    new_row.fee_type := (NEW.event ->> 'fee_type');
+    new_row.interest := (NEW.event ->> 'interest');
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.limits := (NEW.event -> 'limits');
+    new_row.period_start := (NEW.event ->> 'period_start');
+    new_row.posted_on := (NEW.event ->> 'posted_on');
+    new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.signing_rule := (NEW.event -> 'signing_rule');
+    new_row.status := (NEW.event ->> 'status');
+    new_row.withdrawal_id := (NEW.event ->> 'withdrawal_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.account_ids := current_row.account_ids;
+    new_row.accrued_on := current_row.accrued_on;
+    new_row.activity := current_row.activity;
+    new_row.amount := current_row.amount;
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
+    new_row.assigned_at := current_row.assigned_at;
+    new_row.balance := current_row.balance;
+    new_row.carried_remainder := current_row.carried_remainder;
+    new_row.computed_on := current_row.computed_on;
+    new_row.currency := current_row.currency;
+    new_row.deposit_product_id := current_row.deposit_product_id;
+    new_row.escheated_on := current_row.escheated_on;
+    new_row.escheatment_date := current_row.escheatment_date;
+    new_row.fee_ledger_tx_id := current_row.fee_ledger_tx_id;
+    new_row.fee_type := current_row.fee_type;
+    new_row.interest := current_row.interest;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.limits := current_row.limits;
+    new_row.period_start := current_row.period_start;
+    new_row.posted_on := current_row.posted_on;
+    new_row.public_id := current_row.public_id;
+    new_row.signing_rule := current_row.signing_rule;
+    new_row.status := current_row.status;
+    new_row.withdrawal_id := current_row.withdrawal_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.account_ids := (NEW.event -> 'account_ids');
+      new_row.activity := (NEW.event ->> 'activity');
+      new_row.currency := (NEW.event ->> 'currency');
+      new_row.public_id := (NEW.event ->> 'public_id');
+      new_row.signing_rule := (NEW.event -> 'signing_rule');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'activity_updated' THEN
+      new_row.activity := (NEW.event ->> 'activity');
+    WHEN 'frozen' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'unfrozen' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'closed' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'deposit_product_assigned' THEN
+      new_row.assigned_at := (NEW.event ->> 'assigned_at')::TIMESTAMPTZ;
+      new_row.deposit_product_id := (NEW.event ->> 'deposit_product_id')::UUID;
+    WHEN 'daily_interest_computed' THEN
+      new_row.balance := (NEW.event ->> 'balance')::BIGINT;
+      new_row.computed_on := (NEW.event ->> 'computed_on');
+      new_row.interest := (NEW.event ->> 'interest');
+    WHEN 'interest_accrued' THEN
+      new_row.accrued_on := (NEW.event ->> 'accrued_on');
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.carried_remainder := (NEW.event ->> 'carried_remainder');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'interest_posted' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.posted_on := (NEW.event ->> 'posted_on');
+    WHEN 'early_withdrawal_penalty_charged' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.withdrawal_id := (NEW.event ->> 'withdrawal_id')::UUID;
+    WHEN 'fee_charged' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.fee_type := (NEW.event ->> 'fee_type');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.period_start := (NEW.event ->> 'period_start');
+      new_row.withdrawal_id := (NEW.event ->> 'withdrawal_id')::UUID;
+    WHEN 'fee_reversed' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.fee_ledger_tx_id := (NEW.event ->> 'fee_ledger_tx_id')::UUID;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'escheatment_notice_issued' THEN
+      new_row.escheatment_date := (NEW.event ->> 'escheatment_date');
+    WHEN 'escheated' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.escheated_on := (NEW.event ->> 'escheated_on');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'escheated_balance_reclaimed' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'withdrawal_limit_increase_requested' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.limits := (NEW.event -> 'limits');
+    WHEN 'withdrawal_limit_increase_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    WHEN 'joint_holder_added' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.signing_rule := (NEW.event -> 'signing_rule');
+    WHEN 'joint_holder_removed' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    WHEN 'holder_signing_rule_updated' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.signing_rule := (NEW.event -> 'signing_rule');
+    WHEN 'holder_suspended' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    WHEN 'holder_reinstated' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_deposit_account_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    account_holder_id,
+    account_ids,
+    accrued_on,
+    activity,
+    amount,
+    approval_process_id,
+    approved,
+    assigned_at,
+    balance,
+    carried_remainder,
+    computed_on,
+    currency,
+    deposit_product_id,
+    escheated_on,
+    escheatment_date,
+    fee_ledger_tx_id,
+    fee_type,
+    interest,
+    ledger_tx_id,
+    limits,
+    period_start,
+    posted_on,
+    public_id,
+    signing_rule,
+    status,
+    withdrawal_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.account_holder_id,
+    new_row.account_ids,
+    new_row.accrued_on,
+    new_row.activity,
+    new_row.amount,
+    new_row.approval_process_id,
+    new_row.approved,
+    new_row.assigned_at,
+    new_row.balance,
+    new_row.carried_remainder,
+    new_row.computed_on,
+    new_row.currency,
+    new_row.deposit_product_id,
+    new_row.escheated_on,
+    new_row.escheatment_date,
+    new_row.fee_ledger_tx_id,
+    new_row.fee_type,
+    new_row.interest,
+    new_row.ledger_tx_id,
+    new_row.limits,
+    new_row.period_start,
+    new_row.posted_on,
+    new_row.public_id,
+    new_row.signing_rule,
+    new_row.status,
+    new_row.withdrawal_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
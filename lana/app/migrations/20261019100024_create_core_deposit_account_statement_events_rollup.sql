@@ -0,0 +1,114 @@
+-- Auto-generated rollup table for DepositAccountStatementEvent
+CREATE TABLE core_deposit_account_statement_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  deposit_account_id UUID,
+  document_id UUID,
+  format VARCHAR,
+  monthly BOOLEAN,
+  period_end VARCHAR,
+  period_start VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for DepositAccountStatementEvent
+CREATE OR REPLACE FUNCTION core_deposit_account_statement_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_account_statement_events_rollup%ROWTYPE;
+  new_row core_deposit_account_statement_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_account_statement_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+    new_row.format := (NEW.event ->> 'format');
+    new_row.monthly := (NEW.event ->> 'monthly')::BOOLEAN;
+    new_row.period_end := (NEW.event ->> 'period_end');
+    new_row.period_start := (NEW.event ->> 'period_start');
+  ELSE
+    -- Default all fields to current values
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.document_id := current_row.document_id;
+    new_row.format := current_row.format;
+    new_row.monthly := current_row.monthly;
+    new_row.period_end := current_row.period_end;
+    new_row.period_start := current_row.period_start;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.format := (NEW.event ->> 'format');
+      new_row.monthly := (NEW.event ->> 'monthly')::BOOLEAN;
+      new_row.period_end := (NEW.event ->> 'period_end');
+      new_row.period_start := (NEW.event ->> 'period_start');
+  END CASE;
+
+  INSERT INTO core_deposit_account_statement_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    deposit_account_id,
+    document_id,
+    format,
+    monthly,
+    period_end,
+    period_start
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.deposit_account_id,
+    new_row.document_id,
+    new_row.format,
+    new_row.monthly,
+    new_row.period_end,
+    new_row.period_start
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for DepositAccountStatementEvent
+CREATE TRIGGER core_deposit_account_statement_events_rollup_trigger
+  AFTER INSERT ON core_deposit_account_statement_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_deposit_account_statement_events_rollup_trigger();
@@ -0,0 +1,147 @@
+-- Auto-generated rollup table for AmlAlertEvent
+CREATE TABLE core_aml_alert_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  account_id UUID,
+  amount BIGINT,
+  customer_id UUID,
+  details VARCHAR,
+  detected_at TIMESTAMPTZ,
+  note VARCHAR,
+  reason VARCHAR,
+  rule JSONB,
+  status VARCHAR,
+  transaction_ids JSONB
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for AmlAlertEvent
+CREATE OR REPLACE FUNCTION core_aml_alert_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_aml_alert_events_rollup%ROWTYPE;
+  new_row core_aml_alert_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_aml_alert_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'review_started', 'note_added', 'escalated', 'dismissed', 'customer_frozen') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_id := (NEW.event ->> 'account_id')::UUID;
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+    new_row.details := (NEW.event ->> 'details');
+    new_row.detected_at := (NEW.event ->> 'detected_at')::TIMESTAMPTZ;
+    new_row.note := (NEW.event ->> 'note');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.rule := (NEW.event -> 'rule');
+    new_row.status := (NEW.event ->> 'status');
+    new_row.transaction_ids := (NEW.event -> 'transaction_ids');
+  ELSE
+    -- Default all fields to current values
+    new_row.account_id := current_row.account_id;
+    new_row.amount := current_row.amount;
+    new_row.customer_id := current_row.customer_id;
+    new_row.details := current_row.details;
+    new_row.detected_at := current_row.detected_at;
+    new_row.note := current_row.note;
+    new_row.reason := current_row.reason;
+    new_row.rule := current_row.rule;
+    new_row.status := current_row.status;
+    new_row.transaction_ids := current_row.transaction_ids;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_id := (NEW.event ->> 'account_id')::UUID;
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+      new_row.details := (NEW.event ->> 'details');
+      new_row.detected_at := (NEW.event ->> 'detected_at')::TIMESTAMPTZ;
+      new_row.rule := (NEW.event -> 'rule');
+      new_row.status := (NEW.event ->> 'status');
+      new_row.transaction_ids := (NEW.event -> 'transaction_ids');
+    WHEN 'review_started' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'note_added' THEN
+      new_row.note := (NEW.event ->> 'note');
+    WHEN 'escalated' THEN
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'dismissed' THEN
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'customer_frozen' THEN
+  END CASE;
+
+  INSERT INTO core_aml_alert_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    account_id,
+    amount,
+    customer_id,
+    details,
+    detected_at,
+    note,
+    reason,
+    rule,
+    status,
+    transaction_ids
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.account_id,
+    new_row.amount,
+    new_row.customer_id,
+    new_row.details,
+    new_row.detected_at,
+    new_row.note,
+    new_row.reason,
+    new_row.rule,
+    new_row.status,
+    new_row.transaction_ids
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for AmlAlertEvent
+CREATE TRIGGER core_aml_alert_events_rollup_trigger
+  AFTER INSERT ON core_aml_alert_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_aml_alert_events_rollup_trigger();
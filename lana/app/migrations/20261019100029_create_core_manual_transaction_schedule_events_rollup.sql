@@ -0,0 +1,150 @@
+-- Auto-generated rollup table for ManualTransactionScheduleEvent
+CREATE TABLE core_manual_transaction_schedule_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  auto_reverse BOOLEAN,
+  description VARCHAR,
+  end_date VARCHAR,
+  entries JSONB,
+  frequency JSONB,
+  manual_transaction_id UUID,
+  period_end VARCHAR,
+  reference VARCHAR,
+  reversal_id UUID,
+  reverse_on VARCHAR,
+  start_date VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for ManualTransactionScheduleEvent
+CREATE OR REPLACE FUNCTION core_manual_transaction_schedule_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_manual_transaction_schedule_events_rollup%ROWTYPE;
+  new_row core_manual_transaction_schedule_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_manual_transaction_schedule_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'executed', 'execution_reversed', 'execution_denied', 'cancelled') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.auto_reverse := (NEW.event ->> 'auto_reverse')::BOOLEAN;
+    new_row.description := (NEW.event ->> 'description');
+    new_row.end_date := (NEW.event ->> 'end_date');
+    new_row.entries := (NEW.event -> 'entries');
+    new_row.frequency := (NEW.event -> 'frequency');
+    new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+    new_row.period_end := (NEW.event ->> 'period_end');
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.reversal_id := (NEW.event ->> 'reversal_id')::UUID;
+    new_row.reverse_on := (NEW.event ->> 'reverse_on');
+    new_row.start_date := (NEW.event ->> 'start_date');
+  ELSE
+    -- Default all fields to current values
+    new_row.auto_reverse := current_row.auto_reverse;
+    new_row.description := current_row.description;
+    new_row.end_date := current_row.end_date;
+    new_row.entries := current_row.entries;
+    new_row.frequency := current_row.frequency;
+    new_row.manual_transaction_id := current_row.manual_transaction_id;
+    new_row.period_end := current_row.period_end;
+    new_row.reference := current_row.reference;
+    new_row.reversal_id := current_row.reversal_id;
+    new_row.reverse_on := current_row.reverse_on;
+    new_row.start_date := current_row.start_date;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.auto_reverse := (NEW.event ->> 'auto_reverse')::BOOLEAN;
+      new_row.description := (NEW.event ->> 'description');
+      new_row.end_date := (NEW.event ->> 'end_date');
+      new_row.entries := (NEW.event -> 'entries');
+      new_row.frequency := (NEW.event -> 'frequency');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.start_date := (NEW.event ->> 'start_date');
+    WHEN 'executed' THEN
+      new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+      new_row.period_end := (NEW.event ->> 'period_end');
+      new_row.reverse_on := (NEW.event ->> 'reverse_on');
+    WHEN 'execution_reversed' THEN
+      new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+      new_row.reversal_id := (NEW.event ->> 'reversal_id')::UUID;
+    WHEN 'execution_denied' THEN
+      new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+    WHEN 'cancelled' THEN
+  END CASE;
+
+  INSERT INTO core_manual_transaction_schedule_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    auto_reverse,
+    description,
+    end_date,
+    entries,
+    frequency,
+    manual_transaction_id,
+    period_end,
+    reference,
+    reversal_id,
+    reverse_on,
+    start_date
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.auto_reverse,
+    new_row.description,
+    new_row.end_date,
+    new_row.entries,
+    new_row.frequency,
+    new_row.manual_transaction_id,
+    new_row.period_end,
+    new_row.reference,
+    new_row.reversal_id,
+    new_row.reverse_on,
+    new_row.start_date
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for ManualTransactionScheduleEvent
+CREATE TRIGGER core_manual_transaction_schedule_events_rollup_trigger
+  AFTER INSERT ON core_manual_transaction_schedule_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_manual_transaction_schedule_events_rollup_trigger();
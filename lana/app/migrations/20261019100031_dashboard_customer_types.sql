@@ -0,0 +1,21 @@
+CREATE TABLE dashboard_party_customer_types (
+  id UUID PRIMARY KEY,
+  customer_type VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  modified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE dashboard_customer_parties (
+  id UUID PRIMARY KEY,
+  party_id UUID NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Customer types used to be kept in the dashboard row itself. Rebuild the dashboard
+-- so the new tables are backfilled by replaying the outbox from the beginning.
+DELETE FROM dashboards;
+DELETE FROM dashboard_open_obligations;
+DELETE FROM dashboard_facility_exposures;
+DELETE FROM job_executions WHERE job_type = 'outbox.dashboard-projection';
+DELETE FROM job_events WHERE id IN (SELECT id FROM jobs WHERE job_type = 'outbox.dashboard-projection');
+DELETE FROM jobs WHERE job_type = 'outbox.dashboard-projection';
@@ -30,6 +30,8 @@ pub enum AccountingInitError {
     ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
     #[error("AccountingInitError - BalanceSheetError: {0}")]
     BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("AccountingInitError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] crate::cash_flow::error::CashFlowStatementError),
     #[error("AccountingInitError - FiscalYearError: {0}")]
     FiscalYearError(#[from] crate::fiscal_year::error::FiscalYearError),
     #[error("AccountingInitError - SeedFileError: {0}")]
@@ -57,6 +59,7 @@ impl ErrorSeverity for AccountingInitError {
             Self::TrialBalanceError(e) => e.severity(),
             Self::ProfitAndLossStatementError(e) => e.severity(),
             Self::BalanceSheetError(e) => e.severity(),
+            Self::CashFlowStatementError(e) => e.severity(),
             Self::FiscalYearError(e) => e.severity(),
             Self::SeedFileError(_) => Level::ERROR,
             Self::MissingConfig(_) => Level::ERROR,
@@ -7,7 +7,7 @@ use tracing_macros::record_error_severity;
 
 use crate::{
     accounting::Accounting, app::AccountingInitConfig, balance_sheet::BalanceSheets,
-    credit::Credit, deposit::Deposits, primitives::CalaJournalId,
+    cash_flow::CashFlowStatements, credit::Credit, deposit::Deposits, primitives::CalaJournalId,
     profit_and_loss::ProfitAndLossStatements, trial_balance::TrialBalances,
 };
 
@@ -39,6 +39,7 @@ impl StatementsInit {
             accounting.trial_balances(),
             accounting.profit_and_loss(),
             accounting.balance_sheets(),
+            accounting.cash_flow_statements(),
         )
         .await?;
         Ok(())
@@ -26,6 +26,8 @@ pub enum ApplicationError {
     ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
     #[error("ApplicationError - BalanceSheetError: {0}")]
     BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("ApplicationError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] crate::cash_flow::error::CashFlowStatementError),
     #[error("ApplicationError - CoreAccessError: {0}")]
     CoreAccessError(#[from] crate::access::error::CoreAccessError),
     #[error("ApplicationError - UserOnboardingError: {0}")]
@@ -46,8 +48,12 @@ pub enum ApplicationError {
     GovernanceError(#[from] governance::error::GovernanceError),
     #[error("ApplicationError - DashboardError: {0}")]
     DashboardError(#[from] dashboard::error::DashboardError),
+    #[error("ApplicationError - AmlError: {0}")]
+    AmlError(#[from] crate::aml::error::AmlError),
     #[error("ApplicationError - CalaInit: {0}")]
     CalaError(#[from] cala_ledger::error::LedgerError),
+    #[error("ApplicationError - CoreAccountingError: {0}")]
+    CoreAccountingError(#[from] core_accounting::error::CoreAccountingError),
     #[error("ApplicationError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
     #[error("ApplicationError - DepositError: {0}")]
@@ -86,6 +92,7 @@ impl ErrorSeverity for ApplicationError {
             Self::TrialBalanceError(e) => e.severity(),
             Self::ProfitAndLossStatementError(e) => e.severity(),
             Self::BalanceSheetError(e) => e.severity(),
+            Self::CashFlowStatementError(e) => e.severity(),
             Self::CoreAccessError(e) => e.severity(),
             Self::UserOnboardingError(e) => e.severity(),
             Self::AuthorizationError(e) => e.severity(),
@@ -95,7 +102,9 @@ impl ErrorSeverity for ApplicationError {
             Self::AccountingInitError(e) => e.severity(),
             Self::GovernanceError(e) => e.severity(),
             Self::DashboardError(e) => e.severity(),
+            Self::AmlError(e) => e.severity(),
             Self::CalaError(_) => Level::ERROR,
+            Self::CoreAccountingError(e) => e.severity(),
             Self::ChartOfAccountsError(e) => e.severity(),
             Self::DepositError(e) => e.severity(),
             Self::StorageError(e) => e.severity(),
@@ -15,6 +15,7 @@ use crate::{
     access::Access,
     accounting::Accounting,
     accounting_init::{ChartsInit, JournalInit, StatementsInit},
+    aml::Aml,
     audit::{Audit, AuditCursor, AuditEntry},
     authorization::{Authorization, seed},
     contract_creation::ContractCreation,
@@ -63,6 +64,7 @@ pub struct LanaApp {
     outbox: Outbox,
     governance: Governance,
     dashboard: Dashboard,
+    aml: Aml,
     public_ids: PublicIds,
     contract_creation: ContractCreation,
     reports: Reports,
@@ -174,7 +176,7 @@ impl LanaApp {
             .expect("cala config");
         let cala = cala_ledger::CalaLedger::init(cala_config).await?;
         let journal_init = JournalInit::journal(&cala).await?;
-        let accounting = Accounting::new(
+        let accounting = Accounting::init(
             &pool,
             &authz,
             &cala,
@@ -182,7 +184,11 @@ impl LanaApp {
             documents.clone(),
             &mut jobs,
             &outbox,
-        );
+            &governance,
+            &exposed_domain_configs_readonly,
+            core_price.price(),
+        )
+        .await?;
 
         StatementsInit::statements(&accounting).await?;
 
@@ -207,6 +213,8 @@ impl LanaApp {
             &customers,
             &exposed_domain_configs_readonly,
             &internal_domain_configs,
+            &documents,
+            config.gotenberg.clone(),
         )
         .await?;
         let customer_kyc = CustomerKyc::init(
@@ -261,6 +269,20 @@ impl LanaApp {
             &public_ids,
             &exposed_domain_configs_readonly,
             &internal_domain_configs,
+            &documents,
+            config.gotenberg.clone(),
+        )
+        .await?;
+
+        let aml = Aml::init(
+            &pool,
+            &authz,
+            &mut jobs,
+            &outbox,
+            &customers,
+            &reports,
+            &exposed_domain_configs_readonly,
+            clock.clone(),
         )
         .await?;
 
@@ -309,6 +331,7 @@ impl LanaApp {
             outbox,
             governance,
             dashboard,
+            aml,
             public_ids,
             contract_creation,
             reports,
@@ -325,6 +348,10 @@ impl LanaApp {
         &self.dashboard
     }
 
+    pub fn aml(&self) -> &Aml {
+        &self.aml
+    }
+
     pub fn exposed_domain_configs(&self) -> &ExposedDomainConfigs<Authorization> {
         &self.exposed_domain_configs
     }
@@ -590,6 +617,32 @@ impl LanaApp {
         Ok(ret)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "lana.app.request_disbursal", skip(self))]
+    pub async fn request_disbursal(
+        &self,
+        sub: &Subject,
+        credit_facility_id: impl Into<crate::primitives::CreditFacilityId> + std::fmt::Debug + Copy,
+        deposit_account_id: impl Into<crate::primitives::DepositAccountId> + std::fmt::Debug + Copy,
+        amount: money::UsdCents,
+    ) -> Result<crate::credit::Disbursal, ApplicationError> {
+        let deposit_account = self
+            .deposits()
+            .for_subject(sub)?
+            .find_account_by_id(deposit_account_id)
+            .await?;
+        if deposit_account.is_closed() || deposit_account.is_frozen() {
+            return Err(ApplicationError::ClosedOrFrozenAccount);
+        }
+
+        let ret = self
+            .credit()
+            .request_disbursal(sub, credit_facility_id.into(), deposit_account.id, amount)
+            .await?;
+
+        Ok(ret)
+    }
+
     #[record_error_severity]
     #[instrument(name = "lana.app.close_customer", skip(self))]
     pub async fn close_customer(
@@ -687,8 +740,10 @@ impl LanaApp {
             if withdrawals.iter().any(|w| {
                 matches!(
                     w.status(),
-                    crate::deposit::WithdrawalStatus::PendingApproval
+                    crate::deposit::WithdrawalStatus::PendingSignatures
+                        | crate::deposit::WithdrawalStatus::PendingApproval
                         | crate::deposit::WithdrawalStatus::PendingConfirmation
+                        | crate::deposit::WithdrawalStatus::PaymentSent
                 )
             }) {
                 return Err(ApplicationError::CustomerClosePreconditionFailed(
@@ -1,3 +1,4 @@
+use aml::{PERMISSION_SET_AML_VIEWER, PERMISSION_SET_AML_WRITER};
 use core_access::{PERMISSION_SET_ACCESS_VIEWER, PERMISSION_SET_ACCESS_WRITER};
 use core_accounting::{PERMISSION_SET_ACCOUNTING_VIEWER, PERMISSION_SET_ACCOUNTING_WRITER};
 use core_credit::{
@@ -24,6 +25,8 @@ use rbac_types::{
 const ADMIN_PERMISSION_SETS: &[&str] = &[
     PERMISSION_SET_ACCOUNTING_VIEWER,
     PERMISSION_SET_ACCOUNTING_WRITER,
+    PERMISSION_SET_AML_VIEWER,
+    PERMISSION_SET_AML_WRITER,
     PERMISSION_SET_AUDIT_VIEWER,
     PERMISSION_SET_CUSTODY_VIEWER,
     PERMISSION_SET_CUSTODY_WRITER,
@@ -54,6 +57,8 @@ const ADMIN_PERMISSION_SETS: &[&str] = &[
 const BANK_MANAGER_PERMISSION_SETS: &[&str] = &[
     PERMISSION_SET_ACCOUNTING_VIEWER,
     PERMISSION_SET_ACCOUNTING_WRITER,
+    PERMISSION_SET_AML_VIEWER,
+    PERMISSION_SET_AML_WRITER,
     PERMISSION_SET_AUDIT_VIEWER,
     PERMISSION_SET_CREDIT_VIEWER,
     PERMISSION_SET_CREDIT_WRITER,
@@ -77,6 +82,7 @@ const BANK_MANAGER_PERMISSION_SETS: &[&str] = &[
 const ACCOUNTANT_PERMISSION_SETS: &[&str] = &[
     PERMISSION_SET_ACCOUNTING_VIEWER,
     PERMISSION_SET_ACCOUNTING_WRITER,
+    PERMISSION_SET_AML_VIEWER,
     PERMISSION_SET_AUDIT_VIEWER,
     PERMISSION_SET_CREDIT_VIEWER,
     PERMISSION_SET_COLLECTION_VIEWER,
@@ -31,7 +31,17 @@ pub mod outbox {
 }
 pub mod dashboard {
     pub type Dashboard = dashboard::Dashboard<crate::authorization::Authorization>;
-    pub use dashboard::DashboardValues;
+    pub use dashboard::{
+        BorrowerConcentration, CustomerTypeExposure, CvlDistributionBucket, DashboardValues,
+        DaysPastDueBucket, PortfolioRisk, PriceShockOutcome,
+    };
+}
+
+pub mod aml {
+    pub use aml::{
+        AmlAlert, AmlAlertId, AmlAlertStatus, AmlAlertsByCreatedAtCursor, AmlRule, error,
+    };
+    pub type Aml = aml::Aml<crate::authorization::Authorization, lana_events::LanaEvent>;
 }
 
 pub mod user_onboarding {
@@ -93,7 +103,11 @@ pub mod governance {
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
-    pub use core_deposit::APPROVE_WITHDRAWAL_PROCESS;
+    pub use core_accounting::APPROVE_MANUAL_TRANSACTION_PROCESS;
+    pub use core_deposit::{
+        APPROVE_TRANSFER_PROCESS, APPROVE_WITHDRAWAL_LIMIT_INCREASE_PROCESS,
+        APPROVE_WITHDRAWAL_PROCESS,
+    };
     pub use governance::{
         ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, ApprovalRules, Committee,
         CommitteeId, CommitteesSortBy, Policy, approval_process_cursor, committee_cursor, error,
@@ -114,14 +128,31 @@ pub mod audit {
 
 pub mod deposit {
     pub use core_deposit::{
-        Activity, ChartOfAccountsIntegrationConfig, CoreDepositEvent, DEPOSIT_ACCOUNT_ENTITY_TYPE,
+        AccountClosure, AccountClosureHoldTreatment, AccountClosurePayout,
+        AccountClosurePayoutTransaction, AccountClosureStatus, AccountClosuresByCreatedAtCursor,
+        Activity, BankAccountDetails, BeneficiariesByCreatedAtCursor, Beneficiary,
+        BeneficiaryDestination, BeneficiaryStatus, ChartOfAccountsIntegrationConfig,
+        CoreDepositEvent, CryptoAddressDetails, CryptoNetwork, DEPOSIT_ACCOUNT_ENTITY_TYPE,
         DEPOSIT_TRANSACTION_ENTITY_TYPE, Deposit, DepositAccount, DepositAccountBalance,
-        DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositAccountStatus,
-        DepositAccountsByCreatedAtCursor, DepositAccountsCursor, DepositAccountsFilters,
-        DepositAccountsSortBy, DepositId, DepositStatus, DepositsByCreatedAtCursor, DepositsCursor,
-        DepositsFilters, DepositsSortBy, WITHDRAWAL_TRANSACTION_ENTITY_TYPE, Withdrawal,
-        WithdrawalId, WithdrawalStatus, WithdrawalsByCreatedAtCursor, WithdrawalsCursor,
-        WithdrawalsFilters, WithdrawalsSortBy, error,
+        DepositAccountCurrencyBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry,
+        DepositAccountHolder, DepositAccountStatement, DepositAccountStatementFormat,
+        DepositAccountStatus, DepositAccountsByCreatedAtCursor, DepositAccountsCursor,
+        DepositAccountsFilters, DepositAccountsSortBy, DepositBeneficiaryCoolingOffHours,
+        DepositChannel, DepositFeeSchedule, DepositFeeType, DepositId, DepositImport,
+        DepositImportRow, DepositImportRowError, DepositImportRowOutcome, DepositImportStatus,
+        DepositImportsByCreatedAtCursor, DepositJointAccountSuspendHolderOnFreeze, DepositProduct,
+        DepositProductId, DepositProductValues, DepositProductsByNameCursor, DepositRatePct,
+        DepositStatus, DepositTransferApprovalThresholdCents, DepositsByCreatedAtCursor,
+        DepositsCursor, DepositsFilters, DepositsSortBy, EscheatmentRemittanceEntry,
+        FX_CONVERSION_TRANSACTION_ENTITY_TYPE, FxConversion, FxConversionsByCreatedAtCursor,
+        HOLD_TRANSACTION_ENTITY_TYPE, Hold, HoldId, HoldStatus, HoldsByCreatedAtCursor,
+        InterestFrequency, PaymentFile, PaymentFileStatus, PaymentFilesByCreatedAtCursor,
+        PaymentOriginator, SigningRule, TRANSFER_TRANSACTION_ENTITY_TYPE, TermDepositTerms,
+        Transfer, TransferId, TransferStatus, TransfersByCreatedAtCursor, TransfersCursor,
+        TransfersFilters, TransfersSortBy, WITHDRAWAL_TRANSACTION_ENTITY_TYPE, Withdrawal,
+        WithdrawalId, WithdrawalLimitPeriod, WithdrawalLimits, WithdrawalStatus,
+        WithdrawalsByCreatedAtCursor, WithdrawalsCursor, WithdrawalsFilters, WithdrawalsSortBy,
+        error,
     };
 
     pub type Deposits =
@@ -132,9 +163,13 @@ pub mod accounting {
     pub use core_accounting::{
         AccountCategory, AccountCode, AccountCodeSection, AccountInfo, AccountingCsvId,
         CalaAccountBalance, CalaAccountId, ChartId, CoreAccountingEvent, FiscalYearId,
-        LedgerAccountId, TransactionTemplateId, chart_of_accounts, csv, error, fiscal_year,
-        journal, ledger_account, ledger_transaction, manual_transaction, transaction_templates,
-        {Chart, tree},
+        LedgerAccountId, ManualTransaction, ManualTransactionApprovalThresholdCents,
+        ManualTransactionId, ManualTransactionSchedule, ManualTransactionScheduleExecution,
+        ManualTransactionScheduleFrequency, ManualTransactionScheduleId,
+        ManualTransactionScheduleStatus, ManualTransactionSchedulesByCreatedAtCursor,
+        ManualTransactionStatus, ManualTransactionsByCreatedAtCursor, TransactionTemplateId,
+        chart_of_accounts, csv, error, fiscal_year, journal, ledger_account, ledger_transaction,
+        manual_transaction, manual_transaction_schedule, transaction_templates, {Chart, tree},
     };
 
     pub type Accounting = core_accounting::CoreAccounting<
@@ -156,6 +191,12 @@ pub mod balance_sheet {
     pub type BalanceSheets = core_accounting::BalanceSheets<crate::authorization::Authorization>;
 }
 
+pub mod cash_flow {
+    pub use core_accounting::cash_flow::*;
+    pub type CashFlowStatements =
+        core_accounting::CashFlowStatements<crate::authorization::Authorization>;
+}
+
 pub mod trial_balance {
     pub use core_accounting::trial_balance::*;
     pub type TrialBalances = core_accounting::TrialBalances<crate::authorization::Authorization>;
@@ -185,14 +226,17 @@ pub mod credit {
         CreditFacilityProposalId, CreditFacilityProposalStatus,
         CreditFacilityProposalsByCreatedAtCursor, CreditFacilityProposalsCursor,
         CreditFacilityProposalsFilters, CreditFacilityProposalsSortBy,
-        CreditFacilityRepaymentPlanEntry, CreditFacilityStatus, DISBURSAL_TRANSACTION_ENTITY_TYPE,
-        Disbursal, DisbursalExecuted, DisbursalStatus, DisbursalsCursor, DisbursalsFilters,
-        DisbursalsSortBy, IncrementalPayment, InterestAccrualsPosted, ListDirection, Payment,
-        PaymentAllocation, PendingCreditFacilitiesByCreatedAtCursor, PendingCreditFacilitiesCursor,
+        CreditFacilityRepaymentPlanEntry, CreditFacilityStatement, CreditFacilityStatus,
+        CreditTermsSimulation, DISBURSAL_TRANSACTION_ENTITY_TYPE, Disbursal, DisbursalExecuted,
+        DisbursalStatus, DisbursalsCursor, DisbursalsFilters, DisbursalsSortBy, IncrementalPayment,
+        InterestAccrualsPosted, ListDirection, ParticipantStatement, ParticipantStatementEntry,
+        ParticipantStatementEntryType, Participation, Payment, PaymentAllocation,
+        PendingCreditFacilitiesByCreatedAtCursor, PendingCreditFacilitiesCursor,
         PendingCreditFacilitiesFilters, PendingCreditFacilitiesSortBy, PendingCreditFacility,
         PendingCreditFacilityCollateralizationUpdated, PendingCreditFacilityId,
-        PendingCreditFacilityStatus, ProceedsFromLiquidationReceived, RepaymentStatus,
-        RepaymentType, Sort, error,
+        PendingCreditFacilityStatus, PricePathPoint, PriceStressScenario,
+        ProceedsFromLiquidationReceived, RepaymentStatus, RepaymentType, Sort,
+        StressScenarioOutcome, StressScenarioPoint, error,
     };
 
     pub use core_credit_collateral::{
@@ -1,18 +1,23 @@
 pub use core_access::{PermissionSetId, RoleId, UserId};
 pub use core_accounting::{
     AccountInfo, AccountSpec, AccountingBaseConfig, BalanceRange, Chart, ChartId, FiscalYearId,
-    LedgerTransactionId, ManualTransactionId,
+    LedgerTransactionId, ManualTransactionId, ManualTransactionScheduleId,
 };
 pub use core_credit::{
-    CreditFacilityId, CreditFacilityProposalId, CreditFacilityProposalStatus, CreditFacilityStatus,
-    DisbursalId, DisbursalStatus, PaymentAllocationId, PaymentId,
+    CreditFacilityId, CreditFacilityProposalId, CreditFacilityProposalStatus,
+    CreditFacilityStatementId, CreditFacilityStatus, DisbursalId, DisbursalStatus, ParticipationId,
+    ParticipationSharePct, PaymentAllocationId, PaymentId,
     PendingCreditFacilityCollateralizationState, PendingCreditFacilityId,
     PendingCreditFacilityStatus, TermsTemplateId,
 };
 pub use core_credit_collateral::{CollateralDirection, CollateralId, LiquidationId};
 pub use core_custody::{CustodianId, WalletId};
 pub use core_customer::{CustomerDocumentId, CustomerId, PartyId, ProspectId};
-pub use core_deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use core_deposit::{
+    AccountClosureId, BeneficiaryId, DepositAccountHolderId, DepositAccountId,
+    DepositAccountStatementId, DepositId, DepositImportId, DepositProductId, FxConversionId,
+    HoldId, PaymentFileId, TransferId, WithdrawalId,
+};
 pub use core_price::PriceOfOneBTC;
 pub use core_report::ReportId;
 pub use document_storage::{DocumentId, ReferenceId};
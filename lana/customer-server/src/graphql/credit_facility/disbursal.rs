@@ -24,3 +24,11 @@ impl From<DomainDisbursal> for CreditFacilityDisbursal {
         }
     }
 }
+
+#[derive(InputObject)]
+pub struct CreditFacilityDisbursalRequestInput {
+    pub credit_facility_id: UUID,
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+}
+crate::mutation_payload! { CreditFacilityDisbursalRequestPayload, disbursal: CreditFacilityDisbursal }
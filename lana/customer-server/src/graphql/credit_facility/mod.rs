@@ -3,6 +3,7 @@ pub mod disbursal;
 mod history;
 pub(super) mod payment_allocation;
 mod repayment;
+mod statement;
 
 use async_graphql::*;
 
@@ -19,6 +20,7 @@ use balance::*;
 use disbursal::*;
 use history::*;
 use repayment::*;
+use statement::*;
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -122,4 +124,21 @@ impl CreditFacility {
             .repayment_plan(self.entity.id)
             .await?)
     }
+
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .credit()
+            .for_subject(sub)?
+            .statements(self.entity.id)
+            .await?;
+
+        Ok(statements
+            .into_iter()
+            .map(CreditFacilityStatement::from)
+            .collect())
+    }
 }
@@ -0,0 +1,42 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::credit::CreditFacilityStatement as DomainCreditFacilityStatement;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityStatement {
+    credit_facility_statement_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainCreditFacilityStatement>,
+}
+
+impl From<DomainCreditFacilityStatement> for CreditFacilityStatement {
+    fn from(statement: DomainCreditFacilityStatement) -> Self {
+        Self {
+            credit_facility_statement_id: UUID::from(statement.id),
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            created_at: statement.created_at().into(),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityStatement {
+    async fn download_link(&self, ctx: &Context<'_>) -> async_graphql::Result<String> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let link = app
+            .credit()
+            .for_subject(sub)?
+            .generate_statement_download_link(self.entity.id)
+            .await?;
+        Ok(link.link)
+    }
+}
@@ -99,6 +99,22 @@ impl Customer {
             .ok_or(CustomerError::DepositAccountNotFound)?)
     }
 
+    async fn joint_deposit_accounts(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccount>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        Ok(app
+            .deposits()
+            .for_subject(sub)?
+            .list_joint_accounts()
+            .await?
+            .into_iter()
+            .map(DepositAccount::from)
+            .collect())
+    }
+
     async fn credit_facilities(
         &self,
         ctx: &Context<'_>,
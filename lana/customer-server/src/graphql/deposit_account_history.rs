@@ -5,7 +5,7 @@ use crate::primitives::*;
 use super::{
     credit_facility::disbursal::CreditFacilityDisbursal,
     credit_facility::payment_allocation::CreditFacilityPaymentAllocation, deposit::Deposit,
-    withdrawal::Withdrawal,
+    transfer::Transfer, withdrawal::Withdrawal,
 };
 
 #[derive(Union)]
@@ -13,10 +13,19 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
+    OutgoingTransfer(OutgoingTransferEntry),
+    IncomingTransfer(IncomingTransferEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
     Freeze(FreezeEntry),
     Unfreeze(UnfreezeEntry),
+    InterestAccrual(InterestAccrualEntry),
+    InterestPosting(InterestPostingEntry),
+    EarlyWithdrawalPenalty(EarlyWithdrawalPenaltyEntry),
+    FeeCharge(FeeChargeEntry),
+    FeeReversal(FeeReversalEntry),
+    Escheatment(EscheatmentEntry),
+    EscheatmentReclaim(EscheatmentReclaimEntry),
     Unknown(UnknownEntry),
 }
 
@@ -43,6 +52,22 @@ pub struct CancelledWithdrawalEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct OutgoingTransferEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct IncomingTransferEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub struct DisbursalEntry {
@@ -73,6 +98,55 @@ pub struct UnfreezeEntry {
     pub amount: UsdCents,
 }
 
+#[derive(SimpleObject)]
+pub struct InterestAccrualEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct InterestPostingEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EarlyWithdrawalPenaltyEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct FeeChargeEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct FeeReversalEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EscheatmentEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EscheatmentReclaimEntry {
+    pub ledger_transaction_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub ledger_transaction_id: UUID,
@@ -124,6 +198,32 @@ impl CancelledWithdrawalEntry {
     }
 }
 
+#[ComplexObject]
+impl OutgoingTransferEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        find_transfer(ctx, self.tx_id).await
+    }
+}
+
+#[ComplexObject]
+impl IncomingTransferEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        find_transfer(ctx, self.tx_id).await
+    }
+}
+
+async fn find_transfer(ctx: &Context<'_>, tx_id: UUID) -> async_graphql::Result<Transfer> {
+    let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+    let transfer = app
+        .deposits()
+        .for_subject(sub)?
+        .find_transfer_by_id(tx_id)
+        .await?;
+
+    Ok(Transfer::from(transfer))
+}
+
 #[ComplexObject]
 impl DisbursalEntry {
     async fn disbursal(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityDisbursal> {
@@ -178,6 +278,18 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::OutgoingTransfer(entry) => {
+                Self::OutgoingTransfer(OutgoingTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::IncomingTransfer(entry) => {
+                Self::IncomingTransfer(IncomingTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Disbursal(entry) => {
                 Self::Disbursal(DisbursalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -204,6 +316,55 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     amount: entry.amount,
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestAccrual(entry) => {
+                Self::InterestAccrual(InterestAccrualEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestPosting(entry) => {
+                Self::InterestPosting(InterestPostingEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::EarlyWithdrawalPenalty(entry) => {
+                Self::EarlyWithdrawalPenalty(EarlyWithdrawalPenaltyEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::FeeCharge(entry) => {
+                Self::FeeCharge(FeeChargeEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::FeeReversal(entry) => {
+                Self::FeeReversal(FeeReversalEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::Escheatment(entry) => {
+                Self::Escheatment(EscheatmentEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::EscheatmentReclaim(entry) => {
+                Self::EscheatmentReclaim(EscheatmentReclaimEntry {
+                    ledger_transaction_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Unknown(entry) => {
                 Self::Unknown(UnknownEntry {
                     ledger_transaction_id: UUID::from(entry.tx_id),
@@ -0,0 +1,46 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    DepositAccountStatement as DomainDepositAccountStatement, DepositAccountStatementFormat,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositAccountStatement {
+    deposit_account_statement_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    format: DepositAccountStatementFormat,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositAccountStatement>,
+}
+
+impl From<DomainDepositAccountStatement> for DepositAccountStatement {
+    fn from(statement: DomainDepositAccountStatement) -> Self {
+        Self {
+            deposit_account_statement_id: UUID::from(statement.id),
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            format: statement.format,
+            created_at: statement.created_at().into(),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositAccountStatement {
+    async fn download_link(&self, ctx: &Context<'_>) -> async_graphql::Result<String> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .for_subject(sub)?
+            .generate_statement_download_link(self.entity.id)
+            .await?;
+        Ok(link.link)
+    }
+}
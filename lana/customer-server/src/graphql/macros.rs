@@ -20,3 +20,32 @@ macro_rules! app_and_sub_from_ctx {
         (app, sub)
     }};
 }
+
+/// Helper to create a 'standard' payload
+///
+/// Creates a payload struct of the form:
+/// ```rust
+/// pub struct SomeMutationPayload {
+///     entity: Entity
+/// }
+/// ```
+///
+/// Example usage:
+/// ```rust
+/// mutation_payload! { CreditFacilityDisbursalRequestPayload, disbursal: CreditFacilityDisbursal }
+/// ```
+#[macro_export]
+macro_rules! mutation_payload {
+    ($payload:ident, $name:ident: $gql_type:ty) => {
+        #[derive(SimpleObject)]
+        pub struct $payload {
+            $name: $gql_type,
+        }
+
+        impl From<$gql_type> for $payload {
+            fn from($name: $gql_type) -> Self {
+                Self { $name }
+            }
+        }
+    };
+}
@@ -5,10 +5,12 @@ mod customer;
 mod deposit;
 mod deposit_account;
 mod deposit_account_history;
+mod deposit_account_statement;
 mod me;
 mod price;
 mod schema;
 mod terms;
+mod transfer;
 mod withdrawal;
 
 use async_graphql::*;
@@ -17,9 +19,9 @@ pub use schema::*;
 
 use lana_app::app::LanaApp;
 
-pub fn schema(app: Option<LanaApp>) -> Schema<Query, EmptyMutation, EmptySubscription> {
+pub fn schema(app: Option<LanaApp>) -> Schema<Query, Mutation, EmptySubscription> {
     let mut schema_builder =
-        Schema::build(Query, EmptyMutation, EmptySubscription).extension(extensions::Tracing);
+        Schema::build(Query, Mutation, EmptySubscription).extension(extensions::Tracing);
 
     if let Some(app) = app {
         schema_builder = schema_builder.data(app);
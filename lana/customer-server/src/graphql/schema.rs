@@ -2,7 +2,12 @@ use async_graphql::{Context, Object};
 
 use crate::{LanaApp, primitives::*};
 
-use super::{credit_facility::*, me::*, price::*};
+use super::{
+    credit_facility::{disbursal::*, *},
+    me::*,
+    price::*,
+    transfer::*,
+};
 
 pub struct Query;
 
@@ -35,3 +40,48 @@ impl Query {
         Ok(usd_cents_per_btc.into())
     }
 }
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn credit_facility_disbursal_request(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityDisbursalRequestInput,
+    ) -> async_graphql::Result<CreditFacilityDisbursalRequestPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let disbursal = app
+            .request_disbursal(
+                sub,
+                input.credit_facility_id,
+                input.deposit_account_id,
+                input.amount,
+            )
+            .await?;
+        Ok(CreditFacilityDisbursalRequestPayload::from(
+            CreditFacilityDisbursal::from(disbursal),
+        ))
+    }
+
+    async fn deposit_account_transfer_request(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountTransferRequestInput,
+    ) -> async_graphql::Result<DepositAccountTransferRequestPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let transfer = app
+            .deposits()
+            .request_transfer(
+                sub,
+                input.from_account_id,
+                input.to_account_id,
+                input.amount,
+                input.reference,
+            )
+            .await?;
+        Ok(DepositAccountTransferRequestPayload::from(Transfer::from(
+            transfer,
+        )))
+    }
+}
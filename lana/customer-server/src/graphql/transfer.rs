@@ -0,0 +1,51 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{Transfer as DomainTransfer, TransferStatus};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Transfer {
+    id: ID,
+    transfer_id: UUID,
+    from_account_id: UUID,
+    to_account_id: UUID,
+    amount: UsdCents,
+    status: TransferStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<lana_app::deposit::Transfer> for Transfer {
+    fn from(transfer: lana_app::deposit::Transfer) -> Self {
+        Transfer {
+            id: transfer.id.to_global_id(),
+            created_at: transfer.created_at().into(),
+            from_account_id: transfer.from_account_id.into(),
+            to_account_id: transfer.to_account_id.into(),
+            transfer_id: UUID::from(transfer.id),
+            amount: transfer.amount,
+            status: transfer.status(),
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountTransferRequestInput {
+    pub from_account_id: UUID,
+    pub to_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { DepositAccountTransferRequestPayload, transfer: Transfer }
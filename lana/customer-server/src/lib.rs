@@ -87,7 +87,7 @@ pub struct CustomerJwtClaims {
 )]
 #[es_entity::es_event_context]
 pub async fn graphql_handler(
-    schema: Extension<Schema<graphql::Query, EmptyMutation, EmptySubscription>>,
+    schema: Extension<Schema<graphql::Query, graphql::Mutation, EmptySubscription>>,
     Claims(jwt_claims): Claims<CustomerJwtClaims>,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
@@ -7,7 +7,8 @@ pub use lana_app::{
     primitives::{
         CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId, DepositId,
         DisbursalId, DisbursalStatus, PaymentAllocationId,
-        PendingCreditFacilityCollateralizationState, Satoshis, Subject, UsdCents, WithdrawalId,
+        PendingCreditFacilityCollateralizationState, Satoshis, Subject, TransferId, UsdCents,
+        WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -87,6 +88,7 @@ impl_to_global_id! {
     DepositAccountId,
     DepositId,
     WithdrawalId,
+    TransferId,
     CreditFacilityId,
     DisbursalId,
     PaymentAllocationId
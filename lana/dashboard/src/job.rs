@@ -4,7 +4,7 @@ use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
 
 use job::JobType;
 
-use crate::repo::DashboardRepo;
+use crate::{portfolio::ExposureChange, repo::DashboardRepo};
 
 pub const DASHBOARD_PROJECTION_JOB: JobType = JobType::new("outbox.dashboard-projection");
 
@@ -29,11 +29,18 @@ where
         event: &PersistentOutboxEvent<E>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let mut dashboard = self.repo.load().await?;
-        if let Some(payload) = event.as_event::<lana_events::LanaEvent>()
-            && dashboard.process_event(event.recorded_at, payload)
-        {
-            event.inject_trace_parent();
-            Span::current().record("handled", true);
+        if let Some(payload) = event.as_event::<lana_events::LanaEvent>() {
+            let mut handled = dashboard.process_event(event.recorded_at, payload);
+            if let Some(change) = ExposureChange::from_event(payload) {
+                self.repo
+                    .apply_exposure_change_in_tx(op.tx_mut(), change)
+                    .await?;
+                handled = true;
+            }
+            if handled {
+                event.inject_trace_parent();
+                Span::current().record("handled", true);
+            }
         }
         self.repo.persist_in_tx(op.tx_mut(), &dashboard).await?;
         Ok(())
@@ -3,6 +3,7 @@
 
 pub mod error;
 mod job;
+mod portfolio;
 mod primitives;
 mod repo;
 mod values;
@@ -16,6 +17,7 @@ use tracing_macros::record_error_severity;
 
 use error::*;
 use job::*;
+pub use portfolio::*;
 pub use primitives::*;
 use repo::*;
 pub use values::*;
@@ -85,7 +87,8 @@ where
                 DashboardModuleAction::DASHBOARD_READ,
             )
             .await?;
-        let res = self.repo.load().await?;
+        let mut res = self.repo.load().await?;
+        self.repo.load_exposures(&mut res.portfolio_risk).await?;
         Ok(res)
     }
 }
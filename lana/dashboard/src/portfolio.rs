@@ -0,0 +1,554 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use core_credit_terms::{AnnualRatePct, CVLPct, TermValues};
+use core_customer::{CustomerId, CustomerType, PartyId};
+use core_price::PriceOfOneBTC;
+use lana_events::*;
+use money::{Satoshis, UsdCents};
+
+const CVL_BUCKET_BOUNDS: [u64; 4] = [100, 125, 150, 200];
+const DAYS_PAST_DUE_BUCKET_BOUNDS: [u32; 4] = [1, 31, 61, 91];
+const PRICE_SHOCK_PCTS: [u64; 3] = [10, 20, 30];
+
+#[derive(Clone, Debug)]
+pub(crate) struct FacilityExposure {
+    pub(crate) customer_id: CustomerId,
+    pub(crate) customer_type: CustomerType,
+    pub(crate) terms: TermValues,
+    pub(crate) activated_at: Option<DateTime<Utc>>,
+    pub(crate) collateral: Satoshis,
+    pub(crate) outstanding: UsdCents,
+}
+
+impl FacilityExposure {
+    fn cvl_at(&self, price: PriceOfOneBTC) -> CVLPct {
+        CVLPct::from_loan_amounts(
+            price.sats_to_cents_round_down(self.collateral),
+            self.outstanding,
+        )
+    }
+
+    fn matures_on(&self) -> Option<NaiveDate> {
+        self.activated_at
+            .map(|activated_at| self.terms.maturity_date(activated_at).into())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct OpenObligation {
+    pub(crate) credit_facility_id: uuid::Uuid,
+    pub(crate) due_at: NaiveDate,
+    pub(crate) outstanding: UsdCents,
+}
+
+/// A change to the per-facility exposure tables. Proposals are tracked from creation
+/// because terms and the borrower are only published at that point; the proposal,
+/// pending facility and facility all share the same id. Facilities and their
+/// obligations are pruned once the facility completes or the proposal is not approved.
+/// Customer types are kept per party and resolved when exposures are loaded, so a
+/// party update is reflected in every facility of its customers.
+pub(crate) enum ExposureChange {
+    PartyUpdated {
+        id: PartyId,
+        customer_type: CustomerType,
+    },
+    CustomerCreated {
+        id: CustomerId,
+        party_id: PartyId,
+    },
+    FacilityProposed {
+        id: uuid::Uuid,
+        customer_id: CustomerId,
+        terms: TermValues,
+    },
+    FacilityActivated {
+        id: uuid::Uuid,
+        activated_at: DateTime<Utc>,
+    },
+    FacilityRemoved {
+        id: uuid::Uuid,
+    },
+    CollateralUpdated {
+        id: uuid::Uuid,
+        collateral: Satoshis,
+    },
+    ObligationCreated {
+        id: uuid::Uuid,
+        obligation: OpenObligation,
+    },
+    PaymentAllocated {
+        obligation_id: uuid::Uuid,
+        credit_facility_id: uuid::Uuid,
+        amount: UsdCents,
+    },
+    ObligationCompleted {
+        id: uuid::Uuid,
+    },
+}
+
+impl ExposureChange {
+    pub(crate) fn from_event(event: &LanaEvent) -> Option<Self> {
+        let change = match event {
+            LanaEvent::Customer(
+                CoreCustomerEvent::PartyCreated { entity }
+                | CoreCustomerEvent::PartyEmailUpdated { entity },
+            ) => Self::PartyUpdated {
+                id: entity.id,
+                customer_type: entity.customer_type,
+            },
+            LanaEvent::Customer(CoreCustomerEvent::CustomerCreated { entity }) => {
+                Self::CustomerCreated {
+                    id: entity.id,
+                    party_id: entity.party_id,
+                }
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityProposalCreated { entity }) => {
+                Self::FacilityProposed {
+                    id: entity.id.into(),
+                    customer_id: entity.customer_id,
+                    terms: entity.terms,
+                }
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityProposalConcluded { entity })
+                if entity.status != CreditFacilityProposalStatus::Approved =>
+            {
+                Self::FacilityRemoved {
+                    id: entity.id.into(),
+                }
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityActivated { entity }) => {
+                Self::FacilityActivated {
+                    id: entity.id.into(),
+                    activated_at: entity.activated_at,
+                }
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityCompleted { entity }) => {
+                Self::FacilityRemoved {
+                    id: entity.id.into(),
+                }
+            }
+            LanaEvent::CreditCollateral(CoreCreditCollateralEvent::CollateralUpdated {
+                entity,
+            }) => Self::CollateralUpdated {
+                id: entity.secured_loan_id.into(),
+                collateral: entity.amount,
+            },
+            LanaEvent::CreditCollection(CoreCreditCollectionEvent::ObligationCreated {
+                entity,
+            }) => Self::ObligationCreated {
+                id: entity.id.into(),
+                obligation: OpenObligation {
+                    credit_facility_id: entity.beneficiary_id.into(),
+                    due_at: entity.due_at.into(),
+                    outstanding: entity.outstanding_amount,
+                },
+            },
+            LanaEvent::CreditCollection(CoreCreditCollectionEvent::PaymentAllocationCreated {
+                entity,
+            }) => Self::PaymentAllocated {
+                obligation_id: entity.obligation_id.into(),
+                credit_facility_id: entity.beneficiary_id.into(),
+                amount: entity.amount,
+            },
+            LanaEvent::CreditCollection(CoreCreditCollectionEvent::ObligationCompleted {
+                entity,
+            }) => Self::ObligationCompleted {
+                id: entity.id.into(),
+            },
+            _ => return None,
+        };
+        Some(change)
+    }
+}
+
+/// Portfolio-wide state kept in the dashboard itself. Per-facility exposures live in
+/// their own tables and are only loaded when the dashboard is read.
+#[derive(Clone, Default, Serialize, Deserialize, Debug)]
+pub struct PortfolioRisk {
+    price: Option<PriceOfOneBTC>,
+    as_of: Option<NaiveDate>,
+    #[serde(skip)]
+    pub(crate) facilities: HashMap<uuid::Uuid, FacilityExposure>,
+    #[serde(skip)]
+    pub(crate) obligations: HashMap<uuid::Uuid, OpenObligation>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomerTypeExposure {
+    pub customer_type: CustomerType,
+    pub facilities: u32,
+    pub outstanding: UsdCents,
+}
+
+#[derive(Clone, Debug)]
+pub struct BorrowerConcentration {
+    pub customer_id: CustomerId,
+    pub outstanding: UsdCents,
+    pub share_pct: Decimal,
+}
+
+#[derive(Clone, Debug)]
+pub struct CvlDistributionBucket {
+    pub min_cvl: CVLPct,
+    pub max_cvl: Option<CVLPct>,
+    pub facilities: u32,
+    pub outstanding: UsdCents,
+}
+
+#[derive(Clone, Debug)]
+pub struct DaysPastDueBucket {
+    pub min_days: u32,
+    pub max_days: Option<u32>,
+    pub facilities: u32,
+    pub outstanding: UsdCents,
+}
+
+#[derive(Clone, Debug)]
+pub struct PriceShockOutcome {
+    pub shock_pct: u64,
+    pub price: PriceOfOneBTC,
+    pub margin_call_facilities: u32,
+    pub liquidation_facilities: u32,
+}
+
+impl PortfolioRisk {
+    pub(crate) fn process_event(&mut self, event: &LanaEvent) -> bool {
+        match event {
+            LanaEvent::Price(CorePriceEvent::PriceUpdated { price, .. }) => {
+                self.price = Some(*price);
+            }
+            LanaEvent::Time(CoreTimeEvent::EndOfDay { day, .. }) => {
+                self.as_of = Some(*day);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn active_facilities(&self) -> impl Iterator<Item = (&uuid::Uuid, &FacilityExposure)> {
+        self.facilities
+            .iter()
+            .filter(|(_, facility)| facility.activated_at.is_some())
+    }
+
+    fn total_outstanding(&self) -> UsdCents {
+        self.active_facilities()
+            .fold(UsdCents::ZERO, |total, (_, facility)| {
+                total + facility.outstanding
+            })
+    }
+
+    pub fn exposure_by_customer_type(&self) -> Vec<CustomerTypeExposure> {
+        let mut exposures: Vec<CustomerTypeExposure> = Vec::new();
+        for (_, facility) in self.active_facilities() {
+            let customer_type = facility.customer_type;
+            match exposures
+                .iter_mut()
+                .find(|exposure| exposure.customer_type == customer_type)
+            {
+                Some(exposure) => {
+                    exposure.facilities += 1;
+                    exposure.outstanding += facility.outstanding;
+                }
+                None => exposures.push(CustomerTypeExposure {
+                    customer_type,
+                    facilities: 1,
+                    outstanding: facility.outstanding,
+                }),
+            }
+        }
+        exposures.sort_by_key(|exposure| std::cmp::Reverse(exposure.outstanding));
+        exposures
+    }
+
+    pub fn top_borrowers(&self, n: usize) -> Vec<BorrowerConcentration> {
+        let mut by_customer: HashMap<CustomerId, UsdCents> = HashMap::new();
+        for (_, facility) in self.active_facilities() {
+            *by_customer.entry(facility.customer_id).or_default() += facility.outstanding;
+        }
+
+        let total = self.total_outstanding();
+        let mut borrowers: Vec<_> = by_customer
+            .into_iter()
+            .filter(|(_, outstanding)| !outstanding.is_zero())
+            .map(|(customer_id, outstanding)| BorrowerConcentration {
+                customer_id,
+                outstanding,
+                share_pct: share_pct(outstanding, total),
+            })
+            .collect();
+        borrowers.sort_by_key(|borrower| std::cmp::Reverse(borrower.outstanding));
+        borrowers.truncate(n);
+        borrowers
+    }
+
+    /// Empty until a price has been observed, since CVL cannot be derived without one.
+    pub fn cvl_distribution(&self) -> Vec<CvlDistributionBucket> {
+        let Some(price) = self.price else {
+            return Vec::new();
+        };
+
+        let mut lower = CVLPct::ZERO;
+        let mut buckets: Vec<_> = CVL_BUCKET_BOUNDS
+            .iter()
+            .map(|bound| CVLPct::new(*bound))
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|upper| {
+                let bucket = CvlDistributionBucket {
+                    min_cvl: lower,
+                    max_cvl: upper,
+                    facilities: 0,
+                    outstanding: UsdCents::ZERO,
+                };
+                if let Some(upper) = upper {
+                    lower = upper;
+                }
+                bucket
+            })
+            .collect();
+
+        for (_, facility) in self.active_facilities() {
+            if facility.outstanding.is_zero() {
+                continue;
+            }
+            let cvl = facility.cvl_at(price);
+            if let Some(bucket) = buckets
+                .iter_mut()
+                .find(|bucket| bucket.max_cvl.is_none_or(|max| cvl < max))
+            {
+                bucket.facilities += 1;
+                bucket.outstanding += facility.outstanding;
+            }
+        }
+        buckets
+    }
+
+    /// Ages each facility by its oldest unpaid obligation as of the last end-of-day.
+    pub fn days_past_due_ageing(&self) -> Vec<DaysPastDueBucket> {
+        let mut lower = 0;
+        let mut buckets: Vec<_> = DAYS_PAST_DUE_BUCKET_BOUNDS
+            .iter()
+            .map(|bound| Some(*bound - 1))
+            .chain(std::iter::once(None))
+            .map(|upper| {
+                let bucket = DaysPastDueBucket {
+                    min_days: lower,
+                    max_days: upper,
+                    facilities: 0,
+                    outstanding: UsdCents::ZERO,
+                };
+                if let Some(upper) = upper {
+                    lower = upper + 1;
+                }
+                bucket
+            })
+            .collect();
+
+        for (id, facility) in self.active_facilities() {
+            let days_past_due = self.days_past_due(*id);
+            if let Some(bucket) = buckets
+                .iter_mut()
+                .find(|bucket| bucket.max_days.is_none_or(|max| days_past_due <= max))
+            {
+                bucket.facilities += 1;
+                bucket.outstanding += facility.outstanding;
+            }
+        }
+        buckets
+    }
+
+    fn days_past_due(&self, credit_facility_id: uuid::Uuid) -> u32 {
+        let Some(as_of) = self.as_of else {
+            return 0;
+        };
+        self.obligations
+            .values()
+            .filter(|obligation| {
+                obligation.credit_facility_id == credit_facility_id
+                    && !obligation.outstanding.is_zero()
+                    && obligation.due_at < as_of
+            })
+            .map(|obligation| obligation.due_at)
+            .min()
+            .map(|oldest| (as_of - oldest).num_days() as u32)
+            .unwrap_or(0)
+    }
+
+    pub fn weighted_average_annual_rate(&self) -> Option<AnnualRatePct> {
+        let total = self.total_outstanding();
+        if total.is_zero() {
+            return None;
+        }
+        let weighted: Decimal = self
+            .active_facilities()
+            .map(|(_, facility)| {
+                facility.terms.annual_rate.into_inner() * facility.outstanding.to_usd()
+            })
+            .sum();
+        Some(AnnualRatePct::from(
+            (weighted / total.to_usd())
+                .round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven),
+        ))
+    }
+
+    pub fn weighted_average_days_to_maturity(&self) -> Option<Decimal> {
+        let total = self.total_outstanding();
+        let as_of = self.as_of?;
+        if total.is_zero() {
+            return None;
+        }
+        let weighted: Decimal = self
+            .active_facilities()
+            .filter_map(|(_, facility)| {
+                let days = (facility.matures_on()? - as_of).num_days().max(0);
+                Some(Decimal::from(days) * facility.outstanding.to_usd())
+            })
+            .sum();
+        Some(
+            (weighted / total.to_usd())
+                .round_dp_with_strategy(1, RoundingStrategy::MidpointNearestEven),
+        )
+    }
+
+    /// Counts the facilities that would fall under their own margin call and liquidation
+    /// thresholds if the current BTC price dropped by each of the configured shocks.
+    pub fn price_shocks(&self) -> Vec<PriceShockOutcome> {
+        let Some(price) = self.price else {
+            return Vec::new();
+        };
+
+        PRICE_SHOCK_PCTS
+            .iter()
+            .map(|shock_pct| {
+                let shocked = PriceOfOneBTC::new(UsdCents::from(
+                    price.into_inner().into_inner() * (100 - shock_pct) / 100,
+                ));
+                let mut outcome = PriceShockOutcome {
+                    shock_pct: *shock_pct,
+                    price: shocked,
+                    margin_call_facilities: 0,
+                    liquidation_facilities: 0,
+                };
+                for (_, facility) in self.active_facilities() {
+                    if facility.outstanding.is_zero() {
+                        continue;
+                    }
+                    let cvl = facility.cvl_at(shocked);
+                    if cvl < facility.terms.margin_call_cvl {
+                        outcome.margin_call_facilities += 1;
+                    }
+                    if cvl < facility.terms.liquidation_cvl {
+                        outcome.liquidation_facilities += 1;
+                    }
+                }
+                outcome
+            })
+            .collect()
+    }
+}
+
+fn share_pct(part: UsdCents, total: UsdCents) -> Decimal {
+    if total.is_zero() {
+        return Decimal::ZERO;
+    }
+    (part.to_usd() / total.to_usd() * dec!(100))
+        .round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven)
+}
+
+#[cfg(test)]
+mod test {
+    use core_credit_terms::{
+        DisbursalPolicy, FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct,
+    };
+
+    use super::*;
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(12))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::new(1))
+            .disbursal_policy(DisbursalPolicy::SingleDisbursal)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn portfolio() -> PortfolioRisk {
+        let mut portfolio = PortfolioRisk {
+            price: Some(PriceOfOneBTC::new(UsdCents::from(10_000_000))),
+            as_of: Some(date("2025-03-15")),
+            ..Default::default()
+        };
+        let healthy = uuid::Uuid::new_v4();
+        let stressed = uuid::Uuid::new_v4();
+        for (id, collateral) in [(healthy, 200_000_000), (stressed, 140_000_000)] {
+            portfolio.facilities.insert(
+                id,
+                FacilityExposure {
+                    customer_id: CustomerId::new(),
+                    customer_type: CustomerType::Individual,
+                    terms: terms(),
+                    activated_at: Some("2025-01-01T00:00:00Z".parse().unwrap()),
+                    collateral: Satoshis::from(collateral),
+                    outstanding: UsdCents::from(10_000_000),
+                },
+            );
+        }
+        portfolio.obligations.insert(
+            uuid::Uuid::new_v4(),
+            OpenObligation {
+                credit_facility_id: stressed,
+                due_at: date("2025-02-01"),
+                outstanding: UsdCents::from(100_000),
+            },
+        );
+        portfolio
+    }
+
+    #[test]
+    fn price_shocks_flag_facilities_below_their_thresholds() {
+        let portfolio = portfolio();
+
+        let shocks = portfolio.price_shocks();
+
+        assert_eq!(shocks.len(), 3);
+        assert_eq!(shocks[0].margin_call_facilities, 0);
+        assert_eq!(shocks[1].margin_call_facilities, 1);
+        assert_eq!(shocks[1].liquidation_facilities, 0);
+        assert_eq!(shocks[2].margin_call_facilities, 1);
+        assert_eq!(shocks[2].liquidation_facilities, 1);
+    }
+
+    #[test]
+    fn ages_facilities_by_oldest_unpaid_obligation() {
+        let portfolio = portfolio();
+
+        let buckets = portfolio.days_past_due_ageing();
+
+        assert_eq!(buckets[0].max_days, Some(0));
+        assert_eq!(buckets[0].facilities, 1);
+        assert_eq!(buckets[2].min_days, 31);
+        assert_eq!(buckets[2].facilities, 1);
+        assert_eq!(
+            portfolio.weighted_average_annual_rate(),
+            Some(AnnualRatePct::from(dec!(12)))
+        );
+    }
+}
@@ -2,7 +2,7 @@ use sqlx::PgPool;
 
 use tracing_macros::record_error_severity;
 
-use crate::{error::*, values::*};
+use crate::{error::*, portfolio::*, values::*};
 
 const DASHBOARD_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000000");
 
@@ -61,4 +61,223 @@ impl DashboardRepo {
         };
         Ok(res)
     }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "dashboard.apply_exposure_change_in_tx", skip_all)]
+    pub async fn apply_exposure_change_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        change: ExposureChange,
+    ) -> Result<(), DashboardError> {
+        match change {
+            ExposureChange::PartyUpdated { id, customer_type } => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO dashboard_party_customer_types (id, customer_type)
+                    VALUES ($1, $2)
+                    ON CONFLICT (id) DO UPDATE
+                    SET customer_type = $2, modified_at = NOW()
+                    "#,
+                    id as core_customer::PartyId,
+                    customer_type.to_string()
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::CustomerCreated { id, party_id } => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO dashboard_customer_parties (id, party_id)
+                    VALUES ($1, $2)
+                    ON CONFLICT (id) DO NOTHING
+                    "#,
+                    id as core_customer::CustomerId,
+                    party_id as core_customer::PartyId
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::FacilityProposed {
+                id,
+                customer_id,
+                terms,
+            } => {
+                let terms = serde_json::to_value(terms).expect("Could not serialize terms");
+                sqlx::query!(
+                    r#"
+                    INSERT INTO dashboard_facility_exposures (id, customer_id, terms)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (id) DO NOTHING
+                    "#,
+                    id,
+                    customer_id as core_customer::CustomerId,
+                    terms
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::FacilityActivated { id, activated_at } => {
+                sqlx::query!(
+                    r#"
+                    UPDATE dashboard_facility_exposures
+                    SET activated_at = $2, modified_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    id,
+                    activated_at
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::FacilityRemoved { id } => {
+                sqlx::query!(
+                    "DELETE FROM dashboard_open_obligations WHERE credit_facility_id = $1",
+                    id
+                )
+                .execute(&mut **tx)
+                .await?;
+                sqlx::query!("DELETE FROM dashboard_facility_exposures WHERE id = $1", id)
+                    .execute(&mut **tx)
+                    .await?;
+            }
+            ExposureChange::CollateralUpdated { id, collateral } => {
+                sqlx::query!(
+                    r#"
+                    UPDATE dashboard_facility_exposures
+                    SET collateral = $2, modified_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    id,
+                    collateral as money::Satoshis
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::ObligationCreated { id, obligation } => {
+                sqlx::query!(
+                    r#"
+                    UPDATE dashboard_facility_exposures
+                    SET outstanding = outstanding + $2, modified_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    obligation.credit_facility_id,
+                    obligation.outstanding as money::UsdCents
+                )
+                .execute(&mut **tx)
+                .await?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO dashboard_open_obligations (id, credit_facility_id, due_at, outstanding)
+                    VALUES ($1, $2, $3, $4)
+                    ON CONFLICT (id) DO NOTHING
+                    "#,
+                    id,
+                    obligation.credit_facility_id,
+                    obligation.due_at,
+                    obligation.outstanding as money::UsdCents
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::PaymentAllocated {
+                obligation_id,
+                credit_facility_id,
+                amount,
+            } => {
+                sqlx::query!(
+                    r#"
+                    UPDATE dashboard_open_obligations
+                    SET outstanding = outstanding - $2, modified_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    obligation_id,
+                    amount as money::UsdCents
+                )
+                .execute(&mut **tx)
+                .await?;
+                sqlx::query!(
+                    r#"
+                    UPDATE dashboard_facility_exposures
+                    SET outstanding = outstanding - $2, modified_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    credit_facility_id,
+                    amount as money::UsdCents
+                )
+                .execute(&mut **tx)
+                .await?;
+            }
+            ExposureChange::ObligationCompleted { id } => {
+                sqlx::query!("DELETE FROM dashboard_open_obligations WHERE id = $1", id)
+                    .execute(&mut **tx)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "dashboard.load_exposures", skip_all)]
+    pub async fn load_exposures(
+        &self,
+        portfolio: &mut PortfolioRisk,
+    ) -> Result<(), DashboardError> {
+        let facilities = sqlx::query!(
+            r#"
+            SELECT e.id, e.customer_id as "customer_id: core_customer::CustomerId",
+                   p.customer_type as "customer_type?", e.terms, e.activated_at,
+                   e.collateral as "collateral: money::Satoshis",
+                   e.outstanding as "outstanding: money::UsdCents"
+            FROM dashboard_facility_exposures e
+            LEFT JOIN dashboard_customer_parties c ON c.id = e.customer_id
+            LEFT JOIN dashboard_party_customer_types p ON p.id = c.party_id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        portfolio.facilities = facilities
+            .into_iter()
+            .map(|row| {
+                (
+                    row.id,
+                    FacilityExposure {
+                        customer_id: row.customer_id,
+                        customer_type: row
+                            .customer_type
+                            .and_then(|customer_type| customer_type.parse().ok())
+                            .unwrap_or_default(),
+                        terms: serde_json::from_value(row.terms)
+                            .expect("Could not de-serialize terms"),
+                        activated_at: row.activated_at,
+                        collateral: row.collateral,
+                        outstanding: row.outstanding,
+                    },
+                )
+            })
+            .collect();
+
+        let obligations = sqlx::query!(
+            r#"
+            SELECT id, credit_facility_id, due_at,
+                   outstanding as "outstanding: money::UsdCents"
+            FROM dashboard_open_obligations
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        portfolio.obligations = obligations
+            .into_iter()
+            .map(|row| {
+                (
+                    row.id,
+                    OpenObligation {
+                        credit_facility_id: row.credit_facility_id,
+                        due_at: row.due_at,
+                        outstanding: row.outstanding,
+                    },
+                )
+            })
+            .collect();
+        Ok(())
+    }
 }
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use lana_events::*;
 use money::{Satoshis, UsdCents};
 
+use crate::portfolio::PortfolioRisk;
+
 #[derive(Clone, Default, Serialize, Deserialize, Debug)]
 pub struct DashboardValues {
     pub active_facilities: u32,
@@ -11,11 +13,13 @@ pub struct DashboardValues {
     pub total_disbursed: UsdCents,
     pub total_collateral: Satoshis,
     pub last_updated: DateTime<Utc>,
+    pub portfolio_risk: PortfolioRisk,
 }
 
 impl DashboardValues {
     pub(crate) fn process_event(&mut self, recorded_at: DateTime<Utc>, event: &LanaEvent) -> bool {
         self.last_updated = recorded_at;
+        let portfolio_risk_updated = self.portfolio_risk.process_event(event);
         match event {
             LanaEvent::Credit(CoreCreditEvent::FacilityProposalCreated { .. }) => {
                 self.pending_facilities += 1;
@@ -53,7 +57,7 @@ impl DashboardValues {
                 }
                 true
             }
-            _ => false,
+            _ => portfolio_risk_updated,
         }
     }
 }
@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject};
+use core_deposit::{
+    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, DepositAccountId,
+    GovernanceAction, GovernanceObject,
+};
+use governance::GovernanceEvent;
+use job::*;
+use obix::out::OutboxEventMarker;
+
+const ACCRUE_DEPOSIT_INTEREST_JOB: JobType = JobType::new("task.accrue-deposit-interest");
+const PAGE_SIZE: i64 = 100;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccrueDepositInterestConfig {
+    pub closing_time: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct AccrueDepositInterestJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    deposits: CoreDeposit<Perms, E>,
+}
+
+impl<Perms, E> AccrueDepositInterestJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    pub fn new(deposits: &CoreDeposit<Perms, E>) -> Self {
+        Self {
+            deposits: deposits.clone(),
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for AccrueDepositInterestJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    type Config = AccrueDepositInterestConfig;
+
+    fn job_type(&self) -> JobType {
+        ACCRUE_DEPOSIT_INTEREST_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(AccrueDepositInterestJobRunner {
+            config: job.config()?,
+            deposits: self.deposits.clone(),
+        }))
+    }
+}
+
+struct AccrueDepositInterestJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    config: AccrueDepositInterestConfig,
+    deposits: CoreDeposit<Perms, E>,
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccrueDepositInterestState {
+    last_account_id: Option<DepositAccountId>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for AccrueDepositInterestJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit-sync.accrue-deposit-interest.run",
+        skip(self, current_job),
+        fields(closing_time = %self.config.closing_time)
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<AccrueDepositInterestState>()?
+            .unwrap_or_default();
+
+        loop {
+            let mut op = current_job.begin_op().await?;
+            let ids = self
+                .deposits
+                .list_account_ids_with_deposit_product_in_op(
+                    &mut op,
+                    state.last_account_id,
+                    PAGE_SIZE,
+                )
+                .await?;
+
+            if ids.is_empty() {
+                break;
+            }
+
+            for id in ids.iter() {
+                self.deposits
+                    .accrue_interest_in_op(&mut op, *id, self.config.closing_time)
+                    .await?;
+            }
+
+            state.last_account_id = ids.last().copied();
+            current_job
+                .update_execution_state_in_op(&mut op, &state)
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
+
+pub type AccrueDepositInterestJobSpawner = JobSpawner<AccrueDepositInterestConfig>;
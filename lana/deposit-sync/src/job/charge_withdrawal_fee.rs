@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject};
+use core_deposit::{
+    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, GovernanceAction,
+    GovernanceObject, WithdrawalId,
+};
+use governance::GovernanceEvent;
+use job::*;
+use obix::out::OutboxEventMarker;
+
+pub const CHARGE_WITHDRAWAL_FEE_COMMAND: JobType =
+    JobType::new("command.deposit-sync.charge-withdrawal-fee");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChargeWithdrawalFeeConfig {
+    pub withdrawal_id: WithdrawalId,
+}
+
+pub struct ChargeWithdrawalFeeJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    deposits: CoreDeposit<Perms, E>,
+}
+
+impl<Perms, E> ChargeWithdrawalFeeJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    pub fn new(deposits: &CoreDeposit<Perms, E>) -> Self {
+        Self {
+            deposits: deposits.clone(),
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for ChargeWithdrawalFeeJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    type Config = ChargeWithdrawalFeeConfig;
+
+    fn job_type(&self) -> JobType {
+        CHARGE_WITHDRAWAL_FEE_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ChargeWithdrawalFeeJobRunner {
+            config: job.config()?,
+            deposits: self.deposits.clone(),
+        }))
+    }
+}
+
+struct ChargeWithdrawalFeeJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    config: ChargeWithdrawalFeeConfig,
+    deposits: CoreDeposit<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ChargeWithdrawalFeeJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit-sync.charge-withdrawal-fee.process_command",
+        skip(self, current_job),
+        fields(withdrawal_id = %self.config.withdrawal_id)
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut op = current_job.begin_op().await?;
+        self.deposits
+            .charge_withdrawal_fee_in_op(&mut op, self.config.withdrawal_id)
+            .await?;
+        op.commit().await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
+
+pub type ChargeWithdrawalFeeJobSpawner = JobSpawner<ChargeWithdrawalFeeConfig>;
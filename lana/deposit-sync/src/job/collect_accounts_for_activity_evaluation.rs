@@ -135,7 +135,11 @@ where
             let mut op = current_job.begin_op().await?;
             let rows = self
                 .deposits
-                .list_account_ids_not_escheatable_in_op(&mut op, state.last_cursor, PAGE_SIZE)
+                .list_account_ids_for_activity_evaluation_in_op(
+                    &mut op,
+                    state.last_cursor,
+                    PAGE_SIZE,
+                )
                 .await?;
 
             if rows.is_empty() {
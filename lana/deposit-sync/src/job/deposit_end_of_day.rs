@@ -4,19 +4,41 @@ use core_time_events::CoreTimeEvent;
 use job::JobType;
 use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
 
+use super::accrue_deposit_interest::{
+    AccrueDepositInterestConfig, AccrueDepositInterestJobSpawner,
+};
+use super::charge_deposit_fees::{ChargeDepositFeesConfig, ChargeDepositFeesJobSpawner};
 use super::collect_accounts_for_activity_evaluation::{
     CollectAccountsForActivityEvaluationConfig, CollectAccountsForActivityEvaluationJobSpawner,
 };
+use super::process_escheatments::{ProcessEscheatmentsConfig, ProcessEscheatmentsJobSpawner};
+use super::release_expired_holds::{ReleaseExpiredHoldsConfig, ReleaseExpiredHoldsJobSpawner};
 
 pub const DEPOSIT_END_OF_DAY: JobType = JobType::new("outbox.deposit-end-of-day");
 
 pub struct DepositEndOfDayHandler {
     collect_spawner: CollectAccountsForActivityEvaluationJobSpawner,
+    release_holds_spawner: ReleaseExpiredHoldsJobSpawner,
+    accrue_interest_spawner: AccrueDepositInterestJobSpawner,
+    charge_fees_spawner: ChargeDepositFeesJobSpawner,
+    escheatments_spawner: ProcessEscheatmentsJobSpawner,
 }
 
 impl DepositEndOfDayHandler {
-    pub fn new(collect_spawner: CollectAccountsForActivityEvaluationJobSpawner) -> Self {
-        Self { collect_spawner }
+    pub fn new(
+        collect_spawner: CollectAccountsForActivityEvaluationJobSpawner,
+        release_holds_spawner: ReleaseExpiredHoldsJobSpawner,
+        accrue_interest_spawner: AccrueDepositInterestJobSpawner,
+        charge_fees_spawner: ChargeDepositFeesJobSpawner,
+        escheatments_spawner: ProcessEscheatmentsJobSpawner,
+    ) -> Self {
+        Self {
+            collect_spawner,
+            release_holds_spawner,
+            accrue_interest_spawner,
+            charge_fees_spawner,
+            escheatments_spawner,
+        }
     }
 }
 
@@ -44,6 +66,46 @@ where
                     },
                 )
                 .await?;
+
+            self.release_holds_spawner
+                .spawn_in_op(
+                    op,
+                    job::JobId::new(),
+                    ReleaseExpiredHoldsConfig {
+                        closing_time: *closing_time,
+                    },
+                )
+                .await?;
+
+            self.accrue_interest_spawner
+                .spawn_in_op(
+                    op,
+                    job::JobId::new(),
+                    AccrueDepositInterestConfig {
+                        closing_time: *closing_time,
+                    },
+                )
+                .await?;
+
+            self.charge_fees_spawner
+                .spawn_in_op(
+                    op,
+                    job::JobId::new(),
+                    ChargeDepositFeesConfig {
+                        closing_time: *closing_time,
+                    },
+                )
+                .await?;
+
+            self.escheatments_spawner
+                .spawn_in_op(
+                    op,
+                    job::JobId::new(),
+                    ProcessEscheatmentsConfig {
+                        closing_time: *closing_time,
+                    },
+                )
+                .await?;
         }
         Ok(())
     }
@@ -0,0 +1,50 @@
+use tracing::{Span, instrument};
+
+use core_time_events::CoreTimeEvent;
+use job::JobType;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use super::generate_monthly_deposit_statements::{
+    GenerateMonthlyDepositStatementsConfig, GenerateMonthlyDepositStatementsJobSpawner,
+};
+
+pub const DEPOSIT_END_OF_MONTH: JobType = JobType::new("outbox.deposit-end-of-month");
+
+pub struct DepositEndOfMonthHandler {
+    statements_spawner: GenerateMonthlyDepositStatementsJobSpawner,
+}
+
+impl DepositEndOfMonthHandler {
+    pub fn new(statements_spawner: GenerateMonthlyDepositStatementsJobSpawner) -> Self {
+        Self { statements_spawner }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for DepositEndOfMonthHandler
+where
+    E: OutboxEventMarker<CoreTimeEvent>,
+{
+    #[instrument(name = "deposit_sync.deposit_end_of_month.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ CoreTimeEvent::EndOfMonth { month_end, .. }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.statements_spawner
+                .spawn_in_op(
+                    op,
+                    job::JobId::new(),
+                    GenerateMonthlyDepositStatementsConfig {
+                        month_end: *month_end,
+                    },
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use es_entity::DbOp;
+use tracing::Span;
+
+use core_deposit::CoreDepositEvent;
+use job::{JobId, JobType};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use super::charge_withdrawal_fee::{ChargeWithdrawalFeeConfig, ChargeWithdrawalFeeJobSpawner};
+
+pub const DEPOSIT_FEE_JOB: JobType = JobType::new("outbox.deposit-fee");
+
+pub struct DepositFeeHandler {
+    charge_withdrawal_fee: ChargeWithdrawalFeeJobSpawner,
+}
+
+impl DepositFeeHandler {
+    pub fn new(charge_withdrawal_fee: ChargeWithdrawalFeeJobSpawner) -> Self {
+        Self {
+            charge_withdrawal_fee,
+        }
+    }
+}
+
+impl<E> OutboxEventHandler<E> for DepositFeeHandler
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[tracing::instrument(name = "outbox.deposit_sync.deposit_fee.process_message", parent = None, skip_all, fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ CoreDepositEvent::WithdrawalConfirmed { entity }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.charge_withdrawal_fee
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    ChargeWithdrawalFeeConfig {
+                        withdrawal_id: entity.id,
+                    },
+                    entity.deposit_account_id.to_string(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,8 +5,8 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
 use core_deposit::{
-    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, DepositAccountId,
-    DepositId, GovernanceAction, GovernanceObject, UsdCents,
+    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, CurrencyCode,
+    DepositAccountId, DepositId, GovernanceAction, GovernanceObject, Money, UsdCents,
 };
 use governance::GovernanceEvent;
 use job::*;
@@ -25,6 +25,8 @@ pub struct ExportSumsubDepositConfig {
     pub deposit_account_id: DepositAccountId,
     pub deposit_id: DepositId,
     pub amount: UsdCents,
+    #[serde(default = "default_currency")]
+    pub currency: CurrencyCode,
 }
 
 pub struct ExportSumsubDepositJobInitializer<Perms, E>
@@ -144,15 +146,17 @@ where
         // Valid use case branching
         // lint:allow(service-conditionals)
         if customer.should_sync_financial_transactions() {
-            let amount_usd: f64 = self.config.amount.to_usd().try_into()?;
+            let amount = Money::new(self.config.currency, self.config.amount.into_inner());
+            let amount_major: f64 = amount.to_major().try_into()?;
             self.sumsub_client
                 .submit_finance_transaction(
                     account.account_holder_id,
                     self.config.deposit_id.to_string(),
                     "Deposit",
                     "in",
-                    amount_usd,
-                    "USD",
+                    amount_major,
+                    amount.currency().iso(),
+                    None,
                 )
                 .await?;
         } else {
@@ -167,3 +171,7 @@ where
         Ok(JobCompletion::Complete)
     }
 }
+
+fn default_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
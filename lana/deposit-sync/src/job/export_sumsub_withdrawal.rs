@@ -5,8 +5,9 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
 use core_deposit::{
-    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, DepositAccountId,
-    GovernanceAction, GovernanceObject, UsdCents, WithdrawalId,
+    BeneficiaryDestination, CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject,
+    CurrencyCode, DepositAccountId, GovernanceAction, GovernanceObject, Money, UsdCents,
+    WithdrawalId,
 };
 use governance::GovernanceEvent;
 use job::*;
@@ -25,6 +26,10 @@ pub struct ExportSumsubWithdrawalConfig {
     pub deposit_account_id: DepositAccountId,
     pub withdrawal_id: WithdrawalId,
     pub amount: UsdCents,
+    #[serde(default = "default_currency")]
+    pub currency: CurrencyCode,
+    #[serde(default)]
+    pub destination: Option<BeneficiaryDestination>,
 }
 
 pub struct ExportSumsubWithdrawalJobInitializer<Perms, E>
@@ -147,15 +152,22 @@ where
         // Valid use case branching
         // lint:allow(service-conditionals)
         if customer.should_sync_financial_transactions() {
-            let amount_usd: f64 = self.config.amount.to_usd().try_into()?;
+            let amount = Money::new(self.config.currency, self.config.amount.into_inner());
+            let amount_major: f64 = amount.to_major().try_into()?;
+            let payment_details = self
+                .config
+                .destination
+                .as_ref()
+                .map(BeneficiaryDestination::payment_details);
             self.sumsub_client
                 .submit_finance_transaction(
                     account.account_holder_id,
                     self.config.withdrawal_id.to_string(),
                     "Withdrawal",
                     "out",
-                    amount_usd,
-                    "USD",
+                    amount_major,
+                    amount.currency().iso(),
+                    payment_details.as_deref(),
                 )
                 .await?;
         } else {
@@ -170,3 +182,7 @@ where
         Ok(JobCompletion::Complete)
     }
 }
+
+fn default_currency() -> CurrencyCode {
+    CurrencyCode::USD
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject};
+use core_deposit::{
+    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, DepositAccountId,
+    GovernanceAction, GovernanceObject,
+};
+use governance::GovernanceEvent;
+use job::*;
+use obix::out::OutboxEventMarker;
+
+const GENERATE_MONTHLY_DEPOSIT_STATEMENTS_JOB: JobType =
+    JobType::new("task.generate-monthly-deposit-statements");
+const PAGE_SIZE: i64 = 100;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateMonthlyDepositStatementsConfig {
+    pub month_end: chrono::NaiveDate,
+}
+
+pub struct GenerateMonthlyDepositStatementsJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    deposits: CoreDeposit<Perms, E>,
+}
+
+impl<Perms, E> GenerateMonthlyDepositStatementsJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    pub fn new(deposits: &CoreDeposit<Perms, E>) -> Self {
+        Self {
+            deposits: deposits.clone(),
+        }
+    }
+}
+
+impl<Perms, E> JobInitializer for GenerateMonthlyDepositStatementsJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    type Config = GenerateMonthlyDepositStatementsConfig;
+
+    fn job_type(&self) -> JobType {
+        GENERATE_MONTHLY_DEPOSIT_STATEMENTS_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateMonthlyDepositStatementsJobRunner {
+            config: job.config()?,
+            deposits: self.deposits.clone(),
+        }))
+    }
+}
+
+struct GenerateMonthlyDepositStatementsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    config: GenerateMonthlyDepositStatementsConfig,
+    deposits: CoreDeposit<Perms, E>,
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateMonthlyDepositStatementsState {
+    last_account_id: Option<DepositAccountId>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for GenerateMonthlyDepositStatementsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
+{
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit-sync.generate-monthly-deposit-statements.run",
+        skip(self, current_job),
+        fields(month_end = %self.config.month_end)
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<GenerateMonthlyDepositStatementsState>()?
+            .unwrap_or_default();
+
+        loop {
+            let mut op = current_job.begin_op().await?;
+            let ids = self
+                .deposits
+                .list_account_ids_for_statements_in_op(&mut op, state.last_account_id, PAGE_SIZE)
+                .await?;
+
+            if ids.is_empty() {
+                break;
+            }
+
+            for id in ids.iter() {
+                self.deposits
+                    .create_monthly_account_statements_in_op(&mut op, *id, self.config.month_end)
+                    .await?;
+            }
+
+            state.last_account_id = ids.last().copied();
+            current_job
+                .update_execution_state_in_op(&mut op, &state)
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
+
+pub type GenerateMonthlyDepositStatementsJobSpawner =
+    JobSpawner<GenerateMonthlyDepositStatementsConfig>;
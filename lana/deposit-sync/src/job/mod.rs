@@ -1,13 +1,29 @@
+pub mod accrue_deposit_interest;
+pub mod charge_deposit_fees;
+pub mod charge_withdrawal_fee;
 pub mod collect_accounts_for_activity_evaluation;
 pub mod deposit_end_of_day;
+pub mod deposit_end_of_month;
+mod deposit_fee;
 pub mod evaluate_deposit_account_activity;
 pub mod export_sumsub_deposit;
 pub mod export_sumsub_withdrawal;
+pub mod generate_monthly_deposit_statements;
+pub mod process_escheatments;
+pub mod release_expired_holds;
 mod sumsub_export;
 
+pub use accrue_deposit_interest::*;
+pub use charge_deposit_fees::*;
+pub use charge_withdrawal_fee::*;
 pub use collect_accounts_for_activity_evaluation::*;
 pub use deposit_end_of_day::*;
+pub use deposit_end_of_month::*;
+pub use deposit_fee::*;
 pub use evaluate_deposit_account_activity::*;
 pub use export_sumsub_deposit::*;
 pub use export_sumsub_withdrawal::*;
+pub use generate_monthly_deposit_statements::*;
+pub use process_escheatments::*;
+pub use release_expired_holds::*;
 pub use sumsub_export::*;
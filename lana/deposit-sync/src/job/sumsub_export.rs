@@ -50,6 +50,7 @@ where
                         deposit_account_id: entity.deposit_account_id,
                         deposit_id: entity.id,
                         amount: entity.amount,
+                        currency: entity.currency,
                     },
                     entity.deposit_account_id.to_string(),
                 )
@@ -69,6 +70,8 @@ where
                         deposit_account_id: entity.deposit_account_id,
                         withdrawal_id: entity.id,
                         amount: entity.amount,
+                        currency: entity.currency,
+                        destination: entity.destination.clone(),
                     },
                     entity.deposit_account_id.to_string(),
                 )
@@ -84,11 +84,48 @@ where
             CollectAccountsForActivityEvaluationJobInit::new(deposits, evaluate_spawner),
         );
 
+        let release_holds_spawner = jobs.add_initializer(ReleaseExpiredHoldsJobInit::new(deposits));
+
+        let accrue_interest_spawner =
+            jobs.add_initializer(AccrueDepositInterestJobInit::new(deposits));
+
+        let charge_fees_spawner = jobs.add_initializer(ChargeDepositFeesJobInit::new(deposits));
+
+        let escheatments_spawner = jobs.add_initializer(ProcessEscheatmentsJobInit::new(deposits));
+
         outbox
             .register_event_handler(
                 jobs,
                 OutboxEventJobConfig::new(DEPOSIT_END_OF_DAY),
-                DepositEndOfDayHandler::new(collect_spawner),
+                DepositEndOfDayHandler::new(
+                    collect_spawner,
+                    release_holds_spawner,
+                    accrue_interest_spawner,
+                    charge_fees_spawner,
+                    escheatments_spawner,
+                ),
+            )
+            .await?;
+
+        let monthly_statements_spawner =
+            jobs.add_initializer(GenerateMonthlyDepositStatementsJobInit::new(deposits));
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(DEPOSIT_END_OF_MONTH),
+                DepositEndOfMonthHandler::new(monthly_statements_spawner),
+            )
+            .await?;
+
+        let charge_withdrawal_fee_spawner =
+            jobs.add_initializer(ChargeWithdrawalFeeJobInit::new(deposits));
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(DEPOSIT_FEE_JOB),
+                DepositFeeHandler::new(charge_withdrawal_fee_spawner),
             )
             .await?;
 
@@ -8,7 +8,9 @@ use schemars::JsonSchema;
 
 pub use core_access::CoreAccessEvent;
 pub use core_accounting::CoreAccountingEvent;
-pub use core_credit::{CoreCreditEvent, ObligationStatus, ObligationType};
+pub use core_credit::{
+    CoreCreditEvent, CreditFacilityProposalStatus, ObligationStatus, ObligationType,
+};
 pub use core_credit_collateral::{CollateralDirection, CoreCreditCollateralEvent};
 pub use core_credit_collection::CoreCreditCollectionEvent;
 pub use core_custody::CoreCustodyEvent;
@@ -11,6 +11,7 @@ use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
 use job::{JobId, JobSpawner, JobType};
 
 use super::send_deposit_account_created_email::SendDepositAccountCreatedEmailConfig;
+use super::send_escheatment_notice_email::SendEscheatmentNoticeEmailConfig;
 use super::send_obligation_overdue_email::SendObligationOverdueEmailConfig;
 use super::send_partial_liquidation_email::SendPartialLiquidationEmailConfig;
 use super::send_role_created_email::SendRoleCreatedEmailConfig;
@@ -28,6 +29,7 @@ where
     send_partial_liquidation_email: JobSpawner<SendPartialLiquidationEmailConfig>,
     send_under_margin_call_email: JobSpawner<SendUnderMarginCallEmailConfig>,
     send_deposit_account_created_email: JobSpawner<SendDepositAccountCreatedEmailConfig>,
+    send_escheatment_notice_email: JobSpawner<SendEscheatmentNoticeEmailConfig>,
     send_role_created_email: JobSpawner<SendRoleCreatedEmailConfig>,
 }
 
@@ -42,6 +44,7 @@ where
         send_partial_liquidation_email: JobSpawner<SendPartialLiquidationEmailConfig>,
         send_under_margin_call_email: JobSpawner<SendUnderMarginCallEmailConfig>,
         send_deposit_account_created_email: JobSpawner<SendDepositAccountCreatedEmailConfig>,
+        send_escheatment_notice_email: JobSpawner<SendEscheatmentNoticeEmailConfig>,
         send_role_created_email: JobSpawner<SendRoleCreatedEmailConfig>,
     ) -> Self {
         Self {
@@ -51,6 +54,7 @@ where
             send_partial_liquidation_email,
             send_under_margin_call_email,
             send_deposit_account_created_email,
+            send_escheatment_notice_email,
             send_role_created_email,
         }
     }
@@ -236,6 +240,28 @@ where
                 )
                 .await?;
         }
+        if let Some(
+            deposit_event @ CoreDepositEvent::DepositAccountEscheatmentNoticeIssued { entity },
+        ) = event.as_event()
+            && let Some(escheatment_date) = entity.pending_escheatment_date
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", deposit_event.as_ref());
+
+            self.send_escheatment_notice_email
+                .spawn_with_queue_id_in_op(
+                    op,
+                    JobId::new(),
+                    SendEscheatmentNoticeEmailConfig {
+                        account_id: entity.id,
+                        account_holder_id: entity.account_holder_id,
+                        escheatment_date,
+                    },
+                    format!("{}:{}", entity.id, escheatment_date),
+                )
+                .await?;
+        }
         if let Some(access_event @ CoreAccessEvent::RoleCreated { entity }) = event.as_event() {
             event.inject_trace_parent();
             Span::current().record("handled", true);
@@ -1,5 +1,6 @@
 mod event_listener;
 pub(crate) mod send_deposit_account_created_email;
+pub(crate) mod send_escheatment_notice_email;
 pub(crate) mod send_obligation_overdue_email;
 pub(crate) mod send_partial_liquidation_email;
 pub(crate) mod send_role_created_email;
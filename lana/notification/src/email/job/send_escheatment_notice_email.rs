@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use domain_config::ExposedDomainConfigsReadOnly;
+use serde::{Deserialize, Serialize};
+use smtp_client::SmtpClient;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::Customers;
+use core_deposit::{DepositAccountHolderId, DepositAccountId};
+use job::*;
+use lana_events::LanaEvent;
+use tracing_macros::record_error_severity;
+
+use crate::email::templates::{EmailTemplate, EmailType, EscheatmentNoticeEmailData};
+
+pub const SEND_ESCHEATMENT_NOTICE_EMAIL_COMMAND: JobType =
+    JobType::new("command.notification.send-escheatment-notice-email");
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SendEscheatmentNoticeEmailConfig {
+    pub account_id: DepositAccountId,
+    pub account_holder_id: DepositAccountHolderId,
+    pub escheatment_date: NaiveDate,
+}
+
+pub struct SendEscheatmentNoticeEmailInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    customers: Customers<Perms, LanaEvent>,
+    smtp_client: SmtpClient,
+    template: EmailTemplate,
+    domain_configs: ExposedDomainConfigsReadOnly,
+}
+
+impl<Perms> SendEscheatmentNoticeEmailInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(
+        customers: &Customers<Perms, LanaEvent>,
+        smtp_client: SmtpClient,
+        template: EmailTemplate,
+        domain_configs: ExposedDomainConfigsReadOnly,
+    ) -> Self {
+        Self {
+            customers: customers.clone(),
+            smtp_client,
+            template,
+            domain_configs,
+        }
+    }
+}
+
+impl<Perms> JobInitializer for SendEscheatmentNoticeEmailInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<core_customer::CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_customer::CustomerObject>,
+{
+    type Config = SendEscheatmentNoticeEmailConfig;
+
+    fn job_type(&self) -> JobType {
+        SEND_ESCHEATMENT_NOTICE_EMAIL_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(SendEscheatmentNoticeEmailRunner::<Perms> {
+            config: job.config()?,
+            customers: self.customers.clone(),
+            smtp_client: self.smtp_client.clone(),
+            template: self.template.clone(),
+            domain_configs: self.domain_configs.clone(),
+        }))
+    }
+}
+
+struct SendEscheatmentNoticeEmailRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: SendEscheatmentNoticeEmailConfig,
+    customers: Customers<Perms, LanaEvent>,
+    smtp_client: SmtpClient,
+    template: EmailTemplate,
+    domain_configs: ExposedDomainConfigsReadOnly,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for SendEscheatmentNoticeEmailRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<core_customer::CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_customer::CustomerObject>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(name = "notification.send_escheatment_notice_email.run", skip_all)]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let customer_id: core_customer::CustomerId = self.config.account_holder_id.into();
+        let party = self
+            .customers
+            .find_party_by_customer_id_without_audit(customer_id)
+            .await?;
+
+        let email_data = EscheatmentNoticeEmailData {
+            account_id: self.config.account_id.to_string(),
+            customer_email: party.email.clone(),
+            escheatment_date: self.config.escheatment_date,
+        };
+
+        super::send_rendered_email(
+            &self.smtp_client,
+            &self.template,
+            &self.domain_configs,
+            &party.email,
+            &EmailType::EscheatmentNotice(email_data),
+        )
+        .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
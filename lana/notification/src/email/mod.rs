@@ -13,6 +13,7 @@ use lana_events::LanaEvent;
 use smtp_client::SmtpClient;
 
 use job::send_deposit_account_created_email::SendDepositAccountCreatedEmailInitializer;
+use job::send_escheatment_notice_email::SendEscheatmentNoticeEmailInitializer;
 use job::send_obligation_overdue_email::SendObligationOverdueEmailInitializer;
 use job::send_partial_liquidation_email::SendPartialLiquidationEmailInitializer;
 use job::send_role_created_email::SendRoleCreatedEmailInitializer;
@@ -83,6 +84,14 @@ where
             domain_configs.clone(),
         ));
 
+    let send_escheatment_notice_email =
+        jobs.add_initializer(SendEscheatmentNoticeEmailInitializer::<Perms>::new(
+            customers,
+            smtp_client.clone(),
+            template.clone(),
+            domain_configs.clone(),
+        ));
+
     let send_role_created_email = jobs.add_initializer(SendRoleCreatedEmailInitializer::new(
         smtp_client,
         template,
@@ -96,6 +105,7 @@ where
         send_partial_liquidation_email,
         send_under_margin_call_email,
         send_deposit_account_created_email,
+        send_escheatment_notice_email,
         send_role_created_email,
     ))
 }
@@ -13,6 +13,7 @@ pub enum EmailType {
     PartialLiquidationInitiated(PartialLiquidationInitiatedEmailData),
     UnderMarginCall(UnderMarginCallEmailData),
     DepositAccountCreated(DepositAccountCreatedEmailData),
+    EscheatmentNotice(EscheatmentNoticeEmailData),
     RoleCreated(RoleCreatedEmailData),
     General { subject: String, body: String },
 }
@@ -43,6 +44,10 @@ impl EmailTemplate {
             "account_created",
             include_str!("views/account_created.hbs"),
         )?;
+        handlebars.register_template_string(
+            "escheatment_notice",
+            include_str!("views/escheatment_notice.hbs"),
+        )?;
         handlebars
             .register_template_string("role_created", include_str!("views/role_created.hbs"))?;
         Ok(Self {
@@ -62,6 +67,7 @@ impl EmailTemplate {
             EmailType::DepositAccountCreated(data) => {
                 self.render_deposit_account_created_email(data)
             }
+            EmailType::EscheatmentNotice(data) => self.render_escheatment_notice_email(data),
             EmailType::RoleCreated(data) => self.render_role_created_email(data),
             EmailType::General { subject, body } => self.generic_email_template(subject, body),
         }
@@ -164,6 +170,22 @@ impl EmailTemplate {
         Ok((subject, html_body))
     }
 
+    #[allow(clippy::result_large_err)]
+    fn render_escheatment_notice_email(
+        &self,
+        data: &EscheatmentNoticeEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let subject = "Lana Bank: Your Deposit Account Is Subject to Escheatment".to_string();
+        let data = json!({
+            "subject": &subject,
+            "customer_email": &data.customer_email,
+            "account_id": &data.account_id,
+            "escheatment_date": data.escheatment_date,
+        });
+        let html_body = self.handlebars.render("escheatment_notice", &data)?;
+        Ok((subject, html_body))
+    }
+
     #[allow(clippy::result_large_err)]
     fn render_role_created_email(
         &self,
@@ -215,6 +237,13 @@ pub struct DepositAccountCreatedEmailData {
     pub customer_email: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscheatmentNoticeEmailData {
+    pub account_id: String,
+    pub customer_email: String,
+    pub escheatment_date: NaiveDate,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoleCreatedEmailData {
     pub role_id: String,
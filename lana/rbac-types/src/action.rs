@@ -3,6 +3,7 @@ use std::{fmt::Display, str::FromStr};
 use authz::action_description::*;
 
 use crate::audit_action::AuditAction;
+use aml::AmlModuleAction;
 use contract_creation::ContractModuleAction;
 use core_access::CoreAccessAction;
 use core_accounting::CoreAccountingAction;
@@ -39,6 +40,7 @@ pub enum LanaAction {
     Price(CorePriceAction),
     Report(CoreReportAction),
     Contract(ContractModuleAction),
+    Aml(AmlModuleAction),
 }
 
 impl LanaAction {
@@ -61,6 +63,7 @@ impl LanaAction {
             CorePriceAction::actions(),
             CoreReportAction::actions(),
             ContractModuleAction::actions(),
+            AmlModuleAction::actions(),
         ]
         .concat()
     }
@@ -147,6 +150,12 @@ impl From<ContractModuleAction> for LanaAction {
     }
 }
 
+impl From<AmlModuleAction> for LanaAction {
+    fn from(action: AmlModuleAction) -> Self {
+        LanaAction::Aml(action)
+    }
+}
+
 impl Display for LanaAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}:", LanaActionDiscriminants::from(self))?;
@@ -168,6 +177,7 @@ impl Display for LanaAction {
             Price(action) => action.fmt(f),
             Report(action) => action.fmt(f),
             Contract(action) => action.fmt(f),
+            Aml(action) => action.fmt(f),
         }
     }
 }
@@ -195,6 +205,7 @@ impl FromStr for LanaAction {
             Price => LanaAction::from(action.parse::<CorePriceAction>()?),
             Report => LanaAction::from(action.parse::<CoreReportAction>()?),
             Contract => LanaAction::from(action.parse::<ContractModuleAction>()?),
+            Aml => LanaAction::from(action.parse::<AmlModuleAction>()?),
         };
         Ok(res)
     }
@@ -1,6 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
 use crate::audit_object::AuditObject;
+use aml::AmlModuleObject;
 use contract_creation::ContractModuleObject;
 use core_access::CoreAccessObject;
 use core_accounting::CoreAccountingObject;
@@ -37,6 +38,7 @@ pub enum LanaObject {
     Dashboard(DashboardModuleObject),
     Report(ReportObject),
     Contract(ContractModuleObject),
+    Aml(AmlModuleObject),
 }
 
 impl From<AuditObject> for LanaObject {
@@ -125,6 +127,12 @@ impl From<ContractModuleObject> for LanaObject {
     }
 }
 
+impl From<AmlModuleObject> for LanaObject {
+    fn from(object: AmlModuleObject) -> Self {
+        LanaObject::Aml(object)
+    }
+}
+
 impl Display for LanaObject {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}/", LanaObjectDiscriminants::from(self))?;
@@ -146,6 +154,7 @@ impl Display for LanaObject {
             Dashboard(object) => object.fmt(f),
             Report(object) => object.fmt(f),
             Contract(object) => object.fmt(f),
+            Aml(object) => object.fmt(f),
         }
     }
 }
@@ -185,6 +194,7 @@ impl FromStr for LanaObject {
                     .parse::<ContractModuleObject>()
                     .map_err(|_| "could not parse ContractModuleObject")?,
             ),
+            Aml => LanaObject::from(object.parse::<AmlModuleObject>()?),
         };
         Ok(res)
     }
@@ -0,0 +1,222 @@
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use super::{ConversionError, Currency, CurrencyCode, MinorUnits};
+
+// ---------------------------------------------------------------------------
+// Money — minor units of a currency only known at runtime
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct Money {
+    currency: CurrencyCode,
+    minor_units: u64,
+}
+
+impl Money {
+    pub fn new(currency: CurrencyCode, minor_units: u64) -> Self {
+        Self {
+            currency,
+            minor_units,
+        }
+    }
+
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self::new(currency, 0)
+    }
+
+    pub fn try_from_major(currency: CurrencyCode, major: Decimal) -> Result<Self, ConversionError> {
+        let minor = major * Decimal::from(currency.minor_units_per_major());
+        if minor.trunc() != minor {
+            return Err(ConversionError::PrecisionLoss(major));
+        }
+        if minor < Decimal::ZERO {
+            return Err(ConversionError::UnexpectedNegativeNumber(minor));
+        }
+        Ok(Self::new(currency, u64::try_from(minor)?))
+    }
+
+    /// Like `try_from_major` but drops any fraction of a minor unit instead of failing.
+    pub fn try_from_major_truncated(
+        currency: CurrencyCode,
+        major: Decimal,
+    ) -> Result<Self, ConversionError> {
+        let minor = major * Decimal::from(currency.minor_units_per_major());
+        Self::try_from_major(
+            currency,
+            minor.trunc() / Decimal::from(currency.minor_units_per_major()),
+        )
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    pub fn minor_units(&self) -> u64 {
+        self.minor_units
+    }
+
+    pub fn to_major(&self) -> Decimal {
+        Decimal::from(self.minor_units) / Decimal::from(self.currency.minor_units_per_major())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.minor_units == 0
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, ConversionError> {
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_add(other.minor_units)
+            .map(|minor_units| Self::new(self.currency, minor_units))
+            .ok_or(ConversionError::Overflow)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, ConversionError> {
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_sub(other.minor_units)
+            .map(|minor_units| Self::new(self.currency, minor_units))
+            .ok_or(ConversionError::Overflow)
+    }
+
+    fn ensure_same_currency(&self, other: Self) -> Result<(), ConversionError> {
+        if self.currency != other.currency {
+            return Err(ConversionError::CurrencyMismatch {
+                expected: self.currency,
+                actual: other.currency,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.to_major(), self.currency)
+    }
+}
+
+// --- From conversions ---
+
+impl<C: Currency> From<MinorUnits<C>> for Money {
+    fn from(value: MinorUnits<C>) -> Self {
+        Self::new(C::CODE, value.into_inner())
+    }
+}
+
+impl<C: Currency> TryFrom<Money> for MinorUnits<C> {
+    type Error = ConversionError;
+
+    fn try_from(value: Money) -> Result<Self, Self::Error> {
+        if value.currency != C::CODE {
+            return Err(ConversionError::CurrencyMismatch {
+                expected: C::CODE,
+                actual: value.currency,
+            });
+        }
+        Ok(Self::from(value.minor_units))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{Btc, Usd, Usdt};
+
+    #[test]
+    fn major_round_trip_respects_minor_units() {
+        let usd = Money::try_from_major(CurrencyCode::USD, dec!(12.34)).unwrap();
+        assert_eq!(usd.minor_units(), 1234);
+        assert_eq!(usd.to_major(), dec!(12.34));
+
+        let usdt = Money::try_from_major(CurrencyCode::USDT, dec!(1.000001)).unwrap();
+        assert_eq!(usdt.minor_units(), 1_000_001);
+
+        let btc = Money::try_from_major(CurrencyCode::BTC, dec!(0.00000001)).unwrap();
+        assert_eq!(btc.minor_units(), 1);
+    }
+
+    #[test]
+    fn try_from_major_rejects_fractional_minor_units() {
+        assert!(matches!(
+            Money::try_from_major(CurrencyCode::USD, dec!(1.001)),
+            Err(ConversionError::PrecisionLoss(_))
+        ));
+    }
+
+    #[test]
+    fn try_from_major_rejects_negative_amounts() {
+        assert!(matches!(
+            Money::try_from_major(CurrencyCode::EUR, dec!(-1)),
+            Err(ConversionError::UnexpectedNegativeNumber(_))
+        ));
+    }
+
+    #[test]
+    fn try_from_major_truncated_drops_fraction() {
+        let eur = Money::try_from_major_truncated(CurrencyCode::EUR, dec!(1.239)).unwrap();
+        assert_eq!(eur, Money::new(CurrencyCode::EUR, 123));
+    }
+
+    #[test]
+    fn checked_add_and_sub() {
+        let a = Money::new(CurrencyCode::EUR, 150);
+        let b = Money::new(CurrencyCode::EUR, 50);
+        assert_eq!(
+            a.checked_add(b).unwrap(),
+            Money::new(CurrencyCode::EUR, 200)
+        );
+        assert_eq!(
+            a.checked_sub(b).unwrap(),
+            Money::new(CurrencyCode::EUR, 100)
+        );
+        assert!(matches!(b.checked_sub(a), Err(ConversionError::Overflow)));
+        assert!(matches!(
+            Money::new(CurrencyCode::EUR, u64::MAX).checked_add(b),
+            Err(ConversionError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn arithmetic_rejects_mixed_currencies() {
+        let eur = Money::new(CurrencyCode::EUR, 100);
+        let usd = Money::new(CurrencyCode::USD, 100);
+        assert!(matches!(
+            eur.checked_add(usd),
+            Err(ConversionError::CurrencyMismatch { .. })
+        ));
+        assert!(matches!(
+            eur.checked_sub(usd),
+            Err(ConversionError::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn converts_to_and_from_typed_minor_units() {
+        let money = Money::from(MinorUnits::<Usd>::from(500));
+        assert_eq!(money, Money::new(CurrencyCode::USD, 500));
+        assert_eq!(
+            MinorUnits::<Usd>::try_from(money).unwrap().into_inner(),
+            500
+        );
+        assert!(matches!(
+            MinorUnits::<Btc>::try_from(money),
+            Err(ConversionError::CurrencyMismatch { .. })
+        ));
+        assert!(MinorUnits::<Usdt>::try_from(Money::zero(CurrencyCode::USDT)).is_ok());
+    }
+
+    #[test]
+    fn displays_major_amount_with_code() {
+        assert_eq!(Money::new(CurrencyCode::EUR, 1050).to_string(), "10.50 EUR");
+    }
+}
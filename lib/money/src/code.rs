@@ -9,17 +9,24 @@ use thiserror::Error;
 // ---------------------------------------------------------------------------
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct CurrencyCode(&'static str);
+pub struct CurrencyCode(&'static str, u64);
 
 impl CurrencyCode {
-    pub const USD: Self = Self("USD");
-    pub const BTC: Self = Self("BTC");
+    pub const USD: Self = Self("USD", 100);
+    pub const BTC: Self = Self("BTC", 100_000_000);
+    pub const EUR: Self = Self("EUR", 100);
+    pub const USDT: Self = Self("USDT", 1_000_000);
+    pub const USDC: Self = Self("USDC", 1_000_000);
 
-    const ALL: &[Self] = &[Self::USD, Self::BTC];
+    pub const ALL: &[Self] = &[Self::USD, Self::BTC, Self::EUR, Self::USDT, Self::USDC];
 
-    pub fn iso(&self) -> &'static str {
+    pub const fn iso(&self) -> &'static str {
         self.0
     }
+
+    pub const fn minor_units_per_major(&self) -> u64 {
+        self.1
+    }
 }
 
 impl fmt::Display for CurrencyCode {
@@ -62,11 +69,26 @@ impl Serialize for CurrencyCode {
 
 impl<'de> Deserialize<'de> for CurrencyCode {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let s = <&str>::deserialize(deserializer)?;
+        let s = String::deserialize(deserializer)?;
         s.parse().map_err(serde::de::Error::custom)
     }
 }
 
+#[cfg(feature = "json-schema")]
+impl schemars::JsonSchema for CurrencyCode {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        String::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(generator)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Errors
 // ---------------------------------------------------------------------------
@@ -76,3 +98,32 @@ pub enum ParseCurrencyCodeError {
     #[error("ParseCurrencyCodeError - UnknownCurrencyCode: {0}")]
     UnknownCurrencyCode(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_codes() {
+        for code in CurrencyCode::ALL {
+            assert_eq!(code.iso().parse::<CurrencyCode>().unwrap(), *code);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_codes() {
+        assert!(matches!(
+            "XYZ".parse::<CurrencyCode>(),
+            Err(ParseCurrencyCodeError::UnknownCurrencyCode(code)) if code == "XYZ"
+        ));
+    }
+
+    #[test]
+    fn serializes_as_iso_string() {
+        let json = serde_json::to_string(&CurrencyCode::EUR).unwrap();
+        assert_eq!(json, "\"EUR\"");
+        let code: CurrencyCode = serde_json::from_str(&json).unwrap();
+        assert_eq!(code, CurrencyCode::EUR);
+        assert!(serde_json::from_str::<CurrencyCode>("\"XYZ\"").is_err());
+    }
+}
@@ -12,6 +12,11 @@ pub enum ConversionError {
     Overflow,
     #[error("ConversionError - PrecisionLoss: {0} has fractional minor units")]
     PrecisionLoss(rust_decimal::Decimal),
+    #[error("ConversionError - CurrencyMismatch: expected {expected}, got {actual}")]
+    CurrencyMismatch {
+        expected: crate::CurrencyCode,
+        actual: crate::CurrencyCode,
+    },
 }
 
 impl ErrorSeverity for ConversionError {
@@ -21,6 +26,7 @@ impl ErrorSeverity for ConversionError {
             Self::UnexpectedNegativeNumber(_) => Level::WARN,
             Self::Overflow => Level::ERROR,
             Self::PrecisionLoss(_) => Level::WARN,
+            Self::CurrencyMismatch { .. } => Level::WARN,
         }
     }
 }
@@ -1,6 +1,7 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
+mod amount;
 mod code;
 mod error;
 mod map;
@@ -13,6 +14,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 
+pub use amount::Money;
 pub use code::*;
 pub use error::ConversionError;
 pub use map::*;
@@ -44,6 +46,30 @@ impl Currency for Btc {
     const MINOR_UNITS_PER_MAJOR: u64 = 100_000_000;
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Eur;
+
+impl Currency for Eur {
+    const CODE: CurrencyCode = CurrencyCode::EUR;
+    const MINOR_UNITS_PER_MAJOR: u64 = CurrencyCode::EUR.minor_units_per_major();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Usdt;
+
+impl Currency for Usdt {
+    const CODE: CurrencyCode = CurrencyCode::USDT;
+    const MINOR_UNITS_PER_MAJOR: u64 = CurrencyCode::USDT.minor_units_per_major();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Usdc;
+
+impl Currency for Usdc {
+    const CODE: CurrencyCode = CurrencyCode::USDC;
+    const MINOR_UNITS_PER_MAJOR: u64 = CurrencyCode::USDC.minor_units_per_major();
+}
+
 // ---------------------------------------------------------------------------
 // MinorUnits<C> — unsigned
 // ---------------------------------------------------------------------------
@@ -77,6 +103,14 @@ impl<C: Currency> MinorUnits<C> {
     pub fn is_zero(self) -> bool {
         self.0 == 0
     }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(|v| Self(v, PhantomData))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(|v| Self(v, PhantomData))
+    }
 }
 
 // --- Currency-specific methods ---
@@ -458,6 +492,20 @@ mod graphql_scalars {
         }
     }
 
+    #[Scalar(name = "CurrencyCode")]
+    impl ScalarType for CurrencyCode {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match &value {
+                Value::String(s) => Ok(s.parse()?),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.iso().to_string())
+        }
+    }
+
     #[Scalar(name = "UsdCents")]
     impl ScalarType for MinorUnits<Usd> {
         fn parse(value: Value) -> InputValueResult<Self> {
@@ -116,6 +116,7 @@ impl SumsubClient {
         direction: &str,
         amount: f64,
         currency_code: &str,
+        payment_details: Option<&str>,
     ) -> Result<(), SumsubError>
     where
         T: std::fmt::Display + Clone + serde::de::DeserializeOwned,
@@ -143,7 +144,7 @@ impl SumsubClient {
                 "amount": amount,
                 "currencyCode": currency_code,
                 "currencyType": "fiat",
-                "paymentDetails": ""
+                "paymentDetails": payment_details.unwrap_or_default()
             },
             "applicant": {
                 "type": "individual",